@@ -4,9 +4,14 @@ use pg_query;
 brunch::benches!(
     Bench::new("parse").run_seeded_with(seed, |query| pg_query::parse(&query)),
     Bench::new("summary").run_seeded_with(seed, |query| pg_query::summary(&query, -1)),
+    // Filter columns are resolved with a full parse, so this shows what that costs.
+    Bench::new("summary without filter columns").run_seeded_with(unfiltered_seed, |query| pg_query::summary(&query, -1)),
     // I had to be less mean just so the parse+truncate one didn't crash.
     Bench::new("parse + truncate").run_seeded_with(less_mean_seed, |query| pg_query::parse(&query).unwrap().truncate(50).unwrap()),
     Bench::new("summary + truncate").run_seeded_with(less_mean_seed, |query| pg_query::summary(&query, 50)),
+    Bench::new("nodes()").run_seeded_with(parsed_seed, |result| result.protobuf.nodes().len()),
+    Bench::new("iter()").run_seeded_with(parsed_seed, |result| result.protobuf.iter().count()),
+    Bench::new("iter_breadth_first()").run_seeded_with(parsed_seed, |result| result.protobuf.iter_breadth_first().count()),
 );
 
 fn less_mean_seed() -> String {
@@ -17,6 +22,18 @@ fn seed() -> String {
     build_query(500)
 }
 
+fn unfiltered_seed() -> String {
+    let mut query = "SELECT * FROM t".to_string();
+    for i in 0..500 {
+        query = format!("{query} CROSS JOIN t{i} CROSS JOIN (SELECT o FROM p) p{i}");
+    }
+    query
+}
+
+fn parsed_seed() -> pg_query::ParseResult {
+    pg_query::parse(&seed()).unwrap()
+}
+
 fn build_query(table_references: i32) -> String {
     let mut query = "SELECT * FROM t".to_string();
     for i in 0..table_references {
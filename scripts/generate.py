@@ -0,0 +1,516 @@
+#!/usr/bin/env python3
+"""Generates the code that walks parse trees from the protobuf definitions in src/protobuf.rs.
+
+Run `python3 scripts/generate.py` after updating src/protobuf.rs. It rewrites src/visitor.rs, and the code
+between the `BEGIN GENERATED` and `END GENERATED` comments of the other files listed at the bottom, then
+formats the changed files with rustfmt. CI checks that the committed code matches its output.
+"""
+
+import os
+import re
+import subprocess
+import sys
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+BEGIN = 'BEGIN GENERATED by scripts/generate.py from src/protobuf.rs, do not edit by hand'
+END = 'END GENERATED'
+
+
+def snake(name):
+    s = re.sub(r'([A-Z]+)([A-Z][a-z])', r'\1_\2', name)
+    s = re.sub(r'([a-z0-9])([A-Z])', r'\1_\2', s)
+    return s.lower()
+
+
+def field_name(field):
+    return field[2:] if field.startswith('r#') else field
+
+
+def msg_type(ty):
+    """Returns whether a message field is a `vec` or `single` field, and the message type it holds."""
+    kind = 'single'
+    if ty.startswith('::prost::alloc::vec::Vec<'):
+        kind, ty = 'vec', ty[len('::prost::alloc::vec::Vec<'):-1]
+    elif ty.startswith('::core::option::Option<'):
+        ty = ty[len('::core::option::Option<'):-1]
+    if ty.startswith('::prost::alloc::boxed::Box<'):
+        ty = ty[len('::prost::alloc::boxed::Box<'):-1]
+    return kind, ty
+
+
+# Structs as name -> [(prost attribute, field, type)], in definition order
+SOURCE = open(os.path.join(ROOT, 'src/protobuf.rs')).read()
+STRUCTS = {}
+for m in re.finditer(r'^pub struct (\w+) \{(?:\}|\n(.*?)^\})', SOURCE, re.S | re.M):
+    fields = []
+    for f in re.finditer(r'#\[prost\(([^\]]*?)\)\]\s*pub (r#)?(\w+): ([^;]*?),\n(?=\s*(?:#|///|\Z))', m.group(2) or '', re.S):
+        ty = re.sub(r'\s+', '', f.group(4)).replace(',>', '>')
+        fields.append((f.group(1).strip(), (f.group(2) or '') + f.group(3), ty))
+    STRUCTS[m.group(1)] = fields
+
+# Oneofs as module::Enum -> [(variant, type)]
+ONEOFS = {}
+for m in re.finditer(r'^pub mod (\w+) \{\n(.*?)^\}', SOURCE, re.S | re.M):
+    for e in re.finditer(r'#\[derive\(Clone, PartialEq, ::prost::Oneof\)\]\s*pub enum (\w+) \{\n(.*?)\n    \}', m.group(2), re.S):
+        body = re.sub(r'\s+', '', e.group(2))
+        ONEOFS[f'{m.group(1)}::{e.group(1)}'] = re.findall(r'(\w+)\((?:::prost::alloc::boxed::Box<)?super::(\w+)>?,?\)', body)
+
+# The node types, as (NodeEnum variant, struct)
+VARIANTS = ONEOFS['node::Node']
+VARIANT_OF = {t: v for v, t in VARIANTS}
+
+
+def messages():
+    """The message types reachable from ParseResult, in definition order."""
+    reached = set()
+
+    def visit(name):
+        if name in reached:
+            return
+        reached.add(name)
+        for attr, _, ty in STRUCTS[name]:
+            if attr.startswith('oneof'):
+                for _, t in ONEOFS[msg_type(ty)[1]]:
+                    visit(t)
+            elif attr.startswith('message'):
+                visit(msg_type(ty)[1])
+
+    visit('ParseResult')
+    return [name for name in STRUCTS if name in reached]
+
+
+def children(t):
+    """The fields of `t` that hold nodes, as (kind, field, name, message type, boxed)."""
+    result = []
+    for attr, f, ty in STRUCTS[t]:
+        if attr.startswith('oneof'):
+            result.append(('oneof', f, field_name(f), msg_type(ty)[1], False))
+        elif attr.startswith('message'):
+            kind, mt = msg_type(ty)
+            result.append((kind, f, field_name(f), mt, 'Box<' in ty))
+    return result
+
+
+#
+# src/visitor.rs
+#
+
+VISITOR_DOC = '''\
+/// Walks a parse tree by shared reference, with one method per node type.
+///
+/// Every method defaults to calling the matching `walk_*` function in this module, which visits all
+/// child nodes in field order. Override a method to inspect that node type, and call the `walk_*`
+/// function from it to keep descending; returning without calling it skips the subtree.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::protobuf::{ColumnRef, SubLink};
+/// use pg_query::visitor::{walk_column_ref, Visitor};
+///
+/// #[derive(Default)]
+/// struct Columns(usize);
+///
+/// impl<'ast> Visitor<'ast> for Columns {
+///     fn visit_column_ref(&mut self, node: &'ast ColumnRef) {
+///         self.0 += 1;
+///         walk_column_ref(self, node);
+///     }
+///
+///     // Skip subqueries
+///     fn visit_sub_link(&mut self, _node: &'ast SubLink) {}
+/// }
+///
+/// let result = pg_query::parse("SELECT a, b FROM t WHERE c IN (SELECT d FROM u)").unwrap();
+/// let mut columns = Columns::default();
+/// columns.visit_parse_result(&result.protobuf);
+/// assert_eq!(columns.0, 2);
+/// ```
+pub trait Visitor<'ast> {
+    fn visit_node(&mut self, node: &'ast protobuf::Node) {
+        walk_node(self, node);
+    }'''
+
+VISITOR_MUT_DOC = '''\
+/// Walks a parse tree by mutable reference, with one method per node type.
+///
+/// Works like [Visitor], but every node can be modified or replaced in place. Methods default to
+/// calling the matching `walk_*_mut` function in this module.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::protobuf::RangeVar;
+/// use pg_query::visitor::VisitorMut;
+///
+/// struct Rename;
+///
+/// impl VisitorMut for Rename {
+///     fn visit_range_var_mut(&mut self, node: &mut RangeVar) {
+///         if node.relname == "users" {
+///             node.relname = "accounts".to_string();
+///         }
+///     }
+/// }
+///
+/// let mut result = pg_query::parse("SELECT * FROM users JOIN posts ON true").unwrap();
+/// Rename.visit_parse_result_mut(&mut result.protobuf);
+/// assert_eq!(result.deparse().unwrap(), "SELECT * FROM accounts JOIN posts ON true");
+/// ```
+pub trait VisitorMut {
+    fn visit_node_mut(&mut self, node: &mut protobuf::Node) {
+        walk_node_mut(self, node);
+    }'''
+
+
+def visitor():
+    msgs = messages()
+    out = ['//! Traits for walking a parse tree, generated by scripts/generate.py from the protobuf definitions.', '']
+    out += ['use crate::protobuf::{self, a_const, node};', '']
+    for mutable in [False, True]:
+        ref, sfx = ('&mut ', '_mut') if mutable else ('&', '')
+        out.append(VISITOR_MUT_DOC if mutable else VISITOR_DOC)
+        for n in msgs:
+            if n == 'Node':
+                continue
+            if mutable:
+                out.append(f'    fn visit_{snake(n)}_mut(&mut self, node: &mut protobuf::{n}) {{')
+            else:
+                out.append(f"    fn visit_{snake(n)}(&mut self, node: &'ast protobuf::{n}) {{")
+            out += [f'        walk_{snake(n)}{sfx}(self, node);', '    }']
+        out += ['}', '']
+        for n in msgs:
+            body = []
+            for kind, f, _, mt, _ in children(n):
+                if kind == 'oneof':
+                    body.append(f'    match {ref}node.{f} {{')
+                    for var, vt in ONEOFS[mt]:
+                        body.append(f'        Some({mt}::{var}(n)) => v.visit_{snake(vt)}{sfx}(n),')
+                    body += ['        None => {}', '    }']
+                else:
+                    body.append(f'    for n in {ref}node.{f} {{' if kind == 'vec' else f'    if let Some(n) = {ref}node.{f} {{')
+                    body += [f'        v.visit_{snake(mt)}{sfx}(n);', '    }']
+            v, node = ('v', 'node') if body else ('_v', '_node')
+            if mutable:
+                out.append(f'pub fn walk_{snake(n)}_mut<V: VisitorMut + ?Sized>({v}: &mut V, {node}: &mut protobuf::{n}) {{')
+            else:
+                out.append(f"pub fn walk_{snake(n)}<'ast, V: Visitor<'ast> + ?Sized>({v}: &mut V, {node}: &'ast protobuf::{n}) {{")
+            out += body + ['}', '']
+    return out
+
+
+#
+# src/node_path.rs
+#
+
+def node_path():
+    out = ['// Calls `f` with every direct child of `node` and the step leading to it, in field order.']
+    out += ["pub(crate) fn for_each_child<'a>(node: NodeRef<'a>, f: &mut impl FnMut(PathStep, NodeRef<'a>)) {", '    match node {']
+    for var, t in VARIANTS:
+        lines = []
+        for kind, f, name, mt, _ in children(t):
+            if kind == 'oneof':
+                lines.append(f'            match &n.{f} {{')
+                for ov, ot in ONEOFS[mt]:
+                    lines.append(f'                Some({mt}::{ov}(v)) => f(step("{var}", "{name}", None), NodeRef::{VARIANT_OF[ot]}(v)),')
+                lines += ['                None => {}', '            }']
+            elif kind == 'vec':
+                lines.append(f'            for (i, v) in n.{f}.iter().enumerate() {{')
+                if mt == 'Node':
+                    lines += ['                if let Some(v) = &v.node {', f'                    f(step("{var}", "{name}", Some(i)), v.to_ref());', '                }']
+                else:
+                    lines.append(f'                f(step("{var}", "{name}", Some(i)), NodeRef::{VARIANT_OF[mt]}(v));')
+                lines.append('            }')
+            elif mt == 'Node':
+                lines.append(f'            if let Some(v) = n.{f}.as_ref().and_then(|v| v.node.as_ref()) {{')
+                lines += [f'                f(step("{var}", "{name}", None), v.to_ref());', '            }']
+            else:
+                lines.append(f'            if let Some(v) = &n.{f} {{')
+                lines += [f'                f(step("{var}", "{name}", None), NodeRef::{VARIANT_OF[mt]}(v));', '            }']
+        out += [f'        NodeRef::{var}(n) => {{'] + lines + ['        }'] if lines else [f'        NodeRef::{var}(_) => {{}}']
+    out += ['    }', '}', '']
+
+    # Types that have a path to a `Node` field, which are the only ones a mutable path can lead through
+    resolvable = set()
+    changed = True
+    while changed:
+        changed = False
+        for _, t in VARIANTS:
+            if t in resolvable:
+                continue
+            for kind, _, _, mt, _ in children(t):
+                if mt == 'Node' or mt in resolvable or (kind == 'oneof' and any(ot in resolvable for _, ot in ONEOFS[mt])):
+                    resolvable.add(t)
+                    changed = True
+                    break
+
+    out.append('// Follows `steps` from `node` to a descendant that is stored as a `Node`.')
+    out.append("fn descendant_mut<'a>(node: &'a mut NodeEnum, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {")
+    out += ['    if steps.is_empty() {', '        return Some(node);', '    }', '    match node {']
+    for var, t in VARIANTS:
+        if t in resolvable:
+            out.append(f'        NodeEnum::{var}(n) => resolve_{snake(t)}(n, steps),')
+    out += ['        _ => None,', '    }', '}']
+    for var, t in VARIANTS:
+        if t not in resolvable:
+            continue
+        out.append('')
+        out.append(f"fn resolve_{snake(t)}<'a>(n: &'a mut protobuf::{t}, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {{")
+        out += ['    let (step, rest) = steps.split_first()?;', f'    if step.node_type != "{var}" {{', '        return None;', '    }']
+        out.append('    match (step.field, step.index) {')
+        for kind, f, name, mt, boxed in children(t):
+            if kind == 'oneof':
+                arms = [f'            Some({mt}::{ov}(v)) => resolve_{snake(ot)}(v, rest),' for ov, ot in ONEOFS[mt] if ot in resolvable]
+                if arms:
+                    out += [f'        ("{name}", None) => match &mut n.{f} {{'] + arms + ['            _ => None,', '        },']
+                continue
+            if kind == 'vec':
+                get = f'n.{f}.get_mut(i)?'
+            else:
+                get = f'n.{f}.as_deref_mut()?' if boxed else f'n.{f}.as_mut()?'
+            index = 'Some(i)' if kind == 'vec' else 'None'
+            if mt == 'Node':
+                out.append(f'        ("{name}", {index}) => descendant_mut({get}.node.as_mut()?, rest),')
+            elif mt in resolvable:
+                out.append(f'        ("{name}", {index}) => resolve_{snake(mt)}({get}, rest),')
+        out += ['        _ => None,', '    }', '}']
+    return out
+
+
+#
+# src/node_ref.rs and src/node_mut.rs
+#
+
+def type_name(enum):
+    out = ['/// The name of the node type, e.g. `SelectStmt`.', "pub fn type_name(&self) -> &'static str {", '    match self {']
+    out += [f'        {enum}::{var}(_) => "{var}",' for var, _ in VARIANTS]
+    out += ['    }', '}']
+    if enum == 'NodeRef':
+        out += ['', '// The address of the node, which identifies it within a tree together with its type', 'pub(crate) fn as_ptr(&self) -> *const () {']
+        out += ['    match self {'] + [f'        NodeRef::{var}(n) => (*n as *const protobuf::{t}).cast(),' for var, t in VARIANTS] + ['    }', '}']
+    return out
+
+
+#
+# src/node_fields.rs
+#
+
+def scalar(attr, f, ty):
+    m = re.match(r'enumeration = "(\w+)"', attr)
+    if m:
+        return f'Scalar::Enum(n.{f}, protobuf::{m.group(1)}::try_from(n.{f}).map_or("", |e| e.as_str_name()))'
+    return {
+        'bool': f'Scalar::Bool(n.{f})',
+        'i32': f'Scalar::Int(n.{f}.into())',
+        'i64': f'Scalar::Int(n.{f})',
+        'u32': f'Scalar::UInt(n.{f}.into())',
+        'u64': f'Scalar::UInt(n.{f})',
+        'f64': f'Scalar::Float(n.{f})',
+        '::prost::alloc::string::String': f'Scalar::Str(&n.{f})',
+        '::prost::alloc::vec::Vec<u64>': f'Scalar::UInts(&n.{f})',
+    }[ty]
+
+
+def node_fields():
+    out = ['// Calls `f` with every field of `node` that is not itself a node, in field order.']
+    out += ["pub(crate) fn for_each_scalar<'a>(node: NodeRef<'a>, f: &mut impl FnMut(&'static str, Scalar<'a>)) {", '    match node {']
+    for var, t in VARIANTS:
+        lines = [f'            f("{field_name(f)}", {scalar(attr, f, ty)});' for attr, f, ty in STRUCTS[t] if not attr.startswith(('message', 'oneof'))]
+        out += [f'        NodeRef::{var}(n) => {{'] + lines + ['        }'] if lines else [f'        NodeRef::{var}(_) => {{}}']
+    return out + ['    }', '}']
+
+
+#
+# src/span.rs
+#
+
+def location():
+    out = ['// The `location` field of the node, if it has one and it is set.', 'fn location(node: NodeRef) -> Option<usize> {', '    let location = match node {']
+    for var, t in VARIANTS:
+        if any(f == 'location' and ty == 'i32' for _, f, ty in STRUCTS[t]):
+            out.append(f'        NodeRef::{var}(n) => n.location,')
+    return out + ['        _ => return None,', '    };', '    usize::try_from(location).ok()', '}']
+
+
+#
+# src/fingerprint.rs
+#
+
+# Fields whose name in the C structs is the same as in the protobuf definitions, rather than its camelCase form
+SNAKE_CASE_FIELDS = {
+    'AIndices': ['is_slice'], 'AccessPriv': ['priv_name'], 'AlterDomainStmt': ['missing_ok'],
+    'AlterFdwStmt': ['func_options'], 'AlterForeignServerStmt': ['has_version'], 'AlterObjectSchemaStmt': ['missing_ok'],
+    'AlterPolicyStmt': ['policy_name', 'with_check'], 'AlterPublicationStmt': ['for_all_tables'],
+    'AlterSeqStmt': ['for_identity', 'missing_ok'], 'AlterStatsStmt': ['missing_ok'], 'AlterTableCmd': ['missing_ok'],
+    'AlterTableMoveAllStmt': ['orig_tablespacename', 'new_tablespacename'], 'AlterTableStmt': ['missing_ok'],
+    'AlterTsConfigurationStmt': ['missing_ok'], 'ArrayExpr': ['array_typeid', 'array_collid', 'element_typeid'],
+    'ColumnDef': ['is_local', 'is_not_null', 'is_from_type', 'storage_name', 'raw_default', 'cooked_default'],
+    'CommonTableExpr': ['search_clause', 'cycle_clause'],
+    'Constraint': ['is_no_inherit', 'raw_expr', 'cooked_expr', 'generated_when', 'nulls_not_distinct', 'fk_attrs', 'pk_attrs',
+                   'fk_matchtype', 'fk_upd_action', 'fk_del_action', 'fk_del_set_cols', 'old_conpfeqop', 'old_pktable_oid',
+                   'skip_validation', 'initially_valid', 'access_method', 'where_clause', 'reset_default_tblspc'],
+    'CopyStmt': ['is_from', 'is_program'], 'CreateAmStmt': ['handler_name'],
+    'CreateConversionStmt': ['conversion_name', 'for_encoding_name', 'to_encoding_name', 'func_name'],
+    'CreateExtensionStmt': ['if_not_exists'], 'CreateFdwStmt': ['func_options'], 'CreateForeignServerStmt': ['if_not_exists'],
+    'CreateFunctionStmt': ['is_procedure', 'sql_body'], 'CreateOpClassItem': ['order_family', 'class_args'],
+    'CreatePolicyStmt': ['policy_name', 'cmd_name', 'with_check'], 'CreatePublicationStmt': ['for_all_tables'],
+    'CreateRoleStmt': ['stmt_type'], 'CreateSchemaStmt': ['if_not_exists'], 'CreateSeqStmt': ['for_identity', 'if_not_exists'],
+    'CreateStatsStmt': ['stat_types', 'if_not_exists'], 'CreateStmt': ['if_not_exists'],
+    'CreateTableAsStmt': ['is_select_into', 'if_not_exists'], 'CreateTransformStmt': ['type_name'],
+    'CreateUserMappingStmt': ['if_not_exists'],
+    'CteCycleClause': ['cycle_col_list', 'cycle_mark_column', 'cycle_mark_value', 'cycle_mark_default', 'cycle_path_column',
+                       'cycle_mark_type', 'cycle_mark_typmod', 'cycle_mark_collation', 'cycle_mark_neop'],
+    'CteSearchClause': ['search_col_list', 'search_breadth_first', 'search_seq_column'],
+    'CurrentOfExpr': ['cursor_name', 'cursor_param'], 'DefineStmt': ['if_not_exists'], 'DropRoleStmt': ['missing_ok'],
+    'DropStmt': ['missing_ok'], 'DropSubscriptionStmt': ['missing_ok'], 'DropTableSpaceStmt': ['missing_ok'],
+    'DropUserMappingStmt': ['missing_ok'], 'DropdbStmt': ['missing_ok'],
+    'FuncCall': ['agg_order', 'agg_filter', 'agg_within_group', 'agg_star', 'agg_distinct', 'func_variadic'],
+    'GrantRoleStmt': ['granted_roles', 'grantee_roles', 'is_grant'], 'GrantStmt': ['is_grant', 'grant_option'],
+    'ImportForeignSchemaStmt': ['server_name', 'remote_schema', 'local_schema', 'list_type', 'table_list'],
+    'IndexElem': ['nulls_ordering'], 'IndexStmt': ['nulls_not_distinct', 'if_not_exists', 'reset_default_tblspc'],
+    'InlineCodeBlock': ['source_text'], 'JoinExpr': ['join_using_alias'],
+    'JsonAggConstructor': ['agg_filter', 'agg_order'], 'JsonArrayAgg': ['absent_on_null'], 'JsonArrayConstructor': ['absent_on_null'],
+    'JsonArrayQueryConstructor': ['absent_on_null'], 'JsonConstructorExpr': ['absent_on_null'],
+    'JsonExpr': ['column_name', 'formatted_expr', 'omit_quotes', 'on_empty', 'on_error', 'passing_names', 'passing_values',
+                 'path_spec', 'use_io_coercion', 'use_json_coercion'],
+    'JsonFormat': ['format_type'], 'JsonFuncExpr': ['column_name', 'context_item', 'on_empty', 'on_error'],
+    'JsonIsPredicate': ['item_type', 'unique_keys'], 'JsonObjectAgg': ['absent_on_null'], 'JsonObjectConstructor': ['absent_on_null'],
+    'JsonOutput': ['type_name'], 'JsonParseExpr': ['unique_keys'], 'JsonTable': ['context_item', 'on_error'],
+    'JsonTableColumn': ['type_name', 'on_empty', 'on_error'], 'JsonTablePathSpec': ['name_location'],
+    'JsonValueExpr': ['raw_expr', 'formatted_expr'], 'ObjectWithArgs': ['args_unspecified'], 'PartitionBoundSpec': ['is_default'],
+    'Query': ['stmt_location', 'stmt_len'], 'RangeFunction': ['is_rowsfrom'], 'RangeTableFuncCol': ['for_ordinality', 'is_not_null'],
+    'RangeTblEntry': ['security_barrier', 'join_using_alias', 'values_lists', 'self_reference', 'security_quals'],
+    'RawStmt': ['stmt_location', 'stmt_len'], 'RenameStmt': ['missing_ok'], 'ReplicaIdentityStmt': ['identity_type'],
+    'RowExpr': ['row_typeid', 'row_format'], 'SortBy': ['sortby_dir', 'sortby_nulls'], 'SortGroupClause': ['nulls_first'],
+    'SubPlan': ['plan_id', 'plan_name', 'parallel_safe', 'startup_cost', 'per_call_cost'], 'TableFunc': ['ns_uris', 'ns_names'],
+    'TransactionStmt': ['savepoint_name'], 'TruncateStmt': ['restart_seqs'], 'TypeName': ['pct_type'],
+    'VacuumRelation': ['va_cols'], 'VacuumStmt': ['is_vacuumcmd'], 'VariableSetStmt': ['is_local'],
+    'WindowFuncRunCondition': ['wfunc_left'], 'XmlExpr': ['named_args', 'arg_names'],
+}
+# Fields whose name in the C structs is different altogether
+RENAMED_FIELDS = {('CreateForeignTableStmt', 'base_stmt'): 'base', ('RangeTblEntry', 'in_from_cl'): 'inFromCl'}
+# Node types whose name in the C structs differs from the protobuf definitions
+C_TYPES = {
+    'AExpr': 'A_Expr', 'AConst': 'A_Const', 'AStar': 'A_Star', 'AIndices': 'A_Indices', 'AIndirection': 'A_Indirection',
+    'AArrayExpr': 'A_ArrayExpr', 'CoerceViaIo': 'CoerceViaIO', 'SqlvalueFunction': 'SQLValueFunction',
+    'RtepermissionInfo': 'RTEPermissionInfo', 'CtesearchClause': 'CTESearchClause', 'CtecycleClause': 'CTECycleClause',
+    'PlassignStmt': 'PLAssignStmt', 'CreatePlangStmt': 'CreatePLangStmt', 'AlterTsconfigurationStmt': 'AlterTSConfigurationStmt',
+    'AlterTsdictionaryStmt': 'AlterTSDictionaryStmt',
+}
+# Fields libpg_query leaves out, besides locations
+IGNORED_FIELDS = {
+    ('PrepareStmt', 'name'), ('ExecuteStmt', 'name'), ('DeallocateStmt', 'name'), ('DeclareCursorStmt', 'portalname'),
+    ('FetchStmt', 'portalname'), ('ClosePortalStmt', 'portalname'), ('TransactionStmt', 'savepoint_name'), ('TransactionStmt', 'gid'),
+}
+# Node types whose fields libpg_query leaves out entirely
+IGNORED_TYPES = {'AConst', 'Alias', 'ParamRef', 'SetToDefault', 'String', 'Integer', 'Float', 'Boolean', 'BitString', 'List'}
+
+
+def c_field_name(t, f):
+    f = field_name(f)
+    if (t, f) in RENAMED_FIELDS:
+        return RENAMED_FIELDS[(t, f)]
+    if f in SNAKE_CASE_FIELDS.get(t, []):
+        return f
+    parts = f.split('_')
+    return parts[0] + ''.join(p[:1].upper() + p[1:] for p in parts[1:])
+
+
+def fingerprint_field(t, parent, attr, f, ty, cname):
+    name = field_name(f)
+    if attr.startswith('message'):
+        kind, mt = msg_type(ty)
+        if mt == 'Node':
+            if kind == 'vec':
+                return [f'fp.list_field("{parent}", "{cname}", &n.{f}, depth);']
+            return [f'fp.node_field("{parent}", "{cname}", n.{f}.as_deref(), depth);']
+        getter = 'as_deref' if 'Box<' in ty else 'as_ref'
+        return [f'fp.typed_field("{parent}", "{cname}", n.{f}.{getter}().map(NodeRef::{VARIANT_OF[mt]}), depth);']
+    # Special cases of libpg_query's fingerprinting
+    if t == 'ResTarget' and name == 'name':
+        return [
+            '// Output column names are aliases',
+            'if !field.is_some_and(|f| f.parent == "SelectStmt" && f.name == "targetList") {',
+            f'    fp.str_field("{cname}", &n.{f});',
+            '}',
+        ]
+    if t == 'AExpr' and name == 'kind':
+        return [
+            '// IN lists and = ANY(...) are fingerprinted like a single comparison',
+            'let kind = match AExprKind::try_from(n.kind) {',
+            '    Ok(AExprKind::AexprOpAny | AExprKind::AexprIn) => AExprKind::AexprOp.as_str_name(),',
+            '    kind => kind.map_or("", |k| k.as_str_name()),',
+            '};',
+            f'fp.enum_field("{cname}", kind);',
+        ]
+    if t == 'RangeVar' and name == 'relname':
+        return [f'fp.str_field("{cname}", &without_numbers(&n.{f}));']
+    m = re.match(r'enumeration = "(\w+)"', attr)
+    if m:
+        return [f'fp.enum_field("{cname}", protobuf::{m.group(1)}::try_from(n.{f}).map_or("", |e| e.as_str_name()));']
+    return [{
+        'bool': f'fp.bool_field("{cname}", n.{f});',
+        'i32': f'fp.int_field("{cname}", n.{f}.into());',
+        'i64': f'fp.int_field("{cname}", n.{f});',
+        'u32': f'fp.int_field("{cname}", n.{f}.into());',
+        'u64': f'fp.int_field("{cname}", n.{f} as i64);',
+        'f64': f'fp.float_field("{cname}", n.{f});',
+        '::prost::alloc::string::String': f'fp.str_field("{cname}", &n.{f});',
+        '::prost::alloc::vec::Vec<u64>': f'fp.ints_field("{cname}", &n.{f});',
+    }[ty]]
+
+
+def fingerprint():
+    unknown = [(t, f) for t, fs in SNAKE_CASE_FIELDS.items() for f in fs if f not in [x[1] for x in STRUCTS.get(t, [])]]
+    if unknown:
+        sys.exit(f'unknown fields in SNAKE_CASE_FIELDS: {unknown}')
+    out = ['// Adds the fields of `node` in the order libpg_query hashes them, which is by their name in the C structs.']
+    out += ['fn fields(fp: &mut Fingerprinter, node: NodeRef, field: Option<Field>, depth: usize) {', '    match node {']
+    for var, t in VARIANTS:
+        lines = []
+        if t not in IGNORED_TYPES:
+            for cname, attr, f, ty in sorted((c_field_name(t, f), attr, f, ty) for attr, f, ty in STRUCTS[t]):
+                name = field_name(f)
+                if (t, name) in IGNORED_FIELDS or name.endswith('location') or name == 'stmt_len':
+                    continue
+                lines += ['            ' + line for line in fingerprint_field(t, C_TYPES.get(var, var), attr, f, ty, cname)]
+        out += [f'        NodeRef::{var}(n) => {{'] + lines + ['        }'] if lines else [f'        NodeRef::{var}(_) => {{}}']
+    out += ['    }', '}', '']
+    out += ['// The name of the node type in the C structs, e.g. `A_Expr` for `AExpr`', "fn c_type_name(node: NodeRef) -> &'static str {"]
+    out += ['    match node.type_name() {'] + [f'        "{k}" => "{v}",' for k, v in C_TYPES.items()] + ['        name => name,', '    }', '}']
+    return out
+
+
+#
+# Writing the files
+#
+
+def replace_region(path, lines):
+    text = open(path).read()
+    m = re.search(r'^([ \t]*)// ' + re.escape(BEGIN) + r'\n.*?^[ \t]*// ' + re.escape(END) + r'\n', text, re.S | re.M)
+    if not m:
+        sys.exit(f'{path}: no generated region')
+    indent = m.group(1)
+    body = ''.join(f'{indent}{line}\n' if line else '\n' for line in lines)
+    return text[:m.start()] + f'{indent}// {BEGIN}\n{body}{indent}// {END}\n' + text[m.end():]
+
+
+def main():
+    files = {
+        'src/visitor.rs': '\n'.join(visitor()).rstrip() + '\n',
+        'src/node_path.rs': replace_region('src/node_path.rs', node_path()),
+        'src/node_ref.rs': replace_region('src/node_ref.rs', type_name('NodeRef')),
+        'src/node_mut.rs': replace_region('src/node_mut.rs', type_name('NodeMut')),
+        'src/node_fields.rs': replace_region('src/node_fields.rs', node_fields()),
+        'src/span.rs': replace_region('src/span.rs', location()),
+        'src/fingerprint.rs': replace_region('src/fingerprint.rs', fingerprint()),
+    }
+    for path, text in files.items():
+        with open(path, 'w') as f:
+            f.write(text)
+    subprocess.run(['rustfmt', '--edition', '2021', *files], check=True)
+
+
+if __name__ == '__main__':
+    os.chdir(ROOT)
+    main()
@@ -0,0 +1,978 @@
+use std::collections::{HashMap, HashSet};
+
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+use crate::protobuf::{self, node, AlterTableType, ObjectType, RoleSpecType};
+use crate::visitor::*;
+use crate::*;
+
+/// Replaces schema, table, column, alias, function, type, role, tablespace, collation, operator class and prepared
+/// statement names with pseudonyms, so that queries can be
+/// shared without revealing the database schema.
+///
+/// Names are replaced consistently for all queries passed to the same `Anonymizer`, so `users` is `t1` in every
+/// query. With [Anonymizer::with_key], pseudonyms are derived from a keyed hash instead, which keeps them stable
+/// across separate runs using the same key.
+///
+/// Keywords, built-in functions and types, system catalogs, columns qualified by a system catalog or its alias or of a
+/// `SELECT` that only reads from system catalogs, and literals are left untouched; combine with [normalize] to also remove literals. The output is deparsed from the
+/// modified parse tree, so its formatting doesn't follow the original query.
+///
+/// Queries, DML, and the common DDL statements for tables, views, indexes, schemas and triggers are supported.
+/// Other statements, e.g. `CREATE FUNCTION` with a function body or `GRANT`, may hold names in places the
+/// anonymizer doesn't know about, so they return [Error::Anonymize] rather than leaking them.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::Anonymizer;
+///
+/// let mut anonymizer = Anonymizer::new();
+/// let a = anonymizer.anonymize("SELECT u.email, count(*) FROM app.users u WHERE u.active GROUP BY 1").unwrap();
+/// assert_eq!(a, "SELECT t1.c1, count(*) FROM s1.t2 t1 WHERE t1.c2 GROUP BY 1");
+/// let b = anonymizer.anonymize("UPDATE app.users SET active = false WHERE email = $1").unwrap();
+/// assert_eq!(b, "UPDATE s1.t2 SET c2 = false WHERE c1 = $1");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Anonymizer {
+    key: Option<u64>,
+    pseudonyms: HashMap<(char, String), String>,
+    counts: HashMap<char, usize>,
+    // Aliases of system catalogs in the current query, whose columns are kept
+    system_aliases: HashSet<String>,
+    // For each enclosing `SELECT`, whether it only reads from system catalogs, so its unqualified columns are kept
+    system_scopes: Vec<bool>,
+}
+
+// Pseudonym prefixes, which also keep the kinds of names apart
+const SCHEMA: char = 's';
+// Tables, views, aliases, CTEs, indexes, constraints and triggers share a prefix, since a table name and an alias
+// both qualify columns
+const RELATION: char = 't';
+const COLUMN: char = 'c';
+const FUNCTION: char = 'f';
+// Named arguments of functions
+const PARAMETER: char = 'p';
+const TYPE: char = 'y';
+const ROLE: char = 'r';
+const WINDOW: char = 'w';
+const TABLESPACE: char = 'd';
+const COLLATION: char = 'l';
+const OPERATOR_CLASS: char = 'o';
+// Names of prepared statements
+const PREPARED: char = 'q';
+
+impl Anonymizer {
+    /// An anonymizer that numbers names in order of appearance, e.g. `t1`, `t2`, `c1`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An anonymizer that derives pseudonyms from a hash of each name keyed by `key`, e.g. `t5f0c2a9e81b4`.
+    ///
+    /// The hash is not cryptographic, so keep the key private: knowing it allows checking guessed names
+    /// against the pseudonyms.
+    pub fn with_key(key: u64) -> Self {
+        Self { key: Some(key), ..Self::default() }
+    }
+
+    /// Anonymizes the names in `query`, which may contain multiple statements.
+    pub fn anonymize(&mut self, query: &str) -> Result<String> {
+        let mut result = parse(query)?;
+        if let Some(description) = result.protobuf.iter().find_map(unsupported) {
+            return Err(Error::Anonymize(description));
+        }
+        self.system_aliases = result
+            .protobuf
+            .iter()
+            .filter_map(|node| match node {
+                NodeRef::RangeVar(r) if is_system_relation(r) => r.alias.as_ref().map(|a| a.aliasname.clone()),
+                _ => None,
+            })
+            .collect();
+        self.visit_parse_result_mut(&mut result.protobuf);
+        result.deparse()
+    }
+
+    fn pseudonym(&mut self, kind: char, name: &str) -> String {
+        if let Some(pseudonym) = self.pseudonyms.get(&(kind, name.to_string())) {
+            return pseudonym.clone();
+        }
+        let pseudonym = match self.key {
+            Some(key) => format!("{kind}{:012x}", xxh3_64_with_seed(format!("{kind}{name}").as_bytes(), key) >> 16),
+            None => {
+                let count = self.counts.entry(kind).or_default();
+                *count += 1;
+                format!("{kind}{count}")
+            }
+        };
+        self.pseudonyms.insert((kind, name.to_string()), pseudonym.clone());
+        pseudonym
+    }
+
+    fn rename(&mut self, kind: char, name: &mut String) {
+        if !name.is_empty() {
+            *name = self.pseudonym(kind, name);
+        }
+    }
+
+    fn rename_strings(&mut self, kind: char, nodes: &mut [protobuf::Node]) {
+        for node in nodes {
+            if let Some(node::Node::String(s)) = &mut node.node {
+                self.rename(kind, &mut s.sval);
+            }
+        }
+    }
+
+    // Renames a possibly schema-qualified name, e.g. `app.users`, unless it is in a system schema
+    fn rename_qualified(&mut self, kind: char, names: &mut [protobuf::Node]) {
+        if strings(names).first().is_some_and(|schema| is_system_schema(schema)) {
+            return;
+        }
+        if let Some((name, schemas)) = names.split_last_mut() {
+            self.rename_strings(SCHEMA, schemas);
+            self.rename_strings(kind, std::slice::from_mut(name));
+        }
+    }
+
+    // Renames a possibly schema-qualified name like `rename_qualified`, unless it is a single built-in name
+    fn rename_unless_builtin(&mut self, kind: char, names: &mut [protobuf::Node], builtin: fn(&str) -> bool) {
+        if !matches!(strings(names).as_slice(), [name] if builtin(name)) {
+            self.rename_qualified(kind, names);
+        }
+    }
+
+    fn rename_tablespace(&mut self, name: &mut String) {
+        // `pg_default` and `pg_global`
+        if !name.starts_with("pg_") {
+            self.rename(TABLESPACE, name);
+        }
+    }
+}
+
+/// Anonymizes the names in `query` with a new [Anonymizer], numbering them in order of appearance.
+///
+/// # Example
+///
+/// ```rust
+/// let query = "SELECT o.id, lower(c.name) FROM orders o JOIN customers c ON c.id = o.customer_id";
+/// assert_eq!(
+///     pg_query::anonymize(query).unwrap(),
+///     "SELECT t1.c1, lower(t2.c2) FROM t3 t1 JOIN t4 t2 ON t2.c1 = t1.c3"
+/// );
+/// ```
+pub fn anonymize(query: &str) -> Result<String> {
+    Anonymizer::new().anonymize(query)
+}
+
+impl VisitorMut for Anonymizer {
+    fn visit_range_var_mut(&mut self, node: &mut protobuf::RangeVar) {
+        // The alias of a system catalog is kept along with the columns it qualifies
+        if !is_system_relation(node) {
+            self.rename(SCHEMA, &mut node.catalogname);
+            self.rename(SCHEMA, &mut node.schemaname);
+            self.rename(RELATION, &mut node.relname);
+            walk_range_var_mut(self, node);
+        }
+    }
+
+    fn visit_alias_mut(&mut self, node: &mut protobuf::Alias) {
+        self.rename(RELATION, &mut node.aliasname);
+        self.rename_strings(COLUMN, &mut node.colnames);
+    }
+
+    fn visit_column_ref_mut(&mut self, node: &mut protobuf::ColumnRef) {
+        let star = matches!(node.fields.last().and_then(|f| f.node.as_ref()), Some(node::Node::AStar(_)));
+        let names = node.fields.len() - star as usize;
+        let columns = if star { 0 } else { 1 };
+        let qualifier = |idx: usize| match node.fields.get(idx).map(|f| &f.node) {
+            Some(Some(node::Node::String(s))) => s.sval.as_str(),
+            _ => "",
+        };
+        // Columns of system catalogs, e.g. `pg_class.relname`, are kept along with the catalog
+        let relation = names.checked_sub(columns + 1).map_or("", qualifier);
+        let schema = names.checked_sub(columns + 2).map_or("", qualifier);
+        if relation.starts_with("pg_") || is_system_schema(schema) || self.system_aliases.contains(relation) {
+            return;
+        }
+        // An unqualified column, e.g. `relname` in `SELECT relname FROM pg_class`, when there is nothing else to read from
+        if names == columns && self.system_scopes.last() == Some(&true) {
+            return;
+        }
+        // Qualifiers from the innermost outwards, e.g. the table, schema and database of `db.public.users.id`
+        let kinds = [COLUMN, RELATION, SCHEMA, SCHEMA].into_iter().skip(1 - columns);
+        for (field, kind) in node.fields[..names].iter_mut().rev().zip(kinds) {
+            if let Some(node::Node::String(s)) = &mut field.node {
+                // `EXCLUDED` refers to the row proposed for insertion in `ON CONFLICT DO UPDATE`
+                if kind != RELATION || s.sval != "excluded" {
+                    self.rename(kind, &mut s.sval);
+                }
+            }
+        }
+    }
+
+    fn visit_select_stmt_mut(&mut self, node: &mut protobuf::SelectStmt) {
+        self.system_scopes.push(!node.from_clause.is_empty() && node.from_clause.iter().all(reads_system_relations));
+        walk_select_stmt_mut(self, node);
+        self.system_scopes.pop();
+    }
+
+    fn visit_res_target_mut(&mut self, node: &mut protobuf::ResTarget) {
+        walk_res_target_mut(self, node);
+        self.rename(COLUMN, &mut node.name);
+        // Fields of composite columns, e.g. `SET point.x = 1`
+        self.rename_strings(COLUMN, &mut node.indirection);
+    }
+
+    fn visit_a_indirection_mut(&mut self, node: &mut protobuf::AIndirection) {
+        self.rename_strings(COLUMN, &mut node.indirection);
+        walk_a_indirection_mut(self, node);
+    }
+
+    fn visit_func_call_mut(&mut self, node: &mut protobuf::FuncCall) {
+        let builtin = match strings(&node.funcname).as_slice() {
+            [name] => is_builtin_function(name),
+            [schema, ..] => is_system_schema(schema),
+            _ => false,
+        };
+        if !builtin {
+            self.rename_qualified(FUNCTION, &mut node.funcname);
+            for arg in &mut node.args {
+                if let Some(node::Node::NamedArgExpr(arg)) = &mut arg.node {
+                    self.rename(PARAMETER, &mut arg.name);
+                }
+            }
+        }
+        walk_func_call_mut(self, node);
+    }
+
+    fn visit_object_with_args_mut(&mut self, node: &mut protobuf::ObjectWithArgs) {
+        self.rename_qualified(FUNCTION, &mut node.objname);
+        walk_object_with_args_mut(self, node);
+    }
+
+    fn visit_type_name_mut(&mut self, node: &mut protobuf::TypeName) {
+        if node.pct_type {
+            // `users.id%TYPE` refers to the type of a column
+            if let Some((column, relation)) = node.names.split_last_mut() {
+                self.rename_qualified(RELATION, relation);
+                self.rename_strings(COLUMN, std::slice::from_mut(column));
+            }
+        } else {
+            self.rename_unless_builtin(TYPE, &mut node.names, is_builtin_type);
+        }
+        walk_type_name_mut(self, node);
+    }
+
+    fn visit_role_spec_mut(&mut self, node: &mut protobuf::RoleSpec) {
+        if node.roletype == RoleSpecType::RolespecCstring as i32 {
+            self.rename(ROLE, &mut node.rolename);
+        }
+    }
+
+    fn visit_window_def_mut(&mut self, node: &mut protobuf::WindowDef) {
+        self.rename(WINDOW, &mut node.name);
+        self.rename(WINDOW, &mut node.refname);
+        walk_window_def_mut(self, node);
+    }
+
+    fn visit_common_table_expr_mut(&mut self, node: &mut protobuf::CommonTableExpr) {
+        self.rename(RELATION, &mut node.ctename);
+        self.rename_strings(COLUMN, &mut node.aliascolnames);
+        walk_common_table_expr_mut(self, node);
+    }
+
+    fn visit_join_expr_mut(&mut self, node: &mut protobuf::JoinExpr) {
+        self.rename_strings(COLUMN, &mut node.using_clause);
+        walk_join_expr_mut(self, node);
+    }
+
+    fn visit_range_table_func_col_mut(&mut self, node: &mut protobuf::RangeTableFuncCol) {
+        self.rename(COLUMN, &mut node.colname);
+        walk_range_table_func_col_mut(self, node);
+    }
+
+    fn visit_json_table_column_mut(&mut self, node: &mut protobuf::JsonTableColumn) {
+        self.rename(COLUMN, &mut node.name);
+        walk_json_table_column_mut(self, node);
+    }
+
+    fn visit_into_clause_mut(&mut self, node: &mut protobuf::IntoClause) {
+        self.rename_strings(COLUMN, &mut node.col_names);
+        self.rename_tablespace(&mut node.table_space_name);
+        walk_into_clause_mut(self, node);
+    }
+
+    fn visit_infer_clause_mut(&mut self, node: &mut protobuf::InferClause) {
+        self.rename(RELATION, &mut node.conname);
+        walk_infer_clause_mut(self, node);
+    }
+
+    fn visit_column_def_mut(&mut self, node: &mut protobuf::ColumnDef) {
+        self.rename(COLUMN, &mut node.colname);
+        walk_column_def_mut(self, node);
+    }
+
+    fn visit_index_elem_mut(&mut self, node: &mut protobuf::IndexElem) {
+        self.rename(COLUMN, &mut node.name);
+        self.rename_unless_builtin(COLLATION, &mut node.collation, is_builtin_collation);
+        self.rename_unless_builtin(OPERATOR_CLASS, &mut node.opclass, is_builtin_operator_class);
+        walk_index_elem_mut(self, node);
+    }
+
+    fn visit_index_stmt_mut(&mut self, node: &mut protobuf::IndexStmt) {
+        self.rename(RELATION, &mut node.idxname);
+        self.rename_tablespace(&mut node.table_space);
+        walk_index_stmt_mut(self, node);
+    }
+
+    fn visit_partition_elem_mut(&mut self, node: &mut protobuf::PartitionElem) {
+        self.rename(COLUMN, &mut node.name);
+        self.rename_unless_builtin(COLLATION, &mut node.collation, is_builtin_collation);
+        self.rename_unless_builtin(OPERATOR_CLASS, &mut node.opclass, is_builtin_operator_class);
+        walk_partition_elem_mut(self, node);
+    }
+
+    fn visit_collate_clause_mut(&mut self, node: &mut protobuf::CollateClause) {
+        self.rename_unless_builtin(COLLATION, &mut node.collname, is_builtin_collation);
+        walk_collate_clause_mut(self, node);
+    }
+
+    fn visit_create_stmt_mut(&mut self, node: &mut protobuf::CreateStmt) {
+        self.rename_tablespace(&mut node.tablespacename);
+        walk_create_stmt_mut(self, node);
+    }
+
+    fn visit_prepare_stmt_mut(&mut self, node: &mut protobuf::PrepareStmt) {
+        self.rename(PREPARED, &mut node.name);
+        walk_prepare_stmt_mut(self, node);
+    }
+
+    fn visit_execute_stmt_mut(&mut self, node: &mut protobuf::ExecuteStmt) {
+        self.rename(PREPARED, &mut node.name);
+        walk_execute_stmt_mut(self, node);
+    }
+
+    fn visit_deallocate_stmt_mut(&mut self, node: &mut protobuf::DeallocateStmt) {
+        self.rename(PREPARED, &mut node.name);
+    }
+
+    fn visit_view_stmt_mut(&mut self, node: &mut protobuf::ViewStmt) {
+        self.rename_strings(COLUMN, &mut node.aliases);
+        walk_view_stmt_mut(self, node);
+    }
+
+    fn visit_alter_table_cmd_mut(&mut self, node: &mut protobuf::AlterTableCmd) {
+        if let Some(kind) = alter_table_name_kind(node.subtype) {
+            self.rename(kind, &mut node.name);
+        }
+        walk_alter_table_cmd_mut(self, node);
+    }
+
+    fn visit_drop_stmt_mut(&mut self, node: &mut protobuf::DropStmt) {
+        let object_type = ObjectType::try_from(node.remove_type);
+        for object in &mut node.objects {
+            match (object_type, &mut object.node) {
+                (Ok(t), Some(node::Node::List(name))) if is_relation_type(t) => self.rename_qualified(RELATION, &mut name.items),
+                // `DROP TRIGGER name ON table`, as a list ending in the trigger name
+                (Ok(ObjectType::ObjectTrigger), Some(node::Node::List(name))) => {
+                    if let Some((trigger, relation)) = name.items.split_last_mut() {
+                        self.rename_qualified(RELATION, relation);
+                        self.rename_strings(RELATION, std::slice::from_mut(trigger));
+                    }
+                }
+                (Ok(ObjectType::ObjectSchema), Some(node::Node::String(name))) => self.rename(SCHEMA, &mut name.sval),
+                // Functions and types are `ObjectWithArgs` and `TypeName` nodes, which are renamed when visited
+                _ => {}
+            }
+        }
+        walk_drop_stmt_mut(self, node);
+    }
+
+    fn visit_rename_stmt_mut(&mut self, node: &mut protobuf::RenameStmt) {
+        match ObjectType::try_from(node.rename_type) {
+            Ok(t) if is_relation_type(t) => self.rename(RELATION, &mut node.newname),
+            Ok(ObjectType::ObjectColumn) => {
+                self.rename(COLUMN, &mut node.subname);
+                self.rename(COLUMN, &mut node.newname);
+            }
+            Ok(ObjectType::ObjectTabconstraint | ObjectType::ObjectDomconstraint | ObjectType::ObjectTrigger) => {
+                self.rename(RELATION, &mut node.subname);
+                self.rename(RELATION, &mut node.newname);
+            }
+            Ok(ObjectType::ObjectSchema) => {
+                self.rename(SCHEMA, &mut node.subname);
+                self.rename(SCHEMA, &mut node.newname);
+            }
+            Ok(ObjectType::ObjectFunction | ObjectType::ObjectProcedure | ObjectType::ObjectRoutine | ObjectType::ObjectAggregate) => {
+                self.rename(FUNCTION, &mut node.newname);
+            }
+            Ok(ObjectType::ObjectType | ObjectType::ObjectDomain) => self.rename(TYPE, &mut node.newname),
+            _ => {}
+        }
+        // Types and domains, including those of domain constraints, are named by a list
+        if let Some(node::Node::List(name)) = node.object.as_mut().and_then(|o| o.node.as_mut()) {
+            self.rename_qualified(TYPE, &mut name.items);
+        }
+        walk_rename_stmt_mut(self, node);
+    }
+
+    fn visit_create_schema_stmt_mut(&mut self, node: &mut protobuf::CreateSchemaStmt) {
+        self.rename(SCHEMA, &mut node.schemaname);
+        walk_create_schema_stmt_mut(self, node);
+    }
+
+    fn visit_create_trig_stmt_mut(&mut self, node: &mut protobuf::CreateTrigStmt) {
+        self.rename(RELATION, &mut node.trigname);
+        self.rename_qualified(FUNCTION, &mut node.funcname);
+        self.rename_strings(COLUMN, &mut node.columns);
+        walk_create_trig_stmt_mut(self, node);
+    }
+
+    fn visit_trigger_transition_mut(&mut self, node: &mut protobuf::TriggerTransition) {
+        self.rename(RELATION, &mut node.name);
+    }
+
+    fn visit_constraint_mut(&mut self, node: &mut protobuf::Constraint) {
+        self.rename(RELATION, &mut node.conname);
+        self.rename(RELATION, &mut node.indexname);
+        self.rename_tablespace(&mut node.indexspace);
+        self.rename_strings(COLUMN, &mut node.keys);
+        self.rename_strings(COLUMN, &mut node.including);
+        self.rename_strings(COLUMN, &mut node.fk_attrs);
+        self.rename_strings(COLUMN, &mut node.pk_attrs);
+        walk_constraint_mut(self, node);
+    }
+}
+
+// Statements whose names are all replaced; returns a description of any other statement, which might hold names
+// in fields the anonymizer leaves untouched
+fn unsupported(node: NodeRef) -> Option<String> {
+    let object_type = |t: i32| ObjectType::try_from(t).map_or("", |t| t.as_str_name());
+    match node {
+        NodeRef::DropStmt(n) => {
+            let supported = ObjectType::try_from(n.remove_type).is_ok_and(|t| {
+                is_relation_type(t)
+                    || matches!(
+                        t,
+                        ObjectType::ObjectTrigger
+                            | ObjectType::ObjectSchema
+                            | ObjectType::ObjectFunction
+                            | ObjectType::ObjectProcedure
+                            | ObjectType::ObjectRoutine
+                            | ObjectType::ObjectAggregate
+                            | ObjectType::ObjectType
+                            | ObjectType::ObjectDomain
+                    )
+            });
+            (!supported).then(|| format!("DropStmt of {}", object_type(n.remove_type)))
+        }
+        NodeRef::RenameStmt(n) => {
+            let supported = ObjectType::try_from(n.rename_type).is_ok_and(|t| {
+                is_relation_type(t)
+                    || matches!(
+                        t,
+                        ObjectType::ObjectColumn
+                            | ObjectType::ObjectTabconstraint
+                            | ObjectType::ObjectDomconstraint
+                            | ObjectType::ObjectTrigger
+                            | ObjectType::ObjectSchema
+                            | ObjectType::ObjectFunction
+                            | ObjectType::ObjectProcedure
+                            | ObjectType::ObjectRoutine
+                            | ObjectType::ObjectAggregate
+                            | ObjectType::ObjectType
+                            | ObjectType::ObjectDomain
+                    )
+            });
+            (!supported).then(|| format!("RenameStmt of {}", object_type(n.rename_type)))
+        }
+        NodeRef::AlterTableCmd(n) if !n.name.is_empty() && alter_table_name_kind(n.subtype).is_none() => {
+            Some(format!("AlterTableCmd {}", AlterTableType::try_from(n.subtype).map_or("", |t| t.as_str_name())))
+        }
+        node if node.type_name().ends_with("Stmt") && !SUPPORTED_STATEMENTS.contains(&node.type_name()) => Some(node.type_name().to_string()),
+        _ => None,
+    }
+}
+
+const SUPPORTED_STATEMENTS: &[&str] = &[
+    "SelectStmt",
+    "InsertStmt",
+    "UpdateStmt",
+    "DeleteStmt",
+    "MergeStmt",
+    "CallStmt",
+    "ExplainStmt",
+    "PrepareStmt",
+    "ExecuteStmt",
+    "DeallocateStmt",
+    "TransactionStmt",
+    "LockStmt",
+    "TruncateStmt",
+    "CreateStmt",
+    "CreateTableAsStmt",
+    "ViewStmt",
+    "IndexStmt",
+    "AlterTableStmt",
+    "DropStmt",
+    "RenameStmt",
+    "CreateSchemaStmt",
+    "CreateTrigStmt",
+];
+
+fn is_relation_type(object_type: ObjectType) -> bool {
+    matches!(
+        object_type,
+        ObjectType::ObjectTable
+            | ObjectType::ObjectView
+            | ObjectType::ObjectMatview
+            | ObjectType::ObjectIndex
+            | ObjectType::ObjectSequence
+            | ObjectType::ObjectForeignTable
+    )
+}
+
+// The kind of name `AlterTableCmd.name` holds for `subtype`, or `None` if it isn't known to be a column, constraint,
+// index or trigger
+fn alter_table_name_kind(subtype: i32) -> Option<char> {
+    match AlterTableType::try_from(subtype).ok()? {
+        AlterTableType::AtColumnDefault
+        | AlterTableType::AtCookedColumnDefault
+        | AlterTableType::AtDropNotNull
+        | AlterTableType::AtSetNotNull
+        | AlterTableType::AtSetExpression
+        | AlterTableType::AtDropExpression
+        | AlterTableType::AtCheckNotNull
+        | AlterTableType::AtSetStatistics
+        | AlterTableType::AtSetOptions
+        | AlterTableType::AtResetOptions
+        | AlterTableType::AtSetStorage
+        | AlterTableType::AtSetCompression
+        | AlterTableType::AtDropColumn
+        | AlterTableType::AtAlterColumnType
+        | AlterTableType::AtAlterColumnGenericOptions
+        | AlterTableType::AtAddIdentity
+        | AlterTableType::AtSetIdentity
+        | AlterTableType::AtDropIdentity => Some(COLUMN),
+        AlterTableType::AtAlterConstraint
+        | AlterTableType::AtValidateConstraint
+        | AlterTableType::AtDropConstraint
+        | AlterTableType::AtClusterOn
+        | AlterTableType::AtEnableTrig
+        | AlterTableType::AtEnableAlwaysTrig
+        | AlterTableType::AtEnableReplicaTrig
+        | AlterTableType::AtDisableTrig => Some(RELATION),
+        _ => None,
+    }
+}
+
+fn strings(nodes: &[protobuf::Node]) -> Vec<&str> {
+    nodes.iter().filter_map(|n| if let Some(node::Node::String(s)) = &n.node { Some(s.sval.as_str()) } else { None }).collect()
+}
+
+fn is_system_schema(schema: &str) -> bool {
+    schema == "pg_catalog" || schema == "information_schema"
+}
+
+fn is_system_relation(relation: &protobuf::RangeVar) -> bool {
+    is_system_schema(&relation.schemaname) || (relation.schemaname.is_empty() && relation.relname.starts_with("pg_"))
+}
+
+// Whether a `FROM` item only reads from system catalogs, including through joins
+fn reads_system_relations(item: &protobuf::Node) -> bool {
+    match &item.node {
+        Some(node::Node::RangeVar(r)) => is_system_relation(r),
+        Some(node::Node::JoinExpr(j)) => [&j.larg, &j.rarg].into_iter().all(|arg| arg.as_deref().is_some_and(reads_system_relations)),
+        _ => false,
+    }
+}
+
+// Types without a schema that are built in; the parser qualifies types written with SQL syntax, such as `int` or
+// `character varying`, with `pg_catalog`
+fn is_builtin_type(name: &str) -> bool {
+    name.starts_with("pg_") || BUILTIN_TYPES.binary_search(&name).is_ok()
+}
+
+// Collations that exist in every database; ICU collations, e.g. `en-US-x-icu`, are created for each locale ICU knows
+fn is_builtin_collation(name: &str) -> bool {
+    matches!(name, "C" | "POSIX" | "default" | "ucs_basic" | "unicode") || name.starts_with("pg_") || name.ends_with("-x-icu")
+}
+
+// Operator classes are named after their type and kind, e.g. `int4_ops` or `text_pattern_ops`, apart from a few
+fn is_builtin_operator_class(name: &str) -> bool {
+    ["_pattern_ops", "_minmax_multi_ops", "_minmax_ops", "_bloom_ops", "_inclusion_ops", "_ops"]
+        .into_iter()
+        .filter_map(|suffix| name.strip_suffix(suffix))
+        .any(|prefix| is_builtin_type(prefix) || OPERATOR_CLASS_PREFIXES.contains(&prefix))
+}
+
+const OPERATOR_CLASS_PREFIXES: &[&str] =
+    &["array", "enum", "jsonb_path", "kd_point", "multirange", "network", "poly", "quad_point", "range", "record_image"];
+
+// Functions are only known to be built in by name, so this covers keywords such as `coalesce` or `left`, `pg_*`
+// functions and the most commonly used functions and aggregates. Unreserved keywords, e.g. `search` or `label`, are
+// also valid names for user functions, so only the keyword kinds that can't name one without quoting are built in
+fn is_builtin_function(name: &str) -> bool {
+    if name.starts_with("pg_") || BUILTIN_FUNCTIONS.binary_search(&name).is_ok() {
+        return true;
+    }
+    let keyword_kinds = [protobuf::KeywordKind::ColNameKeyword as i32, protobuf::KeywordKind::TypeFuncNameKeyword as i32];
+    scan(name).is_ok_and(|result| matches!(result.tokens.as_slice(), [token] if keyword_kinds.contains(&token.keyword_kind)))
+}
+
+const BUILTIN_TYPES: &[&str] = &[
+    "aclitem",
+    "any",
+    "anyarray",
+    "anycompatible",
+    "anycompatiblearray",
+    "anycompatiblemultirange",
+    "anycompatiblenonarray",
+    "anycompatiblerange",
+    "anyelement",
+    "anyenum",
+    "anymultirange",
+    "anynonarray",
+    "anyrange",
+    "bigint",
+    "bigserial",
+    "bit",
+    "bool",
+    "boolean",
+    "box",
+    "bpchar",
+    "bytea",
+    "char",
+    "cid",
+    "cidr",
+    "circle",
+    "cstring",
+    "date",
+    "datemultirange",
+    "daterange",
+    "decimal",
+    "event_trigger",
+    "float4",
+    "float8",
+    "inet",
+    "int2",
+    "int2vector",
+    "int4",
+    "int4multirange",
+    "int4range",
+    "int8",
+    "int8multirange",
+    "int8range",
+    "integer",
+    "internal",
+    "interval",
+    "json",
+    "jsonb",
+    "jsonpath",
+    "line",
+    "lseg",
+    "macaddr",
+    "macaddr8",
+    "money",
+    "name",
+    "numeric",
+    "nummultirange",
+    "numrange",
+    "oid",
+    "oidvector",
+    "path",
+    "point",
+    "polygon",
+    "real",
+    "record",
+    "refcursor",
+    "regclass",
+    "regcollation",
+    "regconfig",
+    "regdictionary",
+    "regnamespace",
+    "regoper",
+    "regoperator",
+    "regproc",
+    "regprocedure",
+    "regrole",
+    "regtype",
+    "serial",
+    "serial2",
+    "serial4",
+    "serial8",
+    "smallint",
+    "smallserial",
+    "text",
+    "tid",
+    "time",
+    "timestamp",
+    "timestamptz",
+    "timetz",
+    "trigger",
+    "tsmultirange",
+    "tsquery",
+    "tsrange",
+    "tstzmultirange",
+    "tstzrange",
+    "tsvector",
+    "txid_snapshot",
+    "unknown",
+    "uuid",
+    "varbit",
+    "varchar",
+    "void",
+    "xid",
+    "xid8",
+    "xml",
+];
+
+const BUILTIN_FUNCTIONS: &[&str] = &[
+    "abs",
+    "acos",
+    "age",
+    "array_agg",
+    "array_append",
+    "array_cat",
+    "array_dims",
+    "array_fill",
+    "array_length",
+    "array_lower",
+    "array_ndims",
+    "array_position",
+    "array_positions",
+    "array_prepend",
+    "array_remove",
+    "array_replace",
+    "array_to_json",
+    "array_to_string",
+    "array_upper",
+    "ascii",
+    "asin",
+    "atan",
+    "atan2",
+    "avg",
+    "bit_and",
+    "bit_length",
+    "bit_or",
+    "bit_xor",
+    "bool_and",
+    "bool_or",
+    "btrim",
+    "cardinality",
+    "cbrt",
+    "ceil",
+    "ceiling",
+    "char_length",
+    "character_length",
+    "chr",
+    "clock_timestamp",
+    "col_description",
+    "concat",
+    "concat_ws",
+    "convert_from",
+    "convert_to",
+    "corr",
+    "cos",
+    "count",
+    "covar_pop",
+    "covar_samp",
+    "cume_dist",
+    "current_setting",
+    "currval",
+    "date_bin",
+    "date_part",
+    "date_trunc",
+    "decode",
+    "degrees",
+    "dense_rank",
+    "div",
+    "encode",
+    "every",
+    "exp",
+    "first_value",
+    "floor",
+    "format",
+    "format_type",
+    "gcd",
+    "gen_random_uuid",
+    "generate_series",
+    "generate_subscripts",
+    "has_table_privilege",
+    "host",
+    "initcap",
+    "isempty",
+    "isfinite",
+    "json_agg",
+    "json_array_elements",
+    "json_array_elements_text",
+    "json_array_length",
+    "json_build_array",
+    "json_build_object",
+    "json_each",
+    "json_each_text",
+    "json_extract_path",
+    "json_extract_path_text",
+    "json_object_agg",
+    "json_object_keys",
+    "json_populate_record",
+    "json_strip_nulls",
+    "json_to_record",
+    "json_to_recordset",
+    "json_typeof",
+    "jsonb_agg",
+    "jsonb_array_elements",
+    "jsonb_array_elements_text",
+    "jsonb_array_length",
+    "jsonb_build_array",
+    "jsonb_build_object",
+    "jsonb_each",
+    "jsonb_each_text",
+    "jsonb_extract_path",
+    "jsonb_extract_path_text",
+    "jsonb_insert",
+    "jsonb_object_agg",
+    "jsonb_object_keys",
+    "jsonb_path_exists",
+    "jsonb_path_match",
+    "jsonb_path_query",
+    "jsonb_path_query_array",
+    "jsonb_path_query_first",
+    "jsonb_populate_record",
+    "jsonb_pretty",
+    "jsonb_set",
+    "jsonb_strip_nulls",
+    "jsonb_to_record",
+    "jsonb_to_recordset",
+    "jsonb_typeof",
+    "justify_days",
+    "justify_hours",
+    "justify_interval",
+    "lag",
+    "last_value",
+    "lastval",
+    "lcm",
+    "lead",
+    "length",
+    "ln",
+    "log",
+    "log10",
+    "lower",
+    "lower_inc",
+    "lpad",
+    "ltrim",
+    "make_date",
+    "make_interval",
+    "make_time",
+    "make_timestamp",
+    "make_timestamptz",
+    "masklen",
+    "max",
+    "md5",
+    "min",
+    "mod",
+    "mode",
+    "network",
+    "nextval",
+    "now",
+    "nth_value",
+    "ntile",
+    "num_nonnulls",
+    "num_nulls",
+    "obj_description",
+    "octet_length",
+    "percent_rank",
+    "percentile_cont",
+    "percentile_disc",
+    "phraseto_tsquery",
+    "pi",
+    "plainto_tsquery",
+    "power",
+    "quote_ident",
+    "quote_literal",
+    "quote_nullable",
+    "radians",
+    "random",
+    "range_merge",
+    "rank",
+    "regexp_count",
+    "regexp_instr",
+    "regexp_like",
+    "regexp_match",
+    "regexp_matches",
+    "regexp_replace",
+    "regexp_split_to_array",
+    "regexp_split_to_table",
+    "regexp_substr",
+    "regr_avgx",
+    "regr_avgy",
+    "regr_count",
+    "regr_intercept",
+    "regr_r2",
+    "regr_slope",
+    "regr_sxx",
+    "regr_sxy",
+    "regr_syy",
+    "repeat",
+    "replace",
+    "reverse",
+    "round",
+    "row_number",
+    "row_to_json",
+    "rpad",
+    "rtrim",
+    "set_config",
+    "setseed",
+    "setval",
+    "setweight",
+    "sha224",
+    "sha256",
+    "sha384",
+    "sha512",
+    "sign",
+    "sin",
+    "split_part",
+    "sqrt",
+    "starts_with",
+    "statement_timestamp",
+    "stddev",
+    "stddev_pop",
+    "stddev_samp",
+    "string_agg",
+    "string_to_array",
+    "strpos",
+    "substr",
+    "sum",
+    "tan",
+    "timeofday",
+    "to_char",
+    "to_date",
+    "to_hex",
+    "to_json",
+    "to_jsonb",
+    "to_number",
+    "to_regclass",
+    "to_timestamp",
+    "to_tsquery",
+    "to_tsvector",
+    "transaction_timestamp",
+    "translate",
+    "trunc",
+    "ts_headline",
+    "ts_rank",
+    "ts_rank_cd",
+    "tsvector_to_array",
+    "txid_current",
+    "unistr",
+    "unnest",
+    "upper",
+    "upper_inc",
+    "uuid_generate_v4",
+    "var_pop",
+    "var_samp",
+    "variance",
+    "version",
+    "websearch_to_tsquery",
+    "width_bucket",
+    "xmlagg",
+];
@@ -0,0 +1,835 @@
+//! Builders for constructing parse trees without writing SQL.
+//!
+//! The builders fill in every field of the underlying protobuf messages, including enum fields
+//! that must not be left at their zero value, so that the resulting trees can be deparsed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pg_query::builder::*;
+//!
+//! let query = select()
+//!     .columns(["a", "b"])
+//!     .column_as(count_star(), "total")
+//!     .from(range_var("public", "t").alias("t1").left_join(table("u"), col("u.t_id").eq(col("t1.id"))))
+//!     .where_(col("x").eq(param(1)).and(col("y").is_not_null()))
+//!     .group_by(col("a"))
+//!     .group_by(col("b"))
+//!     .order_by_desc(col("total"))
+//!     .limit(10);
+//! assert_eq!(
+//!     query.deparse().unwrap(),
+//!     "SELECT a, b, count(*) AS total FROM public.t t1 LEFT JOIN u ON u.t_id = t1.id \
+//!      WHERE x = $1 AND y IS NOT NULL GROUP BY a, b ORDER BY total DESC LIMIT 10"
+//! );
+//! ```
+
+use std::ops::Not;
+
+use crate::protobuf::{self, a_const::Val};
+use crate::*;
+
+/// An expression, such as a column reference, a constant or an operator.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expr(NodeEnum);
+
+/// A table in the `FROM` clause or the target of an `INSERT`, `UPDATE` or `DELETE`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table(protobuf::RangeVar);
+
+/// An item of the `FROM` clause: a table, a subquery or a join.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FromItem(NodeEnum);
+
+/// Builds a `SELECT` statement, see [select].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectBuilder(protobuf::SelectStmt);
+
+/// Builds an `INSERT` statement, see [insert_into].
+#[derive(Clone, Debug, PartialEq)]
+pub struct InsertBuilder {
+    stmt: protobuf::InsertStmt,
+    values: Vec<Node>,
+}
+
+/// Builds an `UPDATE` statement, see [update].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateBuilder(protobuf::UpdateStmt);
+
+/// Builds a `DELETE` statement, see [delete_from].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeleteBuilder(protobuf::DeleteStmt);
+
+/// A column reference. Qualified names are split on dots, e.g. `t.a` or `t.*`.
+pub fn col(name: &str) -> Expr {
+    let fields = name
+        .split('.')
+        .map(|field| match field {
+            "*" => NodeEnum::AStar(protobuf::AStar {}),
+            _ => string_node(field),
+        })
+        .map(wrap)
+        .collect();
+    Expr(NodeEnum::ColumnRef(protobuf::ColumnRef { fields, location: -1 }))
+}
+
+/// `*`, for use as a column.
+pub fn star() -> Expr {
+    col("*")
+}
+
+/// A parameter reference, e.g. `$1`.
+pub fn param(number: i32) -> Expr {
+    Expr(NodeEnum::ParamRef(protobuf::ParamRef { number, location: -1 }))
+}
+
+/// An integer constant.
+pub fn integer(value: i64) -> Expr {
+    match i32::try_from(value) {
+        Ok(ival) => constant(Val::Ival(protobuf::Integer { ival })),
+        // Like in Postgres, integers that don't fit into 32 bits are kept as text
+        Err(_) => constant(Val::Fval(protobuf::Float { fval: value.to_string() })),
+    }
+}
+
+/// A numeric constant. `NaN` and infinities have no literal syntax, so they become casts like `'NaN'::float8`.
+pub fn float(value: f64) -> Expr {
+    if value.is_nan() {
+        string("NaN").cast("float8")
+    } else if value.is_infinite() {
+        string(if value > 0.0 { "Infinity" } else { "-Infinity" }).cast("float8")
+    } else {
+        constant(Val::Fval(protobuf::Float { fval: value.to_string() }))
+    }
+}
+
+/// A string constant, quoted as needed when deparsing.
+pub fn string(value: &str) -> Expr {
+    constant(Val::Sval(protobuf::String { sval: value.to_string() }))
+}
+
+/// `true` or `false`.
+pub fn boolean(value: bool) -> Expr {
+    constant(Val::Boolval(protobuf::Boolean { boolval: value }))
+}
+
+/// `NULL`.
+pub fn null() -> Expr {
+    Expr(NodeEnum::AConst(protobuf::AConst { isnull: true, val: None, location: -1 }))
+}
+
+/// A function call. Qualified names are split on dots, e.g. `pg_catalog.lower`.
+pub fn func(name: &str, args: impl IntoIterator<Item = impl Into<Expr>>) -> Expr {
+    Expr(NodeEnum::FuncCall(Box::new(protobuf::FuncCall {
+        funcname: name.split('.').map(|part| wrap(string_node(part))).collect(),
+        args: args.into_iter().map(|arg| arg.into().into()).collect(),
+        funcformat: protobuf::CoercionForm::CoerceExplicitCall as i32,
+        location: -1,
+        ..Default::default()
+    })))
+}
+
+/// `count(*)`.
+pub fn count_star() -> Expr {
+    Expr(NodeEnum::FuncCall(Box::new(protobuf::FuncCall {
+        funcname: vec![wrap(string_node("count"))],
+        agg_star: true,
+        funcformat: protobuf::CoercionForm::CoerceExplicitCall as i32,
+        location: -1,
+        ..Default::default()
+    })))
+}
+
+/// `EXISTS (subquery)`.
+pub fn exists(subquery: SelectBuilder) -> Expr {
+    sub_link(protobuf::SubLinkType::ExistsSublink, None, subquery)
+}
+
+/// A scalar subquery, e.g. `(SELECT max(a) FROM t)`.
+pub fn subquery(subquery: SelectBuilder) -> Expr {
+    sub_link(protobuf::SubLinkType::ExprSublink, None, subquery)
+}
+
+/// A table, optionally in a schema.
+pub fn range_var(schema: &str, name: &str) -> Table {
+    Table(protobuf::RangeVar {
+        schemaname: schema.to_string(),
+        relname: name.to_string(),
+        inh: true,
+        relpersistence: "p".to_string(),
+        location: -1,
+        ..Default::default()
+    })
+}
+
+/// A table without a schema.
+pub fn table(name: &str) -> Table {
+    range_var("", name)
+}
+
+/// A subquery in the `FROM` clause, e.g. `(SELECT 1) AS s`. Postgres requires an alias.
+pub fn from_subquery(subquery: SelectBuilder, alias: &str) -> FromItem {
+    FromItem(NodeEnum::RangeSubselect(Box::new(protobuf::RangeSubselect {
+        lateral: false,
+        subquery: Some(Box::new(subquery.into())),
+        alias: Some(make_alias(alias)),
+    })))
+}
+
+/// Starts a `SELECT` statement.
+pub fn select() -> SelectBuilder {
+    SelectBuilder(protobuf::SelectStmt {
+        limit_option: protobuf::LimitOption::Default as i32,
+        op: protobuf::SetOperation::SetopNone as i32,
+        ..Default::default()
+    })
+}
+
+/// Starts an `INSERT` statement.
+pub fn insert_into(table: impl Into<Table>) -> InsertBuilder {
+    let stmt =
+        protobuf::InsertStmt { relation: Some(table.into().0), r#override: protobuf::OverridingKind::OverridingNotSet as i32, ..Default::default() };
+    InsertBuilder { stmt, values: Vec::new() }
+}
+
+/// Starts an `UPDATE` statement.
+pub fn update(table: impl Into<Table>) -> UpdateBuilder {
+    UpdateBuilder(protobuf::UpdateStmt { relation: Some(table.into().0), ..Default::default() })
+}
+
+/// Starts a `DELETE` statement.
+pub fn delete_from(table: impl Into<Table>) -> DeleteBuilder {
+    DeleteBuilder(protobuf::DeleteStmt { relation: Some(table.into().0), ..Default::default() })
+}
+
+/// Wraps statements into a [protobuf::ParseResult], e.g. to deparse several statements at once.
+pub fn parse_result(statements: impl IntoIterator<Item = impl Into<NodeEnum>>) -> protobuf::ParseResult {
+    protobuf::ParseResult {
+        version: crate::bindings::PG_VERSION_NUM as i32,
+        stmts: statements
+            .into_iter()
+            .map(|stmt| protobuf::RawStmt { stmt: Some(Box::new(wrap(stmt.into()))), stmt_location: -1, stmt_len: 0 })
+            .collect(),
+    }
+}
+
+impl Expr {
+    /// A binary operator, e.g. `||` or `@>`.
+    pub fn op(self, operator: &str, right: impl Into<Expr>) -> Expr {
+        a_expr(protobuf::AExprKind::AexprOp, operator, self, right.into().0)
+    }
+
+    /// `self = right`.
+    pub fn eq(self, right: impl Into<Expr>) -> Expr {
+        self.op("=", right)
+    }
+
+    /// `self <> right`.
+    pub fn ne(self, right: impl Into<Expr>) -> Expr {
+        self.op("<>", right)
+    }
+
+    /// `self < right`.
+    pub fn lt(self, right: impl Into<Expr>) -> Expr {
+        self.op("<", right)
+    }
+
+    /// `self <= right`.
+    pub fn le(self, right: impl Into<Expr>) -> Expr {
+        self.op("<=", right)
+    }
+
+    /// `self > right`.
+    pub fn gt(self, right: impl Into<Expr>) -> Expr {
+        self.op(">", right)
+    }
+
+    /// `self >= right`.
+    pub fn ge(self, right: impl Into<Expr>) -> Expr {
+        self.op(">=", right)
+    }
+
+    /// `self AND right`. Chained calls produce a single `AND` with all operands, as Postgres does.
+    pub fn and(self, right: impl Into<Expr>) -> Expr {
+        self.bool_expr(protobuf::BoolExprType::AndExpr, right.into())
+    }
+
+    /// `self OR right`. Chained calls produce a single `OR` with all operands, as Postgres does.
+    pub fn or(self, right: impl Into<Expr>) -> Expr {
+        self.bool_expr(protobuf::BoolExprType::OrExpr, right.into())
+    }
+
+    /// `self IS NULL`.
+    pub fn is_null(self) -> Expr {
+        self.null_test(protobuf::NullTestType::IsNull)
+    }
+
+    /// `self IS NOT NULL`.
+    pub fn is_not_null(self) -> Expr {
+        self.null_test(protobuf::NullTestType::IsNotNull)
+    }
+
+    /// `self IN (values...)`.
+    pub fn in_list(self, values: impl IntoIterator<Item = impl Into<Expr>>) -> Expr {
+        let items = values.into_iter().map(|value| value.into().into()).collect();
+        a_expr(protobuf::AExprKind::AexprIn, "=", self, NodeEnum::List(protobuf::List { items }))
+    }
+
+    /// `self IN (subquery)`.
+    pub fn in_subquery(self, subquery: SelectBuilder) -> Expr {
+        sub_link(protobuf::SubLinkType::AnySublink, Some(self), subquery)
+    }
+
+    /// `self LIKE pattern`.
+    pub fn like(self, pattern: impl Into<Expr>) -> Expr {
+        a_expr(protobuf::AExprKind::AexprLike, "~~", self, pattern.into().0)
+    }
+
+    /// `self::type_name`. SQL spellings of builtin types, e.g. `integer` or `double precision`, are
+    /// mapped to their `pg_catalog` names like the parser does, and a trailing `[]` makes an array type.
+    /// Integer modifiers such as the length in `varchar(10)` are kept, and `char` and `bit` without one
+    /// get the length 1 the parser gives them.
+    pub fn cast(self, type_name: &str) -> Expr {
+        Expr(NodeEnum::TypeCast(Box::new(protobuf::TypeCast {
+            arg: Some(Box::new(self.into())),
+            type_name: Some(make_type_name(type_name)),
+            location: -1,
+        })))
+    }
+
+    /// The underlying node.
+    pub fn into_node(self) -> NodeEnum {
+        self.0
+    }
+
+    /// Converts the expression into SQL, see [NodeEnum::deparse].
+    pub fn deparse(&self) -> Result<String> {
+        self.0.deparse()
+    }
+
+    fn bool_expr(self, boolop: protobuf::BoolExprType, right: Expr) -> Expr {
+        let mut args = match self.0 {
+            NodeEnum::BoolExpr(b) if b.boolop == boolop as i32 => b.args,
+            node => vec![wrap(node)],
+        };
+        args.push(right.into());
+        Expr(NodeEnum::BoolExpr(Box::new(protobuf::BoolExpr { xpr: None, boolop: boolop as i32, args, location: -1 })))
+    }
+
+    fn null_test(self, nulltesttype: protobuf::NullTestType) -> Expr {
+        Expr(NodeEnum::NullTest(Box::new(protobuf::NullTest {
+            arg: Some(Box::new(self.into())),
+            nulltesttype: nulltesttype as i32,
+            location: -1,
+            ..Default::default()
+        })))
+    }
+}
+
+impl Not for Expr {
+    type Output = Expr;
+
+    /// `NOT self`.
+    fn not(self) -> Expr {
+        Expr(NodeEnum::BoolExpr(Box::new(protobuf::BoolExpr {
+            xpr: None,
+            boolop: protobuf::BoolExprType::NotExpr as i32,
+            args: vec![self.into()],
+            location: -1,
+        })))
+    }
+}
+
+impl From<i32> for Expr {
+    fn from(value: i32) -> Self {
+        integer(value.into())
+    }
+}
+
+impl From<i64> for Expr {
+    fn from(value: i64) -> Self {
+        integer(value)
+    }
+}
+
+impl From<bool> for Expr {
+    fn from(value: bool) -> Self {
+        boolean(value)
+    }
+}
+
+impl From<NodeEnum> for Expr {
+    fn from(node: NodeEnum) -> Self {
+        Expr(node)
+    }
+}
+
+impl From<SelectBuilder> for Expr {
+    fn from(select: SelectBuilder) -> Self {
+        subquery(select)
+    }
+}
+
+impl From<Expr> for NodeEnum {
+    fn from(expr: Expr) -> Self {
+        expr.0
+    }
+}
+
+impl From<Expr> for Node {
+    fn from(expr: Expr) -> Self {
+        wrap(expr.0)
+    }
+}
+
+impl Table {
+    /// Adds an alias, e.g. `users u`.
+    pub fn alias(mut self, alias: &str) -> Table {
+        self.0.alias = Some(make_alias(alias));
+        self
+    }
+
+    /// Excludes child tables, i.e. `ONLY t`.
+    pub fn only(mut self) -> Table {
+        self.0.inh = false;
+        self
+    }
+
+    /// `self JOIN right ON on`.
+    pub fn join(self, right: impl Into<FromItem>, on: impl Into<Expr>) -> FromItem {
+        FromItem::from(self).join(right, on)
+    }
+
+    /// `self LEFT JOIN right ON on`.
+    pub fn left_join(self, right: impl Into<FromItem>, on: impl Into<Expr>) -> FromItem {
+        FromItem::from(self).left_join(right, on)
+    }
+
+    /// The underlying node.
+    pub fn into_range_var(self) -> protobuf::RangeVar {
+        self.0
+    }
+}
+
+impl From<&str> for Table {
+    fn from(name: &str) -> Self {
+        table(name)
+    }
+}
+
+impl FromItem {
+    /// `self JOIN right ON on`.
+    pub fn join(self, right: impl Into<FromItem>, on: impl Into<Expr>) -> FromItem {
+        self.join_with(protobuf::JoinType::JoinInner, right.into(), Some(on.into()))
+    }
+
+    /// `self LEFT JOIN right ON on`.
+    pub fn left_join(self, right: impl Into<FromItem>, on: impl Into<Expr>) -> FromItem {
+        self.join_with(protobuf::JoinType::JoinLeft, right.into(), Some(on.into()))
+    }
+
+    /// `self CROSS JOIN right`.
+    pub fn cross_join(self, right: impl Into<FromItem>) -> FromItem {
+        self.join_with(protobuf::JoinType::JoinInner, right.into(), None)
+    }
+
+    /// The underlying node.
+    pub fn into_node(self) -> NodeEnum {
+        self.0
+    }
+
+    fn join_with(self, jointype: protobuf::JoinType, right: FromItem, quals: Option<Expr>) -> FromItem {
+        FromItem(NodeEnum::JoinExpr(Box::new(protobuf::JoinExpr {
+            jointype: jointype as i32,
+            larg: Some(Box::new(wrap(self.0))),
+            rarg: Some(Box::new(wrap(right.0))),
+            quals: quals.map(|quals| Box::new(quals.into())),
+            ..Default::default()
+        })))
+    }
+}
+
+impl From<Table> for FromItem {
+    fn from(table: Table) -> Self {
+        FromItem(NodeEnum::RangeVar(table.0))
+    }
+}
+
+impl From<&str> for FromItem {
+    fn from(name: &str) -> Self {
+        table(name).into()
+    }
+}
+
+impl SelectBuilder {
+    /// Adds columns by name, see [col].
+    pub fn columns(mut self, names: impl IntoIterator<Item = impl AsRef<str>>) -> SelectBuilder {
+        self.0.target_list.extend(names.into_iter().map(|name| target(col(name.as_ref()), "")));
+        self
+    }
+
+    /// Adds an expression to the target list.
+    pub fn column(mut self, expr: impl Into<Expr>) -> SelectBuilder {
+        self.0.target_list.push(target(expr.into(), ""));
+        self
+    }
+
+    /// Adds an expression with an alias to the target list, e.g. `count(*) AS total`.
+    pub fn column_as(mut self, expr: impl Into<Expr>, alias: &str) -> SelectBuilder {
+        self.0.target_list.push(target(expr.into(), alias));
+        self
+    }
+
+    /// `SELECT DISTINCT`.
+    pub fn distinct(mut self) -> SelectBuilder {
+        // A single empty node stands for DISTINCT without ON
+        self.0.distinct_clause = vec![Node { node: None }];
+        self
+    }
+
+    /// Adds an item to the `FROM` clause.
+    pub fn from(mut self, item: impl Into<FromItem>) -> SelectBuilder {
+        self.0.from_clause.push(wrap(item.into().0));
+        self
+    }
+
+    /// Adds a condition to the `WHERE` clause, combined with any previous conditions using `AND`.
+    pub fn where_(mut self, condition: impl Into<Expr>) -> SelectBuilder {
+        self.0.where_clause = and_condition(self.0.where_clause.take(), condition.into());
+        self
+    }
+
+    /// Adds an expression to the `GROUP BY` clause.
+    pub fn group_by(mut self, expr: impl Into<Expr>) -> SelectBuilder {
+        self.0.group_clause.push(expr.into().into());
+        self
+    }
+
+    /// Adds a condition to the `HAVING` clause, combined with any previous conditions using `AND`.
+    pub fn having(mut self, condition: impl Into<Expr>) -> SelectBuilder {
+        self.0.having_clause = and_condition(self.0.having_clause.take(), condition.into());
+        self
+    }
+
+    /// Adds an expression to the `ORDER BY` clause, in the default ascending order.
+    pub fn order_by(self, expr: impl Into<Expr>) -> SelectBuilder {
+        self.sort(expr.into(), protobuf::SortByDir::SortbyDefault)
+    }
+
+    /// Adds an expression to the `ORDER BY` clause, in descending order.
+    pub fn order_by_desc(self, expr: impl Into<Expr>) -> SelectBuilder {
+        self.sort(expr.into(), protobuf::SortByDir::SortbyDesc)
+    }
+
+    /// Sets the `LIMIT`.
+    pub fn limit(mut self, count: impl Into<Expr>) -> SelectBuilder {
+        self.0.limit_count = Some(Box::new(count.into().into()));
+        self.0.limit_option = protobuf::LimitOption::Count as i32;
+        self
+    }
+
+    /// Sets the `OFFSET`.
+    pub fn offset(mut self, offset: impl Into<Expr>) -> SelectBuilder {
+        self.0.limit_offset = Some(Box::new(offset.into().into()));
+        self.0.limit_option = protobuf::LimitOption::Count as i32;
+        self
+    }
+
+    /// `self UNION other`, or `self UNION ALL other` if `all` is set.
+    pub fn union(self, other: SelectBuilder, all: bool) -> SelectBuilder {
+        let mut stmt = select().0;
+        stmt.op = protobuf::SetOperation::SetopUnion as i32;
+        stmt.all = all;
+        stmt.larg = Some(Box::new(self.0));
+        stmt.rarg = Some(Box::new(other.0));
+        SelectBuilder(stmt)
+    }
+
+    /// Returns the statement.
+    pub fn build(self) -> protobuf::SelectStmt {
+        self.0
+    }
+
+    /// Converts the statement into SQL.
+    pub fn deparse(&self) -> Result<String> {
+        NodeEnum::SelectStmt(Box::new(self.0.clone())).deparse()
+    }
+
+    fn sort(mut self, expr: Expr, direction: protobuf::SortByDir) -> SelectBuilder {
+        let sort_by = protobuf::SortBy {
+            node: Some(Box::new(expr.into())),
+            sortby_dir: direction as i32,
+            sortby_nulls: protobuf::SortByNulls::SortbyNullsDefault as i32,
+            use_op: vec![],
+            location: -1,
+        };
+        self.0.sort_clause.push(wrap(NodeEnum::SortBy(Box::new(sort_by))));
+        self
+    }
+}
+
+impl From<SelectBuilder> for NodeEnum {
+    fn from(select: SelectBuilder) -> Self {
+        NodeEnum::SelectStmt(Box::new(select.0))
+    }
+}
+
+impl From<SelectBuilder> for Node {
+    fn from(select: SelectBuilder) -> Self {
+        wrap(select.into())
+    }
+}
+
+impl InsertBuilder {
+    /// Sets the target columns by name.
+    pub fn columns(mut self, names: impl IntoIterator<Item = impl AsRef<str>>) -> InsertBuilder {
+        self.stmt.cols = names.into_iter().map(|name| assignment(name.as_ref(), None)).collect();
+        self
+    }
+
+    /// Adds a row to the `VALUES` list.
+    pub fn values(mut self, row: impl IntoIterator<Item = impl Into<Expr>>) -> InsertBuilder {
+        let items = row.into_iter().map(|value| value.into().into()).collect();
+        self.values.push(wrap(NodeEnum::List(protobuf::List { items })));
+        self
+    }
+
+    /// Inserts the rows returned by a query instead of a `VALUES` list.
+    pub fn select(mut self, query: SelectBuilder) -> InsertBuilder {
+        self.values.clear();
+        self.stmt.select_stmt = Some(Box::new(query.into()));
+        self
+    }
+
+    /// Adds an expression to the `RETURNING` list.
+    pub fn returning(mut self, expr: impl Into<Expr>) -> InsertBuilder {
+        self.stmt.returning_list.push(target(expr.into(), ""));
+        self
+    }
+
+    /// Returns the statement.
+    pub fn build(self) -> protobuf::InsertStmt {
+        let mut stmt = self.stmt;
+        if !self.values.is_empty() {
+            stmt.select_stmt = Some(Box::new(SelectBuilder(protobuf::SelectStmt { values_lists: self.values, ..select().0 }).into()));
+        }
+        stmt
+    }
+
+    /// Converts the statement into SQL.
+    pub fn deparse(&self) -> Result<String> {
+        NodeEnum::from(self.clone()).deparse()
+    }
+}
+
+impl From<InsertBuilder> for NodeEnum {
+    fn from(insert: InsertBuilder) -> Self {
+        NodeEnum::InsertStmt(Box::new(insert.build()))
+    }
+}
+
+impl UpdateBuilder {
+    /// Adds `column = value` to the `SET` clause.
+    pub fn set(mut self, column: &str, value: impl Into<Expr>) -> UpdateBuilder {
+        self.0.target_list.push(assignment(column, Some(value.into())));
+        self
+    }
+
+    /// Adds an item to the `FROM` clause.
+    pub fn from(mut self, item: impl Into<FromItem>) -> UpdateBuilder {
+        self.0.from_clause.push(wrap(item.into().0));
+        self
+    }
+
+    /// Adds a condition to the `WHERE` clause, combined with any previous conditions using `AND`.
+    pub fn where_(mut self, condition: impl Into<Expr>) -> UpdateBuilder {
+        self.0.where_clause = and_condition(self.0.where_clause.take(), condition.into());
+        self
+    }
+
+    /// Adds an expression to the `RETURNING` list.
+    pub fn returning(mut self, expr: impl Into<Expr>) -> UpdateBuilder {
+        self.0.returning_list.push(target(expr.into(), ""));
+        self
+    }
+
+    /// Returns the statement.
+    pub fn build(self) -> protobuf::UpdateStmt {
+        self.0
+    }
+
+    /// Converts the statement into SQL.
+    pub fn deparse(&self) -> Result<String> {
+        NodeEnum::UpdateStmt(Box::new(self.0.clone())).deparse()
+    }
+}
+
+impl From<UpdateBuilder> for NodeEnum {
+    fn from(update: UpdateBuilder) -> Self {
+        NodeEnum::UpdateStmt(Box::new(update.0))
+    }
+}
+
+impl DeleteBuilder {
+    /// Adds an item to the `USING` clause.
+    pub fn using(mut self, item: impl Into<FromItem>) -> DeleteBuilder {
+        self.0.using_clause.push(wrap(item.into().0));
+        self
+    }
+
+    /// Adds a condition to the `WHERE` clause, combined with any previous conditions using `AND`.
+    pub fn where_(mut self, condition: impl Into<Expr>) -> DeleteBuilder {
+        self.0.where_clause = and_condition(self.0.where_clause.take(), condition.into());
+        self
+    }
+
+    /// Adds an expression to the `RETURNING` list.
+    pub fn returning(mut self, expr: impl Into<Expr>) -> DeleteBuilder {
+        self.0.returning_list.push(target(expr.into(), ""));
+        self
+    }
+
+    /// Returns the statement.
+    pub fn build(self) -> protobuf::DeleteStmt {
+        self.0
+    }
+
+    /// Converts the statement into SQL.
+    pub fn deparse(&self) -> Result<String> {
+        NodeEnum::DeleteStmt(Box::new(self.0.clone())).deparse()
+    }
+}
+
+impl From<DeleteBuilder> for NodeEnum {
+    fn from(delete: DeleteBuilder) -> Self {
+        NodeEnum::DeleteStmt(Box::new(delete.0))
+    }
+}
+
+// SQL spellings of builtin types and their names in pg_catalog, from SimpleTypename in gram.y
+const SYSTEM_TYPES: &[(&str, &str)] = &[
+    ("int", "int4"),
+    ("integer", "int4"),
+    ("smallint", "int2"),
+    ("bigint", "int8"),
+    ("real", "float4"),
+    ("float", "float8"),
+    ("double precision", "float8"),
+    ("decimal", "numeric"),
+    ("dec", "numeric"),
+    ("numeric", "numeric"),
+    ("boolean", "bool"),
+    ("char", "bpchar"),
+    ("character", "bpchar"),
+    ("varchar", "varchar"),
+    ("character varying", "varchar"),
+    ("bit", "bit"),
+    ("bit varying", "varbit"),
+    ("timestamp", "timestamp"),
+    ("timestamp without time zone", "timestamp"),
+    ("timestamp with time zone", "timestamptz"),
+    ("time", "time"),
+    ("time without time zone", "time"),
+    ("time with time zone", "timetz"),
+    ("interval", "interval"),
+];
+
+// Types whose SQL spelling without a length means a length of 1, from CharacterWithoutLength and BitWithoutLength
+// in gram.y
+const LENGTH_ONE_TYPES: &[&str] = &["char", "character", "bit"];
+
+fn make_type_name(name: &str) -> protobuf::TypeName {
+    let (name, array_bounds) = match name.trim().strip_suffix("[]") {
+        Some(element) => (element.trim_end(), vec![wrap(NodeEnum::Integer(protobuf::Integer { ival: -1 }))]),
+        None => (name.trim(), vec![]),
+    };
+    let (name, mut typmods) = split_typmods(name);
+    let lower = name.to_lowercase();
+    if typmods.is_empty() && LENGTH_ONE_TYPES.contains(&lower.as_str()) {
+        typmods.push(1);
+    }
+    let names = match SYSTEM_TYPES.iter().find(|(sql, _)| *sql == lower) {
+        Some((_, internal)) => vec!["pg_catalog", internal],
+        None => name.split('.').collect(),
+    };
+    protobuf::TypeName {
+        names: names.into_iter().map(|part| wrap(string_node(part))).collect(),
+        typmods: typmods.into_iter().map(|typmod| wrap(constant(Val::Ival(protobuf::Integer { ival: typmod })).0)).collect(),
+        typemod: -1,
+        array_bounds,
+        location: -1,
+        ..Default::default()
+    }
+}
+
+// Splits integer type modifiers off a type name, e.g. `numeric(10, 2)` into `numeric` and `[10, 2]`
+fn split_typmods(name: &str) -> (&str, Vec<i32>) {
+    let Some((base, args)) = name.strip_suffix(')').and_then(|name| name.split_once('(')) else { return (name, vec![]) };
+    match args.split(',').map(|arg| arg.trim().parse()).collect() {
+        Ok(typmods) => (base.trim_end(), typmods),
+        Err(_) => (name, vec![]),
+    }
+}
+
+fn make_alias(name: &str) -> protobuf::Alias {
+    protobuf::Alias { aliasname: name.to_string(), colnames: vec![] }
+}
+
+fn constant(val: Val) -> Expr {
+    Expr(NodeEnum::AConst(protobuf::AConst { isnull: false, val: Some(val), location: -1 }))
+}
+
+fn a_expr(kind: protobuf::AExprKind, operator: &str, left: Expr, right: NodeEnum) -> Expr {
+    Expr(NodeEnum::AExpr(Box::new(protobuf::AExpr {
+        kind: kind as i32,
+        name: vec![wrap(string_node(operator))],
+        lexpr: Some(Box::new(left.into())),
+        rexpr: Some(Box::new(wrap(right))),
+        location: -1,
+    })))
+}
+
+fn sub_link(sub_link_type: protobuf::SubLinkType, testexpr: Option<Expr>, subquery: SelectBuilder) -> Expr {
+    Expr(NodeEnum::SubLink(Box::new(protobuf::SubLink {
+        sub_link_type: sub_link_type as i32,
+        testexpr: testexpr.map(|expr| Box::new(expr.into())),
+        subselect: Some(Box::new(subquery.into())),
+        location: -1,
+        ..Default::default()
+    })))
+}
+
+fn and_condition(existing: Option<Box<Node>>, condition: Expr) -> Option<Box<Node>> {
+    let condition = match existing.and_then(|node| node.node) {
+        Some(node) => Expr(node).and(condition),
+        None => condition,
+    };
+    Some(Box::new(condition.into()))
+}
+
+fn target(expr: Expr, name: &str) -> Node {
+    wrap(NodeEnum::ResTarget(Box::new(protobuf::ResTarget {
+        name: name.to_string(),
+        indirection: vec![],
+        val: Some(Box::new(expr.into())),
+        location: -1,
+    })))
+}
+
+// A column in an INSERT column list or an UPDATE SET clause
+fn assignment(column: &str, value: Option<Expr>) -> Node {
+    wrap(NodeEnum::ResTarget(Box::new(protobuf::ResTarget {
+        name: column.to_string(),
+        indirection: vec![],
+        val: value.map(|value| Box::new(value.into())),
+        location: -1,
+    })))
+}
+
+fn string_node(value: &str) -> NodeEnum {
+    NodeEnum::String(protobuf::String { sval: value.to_string() })
+}
+
+fn wrap(node: NodeEnum) -> Node {
+    Node { node: Some(node) }
+}
@@ -0,0 +1,166 @@
+use crate::*;
+
+/// A value to substitute for a `$n` placeholder, see [denormalize].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParamValue {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    /// Non-finite values are written as `'NaN'::float8`, `'Infinity'::float8` and `'-Infinity'::float8`
+    Float(f64),
+    Text(String),
+    /// Written as a hex-format `bytea` literal, e.g. `'\x0aff'::bytea`
+    Bytea(Vec<u8>),
+    /// Written as `ARRAY[...]`, or as `'{}'` if empty since an empty `ARRAY[]` needs an explicit type
+    Array(Vec<ParamValue>),
+    /// SQL inserted as-is, such as [NormalizedParam::text] or a typed literal like `DATE '2024-01-01'`
+    Literal(String),
+}
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        ParamValue::Bool(value)
+    }
+}
+
+impl From<i32> for ParamValue {
+    fn from(value: i32) -> Self {
+        ParamValue::Integer(value.into())
+    }
+}
+
+impl From<i64> for ParamValue {
+    fn from(value: i64) -> Self {
+        ParamValue::Integer(value)
+    }
+}
+
+impl From<f64> for ParamValue {
+    fn from(value: f64) -> Self {
+        ParamValue::Float(value)
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        ParamValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        ParamValue::Text(value)
+    }
+}
+
+impl<T: Into<ParamValue>> From<Option<T>> for ParamValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(ParamValue::Null, Into::into)
+    }
+}
+
+impl From<&NormalizedParam> for ParamValue {
+    fn from(param: &NormalizedParam) -> Self {
+        ParamValue::Literal(param.text.clone())
+    }
+}
+
+impl ParamValue {
+    /// The value as a Postgres literal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::ParamValue;
+    ///
+    /// assert_eq!(ParamValue::from("it's").to_sql(), "'it''s'");
+    /// assert_eq!(ParamValue::Bytea(vec![0, 255]).to_sql(), "'\\x00ff'::bytea");
+    /// assert_eq!(ParamValue::Array(vec![1.into(), ParamValue::Null]).to_sql(), "ARRAY[1, NULL]");
+    /// ```
+    pub fn to_sql(&self) -> String {
+        match self {
+            ParamValue::Null => "NULL".to_string(),
+            ParamValue::Bool(value) => value.to_string(),
+            ParamValue::Integer(value) => value.to_string(),
+            ParamValue::Float(value) if value.is_nan() => "'NaN'::float8".to_string(),
+            ParamValue::Float(value) if value.is_infinite() => format!("'{}Infinity'::float8", if *value < 0.0 { "-" } else { "" }),
+            ParamValue::Float(value) => format!("{value:?}"),
+            ParamValue::Text(value) => quote_literal(value),
+            ParamValue::Bytea(bytes) => {
+                let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+                format!("'\\x{hex}'::bytea")
+            }
+            ParamValue::Array(values) if values.is_empty() => "'{}'".to_string(),
+            ParamValue::Array(values) => format!("ARRAY[{}]", values.iter().map(ParamValue::to_sql).collect::<Vec<_>>().join(", ")),
+            ParamValue::Literal(sql) => sql.clone(),
+        }
+    }
+}
+
+/// Quotes a string as a Postgres string literal, following the rules of the `quote_literal` SQL function: single
+/// quotes are doubled, and if the string contains backslashes they are doubled too and the literal is written as
+/// an escape string (`E'...'`).
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(pg_query::quote_literal("O'Reilly"), "'O''Reilly'");
+/// assert_eq!(pg_query::quote_literal(r"C:\temp"), r"E'C:\\temp'");
+/// ```
+pub fn quote_literal(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 3);
+    if value.contains('\\') {
+        quoted.push('E');
+    }
+    quoted.push('\'');
+    for c in value.chars() {
+        if c == '\'' || c == '\\' {
+            quoted.push(c);
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Replaces the `$n` placeholders of a query with the given values, the reverse of [normalize].
+///
+/// `$1` is replaced by `params[0]` and so on. Only placeholder tokens are replaced, so text such as `'$1'` inside
+/// string constants or comments is left untouched. Fails with [Error::MissingParam] if there are fewer values than
+/// placeholders.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::ParamValue;
+///
+/// let query = "SELECT * FROM users WHERE name = $1 AND id = ANY($2) AND note <> '$1'";
+/// let params = [ParamValue::from("O'Reilly"), ParamValue::Array(vec![1.into(), 2.into()])];
+/// assert_eq!(
+///     pg_query::denormalize(query, &params).unwrap(),
+///     "SELECT * FROM users WHERE name = 'O''Reilly' AND id = ANY(ARRAY[1, 2]) AND note <> '$1'"
+/// );
+/// ```
+pub fn denormalize(query: &str, params: &[ParamValue]) -> Result<String> {
+    let mut output = String::with_capacity(query.len());
+    let mut last_end = 0;
+    for token in scan(query)?.tokens {
+        if token.token != protobuf::Token::Param as i32 {
+            continue;
+        }
+        let (start, end) = (token.start as usize, token.end as usize);
+        let placeholder = &query[start..end];
+        let idx = placeholder[1..].parse::<usize>().ok().and_then(|number| number.checked_sub(1));
+        let value = idx.and_then(|idx| params.get(idx)).ok_or_else(|| Error::MissingParam(placeholder.to_string()))?;
+        output.push_str(&query[last_end..start]);
+        let sql = value.to_sql();
+        // Keep a negative number from merging with a preceding operator, e.g. `x-$1` turning into the comment `x--1`
+        if sql.starts_with('-') && output.ends_with(|c| "+-*/<>=~!@#%^&|`?".contains(c)) {
+            output.push(' ');
+        }
+        output.push_str(&sql);
+        last_end = end;
+    }
+    output.push_str(&query[last_end..]);
+    Ok(output)
+}
@@ -0,0 +1,354 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::node_fields::{for_each_scalar, Scalar};
+use crate::node_path::for_each_child;
+use crate::*;
+
+/// What happened to a node between the two trees passed to [diff].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// A structural change between two parse trees, see [diff].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// Path to the node, in the second tree for added and modified nodes and in the first tree for removed nodes.
+    pub path: FieldPath,
+    /// Type of the node, as returned by [NodeRef::type_name].
+    pub node_type: &'static str,
+    /// The node in the first tree as SQL, `None` for added nodes.
+    pub before: Option<String>,
+    /// The node in the second tree as SQL, `None` for removed nodes.
+    pub after: Option<String>,
+}
+
+impl Change {
+    /// The clauses leading to the node, outermost first, e.g. `WHERE clause` or
+    /// `FROM clause → target list` for a column of a subquery, or `statement` for whole statements.
+    pub fn clause(&self) -> String {
+        let labels: Vec<&str> = self.path.steps.iter().filter_map(clause_label).collect();
+        if labels.is_empty() {
+            "statement".to_string()
+        } else {
+            labels.join(" → ")
+        }
+    }
+
+    /// What the node is, e.g. `column`, `table` or `predicate`.
+    pub fn noun(&self) -> &'static str {
+        match self.node_type {
+            "ResTarget" => "column",
+            "RangeVar" => "table",
+            "JoinExpr" => "join",
+            "RangeSubselect" => "subquery",
+            "RangeFunction" => "function",
+            "CommonTableExpr" => "CTE",
+            "SortBy" => "sort key",
+            "TypeName" => "type",
+            node_type if node_type.ends_with("Stmt") => "statement",
+            node_type => {
+                // Conditions of a WHERE clause, possibly combined with AND or OR, but not their operands
+                let clause = self.path.steps.iter().rposition(|step| clause_label(step).is_some());
+                match clause {
+                    Some(i)
+                        if matches!(self.path.steps[i].field, "where_clause" | "having_clause" | "quals")
+                            && self.path.steps[i + 1..].iter().all(|step| step.node_type == "BoolExpr") =>
+                    {
+                        "predicate"
+                    }
+                    _ if node_type == "ColumnRef" => "column",
+                    _ => "expression",
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = |sql: &Option<String>| sql.clone().unwrap_or_else(|| "?".to_string());
+        match self.kind {
+            ChangeKind::Added => write!(f, "{}: added {} `{}`", self.clause(), self.noun(), text(&self.after)),
+            ChangeKind::Removed => write!(f, "{}: removed {} `{}`", self.clause(), self.noun(), text(&self.before)),
+            ChangeKind::Modified => write!(f, "{}: changed {} `{}` to `{}`", self.clause(), self.noun(), text(&self.before), text(&self.after)),
+        }
+    }
+}
+
+/// Compares two parse trees and returns the structural changes from `a` to `b`, ignoring
+/// formatting and `location` fields.
+///
+/// Changes are reported for the smallest node that can be shown as SQL, e.g. a column of the
+/// target list or a single predicate of an `AND`, and statements are matched up even when some
+/// were added or removed.
+///
+/// # Example
+///
+/// ```rust
+/// let a = pg_query::parse("SELECT id, email FROM users WHERE active").unwrap();
+/// let b = pg_query::parse("SELECT id\nFROM users\nWHERE active AND status = $1").unwrap();
+/// let changes: Vec<String> = pg_query::diff(&a.protobuf, &b.protobuf).iter().map(|c| c.to_string()).collect();
+/// assert_eq!(changes, ["target list: removed column `email`", "WHERE clause: added predicate `status = $1`"]);
+/// ```
+pub fn diff(a: &protobuf::ParseResult, b: &protobuf::ParseResult) -> Vec<Change> {
+    let mut differ = Differ::default();
+    differ.list(&statements(a), &statements(b));
+    differ.changes
+}
+
+#[derive(Clone, Debug)]
+struct Item<'a> {
+    node: NodeRef<'a>,
+    path: FieldPath,
+}
+
+#[derive(Default)]
+struct Differ {
+    changes: Vec<Change>,
+}
+
+impl Differ {
+    // Compares two nodes at the same position, and returns whether the difference has to be reported
+    // on an ancestor because the node can't be deparsed on its own
+    fn node(&mut self, a: &Item, b: &Item) -> bool {
+        // An AND or OR compared to a single condition, e.g. after adding a predicate to a WHERE clause
+        match (bool_args(a), bool_args(b)) {
+            (Some(args), None) => return self.list(&args, std::slice::from_ref(b)),
+            (None, Some(args)) => return self.list(std::slice::from_ref(a), &args),
+            _ => {}
+        }
+        if a.node.type_name() != b.node.type_name() || scalars(a.node) != scalars(b.node) {
+            return self.record(ChangeKind::Modified, Some(a), Some(b));
+        }
+        let start = self.changes.len();
+        let (children_a, children_b) = (children(a), children(b));
+        let mut fields: Vec<&str> = Vec::new();
+        for (field, _) in children_a.iter().chain(&children_b) {
+            if !fields.contains(field) {
+                fields.push(field);
+            }
+        }
+        let mut escalate = false;
+        for field in fields {
+            escalate |= self.list(&in_field(&children_a, field), &in_field(&children_b, field));
+        }
+        if escalate {
+            self.changes.truncate(start);
+            return self.record(ChangeKind::Modified, Some(a), Some(b));
+        }
+        false
+    }
+
+    // Compares two lists of nodes, matching up equal nodes and comparing the remaining ones position by position
+    fn list(&mut self, a: &[Item], b: &[Item]) -> bool {
+        let mut escalate = false;
+        let (mut i, mut j) = (0, 0);
+        for (next_i, next_j) in common_subsequence(a, b).into_iter().chain([(a.len(), b.len())]) {
+            let (removed, added) = (&a[i..next_i], &b[j..next_j]);
+            for (x, y) in removed.iter().zip(added) {
+                escalate |= self.node(x, y);
+            }
+            for x in removed.iter().skip(added.len()) {
+                escalate |= self.record(ChangeKind::Removed, Some(x), None);
+            }
+            for y in added.iter().skip(removed.len()) {
+                escalate |= self.record(ChangeKind::Added, None, Some(y));
+            }
+            (i, j) = (next_i + 1, next_j + 1);
+        }
+        escalate
+    }
+
+    fn record(&mut self, kind: ChangeKind, a: Option<&Item>, b: Option<&Item>) -> bool {
+        let before = a.map(sql);
+        let after = b.map(sql);
+        let item = b.or(a).expect("a change needs at least one node");
+        let failed = before.as_ref().is_some_and(|r| r.is_err()) || after.as_ref().is_some_and(|r| r.is_err());
+        if failed && !item.path.steps.is_empty() {
+            return true;
+        }
+        self.changes.push(Change {
+            kind,
+            path: item.path.clone(),
+            node_type: item.node.type_name(),
+            before: before.and_then(|r| r.ok()),
+            after: after.and_then(|r| r.ok()),
+        });
+        false
+    }
+}
+
+fn sql(item: &Item) -> Result<String> {
+    match (item.node, item.path.steps.last().map(|step| (step.node_type, step.field))) {
+        // Values only make sense as part of their parent, e.g. the `String` nodes of a qualified name
+        (
+            NodeRef::String(_)
+            | NodeRef::Integer(_)
+            | NodeRef::Float(_)
+            | NodeRef::Boolean(_)
+            | NodeRef::BitString(_)
+            | NodeRef::List(_)
+            | NodeRef::AStar(_),
+            _,
+        ) => Err(Error::InvalidPointer),
+        // Assignments and INSERT columns would otherwise be deparsed as target list entries
+        (NodeRef::ResTarget(t), Some(("UpdateStmt" | "OnConflictClause", "target_list"))) => {
+            let value = t.val.as_ref().ok_or(Error::InvalidPointer)?.deparse()?;
+            Ok(format!("{} = {value}", t.name))
+        }
+        (NodeRef::ResTarget(t), Some(("InsertStmt", "cols"))) => Ok(t.name.clone()),
+        (node, _) => node.deparse(),
+    }
+}
+
+fn statements(result: &protobuf::ParseResult) -> Vec<Item<'_>> {
+    result
+        .stmts
+        .iter()
+        .enumerate()
+        .filter_map(|(statement, stmt)| {
+            let node = stmt.stmt.as_ref()?.node.as_ref()?.to_ref();
+            Some(Item { node, path: FieldPath { statement, steps: vec![] } })
+        })
+        .collect()
+}
+
+fn in_field<'a>(children: &[(&str, Item<'a>)], field: &str) -> Vec<Item<'a>> {
+    children.iter().filter(|(f, _)| *f == field).map(|(_, item)| item.clone()).collect()
+}
+
+fn children<'a>(item: &Item<'a>) -> Vec<(&'static str, Item<'a>)> {
+    let mut children = Vec::new();
+    for_each_child(item.node, &mut |step, node| {
+        let mut path = item.path.clone();
+        path.steps.push(step);
+        children.push((step.field, Item { node, path }));
+    });
+    children
+}
+
+fn bool_args<'a>(item: &Item<'a>) -> Option<Vec<Item<'a>>> {
+    match item.node {
+        NodeRef::BoolExpr(b) if b.boolop != protobuf::BoolExprType::NotExpr as i32 => {
+            Some(children(item).into_iter().map(|(_, item)| item).collect())
+        }
+        _ => None,
+    }
+}
+
+// The fields of `node` that aren't nodes, without locations
+fn scalars(node: NodeRef) -> Vec<(&'static str, Scalar)> {
+    let mut scalars = Vec::new();
+    for_each_scalar(node, &mut |name, value| match (name, value) {
+        ("location", Scalar::Int(_)) => {}
+        // Only distinguishes between LIMIT and FETCH FIRST ... WITH TIES, which the clauses themselves don't
+        ("limit_option", Scalar::Enum(_, name)) if name != "LIMIT_OPTION_WITH_TIES" => {}
+        _ => scalars.push((name, value)),
+    });
+    scalars
+}
+
+// Hashes the whole subtree of `node`, so that nodes that are equal apart from their locations hash the same
+fn structure_hash(node: NodeRef) -> u64 {
+    fn add(node: NodeRef, hasher: &mut DefaultHasher) {
+        node.type_name().hash(hasher);
+        scalars(node).hash(hasher);
+        let mut children = 0usize;
+        for_each_child(node, &mut |step, child| {
+            step.hash(hasher);
+            add(child, hasher);
+            children += 1;
+        });
+        children.hash(hasher);
+    }
+    let mut hasher = DefaultHasher::new();
+    add(node, &mut hasher);
+    hasher.finish()
+}
+
+// Whether two nodes are equal, ignoring locations
+fn same(a: NodeRef, b: NodeRef) -> bool {
+    if a.type_name() != b.type_name() || scalars(a) != scalars(b) {
+        return false;
+    }
+    let mut children_a = Vec::new();
+    for_each_child(a, &mut |step, node| children_a.push((step, node)));
+    let mut children_b = Vec::new();
+    for_each_child(b, &mut |step, node| children_b.push((step, node)));
+    children_a.len() == children_b.len() && children_a.iter().zip(&children_b).all(|((sa, na), (sb, nb))| sa == sb && same(*na, *nb))
+}
+
+// Returns the index pairs of a longest common subsequence of equal nodes
+fn common_subsequence<'a>(a: &[Item<'a>], b: &[Item<'a>]) -> Vec<(usize, usize)> {
+    // Number the nodes so that equal ones share a number, rather than comparing subtrees for each of the n × m pairs.
+    // Nodes are only compared in full when their hashes match, which also rules out hash collisions
+    let mut classes: HashMap<u64, Vec<(NodeRef<'a>, usize)>> = HashMap::new();
+    let mut count = 0;
+    let mut class = |item: &Item<'a>| {
+        let candidates = classes.entry(structure_hash(item.node)).or_default();
+        match candidates.iter().find(|(node, _)| same(*node, item.node)) {
+            Some((_, class)) => *class,
+            None => {
+                count += 1;
+                candidates.push((item.node, count));
+                count
+            }
+        }
+    };
+    let a: Vec<usize> = a.iter().map(&mut class).collect();
+    let b: Vec<usize> = b.iter().map(&mut class).collect();
+    let (n, m) = (a.len(), b.len());
+    let mut lengths = vec![vec![0; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if a[i] == b[j] { lengths[i + 1][j + 1] + 1 } else { lengths[i + 1][j].max(lengths[i][j + 1]) };
+        }
+    }
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+fn clause_label(step: &PathStep) -> Option<&'static str> {
+    let label = match (step.node_type, step.field) {
+        ("UpdateStmt" | "OnConflictClause", "target_list") => "SET clause",
+        (_, "target_list") => "target list",
+        (_, "from_clause") => "FROM clause",
+        (_, "where_clause") => "WHERE clause",
+        (_, "group_clause") => "GROUP BY clause",
+        (_, "having_clause") => "HAVING clause",
+        (_, "sort_clause") => "ORDER BY clause",
+        (_, "limit_count") => "LIMIT clause",
+        (_, "limit_offset") => "OFFSET clause",
+        (_, "distinct_clause") => "DISTINCT clause",
+        (_, "window_clause") => "WINDOW clause",
+        (_, "locking_clause") => "locking clause",
+        (_, "values_lists") => "VALUES list",
+        (_, "returning_list") => "RETURNING clause",
+        ("WithClause", "ctes") => "WITH clause",
+        ("JoinExpr", "quals") => "JOIN condition",
+        ("DeleteStmt", "using_clause") => "USING clause",
+        ("InsertStmt", "cols") => "column list",
+        ("OnConflictClause", _) => "ON CONFLICT clause",
+        _ => return None,
+    };
+    Some(label)
+}
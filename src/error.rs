@@ -1,5 +1,10 @@
+use std::ffi::CStr;
+use std::fmt;
+
 use thiserror::Error;
 
+use crate::bindings::PgQueryError;
+
 /// Error structure representing the basic error scenarios for `pg_query`.
 #[derive(Debug, Error, Eq, PartialEq)]
 pub enum Error {
@@ -8,16 +13,146 @@ pub enum Error {
     #[error("Error decoding result: {0}")]
     Decode(#[from] prost::DecodeError),
     #[error("Invalid statement: {0}")]
-    Parse(String),
+    Parse(Box<ParseError>),
     #[error("Error parsing JSON: {0}")]
     InvalidJson(String),
     #[error("Invalid pointer")]
     InvalidPointer,
     #[error("Error scanning: {0}")]
-    Scan(String),
+    Scan(Box<ParseError>),
     #[error("Error splitting: {0}")]
-    Split(String),
+    Split(Box<ParseError>),
+    #[error("Invalid rewrite: {0}")]
+    Rewrite(String),
+    #[error("No value for parameter {0}")]
+    MissingParam(String),
+    #[error("Cannot anonymize {0}")]
+    Anonymize(String),
 }
 
 /// Convenient Result alias for returning `pg_query::Error`.
 pub type Result<T> = core::result::Result<T, Error>;
+
+/// Details about an error reported by libpg_query, including where in the input it occurred.
+///
+/// # Example
+///
+/// ```rust
+/// let error = match pg_query::parse("SELECT * FROM t WHERE") {
+///     Err(pg_query::Error::Parse(error)) => error,
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(error.message, "syntax error at end of input");
+/// assert_eq!(error.sqlstate, Some("42601"));
+/// let position = error.position.unwrap();
+/// assert_eq!((position.line, position.column), (1, 22));
+/// assert_eq!(error.snippet.unwrap(), "LINE 1: SELECT * FROM t WHERE\n                             ^");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ParseError {
+    /// The primary error message, e.g. `syntax error at or near "RANDOM"`.
+    pub message: String,
+    /// The SQLSTATE code, when the Postgres source function that raised the error always uses the same one, e.g.
+    /// `42601` for syntax errors. `None` for all other errors, rather than guessing.
+    pub sqlstate: Option<&'static str>,
+    /// Location of the error in the input, if Postgres reported one.
+    pub position: Option<ErrorPosition>,
+    /// The offending input line with a caret under the error position, formatted like psql's `LINE 1: ...`.
+    pub snippet: Option<String>,
+    /// Postgres source function that raised the error.
+    pub funcname: Option<String>,
+    /// Postgres source file that raised the error.
+    pub filename: Option<String>,
+    /// Line in the Postgres source file (not in the input) that raised the error.
+    pub lineno: i32,
+    /// Raw 1-based character offset as reported by Postgres, 0 if unknown.
+    pub cursorpos: i32,
+}
+
+/// Location of an error within the input text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ErrorPosition {
+    /// 0-based byte offset into the input.
+    pub byte_offset: usize,
+    /// 0-based character offset into the input.
+    pub char_offset: usize,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+}
+
+impl ParseError {
+    /// Converts a libpg_query error struct, resolving its cursor position against `input`.
+    ///
+    /// # Safety
+    ///
+    /// All string pointers in `error` must either be null or point to valid C strings.
+    pub(crate) unsafe fn from_raw(error: &PgQueryError, input: &str) -> Self {
+        let string = |ptr: *mut std::os::raw::c_char| (!ptr.is_null()).then(|| CStr::from_ptr(ptr).to_string_lossy().to_string());
+        let message = string(error.message).unwrap_or_default();
+        let funcname = string(error.funcname);
+        let filename = string(error.filename);
+        let position = ErrorPosition::new(input, error.cursorpos);
+        let snippet = position.map(|position| position.snippet(input));
+        let sqlstate = sqlstate(funcname.as_deref());
+        Self { message, sqlstate, position, snippet, funcname, filename, lineno: error.lineno, cursorpos: error.cursorpos }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        Self { message: message.to_string(), ..Self::default() }
+    }
+}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        Self { message, ..Self::default() }
+    }
+}
+
+impl ErrorPosition {
+    fn new(input: &str, cursorpos: i32) -> Option<Self> {
+        if cursorpos <= 0 {
+            return None;
+        }
+        let char_offset = cursorpos as usize - 1;
+        // Postgres reports a position one past the last character for errors at the end of the input
+        let byte_offset = match input.char_indices().nth(char_offset) {
+            Some((idx, _)) => idx,
+            None if input.chars().count() == char_offset => input.len(),
+            None => return None,
+        };
+        let line_start = input[..byte_offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line = input[..byte_offset].matches('\n').count() + 1;
+        let column = input[line_start..byte_offset].chars().count() + 1;
+        Some(Self { byte_offset, char_offset, line, column })
+    }
+
+    fn snippet(&self, input: &str) -> String {
+        let line_start = input[..self.byte_offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[line_start..].find('\n').map_or(input.len(), |idx| line_start + idx);
+        let text = input[line_start..line_end].trim_end_matches('\r');
+        let prefix = format!("LINE {}: ", self.line);
+        let padding = prefix.len() + self.column - 1;
+        format!("{prefix}{text}\n{}^", " ".repeat(padding))
+    }
+}
+
+// libpg_query doesn't pass through the error code, so it's only reported for the functions that always raise errors
+// with the same code. Other errors in the grammar and scanner use a variety of codes, e.g. `0A000` for unsupported
+// features, so they are left without one.
+fn sqlstate(funcname: Option<&str>) -> Option<&'static str> {
+    match funcname? {
+        "check_stack_depth" => Some("54001"),                // statement_too_complex
+        "scanner_yyerror" | "base_yyerror" => Some("42601"), // syntax_error
+        _ => None,
+    }
+}
@@ -0,0 +1,2096 @@
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+use crate::protobuf::AExprKind;
+use crate::*;
+
+// Matches PG_QUERY_FINGERPRINT_VERSION, which libpg_query uses as the hash seed
+const FINGERPRINT_VERSION: u64 = 3;
+
+// Lists whose order doesn't change the fingerprint, and whose duplicate entries are only counted once
+const UNORDERED_LISTS: [&str; 6] = ["fromClause", "targetList", "cols", "rexpr", "valuesLists", "args"];
+
+// Nesting beyond this depth is left out of the fingerprint, as in libpg_query
+const MAX_DEPTH: usize = 100;
+
+impl protobuf::ParseResult {
+    /// Fingerprints the parse tree without calling into libpg_query, returning the same value as
+    /// [fingerprint] for the query it was parsed from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let result = pg_query::parse("SELECT * FROM contacts WHERE name='Paul'").unwrap();
+    /// assert_eq!(result.protobuf.fingerprint().hex, "0e2581a461ece536");
+    /// ```
+    pub fn fingerprint(&self) -> Fingerprint {
+        let mut fp = Fingerprinter::default();
+        for stmt in &self.stmts {
+            fp.node(NodeRef::RawStmt(stmt), None, 0);
+        }
+        fp.finish()
+    }
+}
+
+impl protobuf::RawStmt {
+    /// Fingerprints the statement, returning the same value as [fingerprint] for a query consisting of
+    /// only this statement.
+    pub fn fingerprint(&self) -> Fingerprint {
+        let mut fp = Fingerprinter::default();
+        fp.node(NodeRef::RawStmt(self), None, 0);
+        fp.finish()
+    }
+}
+
+impl protobuf::Node {
+    /// Fingerprints the node the way libpg_query does for nodes within a query, e.g. to group
+    /// expressions that only differ in their constants.
+    ///
+    /// Fingerprinting a statement node returns a different value than [fingerprint] for the statement's SQL, which
+    /// includes the enclosing `RawStmt`; use [protobuf::RawStmt::fingerprint] for that.
+    pub fn fingerprint(&self) -> Fingerprint {
+        self.node.as_ref().map_or_else(|| Fingerprinter::default().finish(), |node| node.to_ref().fingerprint())
+    }
+}
+
+impl NodeRef<'_> {
+    /// Fingerprints the node, see [protobuf::Node::fingerprint].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let a = pg_query::parse_expr("lower(email) = 'a@example.com'").unwrap();
+    /// let b = pg_query::parse_expr("LOWER(email) = $1").unwrap();
+    /// assert_eq!(a.to_ref().fingerprint().hex, b.to_ref().fingerprint().hex);
+    /// ```
+    pub fn fingerprint(&self) -> Fingerprint {
+        let mut fp = Fingerprinter::default();
+        fp.node(*self, None, 0);
+        fp.finish()
+    }
+}
+
+impl ParseResult {
+    /// Fingerprints the parse tree without calling into libpg_query, see
+    /// [protobuf::ParseResult::fingerprint].
+    pub fn fingerprint(&self) -> Fingerprint {
+        self.protobuf.fingerprint()
+    }
+}
+
+// The field of the parent node that leads to a node
+#[derive(Clone, Copy)]
+struct Field {
+    parent: &'static str,
+    name: &'static str,
+}
+
+// Collects the bytes libpg_query feeds into the hash; the fingerprint is the hash of their concatenation
+#[derive(Default)]
+struct Fingerprinter {
+    buffer: Vec<u8>,
+}
+
+impl Fingerprinter {
+    fn finish(&self) -> Fingerprint {
+        let value = self.hash();
+        Fingerprint { value, hex: format!("{value:016x}") }
+    }
+
+    fn hash(&self) -> u64 {
+        xxh3_64_with_seed(&self.buffer, FINGERPRINT_VERSION)
+    }
+
+    fn string(&mut self, value: &str) {
+        self.buffer.extend_from_slice(value.as_bytes());
+    }
+
+    fn node(&mut self, node: NodeRef, field: Option<Field>, depth: usize) {
+        if depth >= MAX_DEPTH {
+            return;
+        }
+        match node {
+            NodeRef::List(list) => self.list(&list.items, field, depth),
+            NodeRef::Integer(n) => {
+                if n.ival != 0 {
+                    self.string("Integer");
+                    self.string("ival");
+                    self.string(&n.ival.to_string());
+                }
+            }
+            NodeRef::Float(n) => {
+                if !n.fval.is_empty() {
+                    self.string("Float");
+                    self.string("str");
+                    self.string(&n.fval);
+                }
+            }
+            NodeRef::Boolean(n) => {
+                self.string("Boolean");
+                self.string("boolval");
+                self.string(if n.boolval { "true" } else { "false" });
+            }
+            NodeRef::String(n) => {
+                self.string("String");
+                self.string("str");
+                self.string(&n.sval);
+            }
+            NodeRef::BitString(n) => {
+                self.string("BitString");
+                self.string("str");
+                self.string(&n.bsval);
+            }
+            // Constants and parameters, including type casts of them, are left out entirely
+            NodeRef::AConst(_) | NodeRef::ParamRef(_) | NodeRef::SetToDefault(_) | NodeRef::Alias(_) => {}
+            NodeRef::TypeCast(cast)
+                if matches!(cast.arg.as_deref().and_then(|n| n.node.as_ref()), Some(NodeEnum::AConst(_) | NodeEnum::ParamRef(_))) => {}
+            node => {
+                self.string(c_type_name(node));
+                fields(self, node, field, depth);
+            }
+        }
+    }
+
+    fn list(&mut self, items: &[protobuf::Node], field: Option<Field>, depth: usize) {
+        let items = items.iter().filter_map(|item| item.node.as_ref());
+        if !field.is_some_and(|f| UNORDERED_LISTS.contains(&f.name)) {
+            for item in items {
+                self.node(item.to_ref(), field, depth + 1);
+            }
+            return;
+        }
+        // Like libpg_query, feed in the sorted hashes of the distinct entries rather than the entries themselves
+        let mut hashes: Vec<u64> = items
+            .map(|item| {
+                let mut fp = Fingerprinter::default();
+                fp.node(item.to_ref(), field, depth + 1);
+                fp.hash()
+            })
+            .collect();
+        hashes.sort_unstable();
+        hashes.dedup();
+        for hash in hashes {
+            self.buffer.extend_from_slice(&hash.to_ne_bytes());
+        }
+    }
+
+    // Adds the field name and the child, or nothing if the child doesn't add anything itself
+    fn child(&mut self, name: &'static str, add: impl FnOnce(&mut Self)) {
+        let start = self.buffer.len();
+        self.string(name);
+        let end = self.buffer.len();
+        add(self);
+        if self.buffer.len() == end {
+            self.buffer.truncate(start);
+        }
+    }
+
+    fn node_field(&mut self, parent: &'static str, name: &'static str, node: Option<&protobuf::Node>, depth: usize) {
+        if let Some(node) = node.and_then(|n| n.node.as_ref()) {
+            self.child(name, |fp| fp.node(node.to_ref(), Some(Field { parent, name }), depth + 1));
+        }
+    }
+
+    // A field holding a specific node type, which leaves out the type name
+    fn typed_field(&mut self, parent: &'static str, name: &'static str, node: Option<NodeRef>, depth: usize) {
+        if let Some(node) = node {
+            self.child(name, |fp| fields(fp, node, Some(Field { parent, name }), depth + 1));
+        }
+    }
+
+    fn list_field(&mut self, parent: &'static str, name: &'static str, items: &[protobuf::Node], depth: usize) {
+        if items.is_empty() {
+            return;
+        }
+        let start = self.buffer.len();
+        self.child(name, |fp| {
+            if depth + 1 < MAX_DEPTH {
+                fp.list(items, Some(Field { parent, name }), depth + 1)
+            }
+        });
+        // A list holding a single empty entry, e.g. for `SELECT DISTINCT`, still counts
+        if items.len() == 1 && items[0].node.is_none() && self.buffer.len() == start {
+            self.string(name);
+        }
+    }
+
+    fn str_field(&mut self, name: &str, value: &str) {
+        if !value.is_empty() {
+            self.string(name);
+            self.string(value);
+        }
+    }
+
+    fn int_field(&mut self, name: &str, value: i64) {
+        if value != 0 {
+            self.string(name);
+            self.string(&value.to_string());
+        }
+    }
+
+    fn ints_field(&mut self, name: &str, values: &[u64]) {
+        if !values.is_empty() {
+            self.string(name);
+            for value in values {
+                self.string(&value.to_string());
+            }
+        }
+    }
+
+    fn float_field(&mut self, name: &str, value: f64) {
+        if value != 0.0 {
+            self.string(name);
+            self.string(&format!("{value:.6}"));
+        }
+    }
+
+    fn bool_field(&mut self, name: &str, value: bool) {
+        if value {
+            self.string(name);
+            self.string("true");
+        }
+    }
+
+    fn enum_field(&mut self, name: &str, value: &str) {
+        self.string(name);
+        self.string(value);
+    }
+}
+
+// Leaves out runs of two or more digits from table names, so that partitions such as `events_20240101` and
+// `events_20240102` are fingerprinted as the same table
+fn without_numbers(name: &str) -> String {
+    let bytes = name.as_bytes();
+    let is_digit = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    name.char_indices().filter(|&(i, _)| !(is_digit(i) && ((i > 0 && is_digit(i - 1)) || is_digit(i + 1)))).map(|(_, c)| c).collect()
+}
+
+// BEGIN GENERATED by scripts/generate.py from src/protobuf.rs, do not edit by hand
+// Adds the fields of `node` in the order libpg_query hashes them, which is by their name in the C structs.
+fn fields(fp: &mut Fingerprinter, node: NodeRef, field: Option<Field>, depth: usize) {
+    match node {
+        NodeRef::Alias(_) => {}
+        NodeRef::RangeVar(n) => {
+            fp.typed_field("RangeVar", "alias", n.alias.as_ref().map(NodeRef::Alias), depth);
+            fp.str_field("catalogname", &n.catalogname);
+            fp.bool_field("inh", n.inh);
+            fp.str_field("relname", &without_numbers(&n.relname));
+            fp.str_field("relpersistence", &n.relpersistence);
+            fp.str_field("schemaname", &n.schemaname);
+        }
+        NodeRef::TableFunc(n) => {
+            fp.list_field("TableFunc", "colcollations", &n.colcollations, depth);
+            fp.list_field("TableFunc", "coldefexprs", &n.coldefexprs, depth);
+            fp.list_field("TableFunc", "colexprs", &n.colexprs, depth);
+            fp.list_field("TableFunc", "colnames", &n.colnames, depth);
+            fp.list_field("TableFunc", "coltypes", &n.coltypes, depth);
+            fp.list_field("TableFunc", "coltypmods", &n.coltypmods, depth);
+            fp.list_field("TableFunc", "colvalexprs", &n.colvalexprs, depth);
+            fp.node_field("TableFunc", "docexpr", n.docexpr.as_deref(), depth);
+            fp.enum_field("functype", protobuf::TableFuncType::try_from(n.functype).map_or("", |e| e.as_str_name()));
+            fp.ints_field("notnulls", &n.notnulls);
+            fp.list_field("TableFunc", "ns_names", &n.ns_names, depth);
+            fp.list_field("TableFunc", "ns_uris", &n.ns_uris, depth);
+            fp.int_field("ordinalitycol", n.ordinalitycol.into());
+            fp.list_field("TableFunc", "passingvalexprs", &n.passingvalexprs, depth);
+            fp.node_field("TableFunc", "plan", n.plan.as_deref(), depth);
+            fp.node_field("TableFunc", "rowexpr", n.rowexpr.as_deref(), depth);
+        }
+        NodeRef::IntoClause(n) => {
+            fp.str_field("accessMethod", &n.access_method);
+            fp.list_field("IntoClause", "colNames", &n.col_names, depth);
+            fp.enum_field("onCommit", protobuf::OnCommitAction::try_from(n.on_commit).map_or("", |e| e.as_str_name()));
+            fp.list_field("IntoClause", "options", &n.options, depth);
+            fp.typed_field("IntoClause", "rel", n.rel.as_ref().map(NodeRef::RangeVar), depth);
+            fp.bool_field("skipData", n.skip_data);
+            fp.str_field("tableSpaceName", &n.table_space_name);
+            fp.node_field("IntoClause", "viewQuery", n.view_query.as_deref(), depth);
+        }
+        NodeRef::Var(n) => {
+            fp.int_field("varattno", n.varattno.into());
+            fp.int_field("varcollid", n.varcollid.into());
+            fp.int_field("varlevelsup", n.varlevelsup.into());
+            fp.int_field("varno", n.varno.into());
+            fp.ints_field("varnullingrels", &n.varnullingrels);
+            fp.int_field("vartype", n.vartype.into());
+            fp.int_field("vartypmod", n.vartypmod.into());
+            fp.node_field("Var", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::Param(n) => {
+            fp.int_field("paramcollid", n.paramcollid.into());
+            fp.int_field("paramid", n.paramid.into());
+            fp.enum_field("paramkind", protobuf::ParamKind::try_from(n.paramkind).map_or("", |e| e.as_str_name()));
+            fp.int_field("paramtype", n.paramtype.into());
+            fp.int_field("paramtypmod", n.paramtypmod.into());
+            fp.node_field("Param", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::Aggref(n) => {
+            fp.list_field("Aggref", "aggargtypes", &n.aggargtypes, depth);
+            fp.int_field("aggcollid", n.aggcollid.into());
+            fp.list_field("Aggref", "aggdirectargs", &n.aggdirectargs, depth);
+            fp.list_field("Aggref", "aggdistinct", &n.aggdistinct, depth);
+            fp.node_field("Aggref", "aggfilter", n.aggfilter.as_deref(), depth);
+            fp.int_field("aggfnoid", n.aggfnoid.into());
+            fp.str_field("aggkind", &n.aggkind);
+            fp.int_field("agglevelsup", n.agglevelsup.into());
+            fp.int_field("aggno", n.aggno.into());
+            fp.list_field("Aggref", "aggorder", &n.aggorder, depth);
+            fp.enum_field("aggsplit", protobuf::AggSplit::try_from(n.aggsplit).map_or("", |e| e.as_str_name()));
+            fp.bool_field("aggstar", n.aggstar);
+            fp.int_field("aggtransno", n.aggtransno.into());
+            fp.int_field("aggtype", n.aggtype.into());
+            fp.bool_field("aggvariadic", n.aggvariadic);
+            fp.list_field("Aggref", "args", &n.args, depth);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.node_field("Aggref", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::GroupingFunc(n) => {
+            fp.int_field("agglevelsup", n.agglevelsup.into());
+            fp.list_field("GroupingFunc", "args", &n.args, depth);
+            fp.list_field("GroupingFunc", "refs", &n.refs, depth);
+            fp.node_field("GroupingFunc", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::WindowFunc(n) => {
+            fp.node_field("WindowFunc", "aggfilter", n.aggfilter.as_deref(), depth);
+            fp.list_field("WindowFunc", "args", &n.args, depth);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.list_field("WindowFunc", "runCondition", &n.run_condition, depth);
+            fp.bool_field("winagg", n.winagg);
+            fp.int_field("wincollid", n.wincollid.into());
+            fp.int_field("winfnoid", n.winfnoid.into());
+            fp.int_field("winref", n.winref.into());
+            fp.bool_field("winstar", n.winstar);
+            fp.int_field("wintype", n.wintype.into());
+            fp.node_field("WindowFunc", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::WindowFuncRunCondition(n) => {
+            fp.node_field("WindowFuncRunCondition", "arg", n.arg.as_deref(), depth);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.int_field("opno", n.opno.into());
+            fp.bool_field("wfunc_left", n.wfunc_left);
+            fp.node_field("WindowFuncRunCondition", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::MergeSupportFunc(n) => {
+            fp.int_field("msfcollid", n.msfcollid.into());
+            fp.int_field("msftype", n.msftype.into());
+            fp.node_field("MergeSupportFunc", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::SubscriptingRef(n) => {
+            fp.node_field("SubscriptingRef", "refassgnexpr", n.refassgnexpr.as_deref(), depth);
+            fp.int_field("refcollid", n.refcollid.into());
+            fp.int_field("refcontainertype", n.refcontainertype.into());
+            fp.int_field("refelemtype", n.refelemtype.into());
+            fp.node_field("SubscriptingRef", "refexpr", n.refexpr.as_deref(), depth);
+            fp.list_field("SubscriptingRef", "reflowerindexpr", &n.reflowerindexpr, depth);
+            fp.int_field("refrestype", n.refrestype.into());
+            fp.int_field("reftypmod", n.reftypmod.into());
+            fp.list_field("SubscriptingRef", "refupperindexpr", &n.refupperindexpr, depth);
+            fp.node_field("SubscriptingRef", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::FuncExpr(n) => {
+            fp.list_field("FuncExpr", "args", &n.args, depth);
+            fp.int_field("funccollid", n.funccollid.into());
+            fp.enum_field("funcformat", protobuf::CoercionForm::try_from(n.funcformat).map_or("", |e| e.as_str_name()));
+            fp.int_field("funcid", n.funcid.into());
+            fp.int_field("funcresulttype", n.funcresulttype.into());
+            fp.bool_field("funcretset", n.funcretset);
+            fp.bool_field("funcvariadic", n.funcvariadic);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.node_field("FuncExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::NamedArgExpr(n) => {
+            fp.node_field("NamedArgExpr", "arg", n.arg.as_deref(), depth);
+            fp.int_field("argnumber", n.argnumber.into());
+            fp.str_field("name", &n.name);
+            fp.node_field("NamedArgExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::OpExpr(n) => {
+            fp.list_field("OpExpr", "args", &n.args, depth);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.int_field("opcollid", n.opcollid.into());
+            fp.int_field("opno", n.opno.into());
+            fp.int_field("opresulttype", n.opresulttype.into());
+            fp.bool_field("opretset", n.opretset);
+            fp.node_field("OpExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::DistinctExpr(n) => {
+            fp.list_field("DistinctExpr", "args", &n.args, depth);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.int_field("opcollid", n.opcollid.into());
+            fp.int_field("opno", n.opno.into());
+            fp.int_field("opresulttype", n.opresulttype.into());
+            fp.bool_field("opretset", n.opretset);
+            fp.node_field("DistinctExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::NullIfExpr(n) => {
+            fp.list_field("NullIfExpr", "args", &n.args, depth);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.int_field("opcollid", n.opcollid.into());
+            fp.int_field("opno", n.opno.into());
+            fp.int_field("opresulttype", n.opresulttype.into());
+            fp.bool_field("opretset", n.opretset);
+            fp.node_field("NullIfExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::ScalarArrayOpExpr(n) => {
+            fp.list_field("ScalarArrayOpExpr", "args", &n.args, depth);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.int_field("opno", n.opno.into());
+            fp.bool_field("useOr", n.use_or);
+            fp.node_field("ScalarArrayOpExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::BoolExpr(n) => {
+            fp.list_field("BoolExpr", "args", &n.args, depth);
+            fp.enum_field("boolop", protobuf::BoolExprType::try_from(n.boolop).map_or("", |e| e.as_str_name()));
+            fp.node_field("BoolExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::SubLink(n) => {
+            fp.list_field("SubLink", "operName", &n.oper_name, depth);
+            fp.int_field("subLinkId", n.sub_link_id.into());
+            fp.enum_field("subLinkType", protobuf::SubLinkType::try_from(n.sub_link_type).map_or("", |e| e.as_str_name()));
+            fp.node_field("SubLink", "subselect", n.subselect.as_deref(), depth);
+            fp.node_field("SubLink", "testexpr", n.testexpr.as_deref(), depth);
+            fp.node_field("SubLink", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::SubPlan(n) => {
+            fp.list_field("SubPlan", "args", &n.args, depth);
+            fp.int_field("firstColCollation", n.first_col_collation.into());
+            fp.int_field("firstColType", n.first_col_type.into());
+            fp.int_field("firstColTypmod", n.first_col_typmod.into());
+            fp.list_field("SubPlan", "parParam", &n.par_param, depth);
+            fp.bool_field("parallel_safe", n.parallel_safe);
+            fp.list_field("SubPlan", "paramIds", &n.param_ids, depth);
+            fp.float_field("per_call_cost", n.per_call_cost);
+            fp.int_field("plan_id", n.plan_id.into());
+            fp.str_field("plan_name", &n.plan_name);
+            fp.list_field("SubPlan", "setParam", &n.set_param, depth);
+            fp.float_field("startup_cost", n.startup_cost);
+            fp.enum_field("subLinkType", protobuf::SubLinkType::try_from(n.sub_link_type).map_or("", |e| e.as_str_name()));
+            fp.node_field("SubPlan", "testexpr", n.testexpr.as_deref(), depth);
+            fp.bool_field("unknownEqFalse", n.unknown_eq_false);
+            fp.bool_field("useHashTable", n.use_hash_table);
+            fp.node_field("SubPlan", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::AlternativeSubPlan(n) => {
+            fp.list_field("AlternativeSubPlan", "subplans", &n.subplans, depth);
+            fp.node_field("AlternativeSubPlan", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::FieldSelect(n) => {
+            fp.node_field("FieldSelect", "arg", n.arg.as_deref(), depth);
+            fp.int_field("fieldnum", n.fieldnum.into());
+            fp.int_field("resultcollid", n.resultcollid.into());
+            fp.int_field("resulttype", n.resulttype.into());
+            fp.int_field("resulttypmod", n.resulttypmod.into());
+            fp.node_field("FieldSelect", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::FieldStore(n) => {
+            fp.node_field("FieldStore", "arg", n.arg.as_deref(), depth);
+            fp.list_field("FieldStore", "fieldnums", &n.fieldnums, depth);
+            fp.list_field("FieldStore", "newvals", &n.newvals, depth);
+            fp.int_field("resulttype", n.resulttype.into());
+            fp.node_field("FieldStore", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::RelabelType(n) => {
+            fp.node_field("RelabelType", "arg", n.arg.as_deref(), depth);
+            fp.enum_field("relabelformat", protobuf::CoercionForm::try_from(n.relabelformat).map_or("", |e| e.as_str_name()));
+            fp.int_field("resultcollid", n.resultcollid.into());
+            fp.int_field("resulttype", n.resulttype.into());
+            fp.int_field("resulttypmod", n.resulttypmod.into());
+            fp.node_field("RelabelType", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::CoerceViaIo(n) => {
+            fp.node_field("CoerceViaIO", "arg", n.arg.as_deref(), depth);
+            fp.enum_field("coerceformat", protobuf::CoercionForm::try_from(n.coerceformat).map_or("", |e| e.as_str_name()));
+            fp.int_field("resultcollid", n.resultcollid.into());
+            fp.int_field("resulttype", n.resulttype.into());
+            fp.node_field("CoerceViaIO", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::ArrayCoerceExpr(n) => {
+            fp.node_field("ArrayCoerceExpr", "arg", n.arg.as_deref(), depth);
+            fp.enum_field("coerceformat", protobuf::CoercionForm::try_from(n.coerceformat).map_or("", |e| e.as_str_name()));
+            fp.node_field("ArrayCoerceExpr", "elemexpr", n.elemexpr.as_deref(), depth);
+            fp.int_field("resultcollid", n.resultcollid.into());
+            fp.int_field("resulttype", n.resulttype.into());
+            fp.int_field("resulttypmod", n.resulttypmod.into());
+            fp.node_field("ArrayCoerceExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::ConvertRowtypeExpr(n) => {
+            fp.node_field("ConvertRowtypeExpr", "arg", n.arg.as_deref(), depth);
+            fp.enum_field("convertformat", protobuf::CoercionForm::try_from(n.convertformat).map_or("", |e| e.as_str_name()));
+            fp.int_field("resulttype", n.resulttype.into());
+            fp.node_field("ConvertRowtypeExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::CollateExpr(n) => {
+            fp.node_field("CollateExpr", "arg", n.arg.as_deref(), depth);
+            fp.int_field("collOid", n.coll_oid.into());
+            fp.node_field("CollateExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::CaseExpr(n) => {
+            fp.node_field("CaseExpr", "arg", n.arg.as_deref(), depth);
+            fp.list_field("CaseExpr", "args", &n.args, depth);
+            fp.int_field("casecollid", n.casecollid.into());
+            fp.int_field("casetype", n.casetype.into());
+            fp.node_field("CaseExpr", "defresult", n.defresult.as_deref(), depth);
+            fp.node_field("CaseExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::CaseWhen(n) => {
+            fp.node_field("CaseWhen", "expr", n.expr.as_deref(), depth);
+            fp.node_field("CaseWhen", "result", n.result.as_deref(), depth);
+            fp.node_field("CaseWhen", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::CaseTestExpr(n) => {
+            fp.int_field("collation", n.collation.into());
+            fp.int_field("typeId", n.type_id.into());
+            fp.int_field("typeMod", n.type_mod.into());
+            fp.node_field("CaseTestExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::ArrayExpr(n) => {
+            fp.int_field("array_collid", n.array_collid.into());
+            fp.int_field("array_typeid", n.array_typeid.into());
+            fp.int_field("element_typeid", n.element_typeid.into());
+            fp.list_field("ArrayExpr", "elements", &n.elements, depth);
+            fp.bool_field("multidims", n.multidims);
+            fp.node_field("ArrayExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::RowExpr(n) => {
+            fp.list_field("RowExpr", "args", &n.args, depth);
+            fp.list_field("RowExpr", "colnames", &n.colnames, depth);
+            fp.enum_field("row_format", protobuf::CoercionForm::try_from(n.row_format).map_or("", |e| e.as_str_name()));
+            fp.int_field("row_typeid", n.row_typeid.into());
+            fp.node_field("RowExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::RowCompareExpr(n) => {
+            fp.list_field("RowCompareExpr", "inputcollids", &n.inputcollids, depth);
+            fp.list_field("RowCompareExpr", "largs", &n.largs, depth);
+            fp.list_field("RowCompareExpr", "opfamilies", &n.opfamilies, depth);
+            fp.list_field("RowCompareExpr", "opnos", &n.opnos, depth);
+            fp.list_field("RowCompareExpr", "rargs", &n.rargs, depth);
+            fp.enum_field("rctype", protobuf::RowCompareType::try_from(n.rctype).map_or("", |e| e.as_str_name()));
+            fp.node_field("RowCompareExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::CoalesceExpr(n) => {
+            fp.list_field("CoalesceExpr", "args", &n.args, depth);
+            fp.int_field("coalescecollid", n.coalescecollid.into());
+            fp.int_field("coalescetype", n.coalescetype.into());
+            fp.node_field("CoalesceExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::MinMaxExpr(n) => {
+            fp.list_field("MinMaxExpr", "args", &n.args, depth);
+            fp.int_field("inputcollid", n.inputcollid.into());
+            fp.int_field("minmaxcollid", n.minmaxcollid.into());
+            fp.int_field("minmaxtype", n.minmaxtype.into());
+            fp.enum_field("op", protobuf::MinMaxOp::try_from(n.op).map_or("", |e| e.as_str_name()));
+            fp.node_field("MinMaxExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::SqlvalueFunction(n) => {
+            fp.enum_field("op", protobuf::SqlValueFunctionOp::try_from(n.op).map_or("", |e| e.as_str_name()));
+            fp.int_field("type", n.r#type.into());
+            fp.int_field("typmod", n.typmod.into());
+            fp.node_field("SQLValueFunction", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::XmlExpr(n) => {
+            fp.list_field("XmlExpr", "arg_names", &n.arg_names, depth);
+            fp.list_field("XmlExpr", "args", &n.args, depth);
+            fp.bool_field("indent", n.indent);
+            fp.str_field("name", &n.name);
+            fp.list_field("XmlExpr", "named_args", &n.named_args, depth);
+            fp.enum_field("op", protobuf::XmlExprOp::try_from(n.op).map_or("", |e| e.as_str_name()));
+            fp.int_field("type", n.r#type.into());
+            fp.int_field("typmod", n.typmod.into());
+            fp.enum_field("xmloption", protobuf::XmlOptionType::try_from(n.xmloption).map_or("", |e| e.as_str_name()));
+            fp.node_field("XmlExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::JsonFormat(n) => {
+            fp.enum_field("encoding", protobuf::JsonEncoding::try_from(n.encoding).map_or("", |e| e.as_str_name()));
+            fp.enum_field("format_type", protobuf::JsonFormatType::try_from(n.format_type).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::JsonReturning(n) => {
+            fp.typed_field("JsonReturning", "format", n.format.as_ref().map(NodeRef::JsonFormat), depth);
+            fp.int_field("typid", n.typid.into());
+            fp.int_field("typmod", n.typmod.into());
+        }
+        NodeRef::JsonValueExpr(n) => {
+            fp.typed_field("JsonValueExpr", "format", n.format.as_ref().map(NodeRef::JsonFormat), depth);
+            fp.node_field("JsonValueExpr", "formatted_expr", n.formatted_expr.as_deref(), depth);
+            fp.node_field("JsonValueExpr", "raw_expr", n.raw_expr.as_deref(), depth);
+        }
+        NodeRef::JsonConstructorExpr(n) => {
+            fp.bool_field("absent_on_null", n.absent_on_null);
+            fp.list_field("JsonConstructorExpr", "args", &n.args, depth);
+            fp.node_field("JsonConstructorExpr", "coercion", n.coercion.as_deref(), depth);
+            fp.node_field("JsonConstructorExpr", "func", n.func.as_deref(), depth);
+            fp.typed_field("JsonConstructorExpr", "returning", n.returning.as_ref().map(NodeRef::JsonReturning), depth);
+            fp.enum_field("type", protobuf::JsonConstructorType::try_from(n.r#type).map_or("", |e| e.as_str_name()));
+            fp.bool_field("unique", n.unique);
+            fp.node_field("JsonConstructorExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::JsonIsPredicate(n) => {
+            fp.node_field("JsonIsPredicate", "expr", n.expr.as_deref(), depth);
+            fp.typed_field("JsonIsPredicate", "format", n.format.as_ref().map(NodeRef::JsonFormat), depth);
+            fp.enum_field("item_type", protobuf::JsonValueType::try_from(n.item_type).map_or("", |e| e.as_str_name()));
+            fp.bool_field("unique_keys", n.unique_keys);
+        }
+        NodeRef::JsonBehavior(n) => {
+            fp.enum_field("btype", protobuf::JsonBehaviorType::try_from(n.btype).map_or("", |e| e.as_str_name()));
+            fp.bool_field("coerce", n.coerce);
+            fp.node_field("JsonBehavior", "expr", n.expr.as_deref(), depth);
+        }
+        NodeRef::JsonExpr(n) => {
+            fp.int_field("collation", n.collation.into());
+            fp.str_field("column_name", &n.column_name);
+            fp.typed_field("JsonExpr", "format", n.format.as_ref().map(NodeRef::JsonFormat), depth);
+            fp.node_field("JsonExpr", "formatted_expr", n.formatted_expr.as_deref(), depth);
+            fp.bool_field("omit_quotes", n.omit_quotes);
+            fp.typed_field("JsonExpr", "on_empty", n.on_empty.as_deref().map(NodeRef::JsonBehavior), depth);
+            fp.typed_field("JsonExpr", "on_error", n.on_error.as_deref().map(NodeRef::JsonBehavior), depth);
+            fp.enum_field("op", protobuf::JsonExprOp::try_from(n.op).map_or("", |e| e.as_str_name()));
+            fp.list_field("JsonExpr", "passing_names", &n.passing_names, depth);
+            fp.list_field("JsonExpr", "passing_values", &n.passing_values, depth);
+            fp.node_field("JsonExpr", "path_spec", n.path_spec.as_deref(), depth);
+            fp.typed_field("JsonExpr", "returning", n.returning.as_ref().map(NodeRef::JsonReturning), depth);
+            fp.bool_field("use_io_coercion", n.use_io_coercion);
+            fp.bool_field("use_json_coercion", n.use_json_coercion);
+            fp.enum_field("wrapper", protobuf::JsonWrapper::try_from(n.wrapper).map_or("", |e| e.as_str_name()));
+            fp.node_field("JsonExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::JsonTablePath(n) => {
+            fp.str_field("name", &n.name);
+        }
+        NodeRef::JsonTablePathScan(n) => {
+            fp.node_field("JsonTablePathScan", "child", n.child.as_deref(), depth);
+            fp.int_field("colMax", n.col_max.into());
+            fp.int_field("colMin", n.col_min.into());
+            fp.bool_field("errorOnError", n.error_on_error);
+            fp.typed_field("JsonTablePathScan", "path", n.path.as_ref().map(NodeRef::JsonTablePath), depth);
+            fp.node_field("JsonTablePathScan", "plan", n.plan.as_deref(), depth);
+        }
+        NodeRef::JsonTableSiblingJoin(n) => {
+            fp.node_field("JsonTableSiblingJoin", "lplan", n.lplan.as_deref(), depth);
+            fp.node_field("JsonTableSiblingJoin", "plan", n.plan.as_deref(), depth);
+            fp.node_field("JsonTableSiblingJoin", "rplan", n.rplan.as_deref(), depth);
+        }
+        NodeRef::NullTest(n) => {
+            fp.node_field("NullTest", "arg", n.arg.as_deref(), depth);
+            fp.bool_field("argisrow", n.argisrow);
+            fp.enum_field("nulltesttype", protobuf::NullTestType::try_from(n.nulltesttype).map_or("", |e| e.as_str_name()));
+            fp.node_field("NullTest", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::BooleanTest(n) => {
+            fp.node_field("BooleanTest", "arg", n.arg.as_deref(), depth);
+            fp.enum_field("booltesttype", protobuf::BoolTestType::try_from(n.booltesttype).map_or("", |e| e.as_str_name()));
+            fp.node_field("BooleanTest", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::MergeAction(n) => {
+            fp.enum_field("commandType", protobuf::CmdType::try_from(n.command_type).map_or("", |e| e.as_str_name()));
+            fp.enum_field("matchKind", protobuf::MergeMatchKind::try_from(n.match_kind).map_or("", |e| e.as_str_name()));
+            fp.enum_field("override", protobuf::OverridingKind::try_from(n.r#override).map_or("", |e| e.as_str_name()));
+            fp.node_field("MergeAction", "qual", n.qual.as_deref(), depth);
+            fp.list_field("MergeAction", "targetList", &n.target_list, depth);
+            fp.list_field("MergeAction", "updateColnos", &n.update_colnos, depth);
+        }
+        NodeRef::CoerceToDomain(n) => {
+            fp.node_field("CoerceToDomain", "arg", n.arg.as_deref(), depth);
+            fp.enum_field("coercionformat", protobuf::CoercionForm::try_from(n.coercionformat).map_or("", |e| e.as_str_name()));
+            fp.int_field("resultcollid", n.resultcollid.into());
+            fp.int_field("resulttype", n.resulttype.into());
+            fp.int_field("resulttypmod", n.resulttypmod.into());
+            fp.node_field("CoerceToDomain", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::CoerceToDomainValue(n) => {
+            fp.int_field("collation", n.collation.into());
+            fp.int_field("typeId", n.type_id.into());
+            fp.int_field("typeMod", n.type_mod.into());
+            fp.node_field("CoerceToDomainValue", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::SetToDefault(_) => {}
+        NodeRef::CurrentOfExpr(n) => {
+            fp.str_field("cursor_name", &n.cursor_name);
+            fp.int_field("cursor_param", n.cursor_param.into());
+            fp.int_field("cvarno", n.cvarno.into());
+            fp.node_field("CurrentOfExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::NextValueExpr(n) => {
+            fp.int_field("seqid", n.seqid.into());
+            fp.int_field("typeId", n.type_id.into());
+            fp.node_field("NextValueExpr", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::InferenceElem(n) => {
+            fp.node_field("InferenceElem", "expr", n.expr.as_deref(), depth);
+            fp.int_field("infercollid", n.infercollid.into());
+            fp.int_field("inferopclass", n.inferopclass.into());
+            fp.node_field("InferenceElem", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::TargetEntry(n) => {
+            fp.node_field("TargetEntry", "expr", n.expr.as_deref(), depth);
+            fp.bool_field("resjunk", n.resjunk);
+            fp.str_field("resname", &n.resname);
+            fp.int_field("resno", n.resno.into());
+            fp.int_field("resorigcol", n.resorigcol.into());
+            fp.int_field("resorigtbl", n.resorigtbl.into());
+            fp.int_field("ressortgroupref", n.ressortgroupref.into());
+            fp.node_field("TargetEntry", "xpr", n.xpr.as_deref(), depth);
+        }
+        NodeRef::RangeTblRef(n) => {
+            fp.int_field("rtindex", n.rtindex.into());
+        }
+        NodeRef::JoinExpr(n) => {
+            fp.typed_field("JoinExpr", "alias", n.alias.as_ref().map(NodeRef::Alias), depth);
+            fp.bool_field("isNatural", n.is_natural);
+            fp.typed_field("JoinExpr", "join_using_alias", n.join_using_alias.as_ref().map(NodeRef::Alias), depth);
+            fp.enum_field("jointype", protobuf::JoinType::try_from(n.jointype).map_or("", |e| e.as_str_name()));
+            fp.node_field("JoinExpr", "larg", n.larg.as_deref(), depth);
+            fp.node_field("JoinExpr", "quals", n.quals.as_deref(), depth);
+            fp.node_field("JoinExpr", "rarg", n.rarg.as_deref(), depth);
+            fp.int_field("rtindex", n.rtindex.into());
+            fp.list_field("JoinExpr", "usingClause", &n.using_clause, depth);
+        }
+        NodeRef::FromExpr(n) => {
+            fp.list_field("FromExpr", "fromlist", &n.fromlist, depth);
+            fp.node_field("FromExpr", "quals", n.quals.as_deref(), depth);
+        }
+        NodeRef::OnConflictExpr(n) => {
+            fp.enum_field("action", protobuf::OnConflictAction::try_from(n.action).map_or("", |e| e.as_str_name()));
+            fp.list_field("OnConflictExpr", "arbiterElems", &n.arbiter_elems, depth);
+            fp.node_field("OnConflictExpr", "arbiterWhere", n.arbiter_where.as_deref(), depth);
+            fp.int_field("constraint", n.constraint.into());
+            fp.int_field("exclRelIndex", n.excl_rel_index.into());
+            fp.list_field("OnConflictExpr", "exclRelTlist", &n.excl_rel_tlist, depth);
+            fp.list_field("OnConflictExpr", "onConflictSet", &n.on_conflict_set, depth);
+            fp.node_field("OnConflictExpr", "onConflictWhere", n.on_conflict_where.as_deref(), depth);
+        }
+        NodeRef::Query(n) => {
+            fp.bool_field("canSetTag", n.can_set_tag);
+            fp.enum_field("commandType", protobuf::CmdType::try_from(n.command_type).map_or("", |e| e.as_str_name()));
+            fp.list_field("Query", "constraintDeps", &n.constraint_deps, depth);
+            fp.list_field("Query", "cteList", &n.cte_list, depth);
+            fp.list_field("Query", "distinctClause", &n.distinct_clause, depth);
+            fp.list_field("Query", "groupClause", &n.group_clause, depth);
+            fp.bool_field("groupDistinct", n.group_distinct);
+            fp.list_field("Query", "groupingSets", &n.grouping_sets, depth);
+            fp.bool_field("hasAggs", n.has_aggs);
+            fp.bool_field("hasDistinctOn", n.has_distinct_on);
+            fp.bool_field("hasForUpdate", n.has_for_update);
+            fp.bool_field("hasModifyingCte", n.has_modifying_cte);
+            fp.bool_field("hasRecursive", n.has_recursive);
+            fp.bool_field("hasRowSecurity", n.has_row_security);
+            fp.bool_field("hasSubLinks", n.has_sub_links);
+            fp.bool_field("hasTargetSrfs", n.has_target_srfs);
+            fp.bool_field("hasWindowFuncs", n.has_window_funcs);
+            fp.node_field("Query", "havingQual", n.having_qual.as_deref(), depth);
+            fp.bool_field("isReturn", n.is_return);
+            fp.typed_field("Query", "jointree", n.jointree.as_deref().map(NodeRef::FromExpr), depth);
+            fp.node_field("Query", "limitCount", n.limit_count.as_deref(), depth);
+            fp.node_field("Query", "limitOffset", n.limit_offset.as_deref(), depth);
+            fp.enum_field("limitOption", protobuf::LimitOption::try_from(n.limit_option).map_or("", |e| e.as_str_name()));
+            fp.list_field("Query", "mergeActionList", &n.merge_action_list, depth);
+            fp.node_field("Query", "mergeJoinCondition", n.merge_join_condition.as_deref(), depth);
+            fp.int_field("mergeTargetRelation", n.merge_target_relation.into());
+            fp.typed_field("Query", "onConflict", n.on_conflict.as_deref().map(NodeRef::OnConflictExpr), depth);
+            fp.enum_field("override", protobuf::OverridingKind::try_from(n.r#override).map_or("", |e| e.as_str_name()));
+            fp.enum_field("querySource", protobuf::QuerySource::try_from(n.query_source).map_or("", |e| e.as_str_name()));
+            fp.int_field("resultRelation", n.result_relation.into());
+            fp.list_field("Query", "returningList", &n.returning_list, depth);
+            fp.list_field("Query", "rowMarks", &n.row_marks, depth);
+            fp.list_field("Query", "rtable", &n.rtable, depth);
+            fp.list_field("Query", "rteperminfos", &n.rteperminfos, depth);
+            fp.node_field("Query", "setOperations", n.set_operations.as_deref(), depth);
+            fp.list_field("Query", "sortClause", &n.sort_clause, depth);
+            fp.list_field("Query", "targetList", &n.target_list, depth);
+            fp.node_field("Query", "utilityStmt", n.utility_stmt.as_deref(), depth);
+            fp.list_field("Query", "windowClause", &n.window_clause, depth);
+            fp.list_field("Query", "withCheckOptions", &n.with_check_options, depth);
+        }
+        NodeRef::TypeName(n) => {
+            fp.list_field("TypeName", "arrayBounds", &n.array_bounds, depth);
+            fp.list_field("TypeName", "names", &n.names, depth);
+            fp.bool_field("pct_type", n.pct_type);
+            fp.bool_field("setof", n.setof);
+            fp.int_field("typeOid", n.type_oid.into());
+            fp.int_field("typemod", n.typemod.into());
+            fp.list_field("TypeName", "typmods", &n.typmods, depth);
+        }
+        NodeRef::ColumnRef(n) => {
+            fp.list_field("ColumnRef", "fields", &n.fields, depth);
+        }
+        NodeRef::ParamRef(_) => {}
+        NodeRef::AExpr(n) => {
+            // IN lists and = ANY(...) are fingerprinted like a single comparison
+            let kind = match AExprKind::try_from(n.kind) {
+                Ok(AExprKind::AexprOpAny | AExprKind::AexprIn) => AExprKind::AexprOp.as_str_name(),
+                kind => kind.map_or("", |k| k.as_str_name()),
+            };
+            fp.enum_field("kind", kind);
+            fp.node_field("A_Expr", "lexpr", n.lexpr.as_deref(), depth);
+            fp.list_field("A_Expr", "name", &n.name, depth);
+            fp.node_field("A_Expr", "rexpr", n.rexpr.as_deref(), depth);
+        }
+        NodeRef::TypeCast(n) => {
+            fp.node_field("TypeCast", "arg", n.arg.as_deref(), depth);
+            fp.typed_field("TypeCast", "typeName", n.type_name.as_ref().map(NodeRef::TypeName), depth);
+        }
+        NodeRef::CollateClause(n) => {
+            fp.node_field("CollateClause", "arg", n.arg.as_deref(), depth);
+            fp.list_field("CollateClause", "collname", &n.collname, depth);
+        }
+        NodeRef::RoleSpec(n) => {
+            fp.str_field("rolename", &n.rolename);
+            fp.enum_field("roletype", protobuf::RoleSpecType::try_from(n.roletype).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::FuncCall(n) => {
+            fp.bool_field("agg_distinct", n.agg_distinct);
+            fp.node_field("FuncCall", "agg_filter", n.agg_filter.as_deref(), depth);
+            fp.list_field("FuncCall", "agg_order", &n.agg_order, depth);
+            fp.bool_field("agg_star", n.agg_star);
+            fp.bool_field("agg_within_group", n.agg_within_group);
+            fp.list_field("FuncCall", "args", &n.args, depth);
+            fp.bool_field("func_variadic", n.func_variadic);
+            fp.enum_field("funcformat", protobuf::CoercionForm::try_from(n.funcformat).map_or("", |e| e.as_str_name()));
+            fp.list_field("FuncCall", "funcname", &n.funcname, depth);
+            fp.typed_field("FuncCall", "over", n.over.as_deref().map(NodeRef::WindowDef), depth);
+        }
+        NodeRef::AStar(_) => {}
+        NodeRef::AIndices(n) => {
+            fp.bool_field("is_slice", n.is_slice);
+            fp.node_field("A_Indices", "lidx", n.lidx.as_deref(), depth);
+            fp.node_field("A_Indices", "uidx", n.uidx.as_deref(), depth);
+        }
+        NodeRef::AIndirection(n) => {
+            fp.node_field("A_Indirection", "arg", n.arg.as_deref(), depth);
+            fp.list_field("A_Indirection", "indirection", &n.indirection, depth);
+        }
+        NodeRef::AArrayExpr(n) => {
+            fp.list_field("A_ArrayExpr", "elements", &n.elements, depth);
+        }
+        NodeRef::ResTarget(n) => {
+            fp.list_field("ResTarget", "indirection", &n.indirection, depth);
+            // Output column names are aliases
+            if !field.is_some_and(|f| f.parent == "SelectStmt" && f.name == "targetList") {
+                fp.str_field("name", &n.name);
+            }
+            fp.node_field("ResTarget", "val", n.val.as_deref(), depth);
+        }
+        NodeRef::MultiAssignRef(n) => {
+            fp.int_field("colno", n.colno.into());
+            fp.int_field("ncolumns", n.ncolumns.into());
+            fp.node_field("MultiAssignRef", "source", n.source.as_deref(), depth);
+        }
+        NodeRef::SortBy(n) => {
+            fp.node_field("SortBy", "node", n.node.as_deref(), depth);
+            fp.enum_field("sortby_dir", protobuf::SortByDir::try_from(n.sortby_dir).map_or("", |e| e.as_str_name()));
+            fp.enum_field("sortby_nulls", protobuf::SortByNulls::try_from(n.sortby_nulls).map_or("", |e| e.as_str_name()));
+            fp.list_field("SortBy", "useOp", &n.use_op, depth);
+        }
+        NodeRef::WindowDef(n) => {
+            fp.node_field("WindowDef", "endOffset", n.end_offset.as_deref(), depth);
+            fp.int_field("frameOptions", n.frame_options.into());
+            fp.str_field("name", &n.name);
+            fp.list_field("WindowDef", "orderClause", &n.order_clause, depth);
+            fp.list_field("WindowDef", "partitionClause", &n.partition_clause, depth);
+            fp.str_field("refname", &n.refname);
+            fp.node_field("WindowDef", "startOffset", n.start_offset.as_deref(), depth);
+        }
+        NodeRef::RangeSubselect(n) => {
+            fp.typed_field("RangeSubselect", "alias", n.alias.as_ref().map(NodeRef::Alias), depth);
+            fp.bool_field("lateral", n.lateral);
+            fp.node_field("RangeSubselect", "subquery", n.subquery.as_deref(), depth);
+        }
+        NodeRef::RangeFunction(n) => {
+            fp.typed_field("RangeFunction", "alias", n.alias.as_ref().map(NodeRef::Alias), depth);
+            fp.list_field("RangeFunction", "coldeflist", &n.coldeflist, depth);
+            fp.list_field("RangeFunction", "functions", &n.functions, depth);
+            fp.bool_field("is_rowsfrom", n.is_rowsfrom);
+            fp.bool_field("lateral", n.lateral);
+            fp.bool_field("ordinality", n.ordinality);
+        }
+        NodeRef::RangeTableFunc(n) => {
+            fp.typed_field("RangeTableFunc", "alias", n.alias.as_ref().map(NodeRef::Alias), depth);
+            fp.list_field("RangeTableFunc", "columns", &n.columns, depth);
+            fp.node_field("RangeTableFunc", "docexpr", n.docexpr.as_deref(), depth);
+            fp.bool_field("lateral", n.lateral);
+            fp.list_field("RangeTableFunc", "namespaces", &n.namespaces, depth);
+            fp.node_field("RangeTableFunc", "rowexpr", n.rowexpr.as_deref(), depth);
+        }
+        NodeRef::RangeTableFuncCol(n) => {
+            fp.node_field("RangeTableFuncCol", "coldefexpr", n.coldefexpr.as_deref(), depth);
+            fp.node_field("RangeTableFuncCol", "colexpr", n.colexpr.as_deref(), depth);
+            fp.str_field("colname", &n.colname);
+            fp.bool_field("for_ordinality", n.for_ordinality);
+            fp.bool_field("is_not_null", n.is_not_null);
+            fp.typed_field("RangeTableFuncCol", "typeName", n.type_name.as_ref().map(NodeRef::TypeName), depth);
+        }
+        NodeRef::RangeTableSample(n) => {
+            fp.list_field("RangeTableSample", "args", &n.args, depth);
+            fp.list_field("RangeTableSample", "method", &n.method, depth);
+            fp.node_field("RangeTableSample", "relation", n.relation.as_deref(), depth);
+            fp.node_field("RangeTableSample", "repeatable", n.repeatable.as_deref(), depth);
+        }
+        NodeRef::ColumnDef(n) => {
+            fp.typed_field("ColumnDef", "collClause", n.coll_clause.as_deref().map(NodeRef::CollateClause), depth);
+            fp.int_field("collOid", n.coll_oid.into());
+            fp.str_field("colname", &n.colname);
+            fp.str_field("compression", &n.compression);
+            fp.list_field("ColumnDef", "constraints", &n.constraints, depth);
+            fp.node_field("ColumnDef", "cooked_default", n.cooked_default.as_deref(), depth);
+            fp.list_field("ColumnDef", "fdwoptions", &n.fdwoptions, depth);
+            fp.str_field("generated", &n.generated);
+            fp.str_field("identity", &n.identity);
+            fp.typed_field("ColumnDef", "identitySequence", n.identity_sequence.as_ref().map(NodeRef::RangeVar), depth);
+            fp.int_field("inhcount", n.inhcount.into());
+            fp.bool_field("is_from_type", n.is_from_type);
+            fp.bool_field("is_local", n.is_local);
+            fp.bool_field("is_not_null", n.is_not_null);
+            fp.node_field("ColumnDef", "raw_default", n.raw_default.as_deref(), depth);
+            fp.str_field("storage", &n.storage);
+            fp.str_field("storage_name", &n.storage_name);
+            fp.typed_field("ColumnDef", "typeName", n.type_name.as_ref().map(NodeRef::TypeName), depth);
+        }
+        NodeRef::TableLikeClause(n) => {
+            fp.int_field("options", n.options.into());
+            fp.typed_field("TableLikeClause", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.int_field("relationOid", n.relation_oid.into());
+        }
+        NodeRef::IndexElem(n) => {
+            fp.list_field("IndexElem", "collation", &n.collation, depth);
+            fp.node_field("IndexElem", "expr", n.expr.as_deref(), depth);
+            fp.str_field("indexcolname", &n.indexcolname);
+            fp.str_field("name", &n.name);
+            fp.enum_field("nulls_ordering", protobuf::SortByNulls::try_from(n.nulls_ordering).map_or("", |e| e.as_str_name()));
+            fp.list_field("IndexElem", "opclass", &n.opclass, depth);
+            fp.list_field("IndexElem", "opclassopts", &n.opclassopts, depth);
+            fp.enum_field("ordering", protobuf::SortByDir::try_from(n.ordering).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::DefElem(n) => {
+            fp.node_field("DefElem", "arg", n.arg.as_deref(), depth);
+            fp.enum_field("defaction", protobuf::DefElemAction::try_from(n.defaction).map_or("", |e| e.as_str_name()));
+            fp.str_field("defname", &n.defname);
+            fp.str_field("defnamespace", &n.defnamespace);
+        }
+        NodeRef::LockingClause(n) => {
+            fp.list_field("LockingClause", "lockedRels", &n.locked_rels, depth);
+            fp.enum_field("strength", protobuf::LockClauseStrength::try_from(n.strength).map_or("", |e| e.as_str_name()));
+            fp.enum_field("waitPolicy", protobuf::LockWaitPolicy::try_from(n.wait_policy).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::XmlSerialize(n) => {
+            fp.node_field("XmlSerialize", "expr", n.expr.as_deref(), depth);
+            fp.bool_field("indent", n.indent);
+            fp.typed_field("XmlSerialize", "typeName", n.type_name.as_ref().map(NodeRef::TypeName), depth);
+            fp.enum_field("xmloption", protobuf::XmlOptionType::try_from(n.xmloption).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::PartitionElem(n) => {
+            fp.list_field("PartitionElem", "collation", &n.collation, depth);
+            fp.node_field("PartitionElem", "expr", n.expr.as_deref(), depth);
+            fp.str_field("name", &n.name);
+            fp.list_field("PartitionElem", "opclass", &n.opclass, depth);
+        }
+        NodeRef::PartitionSpec(n) => {
+            fp.list_field("PartitionSpec", "partParams", &n.part_params, depth);
+            fp.enum_field("strategy", protobuf::PartitionStrategy::try_from(n.strategy).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::PartitionBoundSpec(n) => {
+            fp.bool_field("is_default", n.is_default);
+            fp.list_field("PartitionBoundSpec", "listdatums", &n.listdatums, depth);
+            fp.list_field("PartitionBoundSpec", "lowerdatums", &n.lowerdatums, depth);
+            fp.int_field("modulus", n.modulus.into());
+            fp.int_field("remainder", n.remainder.into());
+            fp.str_field("strategy", &n.strategy);
+            fp.list_field("PartitionBoundSpec", "upperdatums", &n.upperdatums, depth);
+        }
+        NodeRef::PartitionRangeDatum(n) => {
+            fp.enum_field("kind", protobuf::PartitionRangeDatumKind::try_from(n.kind).map_or("", |e| e.as_str_name()));
+            fp.node_field("PartitionRangeDatum", "value", n.value.as_deref(), depth);
+        }
+        NodeRef::SinglePartitionSpec(_) => {}
+        NodeRef::PartitionCmd(n) => {
+            fp.typed_field("PartitionCmd", "bound", n.bound.as_ref().map(NodeRef::PartitionBoundSpec), depth);
+            fp.bool_field("concurrent", n.concurrent);
+            fp.typed_field("PartitionCmd", "name", n.name.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::RangeTblEntry(n) => {
+            fp.typed_field("RangeTblEntry", "alias", n.alias.as_ref().map(NodeRef::Alias), depth);
+            fp.list_field("RangeTblEntry", "colcollations", &n.colcollations, depth);
+            fp.list_field("RangeTblEntry", "coltypes", &n.coltypes, depth);
+            fp.list_field("RangeTblEntry", "coltypmods", &n.coltypmods, depth);
+            fp.int_field("ctelevelsup", n.ctelevelsup.into());
+            fp.str_field("ctename", &n.ctename);
+            fp.str_field("enrname", &n.enrname);
+            fp.float_field("enrtuples", n.enrtuples);
+            fp.typed_field("RangeTblEntry", "eref", n.eref.as_ref().map(NodeRef::Alias), depth);
+            fp.bool_field("funcordinality", n.funcordinality);
+            fp.list_field("RangeTblEntry", "functions", &n.functions, depth);
+            fp.bool_field("inFromCl", n.in_from_cl);
+            fp.bool_field("inh", n.inh);
+            fp.typed_field("RangeTblEntry", "join_using_alias", n.join_using_alias.as_ref().map(NodeRef::Alias), depth);
+            fp.list_field("RangeTblEntry", "joinaliasvars", &n.joinaliasvars, depth);
+            fp.list_field("RangeTblEntry", "joinleftcols", &n.joinleftcols, depth);
+            fp.int_field("joinmergedcols", n.joinmergedcols.into());
+            fp.list_field("RangeTblEntry", "joinrightcols", &n.joinrightcols, depth);
+            fp.enum_field("jointype", protobuf::JoinType::try_from(n.jointype).map_or("", |e| e.as_str_name()));
+            fp.bool_field("lateral", n.lateral);
+            fp.int_field("perminfoindex", n.perminfoindex.into());
+            fp.int_field("relid", n.relid.into());
+            fp.str_field("relkind", &n.relkind);
+            fp.int_field("rellockmode", n.rellockmode.into());
+            fp.enum_field("rtekind", protobuf::RteKind::try_from(n.rtekind).map_or("", |e| e.as_str_name()));
+            fp.bool_field("security_barrier", n.security_barrier);
+            fp.list_field("RangeTblEntry", "security_quals", &n.security_quals, depth);
+            fp.bool_field("self_reference", n.self_reference);
+            fp.typed_field("RangeTblEntry", "subquery", n.subquery.as_deref().map(NodeRef::Query), depth);
+            fp.typed_field("RangeTblEntry", "tablefunc", n.tablefunc.as_deref().map(NodeRef::TableFunc), depth);
+            fp.typed_field("RangeTblEntry", "tablesample", n.tablesample.as_deref().map(NodeRef::TableSampleClause), depth);
+            fp.list_field("RangeTblEntry", "values_lists", &n.values_lists, depth);
+        }
+        NodeRef::RtepermissionInfo(n) => {
+            fp.int_field("checkAsUser", n.check_as_user.into());
+            fp.bool_field("inh", n.inh);
+            fp.ints_field("insertedCols", &n.inserted_cols);
+            fp.int_field("relid", n.relid.into());
+            fp.int_field("requiredPerms", n.required_perms as i64);
+            fp.ints_field("selectedCols", &n.selected_cols);
+            fp.ints_field("updatedCols", &n.updated_cols);
+        }
+        NodeRef::RangeTblFunction(n) => {
+            fp.list_field("RangeTblFunction", "funccolcollations", &n.funccolcollations, depth);
+            fp.int_field("funccolcount", n.funccolcount.into());
+            fp.list_field("RangeTblFunction", "funccolnames", &n.funccolnames, depth);
+            fp.list_field("RangeTblFunction", "funccoltypes", &n.funccoltypes, depth);
+            fp.list_field("RangeTblFunction", "funccoltypmods", &n.funccoltypmods, depth);
+            fp.node_field("RangeTblFunction", "funcexpr", n.funcexpr.as_deref(), depth);
+            fp.ints_field("funcparams", &n.funcparams);
+        }
+        NodeRef::TableSampleClause(n) => {
+            fp.list_field("TableSampleClause", "args", &n.args, depth);
+            fp.node_field("TableSampleClause", "repeatable", n.repeatable.as_deref(), depth);
+            fp.int_field("tsmhandler", n.tsmhandler.into());
+        }
+        NodeRef::WithCheckOption(n) => {
+            fp.bool_field("cascaded", n.cascaded);
+            fp.enum_field("kind", protobuf::WcoKind::try_from(n.kind).map_or("", |e| e.as_str_name()));
+            fp.str_field("polname", &n.polname);
+            fp.node_field("WithCheckOption", "qual", n.qual.as_deref(), depth);
+            fp.str_field("relname", &n.relname);
+        }
+        NodeRef::SortGroupClause(n) => {
+            fp.int_field("eqop", n.eqop.into());
+            fp.bool_field("hashable", n.hashable);
+            fp.bool_field("nulls_first", n.nulls_first);
+            fp.int_field("sortop", n.sortop.into());
+            fp.int_field("tleSortGroupRef", n.tle_sort_group_ref.into());
+        }
+        NodeRef::GroupingSet(n) => {
+            fp.list_field("GroupingSet", "content", &n.content, depth);
+            fp.enum_field("kind", protobuf::GroupingSetKind::try_from(n.kind).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::WindowClause(n) => {
+            fp.bool_field("copiedOrder", n.copied_order);
+            fp.int_field("endInRangeFunc", n.end_in_range_func.into());
+            fp.node_field("WindowClause", "endOffset", n.end_offset.as_deref(), depth);
+            fp.int_field("frameOptions", n.frame_options.into());
+            fp.bool_field("inRangeAsc", n.in_range_asc);
+            fp.int_field("inRangeColl", n.in_range_coll.into());
+            fp.bool_field("inRangeNullsFirst", n.in_range_nulls_first);
+            fp.str_field("name", &n.name);
+            fp.list_field("WindowClause", "orderClause", &n.order_clause, depth);
+            fp.list_field("WindowClause", "partitionClause", &n.partition_clause, depth);
+            fp.str_field("refname", &n.refname);
+            fp.int_field("startInRangeFunc", n.start_in_range_func.into());
+            fp.node_field("WindowClause", "startOffset", n.start_offset.as_deref(), depth);
+            fp.int_field("winref", n.winref.into());
+        }
+        NodeRef::RowMarkClause(n) => {
+            fp.bool_field("pushedDown", n.pushed_down);
+            fp.int_field("rti", n.rti.into());
+            fp.enum_field("strength", protobuf::LockClauseStrength::try_from(n.strength).map_or("", |e| e.as_str_name()));
+            fp.enum_field("waitPolicy", protobuf::LockWaitPolicy::try_from(n.wait_policy).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::WithClause(n) => {
+            fp.list_field("WithClause", "ctes", &n.ctes, depth);
+            fp.bool_field("recursive", n.recursive);
+        }
+        NodeRef::InferClause(n) => {
+            fp.str_field("conname", &n.conname);
+            fp.list_field("InferClause", "indexElems", &n.index_elems, depth);
+            fp.node_field("InferClause", "whereClause", n.where_clause.as_deref(), depth);
+        }
+        NodeRef::OnConflictClause(n) => {
+            fp.enum_field("action", protobuf::OnConflictAction::try_from(n.action).map_or("", |e| e.as_str_name()));
+            fp.typed_field("OnConflictClause", "infer", n.infer.as_deref().map(NodeRef::InferClause), depth);
+            fp.list_field("OnConflictClause", "targetList", &n.target_list, depth);
+            fp.node_field("OnConflictClause", "whereClause", n.where_clause.as_deref(), depth);
+        }
+        NodeRef::CtesearchClause(n) => {
+            fp.bool_field("search_breadth_first", n.search_breadth_first);
+            fp.list_field("CTESearchClause", "search_col_list", &n.search_col_list, depth);
+            fp.str_field("search_seq_column", &n.search_seq_column);
+        }
+        NodeRef::CtecycleClause(n) => {
+            fp.list_field("CTECycleClause", "cycle_col_list", &n.cycle_col_list, depth);
+            fp.int_field("cycle_mark_collation", n.cycle_mark_collation.into());
+            fp.str_field("cycle_mark_column", &n.cycle_mark_column);
+            fp.node_field("CTECycleClause", "cycle_mark_default", n.cycle_mark_default.as_deref(), depth);
+            fp.int_field("cycle_mark_neop", n.cycle_mark_neop.into());
+            fp.int_field("cycle_mark_type", n.cycle_mark_type.into());
+            fp.int_field("cycle_mark_typmod", n.cycle_mark_typmod.into());
+            fp.node_field("CTECycleClause", "cycle_mark_value", n.cycle_mark_value.as_deref(), depth);
+            fp.str_field("cycle_path_column", &n.cycle_path_column);
+        }
+        NodeRef::CommonTableExpr(n) => {
+            fp.list_field("CommonTableExpr", "aliascolnames", &n.aliascolnames, depth);
+            fp.list_field("CommonTableExpr", "ctecolcollations", &n.ctecolcollations, depth);
+            fp.list_field("CommonTableExpr", "ctecolnames", &n.ctecolnames, depth);
+            fp.list_field("CommonTableExpr", "ctecoltypes", &n.ctecoltypes, depth);
+            fp.list_field("CommonTableExpr", "ctecoltypmods", &n.ctecoltypmods, depth);
+            fp.enum_field("ctematerialized", protobuf::CteMaterialize::try_from(n.ctematerialized).map_or("", |e| e.as_str_name()));
+            fp.str_field("ctename", &n.ctename);
+            fp.node_field("CommonTableExpr", "ctequery", n.ctequery.as_deref(), depth);
+            fp.bool_field("cterecursive", n.cterecursive);
+            fp.int_field("cterefcount", n.cterefcount.into());
+            fp.typed_field("CommonTableExpr", "cycle_clause", n.cycle_clause.as_deref().map(NodeRef::CtecycleClause), depth);
+            fp.typed_field("CommonTableExpr", "search_clause", n.search_clause.as_ref().map(NodeRef::CtesearchClause), depth);
+        }
+        NodeRef::MergeWhenClause(n) => {
+            fp.enum_field("commandType", protobuf::CmdType::try_from(n.command_type).map_or("", |e| e.as_str_name()));
+            fp.node_field("MergeWhenClause", "condition", n.condition.as_deref(), depth);
+            fp.enum_field("matchKind", protobuf::MergeMatchKind::try_from(n.match_kind).map_or("", |e| e.as_str_name()));
+            fp.enum_field("override", protobuf::OverridingKind::try_from(n.r#override).map_or("", |e| e.as_str_name()));
+            fp.list_field("MergeWhenClause", "targetList", &n.target_list, depth);
+            fp.list_field("MergeWhenClause", "values", &n.values, depth);
+        }
+        NodeRef::TriggerTransition(n) => {
+            fp.bool_field("isNew", n.is_new);
+            fp.bool_field("isTable", n.is_table);
+            fp.str_field("name", &n.name);
+        }
+        NodeRef::JsonOutput(n) => {
+            fp.typed_field("JsonOutput", "returning", n.returning.as_ref().map(NodeRef::JsonReturning), depth);
+            fp.typed_field("JsonOutput", "type_name", n.type_name.as_ref().map(NodeRef::TypeName), depth);
+        }
+        NodeRef::JsonArgument(n) => {
+            fp.str_field("name", &n.name);
+            fp.typed_field("JsonArgument", "val", n.val.as_deref().map(NodeRef::JsonValueExpr), depth);
+        }
+        NodeRef::JsonFuncExpr(n) => {
+            fp.str_field("column_name", &n.column_name);
+            fp.typed_field("JsonFuncExpr", "context_item", n.context_item.as_deref().map(NodeRef::JsonValueExpr), depth);
+            fp.typed_field("JsonFuncExpr", "on_empty", n.on_empty.as_deref().map(NodeRef::JsonBehavior), depth);
+            fp.typed_field("JsonFuncExpr", "on_error", n.on_error.as_deref().map(NodeRef::JsonBehavior), depth);
+            fp.enum_field("op", protobuf::JsonExprOp::try_from(n.op).map_or("", |e| e.as_str_name()));
+            fp.typed_field("JsonFuncExpr", "output", n.output.as_ref().map(NodeRef::JsonOutput), depth);
+            fp.list_field("JsonFuncExpr", "passing", &n.passing, depth);
+            fp.node_field("JsonFuncExpr", "pathspec", n.pathspec.as_deref(), depth);
+            fp.enum_field("quotes", protobuf::JsonQuotes::try_from(n.quotes).map_or("", |e| e.as_str_name()));
+            fp.enum_field("wrapper", protobuf::JsonWrapper::try_from(n.wrapper).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::JsonTablePathSpec(n) => {
+            fp.str_field("name", &n.name);
+            fp.node_field("JsonTablePathSpec", "string", n.string.as_deref(), depth);
+        }
+        NodeRef::JsonTable(n) => {
+            fp.typed_field("JsonTable", "alias", n.alias.as_ref().map(NodeRef::Alias), depth);
+            fp.list_field("JsonTable", "columns", &n.columns, depth);
+            fp.typed_field("JsonTable", "context_item", n.context_item.as_deref().map(NodeRef::JsonValueExpr), depth);
+            fp.bool_field("lateral", n.lateral);
+            fp.typed_field("JsonTable", "on_error", n.on_error.as_deref().map(NodeRef::JsonBehavior), depth);
+            fp.list_field("JsonTable", "passing", &n.passing, depth);
+            fp.typed_field("JsonTable", "pathspec", n.pathspec.as_deref().map(NodeRef::JsonTablePathSpec), depth);
+        }
+        NodeRef::JsonTableColumn(n) => {
+            fp.enum_field("coltype", protobuf::JsonTableColumnType::try_from(n.coltype).map_or("", |e| e.as_str_name()));
+            fp.list_field("JsonTableColumn", "columns", &n.columns, depth);
+            fp.typed_field("JsonTableColumn", "format", n.format.as_ref().map(NodeRef::JsonFormat), depth);
+            fp.str_field("name", &n.name);
+            fp.typed_field("JsonTableColumn", "on_empty", n.on_empty.as_deref().map(NodeRef::JsonBehavior), depth);
+            fp.typed_field("JsonTableColumn", "on_error", n.on_error.as_deref().map(NodeRef::JsonBehavior), depth);
+            fp.typed_field("JsonTableColumn", "pathspec", n.pathspec.as_deref().map(NodeRef::JsonTablePathSpec), depth);
+            fp.enum_field("quotes", protobuf::JsonQuotes::try_from(n.quotes).map_or("", |e| e.as_str_name()));
+            fp.typed_field("JsonTableColumn", "type_name", n.type_name.as_ref().map(NodeRef::TypeName), depth);
+            fp.enum_field("wrapper", protobuf::JsonWrapper::try_from(n.wrapper).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::JsonKeyValue(n) => {
+            fp.node_field("JsonKeyValue", "key", n.key.as_deref(), depth);
+            fp.typed_field("JsonKeyValue", "value", n.value.as_deref().map(NodeRef::JsonValueExpr), depth);
+        }
+        NodeRef::JsonParseExpr(n) => {
+            fp.typed_field("JsonParseExpr", "expr", n.expr.as_deref().map(NodeRef::JsonValueExpr), depth);
+            fp.typed_field("JsonParseExpr", "output", n.output.as_ref().map(NodeRef::JsonOutput), depth);
+            fp.bool_field("unique_keys", n.unique_keys);
+        }
+        NodeRef::JsonScalarExpr(n) => {
+            fp.node_field("JsonScalarExpr", "expr", n.expr.as_deref(), depth);
+            fp.typed_field("JsonScalarExpr", "output", n.output.as_ref().map(NodeRef::JsonOutput), depth);
+        }
+        NodeRef::JsonSerializeExpr(n) => {
+            fp.typed_field("JsonSerializeExpr", "expr", n.expr.as_deref().map(NodeRef::JsonValueExpr), depth);
+            fp.typed_field("JsonSerializeExpr", "output", n.output.as_ref().map(NodeRef::JsonOutput), depth);
+        }
+        NodeRef::JsonObjectConstructor(n) => {
+            fp.bool_field("absent_on_null", n.absent_on_null);
+            fp.list_field("JsonObjectConstructor", "exprs", &n.exprs, depth);
+            fp.typed_field("JsonObjectConstructor", "output", n.output.as_ref().map(NodeRef::JsonOutput), depth);
+            fp.bool_field("unique", n.unique);
+        }
+        NodeRef::JsonArrayConstructor(n) => {
+            fp.bool_field("absent_on_null", n.absent_on_null);
+            fp.list_field("JsonArrayConstructor", "exprs", &n.exprs, depth);
+            fp.typed_field("JsonArrayConstructor", "output", n.output.as_ref().map(NodeRef::JsonOutput), depth);
+        }
+        NodeRef::JsonArrayQueryConstructor(n) => {
+            fp.bool_field("absent_on_null", n.absent_on_null);
+            fp.typed_field("JsonArrayQueryConstructor", "format", n.format.as_ref().map(NodeRef::JsonFormat), depth);
+            fp.typed_field("JsonArrayQueryConstructor", "output", n.output.as_ref().map(NodeRef::JsonOutput), depth);
+            fp.node_field("JsonArrayQueryConstructor", "query", n.query.as_deref(), depth);
+        }
+        NodeRef::JsonAggConstructor(n) => {
+            fp.node_field("JsonAggConstructor", "agg_filter", n.agg_filter.as_deref(), depth);
+            fp.list_field("JsonAggConstructor", "agg_order", &n.agg_order, depth);
+            fp.typed_field("JsonAggConstructor", "output", n.output.as_ref().map(NodeRef::JsonOutput), depth);
+            fp.typed_field("JsonAggConstructor", "over", n.over.as_deref().map(NodeRef::WindowDef), depth);
+        }
+        NodeRef::JsonObjectAgg(n) => {
+            fp.bool_field("absent_on_null", n.absent_on_null);
+            fp.typed_field("JsonObjectAgg", "arg", n.arg.as_deref().map(NodeRef::JsonKeyValue), depth);
+            fp.typed_field("JsonObjectAgg", "constructor", n.constructor.as_deref().map(NodeRef::JsonAggConstructor), depth);
+            fp.bool_field("unique", n.unique);
+        }
+        NodeRef::JsonArrayAgg(n) => {
+            fp.bool_field("absent_on_null", n.absent_on_null);
+            fp.typed_field("JsonArrayAgg", "arg", n.arg.as_deref().map(NodeRef::JsonValueExpr), depth);
+            fp.typed_field("JsonArrayAgg", "constructor", n.constructor.as_deref().map(NodeRef::JsonAggConstructor), depth);
+        }
+        NodeRef::RawStmt(n) => {
+            fp.node_field("RawStmt", "stmt", n.stmt.as_deref(), depth);
+        }
+        NodeRef::InsertStmt(n) => {
+            fp.list_field("InsertStmt", "cols", &n.cols, depth);
+            fp.typed_field("InsertStmt", "onConflictClause", n.on_conflict_clause.as_deref().map(NodeRef::OnConflictClause), depth);
+            fp.enum_field("override", protobuf::OverridingKind::try_from(n.r#override).map_or("", |e| e.as_str_name()));
+            fp.typed_field("InsertStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.list_field("InsertStmt", "returningList", &n.returning_list, depth);
+            fp.node_field("InsertStmt", "selectStmt", n.select_stmt.as_deref(), depth);
+            fp.typed_field("InsertStmt", "withClause", n.with_clause.as_ref().map(NodeRef::WithClause), depth);
+        }
+        NodeRef::DeleteStmt(n) => {
+            fp.typed_field("DeleteStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.list_field("DeleteStmt", "returningList", &n.returning_list, depth);
+            fp.list_field("DeleteStmt", "usingClause", &n.using_clause, depth);
+            fp.node_field("DeleteStmt", "whereClause", n.where_clause.as_deref(), depth);
+            fp.typed_field("DeleteStmt", "withClause", n.with_clause.as_ref().map(NodeRef::WithClause), depth);
+        }
+        NodeRef::UpdateStmt(n) => {
+            fp.list_field("UpdateStmt", "fromClause", &n.from_clause, depth);
+            fp.typed_field("UpdateStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.list_field("UpdateStmt", "returningList", &n.returning_list, depth);
+            fp.list_field("UpdateStmt", "targetList", &n.target_list, depth);
+            fp.node_field("UpdateStmt", "whereClause", n.where_clause.as_deref(), depth);
+            fp.typed_field("UpdateStmt", "withClause", n.with_clause.as_ref().map(NodeRef::WithClause), depth);
+        }
+        NodeRef::MergeStmt(n) => {
+            fp.node_field("MergeStmt", "joinCondition", n.join_condition.as_deref(), depth);
+            fp.list_field("MergeStmt", "mergeWhenClauses", &n.merge_when_clauses, depth);
+            fp.typed_field("MergeStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.list_field("MergeStmt", "returningList", &n.returning_list, depth);
+            fp.node_field("MergeStmt", "sourceRelation", n.source_relation.as_deref(), depth);
+            fp.typed_field("MergeStmt", "withClause", n.with_clause.as_ref().map(NodeRef::WithClause), depth);
+        }
+        NodeRef::SelectStmt(n) => {
+            fp.bool_field("all", n.all);
+            fp.list_field("SelectStmt", "distinctClause", &n.distinct_clause, depth);
+            fp.list_field("SelectStmt", "fromClause", &n.from_clause, depth);
+            fp.list_field("SelectStmt", "groupClause", &n.group_clause, depth);
+            fp.bool_field("groupDistinct", n.group_distinct);
+            fp.node_field("SelectStmt", "havingClause", n.having_clause.as_deref(), depth);
+            fp.typed_field("SelectStmt", "intoClause", n.into_clause.as_deref().map(NodeRef::IntoClause), depth);
+            fp.typed_field("SelectStmt", "larg", n.larg.as_deref().map(NodeRef::SelectStmt), depth);
+            fp.node_field("SelectStmt", "limitCount", n.limit_count.as_deref(), depth);
+            fp.node_field("SelectStmt", "limitOffset", n.limit_offset.as_deref(), depth);
+            fp.enum_field("limitOption", protobuf::LimitOption::try_from(n.limit_option).map_or("", |e| e.as_str_name()));
+            fp.list_field("SelectStmt", "lockingClause", &n.locking_clause, depth);
+            fp.enum_field("op", protobuf::SetOperation::try_from(n.op).map_or("", |e| e.as_str_name()));
+            fp.typed_field("SelectStmt", "rarg", n.rarg.as_deref().map(NodeRef::SelectStmt), depth);
+            fp.list_field("SelectStmt", "sortClause", &n.sort_clause, depth);
+            fp.list_field("SelectStmt", "targetList", &n.target_list, depth);
+            fp.list_field("SelectStmt", "valuesLists", &n.values_lists, depth);
+            fp.node_field("SelectStmt", "whereClause", n.where_clause.as_deref(), depth);
+            fp.list_field("SelectStmt", "windowClause", &n.window_clause, depth);
+            fp.typed_field("SelectStmt", "withClause", n.with_clause.as_ref().map(NodeRef::WithClause), depth);
+        }
+        NodeRef::SetOperationStmt(n) => {
+            fp.bool_field("all", n.all);
+            fp.list_field("SetOperationStmt", "colCollations", &n.col_collations, depth);
+            fp.list_field("SetOperationStmt", "colTypes", &n.col_types, depth);
+            fp.list_field("SetOperationStmt", "colTypmods", &n.col_typmods, depth);
+            fp.list_field("SetOperationStmt", "groupClauses", &n.group_clauses, depth);
+            fp.node_field("SetOperationStmt", "larg", n.larg.as_deref(), depth);
+            fp.enum_field("op", protobuf::SetOperation::try_from(n.op).map_or("", |e| e.as_str_name()));
+            fp.node_field("SetOperationStmt", "rarg", n.rarg.as_deref(), depth);
+        }
+        NodeRef::ReturnStmt(n) => {
+            fp.node_field("ReturnStmt", "returnval", n.returnval.as_deref(), depth);
+        }
+        NodeRef::PlassignStmt(n) => {
+            fp.list_field("PLAssignStmt", "indirection", &n.indirection, depth);
+            fp.str_field("name", &n.name);
+            fp.int_field("nnames", n.nnames.into());
+            fp.typed_field("PLAssignStmt", "val", n.val.as_deref().map(NodeRef::SelectStmt), depth);
+        }
+        NodeRef::CreateSchemaStmt(n) => {
+            fp.typed_field("CreateSchemaStmt", "authrole", n.authrole.as_ref().map(NodeRef::RoleSpec), depth);
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.list_field("CreateSchemaStmt", "schemaElts", &n.schema_elts, depth);
+            fp.str_field("schemaname", &n.schemaname);
+        }
+        NodeRef::AlterTableStmt(n) => {
+            fp.list_field("AlterTableStmt", "cmds", &n.cmds, depth);
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.enum_field("objtype", protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name()));
+            fp.typed_field("AlterTableStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::ReplicaIdentityStmt(n) => {
+            fp.str_field("identity_type", &n.identity_type);
+            fp.str_field("name", &n.name);
+        }
+        NodeRef::AlterTableCmd(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.node_field("AlterTableCmd", "def", n.def.as_deref(), depth);
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.str_field("name", &n.name);
+            fp.typed_field("AlterTableCmd", "newowner", n.newowner.as_ref().map(NodeRef::RoleSpec), depth);
+            fp.int_field("num", n.num.into());
+            fp.bool_field("recurse", n.recurse);
+            fp.enum_field("subtype", protobuf::AlterTableType::try_from(n.subtype).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::AlterCollationStmt(n) => {
+            fp.list_field("AlterCollationStmt", "collname", &n.collname, depth);
+        }
+        NodeRef::AlterDomainStmt(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.node_field("AlterDomainStmt", "def", n.def.as_deref(), depth);
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.str_field("name", &n.name);
+            fp.str_field("subtype", &n.subtype);
+            fp.list_field("AlterDomainStmt", "typeName", &n.type_name, depth);
+        }
+        NodeRef::GrantStmt(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.bool_field("grant_option", n.grant_option);
+            fp.list_field("GrantStmt", "grantees", &n.grantees, depth);
+            fp.typed_field("GrantStmt", "grantor", n.grantor.as_ref().map(NodeRef::RoleSpec), depth);
+            fp.bool_field("is_grant", n.is_grant);
+            fp.list_field("GrantStmt", "objects", &n.objects, depth);
+            fp.enum_field("objtype", protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name()));
+            fp.list_field("GrantStmt", "privileges", &n.privileges, depth);
+            fp.enum_field("targtype", protobuf::GrantTargetType::try_from(n.targtype).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::ObjectWithArgs(n) => {
+            fp.bool_field("args_unspecified", n.args_unspecified);
+            fp.list_field("ObjectWithArgs", "objargs", &n.objargs, depth);
+            fp.list_field("ObjectWithArgs", "objfuncargs", &n.objfuncargs, depth);
+            fp.list_field("ObjectWithArgs", "objname", &n.objname, depth);
+        }
+        NodeRef::AccessPriv(n) => {
+            fp.list_field("AccessPriv", "cols", &n.cols, depth);
+            fp.str_field("priv_name", &n.priv_name);
+        }
+        NodeRef::GrantRoleStmt(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.list_field("GrantRoleStmt", "granted_roles", &n.granted_roles, depth);
+            fp.list_field("GrantRoleStmt", "grantee_roles", &n.grantee_roles, depth);
+            fp.typed_field("GrantRoleStmt", "grantor", n.grantor.as_ref().map(NodeRef::RoleSpec), depth);
+            fp.bool_field("is_grant", n.is_grant);
+            fp.list_field("GrantRoleStmt", "opt", &n.opt, depth);
+        }
+        NodeRef::AlterDefaultPrivilegesStmt(n) => {
+            fp.typed_field("AlterDefaultPrivilegesStmt", "action", n.action.as_ref().map(NodeRef::GrantStmt), depth);
+            fp.list_field("AlterDefaultPrivilegesStmt", "options", &n.options, depth);
+        }
+        NodeRef::CopyStmt(n) => {
+            fp.list_field("CopyStmt", "attlist", &n.attlist, depth);
+            fp.str_field("filename", &n.filename);
+            fp.bool_field("is_from", n.is_from);
+            fp.bool_field("is_program", n.is_program);
+            fp.list_field("CopyStmt", "options", &n.options, depth);
+            fp.node_field("CopyStmt", "query", n.query.as_deref(), depth);
+            fp.typed_field("CopyStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.node_field("CopyStmt", "whereClause", n.where_clause.as_deref(), depth);
+        }
+        NodeRef::VariableSetStmt(n) => {
+            fp.list_field("VariableSetStmt", "args", &n.args, depth);
+            fp.bool_field("is_local", n.is_local);
+            fp.enum_field("kind", protobuf::VariableSetKind::try_from(n.kind).map_or("", |e| e.as_str_name()));
+            fp.str_field("name", &n.name);
+        }
+        NodeRef::VariableShowStmt(n) => {
+            fp.str_field("name", &n.name);
+        }
+        NodeRef::CreateStmt(n) => {
+            fp.str_field("accessMethod", &n.access_method);
+            fp.list_field("CreateStmt", "constraints", &n.constraints, depth);
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.list_field("CreateStmt", "inhRelations", &n.inh_relations, depth);
+            fp.typed_field("CreateStmt", "ofTypename", n.of_typename.as_ref().map(NodeRef::TypeName), depth);
+            fp.enum_field("oncommit", protobuf::OnCommitAction::try_from(n.oncommit).map_or("", |e| e.as_str_name()));
+            fp.list_field("CreateStmt", "options", &n.options, depth);
+            fp.typed_field("CreateStmt", "partbound", n.partbound.as_ref().map(NodeRef::PartitionBoundSpec), depth);
+            fp.typed_field("CreateStmt", "partspec", n.partspec.as_ref().map(NodeRef::PartitionSpec), depth);
+            fp.typed_field("CreateStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.list_field("CreateStmt", "tableElts", &n.table_elts, depth);
+            fp.str_field("tablespacename", &n.tablespacename);
+        }
+        NodeRef::Constraint(n) => {
+            fp.str_field("access_method", &n.access_method);
+            fp.str_field("conname", &n.conname);
+            fp.enum_field("contype", protobuf::ConstrType::try_from(n.contype).map_or("", |e| e.as_str_name()));
+            fp.str_field("cooked_expr", &n.cooked_expr);
+            fp.bool_field("deferrable", n.deferrable);
+            fp.list_field("Constraint", "exclusions", &n.exclusions, depth);
+            fp.list_field("Constraint", "fk_attrs", &n.fk_attrs, depth);
+            fp.str_field("fk_del_action", &n.fk_del_action);
+            fp.list_field("Constraint", "fk_del_set_cols", &n.fk_del_set_cols, depth);
+            fp.str_field("fk_matchtype", &n.fk_matchtype);
+            fp.str_field("fk_upd_action", &n.fk_upd_action);
+            fp.str_field("generated_when", &n.generated_when);
+            fp.list_field("Constraint", "including", &n.including, depth);
+            fp.str_field("indexname", &n.indexname);
+            fp.str_field("indexspace", &n.indexspace);
+            fp.int_field("inhcount", n.inhcount.into());
+            fp.bool_field("initdeferred", n.initdeferred);
+            fp.bool_field("initially_valid", n.initially_valid);
+            fp.bool_field("is_no_inherit", n.is_no_inherit);
+            fp.list_field("Constraint", "keys", &n.keys, depth);
+            fp.bool_field("nulls_not_distinct", n.nulls_not_distinct);
+            fp.list_field("Constraint", "old_conpfeqop", &n.old_conpfeqop, depth);
+            fp.int_field("old_pktable_oid", n.old_pktable_oid.into());
+            fp.list_field("Constraint", "options", &n.options, depth);
+            fp.list_field("Constraint", "pk_attrs", &n.pk_attrs, depth);
+            fp.typed_field("Constraint", "pktable", n.pktable.as_ref().map(NodeRef::RangeVar), depth);
+            fp.node_field("Constraint", "raw_expr", n.raw_expr.as_deref(), depth);
+            fp.bool_field("reset_default_tblspc", n.reset_default_tblspc);
+            fp.bool_field("skip_validation", n.skip_validation);
+            fp.node_field("Constraint", "where_clause", n.where_clause.as_deref(), depth);
+        }
+        NodeRef::CreateTableSpaceStmt(n) => {
+            fp.list_field("CreateTableSpaceStmt", "options", &n.options, depth);
+            fp.typed_field("CreateTableSpaceStmt", "owner", n.owner.as_ref().map(NodeRef::RoleSpec), depth);
+            fp.str_field("tablespacename", &n.tablespacename);
+        }
+        NodeRef::DropTableSpaceStmt(n) => {
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.str_field("tablespacename", &n.tablespacename);
+        }
+        NodeRef::AlterTableSpaceOptionsStmt(n) => {
+            fp.bool_field("isReset", n.is_reset);
+            fp.list_field("AlterTableSpaceOptionsStmt", "options", &n.options, depth);
+            fp.str_field("tablespacename", &n.tablespacename);
+        }
+        NodeRef::AlterTableMoveAllStmt(n) => {
+            fp.str_field("new_tablespacename", &n.new_tablespacename);
+            fp.bool_field("nowait", n.nowait);
+            fp.enum_field("objtype", protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name()));
+            fp.str_field("orig_tablespacename", &n.orig_tablespacename);
+            fp.list_field("AlterTableMoveAllStmt", "roles", &n.roles, depth);
+        }
+        NodeRef::CreateExtensionStmt(n) => {
+            fp.str_field("extname", &n.extname);
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.list_field("CreateExtensionStmt", "options", &n.options, depth);
+        }
+        NodeRef::AlterExtensionStmt(n) => {
+            fp.str_field("extname", &n.extname);
+            fp.list_field("AlterExtensionStmt", "options", &n.options, depth);
+        }
+        NodeRef::AlterExtensionContentsStmt(n) => {
+            fp.int_field("action", n.action.into());
+            fp.str_field("extname", &n.extname);
+            fp.node_field("AlterExtensionContentsStmt", "object", n.object.as_deref(), depth);
+            fp.enum_field("objtype", protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::CreateFdwStmt(n) => {
+            fp.str_field("fdwname", &n.fdwname);
+            fp.list_field("CreateFdwStmt", "func_options", &n.func_options, depth);
+            fp.list_field("CreateFdwStmt", "options", &n.options, depth);
+        }
+        NodeRef::AlterFdwStmt(n) => {
+            fp.str_field("fdwname", &n.fdwname);
+            fp.list_field("AlterFdwStmt", "func_options", &n.func_options, depth);
+            fp.list_field("AlterFdwStmt", "options", &n.options, depth);
+        }
+        NodeRef::CreateForeignServerStmt(n) => {
+            fp.str_field("fdwname", &n.fdwname);
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.list_field("CreateForeignServerStmt", "options", &n.options, depth);
+            fp.str_field("servername", &n.servername);
+            fp.str_field("servertype", &n.servertype);
+            fp.str_field("version", &n.version);
+        }
+        NodeRef::AlterForeignServerStmt(n) => {
+            fp.bool_field("has_version", n.has_version);
+            fp.list_field("AlterForeignServerStmt", "options", &n.options, depth);
+            fp.str_field("servername", &n.servername);
+            fp.str_field("version", &n.version);
+        }
+        NodeRef::CreateForeignTableStmt(n) => {
+            fp.typed_field("CreateForeignTableStmt", "base", n.base_stmt.as_ref().map(NodeRef::CreateStmt), depth);
+            fp.list_field("CreateForeignTableStmt", "options", &n.options, depth);
+            fp.str_field("servername", &n.servername);
+        }
+        NodeRef::CreateUserMappingStmt(n) => {
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.list_field("CreateUserMappingStmt", "options", &n.options, depth);
+            fp.str_field("servername", &n.servername);
+            fp.typed_field("CreateUserMappingStmt", "user", n.user.as_ref().map(NodeRef::RoleSpec), depth);
+        }
+        NodeRef::AlterUserMappingStmt(n) => {
+            fp.list_field("AlterUserMappingStmt", "options", &n.options, depth);
+            fp.str_field("servername", &n.servername);
+            fp.typed_field("AlterUserMappingStmt", "user", n.user.as_ref().map(NodeRef::RoleSpec), depth);
+        }
+        NodeRef::DropUserMappingStmt(n) => {
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.str_field("servername", &n.servername);
+            fp.typed_field("DropUserMappingStmt", "user", n.user.as_ref().map(NodeRef::RoleSpec), depth);
+        }
+        NodeRef::ImportForeignSchemaStmt(n) => {
+            fp.enum_field("list_type", protobuf::ImportForeignSchemaType::try_from(n.list_type).map_or("", |e| e.as_str_name()));
+            fp.str_field("local_schema", &n.local_schema);
+            fp.list_field("ImportForeignSchemaStmt", "options", &n.options, depth);
+            fp.str_field("remote_schema", &n.remote_schema);
+            fp.str_field("server_name", &n.server_name);
+            fp.list_field("ImportForeignSchemaStmt", "table_list", &n.table_list, depth);
+        }
+        NodeRef::CreatePolicyStmt(n) => {
+            fp.str_field("cmd_name", &n.cmd_name);
+            fp.bool_field("permissive", n.permissive);
+            fp.str_field("policy_name", &n.policy_name);
+            fp.node_field("CreatePolicyStmt", "qual", n.qual.as_deref(), depth);
+            fp.list_field("CreatePolicyStmt", "roles", &n.roles, depth);
+            fp.typed_field("CreatePolicyStmt", "table", n.table.as_ref().map(NodeRef::RangeVar), depth);
+            fp.node_field("CreatePolicyStmt", "with_check", n.with_check.as_deref(), depth);
+        }
+        NodeRef::AlterPolicyStmt(n) => {
+            fp.str_field("policy_name", &n.policy_name);
+            fp.node_field("AlterPolicyStmt", "qual", n.qual.as_deref(), depth);
+            fp.list_field("AlterPolicyStmt", "roles", &n.roles, depth);
+            fp.typed_field("AlterPolicyStmt", "table", n.table.as_ref().map(NodeRef::RangeVar), depth);
+            fp.node_field("AlterPolicyStmt", "with_check", n.with_check.as_deref(), depth);
+        }
+        NodeRef::CreateAmStmt(n) => {
+            fp.str_field("amname", &n.amname);
+            fp.str_field("amtype", &n.amtype);
+            fp.list_field("CreateAmStmt", "handler_name", &n.handler_name, depth);
+        }
+        NodeRef::CreateTrigStmt(n) => {
+            fp.list_field("CreateTrigStmt", "args", &n.args, depth);
+            fp.list_field("CreateTrigStmt", "columns", &n.columns, depth);
+            fp.typed_field("CreateTrigStmt", "constrrel", n.constrrel.as_ref().map(NodeRef::RangeVar), depth);
+            fp.bool_field("deferrable", n.deferrable);
+            fp.int_field("events", n.events.into());
+            fp.list_field("CreateTrigStmt", "funcname", &n.funcname, depth);
+            fp.bool_field("initdeferred", n.initdeferred);
+            fp.bool_field("isconstraint", n.isconstraint);
+            fp.typed_field("CreateTrigStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.bool_field("replace", n.replace);
+            fp.bool_field("row", n.row);
+            fp.int_field("timing", n.timing.into());
+            fp.list_field("CreateTrigStmt", "transitionRels", &n.transition_rels, depth);
+            fp.str_field("trigname", &n.trigname);
+            fp.node_field("CreateTrigStmt", "whenClause", n.when_clause.as_deref(), depth);
+        }
+        NodeRef::CreateEventTrigStmt(n) => {
+            fp.str_field("eventname", &n.eventname);
+            fp.list_field("CreateEventTrigStmt", "funcname", &n.funcname, depth);
+            fp.str_field("trigname", &n.trigname);
+            fp.list_field("CreateEventTrigStmt", "whenclause", &n.whenclause, depth);
+        }
+        NodeRef::AlterEventTrigStmt(n) => {
+            fp.str_field("tgenabled", &n.tgenabled);
+            fp.str_field("trigname", &n.trigname);
+        }
+        NodeRef::CreatePlangStmt(n) => {
+            fp.list_field("CreatePLangStmt", "plhandler", &n.plhandler, depth);
+            fp.list_field("CreatePLangStmt", "plinline", &n.plinline, depth);
+            fp.str_field("plname", &n.plname);
+            fp.bool_field("pltrusted", n.pltrusted);
+            fp.list_field("CreatePLangStmt", "plvalidator", &n.plvalidator, depth);
+            fp.bool_field("replace", n.replace);
+        }
+        NodeRef::CreateRoleStmt(n) => {
+            fp.list_field("CreateRoleStmt", "options", &n.options, depth);
+            fp.str_field("role", &n.role);
+            fp.enum_field("stmt_type", protobuf::RoleStmtType::try_from(n.stmt_type).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::AlterRoleStmt(n) => {
+            fp.int_field("action", n.action.into());
+            fp.list_field("AlterRoleStmt", "options", &n.options, depth);
+            fp.typed_field("AlterRoleStmt", "role", n.role.as_ref().map(NodeRef::RoleSpec), depth);
+        }
+        NodeRef::AlterRoleSetStmt(n) => {
+            fp.str_field("database", &n.database);
+            fp.typed_field("AlterRoleSetStmt", "role", n.role.as_ref().map(NodeRef::RoleSpec), depth);
+            fp.typed_field("AlterRoleSetStmt", "setstmt", n.setstmt.as_ref().map(NodeRef::VariableSetStmt), depth);
+        }
+        NodeRef::DropRoleStmt(n) => {
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.list_field("DropRoleStmt", "roles", &n.roles, depth);
+        }
+        NodeRef::CreateSeqStmt(n) => {
+            fp.bool_field("for_identity", n.for_identity);
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.list_field("CreateSeqStmt", "options", &n.options, depth);
+            fp.int_field("ownerId", n.owner_id.into());
+            fp.typed_field("CreateSeqStmt", "sequence", n.sequence.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::AlterSeqStmt(n) => {
+            fp.bool_field("for_identity", n.for_identity);
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.list_field("AlterSeqStmt", "options", &n.options, depth);
+            fp.typed_field("AlterSeqStmt", "sequence", n.sequence.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::DefineStmt(n) => {
+            fp.list_field("DefineStmt", "args", &n.args, depth);
+            fp.list_field("DefineStmt", "definition", &n.definition, depth);
+            fp.list_field("DefineStmt", "defnames", &n.defnames, depth);
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.enum_field("kind", protobuf::ObjectType::try_from(n.kind).map_or("", |e| e.as_str_name()));
+            fp.bool_field("oldstyle", n.oldstyle);
+            fp.bool_field("replace", n.replace);
+        }
+        NodeRef::CreateDomainStmt(n) => {
+            fp.typed_field("CreateDomainStmt", "collClause", n.coll_clause.as_deref().map(NodeRef::CollateClause), depth);
+            fp.list_field("CreateDomainStmt", "constraints", &n.constraints, depth);
+            fp.list_field("CreateDomainStmt", "domainname", &n.domainname, depth);
+            fp.typed_field("CreateDomainStmt", "typeName", n.type_name.as_ref().map(NodeRef::TypeName), depth);
+        }
+        NodeRef::CreateOpClassStmt(n) => {
+            fp.str_field("amname", &n.amname);
+            fp.typed_field("CreateOpClassStmt", "datatype", n.datatype.as_ref().map(NodeRef::TypeName), depth);
+            fp.bool_field("isDefault", n.is_default);
+            fp.list_field("CreateOpClassStmt", "items", &n.items, depth);
+            fp.list_field("CreateOpClassStmt", "opclassname", &n.opclassname, depth);
+            fp.list_field("CreateOpClassStmt", "opfamilyname", &n.opfamilyname, depth);
+        }
+        NodeRef::CreateOpClassItem(n) => {
+            fp.list_field("CreateOpClassItem", "class_args", &n.class_args, depth);
+            fp.int_field("itemtype", n.itemtype.into());
+            fp.typed_field("CreateOpClassItem", "name", n.name.as_ref().map(NodeRef::ObjectWithArgs), depth);
+            fp.int_field("number", n.number.into());
+            fp.list_field("CreateOpClassItem", "order_family", &n.order_family, depth);
+            fp.typed_field("CreateOpClassItem", "storedtype", n.storedtype.as_ref().map(NodeRef::TypeName), depth);
+        }
+        NodeRef::CreateOpFamilyStmt(n) => {
+            fp.str_field("amname", &n.amname);
+            fp.list_field("CreateOpFamilyStmt", "opfamilyname", &n.opfamilyname, depth);
+        }
+        NodeRef::AlterOpFamilyStmt(n) => {
+            fp.str_field("amname", &n.amname);
+            fp.bool_field("isDrop", n.is_drop);
+            fp.list_field("AlterOpFamilyStmt", "items", &n.items, depth);
+            fp.list_field("AlterOpFamilyStmt", "opfamilyname", &n.opfamilyname, depth);
+        }
+        NodeRef::DropStmt(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.bool_field("concurrent", n.concurrent);
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.list_field("DropStmt", "objects", &n.objects, depth);
+            fp.enum_field("removeType", protobuf::ObjectType::try_from(n.remove_type).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::TruncateStmt(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.list_field("TruncateStmt", "relations", &n.relations, depth);
+            fp.bool_field("restart_seqs", n.restart_seqs);
+        }
+        NodeRef::CommentStmt(n) => {
+            fp.str_field("comment", &n.comment);
+            fp.node_field("CommentStmt", "object", n.object.as_deref(), depth);
+            fp.enum_field("objtype", protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::SecLabelStmt(n) => {
+            fp.str_field("label", &n.label);
+            fp.node_field("SecLabelStmt", "object", n.object.as_deref(), depth);
+            fp.enum_field("objtype", protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name()));
+            fp.str_field("provider", &n.provider);
+        }
+        NodeRef::DeclareCursorStmt(n) => {
+            fp.int_field("options", n.options.into());
+            fp.node_field("DeclareCursorStmt", "query", n.query.as_deref(), depth);
+        }
+        NodeRef::ClosePortalStmt(_) => {}
+        NodeRef::FetchStmt(n) => {
+            fp.enum_field("direction", protobuf::FetchDirection::try_from(n.direction).map_or("", |e| e.as_str_name()));
+            fp.int_field("howMany", n.how_many);
+            fp.bool_field("ismove", n.ismove);
+        }
+        NodeRef::IndexStmt(n) => {
+            fp.str_field("accessMethod", &n.access_method);
+            fp.bool_field("concurrent", n.concurrent);
+            fp.bool_field("deferrable", n.deferrable);
+            fp.list_field("IndexStmt", "excludeOpNames", &n.exclude_op_names, depth);
+            fp.str_field("idxcomment", &n.idxcomment);
+            fp.str_field("idxname", &n.idxname);
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.list_field("IndexStmt", "indexIncludingParams", &n.index_including_params, depth);
+            fp.int_field("indexOid", n.index_oid.into());
+            fp.list_field("IndexStmt", "indexParams", &n.index_params, depth);
+            fp.bool_field("initdeferred", n.initdeferred);
+            fp.bool_field("isconstraint", n.isconstraint);
+            fp.bool_field("nulls_not_distinct", n.nulls_not_distinct);
+            fp.int_field("oldCreateSubid", n.old_create_subid.into());
+            fp.int_field("oldFirstRelfilelocatorSubid", n.old_first_relfilelocator_subid.into());
+            fp.int_field("oldNumber", n.old_number.into());
+            fp.list_field("IndexStmt", "options", &n.options, depth);
+            fp.bool_field("primary", n.primary);
+            fp.typed_field("IndexStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.bool_field("reset_default_tblspc", n.reset_default_tblspc);
+            fp.str_field("tableSpace", &n.table_space);
+            fp.bool_field("transformed", n.transformed);
+            fp.bool_field("unique", n.unique);
+            fp.node_field("IndexStmt", "whereClause", n.where_clause.as_deref(), depth);
+        }
+        NodeRef::CreateStatsStmt(n) => {
+            fp.list_field("CreateStatsStmt", "defnames", &n.defnames, depth);
+            fp.list_field("CreateStatsStmt", "exprs", &n.exprs, depth);
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.list_field("CreateStatsStmt", "relations", &n.relations, depth);
+            fp.list_field("CreateStatsStmt", "stat_types", &n.stat_types, depth);
+            fp.str_field("stxcomment", &n.stxcomment);
+            fp.bool_field("transformed", n.transformed);
+        }
+        NodeRef::StatsElem(n) => {
+            fp.node_field("StatsElem", "expr", n.expr.as_deref(), depth);
+            fp.str_field("name", &n.name);
+        }
+        NodeRef::AlterStatsStmt(n) => {
+            fp.list_field("AlterStatsStmt", "defnames", &n.defnames, depth);
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.node_field("AlterStatsStmt", "stxstattarget", n.stxstattarget.as_deref(), depth);
+        }
+        NodeRef::CreateFunctionStmt(n) => {
+            fp.list_field("CreateFunctionStmt", "funcname", &n.funcname, depth);
+            fp.bool_field("is_procedure", n.is_procedure);
+            fp.list_field("CreateFunctionStmt", "options", &n.options, depth);
+            fp.list_field("CreateFunctionStmt", "parameters", &n.parameters, depth);
+            fp.bool_field("replace", n.replace);
+            fp.typed_field("CreateFunctionStmt", "returnType", n.return_type.as_ref().map(NodeRef::TypeName), depth);
+            fp.node_field("CreateFunctionStmt", "sql_body", n.sql_body.as_deref(), depth);
+        }
+        NodeRef::FunctionParameter(n) => {
+            fp.typed_field("FunctionParameter", "argType", n.arg_type.as_ref().map(NodeRef::TypeName), depth);
+            fp.node_field("FunctionParameter", "defexpr", n.defexpr.as_deref(), depth);
+            fp.enum_field("mode", protobuf::FunctionParameterMode::try_from(n.mode).map_or("", |e| e.as_str_name()));
+            fp.str_field("name", &n.name);
+        }
+        NodeRef::AlterFunctionStmt(n) => {
+            fp.list_field("AlterFunctionStmt", "actions", &n.actions, depth);
+            fp.typed_field("AlterFunctionStmt", "func", n.func.as_ref().map(NodeRef::ObjectWithArgs), depth);
+            fp.enum_field("objtype", protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::DoStmt(n) => {
+            fp.list_field("DoStmt", "args", &n.args, depth);
+        }
+        NodeRef::InlineCodeBlock(n) => {
+            fp.bool_field("atomic", n.atomic);
+            fp.bool_field("langIsTrusted", n.lang_is_trusted);
+            fp.int_field("langOid", n.lang_oid.into());
+            fp.str_field("source_text", &n.source_text);
+        }
+        NodeRef::CallStmt(n) => {
+            fp.typed_field("CallStmt", "funccall", n.funccall.as_deref().map(NodeRef::FuncCall), depth);
+            fp.typed_field("CallStmt", "funcexpr", n.funcexpr.as_deref().map(NodeRef::FuncExpr), depth);
+            fp.list_field("CallStmt", "outargs", &n.outargs, depth);
+        }
+        NodeRef::CallContext(n) => {
+            fp.bool_field("atomic", n.atomic);
+        }
+        NodeRef::RenameStmt(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.str_field("newname", &n.newname);
+            fp.node_field("RenameStmt", "object", n.object.as_deref(), depth);
+            fp.typed_field("RenameStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.enum_field("relationType", protobuf::ObjectType::try_from(n.relation_type).map_or("", |e| e.as_str_name()));
+            fp.enum_field("renameType", protobuf::ObjectType::try_from(n.rename_type).map_or("", |e| e.as_str_name()));
+            fp.str_field("subname", &n.subname);
+        }
+        NodeRef::AlterObjectDependsStmt(n) => {
+            fp.typed_field("AlterObjectDependsStmt", "extname", n.extname.as_ref().map(NodeRef::String), depth);
+            fp.node_field("AlterObjectDependsStmt", "object", n.object.as_deref(), depth);
+            fp.enum_field("objectType", protobuf::ObjectType::try_from(n.object_type).map_or("", |e| e.as_str_name()));
+            fp.typed_field("AlterObjectDependsStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.bool_field("remove", n.remove);
+        }
+        NodeRef::AlterObjectSchemaStmt(n) => {
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.str_field("newschema", &n.newschema);
+            fp.node_field("AlterObjectSchemaStmt", "object", n.object.as_deref(), depth);
+            fp.enum_field("objectType", protobuf::ObjectType::try_from(n.object_type).map_or("", |e| e.as_str_name()));
+            fp.typed_field("AlterObjectSchemaStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::AlterOwnerStmt(n) => {
+            fp.typed_field("AlterOwnerStmt", "newowner", n.newowner.as_ref().map(NodeRef::RoleSpec), depth);
+            fp.node_field("AlterOwnerStmt", "object", n.object.as_deref(), depth);
+            fp.enum_field("objectType", protobuf::ObjectType::try_from(n.object_type).map_or("", |e| e.as_str_name()));
+            fp.typed_field("AlterOwnerStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::AlterOperatorStmt(n) => {
+            fp.typed_field("AlterOperatorStmt", "opername", n.opername.as_ref().map(NodeRef::ObjectWithArgs), depth);
+            fp.list_field("AlterOperatorStmt", "options", &n.options, depth);
+        }
+        NodeRef::AlterTypeStmt(n) => {
+            fp.list_field("AlterTypeStmt", "options", &n.options, depth);
+            fp.list_field("AlterTypeStmt", "typeName", &n.type_name, depth);
+        }
+        NodeRef::RuleStmt(n) => {
+            fp.list_field("RuleStmt", "actions", &n.actions, depth);
+            fp.enum_field("event", protobuf::CmdType::try_from(n.event).map_or("", |e| e.as_str_name()));
+            fp.bool_field("instead", n.instead);
+            fp.typed_field("RuleStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.bool_field("replace", n.replace);
+            fp.str_field("rulename", &n.rulename);
+            fp.node_field("RuleStmt", "whereClause", n.where_clause.as_deref(), depth);
+        }
+        NodeRef::NotifyStmt(n) => {
+            fp.str_field("conditionname", &n.conditionname);
+            fp.str_field("payload", &n.payload);
+        }
+        NodeRef::ListenStmt(n) => {
+            fp.str_field("conditionname", &n.conditionname);
+        }
+        NodeRef::UnlistenStmt(n) => {
+            fp.str_field("conditionname", &n.conditionname);
+        }
+        NodeRef::TransactionStmt(n) => {
+            fp.bool_field("chain", n.chain);
+            fp.enum_field("kind", protobuf::TransactionStmtKind::try_from(n.kind).map_or("", |e| e.as_str_name()));
+            fp.list_field("TransactionStmt", "options", &n.options, depth);
+        }
+        NodeRef::CompositeTypeStmt(n) => {
+            fp.list_field("CompositeTypeStmt", "coldeflist", &n.coldeflist, depth);
+            fp.typed_field("CompositeTypeStmt", "typevar", n.typevar.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::CreateEnumStmt(n) => {
+            fp.list_field("CreateEnumStmt", "typeName", &n.type_name, depth);
+            fp.list_field("CreateEnumStmt", "vals", &n.vals, depth);
+        }
+        NodeRef::CreateRangeStmt(n) => {
+            fp.list_field("CreateRangeStmt", "params", &n.params, depth);
+            fp.list_field("CreateRangeStmt", "typeName", &n.type_name, depth);
+        }
+        NodeRef::AlterEnumStmt(n) => {
+            fp.str_field("newVal", &n.new_val);
+            fp.bool_field("newValIsAfter", n.new_val_is_after);
+            fp.str_field("newValNeighbor", &n.new_val_neighbor);
+            fp.str_field("oldVal", &n.old_val);
+            fp.bool_field("skipIfNewValExists", n.skip_if_new_val_exists);
+            fp.list_field("AlterEnumStmt", "typeName", &n.type_name, depth);
+        }
+        NodeRef::ViewStmt(n) => {
+            fp.list_field("ViewStmt", "aliases", &n.aliases, depth);
+            fp.list_field("ViewStmt", "options", &n.options, depth);
+            fp.node_field("ViewStmt", "query", n.query.as_deref(), depth);
+            fp.bool_field("replace", n.replace);
+            fp.typed_field("ViewStmt", "view", n.view.as_ref().map(NodeRef::RangeVar), depth);
+            fp.enum_field("withCheckOption", protobuf::ViewCheckOption::try_from(n.with_check_option).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::LoadStmt(n) => {
+            fp.str_field("filename", &n.filename);
+        }
+        NodeRef::CreatedbStmt(n) => {
+            fp.str_field("dbname", &n.dbname);
+            fp.list_field("CreatedbStmt", "options", &n.options, depth);
+        }
+        NodeRef::AlterDatabaseStmt(n) => {
+            fp.str_field("dbname", &n.dbname);
+            fp.list_field("AlterDatabaseStmt", "options", &n.options, depth);
+        }
+        NodeRef::AlterDatabaseRefreshCollStmt(n) => {
+            fp.str_field("dbname", &n.dbname);
+        }
+        NodeRef::AlterDatabaseSetStmt(n) => {
+            fp.str_field("dbname", &n.dbname);
+            fp.typed_field("AlterDatabaseSetStmt", "setstmt", n.setstmt.as_ref().map(NodeRef::VariableSetStmt), depth);
+        }
+        NodeRef::DropdbStmt(n) => {
+            fp.str_field("dbname", &n.dbname);
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.list_field("DropdbStmt", "options", &n.options, depth);
+        }
+        NodeRef::AlterSystemStmt(n) => {
+            fp.typed_field("AlterSystemStmt", "setstmt", n.setstmt.as_ref().map(NodeRef::VariableSetStmt), depth);
+        }
+        NodeRef::ClusterStmt(n) => {
+            fp.str_field("indexname", &n.indexname);
+            fp.list_field("ClusterStmt", "params", &n.params, depth);
+            fp.typed_field("ClusterStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::VacuumStmt(n) => {
+            fp.bool_field("is_vacuumcmd", n.is_vacuumcmd);
+            fp.list_field("VacuumStmt", "options", &n.options, depth);
+            fp.list_field("VacuumStmt", "rels", &n.rels, depth);
+        }
+        NodeRef::VacuumRelation(n) => {
+            fp.int_field("oid", n.oid.into());
+            fp.typed_field("VacuumRelation", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.list_field("VacuumRelation", "va_cols", &n.va_cols, depth);
+        }
+        NodeRef::ExplainStmt(n) => {
+            fp.list_field("ExplainStmt", "options", &n.options, depth);
+            fp.node_field("ExplainStmt", "query", n.query.as_deref(), depth);
+        }
+        NodeRef::CreateTableAsStmt(n) => {
+            fp.bool_field("if_not_exists", n.if_not_exists);
+            fp.typed_field("CreateTableAsStmt", "into", n.into.as_deref().map(NodeRef::IntoClause), depth);
+            fp.bool_field("is_select_into", n.is_select_into);
+            fp.enum_field("objtype", protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name()));
+            fp.node_field("CreateTableAsStmt", "query", n.query.as_deref(), depth);
+        }
+        NodeRef::RefreshMatViewStmt(n) => {
+            fp.bool_field("concurrent", n.concurrent);
+            fp.typed_field("RefreshMatViewStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.bool_field("skipData", n.skip_data);
+        }
+        NodeRef::CheckPointStmt(_) => {}
+        NodeRef::DiscardStmt(n) => {
+            fp.enum_field("target", protobuf::DiscardMode::try_from(n.target).map_or("", |e| e.as_str_name()));
+        }
+        NodeRef::LockStmt(n) => {
+            fp.int_field("mode", n.mode.into());
+            fp.bool_field("nowait", n.nowait);
+            fp.list_field("LockStmt", "relations", &n.relations, depth);
+        }
+        NodeRef::ConstraintsSetStmt(n) => {
+            fp.list_field("ConstraintsSetStmt", "constraints", &n.constraints, depth);
+            fp.bool_field("deferred", n.deferred);
+        }
+        NodeRef::ReindexStmt(n) => {
+            fp.enum_field("kind", protobuf::ReindexObjectType::try_from(n.kind).map_or("", |e| e.as_str_name()));
+            fp.str_field("name", &n.name);
+            fp.list_field("ReindexStmt", "params", &n.params, depth);
+            fp.typed_field("ReindexStmt", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+        }
+        NodeRef::CreateConversionStmt(n) => {
+            fp.list_field("CreateConversionStmt", "conversion_name", &n.conversion_name, depth);
+            fp.bool_field("def", n.def);
+            fp.str_field("for_encoding_name", &n.for_encoding_name);
+            fp.list_field("CreateConversionStmt", "func_name", &n.func_name, depth);
+            fp.str_field("to_encoding_name", &n.to_encoding_name);
+        }
+        NodeRef::CreateCastStmt(n) => {
+            fp.enum_field("context", protobuf::CoercionContext::try_from(n.context).map_or("", |e| e.as_str_name()));
+            fp.typed_field("CreateCastStmt", "func", n.func.as_ref().map(NodeRef::ObjectWithArgs), depth);
+            fp.bool_field("inout", n.inout);
+            fp.typed_field("CreateCastStmt", "sourcetype", n.sourcetype.as_ref().map(NodeRef::TypeName), depth);
+            fp.typed_field("CreateCastStmt", "targettype", n.targettype.as_ref().map(NodeRef::TypeName), depth);
+        }
+        NodeRef::CreateTransformStmt(n) => {
+            fp.typed_field("CreateTransformStmt", "fromsql", n.fromsql.as_ref().map(NodeRef::ObjectWithArgs), depth);
+            fp.str_field("lang", &n.lang);
+            fp.bool_field("replace", n.replace);
+            fp.typed_field("CreateTransformStmt", "tosql", n.tosql.as_ref().map(NodeRef::ObjectWithArgs), depth);
+            fp.typed_field("CreateTransformStmt", "type_name", n.type_name.as_ref().map(NodeRef::TypeName), depth);
+        }
+        NodeRef::PrepareStmt(n) => {
+            fp.list_field("PrepareStmt", "argtypes", &n.argtypes, depth);
+            fp.node_field("PrepareStmt", "query", n.query.as_deref(), depth);
+        }
+        NodeRef::ExecuteStmt(n) => {
+            fp.list_field("ExecuteStmt", "params", &n.params, depth);
+        }
+        NodeRef::DeallocateStmt(n) => {
+            fp.bool_field("isall", n.isall);
+        }
+        NodeRef::DropOwnedStmt(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.list_field("DropOwnedStmt", "roles", &n.roles, depth);
+        }
+        NodeRef::ReassignOwnedStmt(n) => {
+            fp.typed_field("ReassignOwnedStmt", "newrole", n.newrole.as_ref().map(NodeRef::RoleSpec), depth);
+            fp.list_field("ReassignOwnedStmt", "roles", &n.roles, depth);
+        }
+        NodeRef::AlterTsdictionaryStmt(n) => {
+            fp.list_field("AlterTSDictionaryStmt", "dictname", &n.dictname, depth);
+            fp.list_field("AlterTSDictionaryStmt", "options", &n.options, depth);
+        }
+        NodeRef::AlterTsconfigurationStmt(n) => {
+            fp.list_field("AlterTSConfigurationStmt", "cfgname", &n.cfgname, depth);
+            fp.list_field("AlterTSConfigurationStmt", "dicts", &n.dicts, depth);
+            fp.enum_field("kind", protobuf::AlterTsConfigType::try_from(n.kind).map_or("", |e| e.as_str_name()));
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.bool_field("override", n.r#override);
+            fp.bool_field("replace", n.replace);
+            fp.list_field("AlterTSConfigurationStmt", "tokentype", &n.tokentype, depth);
+        }
+        NodeRef::PublicationTable(n) => {
+            fp.list_field("PublicationTable", "columns", &n.columns, depth);
+            fp.typed_field("PublicationTable", "relation", n.relation.as_ref().map(NodeRef::RangeVar), depth);
+            fp.node_field("PublicationTable", "whereClause", n.where_clause.as_deref(), depth);
+        }
+        NodeRef::PublicationObjSpec(n) => {
+            fp.str_field("name", &n.name);
+            fp.enum_field("pubobjtype", protobuf::PublicationObjSpecType::try_from(n.pubobjtype).map_or("", |e| e.as_str_name()));
+            fp.typed_field("PublicationObjSpec", "pubtable", n.pubtable.as_deref().map(NodeRef::PublicationTable), depth);
+        }
+        NodeRef::CreatePublicationStmt(n) => {
+            fp.bool_field("for_all_tables", n.for_all_tables);
+            fp.list_field("CreatePublicationStmt", "options", &n.options, depth);
+            fp.str_field("pubname", &n.pubname);
+            fp.list_field("CreatePublicationStmt", "pubobjects", &n.pubobjects, depth);
+        }
+        NodeRef::AlterPublicationStmt(n) => {
+            fp.enum_field("action", protobuf::AlterPublicationAction::try_from(n.action).map_or("", |e| e.as_str_name()));
+            fp.bool_field("for_all_tables", n.for_all_tables);
+            fp.list_field("AlterPublicationStmt", "options", &n.options, depth);
+            fp.str_field("pubname", &n.pubname);
+            fp.list_field("AlterPublicationStmt", "pubobjects", &n.pubobjects, depth);
+        }
+        NodeRef::CreateSubscriptionStmt(n) => {
+            fp.str_field("conninfo", &n.conninfo);
+            fp.list_field("CreateSubscriptionStmt", "options", &n.options, depth);
+            fp.list_field("CreateSubscriptionStmt", "publication", &n.publication, depth);
+            fp.str_field("subname", &n.subname);
+        }
+        NodeRef::AlterSubscriptionStmt(n) => {
+            fp.str_field("conninfo", &n.conninfo);
+            fp.enum_field("kind", protobuf::AlterSubscriptionType::try_from(n.kind).map_or("", |e| e.as_str_name()));
+            fp.list_field("AlterSubscriptionStmt", "options", &n.options, depth);
+            fp.list_field("AlterSubscriptionStmt", "publication", &n.publication, depth);
+            fp.str_field("subname", &n.subname);
+        }
+        NodeRef::DropSubscriptionStmt(n) => {
+            fp.enum_field("behavior", protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name()));
+            fp.bool_field("missing_ok", n.missing_ok);
+            fp.str_field("subname", &n.subname);
+        }
+        NodeRef::Integer(_) => {}
+        NodeRef::Float(_) => {}
+        NodeRef::Boolean(_) => {}
+        NodeRef::String(_) => {}
+        NodeRef::BitString(_) => {}
+        NodeRef::List(_) => {}
+        NodeRef::IntList(n) => {
+            fp.list_field("IntList", "items", &n.items, depth);
+        }
+        NodeRef::OidList(n) => {
+            fp.list_field("OidList", "items", &n.items, depth);
+        }
+        NodeRef::AConst(_) => {}
+    }
+}
+
+// The name of the node type in the C structs, e.g. `A_Expr` for `AExpr`
+fn c_type_name(node: NodeRef) -> &'static str {
+    match node.type_name() {
+        "AExpr" => "A_Expr",
+        "AConst" => "A_Const",
+        "AStar" => "A_Star",
+        "AIndices" => "A_Indices",
+        "AIndirection" => "A_Indirection",
+        "AArrayExpr" => "A_ArrayExpr",
+        "CoerceViaIo" => "CoerceViaIO",
+        "SqlvalueFunction" => "SQLValueFunction",
+        "RtepermissionInfo" => "RTEPermissionInfo",
+        "CtesearchClause" => "CTESearchClause",
+        "CtecycleClause" => "CTECycleClause",
+        "PlassignStmt" => "PLAssignStmt",
+        "CreatePlangStmt" => "CreatePLangStmt",
+        "AlterTsconfigurationStmt" => "AlterTSConfigurationStmt",
+        "AlterTsdictionaryStmt" => "AlterTSDictionaryStmt",
+        name => name,
+    }
+}
+// END GENERATED
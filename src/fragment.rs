@@ -0,0 +1,183 @@
+use crate::*;
+
+/// Deparses any node, including nodes that are only valid as part of a statement.
+///
+/// libpg_query can only deparse whole statements, so fragments are embedded into a minimal
+/// statement, and that statement's fixed prefix and suffix are removed from the output again.
+pub(crate) fn deparse_node(node: &NodeEnum) -> Result<String> {
+    match node {
+        // Expressions
+        NodeEnum::AExpr(_)
+        | NodeEnum::AConst(_)
+        | NodeEnum::AArrayExpr(_)
+        | NodeEnum::AIndirection(_)
+        | NodeEnum::BoolExpr(_)
+        | NodeEnum::BooleanTest(_)
+        | NodeEnum::CaseExpr(_)
+        | NodeEnum::CoalesceExpr(_)
+        | NodeEnum::CollateClause(_)
+        | NodeEnum::ColumnRef(_)
+        | NodeEnum::FuncCall(_)
+        | NodeEnum::GroupingFunc(_)
+        | NodeEnum::JsonArrayAgg(_)
+        | NodeEnum::JsonArrayConstructor(_)
+        | NodeEnum::JsonArrayQueryConstructor(_)
+        | NodeEnum::JsonFuncExpr(_)
+        | NodeEnum::JsonIsPredicate(_)
+        | NodeEnum::JsonObjectAgg(_)
+        | NodeEnum::JsonObjectConstructor(_)
+        | NodeEnum::JsonParseExpr(_)
+        | NodeEnum::JsonScalarExpr(_)
+        | NodeEnum::JsonSerializeExpr(_)
+        | NodeEnum::MergeSupportFunc(_)
+        | NodeEnum::MinMaxExpr(_)
+        | NodeEnum::NullTest(_)
+        | NodeEnum::ParamRef(_)
+        | NodeEnum::RowExpr(_)
+        | NodeEnum::SetToDefault(_)
+        | NodeEnum::SqlvalueFunction(_)
+        | NodeEnum::SubLink(_)
+        | NodeEnum::TypeCast(_)
+        | NodeEnum::XmlExpr(_)
+        | NodeEnum::XmlSerialize(_) => {
+            let target = NodeEnum::ResTarget(Box::new(protobuf::ResTarget { val: Some(boxed(node.clone())), ..Default::default() }));
+            deparse_wrapped(select(protobuf::SelectStmt { target_list: vec![wrap(target)], ..select_defaults() }), "SELECT ", "")
+        }
+        // `WHERE CURRENT OF cursor` is only valid as a WHERE clause
+        NodeEnum::CurrentOfExpr(_) => {
+            deparse_wrapped(select(protobuf::SelectStmt { where_clause: Some(boxed(node.clone())), ..select_defaults() }), "SELECT WHERE ", "")
+        }
+        // A target list entry, e.g. `a + 1 AS b`
+        NodeEnum::ResTarget(_) => {
+            deparse_wrapped(select(protobuf::SelectStmt { target_list: vec![wrap(node.clone())], ..select_defaults() }), "SELECT ", "")
+        }
+        // An ORDER BY item, e.g. `a DESC NULLS LAST`
+        NodeEnum::SortBy(_) => {
+            deparse_wrapped(select(protobuf::SelectStmt { sort_clause: vec![wrap(node.clone())], ..select_defaults() }), "SELECT ORDER BY ", "")
+        }
+        // A named WINDOW clause entry, e.g. `w AS (PARTITION BY a)`
+        NodeEnum::WindowDef(w) if !w.name.is_empty() && has_window_spec(w) => {
+            deparse_wrapped(select(protobuf::SelectStmt { window_clause: vec![wrap(node.clone())], ..select_defaults() }), "SELECT WINDOW ", "")
+        }
+        // An OVER clause, e.g. `(PARTITION BY a ORDER BY b)` or a reference to a named window
+        NodeEnum::WindowDef(w) => {
+            let call = NodeEnum::FuncCall(Box::new(protobuf::FuncCall {
+                funcname: vec![wrap(NodeEnum::String(protobuf::String { sval: "f".to_string() }))],
+                over: Some(Box::new((**w).clone())),
+                funcformat: protobuf::CoercionForm::CoerceExplicitCall as i32,
+                ..Default::default()
+            }));
+            let target = NodeEnum::ResTarget(Box::new(protobuf::ResTarget { val: Some(boxed(call)), ..Default::default() }));
+            deparse_wrapped(select(protobuf::SelectStmt { target_list: vec![wrap(target)], ..select_defaults() }), "SELECT f() OVER ", "")
+        }
+        // FROM clause items
+        NodeEnum::RangeVar(_)
+        | NodeEnum::JoinExpr(_)
+        | NodeEnum::RangeSubselect(_)
+        | NodeEnum::RangeFunction(_)
+        | NodeEnum::RangeTableSample(_)
+        | NodeEnum::RangeTableFunc(_)
+        | NodeEnum::JsonTable(_) => {
+            deparse_wrapped(select(protobuf::SelectStmt { from_clause: vec![wrap(node.clone())], ..select_defaults() }), "SELECT FROM ", "")
+        }
+        // A WITH clause entry, e.g. `a AS (SELECT 1)`
+        NodeEnum::CommonTableExpr(_) => {
+            let with_clause = protobuf::WithClause { ctes: vec![wrap(node.clone())], ..Default::default() };
+            deparse_wrapped(select(protobuf::SelectStmt { with_clause: Some(with_clause), ..select_defaults() }), "WITH ", " SELECT")
+        }
+        // A type name, e.g. `varchar(20)[]`
+        NodeEnum::TypeName(t) => {
+            let column = protobuf::ColumnDef { colname: "c".to_string(), type_name: Some(t.clone()), is_local: true, ..Default::default() };
+            let create = NodeEnum::CreateStmt(protobuf::CreateStmt {
+                relation: Some(relation()),
+                table_elts: vec![wrap(NodeEnum::ColumnDef(Box::new(column)))],
+                oncommit: protobuf::OnCommitAction::OncommitNoop as i32,
+                ..Default::default()
+            });
+            deparse_wrapped(create, "CREATE TABLE x (c ", ")")
+        }
+        _ if node.to_ref().type_name().ends_with("Stmt") => deparse_stmt(node.clone()),
+        // The deparser aborts on nodes it doesn't expect at the top level
+        _ => Err(Error::Parse(Box::new(format!("{} can't be deparsed on its own", node.to_ref().type_name()).into()))),
+    }
+}
+
+/// A clause that holds a list of items, see [deparse_clause].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ListClause {
+    /// The target list of a `SELECT`, e.g. `a, b + 1 AS c`
+    TargetList,
+    /// The rows of a `VALUES` list, e.g. `(1, 'a'), (2, 'b')`
+    ValuesLists,
+    /// The `SET` list of an `UPDATE`, e.g. `a = 1, b = 2`
+    SetClause,
+    /// The column list of an `INSERT`, e.g. `a, b`
+    InsertColumns,
+}
+
+/// Deparses the items of a clause, separated by commas like in the clause itself.
+///
+/// Unlike with [deparse_node], items are deparsed in the context of their clause, e.g. a `ResTarget`
+/// of a `SET` list becomes `a = 1` rather than `1 AS a`.
+pub(crate) fn deparse_clause(clause: ListClause, items: Vec<Node>) -> Result<String> {
+    match clause {
+        ListClause::TargetList => deparse_wrapped(select(protobuf::SelectStmt { target_list: items, ..select_defaults() }), "SELECT ", ""),
+        ListClause::ValuesLists => deparse_wrapped(select(protobuf::SelectStmt { values_lists: items, ..select_defaults() }), "VALUES ", ""),
+        ListClause::SetClause => {
+            let update = protobuf::UpdateStmt { relation: Some(relation()), target_list: items, ..Default::default() };
+            deparse_wrapped(NodeEnum::UpdateStmt(Box::new(update)), "UPDATE x SET ", "")
+        }
+        ListClause::InsertColumns => {
+            let insert = protobuf::InsertStmt {
+                relation: Some(relation()),
+                cols: items,
+                r#override: protobuf::OverridingKind::OverridingNotSet as i32,
+                ..Default::default()
+            };
+            deparse_wrapped(NodeEnum::InsertStmt(Box::new(insert)), "INSERT INTO x (", ") DEFAULT VALUES")
+        }
+    }
+}
+
+fn deparse_stmt(node: NodeEnum) -> Result<String> {
+    crate::deparse(&protobuf::ParseResult {
+        version: crate::bindings::PG_VERSION_NUM as i32,
+        stmts: vec![protobuf::RawStmt { stmt: Some(boxed(node)), stmt_location: 0, stmt_len: 0 }],
+    })
+}
+
+fn deparse_wrapped(stmt: NodeEnum, prefix: &str, suffix: &str) -> Result<String> {
+    let output = deparse_stmt(stmt)?;
+    output
+        .strip_prefix(prefix)
+        .and_then(|s| s.strip_suffix(suffix))
+        .map(|s| s.to_string())
+        .ok_or_else(|| Error::Parse(Box::new(format!("unexpected deparser output for fragment: {output}").into())))
+}
+
+// From Postgres source: src/include/nodes/parsenodes.h (RANGE UNBOUNDED PRECEDING AND CURRENT ROW)
+const FRAMEOPTION_DEFAULTS: i32 = 0x00002 | 0x00020 | 0x00400;
+
+fn has_window_spec(w: &protobuf::WindowDef) -> bool {
+    !w.refname.is_empty() || !w.partition_clause.is_empty() || !w.order_clause.is_empty() || w.frame_options != FRAMEOPTION_DEFAULTS
+}
+
+fn select_defaults() -> protobuf::SelectStmt {
+    protobuf::SelectStmt { limit_option: protobuf::LimitOption::Default as i32, op: protobuf::SetOperation::SetopNone as i32, ..Default::default() }
+}
+
+fn relation() -> protobuf::RangeVar {
+    protobuf::RangeVar { relname: "x".to_string(), inh: true, relpersistence: "p".to_string(), ..Default::default() }
+}
+
+fn select(stmt: protobuf::SelectStmt) -> NodeEnum {
+    NodeEnum::SelectStmt(Box::new(stmt))
+}
+
+fn wrap(node: NodeEnum) -> Node {
+    Node { node: Some(node) }
+}
+
+fn boxed(node: NodeEnum) -> Box<Node> {
+    Box::new(wrap(node))
+}
@@ -38,29 +38,58 @@
 //! ```
 //!
 
+mod anonymize;
 mod bindings;
+pub mod builder;
+mod denormalize;
+mod diff;
 mod error;
+mod fingerprint;
+mod fragment;
+mod lineage;
+mod locks;
 mod node_enum;
+mod node_fields;
+mod node_iter;
 mod node_mut;
+mod node_path;
 mod node_ref;
 mod node_structs;
 mod parse_result;
 #[rustfmt::skip]
 pub mod protobuf;
 mod query;
+mod redact;
+mod rewrite;
+mod semantic;
+mod span;
 mod summary;
 mod summary_result;
+mod trigger;
 mod truncate;
+pub mod visitor;
 
+pub use anonymize::*;
+pub use denormalize::*;
+pub use diff::*;
 pub use error::*;
+pub use lineage::*;
 pub use node_enum::*;
+pub use node_iter::*;
 pub use node_mut::*;
+pub use node_path::*;
 pub use node_ref::*;
 pub use parse_result::*;
 pub use query::*;
+pub use redact::*;
+pub use rewrite::*;
+pub use semantic::*;
+pub use span::*;
 pub use summary::*;
 pub use summary_result::*;
+pub use trigger::*;
 pub use truncate::*;
+pub use visitor::{Visitor, VisitorMut};
 
 pub use protobuf::Node;
 
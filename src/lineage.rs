@@ -0,0 +1,700 @@
+use std::collections::{BTreeSet, HashSet};
+use std::rc::Rc;
+
+use crate::node_path::for_each_child;
+use crate::protobuf::{self, CmdType};
+use crate::*;
+
+/// A table column read or written by a statement, see [ParseResult::column_lineage].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TableColumn {
+    pub schema: Option<String>,
+    /// `None` for an unqualified column that could belong to more than one table of the query
+    pub table: Option<String>,
+    /// The column name, or `*` for `SELECT *`, `t.*` and `INSERT` without a column list
+    pub column: String,
+}
+
+/// The table columns read and written by one statement.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColumnLineage {
+    /// The node type of the statement, e.g. `UpdateStmt`
+    pub statement_type: &'static str,
+    /// Columns read anywhere in the statement, including the target list, predicates, `GROUP BY`, `ORDER BY`
+    /// and `RETURNING`, sorted
+    pub reads: Vec<TableColumn>,
+    /// Columns written by `INSERT` column lists, `UPDATE SET` and `MERGE` actions, sorted
+    pub writes: Vec<TableColumn>,
+}
+
+impl ParseResult {
+    /// Returns the table columns read and written by each statement.
+    ///
+    /// References through table aliases, subqueries and CTEs are resolved to the underlying table. Unqualified
+    /// columns are attributed to the table they must come from, which is only known if the query selects from a
+    /// single table, or the column is defined by a subquery or CTE.
+    ///
+    /// Column names are not checked against a schema, so a `*` is reported as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::TableColumn;
+    ///
+    /// let result = pg_query::parse(
+    ///     "WITH recent AS (SELECT id, total FROM orders WHERE created_at > now() - interval '1 day')
+    ///      UPDATE customers c SET spent = c.spent + r.total FROM recent r WHERE c.id = r.id",
+    /// )
+    /// .unwrap();
+    /// let lineage = &result.column_lineage()[0];
+    /// let column = |table: &str, column: &str| TableColumn { schema: None, table: Some(table.into()), column: column.into() };
+    /// assert_eq!(lineage.statement_type, "UpdateStmt");
+    /// assert_eq!(
+    ///     lineage.reads,
+    ///     [column("customers", "id"), column("customers", "spent"), column("orders", "created_at"), column("orders", "id"), column("orders", "total")]
+    /// );
+    /// assert_eq!(lineage.writes, [column("customers", "spent")]);
+    /// ```
+    pub fn column_lineage(&self) -> Vec<ColumnLineage> {
+        self.protobuf
+            .stmts
+            .iter()
+            .filter_map(|s| s.stmt.as_ref().and_then(|s| s.node.as_ref()))
+            .map(|node| {
+                let mut resolver = Resolver::default();
+                resolver.statement(node.to_ref());
+                ColumnLineage {
+                    statement_type: node.to_ref().type_name(),
+                    reads: resolver.reads.into_iter().collect(),
+                    writes: resolver.writes.into_iter().collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+// Name resolution results used by [ParseResult] and [SummaryResult]
+pub(crate) struct Names {
+    // Columns referenced by `WHERE` conditions, including those of `MERGE` actions and partial indexes
+    pub(crate) filter_columns: BTreeSet<TableColumn>,
+    // The `RangeVar`s that refer to a CTE rather than a table, by address
+    pub(crate) cte_references: HashSet<*const protobuf::RangeVar>,
+}
+
+// Resolves the names of all statements like [ParseResult::column_lineage]
+pub(crate) fn resolve_names(protobuf: &protobuf::ParseResult) -> Names {
+    let mut resolver = Resolver::default();
+    for node in protobuf.stmts.iter().filter_map(|s| s.stmt.as_ref().and_then(|s| s.node.as_ref())) {
+        resolver.statement(node.to_ref());
+    }
+    Names { filter_columns: resolver.filters, cte_references: resolver.cte_references }
+}
+
+type Columns = BTreeSet<TableColumn>;
+
+// Something a query selects from
+#[derive(Clone, Debug)]
+enum Source {
+    Table { schema: Option<String>, name: String },
+    // A subquery or CTE
+    Derived(Rc<Relation>),
+    // A set-returning function, whose columns don't come from any table
+    Function,
+}
+
+// The output columns of a subquery, CTE or `RETURNING` list, with the table columns each one is computed from
+#[derive(Debug, Default)]
+struct Relation {
+    columns: Vec<(String, Columns)>,
+    // Sources whose columns are all passed through, by `*` or `t.*`
+    stars: Vec<Source>,
+}
+
+impl Relation {
+    // The relation with its columns renamed by a column alias list, as in `AS x(a, b)`
+    fn renamed(self: Rc<Self>, names: &[protobuf::Node]) -> Rc<Self> {
+        if names.is_empty() {
+            return self;
+        }
+        let mut columns = self.columns.clone();
+        for (column, name) in columns.iter_mut().zip(names.iter().map(string_value)) {
+            column.0 = name.to_string();
+        }
+        Rc::new(Relation { columns, stars: self.stars.clone() })
+    }
+}
+
+impl Source {
+    fn table(range_var: &protobuf::RangeVar) -> Self {
+        let schema = Some(range_var.schemaname.clone()).filter(|s| !s.is_empty());
+        Source::Table { schema, name: range_var.relname.clone() }
+    }
+
+    fn column(&self, column: &str) -> Columns {
+        match self {
+            Source::Table { schema, name } => {
+                Columns::from([TableColumn { schema: schema.clone(), table: Some(name.clone()), column: column.to_string() }])
+            }
+            Source::Derived(relation) => match relation.columns.iter().find(|(name, _)| name == column) {
+                Some((_, columns)) => columns.clone(),
+                None => relation.stars.iter().flat_map(|s| s.column(column)).collect(),
+            },
+            Source::Function => Columns::new(),
+        }
+    }
+
+    fn star(&self) -> Columns {
+        match self {
+            Source::Table { .. } => self.column("*"),
+            Source::Derived(relation) => {
+                relation.columns.iter().flat_map(|(_, columns)| columns.iter().cloned()).chain(relation.stars.iter().flat_map(Source::star)).collect()
+            }
+            Source::Function => Columns::new(),
+        }
+    }
+
+    // Whether a column of this name may come from the source
+    fn has_column(&self, column: &str) -> bool {
+        match self {
+            Source::Table { .. } | Source::Function => true,
+            Source::Derived(relation) => {
+                relation.columns.iter().any(|(name, _)| name == column) || relation.stars.iter().any(|s| s.has_column(column))
+            }
+        }
+    }
+
+    fn defines_column(&self, column: &str) -> bool {
+        matches!(self, Source::Derived(relation) if relation.columns.iter().any(|(name, _)| name == column))
+    }
+}
+
+// The names visible at one query level
+#[derive(Debug, Default)]
+struct Scope {
+    ctes: Vec<(String, Rc<Relation>)>,
+    sources: Vec<(String, Source)>,
+}
+
+#[derive(Default)]
+struct Resolver {
+    scopes: Vec<Scope>,
+    reads: Columns,
+    writes: Columns,
+    // Columns read by `WHERE` conditions, and whether such a condition is being resolved
+    filters: Columns,
+    filter: bool,
+    cte_references: HashSet<*const protobuf::RangeVar>,
+}
+
+impl Resolver {
+    fn scope(&mut self) -> &mut Scope {
+        self.scopes.last_mut().expect("no scope")
+    }
+
+    fn statement(&mut self, node: NodeRef) -> Rc<Relation> {
+        // Only the conditions of a subquery within a condition are filters, not its target list
+        let filter = std::mem::replace(&mut self.filter, false);
+        let relation = match node {
+            NodeRef::SelectStmt(s) => self.select(s),
+            NodeRef::InsertStmt(s) => self.insert(s),
+            NodeRef::UpdateStmt(s) => self.update(s),
+            NodeRef::DeleteStmt(s) => self.delete(s),
+            NodeRef::MergeStmt(s) => self.merge(s),
+            NodeRef::IndexStmt(s) => self.index(s),
+            // Statements containing queries, such as `CREATE VIEW`, `EXPLAIN` or `COPY`
+            _ => {
+                for_each_child(node, &mut |_, child| {
+                    self.statement(child);
+                });
+                Rc::default()
+            }
+        };
+        self.filter = filter;
+        relation
+    }
+
+    fn select(&mut self, s: &protobuf::SelectStmt) -> Rc<Relation> {
+        self.scopes.push(Scope::default());
+        if let Some(with) = &s.with_clause {
+            self.with(with);
+        }
+        let relation = match (&s.larg, &s.rarg) {
+            (Some(larg), Some(rarg)) => {
+                let left = self.select(larg);
+                let right = self.select(rarg);
+                let columns = left.columns.iter().enumerate().map(|(i, (name, columns))| {
+                    let mut columns = columns.clone();
+                    columns.extend(right.columns.get(i).into_iter().flat_map(|(_, c)| c.iter().cloned()));
+                    (name.clone(), columns)
+                });
+                Relation { columns: columns.collect(), stars: left.stars.iter().chain(&right.stars).cloned().collect() }
+            }
+            _ if !s.values_lists.is_empty() => {
+                let mut columns: Vec<(String, Columns)> = Vec::new();
+                for row in &s.values_lists {
+                    let Some(NodeEnum::List(row)) = &row.node else { continue };
+                    for (i, value) in nodes(&row.items).enumerate() {
+                        let value = self.expr(value.to_ref());
+                        match columns.get_mut(i) {
+                            Some((_, columns)) => columns.extend(value),
+                            None => columns.push((format!("column{}", i + 1), value)),
+                        }
+                    }
+                }
+                Relation { columns, stars: Vec::new() }
+            }
+            _ => {
+                let sources = self.sources(&s.from_clause);
+                self.scope().sources = sources;
+                let relation = self.targets(&s.target_list);
+                self.exprs(nodes(&s.distinct_clause));
+                self.filters(s.where_clause.iter().filter_map(|n| n.node.as_ref()));
+                self.exprs(nodes(&s.group_clause).filter(|n| !is_output_name(n, &relation)));
+                self.exprs(s.having_clause.iter().filter_map(|n| n.node.as_ref()));
+                self.exprs(nodes(&s.window_clause));
+                relation
+            }
+        };
+        self.exprs(nodes(&s.sort_clause).filter(|n| !is_output_name(n, &relation)));
+        self.exprs(s.limit_offset.iter().chain(&s.limit_count).filter_map(|n| n.node.as_ref()));
+        self.scopes.pop();
+        Rc::new(relation)
+    }
+
+    fn insert(&mut self, s: &protobuf::InsertStmt) -> Rc<Relation> {
+        let Some(relation) = &s.relation else { return Rc::default() };
+        self.scopes.push(Scope::default());
+        if let Some(with) = &s.with_clause {
+            self.with(with);
+        }
+        let target = Source::table(relation);
+        if s.cols.is_empty() {
+            self.writes.extend(target.column("*"));
+        }
+        for col in nodes(&s.cols) {
+            if let NodeEnum::ResTarget(col) = col {
+                self.writes.extend(target.column(&col.name));
+            }
+        }
+        if let Some(select) = s.select_stmt.as_ref().and_then(|n| n.node.as_ref()) {
+            self.statement(select.to_ref());
+        }
+        self.scope().sources = vec![(alias_or(&relation.alias, &relation.relname), target.clone())];
+        if let Some(on_conflict) = &s.on_conflict_clause {
+            self.scope().sources.push(("excluded".to_string(), target.clone()));
+            if let Some(infer) = &on_conflict.infer {
+                for elem in nodes(&infer.index_elems) {
+                    match elem {
+                        NodeEnum::IndexElem(elem) if !elem.name.is_empty() => self.reads.extend(target.column(&elem.name)),
+                        _ => self.exprs([elem]),
+                    }
+                }
+                self.exprs(infer.where_clause.iter().filter_map(|n| n.node.as_ref()));
+            }
+            self.assignments(&target, &on_conflict.target_list);
+            self.exprs(on_conflict.where_clause.iter().filter_map(|n| n.node.as_ref()));
+            self.scope().sources.pop();
+        }
+        let returning = self.targets(&s.returning_list);
+        self.scopes.pop();
+        Rc::new(returning)
+    }
+
+    fn update(&mut self, s: &protobuf::UpdateStmt) -> Rc<Relation> {
+        let Some(relation) = &s.relation else { return Rc::default() };
+        self.scopes.push(Scope::default());
+        if let Some(with) = &s.with_clause {
+            self.with(with);
+        }
+        let target = Source::table(relation);
+        let mut sources = vec![(alias_or(&relation.alias, &relation.relname), target.clone())];
+        sources.extend(self.sources(&s.from_clause));
+        self.scope().sources = sources;
+        self.assignments(&target, &s.target_list);
+        self.filters(s.where_clause.iter().filter_map(|n| n.node.as_ref()));
+        let returning = self.targets(&s.returning_list);
+        self.scopes.pop();
+        Rc::new(returning)
+    }
+
+    fn delete(&mut self, s: &protobuf::DeleteStmt) -> Rc<Relation> {
+        let Some(relation) = &s.relation else { return Rc::default() };
+        self.scopes.push(Scope::default());
+        if let Some(with) = &s.with_clause {
+            self.with(with);
+        }
+        let mut sources = vec![(alias_or(&relation.alias, &relation.relname), Source::table(relation))];
+        sources.extend(self.sources(&s.using_clause));
+        self.scope().sources = sources;
+        self.filters(s.where_clause.iter().filter_map(|n| n.node.as_ref()));
+        let returning = self.targets(&s.returning_list);
+        self.scopes.pop();
+        Rc::new(returning)
+    }
+
+    fn merge(&mut self, s: &protobuf::MergeStmt) -> Rc<Relation> {
+        let Some(relation) = &s.relation else { return Rc::default() };
+        self.scopes.push(Scope::default());
+        if let Some(with) = &s.with_clause {
+            self.with(with);
+        }
+        let target = Source::table(relation);
+        let mut sources = vec![(alias_or(&relation.alias, &relation.relname), target.clone())];
+        if let Some(source) = s.source_relation.as_ref().and_then(|n| n.node.as_ref()) {
+            let mut source_sources = Vec::new();
+            self.add_sources(source.to_ref(), &mut source_sources);
+            sources.extend(source_sources);
+        }
+        self.scope().sources = sources;
+        self.exprs(s.join_condition.iter().filter_map(|n| n.node.as_ref()));
+        for clause in nodes(&s.merge_when_clauses) {
+            let NodeEnum::MergeWhenClause(clause) = clause else { continue };
+            self.filters(clause.condition.iter().filter_map(|n| n.node.as_ref()));
+            match CmdType::try_from(clause.command_type) {
+                Ok(CmdType::CmdUpdate) => self.assignments(&target, &clause.target_list),
+                Ok(CmdType::CmdInsert) => {
+                    if clause.target_list.is_empty() {
+                        self.writes.extend(target.column("*"));
+                    }
+                    for col in nodes(&clause.target_list) {
+                        if let NodeEnum::ResTarget(col) = col {
+                            self.writes.extend(target.column(&col.name));
+                        }
+                    }
+                    self.exprs(nodes(&clause.values));
+                }
+                _ => {}
+            }
+        }
+        let returning = self.targets(&s.returning_list);
+        self.scopes.pop();
+        Rc::new(returning)
+    }
+
+    fn index(&mut self, s: &protobuf::IndexStmt) -> Rc<Relation> {
+        let Some(relation) = &s.relation else { return Rc::default() };
+        let table = Source::table(relation);
+        self.scopes.push(Scope { ctes: Vec::new(), sources: vec![(relation.relname.clone(), table.clone())] });
+        for param in nodes(&s.index_params).chain(nodes(&s.index_including_params)) {
+            match param {
+                NodeEnum::IndexElem(elem) if !elem.name.is_empty() => self.reads.extend(table.column(&elem.name)),
+                NodeEnum::IndexElem(elem) => self.exprs(elem.expr.iter().filter_map(|n| n.node.as_ref())),
+                _ => {}
+            }
+        }
+        // The predicate of a partial index
+        self.filters(s.where_clause.iter().filter_map(|n| n.node.as_ref()));
+        self.scopes.pop();
+        Rc::default()
+    }
+
+    // Defines the CTEs of a `WITH` clause in the current scope. Each CTE can refer to the ones before it, or with
+    // `RECURSIVE` to all CTEs of the clause including itself, while its name still refers to a table before that.
+    fn with(&mut self, with: &protobuf::WithClause) {
+        let ctes: Vec<&protobuf::CommonTableExpr> = nodes(&with.ctes)
+            .filter_map(|n| match n {
+                NodeEnum::CommonTableExpr(cte) => Some(cte.as_ref()),
+                _ => None,
+            })
+            .collect();
+        let first = self.scope().ctes.len();
+        if with.recursive {
+            self.scope().ctes.extend(ctes.iter().map(|cte| (cte.ctename.clone(), Rc::default())));
+        }
+        for (i, cte) in ctes.iter().enumerate() {
+            let query = cte.ctequery.as_ref().and_then(|n| n.node.as_ref());
+            if with.recursive {
+                // A self-reference has the columns of the non-recursive term, which comes before the `UNION`
+                if let Some(NodeEnum::SelectStmt(select)) = query {
+                    if let Some(anchor) = &select.larg {
+                        self.scope().ctes[first + i].1 = self.select(anchor).renamed(&cte.aliascolnames);
+                    }
+                }
+            }
+            let relation = match query {
+                Some(query) => self.statement(query.to_ref()).renamed(&cte.aliascolnames),
+                None => Rc::default(),
+            };
+            if with.recursive {
+                self.scope().ctes[first + i].1 = relation;
+            } else {
+                self.scope().ctes.push((cte.ctename.clone(), relation));
+            }
+        }
+    }
+
+    fn sources(&mut self, from_clause: &[protobuf::Node]) -> Vec<(String, Source)> {
+        let mut sources = Vec::new();
+        for item in nodes(from_clause) {
+            self.add_sources(item.to_ref(), &mut sources);
+        }
+        sources
+    }
+
+    // Adds the sources of a `FROM` item, and resolves the columns it reads
+    fn add_sources(&mut self, node: NodeRef, sources: &mut Vec<(String, Source)>) {
+        match node {
+            NodeRef::RangeVar(v) => {
+                let cte = match v.schemaname.as_str() {
+                    "" => self.scopes.iter().rev().find_map(|s| s.ctes.iter().rev().find(|(name, _)| *name == v.relname)),
+                    _ => None,
+                };
+                let source = match cte {
+                    Some((_, relation)) => Source::Derived(relation.clone()),
+                    None => Source::table(v),
+                };
+                if cte.is_some() {
+                    self.cte_references.insert(v as *const protobuf::RangeVar);
+                }
+                sources.push((alias_or(&v.alias, &v.relname), source));
+            }
+            NodeRef::RangeSubselect(s) => {
+                let Some(subquery) = s.subquery.as_ref().and_then(|n| n.node.as_ref()) else { return };
+                let relation = self.lateral(s.lateral, sources, |resolver| resolver.statement(subquery.to_ref()));
+                let names = s.alias.as_ref().map(|a| a.colnames.as_slice()).unwrap_or_default();
+                sources.push((alias_or(&s.alias, ""), Source::Derived(relation.renamed(names))));
+            }
+            NodeRef::RangeFunction(f) => {
+                // Function arguments can always refer to earlier `FROM` items
+                self.lateral(true, sources, |resolver| resolver.exprs(nodes(&f.functions)));
+                let name = f.alias.as_ref().map(|a| a.aliasname.clone()).or_else(|| function_name(&f.functions)).unwrap_or_default();
+                sources.push((name, Source::Function));
+            }
+            NodeRef::RangeTableSample(s) => {
+                if let Some(relation) = s.relation.as_ref().and_then(|n| n.node.as_ref()) {
+                    self.add_sources(relation.to_ref(), sources);
+                }
+                self.exprs(nodes(&s.args).chain(s.repeatable.iter().filter_map(|n| n.node.as_ref())));
+            }
+            NodeRef::JoinExpr(j) => {
+                let start = sources.len();
+                if let Some(larg) = j.larg.as_ref().and_then(|n| n.node.as_ref()) {
+                    self.add_sources(larg.to_ref(), sources);
+                }
+                let right = sources.len();
+                if let Some(rarg) = j.rarg.as_ref().and_then(|n| n.node.as_ref()) {
+                    self.add_sources(rarg.to_ref(), sources);
+                }
+                let mut using = Vec::new();
+                for name in j.using_clause.iter().map(string_value) {
+                    let columns: Columns =
+                        [&sources[start..right], &sources[right..]].into_iter().filter_map(|side| unqualified(side, name)).flatten().collect();
+                    self.reads.extend(columns.iter().cloned());
+                    using.push((name.to_string(), columns));
+                }
+                if let Some(quals) = j.quals.as_ref().and_then(|n| n.node.as_ref()) {
+                    self.lateral(true, sources, |resolver| resolver.exprs([quals]));
+                }
+                if let Some(alias) = &j.join_using_alias {
+                    let relation = Relation { columns: using, stars: Vec::new() };
+                    sources.push((alias.aliasname.clone(), Source::Derived(Rc::new(relation))));
+                }
+                // An alias hides the names of the joined tables
+                if let Some(alias) = &j.alias {
+                    let joined = sources.split_off(start).into_iter().map(|(_, source)| source).collect();
+                    sources.push((alias.aliasname.clone(), Source::Derived(Rc::new(Relation { columns: Vec::new(), stars: joined }))));
+                }
+            }
+            _ => {
+                self.expr(node);
+            }
+        }
+    }
+
+    // Calls `f` with the preceding `FROM` items visible if `lateral` is set
+    fn lateral<T>(&mut self, lateral: bool, sources: &[(String, Source)], f: impl FnOnce(&mut Self) -> T) -> T {
+        if !lateral {
+            return f(self);
+        }
+        let outer = std::mem::replace(&mut self.scope().sources, sources.to_vec());
+        let result = f(self);
+        self.scope().sources = outer;
+        result
+    }
+
+    // The output columns of a target list, reading the columns they are computed from
+    fn targets(&mut self, target_list: &[protobuf::Node]) -> Relation {
+        let mut relation = Relation::default();
+        for target in nodes(target_list) {
+            let NodeEnum::ResTarget(target) = target else { continue };
+            let Some(val) = target.val.as_ref().and_then(|n| n.node.as_ref()) else { continue };
+            match val {
+                NodeEnum::ColumnRef(c) if is_star(c) => {
+                    self.exprs([val]);
+                    relation.stars.extend(self.star_sources(c));
+                }
+                _ => {
+                    let columns = self.expr(val.to_ref());
+                    let name = if target.name.is_empty() { output_name(val) } else { target.name.clone() };
+                    relation.columns.push((name, columns));
+                }
+            }
+        }
+        relation
+    }
+
+    // `SET` assignments of `UPDATE`, `ON CONFLICT DO UPDATE` and `MERGE`
+    fn assignments(&mut self, target: &Source, target_list: &[protobuf::Node]) {
+        for assignment in nodes(target_list) {
+            let NodeEnum::ResTarget(assignment) = assignment else { continue };
+            self.writes.extend(target.column(&assignment.name));
+            self.exprs(assignment.val.iter().filter_map(|n| n.node.as_ref()).chain(nodes(&assignment.indirection)));
+        }
+    }
+
+    fn exprs<'a>(&mut self, nodes: impl IntoIterator<Item = &'a NodeEnum>) {
+        for node in nodes {
+            self.expr(node.to_ref());
+        }
+    }
+
+    fn filters<'a>(&mut self, nodes: impl IntoIterator<Item = &'a NodeEnum>) {
+        let filter = std::mem::replace(&mut self.filter, true);
+        self.exprs(nodes);
+        self.filter = filter;
+    }
+
+    // Reads the columns referenced by an expression, returning them
+    fn expr(&mut self, node: NodeRef) -> Columns {
+        match node {
+            NodeRef::ColumnRef(c) => {
+                let columns = self.column_ref(c);
+                self.reads.extend(columns.iter().cloned());
+                if self.filter {
+                    self.filters.extend(columns.iter().cloned());
+                }
+                columns
+            }
+            NodeRef::SelectStmt(_) | NodeRef::InsertStmt(_) | NodeRef::UpdateStmt(_) | NodeRef::DeleteStmt(_) | NodeRef::MergeStmt(_) => {
+                let relation = self.statement(node);
+                Source::Derived(relation).star()
+            }
+            _ => {
+                let mut columns = Columns::new();
+                for_each_child(node, &mut |_, child| columns.extend(self.expr(child)));
+                columns
+            }
+        }
+    }
+
+    fn column_ref(&self, c: &protobuf::ColumnRef) -> Columns {
+        let fields: Vec<&str> = c.fields.iter().map(string_value).collect();
+        let star = is_star(c);
+        match fields.as_slice() {
+            [.., schema, table, column] => {
+                let source = Source::Table { schema: Some(schema.to_string()), name: table.to_string() };
+                if star {
+                    source.star()
+                } else {
+                    source.column(column)
+                }
+            }
+            [qualifier, column] => {
+                let source = match self.source(qualifier) {
+                    Some(source) => source.clone(),
+                    None => Source::Table { schema: None, name: qualifier.to_string() },
+                };
+                if star {
+                    source.star()
+                } else {
+                    source.column(column)
+                }
+            }
+            [_] if star => self.star_sources(c).iter().flat_map(Source::star).collect(),
+            [column] => self
+                .scopes
+                .iter()
+                .rev()
+                .find_map(|s| unqualified(&s.sources, column))
+                .unwrap_or_else(|| Columns::from([TableColumn { schema: None, table: None, column: column.to_string() }])),
+            [] => Columns::new(),
+        }
+    }
+
+    // The sources whose columns are selected by `*` or `t.*`
+    fn star_sources(&self, c: &protobuf::ColumnRef) -> Vec<Source> {
+        match c.fields.iter().map(string_value).collect::<Vec<_>>().as_slice() {
+            [_] => self.scopes.iter().rev().map(|s| &s.sources).find(|s| !s.is_empty()).into_iter().flatten().map(|(_, s)| s.clone()).collect(),
+            [qualifier, _] => self.source(qualifier).into_iter().cloned().collect(),
+            [.., schema, table, _] => vec![Source::Table { schema: Some(schema.to_string()), name: table.to_string() }],
+            [] => Vec::new(),
+        }
+    }
+
+    // The source named `name`, searching from the innermost query outwards
+    fn source(&self, name: &str) -> Option<&Source> {
+        self.scopes.iter().rev().find_map(|s| s.sources.iter().rev().find(|(n, _)| n == name).map(|(_, source)| source))
+    }
+}
+
+// Resolves an unqualified column against the sources of one query level, or returns `None` if none of them can
+// have it
+fn unqualified(sources: &[(String, Source)], column: &str) -> Option<Columns> {
+    let candidates: Vec<&Source> = sources.iter().map(|(_, s)| s).filter(|s| s.has_column(column)).collect();
+    match candidates.as_slice() {
+        [] => None,
+        [source] => Some(source.column(column)),
+        _ => {
+            // A column defined by a subquery or CTE can't also be a column of another table, or it would be ambiguous
+            let defining: Vec<&&Source> = candidates.iter().filter(|s| s.defines_column(column)).collect();
+            match defining.as_slice() {
+                [source] => Some(source.column(column)),
+                _ => Some(Columns::from([TableColumn { schema: None, table: None, column: column.to_string() }])),
+            }
+        }
+    }
+}
+
+fn nodes(list: &[protobuf::Node]) -> impl Iterator<Item = &NodeEnum> {
+    list.iter().filter_map(|n| n.node.as_ref())
+}
+
+fn string_value(node: &protobuf::Node) -> &str {
+    match &node.node {
+        Some(NodeEnum::String(s)) => &s.sval,
+        _ => "",
+    }
+}
+
+fn is_star(c: &protobuf::ColumnRef) -> bool {
+    matches!(c.fields.last().and_then(|n| n.node.as_ref()), Some(NodeEnum::AStar(_)))
+}
+
+fn alias_or(alias: &Option<protobuf::Alias>, name: &str) -> String {
+    alias.as_ref().map_or(name, |a| &a.aliasname).to_string()
+}
+
+// Whether an `ORDER BY` or `GROUP BY` item refers to an output column by name, such as `ORDER BY total` for
+// `sum(amount) AS total`
+fn is_output_name(node: &NodeEnum, relation: &Relation) -> bool {
+    let node = match node {
+        NodeEnum::SortBy(s) => s.node.as_ref().and_then(|n| n.node.as_ref()),
+        node => Some(node),
+    };
+    match node {
+        Some(NodeEnum::ColumnRef(c)) if c.fields.len() == 1 => relation.columns.iter().any(|(name, _)| name == string_value(&c.fields[0])),
+        _ => false,
+    }
+}
+
+// The name Postgres gives an output column without an alias
+fn output_name(node: &NodeEnum) -> String {
+    match node {
+        NodeEnum::ColumnRef(c) => c.fields.last().map(string_value).unwrap_or_default().to_string(),
+        NodeEnum::FuncCall(f) => f.funcname.last().map(string_value).unwrap_or_default().to_string(),
+        NodeEnum::TypeCast(c) => match c.arg.as_ref().and_then(|n| n.node.as_ref()) {
+            Some(NodeEnum::AConst(_)) | None => c.type_name.as_ref().and_then(|t| t.names.last()).map(string_value).unwrap_or_default().to_string(),
+            Some(arg) => output_name(arg),
+        },
+        _ => "?column?".to_string(),
+    }
+}
+
+fn function_name(functions: &[protobuf::Node]) -> Option<String> {
+    // Each function is a list of the call and its column definitions
+    let Some(NodeEnum::List(list)) = functions.first().and_then(|n| n.node.as_ref()) else { return None };
+    match list.items.first().and_then(|n| n.node.as_ref()) {
+        Some(NodeEnum::FuncCall(f)) => f.funcname.last().map(|n| string_value(n).to_string()),
+        _ => None,
+    }
+}
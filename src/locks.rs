@@ -0,0 +1,397 @@
+use std::collections::HashSet;
+
+use crate::lineage::resolve_names;
+use crate::node_path::for_each_child;
+use crate::parse_result::relation_name;
+use crate::protobuf::{self, AlterTableType, ConstrType, ObjectType, ReindexObjectType};
+use crate::*;
+
+use crate::LockMode::*;
+
+impl ParseResult {
+    /// Returns the relations the statements lock, with the lock mode Postgres takes on each of them.
+    ///
+    /// Each statement locks a relation once, in the strongest mode it needs, e.g. `RowExclusiveLock` for the
+    /// target of an `UPDATE` that also reads it. Locks are listed in the order they appear, and a relation can
+    /// be listed with several modes if different statements lock it differently.
+    ///
+    /// This follows the rules of Postgres 17: queries take `AccessShareLock`, `FOR UPDATE`/`FOR SHARE` take
+    /// `RowShareLock`, and `INSERT`, `UPDATE`, `DELETE` and `MERGE` take `RowExclusiveLock` on their target.
+    /// `ALTER TABLE` takes the strongest level required by its subcommands, and other DDL and maintenance
+    /// statements take the level documented for them. Relations that are only known at runtime, such as the
+    /// partitions of a partitioned table or the tables of a bare `VACUUM`, aren't included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::LockMode;
+    ///
+    /// let result = pg_query::parse(
+    ///     "ALTER TABLE orders ADD CONSTRAINT fk_customer FOREIGN KEY (customer_id) REFERENCES customers (id) NOT VALID;
+    ///      ALTER TABLE orders VALIDATE CONSTRAINT fk_customer;
+    ///      CREATE INDEX CONCURRENTLY ON orders (created_at)",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     result.locks(),
+    ///     [
+    ///         ("orders".to_string(), LockMode::ShareRowExclusiveLock),
+    ///         ("customers".to_string(), LockMode::ShareRowExclusiveLock),
+    ///         ("orders".to_string(), LockMode::ShareUpdateExclusiveLock),
+    ///     ]
+    /// );
+    /// ```
+    pub fn locks(&self) -> Vec<(String, LockMode)> {
+        let names = resolve_names(&self.protobuf);
+        let mut locks = Vec::new();
+        for node in self.protobuf.stmts.iter().filter_map(|s| s.stmt.as_ref().and_then(|s| s.node.as_ref())) {
+            let mut statement = Locks { cte_references: &names.cte_references, locks: Vec::new() };
+            statement.statement(node.to_ref());
+            for lock in statement.locks {
+                if !locks.contains(&lock) {
+                    locks.push(lock);
+                }
+            }
+        }
+        locks
+    }
+}
+
+// The locks of one statement, in the strongest mode taken on each relation
+struct Locks<'a> {
+    cte_references: &'a HashSet<*const protobuf::RangeVar>,
+    locks: Vec<(String, LockMode)>,
+}
+
+impl Locks<'_> {
+    fn lock(&mut self, relation: String, mode: LockMode) {
+        match self.locks.iter_mut().find(|(r, _)| *r == relation) {
+            Some((_, m)) => *m = (*m).max(mode),
+            None => self.locks.push((relation, mode)),
+        }
+    }
+
+    fn relation(&mut self, relation: Option<&protobuf::RangeVar>, mode: LockMode) {
+        let Some(relation) = relation else { return };
+        if !self.cte_references.contains(&(relation as *const protobuf::RangeVar)) {
+            self.lock(relation_name(relation), mode);
+        }
+    }
+
+    fn relations(&mut self, relations: &[protobuf::Node], mode: LockMode) {
+        for relation in relations {
+            match &relation.node {
+                Some(NodeEnum::RangeVar(v)) => self.relation(Some(v), mode),
+                Some(NodeEnum::VacuumRelation(v)) => self.relation(v.relation.as_ref(), mode),
+                _ => {}
+            }
+        }
+    }
+
+    fn statement(&mut self, node: NodeRef) {
+        match node {
+            NodeRef::SelectStmt(_)
+            | NodeRef::InsertStmt(_)
+            | NodeRef::UpdateStmt(_)
+            | NodeRef::DeleteStmt(_)
+            | NodeRef::MergeStmt(_)
+            | NodeRef::ExplainStmt(_)
+            | NodeRef::DeclareCursorStmt(_)
+            | NodeRef::PrepareStmt(_) => self.query(node),
+            NodeRef::CreateTableAsStmt(s) => self.query_of(s.query.as_deref()),
+            NodeRef::ViewStmt(s) => {
+                // `CREATE OR REPLACE VIEW` has to wait for queries using the view, if it exists
+                if s.replace {
+                    self.relation(s.view.as_ref(), AccessExclusiveLock);
+                }
+                self.query_of(s.query.as_deref());
+            }
+            NodeRef::CopyStmt(s) => {
+                self.relation(s.relation.as_ref(), if s.is_from { RowExclusiveLock } else { AccessShareLock });
+                self.query_of(s.query.as_deref());
+            }
+            NodeRef::CreateStmt(s) => self.create_table(s),
+            NodeRef::AlterTableStmt(s) => self.alter_table(s),
+            NodeRef::IndexStmt(s) => self.relation(s.relation.as_ref(), if s.concurrent { ShareUpdateExclusiveLock } else { ShareLock }),
+            NodeRef::RenameStmt(s) => {
+                let mode = match ObjectType::try_from(s.rename_type) {
+                    Ok(ObjectType::ObjectIndex) => ShareUpdateExclusiveLock,
+                    Ok(
+                        ObjectType::ObjectTable
+                        | ObjectType::ObjectView
+                        | ObjectType::ObjectMatview
+                        | ObjectType::ObjectSequence
+                        | ObjectType::ObjectForeignTable
+                        | ObjectType::ObjectColumn
+                        | ObjectType::ObjectTabconstraint
+                        | ObjectType::ObjectTrigger
+                        | ObjectType::ObjectRule
+                        | ObjectType::ObjectPolicy,
+                    ) => AccessExclusiveLock,
+                    _ => return,
+                };
+                self.relation(s.relation.as_ref(), mode);
+            }
+            NodeRef::AlterObjectSchemaStmt(s) => self.relation(s.relation.as_ref(), AccessExclusiveLock),
+            NodeRef::DropStmt(s) => self.drop(s),
+            NodeRef::TruncateStmt(s) => self.relations(&s.relations, AccessExclusiveLock),
+            NodeRef::VacuumStmt(s) => {
+                let mode = if is_enabled(&s.options, "full") { AccessExclusiveLock } else { ShareUpdateExclusiveLock };
+                self.relations(&s.rels, mode);
+            }
+            NodeRef::ClusterStmt(s) => self.relation(s.relation.as_ref(), AccessExclusiveLock),
+            NodeRef::ReindexStmt(s) => {
+                let concurrently = is_enabled(&s.params, "concurrently");
+                let mode = match ReindexObjectType::try_from(s.kind) {
+                    Ok(_) if concurrently => ShareUpdateExclusiveLock,
+                    Ok(ReindexObjectType::ReindexObjectTable) => ShareLock,
+                    Ok(ReindexObjectType::ReindexObjectIndex) => AccessExclusiveLock,
+                    _ => return,
+                };
+                self.relation(s.relation.as_ref(), mode);
+            }
+            NodeRef::LockStmt(s) => {
+                if let Ok(mode) = LockMode::try_from(s.mode) {
+                    self.relations(&s.relations, mode);
+                }
+            }
+            NodeRef::RefreshMatViewStmt(s) => {
+                self.relation(s.relation.as_ref(), if s.concurrent { ExclusiveLock } else { AccessExclusiveLock });
+            }
+            NodeRef::CreateTrigStmt(s) => {
+                self.relation(s.relation.as_ref(), ShareRowExclusiveLock);
+                self.relation(s.constrrel.as_ref(), AccessShareLock);
+            }
+            NodeRef::RuleStmt(s) => self.relation(s.relation.as_ref(), AccessExclusiveLock),
+            NodeRef::CreatePolicyStmt(s) => self.relation(s.table.as_ref(), AccessExclusiveLock),
+            NodeRef::AlterPolicyStmt(s) => self.relation(s.table.as_ref(), AccessExclusiveLock),
+            NodeRef::AlterSeqStmt(s) => self.relation(s.sequence.as_ref(), ShareRowExclusiveLock),
+            NodeRef::CreateStatsStmt(s) => self.relations(&s.relations, ShareUpdateExclusiveLock),
+            _ => {}
+        }
+    }
+
+    fn query_of(&mut self, node: Option<&protobuf::Node>) {
+        if let Some(node) = node.and_then(|n| n.node.as_ref()) {
+            self.query(node.to_ref());
+        }
+    }
+
+    // The locks taken by parse analysis of a query, including its CTEs and subqueries
+    fn query(&mut self, node: NodeRef) {
+        match node {
+            NodeRef::RangeVar(v) => self.relation(Some(v), AccessShareLock),
+            // The table created by `SELECT INTO` doesn't exist yet
+            NodeRef::IntoClause(_) => return,
+            // Names `FROM` items rather than relations, handled with its `SelectStmt`
+            NodeRef::LockingClause(_) => return,
+            NodeRef::InsertStmt(s) => self.relation(s.relation.as_ref(), RowExclusiveLock),
+            NodeRef::UpdateStmt(s) => self.relation(s.relation.as_ref(), RowExclusiveLock),
+            NodeRef::DeleteStmt(s) => self.relation(s.relation.as_ref(), RowExclusiveLock),
+            NodeRef::MergeStmt(s) => self.relation(s.relation.as_ref(), RowExclusiveLock),
+            NodeRef::SelectStmt(s) => {
+                for clause in &s.locking_clause {
+                    if let Some(NodeEnum::LockingClause(clause)) = &clause.node {
+                        let names: Vec<&str> = clause
+                            .locked_rels
+                            .iter()
+                            .filter_map(|n| match &n.node {
+                                Some(NodeEnum::RangeVar(v)) => Some(v.relname.as_str()),
+                                _ => None,
+                            })
+                            .collect();
+                        for item in s.from_clause.iter().filter_map(|n| n.node.as_ref()) {
+                            self.locked_rows(item.to_ref(), &names);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        for_each_child(node, &mut |_, child| self.query(child));
+    }
+
+    // `FOR UPDATE` and `FOR SHARE` lock the tables of the `FROM` items they name, or all of them
+    fn locked_rows(&mut self, item: NodeRef, names: &[&str]) {
+        let name = match item {
+            NodeRef::RangeVar(v) => Some(v.alias.as_ref().map_or(&v.relname, |a| &a.aliasname)),
+            NodeRef::RangeSubselect(s) => s.alias.as_ref().map(|a| &a.aliasname),
+            NodeRef::JoinExpr(j) => match &j.alias {
+                Some(alias) => Some(&alias.aliasname),
+                None => {
+                    for arg in [&j.larg, &j.rarg].into_iter().flatten().filter_map(|n| n.node.as_ref()) {
+                        self.locked_rows(arg.to_ref(), names);
+                    }
+                    return;
+                }
+            },
+            _ => return,
+        };
+        if names.is_empty() || name.is_some_and(|name| names.contains(&name.as_str())) {
+            for node in item.iter() {
+                if let NodeRef::RangeVar(v) = node {
+                    self.relation(Some(v), RowShareLock);
+                }
+            }
+        }
+    }
+
+    fn create_table(&mut self, s: &protobuf::CreateStmt) {
+        // Attaching a new partition locks out all queries on the parent, inheriting only blocks schema changes
+        let mode = if s.partbound.is_some() { AccessExclusiveLock } else { ShareUpdateExclusiveLock };
+        self.relations(&s.inh_relations, mode);
+        for node in s.table_elts.iter().chain(&s.constraints).filter_map(|n| n.node.as_ref()) {
+            for node in node.iter() {
+                match node {
+                    NodeRef::TableLikeClause(like) => self.relation(like.relation.as_ref(), AccessShareLock),
+                    NodeRef::Constraint(c) => self.foreign_key(c),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Adding a foreign key creates triggers on the referenced table too
+    fn foreign_key(&mut self, constraint: &protobuf::Constraint) {
+        if constraint.contype == ConstrType::ConstrForeign as i32 {
+            self.relation(constraint.pktable.as_ref(), ShareRowExclusiveLock);
+        }
+    }
+
+    fn alter_table(&mut self, s: &protobuf::AlterTableStmt) {
+        let cmds: Vec<&protobuf::AlterTableCmd> = s
+            .cmds
+            .iter()
+            .filter_map(|n| match &n.node {
+                Some(NodeEnum::AlterTableCmd(cmd)) => Some(cmd.as_ref()),
+                _ => None,
+            })
+            .collect();
+        let Some(mode) = cmds.iter().map(|cmd| alter_table_cmd_lock(cmd)).max() else { return };
+        self.relation(s.relation.as_ref(), mode);
+
+        for cmd in cmds {
+            let Some(def) = cmd.def.as_ref().and_then(|n| n.node.as_ref()) else { continue };
+            match (AlterTableType::try_from(cmd.subtype), def) {
+                (Ok(AlterTableType::AtAttachPartition), NodeEnum::PartitionCmd(partition)) => {
+                    self.relation(partition.name.as_ref(), AccessExclusiveLock);
+                }
+                (Ok(AlterTableType::AtDetachPartition | AlterTableType::AtDetachPartitionFinalize), NodeEnum::PartitionCmd(partition)) => {
+                    let concurrent = partition.concurrent || cmd.subtype == AlterTableType::AtDetachPartitionFinalize as i32;
+                    self.relation(partition.name.as_ref(), if concurrent { ShareUpdateExclusiveLock } else { AccessExclusiveLock });
+                }
+                (Ok(AlterTableType::AtAddInherit), NodeEnum::RangeVar(parent)) => self.relation(Some(parent), ShareUpdateExclusiveLock),
+                (Ok(AlterTableType::AtDropInherit), NodeEnum::RangeVar(parent)) => self.relation(Some(parent), AccessShareLock),
+                _ => {
+                    for node in def.iter() {
+                        if let NodeRef::Constraint(c) = node {
+                            self.foreign_key(c);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn drop(&mut self, s: &protobuf::DropStmt) {
+        let (mode, qualifier_only) = match ObjectType::try_from(s.remove_type) {
+            Ok(ObjectType::ObjectIndex) if s.concurrent => (ShareUpdateExclusiveLock, false),
+            Ok(
+                ObjectType::ObjectTable
+                | ObjectType::ObjectView
+                | ObjectType::ObjectMatview
+                | ObjectType::ObjectSequence
+                | ObjectType::ObjectForeignTable
+                | ObjectType::ObjectIndex,
+            ) => (AccessExclusiveLock, false),
+            // Dropping a trigger, rule or policy locks its table, which precedes the object name
+            Ok(ObjectType::ObjectTrigger | ObjectType::ObjectRule | ObjectType::ObjectPolicy) => (AccessExclusiveLock, true),
+            _ => return,
+        };
+        for object in &s.objects {
+            let Some(NodeEnum::List(list)) = &object.node else { continue };
+            let names = if qualifier_only { &list.items[..list.items.len().saturating_sub(1)] } else { &list.items[..] };
+            let names: Vec<&str> = names
+                .iter()
+                .filter_map(|n| match &n.node {
+                    Some(NodeEnum::String(s)) => Some(s.sval.as_str()),
+                    _ => None,
+                })
+                .collect();
+            if !names.is_empty() {
+                self.lock(names.join("."), mode);
+            }
+        }
+    }
+}
+
+// From Postgres source: AlterTableGetLockLevel in src/backend/commands/tablecmds.c
+fn alter_table_cmd_lock(cmd: &protobuf::AlterTableCmd) -> LockMode {
+    let Ok(subtype) = AlterTableType::try_from(cmd.subtype) else { return AccessExclusiveLock };
+    match subtype {
+        // Only affect writes, by firing triggers
+        AlterTableType::AtEnableTrig
+        | AlterTableType::AtEnableAlwaysTrig
+        | AlterTableType::AtEnableReplicaTrig
+        | AlterTableType::AtEnableTrigAll
+        | AlterTableType::AtEnableTrigUser
+        | AlterTableType::AtDisableTrig
+        | AlterTableType::AtDisableTrigAll
+        | AlterTableType::AtDisableTrigUser => ShareRowExclusiveLock,
+        // Foreign keys add triggers, like `CREATE TRIGGER`, other constraints are as strong as `CREATE INDEX` or
+        // may change query plans
+        AlterTableType::AtAddConstraint | AlterTableType::AtReAddConstraint => match cmd.def.as_ref().and_then(|n| n.node.as_ref()) {
+            Some(NodeEnum::Constraint(c)) if c.contype == ConstrType::ConstrForeign as i32 => ShareRowExclusiveLock,
+            _ => AccessExclusiveLock,
+        },
+        // Only change statistics, maintenance or how future queries are planned
+        AlterTableType::AtSetStatistics
+        | AlterTableType::AtClusterOn
+        | AlterTableType::AtDropCluster
+        | AlterTableType::AtSetOptions
+        | AlterTableType::AtResetOptions
+        | AlterTableType::AtValidateConstraint
+        | AlterTableType::AtAttachPartition
+        | AlterTableType::AtDetachPartitionFinalize => ShareUpdateExclusiveLock,
+        AlterTableType::AtDetachPartition => match cmd.def.as_ref().and_then(|n| n.node.as_ref()) {
+            Some(NodeEnum::PartitionCmd(p)) if p.concurrent => ShareUpdateExclusiveLock,
+            _ => AccessExclusiveLock,
+        },
+        AlterTableType::AtSetRelOptions | AlterTableType::AtResetRelOptions => match cmd.def.as_ref().and_then(|n| n.node.as_ref()) {
+            Some(NodeEnum::List(options)) => options
+                .items
+                .iter()
+                .filter_map(|n| match &n.node {
+                    Some(NodeEnum::DefElem(option)) => Some(rel_option_lock(&option.defname)),
+                    _ => None,
+                })
+                .max()
+                .unwrap_or(AccessExclusiveLock),
+            _ => AccessExclusiveLock,
+        },
+        // Everything else rewrites the table or changes what concurrent queries see
+        _ => AccessExclusiveLock,
+    }
+}
+
+// From Postgres source: the lock modes of the options in src/backend/access/common/reloptions.c
+fn rel_option_lock(name: &str) -> LockMode {
+    let name = name.strip_prefix("toast.").unwrap_or(name);
+    match name {
+        "user_catalog_table" | "fastupdate" | "buffering" | "check_option" | "security_barrier" | "security_invoker" => AccessExclusiveLock,
+        _ => ShareUpdateExclusiveLock,
+    }
+}
+
+// Whether a boolean option such as `FULL` of `VACUUM` is set, which it is if given without a value
+fn is_enabled(options: &[protobuf::Node], name: &str) -> bool {
+    options.iter().any(|n| match &n.node {
+        Some(NodeEnum::DefElem(option)) if option.defname == name => match option.arg.as_ref().and_then(|n| n.node.as_ref()) {
+            Some(NodeEnum::Boolean(b)) => b.boolval,
+            Some(NodeEnum::Integer(i)) => i.ival != 0,
+            Some(NodeEnum::String(s)) => !matches!(s.sval.to_lowercase().as_str(), "false" | "off" | "0"),
+            _ => true,
+        },
+        _ => false,
+    })
+}
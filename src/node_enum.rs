@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use crate::*;
 
 pub use protobuf::node::Node as NodeEnum;
@@ -12,18 +14,28 @@ pub enum Context {
 }
 
 impl NodeEnum {
+    /// Converts the node back into SQL. Statements are deparsed as a whole, while expressions, type names, target list
+    /// entries, ORDER BY items, window definitions, CTEs and FROM clause items are deparsed as the bare fragment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let result = pg_query::parse("SELECT * FROM users u WHERE u.id = $1 AND u.name LIKE 'a%' ORDER BY u.id DESC").unwrap();
+    /// let stmt = result.protobuf.stmts[0].stmt.as_ref().and_then(|n| n.node.as_ref()).unwrap();
+    /// let pg_query::NodeEnum::SelectStmt(select) = stmt else { unreachable!() };
+    /// let where_clause = select.where_clause.as_ref().and_then(|n| n.node.as_ref()).unwrap();
+    /// assert_eq!(where_clause.deparse().unwrap(), "u.id = $1 AND u.name LIKE 'a%'");
+    /// assert_eq!(select.from_clause[0].deparse().unwrap(), "users u");
+    /// assert_eq!(select.sort_clause[0].deparse().unwrap(), "u.id DESC");
+    /// ```
     pub fn deparse(&self) -> Result<String> {
-        crate::deparse(&protobuf::ParseResult {
-            version: crate::bindings::PG_VERSION_NUM as i32,
-            stmts: vec![protobuf::RawStmt { stmt: Some(Box::new(Node { node: Some(self.clone()) })), stmt_location: 0, stmt_len: 0 }],
-        })
+        crate::fragment::deparse_node(self)
     }
 
     pub fn nodes(&self) -> Vec<(NodeRef<'_>, i32, Context, bool)> {
-        let mut iter = vec![(self.to_ref(), 0, Context::None, false)];
+        let mut iter = VecDeque::from([(self.to_ref(), 0, Context::None, false)]);
         let mut nodes = Vec::new();
-        while !iter.is_empty() {
-            let (node, depth, context, has_filter_columns) = iter.remove(0);
+        while let Some((node, depth, context, has_filter_columns)) = iter.pop_front() {
             let depth = depth + 1;
             match node {
                 //
@@ -32,33 +44,33 @@ impl NodeEnum {
                 NodeRef::SelectStmt(s) => {
                     s.target_list.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     if let Some(n) = &s.where_clause {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, true));
+                            iter.push_back((n.to_ref(), depth, Context::Select, true));
                         }
                     }
                     s.sort_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     s.group_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     if let Some(n) = &s.having_clause {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     }
                     if let Some(clause) = &s.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::Select, false));
+                                iter.push_back((n.to_ref(), depth, Context::Select, false));
                             }
                         });
                     }
@@ -66,32 +78,32 @@ impl NodeEnum {
                         Ok(protobuf::SetOperation::SetopNone) => {
                             s.from_clause.iter().for_each(|n| {
                                 if let Some(n) = n.node.as_ref() {
-                                    iter.push((n.to_ref(), depth, Context::Select, false));
+                                    iter.push_back((n.to_ref(), depth, Context::Select, false));
                                 }
                             });
                         }
                         Ok(protobuf::SetOperation::SetopUnion) => {
                             if let Some(left) = s.larg.as_ref() {
-                                iter.push((left.to_ref(), depth, Context::Select, false));
+                                iter.push_back((left.to_ref(), depth, Context::Select, false));
                             }
                             if let Some(right) = s.rarg.as_ref() {
-                                iter.push((right.to_ref(), depth, Context::Select, false));
+                                iter.push_back((right.to_ref(), depth, Context::Select, false));
                             }
                         }
                         Ok(protobuf::SetOperation::SetopExcept) => {
                             if let Some(left) = s.larg.as_ref() {
-                                iter.push((left.to_ref(), depth, Context::Select, false));
+                                iter.push_back((left.to_ref(), depth, Context::Select, false));
                             }
                             if let Some(right) = s.rarg.as_ref() {
-                                iter.push((right.to_ref(), depth, Context::Select, false));
+                                iter.push_back((right.to_ref(), depth, Context::Select, false));
                             }
                         }
                         Ok(protobuf::SetOperation::SetopIntersect) => {
                             if let Some(left) = s.larg.as_ref() {
-                                iter.push((left.to_ref(), depth, Context::Select, false));
+                                iter.push_back((left.to_ref(), depth, Context::Select, false));
                             }
                             if let Some(right) = s.rarg.as_ref() {
-                                iter.push((right.to_ref(), depth, Context::Select, false));
+                                iter.push_back((right.to_ref(), depth, Context::Select, false));
                             }
                         }
                         Ok(protobuf::SetOperation::Undefined) | Err(_) => (),
@@ -100,46 +112,46 @@ impl NodeEnum {
                 NodeRef::InsertStmt(s) => {
                     if let Some(n) = &s.select_stmt {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, false));
+                            iter.push_back((n.to_ref(), depth, Context::DML, false));
                         }
                     }
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DML, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DML, false));
                     }
                     if let Some(clause) = &s.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DML, false));
+                                iter.push_back((n.to_ref(), depth, Context::DML, false));
                             }
                         });
                     }
                     if let Some(n) = &s.on_conflict_clause {
-                        iter.push((n.to_ref(), depth, Context::DML, false));
+                        iter.push_back((n.to_ref(), depth, Context::DML, false));
                     }
                 }
                 NodeRef::UpdateStmt(s) => {
                     s.target_list.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, false));
+                            iter.push_back((n.to_ref(), depth, Context::DML, false));
                         }
                     });
                     s.where_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, true));
+                            iter.push_back((n.to_ref(), depth, Context::DML, true));
                         }
                     });
                     s.from_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DML, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DML, false));
                     }
                     if let Some(clause) = &s.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DML, false));
+                                iter.push_back((n.to_ref(), depth, Context::DML, false));
                             }
                         });
                     }
@@ -147,74 +159,74 @@ impl NodeEnum {
                 NodeRef::DeleteStmt(s) => {
                     if let Some(n) = &s.where_clause {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, true));
+                            iter.push_back((n.to_ref(), depth, Context::DML, true));
                         }
                     }
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DML, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DML, false));
                     }
                     if let Some(clause) = &s.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DML, false));
+                                iter.push_back((n.to_ref(), depth, Context::DML, false));
                             }
                         });
                     }
                     s.using_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                 }
                 NodeRef::MergeStmt(m) => {
                     if let Some(t) = m.relation.as_ref() {
-                        iter.push((t.to_ref(), depth, Context::DML, false));
+                        iter.push_back((t.to_ref(), depth, Context::DML, false));
                     }
 
                     if let Some(clause) = &m.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DML, false));
+                                iter.push_back((n.to_ref(), depth, Context::DML, false));
                             }
                         });
                     }
 
                     m.source_relation.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     m.merge_when_clauses.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, true));
+                            iter.push_back((n.to_ref(), depth, Context::DML, true));
                         }
                     });
                     m.join_condition.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                 }
                 NodeRef::CommonTableExpr(s) => {
                     if let Some(n) = &s.ctequery {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, false));
+                            iter.push_back((n.to_ref(), depth, context, false));
                         }
                     }
                 }
                 NodeRef::CopyStmt(s) => {
                     if let Some(n) = &s.query {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, false));
+                            iter.push_back((n.to_ref(), depth, Context::DML, false));
                         }
                     }
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DML, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DML, false));
                     }
                 }
                 NodeRef::CallStmt(s) => {
                     if let Some(n) = s.funccall.as_ref() {
-                        iter.push((n.to_ref(), depth, Context::Call, false));
+                        iter.push_back((n.to_ref(), depth, Context::Call, false));
                     }
                 }
                 //
@@ -222,91 +234,91 @@ impl NodeEnum {
                 //
                 NodeRef::AlterTableStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::CreateStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::CreateTableAsStmt(s) => {
                     if let Some(n) = &s.query {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
                         }
                     }
                     if let Some(n) = &s.into {
                         if let Some(rel) = n.rel.as_ref() {
-                            iter.push((rel.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                         }
                     }
                 }
                 NodeRef::TruncateStmt(s) => {
                     s.relations.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
                         }
                     });
                 }
                 NodeRef::ViewStmt(s) => {
                     if let Some(n) = &s.query {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
                         }
                     }
                     if let Some(rel) = s.view.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::IndexStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                     s.index_params.iter().for_each(|n| {
                         if let Some(NodeEnum::IndexElem(n)) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
 
                             if let Some(n) = n.expr.as_ref().and_then(|n| n.node.as_ref()) {
-                                iter.push((n.to_ref(), depth, Context::DDL, false));
+                                iter.push_back((n.to_ref(), depth, Context::DDL, false));
                             }
                         }
                     });
                     if let Some(n) = s.where_clause.as_ref() {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, true));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, true));
                         }
                     }
                 }
                 NodeRef::CreateTrigStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::RuleStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::VacuumStmt(s) => {
                     for node in &s.rels {
                         if let Some(NodeEnum::VacuumRelation(r)) = &node.node {
                             if let Some(rel) = r.relation.as_ref() {
-                                iter.push((rel.to_ref(), depth, Context::DDL, false));
+                                iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                             }
                         }
                     }
                 }
                 NodeRef::RefreshMatViewStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::GrantStmt(s) => {
                     if let Ok(protobuf::ObjectType::ObjectTable) = protobuf::ObjectType::try_from(s.objtype) {
                         s.objects.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DDL, false));
+                                iter.push_back((n.to_ref(), depth, Context::DDL, false));
                             }
                         });
                     }
@@ -314,14 +326,14 @@ impl NodeEnum {
                 NodeRef::LockStmt(s) => {
                     s.relations.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
                         }
                     });
                 }
                 NodeRef::ExplainStmt(s) => {
                     if let Some(n) = &s.query {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, false));
+                            iter.push_back((n.to_ref(), depth, context, false));
                         }
                     }
                 }
@@ -331,106 +343,106 @@ impl NodeEnum {
                 NodeRef::AExpr(e) => {
                     if let Some(n) = &e.lexpr {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                     if let Some(n) = &e.rexpr {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::BoolExpr(e) => {
                     e.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::BooleanTest(e) => {
                     if let Some(n) = &e.arg {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::CoalesceExpr(e) => {
                     e.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::MinMaxExpr(e) => {
                     e.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::NullTest(e) => {
                     if let Some(n) = &e.arg {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::ResTarget(t) => {
                     if let Some(n) = &t.val {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::SubLink(l) => {
                     if let Some(n) = &l.subselect {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::FuncCall(c) => {
                     c.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::CaseExpr(c) => {
                     c.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                     if let Some(n) = &c.defresult {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::CaseWhen(w) => {
                     if let Some(n) = &w.expr {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                     if let Some(n) = &w.result {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::SortBy(n) => {
                     if let Some(n) = &n.node {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::TypeCast(n) => {
                     if let Some(n) = &n.arg {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
@@ -440,7 +452,7 @@ impl NodeEnum {
                 NodeRef::List(l) => {
                     l.items.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
@@ -448,7 +460,7 @@ impl NodeEnum {
                     [&e.larg, &e.rarg, &e.quals].iter().for_each(|n| {
                         if let Some(n) = n {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, context, has_filter_columns));
+                                iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                             }
                         }
                     });
@@ -456,21 +468,21 @@ impl NodeEnum {
                 NodeRef::RowExpr(e) => {
                     e.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::RangeSubselect(s) => {
                     if let Some(n) = &s.subquery {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::RangeFunction(f) => {
                     f.functions.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
@@ -488,10 +500,9 @@ impl NodeEnum {
     /// The caller may have to deal with dangling pointers, and passing an
     /// invalid tree back to libpg_query may cause it to panic.
     pub unsafe fn nodes_mut(&mut self) -> Vec<(NodeMut, i32, Context)> {
-        let mut iter = vec![(self.to_mut(), 0, Context::None)];
+        let mut iter = VecDeque::from([(self.to_mut(), 0, Context::None)]);
         let mut nodes = Vec::new();
-        while !iter.is_empty() {
-            let (node, depth, context) = iter.remove(0);
+        while let Some((node, depth, context)) = iter.pop_front() {
             let depth = depth + 1;
             match node {
                 //
@@ -501,33 +512,33 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     s.target_list.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::Select));
+                            iter.push_back((n.to_mut(), depth, Context::Select));
                         }
                     });
                     if let Some(n) = s.where_clause.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::Select));
+                            iter.push_back((n.to_mut(), depth, Context::Select));
                         }
                     }
                     s.sort_clause.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::Select));
+                            iter.push_back((n.to_mut(), depth, Context::Select));
                         }
                     });
                     s.group_clause.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::Select));
+                            iter.push_back((n.to_mut(), depth, Context::Select));
                         }
                     });
                     if let Some(n) = s.having_clause.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::Select));
+                            iter.push_back((n.to_mut(), depth, Context::Select));
                         }
                     }
                     if let Some(clause) = s.with_clause.as_mut() {
                         clause.ctes.iter_mut().for_each(|n| {
                             if let Some(n) = n.node.as_mut() {
-                                iter.push((n.to_mut(), depth, Context::Select));
+                                iter.push_back((n.to_mut(), depth, Context::Select));
                             }
                         });
                     }
@@ -535,32 +546,32 @@ impl NodeEnum {
                         Ok(protobuf::SetOperation::SetopNone) => {
                             s.from_clause.iter_mut().for_each(|n| {
                                 if let Some(n) = n.node.as_mut() {
-                                    iter.push((n.to_mut(), depth, Context::Select));
+                                    iter.push_back((n.to_mut(), depth, Context::Select));
                                 }
                             });
                         }
                         Ok(protobuf::SetOperation::SetopUnion) => {
                             if let Some(left) = s.larg.as_mut() {
-                                iter.push((left.to_mut(), depth, Context::Select));
+                                iter.push_back((left.to_mut(), depth, Context::Select));
                             }
                             if let Some(right) = s.rarg.as_mut() {
-                                iter.push((right.to_mut(), depth, Context::Select));
+                                iter.push_back((right.to_mut(), depth, Context::Select));
                             }
                         }
                         Ok(protobuf::SetOperation::SetopExcept) => {
                             if let Some(left) = s.larg.as_mut() {
-                                iter.push((left.to_mut(), depth, Context::Select));
+                                iter.push_back((left.to_mut(), depth, Context::Select));
                             }
                             if let Some(right) = s.rarg.as_mut() {
-                                iter.push((right.to_mut(), depth, Context::Select));
+                                iter.push_back((right.to_mut(), depth, Context::Select));
                             }
                         }
                         Ok(protobuf::SetOperation::SetopIntersect) => {
                             if let Some(left) = s.larg.as_mut() {
-                                iter.push((left.to_mut(), depth, Context::Select));
+                                iter.push_back((left.to_mut(), depth, Context::Select));
                             }
                             if let Some(right) = s.rarg.as_mut() {
-                                iter.push((right.to_mut(), depth, Context::Select));
+                                iter.push_back((right.to_mut(), depth, Context::Select));
                             }
                         }
                         Ok(protobuf::SetOperation::Undefined) | Err(_) => (),
@@ -570,47 +581,47 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.select_stmt.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DML));
+                            iter.push_back((n.to_mut(), depth, Context::DML));
                         }
                     }
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DML));
+                        iter.push_back((rel.to_mut(), depth, Context::DML));
                     }
                     if let Some(clause) = s.with_clause.as_mut() {
                         clause.ctes.iter_mut().for_each(|n| {
                             if let Some(n) = n.node.as_mut() {
-                                iter.push((n.to_mut(), depth, Context::DML));
+                                iter.push_back((n.to_mut(), depth, Context::DML));
                             }
                         });
                     }
                     if let Some(n) = s.on_conflict_clause.as_mut() {
-                        iter.push((n.to_mut(), depth, Context::DML));
+                        iter.push_back((n.to_mut(), depth, Context::DML));
                     }
                 }
                 NodeMut::UpdateStmt(s) => {
                     let s = s.as_mut().unwrap();
                     s.target_list.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DML));
+                            iter.push_back((n.to_mut(), depth, Context::DML));
                         }
                     });
                     s.where_clause.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DML));
+                            iter.push_back((n.to_mut(), depth, Context::DML));
                         }
                     });
                     s.from_clause.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::Select));
+                            iter.push_back((n.to_mut(), depth, Context::Select));
                         }
                     });
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DML));
+                        iter.push_back((rel.to_mut(), depth, Context::DML));
                     }
                     if let Some(clause) = s.with_clause.as_mut() {
                         clause.ctes.iter_mut().for_each(|n| {
                             if let Some(n) = n.node.as_mut() {
-                                iter.push((n.to_mut(), depth, Context::DML));
+                                iter.push_back((n.to_mut(), depth, Context::DML));
                             }
                         });
                     }
@@ -619,22 +630,22 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.where_clause.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DML));
+                            iter.push_back((n.to_mut(), depth, Context::DML));
                         }
                     }
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DML));
+                        iter.push_back((rel.to_mut(), depth, Context::DML));
                     }
                     if let Some(clause) = s.with_clause.as_mut() {
                         clause.ctes.iter_mut().for_each(|n| {
                             if let Some(n) = n.node.as_mut() {
-                                iter.push((n.to_mut(), depth, Context::DML));
+                                iter.push_back((n.to_mut(), depth, Context::DML));
                             }
                         });
                     }
                     s.using_clause.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::Select));
+                            iter.push_back((n.to_mut(), depth, Context::Select));
                         }
                     });
                 }
@@ -642,7 +653,7 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.ctequery.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -650,17 +661,17 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.query.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DML));
+                            iter.push_back((n.to_mut(), depth, Context::DML));
                         }
                     }
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DML));
+                        iter.push_back((rel.to_mut(), depth, Context::DML));
                     }
                 }
                 NodeMut::CallStmt(s) => {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.funccall.as_mut() {
-                        iter.push((n.to_mut(), depth, Context::Call));
+                        iter.push_back((n.to_mut(), depth, Context::Call));
                     }
                 }
                 //
@@ -669,25 +680,25 @@ impl NodeEnum {
                 NodeMut::AlterTableStmt(s) => {
                     let s = s.as_mut().unwrap();
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DDL));
+                        iter.push_back((rel.to_mut(), depth, Context::DDL));
                     }
                 }
                 NodeMut::CreateStmt(s) => {
                     let s = s.as_mut().unwrap();
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DDL));
+                        iter.push_back((rel.to_mut(), depth, Context::DDL));
                     }
                 }
                 NodeMut::CreateTableAsStmt(s) => {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.query.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DDL));
+                            iter.push_back((n.to_mut(), depth, Context::DDL));
                         }
                     }
                     if let Some(n) = s.into.as_mut() {
                         if let Some(rel) = n.rel.as_mut() {
-                            iter.push((rel.to_mut(), depth, Context::DDL));
+                            iter.push_back((rel.to_mut(), depth, Context::DDL));
                         }
                     }
                 }
@@ -695,7 +706,7 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     s.relations.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DDL));
+                            iter.push_back((n.to_mut(), depth, Context::DDL));
                         }
                     });
                 }
@@ -703,22 +714,22 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.query.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DDL));
+                            iter.push_back((n.to_mut(), depth, Context::DDL));
                         }
                     }
                     if let Some(rel) = s.view.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DDL));
+                        iter.push_back((rel.to_mut(), depth, Context::DDL));
                     }
                 }
                 NodeMut::IndexStmt(s) => {
                     let s = s.as_mut().unwrap();
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DDL));
+                        iter.push_back((rel.to_mut(), depth, Context::DDL));
                     }
                     s.index_params.iter_mut().for_each(|n| {
                         if let Some(NodeEnum::IndexElem(n)) = n.node.as_mut() {
                             if let Some(n) = n.expr.as_mut().and_then(|n| n.node.as_mut()) {
-                                iter.push((n.to_mut(), depth, Context::DDL));
+                                iter.push_back((n.to_mut(), depth, Context::DDL));
                             }
                         }
                     });
@@ -726,13 +737,13 @@ impl NodeEnum {
                 NodeMut::CreateTrigStmt(s) => {
                     let s = s.as_mut().unwrap();
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DDL));
+                        iter.push_back((rel.to_mut(), depth, Context::DDL));
                     }
                 }
                 NodeMut::RuleStmt(s) => {
                     let s = s.as_mut().unwrap();
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DDL));
+                        iter.push_back((rel.to_mut(), depth, Context::DDL));
                     }
                 }
                 NodeMut::VacuumStmt(s) => {
@@ -740,7 +751,7 @@ impl NodeEnum {
                     for node in s.rels.iter_mut() {
                         if let Some(NodeEnum::VacuumRelation(r)) = node.node.as_mut() {
                             if let Some(rel) = r.relation.as_mut() {
-                                iter.push((rel.to_mut(), depth, Context::DDL));
+                                iter.push_back((rel.to_mut(), depth, Context::DDL));
                             }
                         }
                     }
@@ -748,7 +759,7 @@ impl NodeEnum {
                 NodeMut::RefreshMatViewStmt(s) => {
                     let s = s.as_mut().unwrap();
                     if let Some(rel) = s.relation.as_mut() {
-                        iter.push((rel.to_mut(), depth, Context::DDL));
+                        iter.push_back((rel.to_mut(), depth, Context::DDL));
                     }
                 }
                 NodeMut::GrantStmt(s) => {
@@ -756,7 +767,7 @@ impl NodeEnum {
                     if let Ok(protobuf::ObjectType::ObjectTable) = protobuf::ObjectType::try_from(s.objtype) {
                         s.objects.iter_mut().for_each(|n| {
                             if let Some(n) = n.node.as_mut() {
-                                iter.push((n.to_mut(), depth, Context::DDL));
+                                iter.push_back((n.to_mut(), depth, Context::DDL));
                             }
                         });
                     }
@@ -765,7 +776,7 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     s.relations.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, Context::DDL));
+                            iter.push_back((n.to_mut(), depth, Context::DDL));
                         }
                     });
                 }
@@ -773,7 +784,7 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.query.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -784,12 +795,12 @@ impl NodeEnum {
                     let e = e.as_mut().unwrap();
                     if let Some(n) = e.lexpr.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                     if let Some(n) = e.rexpr.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -797,7 +808,7 @@ impl NodeEnum {
                     let e = e.as_mut().unwrap();
                     e.args.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     });
                 }
@@ -805,7 +816,7 @@ impl NodeEnum {
                     let e = e.as_mut().unwrap();
                     e.args.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     });
                 }
@@ -813,7 +824,7 @@ impl NodeEnum {
                     let e = e.as_mut().unwrap();
                     e.args.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     });
                 }
@@ -821,7 +832,7 @@ impl NodeEnum {
                     let e = e.as_mut().unwrap();
                     if let Some(n) = e.arg.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -829,7 +840,7 @@ impl NodeEnum {
                     let t = t.as_mut().unwrap();
                     if let Some(n) = t.val.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -837,7 +848,7 @@ impl NodeEnum {
                     let l = l.as_mut().unwrap();
                     if let Some(n) = l.subselect.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -845,7 +856,7 @@ impl NodeEnum {
                     let c = c.as_mut().unwrap();
                     c.args.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     });
                 }
@@ -853,12 +864,12 @@ impl NodeEnum {
                     let c = c.as_mut().unwrap();
                     c.args.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     });
                     if let Some(n) = c.defresult.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -866,12 +877,12 @@ impl NodeEnum {
                     let w = w.as_mut().unwrap();
                     if let Some(n) = w.expr.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                     if let Some(n) = w.result.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -879,7 +890,7 @@ impl NodeEnum {
                     let n = n.as_mut().unwrap();
                     if let Some(n) = n.node.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -887,7 +898,7 @@ impl NodeEnum {
                     let t = t.as_mut().unwrap();
                     if let Some(n) = t.arg.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -898,7 +909,7 @@ impl NodeEnum {
                     let l = l.as_mut().unwrap();
                     l.items.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     });
                 }
@@ -906,17 +917,17 @@ impl NodeEnum {
                     let e = e.as_mut().unwrap();
                     if let Some(n) = e.larg.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                     if let Some(n) = e.rarg.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                     if let Some(n) = e.quals.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -924,7 +935,7 @@ impl NodeEnum {
                     let e = e.as_mut().unwrap();
                     e.args.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     });
                 }
@@ -932,7 +943,7 @@ impl NodeEnum {
                     let s = s.as_mut().unwrap();
                     if let Some(n) = s.subquery.as_mut() {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     }
                 }
@@ -940,7 +951,7 @@ impl NodeEnum {
                     let f = f.as_mut().unwrap();
                     f.functions.iter_mut().for_each(|n| {
                         if let Some(n) = n.node.as_mut() {
-                            iter.push((n.to_mut(), depth, context));
+                            iter.push_back((n.to_mut(), depth, context));
                         }
                     });
                 }
@@ -0,0 +1,1291 @@
+use std::hash::{Hash, Hasher};
+
+use crate::*;
+
+/// A field of a node that is not itself a node.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Scalar<'a> {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Str(&'a str),
+    /// The value and its name in the protobuf definition, e.g. `AEXPR_OP`.
+    Enum(i32, &'static str),
+    UInts(&'a [u64]),
+}
+
+impl Hash for Scalar<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Scalar::Bool(value) => value.hash(state),
+            Scalar::Int(value) => value.hash(state),
+            Scalar::UInt(value) => value.hash(state),
+            Scalar::Float(value) => value.to_bits().hash(state),
+            Scalar::Str(value) => value.hash(state),
+            Scalar::Enum(value, _) => value.hash(state),
+            Scalar::UInts(value) => value.hash(state),
+        }
+    }
+}
+
+// BEGIN GENERATED by scripts/generate.py from src/protobuf.rs, do not edit by hand
+// Calls `f` with every field of `node` that is not itself a node, in field order.
+pub(crate) fn for_each_scalar<'a>(node: NodeRef<'a>, f: &mut impl FnMut(&'static str, Scalar<'a>)) {
+    match node {
+        NodeRef::Alias(n) => {
+            f("aliasname", Scalar::Str(&n.aliasname));
+        }
+        NodeRef::RangeVar(n) => {
+            f("catalogname", Scalar::Str(&n.catalogname));
+            f("schemaname", Scalar::Str(&n.schemaname));
+            f("relname", Scalar::Str(&n.relname));
+            f("inh", Scalar::Bool(n.inh));
+            f("relpersistence", Scalar::Str(&n.relpersistence));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::TableFunc(n) => {
+            f("functype", Scalar::Enum(n.functype, protobuf::TableFuncType::try_from(n.functype).map_or("", |e| e.as_str_name())));
+            f("notnulls", Scalar::UInts(&n.notnulls));
+            f("ordinalitycol", Scalar::Int(n.ordinalitycol.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::IntoClause(n) => {
+            f("access_method", Scalar::Str(&n.access_method));
+            f("on_commit", Scalar::Enum(n.on_commit, protobuf::OnCommitAction::try_from(n.on_commit).map_or("", |e| e.as_str_name())));
+            f("table_space_name", Scalar::Str(&n.table_space_name));
+            f("skip_data", Scalar::Bool(n.skip_data));
+        }
+        NodeRef::Var(n) => {
+            f("varno", Scalar::Int(n.varno.into()));
+            f("varattno", Scalar::Int(n.varattno.into()));
+            f("vartype", Scalar::UInt(n.vartype.into()));
+            f("vartypmod", Scalar::Int(n.vartypmod.into()));
+            f("varcollid", Scalar::UInt(n.varcollid.into()));
+            f("varnullingrels", Scalar::UInts(&n.varnullingrels));
+            f("varlevelsup", Scalar::UInt(n.varlevelsup.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::Param(n) => {
+            f("paramkind", Scalar::Enum(n.paramkind, protobuf::ParamKind::try_from(n.paramkind).map_or("", |e| e.as_str_name())));
+            f("paramid", Scalar::Int(n.paramid.into()));
+            f("paramtype", Scalar::UInt(n.paramtype.into()));
+            f("paramtypmod", Scalar::Int(n.paramtypmod.into()));
+            f("paramcollid", Scalar::UInt(n.paramcollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::Aggref(n) => {
+            f("aggfnoid", Scalar::UInt(n.aggfnoid.into()));
+            f("aggtype", Scalar::UInt(n.aggtype.into()));
+            f("aggcollid", Scalar::UInt(n.aggcollid.into()));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("aggstar", Scalar::Bool(n.aggstar));
+            f("aggvariadic", Scalar::Bool(n.aggvariadic));
+            f("aggkind", Scalar::Str(&n.aggkind));
+            f("agglevelsup", Scalar::UInt(n.agglevelsup.into()));
+            f("aggsplit", Scalar::Enum(n.aggsplit, protobuf::AggSplit::try_from(n.aggsplit).map_or("", |e| e.as_str_name())));
+            f("aggno", Scalar::Int(n.aggno.into()));
+            f("aggtransno", Scalar::Int(n.aggtransno.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::GroupingFunc(n) => {
+            f("agglevelsup", Scalar::UInt(n.agglevelsup.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::WindowFunc(n) => {
+            f("winfnoid", Scalar::UInt(n.winfnoid.into()));
+            f("wintype", Scalar::UInt(n.wintype.into()));
+            f("wincollid", Scalar::UInt(n.wincollid.into()));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("winref", Scalar::UInt(n.winref.into()));
+            f("winstar", Scalar::Bool(n.winstar));
+            f("winagg", Scalar::Bool(n.winagg));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::WindowFuncRunCondition(n) => {
+            f("opno", Scalar::UInt(n.opno.into()));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("wfunc_left", Scalar::Bool(n.wfunc_left));
+        }
+        NodeRef::MergeSupportFunc(n) => {
+            f("msftype", Scalar::UInt(n.msftype.into()));
+            f("msfcollid", Scalar::UInt(n.msfcollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::SubscriptingRef(n) => {
+            f("refcontainertype", Scalar::UInt(n.refcontainertype.into()));
+            f("refelemtype", Scalar::UInt(n.refelemtype.into()));
+            f("refrestype", Scalar::UInt(n.refrestype.into()));
+            f("reftypmod", Scalar::Int(n.reftypmod.into()));
+            f("refcollid", Scalar::UInt(n.refcollid.into()));
+        }
+        NodeRef::FuncExpr(n) => {
+            f("funcid", Scalar::UInt(n.funcid.into()));
+            f("funcresulttype", Scalar::UInt(n.funcresulttype.into()));
+            f("funcretset", Scalar::Bool(n.funcretset));
+            f("funcvariadic", Scalar::Bool(n.funcvariadic));
+            f("funcformat", Scalar::Enum(n.funcformat, protobuf::CoercionForm::try_from(n.funcformat).map_or("", |e| e.as_str_name())));
+            f("funccollid", Scalar::UInt(n.funccollid.into()));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::NamedArgExpr(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("argnumber", Scalar::Int(n.argnumber.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::OpExpr(n) => {
+            f("opno", Scalar::UInt(n.opno.into()));
+            f("opresulttype", Scalar::UInt(n.opresulttype.into()));
+            f("opretset", Scalar::Bool(n.opretset));
+            f("opcollid", Scalar::UInt(n.opcollid.into()));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::DistinctExpr(n) => {
+            f("opno", Scalar::UInt(n.opno.into()));
+            f("opresulttype", Scalar::UInt(n.opresulttype.into()));
+            f("opretset", Scalar::Bool(n.opretset));
+            f("opcollid", Scalar::UInt(n.opcollid.into()));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::NullIfExpr(n) => {
+            f("opno", Scalar::UInt(n.opno.into()));
+            f("opresulttype", Scalar::UInt(n.opresulttype.into()));
+            f("opretset", Scalar::Bool(n.opretset));
+            f("opcollid", Scalar::UInt(n.opcollid.into()));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::ScalarArrayOpExpr(n) => {
+            f("opno", Scalar::UInt(n.opno.into()));
+            f("use_or", Scalar::Bool(n.use_or));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::BoolExpr(n) => {
+            f("boolop", Scalar::Enum(n.boolop, protobuf::BoolExprType::try_from(n.boolop).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::SubLink(n) => {
+            f("sub_link_type", Scalar::Enum(n.sub_link_type, protobuf::SubLinkType::try_from(n.sub_link_type).map_or("", |e| e.as_str_name())));
+            f("sub_link_id", Scalar::Int(n.sub_link_id.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::SubPlan(n) => {
+            f("sub_link_type", Scalar::Enum(n.sub_link_type, protobuf::SubLinkType::try_from(n.sub_link_type).map_or("", |e| e.as_str_name())));
+            f("plan_id", Scalar::Int(n.plan_id.into()));
+            f("plan_name", Scalar::Str(&n.plan_name));
+            f("first_col_type", Scalar::UInt(n.first_col_type.into()));
+            f("first_col_typmod", Scalar::Int(n.first_col_typmod.into()));
+            f("first_col_collation", Scalar::UInt(n.first_col_collation.into()));
+            f("use_hash_table", Scalar::Bool(n.use_hash_table));
+            f("unknown_eq_false", Scalar::Bool(n.unknown_eq_false));
+            f("parallel_safe", Scalar::Bool(n.parallel_safe));
+            f("startup_cost", Scalar::Float(n.startup_cost));
+            f("per_call_cost", Scalar::Float(n.per_call_cost));
+        }
+        NodeRef::AlternativeSubPlan(_) => {}
+        NodeRef::FieldSelect(n) => {
+            f("fieldnum", Scalar::Int(n.fieldnum.into()));
+            f("resulttype", Scalar::UInt(n.resulttype.into()));
+            f("resulttypmod", Scalar::Int(n.resulttypmod.into()));
+            f("resultcollid", Scalar::UInt(n.resultcollid.into()));
+        }
+        NodeRef::FieldStore(n) => {
+            f("resulttype", Scalar::UInt(n.resulttype.into()));
+        }
+        NodeRef::RelabelType(n) => {
+            f("resulttype", Scalar::UInt(n.resulttype.into()));
+            f("resulttypmod", Scalar::Int(n.resulttypmod.into()));
+            f("resultcollid", Scalar::UInt(n.resultcollid.into()));
+            f("relabelformat", Scalar::Enum(n.relabelformat, protobuf::CoercionForm::try_from(n.relabelformat).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CoerceViaIo(n) => {
+            f("resulttype", Scalar::UInt(n.resulttype.into()));
+            f("resultcollid", Scalar::UInt(n.resultcollid.into()));
+            f("coerceformat", Scalar::Enum(n.coerceformat, protobuf::CoercionForm::try_from(n.coerceformat).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::ArrayCoerceExpr(n) => {
+            f("resulttype", Scalar::UInt(n.resulttype.into()));
+            f("resulttypmod", Scalar::Int(n.resulttypmod.into()));
+            f("resultcollid", Scalar::UInt(n.resultcollid.into()));
+            f("coerceformat", Scalar::Enum(n.coerceformat, protobuf::CoercionForm::try_from(n.coerceformat).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::ConvertRowtypeExpr(n) => {
+            f("resulttype", Scalar::UInt(n.resulttype.into()));
+            f("convertformat", Scalar::Enum(n.convertformat, protobuf::CoercionForm::try_from(n.convertformat).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CollateExpr(n) => {
+            f("coll_oid", Scalar::UInt(n.coll_oid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CaseExpr(n) => {
+            f("casetype", Scalar::UInt(n.casetype.into()));
+            f("casecollid", Scalar::UInt(n.casecollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CaseWhen(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CaseTestExpr(n) => {
+            f("type_id", Scalar::UInt(n.type_id.into()));
+            f("type_mod", Scalar::Int(n.type_mod.into()));
+            f("collation", Scalar::UInt(n.collation.into()));
+        }
+        NodeRef::ArrayExpr(n) => {
+            f("array_typeid", Scalar::UInt(n.array_typeid.into()));
+            f("array_collid", Scalar::UInt(n.array_collid.into()));
+            f("element_typeid", Scalar::UInt(n.element_typeid.into()));
+            f("multidims", Scalar::Bool(n.multidims));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::RowExpr(n) => {
+            f("row_typeid", Scalar::UInt(n.row_typeid.into()));
+            f("row_format", Scalar::Enum(n.row_format, protobuf::CoercionForm::try_from(n.row_format).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::RowCompareExpr(n) => {
+            f("rctype", Scalar::Enum(n.rctype, protobuf::RowCompareType::try_from(n.rctype).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::CoalesceExpr(n) => {
+            f("coalescetype", Scalar::UInt(n.coalescetype.into()));
+            f("coalescecollid", Scalar::UInt(n.coalescecollid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::MinMaxExpr(n) => {
+            f("minmaxtype", Scalar::UInt(n.minmaxtype.into()));
+            f("minmaxcollid", Scalar::UInt(n.minmaxcollid.into()));
+            f("inputcollid", Scalar::UInt(n.inputcollid.into()));
+            f("op", Scalar::Enum(n.op, protobuf::MinMaxOp::try_from(n.op).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::SqlvalueFunction(n) => {
+            f("op", Scalar::Enum(n.op, protobuf::SqlValueFunctionOp::try_from(n.op).map_or("", |e| e.as_str_name())));
+            f("type", Scalar::UInt(n.r#type.into()));
+            f("typmod", Scalar::Int(n.typmod.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::XmlExpr(n) => {
+            f("op", Scalar::Enum(n.op, protobuf::XmlExprOp::try_from(n.op).map_or("", |e| e.as_str_name())));
+            f("name", Scalar::Str(&n.name));
+            f("xmloption", Scalar::Enum(n.xmloption, protobuf::XmlOptionType::try_from(n.xmloption).map_or("", |e| e.as_str_name())));
+            f("indent", Scalar::Bool(n.indent));
+            f("type", Scalar::UInt(n.r#type.into()));
+            f("typmod", Scalar::Int(n.typmod.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonFormat(n) => {
+            f("format_type", Scalar::Enum(n.format_type, protobuf::JsonFormatType::try_from(n.format_type).map_or("", |e| e.as_str_name())));
+            f("encoding", Scalar::Enum(n.encoding, protobuf::JsonEncoding::try_from(n.encoding).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonReturning(n) => {
+            f("typid", Scalar::UInt(n.typid.into()));
+            f("typmod", Scalar::Int(n.typmod.into()));
+        }
+        NodeRef::JsonValueExpr(_) => {}
+        NodeRef::JsonConstructorExpr(n) => {
+            f("type", Scalar::Enum(n.r#type, protobuf::JsonConstructorType::try_from(n.r#type).map_or("", |e| e.as_str_name())));
+            f("absent_on_null", Scalar::Bool(n.absent_on_null));
+            f("unique", Scalar::Bool(n.unique));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonIsPredicate(n) => {
+            f("item_type", Scalar::Enum(n.item_type, protobuf::JsonValueType::try_from(n.item_type).map_or("", |e| e.as_str_name())));
+            f("unique_keys", Scalar::Bool(n.unique_keys));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonBehavior(n) => {
+            f("btype", Scalar::Enum(n.btype, protobuf::JsonBehaviorType::try_from(n.btype).map_or("", |e| e.as_str_name())));
+            f("coerce", Scalar::Bool(n.coerce));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonExpr(n) => {
+            f("op", Scalar::Enum(n.op, protobuf::JsonExprOp::try_from(n.op).map_or("", |e| e.as_str_name())));
+            f("column_name", Scalar::Str(&n.column_name));
+            f("use_io_coercion", Scalar::Bool(n.use_io_coercion));
+            f("use_json_coercion", Scalar::Bool(n.use_json_coercion));
+            f("wrapper", Scalar::Enum(n.wrapper, protobuf::JsonWrapper::try_from(n.wrapper).map_or("", |e| e.as_str_name())));
+            f("omit_quotes", Scalar::Bool(n.omit_quotes));
+            f("collation", Scalar::UInt(n.collation.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonTablePath(n) => {
+            f("name", Scalar::Str(&n.name));
+        }
+        NodeRef::JsonTablePathScan(n) => {
+            f("error_on_error", Scalar::Bool(n.error_on_error));
+            f("col_min", Scalar::Int(n.col_min.into()));
+            f("col_max", Scalar::Int(n.col_max.into()));
+        }
+        NodeRef::JsonTableSiblingJoin(_) => {}
+        NodeRef::NullTest(n) => {
+            f("nulltesttype", Scalar::Enum(n.nulltesttype, protobuf::NullTestType::try_from(n.nulltesttype).map_or("", |e| e.as_str_name())));
+            f("argisrow", Scalar::Bool(n.argisrow));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::BooleanTest(n) => {
+            f("booltesttype", Scalar::Enum(n.booltesttype, protobuf::BoolTestType::try_from(n.booltesttype).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::MergeAction(n) => {
+            f("match_kind", Scalar::Enum(n.match_kind, protobuf::MergeMatchKind::try_from(n.match_kind).map_or("", |e| e.as_str_name())));
+            f("command_type", Scalar::Enum(n.command_type, protobuf::CmdType::try_from(n.command_type).map_or("", |e| e.as_str_name())));
+            f("override", Scalar::Enum(n.r#override, protobuf::OverridingKind::try_from(n.r#override).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::CoerceToDomain(n) => {
+            f("resulttype", Scalar::UInt(n.resulttype.into()));
+            f("resulttypmod", Scalar::Int(n.resulttypmod.into()));
+            f("resultcollid", Scalar::UInt(n.resultcollid.into()));
+            f("coercionformat", Scalar::Enum(n.coercionformat, protobuf::CoercionForm::try_from(n.coercionformat).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CoerceToDomainValue(n) => {
+            f("type_id", Scalar::UInt(n.type_id.into()));
+            f("type_mod", Scalar::Int(n.type_mod.into()));
+            f("collation", Scalar::UInt(n.collation.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::SetToDefault(n) => {
+            f("type_id", Scalar::UInt(n.type_id.into()));
+            f("type_mod", Scalar::Int(n.type_mod.into()));
+            f("collation", Scalar::UInt(n.collation.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CurrentOfExpr(n) => {
+            f("cvarno", Scalar::UInt(n.cvarno.into()));
+            f("cursor_name", Scalar::Str(&n.cursor_name));
+            f("cursor_param", Scalar::Int(n.cursor_param.into()));
+        }
+        NodeRef::NextValueExpr(n) => {
+            f("seqid", Scalar::UInt(n.seqid.into()));
+            f("type_id", Scalar::UInt(n.type_id.into()));
+        }
+        NodeRef::InferenceElem(n) => {
+            f("infercollid", Scalar::UInt(n.infercollid.into()));
+            f("inferopclass", Scalar::UInt(n.inferopclass.into()));
+        }
+        NodeRef::TargetEntry(n) => {
+            f("resno", Scalar::Int(n.resno.into()));
+            f("resname", Scalar::Str(&n.resname));
+            f("ressortgroupref", Scalar::UInt(n.ressortgroupref.into()));
+            f("resorigtbl", Scalar::UInt(n.resorigtbl.into()));
+            f("resorigcol", Scalar::Int(n.resorigcol.into()));
+            f("resjunk", Scalar::Bool(n.resjunk));
+        }
+        NodeRef::RangeTblRef(n) => {
+            f("rtindex", Scalar::Int(n.rtindex.into()));
+        }
+        NodeRef::JoinExpr(n) => {
+            f("jointype", Scalar::Enum(n.jointype, protobuf::JoinType::try_from(n.jointype).map_or("", |e| e.as_str_name())));
+            f("is_natural", Scalar::Bool(n.is_natural));
+            f("rtindex", Scalar::Int(n.rtindex.into()));
+        }
+        NodeRef::FromExpr(_) => {}
+        NodeRef::OnConflictExpr(n) => {
+            f("action", Scalar::Enum(n.action, protobuf::OnConflictAction::try_from(n.action).map_or("", |e| e.as_str_name())));
+            f("constraint", Scalar::UInt(n.constraint.into()));
+            f("excl_rel_index", Scalar::Int(n.excl_rel_index.into()));
+        }
+        NodeRef::Query(n) => {
+            f("command_type", Scalar::Enum(n.command_type, protobuf::CmdType::try_from(n.command_type).map_or("", |e| e.as_str_name())));
+            f("query_source", Scalar::Enum(n.query_source, protobuf::QuerySource::try_from(n.query_source).map_or("", |e| e.as_str_name())));
+            f("can_set_tag", Scalar::Bool(n.can_set_tag));
+            f("result_relation", Scalar::Int(n.result_relation.into()));
+            f("has_aggs", Scalar::Bool(n.has_aggs));
+            f("has_window_funcs", Scalar::Bool(n.has_window_funcs));
+            f("has_target_srfs", Scalar::Bool(n.has_target_srfs));
+            f("has_sub_links", Scalar::Bool(n.has_sub_links));
+            f("has_distinct_on", Scalar::Bool(n.has_distinct_on));
+            f("has_recursive", Scalar::Bool(n.has_recursive));
+            f("has_modifying_cte", Scalar::Bool(n.has_modifying_cte));
+            f("has_for_update", Scalar::Bool(n.has_for_update));
+            f("has_row_security", Scalar::Bool(n.has_row_security));
+            f("is_return", Scalar::Bool(n.is_return));
+            f("merge_target_relation", Scalar::Int(n.merge_target_relation.into()));
+            f("override", Scalar::Enum(n.r#override, protobuf::OverridingKind::try_from(n.r#override).map_or("", |e| e.as_str_name())));
+            f("group_distinct", Scalar::Bool(n.group_distinct));
+            f("limit_option", Scalar::Enum(n.limit_option, protobuf::LimitOption::try_from(n.limit_option).map_or("", |e| e.as_str_name())));
+            f("stmt_location", Scalar::Int(n.stmt_location.into()));
+            f("stmt_len", Scalar::Int(n.stmt_len.into()));
+        }
+        NodeRef::TypeName(n) => {
+            f("type_oid", Scalar::UInt(n.type_oid.into()));
+            f("setof", Scalar::Bool(n.setof));
+            f("pct_type", Scalar::Bool(n.pct_type));
+            f("typemod", Scalar::Int(n.typemod.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::ColumnRef(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::ParamRef(n) => {
+            f("number", Scalar::Int(n.number.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::AExpr(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::AExprKind::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::TypeCast(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CollateClause(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::RoleSpec(n) => {
+            f("roletype", Scalar::Enum(n.roletype, protobuf::RoleSpecType::try_from(n.roletype).map_or("", |e| e.as_str_name())));
+            f("rolename", Scalar::Str(&n.rolename));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::FuncCall(n) => {
+            f("agg_within_group", Scalar::Bool(n.agg_within_group));
+            f("agg_star", Scalar::Bool(n.agg_star));
+            f("agg_distinct", Scalar::Bool(n.agg_distinct));
+            f("func_variadic", Scalar::Bool(n.func_variadic));
+            f("funcformat", Scalar::Enum(n.funcformat, protobuf::CoercionForm::try_from(n.funcformat).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::AStar(_) => {}
+        NodeRef::AIndices(n) => {
+            f("is_slice", Scalar::Bool(n.is_slice));
+        }
+        NodeRef::AIndirection(_) => {}
+        NodeRef::AArrayExpr(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::ResTarget(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::MultiAssignRef(n) => {
+            f("colno", Scalar::Int(n.colno.into()));
+            f("ncolumns", Scalar::Int(n.ncolumns.into()));
+        }
+        NodeRef::SortBy(n) => {
+            f("sortby_dir", Scalar::Enum(n.sortby_dir, protobuf::SortByDir::try_from(n.sortby_dir).map_or("", |e| e.as_str_name())));
+            f("sortby_nulls", Scalar::Enum(n.sortby_nulls, protobuf::SortByNulls::try_from(n.sortby_nulls).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::WindowDef(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("refname", Scalar::Str(&n.refname));
+            f("frame_options", Scalar::Int(n.frame_options.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::RangeSubselect(n) => {
+            f("lateral", Scalar::Bool(n.lateral));
+        }
+        NodeRef::RangeFunction(n) => {
+            f("lateral", Scalar::Bool(n.lateral));
+            f("ordinality", Scalar::Bool(n.ordinality));
+            f("is_rowsfrom", Scalar::Bool(n.is_rowsfrom));
+        }
+        NodeRef::RangeTableFunc(n) => {
+            f("lateral", Scalar::Bool(n.lateral));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::RangeTableFuncCol(n) => {
+            f("colname", Scalar::Str(&n.colname));
+            f("for_ordinality", Scalar::Bool(n.for_ordinality));
+            f("is_not_null", Scalar::Bool(n.is_not_null));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::RangeTableSample(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::ColumnDef(n) => {
+            f("colname", Scalar::Str(&n.colname));
+            f("compression", Scalar::Str(&n.compression));
+            f("inhcount", Scalar::Int(n.inhcount.into()));
+            f("is_local", Scalar::Bool(n.is_local));
+            f("is_not_null", Scalar::Bool(n.is_not_null));
+            f("is_from_type", Scalar::Bool(n.is_from_type));
+            f("storage", Scalar::Str(&n.storage));
+            f("storage_name", Scalar::Str(&n.storage_name));
+            f("identity", Scalar::Str(&n.identity));
+            f("generated", Scalar::Str(&n.generated));
+            f("coll_oid", Scalar::UInt(n.coll_oid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::TableLikeClause(n) => {
+            f("options", Scalar::UInt(n.options.into()));
+            f("relation_oid", Scalar::UInt(n.relation_oid.into()));
+        }
+        NodeRef::IndexElem(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("indexcolname", Scalar::Str(&n.indexcolname));
+            f("ordering", Scalar::Enum(n.ordering, protobuf::SortByDir::try_from(n.ordering).map_or("", |e| e.as_str_name())));
+            f("nulls_ordering", Scalar::Enum(n.nulls_ordering, protobuf::SortByNulls::try_from(n.nulls_ordering).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::DefElem(n) => {
+            f("defnamespace", Scalar::Str(&n.defnamespace));
+            f("defname", Scalar::Str(&n.defname));
+            f("defaction", Scalar::Enum(n.defaction, protobuf::DefElemAction::try_from(n.defaction).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::LockingClause(n) => {
+            f("strength", Scalar::Enum(n.strength, protobuf::LockClauseStrength::try_from(n.strength).map_or("", |e| e.as_str_name())));
+            f("wait_policy", Scalar::Enum(n.wait_policy, protobuf::LockWaitPolicy::try_from(n.wait_policy).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::XmlSerialize(n) => {
+            f("xmloption", Scalar::Enum(n.xmloption, protobuf::XmlOptionType::try_from(n.xmloption).map_or("", |e| e.as_str_name())));
+            f("indent", Scalar::Bool(n.indent));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::PartitionElem(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::PartitionSpec(n) => {
+            f("strategy", Scalar::Enum(n.strategy, protobuf::PartitionStrategy::try_from(n.strategy).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::PartitionBoundSpec(n) => {
+            f("strategy", Scalar::Str(&n.strategy));
+            f("is_default", Scalar::Bool(n.is_default));
+            f("modulus", Scalar::Int(n.modulus.into()));
+            f("remainder", Scalar::Int(n.remainder.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::PartitionRangeDatum(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::PartitionRangeDatumKind::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::SinglePartitionSpec(_) => {}
+        NodeRef::PartitionCmd(n) => {
+            f("concurrent", Scalar::Bool(n.concurrent));
+        }
+        NodeRef::RangeTblEntry(n) => {
+            f("rtekind", Scalar::Enum(n.rtekind, protobuf::RteKind::try_from(n.rtekind).map_or("", |e| e.as_str_name())));
+            f("relid", Scalar::UInt(n.relid.into()));
+            f("inh", Scalar::Bool(n.inh));
+            f("relkind", Scalar::Str(&n.relkind));
+            f("rellockmode", Scalar::Int(n.rellockmode.into()));
+            f("perminfoindex", Scalar::UInt(n.perminfoindex.into()));
+            f("security_barrier", Scalar::Bool(n.security_barrier));
+            f("jointype", Scalar::Enum(n.jointype, protobuf::JoinType::try_from(n.jointype).map_or("", |e| e.as_str_name())));
+            f("joinmergedcols", Scalar::Int(n.joinmergedcols.into()));
+            f("funcordinality", Scalar::Bool(n.funcordinality));
+            f("ctename", Scalar::Str(&n.ctename));
+            f("ctelevelsup", Scalar::UInt(n.ctelevelsup.into()));
+            f("self_reference", Scalar::Bool(n.self_reference));
+            f("enrname", Scalar::Str(&n.enrname));
+            f("enrtuples", Scalar::Float(n.enrtuples));
+            f("lateral", Scalar::Bool(n.lateral));
+            f("in_from_cl", Scalar::Bool(n.in_from_cl));
+        }
+        NodeRef::RtepermissionInfo(n) => {
+            f("relid", Scalar::UInt(n.relid.into()));
+            f("inh", Scalar::Bool(n.inh));
+            f("required_perms", Scalar::UInt(n.required_perms));
+            f("check_as_user", Scalar::UInt(n.check_as_user.into()));
+            f("selected_cols", Scalar::UInts(&n.selected_cols));
+            f("inserted_cols", Scalar::UInts(&n.inserted_cols));
+            f("updated_cols", Scalar::UInts(&n.updated_cols));
+        }
+        NodeRef::RangeTblFunction(n) => {
+            f("funccolcount", Scalar::Int(n.funccolcount.into()));
+            f("funcparams", Scalar::UInts(&n.funcparams));
+        }
+        NodeRef::TableSampleClause(n) => {
+            f("tsmhandler", Scalar::UInt(n.tsmhandler.into()));
+        }
+        NodeRef::WithCheckOption(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::WcoKind::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("relname", Scalar::Str(&n.relname));
+            f("polname", Scalar::Str(&n.polname));
+            f("cascaded", Scalar::Bool(n.cascaded));
+        }
+        NodeRef::SortGroupClause(n) => {
+            f("tle_sort_group_ref", Scalar::UInt(n.tle_sort_group_ref.into()));
+            f("eqop", Scalar::UInt(n.eqop.into()));
+            f("sortop", Scalar::UInt(n.sortop.into()));
+            f("nulls_first", Scalar::Bool(n.nulls_first));
+            f("hashable", Scalar::Bool(n.hashable));
+        }
+        NodeRef::GroupingSet(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::GroupingSetKind::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::WindowClause(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("refname", Scalar::Str(&n.refname));
+            f("frame_options", Scalar::Int(n.frame_options.into()));
+            f("start_in_range_func", Scalar::UInt(n.start_in_range_func.into()));
+            f("end_in_range_func", Scalar::UInt(n.end_in_range_func.into()));
+            f("in_range_coll", Scalar::UInt(n.in_range_coll.into()));
+            f("in_range_asc", Scalar::Bool(n.in_range_asc));
+            f("in_range_nulls_first", Scalar::Bool(n.in_range_nulls_first));
+            f("winref", Scalar::UInt(n.winref.into()));
+            f("copied_order", Scalar::Bool(n.copied_order));
+        }
+        NodeRef::RowMarkClause(n) => {
+            f("rti", Scalar::UInt(n.rti.into()));
+            f("strength", Scalar::Enum(n.strength, protobuf::LockClauseStrength::try_from(n.strength).map_or("", |e| e.as_str_name())));
+            f("wait_policy", Scalar::Enum(n.wait_policy, protobuf::LockWaitPolicy::try_from(n.wait_policy).map_or("", |e| e.as_str_name())));
+            f("pushed_down", Scalar::Bool(n.pushed_down));
+        }
+        NodeRef::WithClause(n) => {
+            f("recursive", Scalar::Bool(n.recursive));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::InferClause(n) => {
+            f("conname", Scalar::Str(&n.conname));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::OnConflictClause(n) => {
+            f("action", Scalar::Enum(n.action, protobuf::OnConflictAction::try_from(n.action).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CtesearchClause(n) => {
+            f("search_breadth_first", Scalar::Bool(n.search_breadth_first));
+            f("search_seq_column", Scalar::Str(&n.search_seq_column));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CtecycleClause(n) => {
+            f("cycle_mark_column", Scalar::Str(&n.cycle_mark_column));
+            f("cycle_path_column", Scalar::Str(&n.cycle_path_column));
+            f("location", Scalar::Int(n.location.into()));
+            f("cycle_mark_type", Scalar::UInt(n.cycle_mark_type.into()));
+            f("cycle_mark_typmod", Scalar::Int(n.cycle_mark_typmod.into()));
+            f("cycle_mark_collation", Scalar::UInt(n.cycle_mark_collation.into()));
+            f("cycle_mark_neop", Scalar::UInt(n.cycle_mark_neop.into()));
+        }
+        NodeRef::CommonTableExpr(n) => {
+            f("ctename", Scalar::Str(&n.ctename));
+            f(
+                "ctematerialized",
+                Scalar::Enum(n.ctematerialized, protobuf::CteMaterialize::try_from(n.ctematerialized).map_or("", |e| e.as_str_name())),
+            );
+            f("location", Scalar::Int(n.location.into()));
+            f("cterecursive", Scalar::Bool(n.cterecursive));
+            f("cterefcount", Scalar::Int(n.cterefcount.into()));
+        }
+        NodeRef::MergeWhenClause(n) => {
+            f("match_kind", Scalar::Enum(n.match_kind, protobuf::MergeMatchKind::try_from(n.match_kind).map_or("", |e| e.as_str_name())));
+            f("command_type", Scalar::Enum(n.command_type, protobuf::CmdType::try_from(n.command_type).map_or("", |e| e.as_str_name())));
+            f("override", Scalar::Enum(n.r#override, protobuf::OverridingKind::try_from(n.r#override).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::TriggerTransition(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("is_new", Scalar::Bool(n.is_new));
+            f("is_table", Scalar::Bool(n.is_table));
+        }
+        NodeRef::JsonOutput(_) => {}
+        NodeRef::JsonArgument(n) => {
+            f("name", Scalar::Str(&n.name));
+        }
+        NodeRef::JsonFuncExpr(n) => {
+            f("op", Scalar::Enum(n.op, protobuf::JsonExprOp::try_from(n.op).map_or("", |e| e.as_str_name())));
+            f("column_name", Scalar::Str(&n.column_name));
+            f("wrapper", Scalar::Enum(n.wrapper, protobuf::JsonWrapper::try_from(n.wrapper).map_or("", |e| e.as_str_name())));
+            f("quotes", Scalar::Enum(n.quotes, protobuf::JsonQuotes::try_from(n.quotes).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonTablePathSpec(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("name_location", Scalar::Int(n.name_location.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonTable(n) => {
+            f("lateral", Scalar::Bool(n.lateral));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonTableColumn(n) => {
+            f("coltype", Scalar::Enum(n.coltype, protobuf::JsonTableColumnType::try_from(n.coltype).map_or("", |e| e.as_str_name())));
+            f("name", Scalar::Str(&n.name));
+            f("wrapper", Scalar::Enum(n.wrapper, protobuf::JsonWrapper::try_from(n.wrapper).map_or("", |e| e.as_str_name())));
+            f("quotes", Scalar::Enum(n.quotes, protobuf::JsonQuotes::try_from(n.quotes).map_or("", |e| e.as_str_name())));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonKeyValue(_) => {}
+        NodeRef::JsonParseExpr(n) => {
+            f("unique_keys", Scalar::Bool(n.unique_keys));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonScalarExpr(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonSerializeExpr(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonObjectConstructor(n) => {
+            f("absent_on_null", Scalar::Bool(n.absent_on_null));
+            f("unique", Scalar::Bool(n.unique));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonArrayConstructor(n) => {
+            f("absent_on_null", Scalar::Bool(n.absent_on_null));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonArrayQueryConstructor(n) => {
+            f("absent_on_null", Scalar::Bool(n.absent_on_null));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonAggConstructor(n) => {
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::JsonObjectAgg(n) => {
+            f("absent_on_null", Scalar::Bool(n.absent_on_null));
+            f("unique", Scalar::Bool(n.unique));
+        }
+        NodeRef::JsonArrayAgg(n) => {
+            f("absent_on_null", Scalar::Bool(n.absent_on_null));
+        }
+        NodeRef::RawStmt(n) => {
+            f("stmt_location", Scalar::Int(n.stmt_location.into()));
+            f("stmt_len", Scalar::Int(n.stmt_len.into()));
+        }
+        NodeRef::InsertStmt(n) => {
+            f("override", Scalar::Enum(n.r#override, protobuf::OverridingKind::try_from(n.r#override).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::DeleteStmt(_) => {}
+        NodeRef::UpdateStmt(_) => {}
+        NodeRef::MergeStmt(_) => {}
+        NodeRef::SelectStmt(n) => {
+            f("group_distinct", Scalar::Bool(n.group_distinct));
+            f("limit_option", Scalar::Enum(n.limit_option, protobuf::LimitOption::try_from(n.limit_option).map_or("", |e| e.as_str_name())));
+            f("op", Scalar::Enum(n.op, protobuf::SetOperation::try_from(n.op).map_or("", |e| e.as_str_name())));
+            f("all", Scalar::Bool(n.all));
+        }
+        NodeRef::SetOperationStmt(n) => {
+            f("op", Scalar::Enum(n.op, protobuf::SetOperation::try_from(n.op).map_or("", |e| e.as_str_name())));
+            f("all", Scalar::Bool(n.all));
+        }
+        NodeRef::ReturnStmt(_) => {}
+        NodeRef::PlassignStmt(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("nnames", Scalar::Int(n.nnames.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CreateSchemaStmt(n) => {
+            f("schemaname", Scalar::Str(&n.schemaname));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+        }
+        NodeRef::AlterTableStmt(n) => {
+            f("objtype", Scalar::Enum(n.objtype, protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name())));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::ReplicaIdentityStmt(n) => {
+            f("identity_type", Scalar::Str(&n.identity_type));
+            f("name", Scalar::Str(&n.name));
+        }
+        NodeRef::AlterTableCmd(n) => {
+            f("subtype", Scalar::Enum(n.subtype, protobuf::AlterTableType::try_from(n.subtype).map_or("", |e| e.as_str_name())));
+            f("name", Scalar::Str(&n.name));
+            f("num", Scalar::Int(n.num.into()));
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+            f("recurse", Scalar::Bool(n.recurse));
+        }
+        NodeRef::AlterCollationStmt(_) => {}
+        NodeRef::AlterDomainStmt(n) => {
+            f("subtype", Scalar::Str(&n.subtype));
+            f("name", Scalar::Str(&n.name));
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::GrantStmt(n) => {
+            f("is_grant", Scalar::Bool(n.is_grant));
+            f("targtype", Scalar::Enum(n.targtype, protobuf::GrantTargetType::try_from(n.targtype).map_or("", |e| e.as_str_name())));
+            f("objtype", Scalar::Enum(n.objtype, protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name())));
+            f("grant_option", Scalar::Bool(n.grant_option));
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::ObjectWithArgs(n) => {
+            f("args_unspecified", Scalar::Bool(n.args_unspecified));
+        }
+        NodeRef::AccessPriv(n) => {
+            f("priv_name", Scalar::Str(&n.priv_name));
+        }
+        NodeRef::GrantRoleStmt(n) => {
+            f("is_grant", Scalar::Bool(n.is_grant));
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::AlterDefaultPrivilegesStmt(_) => {}
+        NodeRef::CopyStmt(n) => {
+            f("is_from", Scalar::Bool(n.is_from));
+            f("is_program", Scalar::Bool(n.is_program));
+            f("filename", Scalar::Str(&n.filename));
+        }
+        NodeRef::VariableSetStmt(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::VariableSetKind::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("name", Scalar::Str(&n.name));
+            f("is_local", Scalar::Bool(n.is_local));
+        }
+        NodeRef::VariableShowStmt(n) => {
+            f("name", Scalar::Str(&n.name));
+        }
+        NodeRef::CreateStmt(n) => {
+            f("oncommit", Scalar::Enum(n.oncommit, protobuf::OnCommitAction::try_from(n.oncommit).map_or("", |e| e.as_str_name())));
+            f("tablespacename", Scalar::Str(&n.tablespacename));
+            f("access_method", Scalar::Str(&n.access_method));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+        }
+        NodeRef::Constraint(n) => {
+            f("contype", Scalar::Enum(n.contype, protobuf::ConstrType::try_from(n.contype).map_or("", |e| e.as_str_name())));
+            f("conname", Scalar::Str(&n.conname));
+            f("deferrable", Scalar::Bool(n.deferrable));
+            f("initdeferred", Scalar::Bool(n.initdeferred));
+            f("skip_validation", Scalar::Bool(n.skip_validation));
+            f("initially_valid", Scalar::Bool(n.initially_valid));
+            f("is_no_inherit", Scalar::Bool(n.is_no_inherit));
+            f("cooked_expr", Scalar::Str(&n.cooked_expr));
+            f("generated_when", Scalar::Str(&n.generated_when));
+            f("inhcount", Scalar::Int(n.inhcount.into()));
+            f("nulls_not_distinct", Scalar::Bool(n.nulls_not_distinct));
+            f("indexname", Scalar::Str(&n.indexname));
+            f("indexspace", Scalar::Str(&n.indexspace));
+            f("reset_default_tblspc", Scalar::Bool(n.reset_default_tblspc));
+            f("access_method", Scalar::Str(&n.access_method));
+            f("fk_matchtype", Scalar::Str(&n.fk_matchtype));
+            f("fk_upd_action", Scalar::Str(&n.fk_upd_action));
+            f("fk_del_action", Scalar::Str(&n.fk_del_action));
+            f("old_pktable_oid", Scalar::UInt(n.old_pktable_oid.into()));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CreateTableSpaceStmt(n) => {
+            f("tablespacename", Scalar::Str(&n.tablespacename));
+            f("location", Scalar::Str(&n.location));
+        }
+        NodeRef::DropTableSpaceStmt(n) => {
+            f("tablespacename", Scalar::Str(&n.tablespacename));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::AlterTableSpaceOptionsStmt(n) => {
+            f("tablespacename", Scalar::Str(&n.tablespacename));
+            f("is_reset", Scalar::Bool(n.is_reset));
+        }
+        NodeRef::AlterTableMoveAllStmt(n) => {
+            f("orig_tablespacename", Scalar::Str(&n.orig_tablespacename));
+            f("objtype", Scalar::Enum(n.objtype, protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name())));
+            f("new_tablespacename", Scalar::Str(&n.new_tablespacename));
+            f("nowait", Scalar::Bool(n.nowait));
+        }
+        NodeRef::CreateExtensionStmt(n) => {
+            f("extname", Scalar::Str(&n.extname));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+        }
+        NodeRef::AlterExtensionStmt(n) => {
+            f("extname", Scalar::Str(&n.extname));
+        }
+        NodeRef::AlterExtensionContentsStmt(n) => {
+            f("extname", Scalar::Str(&n.extname));
+            f("action", Scalar::Int(n.action.into()));
+            f("objtype", Scalar::Enum(n.objtype, protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::CreateFdwStmt(n) => {
+            f("fdwname", Scalar::Str(&n.fdwname));
+        }
+        NodeRef::AlterFdwStmt(n) => {
+            f("fdwname", Scalar::Str(&n.fdwname));
+        }
+        NodeRef::CreateForeignServerStmt(n) => {
+            f("servername", Scalar::Str(&n.servername));
+            f("servertype", Scalar::Str(&n.servertype));
+            f("version", Scalar::Str(&n.version));
+            f("fdwname", Scalar::Str(&n.fdwname));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+        }
+        NodeRef::AlterForeignServerStmt(n) => {
+            f("servername", Scalar::Str(&n.servername));
+            f("version", Scalar::Str(&n.version));
+            f("has_version", Scalar::Bool(n.has_version));
+        }
+        NodeRef::CreateForeignTableStmt(n) => {
+            f("servername", Scalar::Str(&n.servername));
+        }
+        NodeRef::CreateUserMappingStmt(n) => {
+            f("servername", Scalar::Str(&n.servername));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+        }
+        NodeRef::AlterUserMappingStmt(n) => {
+            f("servername", Scalar::Str(&n.servername));
+        }
+        NodeRef::DropUserMappingStmt(n) => {
+            f("servername", Scalar::Str(&n.servername));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::ImportForeignSchemaStmt(n) => {
+            f("server_name", Scalar::Str(&n.server_name));
+            f("remote_schema", Scalar::Str(&n.remote_schema));
+            f("local_schema", Scalar::Str(&n.local_schema));
+            f("list_type", Scalar::Enum(n.list_type, protobuf::ImportForeignSchemaType::try_from(n.list_type).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::CreatePolicyStmt(n) => {
+            f("policy_name", Scalar::Str(&n.policy_name));
+            f("cmd_name", Scalar::Str(&n.cmd_name));
+            f("permissive", Scalar::Bool(n.permissive));
+        }
+        NodeRef::AlterPolicyStmt(n) => {
+            f("policy_name", Scalar::Str(&n.policy_name));
+        }
+        NodeRef::CreateAmStmt(n) => {
+            f("amname", Scalar::Str(&n.amname));
+            f("amtype", Scalar::Str(&n.amtype));
+        }
+        NodeRef::CreateTrigStmt(n) => {
+            f("replace", Scalar::Bool(n.replace));
+            f("isconstraint", Scalar::Bool(n.isconstraint));
+            f("trigname", Scalar::Str(&n.trigname));
+            f("row", Scalar::Bool(n.row));
+            f("timing", Scalar::Int(n.timing.into()));
+            f("events", Scalar::Int(n.events.into()));
+            f("deferrable", Scalar::Bool(n.deferrable));
+            f("initdeferred", Scalar::Bool(n.initdeferred));
+        }
+        NodeRef::CreateEventTrigStmt(n) => {
+            f("trigname", Scalar::Str(&n.trigname));
+            f("eventname", Scalar::Str(&n.eventname));
+        }
+        NodeRef::AlterEventTrigStmt(n) => {
+            f("trigname", Scalar::Str(&n.trigname));
+            f("tgenabled", Scalar::Str(&n.tgenabled));
+        }
+        NodeRef::CreatePlangStmt(n) => {
+            f("replace", Scalar::Bool(n.replace));
+            f("plname", Scalar::Str(&n.plname));
+            f("pltrusted", Scalar::Bool(n.pltrusted));
+        }
+        NodeRef::CreateRoleStmt(n) => {
+            f("stmt_type", Scalar::Enum(n.stmt_type, protobuf::RoleStmtType::try_from(n.stmt_type).map_or("", |e| e.as_str_name())));
+            f("role", Scalar::Str(&n.role));
+        }
+        NodeRef::AlterRoleStmt(n) => {
+            f("action", Scalar::Int(n.action.into()));
+        }
+        NodeRef::AlterRoleSetStmt(n) => {
+            f("database", Scalar::Str(&n.database));
+        }
+        NodeRef::DropRoleStmt(n) => {
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::CreateSeqStmt(n) => {
+            f("owner_id", Scalar::UInt(n.owner_id.into()));
+            f("for_identity", Scalar::Bool(n.for_identity));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+        }
+        NodeRef::AlterSeqStmt(n) => {
+            f("for_identity", Scalar::Bool(n.for_identity));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::DefineStmt(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::ObjectType::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("oldstyle", Scalar::Bool(n.oldstyle));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+            f("replace", Scalar::Bool(n.replace));
+        }
+        NodeRef::CreateDomainStmt(_) => {}
+        NodeRef::CreateOpClassStmt(n) => {
+            f("amname", Scalar::Str(&n.amname));
+            f("is_default", Scalar::Bool(n.is_default));
+        }
+        NodeRef::CreateOpClassItem(n) => {
+            f("itemtype", Scalar::Int(n.itemtype.into()));
+            f("number", Scalar::Int(n.number.into()));
+        }
+        NodeRef::CreateOpFamilyStmt(n) => {
+            f("amname", Scalar::Str(&n.amname));
+        }
+        NodeRef::AlterOpFamilyStmt(n) => {
+            f("amname", Scalar::Str(&n.amname));
+            f("is_drop", Scalar::Bool(n.is_drop));
+        }
+        NodeRef::DropStmt(n) => {
+            f("remove_type", Scalar::Enum(n.remove_type, protobuf::ObjectType::try_from(n.remove_type).map_or("", |e| e.as_str_name())));
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+            f("concurrent", Scalar::Bool(n.concurrent));
+        }
+        NodeRef::TruncateStmt(n) => {
+            f("restart_seqs", Scalar::Bool(n.restart_seqs));
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::CommentStmt(n) => {
+            f("objtype", Scalar::Enum(n.objtype, protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name())));
+            f("comment", Scalar::Str(&n.comment));
+        }
+        NodeRef::SecLabelStmt(n) => {
+            f("objtype", Scalar::Enum(n.objtype, protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name())));
+            f("provider", Scalar::Str(&n.provider));
+            f("label", Scalar::Str(&n.label));
+        }
+        NodeRef::DeclareCursorStmt(n) => {
+            f("portalname", Scalar::Str(&n.portalname));
+            f("options", Scalar::Int(n.options.into()));
+        }
+        NodeRef::ClosePortalStmt(n) => {
+            f("portalname", Scalar::Str(&n.portalname));
+        }
+        NodeRef::FetchStmt(n) => {
+            f("direction", Scalar::Enum(n.direction, protobuf::FetchDirection::try_from(n.direction).map_or("", |e| e.as_str_name())));
+            f("how_many", Scalar::Int(n.how_many));
+            f("portalname", Scalar::Str(&n.portalname));
+            f("ismove", Scalar::Bool(n.ismove));
+        }
+        NodeRef::IndexStmt(n) => {
+            f("idxname", Scalar::Str(&n.idxname));
+            f("access_method", Scalar::Str(&n.access_method));
+            f("table_space", Scalar::Str(&n.table_space));
+            f("idxcomment", Scalar::Str(&n.idxcomment));
+            f("index_oid", Scalar::UInt(n.index_oid.into()));
+            f("old_number", Scalar::UInt(n.old_number.into()));
+            f("old_create_subid", Scalar::UInt(n.old_create_subid.into()));
+            f("old_first_relfilelocator_subid", Scalar::UInt(n.old_first_relfilelocator_subid.into()));
+            f("unique", Scalar::Bool(n.unique));
+            f("nulls_not_distinct", Scalar::Bool(n.nulls_not_distinct));
+            f("primary", Scalar::Bool(n.primary));
+            f("isconstraint", Scalar::Bool(n.isconstraint));
+            f("deferrable", Scalar::Bool(n.deferrable));
+            f("initdeferred", Scalar::Bool(n.initdeferred));
+            f("transformed", Scalar::Bool(n.transformed));
+            f("concurrent", Scalar::Bool(n.concurrent));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+            f("reset_default_tblspc", Scalar::Bool(n.reset_default_tblspc));
+        }
+        NodeRef::CreateStatsStmt(n) => {
+            f("stxcomment", Scalar::Str(&n.stxcomment));
+            f("transformed", Scalar::Bool(n.transformed));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+        }
+        NodeRef::StatsElem(n) => {
+            f("name", Scalar::Str(&n.name));
+        }
+        NodeRef::AlterStatsStmt(n) => {
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::CreateFunctionStmt(n) => {
+            f("is_procedure", Scalar::Bool(n.is_procedure));
+            f("replace", Scalar::Bool(n.replace));
+        }
+        NodeRef::FunctionParameter(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("mode", Scalar::Enum(n.mode, protobuf::FunctionParameterMode::try_from(n.mode).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::AlterFunctionStmt(n) => {
+            f("objtype", Scalar::Enum(n.objtype, protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::DoStmt(_) => {}
+        NodeRef::InlineCodeBlock(n) => {
+            f("source_text", Scalar::Str(&n.source_text));
+            f("lang_oid", Scalar::UInt(n.lang_oid.into()));
+            f("lang_is_trusted", Scalar::Bool(n.lang_is_trusted));
+            f("atomic", Scalar::Bool(n.atomic));
+        }
+        NodeRef::CallStmt(_) => {}
+        NodeRef::CallContext(n) => {
+            f("atomic", Scalar::Bool(n.atomic));
+        }
+        NodeRef::RenameStmt(n) => {
+            f("rename_type", Scalar::Enum(n.rename_type, protobuf::ObjectType::try_from(n.rename_type).map_or("", |e| e.as_str_name())));
+            f("relation_type", Scalar::Enum(n.relation_type, protobuf::ObjectType::try_from(n.relation_type).map_or("", |e| e.as_str_name())));
+            f("subname", Scalar::Str(&n.subname));
+            f("newname", Scalar::Str(&n.newname));
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::AlterObjectDependsStmt(n) => {
+            f("object_type", Scalar::Enum(n.object_type, protobuf::ObjectType::try_from(n.object_type).map_or("", |e| e.as_str_name())));
+            f("remove", Scalar::Bool(n.remove));
+        }
+        NodeRef::AlterObjectSchemaStmt(n) => {
+            f("object_type", Scalar::Enum(n.object_type, protobuf::ObjectType::try_from(n.object_type).map_or("", |e| e.as_str_name())));
+            f("newschema", Scalar::Str(&n.newschema));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::AlterOwnerStmt(n) => {
+            f("object_type", Scalar::Enum(n.object_type, protobuf::ObjectType::try_from(n.object_type).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::AlterOperatorStmt(_) => {}
+        NodeRef::AlterTypeStmt(_) => {}
+        NodeRef::RuleStmt(n) => {
+            f("rulename", Scalar::Str(&n.rulename));
+            f("event", Scalar::Enum(n.event, protobuf::CmdType::try_from(n.event).map_or("", |e| e.as_str_name())));
+            f("instead", Scalar::Bool(n.instead));
+            f("replace", Scalar::Bool(n.replace));
+        }
+        NodeRef::NotifyStmt(n) => {
+            f("conditionname", Scalar::Str(&n.conditionname));
+            f("payload", Scalar::Str(&n.payload));
+        }
+        NodeRef::ListenStmt(n) => {
+            f("conditionname", Scalar::Str(&n.conditionname));
+        }
+        NodeRef::UnlistenStmt(n) => {
+            f("conditionname", Scalar::Str(&n.conditionname));
+        }
+        NodeRef::TransactionStmt(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::TransactionStmtKind::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("savepoint_name", Scalar::Str(&n.savepoint_name));
+            f("gid", Scalar::Str(&n.gid));
+            f("chain", Scalar::Bool(n.chain));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CompositeTypeStmt(_) => {}
+        NodeRef::CreateEnumStmt(_) => {}
+        NodeRef::CreateRangeStmt(_) => {}
+        NodeRef::AlterEnumStmt(n) => {
+            f("old_val", Scalar::Str(&n.old_val));
+            f("new_val", Scalar::Str(&n.new_val));
+            f("new_val_neighbor", Scalar::Str(&n.new_val_neighbor));
+            f("new_val_is_after", Scalar::Bool(n.new_val_is_after));
+            f("skip_if_new_val_exists", Scalar::Bool(n.skip_if_new_val_exists));
+        }
+        NodeRef::ViewStmt(n) => {
+            f("replace", Scalar::Bool(n.replace));
+            f(
+                "with_check_option",
+                Scalar::Enum(n.with_check_option, protobuf::ViewCheckOption::try_from(n.with_check_option).map_or("", |e| e.as_str_name())),
+            );
+        }
+        NodeRef::LoadStmt(n) => {
+            f("filename", Scalar::Str(&n.filename));
+        }
+        NodeRef::CreatedbStmt(n) => {
+            f("dbname", Scalar::Str(&n.dbname));
+        }
+        NodeRef::AlterDatabaseStmt(n) => {
+            f("dbname", Scalar::Str(&n.dbname));
+        }
+        NodeRef::AlterDatabaseRefreshCollStmt(n) => {
+            f("dbname", Scalar::Str(&n.dbname));
+        }
+        NodeRef::AlterDatabaseSetStmt(n) => {
+            f("dbname", Scalar::Str(&n.dbname));
+        }
+        NodeRef::DropdbStmt(n) => {
+            f("dbname", Scalar::Str(&n.dbname));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::AlterSystemStmt(_) => {}
+        NodeRef::ClusterStmt(n) => {
+            f("indexname", Scalar::Str(&n.indexname));
+        }
+        NodeRef::VacuumStmt(n) => {
+            f("is_vacuumcmd", Scalar::Bool(n.is_vacuumcmd));
+        }
+        NodeRef::VacuumRelation(n) => {
+            f("oid", Scalar::UInt(n.oid.into()));
+        }
+        NodeRef::ExplainStmt(_) => {}
+        NodeRef::CreateTableAsStmt(n) => {
+            f("objtype", Scalar::Enum(n.objtype, protobuf::ObjectType::try_from(n.objtype).map_or("", |e| e.as_str_name())));
+            f("is_select_into", Scalar::Bool(n.is_select_into));
+            f("if_not_exists", Scalar::Bool(n.if_not_exists));
+        }
+        NodeRef::RefreshMatViewStmt(n) => {
+            f("concurrent", Scalar::Bool(n.concurrent));
+            f("skip_data", Scalar::Bool(n.skip_data));
+        }
+        NodeRef::CheckPointStmt(_) => {}
+        NodeRef::DiscardStmt(n) => {
+            f("target", Scalar::Enum(n.target, protobuf::DiscardMode::try_from(n.target).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::LockStmt(n) => {
+            f("mode", Scalar::Int(n.mode.into()));
+            f("nowait", Scalar::Bool(n.nowait));
+        }
+        NodeRef::ConstraintsSetStmt(n) => {
+            f("deferred", Scalar::Bool(n.deferred));
+        }
+        NodeRef::ReindexStmt(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::ReindexObjectType::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("name", Scalar::Str(&n.name));
+        }
+        NodeRef::CreateConversionStmt(n) => {
+            f("for_encoding_name", Scalar::Str(&n.for_encoding_name));
+            f("to_encoding_name", Scalar::Str(&n.to_encoding_name));
+            f("def", Scalar::Bool(n.def));
+        }
+        NodeRef::CreateCastStmt(n) => {
+            f("context", Scalar::Enum(n.context, protobuf::CoercionContext::try_from(n.context).map_or("", |e| e.as_str_name())));
+            f("inout", Scalar::Bool(n.inout));
+        }
+        NodeRef::CreateTransformStmt(n) => {
+            f("replace", Scalar::Bool(n.replace));
+            f("lang", Scalar::Str(&n.lang));
+        }
+        NodeRef::PrepareStmt(n) => {
+            f("name", Scalar::Str(&n.name));
+        }
+        NodeRef::ExecuteStmt(n) => {
+            f("name", Scalar::Str(&n.name));
+        }
+        NodeRef::DeallocateStmt(n) => {
+            f("name", Scalar::Str(&n.name));
+            f("isall", Scalar::Bool(n.isall));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::DropOwnedStmt(n) => {
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::ReassignOwnedStmt(_) => {}
+        NodeRef::AlterTsdictionaryStmt(_) => {}
+        NodeRef::AlterTsconfigurationStmt(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::AlterTsConfigType::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("override", Scalar::Bool(n.r#override));
+            f("replace", Scalar::Bool(n.replace));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+        }
+        NodeRef::PublicationTable(_) => {}
+        NodeRef::PublicationObjSpec(n) => {
+            f("pubobjtype", Scalar::Enum(n.pubobjtype, protobuf::PublicationObjSpecType::try_from(n.pubobjtype).map_or("", |e| e.as_str_name())));
+            f("name", Scalar::Str(&n.name));
+            f("location", Scalar::Int(n.location.into()));
+        }
+        NodeRef::CreatePublicationStmt(n) => {
+            f("pubname", Scalar::Str(&n.pubname));
+            f("for_all_tables", Scalar::Bool(n.for_all_tables));
+        }
+        NodeRef::AlterPublicationStmt(n) => {
+            f("pubname", Scalar::Str(&n.pubname));
+            f("for_all_tables", Scalar::Bool(n.for_all_tables));
+            f("action", Scalar::Enum(n.action, protobuf::AlterPublicationAction::try_from(n.action).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::CreateSubscriptionStmt(n) => {
+            f("subname", Scalar::Str(&n.subname));
+            f("conninfo", Scalar::Str(&n.conninfo));
+        }
+        NodeRef::AlterSubscriptionStmt(n) => {
+            f("kind", Scalar::Enum(n.kind, protobuf::AlterSubscriptionType::try_from(n.kind).map_or("", |e| e.as_str_name())));
+            f("subname", Scalar::Str(&n.subname));
+            f("conninfo", Scalar::Str(&n.conninfo));
+        }
+        NodeRef::DropSubscriptionStmt(n) => {
+            f("subname", Scalar::Str(&n.subname));
+            f("missing_ok", Scalar::Bool(n.missing_ok));
+            f("behavior", Scalar::Enum(n.behavior, protobuf::DropBehavior::try_from(n.behavior).map_or("", |e| e.as_str_name())));
+        }
+        NodeRef::Integer(n) => {
+            f("ival", Scalar::Int(n.ival.into()));
+        }
+        NodeRef::Float(n) => {
+            f("fval", Scalar::Str(&n.fval));
+        }
+        NodeRef::Boolean(n) => {
+            f("boolval", Scalar::Bool(n.boolval));
+        }
+        NodeRef::String(n) => {
+            f("sval", Scalar::Str(&n.sval));
+        }
+        NodeRef::BitString(n) => {
+            f("bsval", Scalar::Str(&n.bsval));
+        }
+        NodeRef::List(_) => {}
+        NodeRef::IntList(_) => {}
+        NodeRef::OidList(_) => {}
+        NodeRef::AConst(n) => {
+            f("isnull", Scalar::Bool(n.isnull));
+            f("location", Scalar::Int(n.location.into()));
+        }
+    }
+}
+// END GENERATED
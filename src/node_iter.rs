@@ -0,0 +1,112 @@
+use std::collections::VecDeque;
+
+use crate::node_path::for_each_child;
+use crate::*;
+
+/// A lazy iterator over a parse tree, created by `iter()` and `iter_breadth_first()`.
+///
+/// Unlike `nodes()`, it yields every node type, only walks as far as it is advanced, and its only
+/// allocation is the queue of nodes it has seen but not yet yielded.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::NodeRef;
+///
+/// let result = pg_query::parse("SELECT a FROM t WHERE b IN (SELECT c FROM u)").unwrap();
+/// let tables: Vec<&str> = result
+///     .protobuf
+///     .iter()
+///     .filter_map(|node| match node {
+///         NodeRef::RangeVar(r) => Some(r.relname.as_str()),
+///         _ => None,
+///     })
+///     .collect();
+/// assert_eq!(tables, ["t", "u"]);
+///
+/// // Stops walking as soon as a match is found
+/// let first = result.protobuf.iter_breadth_first().find(|node| matches!(node, NodeRef::ColumnRef(_)));
+/// assert!(first.is_some());
+/// ```
+#[derive(Clone, Debug)]
+pub struct NodeIter<'a> {
+    queue: VecDeque<NodeRef<'a>>,
+    breadth_first: bool,
+}
+
+impl<'a> NodeIter<'a> {
+    pub(crate) fn new(roots: impl IntoIterator<Item = NodeRef<'a>>, breadth_first: bool) -> Self {
+        let mut queue: VecDeque<NodeRef<'a>> = roots.into_iter().collect();
+        if !breadth_first {
+            // The stack is popped from the back, so the first root has to be last
+            queue.make_contiguous().reverse();
+        }
+        Self { queue, breadth_first }
+    }
+}
+
+impl<'a> Iterator for NodeIter<'a> {
+    type Item = NodeRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.breadth_first {
+            let node = self.queue.pop_front()?;
+            push_children(node, &mut self.queue);
+            Some(node)
+        } else {
+            let node = self.queue.pop_back()?;
+            let start = self.queue.len();
+            push_children(node, &mut self.queue);
+            // Reverse the children in place so the first child is popped next
+            let end = self.queue.len();
+            for i in 0..(end - start) / 2 {
+                self.queue.swap(start + i, end - 1 - i);
+            }
+            Some(node)
+        }
+    }
+}
+
+impl<'a> NodeRef<'a> {
+    /// Iterates over this node and all of its descendants, depth-first in pre-order.
+    pub fn iter(self) -> NodeIter<'a> {
+        NodeIter::new([self], false)
+    }
+
+    /// Iterates over this node and all of its descendants, breadth-first.
+    pub fn iter_breadth_first(self) -> NodeIter<'a> {
+        NodeIter::new([self], true)
+    }
+}
+
+impl NodeEnum {
+    /// Iterates over this node and all of its descendants, depth-first in pre-order.
+    pub fn iter(&self) -> NodeIter<'_> {
+        self.to_ref().iter()
+    }
+
+    /// Iterates over this node and all of its descendants, breadth-first.
+    pub fn iter_breadth_first(&self) -> NodeIter<'_> {
+        self.to_ref().iter_breadth_first()
+    }
+}
+
+impl protobuf::ParseResult {
+    /// Iterates over every node of every statement, depth-first in pre-order.
+    pub fn iter(&self) -> NodeIter<'_> {
+        NodeIter::new(self.statement_roots(), false)
+    }
+
+    /// Iterates over every node of every statement, breadth-first.
+    pub fn iter_breadth_first(&self) -> NodeIter<'_> {
+        NodeIter::new(self.statement_roots(), true)
+    }
+
+    fn statement_roots(&self) -> impl Iterator<Item = NodeRef<'_>> {
+        self.stmts.iter().filter_map(|s| s.stmt.as_ref().and_then(|s| s.node.as_ref())).map(|n| n.to_ref())
+    }
+}
+
+fn push_children<'a>(node: NodeRef<'a>, queue: &mut VecDeque<NodeRef<'a>>) {
+    for_each_child(node, &mut |_, child| queue.push_back(child));
+}
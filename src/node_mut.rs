@@ -274,12 +274,285 @@ pub enum NodeMut {
 
 impl NodeMut {
     pub fn deparse(&self) -> Result<String> {
-        crate::deparse(&protobuf::ParseResult {
-            version: crate::bindings::PG_VERSION_NUM as i32,
-            stmts: vec![protobuf::RawStmt { stmt: Some(Box::new(Node { node: Some(self.to_enum()?) })), stmt_location: 0, stmt_len: 0 }],
-        })
+        self.to_enum()?.deparse()
     }
 
+    // BEGIN GENERATED by scripts/generate.py from src/protobuf.rs, do not edit by hand
+    /// The name of the node type, e.g. `SelectStmt`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            NodeMut::Alias(_) => "Alias",
+            NodeMut::RangeVar(_) => "RangeVar",
+            NodeMut::TableFunc(_) => "TableFunc",
+            NodeMut::IntoClause(_) => "IntoClause",
+            NodeMut::Var(_) => "Var",
+            NodeMut::Param(_) => "Param",
+            NodeMut::Aggref(_) => "Aggref",
+            NodeMut::GroupingFunc(_) => "GroupingFunc",
+            NodeMut::WindowFunc(_) => "WindowFunc",
+            NodeMut::WindowFuncRunCondition(_) => "WindowFuncRunCondition",
+            NodeMut::MergeSupportFunc(_) => "MergeSupportFunc",
+            NodeMut::SubscriptingRef(_) => "SubscriptingRef",
+            NodeMut::FuncExpr(_) => "FuncExpr",
+            NodeMut::NamedArgExpr(_) => "NamedArgExpr",
+            NodeMut::OpExpr(_) => "OpExpr",
+            NodeMut::DistinctExpr(_) => "DistinctExpr",
+            NodeMut::NullIfExpr(_) => "NullIfExpr",
+            NodeMut::ScalarArrayOpExpr(_) => "ScalarArrayOpExpr",
+            NodeMut::BoolExpr(_) => "BoolExpr",
+            NodeMut::SubLink(_) => "SubLink",
+            NodeMut::SubPlan(_) => "SubPlan",
+            NodeMut::AlternativeSubPlan(_) => "AlternativeSubPlan",
+            NodeMut::FieldSelect(_) => "FieldSelect",
+            NodeMut::FieldStore(_) => "FieldStore",
+            NodeMut::RelabelType(_) => "RelabelType",
+            NodeMut::CoerceViaIo(_) => "CoerceViaIo",
+            NodeMut::ArrayCoerceExpr(_) => "ArrayCoerceExpr",
+            NodeMut::ConvertRowtypeExpr(_) => "ConvertRowtypeExpr",
+            NodeMut::CollateExpr(_) => "CollateExpr",
+            NodeMut::CaseExpr(_) => "CaseExpr",
+            NodeMut::CaseWhen(_) => "CaseWhen",
+            NodeMut::CaseTestExpr(_) => "CaseTestExpr",
+            NodeMut::ArrayExpr(_) => "ArrayExpr",
+            NodeMut::RowExpr(_) => "RowExpr",
+            NodeMut::RowCompareExpr(_) => "RowCompareExpr",
+            NodeMut::CoalesceExpr(_) => "CoalesceExpr",
+            NodeMut::MinMaxExpr(_) => "MinMaxExpr",
+            NodeMut::SqlvalueFunction(_) => "SqlvalueFunction",
+            NodeMut::XmlExpr(_) => "XmlExpr",
+            NodeMut::JsonFormat(_) => "JsonFormat",
+            NodeMut::JsonReturning(_) => "JsonReturning",
+            NodeMut::JsonValueExpr(_) => "JsonValueExpr",
+            NodeMut::JsonConstructorExpr(_) => "JsonConstructorExpr",
+            NodeMut::JsonIsPredicate(_) => "JsonIsPredicate",
+            NodeMut::JsonBehavior(_) => "JsonBehavior",
+            NodeMut::JsonExpr(_) => "JsonExpr",
+            NodeMut::JsonTablePath(_) => "JsonTablePath",
+            NodeMut::JsonTablePathScan(_) => "JsonTablePathScan",
+            NodeMut::JsonTableSiblingJoin(_) => "JsonTableSiblingJoin",
+            NodeMut::NullTest(_) => "NullTest",
+            NodeMut::BooleanTest(_) => "BooleanTest",
+            NodeMut::MergeAction(_) => "MergeAction",
+            NodeMut::CoerceToDomain(_) => "CoerceToDomain",
+            NodeMut::CoerceToDomainValue(_) => "CoerceToDomainValue",
+            NodeMut::SetToDefault(_) => "SetToDefault",
+            NodeMut::CurrentOfExpr(_) => "CurrentOfExpr",
+            NodeMut::NextValueExpr(_) => "NextValueExpr",
+            NodeMut::InferenceElem(_) => "InferenceElem",
+            NodeMut::TargetEntry(_) => "TargetEntry",
+            NodeMut::RangeTblRef(_) => "RangeTblRef",
+            NodeMut::JoinExpr(_) => "JoinExpr",
+            NodeMut::FromExpr(_) => "FromExpr",
+            NodeMut::OnConflictExpr(_) => "OnConflictExpr",
+            NodeMut::Query(_) => "Query",
+            NodeMut::TypeName(_) => "TypeName",
+            NodeMut::ColumnRef(_) => "ColumnRef",
+            NodeMut::ParamRef(_) => "ParamRef",
+            NodeMut::AExpr(_) => "AExpr",
+            NodeMut::TypeCast(_) => "TypeCast",
+            NodeMut::CollateClause(_) => "CollateClause",
+            NodeMut::RoleSpec(_) => "RoleSpec",
+            NodeMut::FuncCall(_) => "FuncCall",
+            NodeMut::AStar(_) => "AStar",
+            NodeMut::AIndices(_) => "AIndices",
+            NodeMut::AIndirection(_) => "AIndirection",
+            NodeMut::AArrayExpr(_) => "AArrayExpr",
+            NodeMut::ResTarget(_) => "ResTarget",
+            NodeMut::MultiAssignRef(_) => "MultiAssignRef",
+            NodeMut::SortBy(_) => "SortBy",
+            NodeMut::WindowDef(_) => "WindowDef",
+            NodeMut::RangeSubselect(_) => "RangeSubselect",
+            NodeMut::RangeFunction(_) => "RangeFunction",
+            NodeMut::RangeTableFunc(_) => "RangeTableFunc",
+            NodeMut::RangeTableFuncCol(_) => "RangeTableFuncCol",
+            NodeMut::RangeTableSample(_) => "RangeTableSample",
+            NodeMut::ColumnDef(_) => "ColumnDef",
+            NodeMut::TableLikeClause(_) => "TableLikeClause",
+            NodeMut::IndexElem(_) => "IndexElem",
+            NodeMut::DefElem(_) => "DefElem",
+            NodeMut::LockingClause(_) => "LockingClause",
+            NodeMut::XmlSerialize(_) => "XmlSerialize",
+            NodeMut::PartitionElem(_) => "PartitionElem",
+            NodeMut::PartitionSpec(_) => "PartitionSpec",
+            NodeMut::PartitionBoundSpec(_) => "PartitionBoundSpec",
+            NodeMut::PartitionRangeDatum(_) => "PartitionRangeDatum",
+            NodeMut::SinglePartitionSpec(_) => "SinglePartitionSpec",
+            NodeMut::PartitionCmd(_) => "PartitionCmd",
+            NodeMut::RangeTblEntry(_) => "RangeTblEntry",
+            NodeMut::RtepermissionInfo(_) => "RtepermissionInfo",
+            NodeMut::RangeTblFunction(_) => "RangeTblFunction",
+            NodeMut::TableSampleClause(_) => "TableSampleClause",
+            NodeMut::WithCheckOption(_) => "WithCheckOption",
+            NodeMut::SortGroupClause(_) => "SortGroupClause",
+            NodeMut::GroupingSet(_) => "GroupingSet",
+            NodeMut::WindowClause(_) => "WindowClause",
+            NodeMut::RowMarkClause(_) => "RowMarkClause",
+            NodeMut::WithClause(_) => "WithClause",
+            NodeMut::InferClause(_) => "InferClause",
+            NodeMut::OnConflictClause(_) => "OnConflictClause",
+            NodeMut::CtesearchClause(_) => "CtesearchClause",
+            NodeMut::CtecycleClause(_) => "CtecycleClause",
+            NodeMut::CommonTableExpr(_) => "CommonTableExpr",
+            NodeMut::MergeWhenClause(_) => "MergeWhenClause",
+            NodeMut::TriggerTransition(_) => "TriggerTransition",
+            NodeMut::JsonOutput(_) => "JsonOutput",
+            NodeMut::JsonArgument(_) => "JsonArgument",
+            NodeMut::JsonFuncExpr(_) => "JsonFuncExpr",
+            NodeMut::JsonTablePathSpec(_) => "JsonTablePathSpec",
+            NodeMut::JsonTable(_) => "JsonTable",
+            NodeMut::JsonTableColumn(_) => "JsonTableColumn",
+            NodeMut::JsonKeyValue(_) => "JsonKeyValue",
+            NodeMut::JsonParseExpr(_) => "JsonParseExpr",
+            NodeMut::JsonScalarExpr(_) => "JsonScalarExpr",
+            NodeMut::JsonSerializeExpr(_) => "JsonSerializeExpr",
+            NodeMut::JsonObjectConstructor(_) => "JsonObjectConstructor",
+            NodeMut::JsonArrayConstructor(_) => "JsonArrayConstructor",
+            NodeMut::JsonArrayQueryConstructor(_) => "JsonArrayQueryConstructor",
+            NodeMut::JsonAggConstructor(_) => "JsonAggConstructor",
+            NodeMut::JsonObjectAgg(_) => "JsonObjectAgg",
+            NodeMut::JsonArrayAgg(_) => "JsonArrayAgg",
+            NodeMut::RawStmt(_) => "RawStmt",
+            NodeMut::InsertStmt(_) => "InsertStmt",
+            NodeMut::DeleteStmt(_) => "DeleteStmt",
+            NodeMut::UpdateStmt(_) => "UpdateStmt",
+            NodeMut::MergeStmt(_) => "MergeStmt",
+            NodeMut::SelectStmt(_) => "SelectStmt",
+            NodeMut::SetOperationStmt(_) => "SetOperationStmt",
+            NodeMut::ReturnStmt(_) => "ReturnStmt",
+            NodeMut::PlassignStmt(_) => "PlassignStmt",
+            NodeMut::CreateSchemaStmt(_) => "CreateSchemaStmt",
+            NodeMut::AlterTableStmt(_) => "AlterTableStmt",
+            NodeMut::ReplicaIdentityStmt(_) => "ReplicaIdentityStmt",
+            NodeMut::AlterTableCmd(_) => "AlterTableCmd",
+            NodeMut::AlterCollationStmt(_) => "AlterCollationStmt",
+            NodeMut::AlterDomainStmt(_) => "AlterDomainStmt",
+            NodeMut::GrantStmt(_) => "GrantStmt",
+            NodeMut::ObjectWithArgs(_) => "ObjectWithArgs",
+            NodeMut::AccessPriv(_) => "AccessPriv",
+            NodeMut::GrantRoleStmt(_) => "GrantRoleStmt",
+            NodeMut::AlterDefaultPrivilegesStmt(_) => "AlterDefaultPrivilegesStmt",
+            NodeMut::CopyStmt(_) => "CopyStmt",
+            NodeMut::VariableSetStmt(_) => "VariableSetStmt",
+            NodeMut::VariableShowStmt(_) => "VariableShowStmt",
+            NodeMut::CreateStmt(_) => "CreateStmt",
+            NodeMut::Constraint(_) => "Constraint",
+            NodeMut::CreateTableSpaceStmt(_) => "CreateTableSpaceStmt",
+            NodeMut::DropTableSpaceStmt(_) => "DropTableSpaceStmt",
+            NodeMut::AlterTableSpaceOptionsStmt(_) => "AlterTableSpaceOptionsStmt",
+            NodeMut::AlterTableMoveAllStmt(_) => "AlterTableMoveAllStmt",
+            NodeMut::CreateExtensionStmt(_) => "CreateExtensionStmt",
+            NodeMut::AlterExtensionStmt(_) => "AlterExtensionStmt",
+            NodeMut::AlterExtensionContentsStmt(_) => "AlterExtensionContentsStmt",
+            NodeMut::CreateFdwStmt(_) => "CreateFdwStmt",
+            NodeMut::AlterFdwStmt(_) => "AlterFdwStmt",
+            NodeMut::CreateForeignServerStmt(_) => "CreateForeignServerStmt",
+            NodeMut::AlterForeignServerStmt(_) => "AlterForeignServerStmt",
+            NodeMut::CreateForeignTableStmt(_) => "CreateForeignTableStmt",
+            NodeMut::CreateUserMappingStmt(_) => "CreateUserMappingStmt",
+            NodeMut::AlterUserMappingStmt(_) => "AlterUserMappingStmt",
+            NodeMut::DropUserMappingStmt(_) => "DropUserMappingStmt",
+            NodeMut::ImportForeignSchemaStmt(_) => "ImportForeignSchemaStmt",
+            NodeMut::CreatePolicyStmt(_) => "CreatePolicyStmt",
+            NodeMut::AlterPolicyStmt(_) => "AlterPolicyStmt",
+            NodeMut::CreateAmStmt(_) => "CreateAmStmt",
+            NodeMut::CreateTrigStmt(_) => "CreateTrigStmt",
+            NodeMut::CreateEventTrigStmt(_) => "CreateEventTrigStmt",
+            NodeMut::AlterEventTrigStmt(_) => "AlterEventTrigStmt",
+            NodeMut::CreatePlangStmt(_) => "CreatePlangStmt",
+            NodeMut::CreateRoleStmt(_) => "CreateRoleStmt",
+            NodeMut::AlterRoleStmt(_) => "AlterRoleStmt",
+            NodeMut::AlterRoleSetStmt(_) => "AlterRoleSetStmt",
+            NodeMut::DropRoleStmt(_) => "DropRoleStmt",
+            NodeMut::CreateSeqStmt(_) => "CreateSeqStmt",
+            NodeMut::AlterSeqStmt(_) => "AlterSeqStmt",
+            NodeMut::DefineStmt(_) => "DefineStmt",
+            NodeMut::CreateDomainStmt(_) => "CreateDomainStmt",
+            NodeMut::CreateOpClassStmt(_) => "CreateOpClassStmt",
+            NodeMut::CreateOpClassItem(_) => "CreateOpClassItem",
+            NodeMut::CreateOpFamilyStmt(_) => "CreateOpFamilyStmt",
+            NodeMut::AlterOpFamilyStmt(_) => "AlterOpFamilyStmt",
+            NodeMut::DropStmt(_) => "DropStmt",
+            NodeMut::TruncateStmt(_) => "TruncateStmt",
+            NodeMut::CommentStmt(_) => "CommentStmt",
+            NodeMut::SecLabelStmt(_) => "SecLabelStmt",
+            NodeMut::DeclareCursorStmt(_) => "DeclareCursorStmt",
+            NodeMut::ClosePortalStmt(_) => "ClosePortalStmt",
+            NodeMut::FetchStmt(_) => "FetchStmt",
+            NodeMut::IndexStmt(_) => "IndexStmt",
+            NodeMut::CreateStatsStmt(_) => "CreateStatsStmt",
+            NodeMut::StatsElem(_) => "StatsElem",
+            NodeMut::AlterStatsStmt(_) => "AlterStatsStmt",
+            NodeMut::CreateFunctionStmt(_) => "CreateFunctionStmt",
+            NodeMut::FunctionParameter(_) => "FunctionParameter",
+            NodeMut::AlterFunctionStmt(_) => "AlterFunctionStmt",
+            NodeMut::DoStmt(_) => "DoStmt",
+            NodeMut::InlineCodeBlock(_) => "InlineCodeBlock",
+            NodeMut::CallStmt(_) => "CallStmt",
+            NodeMut::CallContext(_) => "CallContext",
+            NodeMut::RenameStmt(_) => "RenameStmt",
+            NodeMut::AlterObjectDependsStmt(_) => "AlterObjectDependsStmt",
+            NodeMut::AlterObjectSchemaStmt(_) => "AlterObjectSchemaStmt",
+            NodeMut::AlterOwnerStmt(_) => "AlterOwnerStmt",
+            NodeMut::AlterOperatorStmt(_) => "AlterOperatorStmt",
+            NodeMut::AlterTypeStmt(_) => "AlterTypeStmt",
+            NodeMut::RuleStmt(_) => "RuleStmt",
+            NodeMut::NotifyStmt(_) => "NotifyStmt",
+            NodeMut::ListenStmt(_) => "ListenStmt",
+            NodeMut::UnlistenStmt(_) => "UnlistenStmt",
+            NodeMut::TransactionStmt(_) => "TransactionStmt",
+            NodeMut::CompositeTypeStmt(_) => "CompositeTypeStmt",
+            NodeMut::CreateEnumStmt(_) => "CreateEnumStmt",
+            NodeMut::CreateRangeStmt(_) => "CreateRangeStmt",
+            NodeMut::AlterEnumStmt(_) => "AlterEnumStmt",
+            NodeMut::ViewStmt(_) => "ViewStmt",
+            NodeMut::LoadStmt(_) => "LoadStmt",
+            NodeMut::CreatedbStmt(_) => "CreatedbStmt",
+            NodeMut::AlterDatabaseStmt(_) => "AlterDatabaseStmt",
+            NodeMut::AlterDatabaseRefreshCollStmt(_) => "AlterDatabaseRefreshCollStmt",
+            NodeMut::AlterDatabaseSetStmt(_) => "AlterDatabaseSetStmt",
+            NodeMut::DropdbStmt(_) => "DropdbStmt",
+            NodeMut::AlterSystemStmt(_) => "AlterSystemStmt",
+            NodeMut::ClusterStmt(_) => "ClusterStmt",
+            NodeMut::VacuumStmt(_) => "VacuumStmt",
+            NodeMut::VacuumRelation(_) => "VacuumRelation",
+            NodeMut::ExplainStmt(_) => "ExplainStmt",
+            NodeMut::CreateTableAsStmt(_) => "CreateTableAsStmt",
+            NodeMut::RefreshMatViewStmt(_) => "RefreshMatViewStmt",
+            NodeMut::CheckPointStmt(_) => "CheckPointStmt",
+            NodeMut::DiscardStmt(_) => "DiscardStmt",
+            NodeMut::LockStmt(_) => "LockStmt",
+            NodeMut::ConstraintsSetStmt(_) => "ConstraintsSetStmt",
+            NodeMut::ReindexStmt(_) => "ReindexStmt",
+            NodeMut::CreateConversionStmt(_) => "CreateConversionStmt",
+            NodeMut::CreateCastStmt(_) => "CreateCastStmt",
+            NodeMut::CreateTransformStmt(_) => "CreateTransformStmt",
+            NodeMut::PrepareStmt(_) => "PrepareStmt",
+            NodeMut::ExecuteStmt(_) => "ExecuteStmt",
+            NodeMut::DeallocateStmt(_) => "DeallocateStmt",
+            NodeMut::DropOwnedStmt(_) => "DropOwnedStmt",
+            NodeMut::ReassignOwnedStmt(_) => "ReassignOwnedStmt",
+            NodeMut::AlterTsdictionaryStmt(_) => "AlterTsdictionaryStmt",
+            NodeMut::AlterTsconfigurationStmt(_) => "AlterTsconfigurationStmt",
+            NodeMut::PublicationTable(_) => "PublicationTable",
+            NodeMut::PublicationObjSpec(_) => "PublicationObjSpec",
+            NodeMut::CreatePublicationStmt(_) => "CreatePublicationStmt",
+            NodeMut::AlterPublicationStmt(_) => "AlterPublicationStmt",
+            NodeMut::CreateSubscriptionStmt(_) => "CreateSubscriptionStmt",
+            NodeMut::AlterSubscriptionStmt(_) => "AlterSubscriptionStmt",
+            NodeMut::DropSubscriptionStmt(_) => "DropSubscriptionStmt",
+            NodeMut::Integer(_) => "Integer",
+            NodeMut::Float(_) => "Float",
+            NodeMut::Boolean(_) => "Boolean",
+            NodeMut::String(_) => "String",
+            NodeMut::BitString(_) => "BitString",
+            NodeMut::List(_) => "List",
+            NodeMut::IntList(_) => "IntList",
+            NodeMut::OidList(_) => "OidList",
+            NodeMut::AConst(_) => "AConst",
+        }
+    }
+    // END GENERATED
+
     pub fn to_enum(&self) -> Result<NodeEnum> {
         unsafe {
             let err = Error::InvalidPointer;
@@ -0,0 +1,6485 @@
+use std::fmt;
+
+use crate::protobuf::a_const;
+use crate::*;
+
+/// A single step from a node to one of its children, e.g. `BoolExpr.args[1]`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct PathStep {
+    /// Type of the parent node, as returned by [NodeRef::type_name].
+    pub node_type: &'static str,
+    /// Name of the field on the parent node.
+    pub field: &'static str,
+    /// Position within the field, for list fields.
+    pub index: Option<usize>,
+}
+
+/// Where a node sits in the tree: its ancestors from the statement root down to its parent, each with
+/// the field leading to the next node.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::NodeRef;
+///
+/// let result = pg_query::parse("SELECT * FROM t WHERE a = 1 AND b > c").unwrap();
+/// let (_, path) = result
+///     .protobuf
+///     .iter_with_paths()
+///     .find(|(node, _)| matches!(node, NodeRef::ColumnRef(_)) && node.deparse().unwrap() == "b")
+///     .unwrap();
+/// assert_eq!(path.to_string(), "SelectStmt.where_clause → BoolExpr.args[1] → AExpr.lexpr");
+/// assert_eq!(path.parent().unwrap().type_name(), "AExpr");
+/// assert!(path.is_within("SelectStmt", "where_clause"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NodePath<'a> {
+    /// Index of the statement the path starts from, 0 when traversing a single node.
+    pub statement: usize,
+    /// The ancestors of the node, outermost first, each with the step leading to the next node.
+    pub ancestors: Vec<(NodeRef<'a>, PathStep)>,
+}
+
+impl<'a> NodePath<'a> {
+    /// The steps from the root to the node.
+    pub fn steps(&self) -> impl Iterator<Item = PathStep> + '_ {
+        self.ancestors.iter().map(|(_, step)| *step)
+    }
+
+    /// The immediate parent of the node, or `None` for a root.
+    pub fn parent(&self) -> Option<NodeRef<'a>> {
+        self.ancestors.last().map(|(node, _)| *node)
+    }
+
+    /// The step from the parent to the node, or `None` for a root.
+    pub fn last_step(&self) -> Option<PathStep> {
+        self.ancestors.last().map(|(_, step)| *step)
+    }
+
+    /// The number of ancestors, 0 for a root.
+    pub fn depth(&self) -> usize {
+        self.ancestors.len()
+    }
+
+    /// Returns the steps of this path without the ancestors, so it can outlive the tree it was found in.
+    pub fn to_field_path(&self) -> FieldPath {
+        FieldPath { statement: self.statement, steps: self.steps().collect() }
+    }
+
+    /// Whether any ancestor was reached through `field` on a node of type `node_type`, e.g. to check if a
+    /// column reference is part of a `("SelectStmt", "where_clause")`.
+    pub fn is_within(&self, node_type: &str, field: &str) -> bool {
+        self.steps().any(|step| step.node_type == node_type && step.field == field)
+    }
+}
+
+/// The statement index and field steps of a [NodePath], without references into the tree.
+///
+/// A field path found in one tree can be resolved in a clone of that tree with
+/// [protobuf::ParseResult::resolve], or used for targeted rewrites with
+/// [protobuf::ParseResult::resolve_mut].
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeEnum, NodeRef};
+///
+/// let mut result = pg_query::parse("SELECT * FROM t WHERE a = 1 AND b > c").unwrap();
+/// let path = result
+///     .protobuf
+///     .iter_with_paths()
+///     .find(|(node, _)| matches!(node, NodeRef::ColumnRef(_)) && node.deparse().unwrap() == "b")
+///     .map(|(_, path)| path.to_field_path())
+///     .unwrap();
+/// assert_eq!(result.protobuf.resolve(&path).unwrap().deparse().unwrap(), "b");
+///
+/// // Point the path at the parent expression and swap its operands
+/// let parent = pg_query::FieldPath { steps: path.steps[..path.steps.len() - 1].to_vec(), ..path };
+/// if let Some(NodeEnum::AExpr(expr)) = result.protobuf.resolve_mut(&parent) {
+///     std::mem::swap(&mut expr.lexpr, &mut expr.rexpr);
+/// }
+/// assert_eq!(result.protobuf.deparse().unwrap(), "SELECT * FROM t WHERE a = 1 AND c > b");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FieldPath {
+    /// Index of the statement the path starts from, 0 when traversing a single node.
+    pub statement: usize,
+    /// The steps from the root to the node.
+    pub steps: Vec<PathStep>,
+}
+
+impl fmt::Display for PathStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.node_type, self.field)?;
+        if let Some(index) = self.index {
+            write!(f, "[{index}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for NodePath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_steps(self.steps(), f)
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_steps(self.steps.iter().copied(), f)
+    }
+}
+
+fn fmt_steps(steps: impl Iterator<Item = PathStep>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, step) in steps.enumerate() {
+        if i > 0 {
+            f.write_str(" → ")?;
+        }
+        write!(f, "{step}")?;
+    }
+    Ok(())
+}
+
+/// A depth-first iterator over a parse tree that yields every node with its [NodePath], created by
+/// `iter_with_paths()`.
+#[derive(Clone, Debug)]
+pub struct NodePathIter<'a> {
+    // Nodes left to visit, with their statement, depth and the step leading to them
+    stack: Vec<(NodeRef<'a>, usize, usize, Option<PathStep>)>,
+    // The nodes and steps leading to the most recently yielded node
+    nodes: Vec<NodeRef<'a>>,
+    steps: Vec<PathStep>,
+}
+
+impl<'a> NodePathIter<'a> {
+    fn new(roots: impl Iterator<Item = (usize, NodeRef<'a>)>) -> Self {
+        let mut stack: Vec<_> = roots.map(|(statement, node)| (node, statement, 0, None)).collect();
+        stack.reverse();
+        Self { stack, nodes: Vec::new(), steps: Vec::new() }
+    }
+}
+
+impl<'a> Iterator for NodePathIter<'a> {
+    type Item = (NodeRef<'a>, NodePath<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, statement, depth, step) = self.stack.pop()?;
+        self.nodes.truncate(depth);
+        self.steps.truncate(depth.saturating_sub(1));
+        if let Some(step) = step {
+            self.steps.push(step);
+        }
+        let path = NodePath { statement, ancestors: self.nodes.iter().copied().zip(self.steps.iter().copied()).collect() };
+        self.nodes.push(node);
+
+        let start = self.stack.len();
+        for_each_child(node, &mut |step, child| self.stack.push((child, statement, depth + 1, Some(step))));
+        self.stack[start..].reverse();
+        Some((node, path))
+    }
+}
+
+impl<'a> NodeRef<'a> {
+    /// Iterates over this node and all of its descendants depth-first, together with their paths.
+    pub fn iter_with_paths(self) -> NodePathIter<'a> {
+        NodePathIter::new([(0, self)].into_iter())
+    }
+
+    /// Finds the descendant of this node at `path`, ignoring `path.statement`.
+    pub fn resolve(self, path: &FieldPath) -> Option<NodeRef<'a>> {
+        path.steps.iter().try_fold(self, |node, step| child(node, *step))
+    }
+}
+
+impl NodeEnum {
+    /// Iterates over this node and all of its descendants depth-first, together with their paths.
+    pub fn iter_with_paths(&self) -> NodePathIter<'_> {
+        self.to_ref().iter_with_paths()
+    }
+
+    /// Finds the descendant of this node at `path`, ignoring `path.statement`.
+    pub fn resolve(&self, path: &FieldPath) -> Option<NodeRef<'_>> {
+        self.to_ref().resolve(path)
+    }
+
+    /// Finds the descendant of this node at `path` for modification, ignoring `path.statement`.
+    ///
+    /// Only nodes stored as a [Node] can be resolved this way, so paths ending at a field with a fixed
+    /// type, like `SelectStmt.with_clause`, return `None`.
+    pub fn resolve_mut(&mut self, path: &FieldPath) -> Option<&mut NodeEnum> {
+        descendant_mut(self, &path.steps)
+    }
+}
+
+impl protobuf::ParseResult {
+    /// Iterates over every node of every statement depth-first, together with their paths.
+    pub fn iter_with_paths(&self) -> NodePathIter<'_> {
+        NodePathIter::new(self.stmts.iter().enumerate().filter_map(|(i, s)| s.stmt.as_ref().and_then(|s| s.node.as_ref()).map(|n| (i, n.to_ref()))))
+    }
+
+    /// Finds the node at `path`, starting from statement `path.statement`.
+    pub fn resolve(&self, path: &FieldPath) -> Option<NodeRef<'_>> {
+        self.stmts.get(path.statement)?.stmt.as_ref()?.node.as_ref()?.resolve(path)
+    }
+
+    /// Finds the node at `path` for modification, starting from statement `path.statement`.
+    ///
+    /// This allows targeted rewrites of a node found through [protobuf::ParseResult::iter_with_paths].
+    /// Like [NodeEnum::resolve_mut], it returns `None` for paths ending at a field with a fixed type.
+    pub fn resolve_mut(&mut self, path: &FieldPath) -> Option<&mut NodeEnum> {
+        self.stmts.get_mut(path.statement)?.stmt.as_mut()?.node.as_mut()?.resolve_mut(path)
+    }
+}
+
+// Follows a single step from `node` to one of its children.
+fn child(node: NodeRef<'_>, step: PathStep) -> Option<NodeRef<'_>> {
+    let mut found = None;
+    for_each_child(node, &mut |s, child| {
+        if s == step && found.is_none() {
+            found = Some(child);
+        }
+    });
+    found
+}
+
+fn step(node_type: &'static str, field: &'static str, index: Option<usize>) -> PathStep {
+    PathStep { node_type, field, index }
+}
+
+// BEGIN GENERATED by scripts/generate.py from src/protobuf.rs, do not edit by hand
+// Calls `f` with every direct child of `node` and the step leading to it, in field order.
+pub(crate) fn for_each_child<'a>(node: NodeRef<'a>, f: &mut impl FnMut(PathStep, NodeRef<'a>)) {
+    match node {
+        NodeRef::Alias(n) => {
+            for (i, v) in n.colnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Alias", "colnames", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::RangeVar(n) => {
+            if let Some(v) = &n.alias {
+                f(step("RangeVar", "alias", None), NodeRef::Alias(v));
+            }
+        }
+        NodeRef::TableFunc(n) => {
+            for (i, v) in n.ns_uris.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "ns_uris", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.ns_names.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "ns_names", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.docexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("TableFunc", "docexpr", None), v.to_ref());
+            }
+            if let Some(v) = n.rowexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("TableFunc", "rowexpr", None), v.to_ref());
+            }
+            for (i, v) in n.colnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "colnames", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.coltypes.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "coltypes", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.coltypmods.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "coltypmods", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.colcollations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "colcollations", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.colexprs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "colexprs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.coldefexprs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "coldefexprs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.colvalexprs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "colvalexprs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.passingvalexprs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableFunc", "passingvalexprs", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.plan.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("TableFunc", "plan", None), v.to_ref());
+            }
+        }
+        NodeRef::IntoClause(n) => {
+            if let Some(v) = &n.rel {
+                f(step("IntoClause", "rel", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.col_names.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IntoClause", "col_names", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IntoClause", "options", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.view_query.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("IntoClause", "view_query", None), v.to_ref());
+            }
+        }
+        NodeRef::Var(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Var", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::Param(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Param", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::Aggref(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Aggref", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.aggargtypes.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Aggref", "aggargtypes", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.aggdirectargs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Aggref", "aggdirectargs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Aggref", "args", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.aggorder.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Aggref", "aggorder", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.aggdistinct.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Aggref", "aggdistinct", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.aggfilter.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Aggref", "aggfilter", None), v.to_ref());
+            }
+        }
+        NodeRef::GroupingFunc(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("GroupingFunc", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GroupingFunc", "args", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.refs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GroupingFunc", "refs", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::WindowFunc(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WindowFunc", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("WindowFunc", "args", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.aggfilter.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WindowFunc", "aggfilter", None), v.to_ref());
+            }
+            for (i, v) in n.run_condition.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("WindowFunc", "run_condition", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::WindowFuncRunCondition(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WindowFuncRunCondition", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WindowFuncRunCondition", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::MergeSupportFunc(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("MergeSupportFunc", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::SubscriptingRef(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SubscriptingRef", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.refupperindexpr.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SubscriptingRef", "refupperindexpr", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.reflowerindexpr.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SubscriptingRef", "reflowerindexpr", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.refexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SubscriptingRef", "refexpr", None), v.to_ref());
+            }
+            if let Some(v) = n.refassgnexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SubscriptingRef", "refassgnexpr", None), v.to_ref());
+            }
+        }
+        NodeRef::FuncExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("FuncExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("FuncExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::NamedArgExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("NamedArgExpr", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("NamedArgExpr", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::OpExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("OpExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("OpExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DistinctExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("DistinctExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DistinctExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::NullIfExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("NullIfExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("NullIfExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ScalarArrayOpExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ScalarArrayOpExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ScalarArrayOpExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::BoolExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("BoolExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("BoolExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::SubLink(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SubLink", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.testexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SubLink", "testexpr", None), v.to_ref());
+            }
+            for (i, v) in n.oper_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SubLink", "oper_name", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.subselect.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SubLink", "subselect", None), v.to_ref());
+            }
+        }
+        NodeRef::SubPlan(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SubPlan", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.testexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SubPlan", "testexpr", None), v.to_ref());
+            }
+            for (i, v) in n.param_ids.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SubPlan", "param_ids", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.set_param.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SubPlan", "set_param", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.par_param.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SubPlan", "par_param", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SubPlan", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlternativeSubPlan(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlternativeSubPlan", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.subplans.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlternativeSubPlan", "subplans", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::FieldSelect(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("FieldSelect", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("FieldSelect", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::FieldStore(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("FieldStore", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("FieldStore", "arg", None), v.to_ref());
+            }
+            for (i, v) in n.newvals.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("FieldStore", "newvals", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.fieldnums.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("FieldStore", "fieldnums", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::RelabelType(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RelabelType", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RelabelType", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::CoerceViaIo(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CoerceViaIo", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CoerceViaIo", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::ArrayCoerceExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ArrayCoerceExpr", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ArrayCoerceExpr", "arg", None), v.to_ref());
+            }
+            if let Some(v) = n.elemexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ArrayCoerceExpr", "elemexpr", None), v.to_ref());
+            }
+        }
+        NodeRef::ConvertRowtypeExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ConvertRowtypeExpr", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ConvertRowtypeExpr", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::CollateExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CollateExpr", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CollateExpr", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::CaseExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CaseExpr", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CaseExpr", "arg", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CaseExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.defresult.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CaseExpr", "defresult", None), v.to_ref());
+            }
+        }
+        NodeRef::CaseWhen(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CaseWhen", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CaseWhen", "expr", None), v.to_ref());
+            }
+            if let Some(v) = n.result.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CaseWhen", "result", None), v.to_ref());
+            }
+        }
+        NodeRef::CaseTestExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CaseTestExpr", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::ArrayExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ArrayExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.elements.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ArrayExpr", "elements", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::RowExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RowExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RowExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.colnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RowExpr", "colnames", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::RowCompareExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RowCompareExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.opnos.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RowCompareExpr", "opnos", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.opfamilies.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RowCompareExpr", "opfamilies", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.inputcollids.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RowCompareExpr", "inputcollids", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.largs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RowCompareExpr", "largs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.rargs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RowCompareExpr", "rargs", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CoalesceExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CoalesceExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CoalesceExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::MinMaxExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("MinMaxExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("MinMaxExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::SqlvalueFunction(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SqlvalueFunction", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::XmlExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("XmlExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.named_args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("XmlExpr", "named_args", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.arg_names.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("XmlExpr", "arg_names", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("XmlExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::JsonFormat(_) => {}
+        NodeRef::JsonReturning(n) => {
+            if let Some(v) = &n.format {
+                f(step("JsonReturning", "format", None), NodeRef::JsonFormat(v));
+            }
+        }
+        NodeRef::JsonValueExpr(n) => {
+            if let Some(v) = n.raw_expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonValueExpr", "raw_expr", None), v.to_ref());
+            }
+            if let Some(v) = n.formatted_expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonValueExpr", "formatted_expr", None), v.to_ref());
+            }
+            if let Some(v) = &n.format {
+                f(step("JsonValueExpr", "format", None), NodeRef::JsonFormat(v));
+            }
+        }
+        NodeRef::JsonConstructorExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonConstructorExpr", "xpr", None), v.to_ref());
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonConstructorExpr", "args", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.func.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonConstructorExpr", "func", None), v.to_ref());
+            }
+            if let Some(v) = n.coercion.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonConstructorExpr", "coercion", None), v.to_ref());
+            }
+            if let Some(v) = &n.returning {
+                f(step("JsonConstructorExpr", "returning", None), NodeRef::JsonReturning(v));
+            }
+        }
+        NodeRef::JsonIsPredicate(n) => {
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonIsPredicate", "expr", None), v.to_ref());
+            }
+            if let Some(v) = &n.format {
+                f(step("JsonIsPredicate", "format", None), NodeRef::JsonFormat(v));
+            }
+        }
+        NodeRef::JsonBehavior(n) => {
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonBehavior", "expr", None), v.to_ref());
+            }
+        }
+        NodeRef::JsonExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonExpr", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.formatted_expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonExpr", "formatted_expr", None), v.to_ref());
+            }
+            if let Some(v) = &n.format {
+                f(step("JsonExpr", "format", None), NodeRef::JsonFormat(v));
+            }
+            if let Some(v) = n.path_spec.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonExpr", "path_spec", None), v.to_ref());
+            }
+            if let Some(v) = &n.returning {
+                f(step("JsonExpr", "returning", None), NodeRef::JsonReturning(v));
+            }
+            for (i, v) in n.passing_names.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonExpr", "passing_names", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.passing_values.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonExpr", "passing_values", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.on_empty {
+                f(step("JsonExpr", "on_empty", None), NodeRef::JsonBehavior(v));
+            }
+            if let Some(v) = &n.on_error {
+                f(step("JsonExpr", "on_error", None), NodeRef::JsonBehavior(v));
+            }
+        }
+        NodeRef::JsonTablePath(_) => {}
+        NodeRef::JsonTablePathScan(n) => {
+            if let Some(v) = n.plan.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonTablePathScan", "plan", None), v.to_ref());
+            }
+            if let Some(v) = &n.path {
+                f(step("JsonTablePathScan", "path", None), NodeRef::JsonTablePath(v));
+            }
+            if let Some(v) = n.child.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonTablePathScan", "child", None), v.to_ref());
+            }
+        }
+        NodeRef::JsonTableSiblingJoin(n) => {
+            if let Some(v) = n.plan.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonTableSiblingJoin", "plan", None), v.to_ref());
+            }
+            if let Some(v) = n.lplan.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonTableSiblingJoin", "lplan", None), v.to_ref());
+            }
+            if let Some(v) = n.rplan.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonTableSiblingJoin", "rplan", None), v.to_ref());
+            }
+        }
+        NodeRef::NullTest(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("NullTest", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("NullTest", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::BooleanTest(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("BooleanTest", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("BooleanTest", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::MergeAction(n) => {
+            if let Some(v) = n.qual.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("MergeAction", "qual", None), v.to_ref());
+            }
+            for (i, v) in n.target_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("MergeAction", "target_list", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.update_colnos.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("MergeAction", "update_colnos", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CoerceToDomain(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CoerceToDomain", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CoerceToDomain", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::CoerceToDomainValue(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CoerceToDomainValue", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::SetToDefault(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SetToDefault", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::CurrentOfExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CurrentOfExpr", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::NextValueExpr(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("NextValueExpr", "xpr", None), v.to_ref());
+            }
+        }
+        NodeRef::InferenceElem(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("InferenceElem", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("InferenceElem", "expr", None), v.to_ref());
+            }
+        }
+        NodeRef::TargetEntry(n) => {
+            if let Some(v) = n.xpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("TargetEntry", "xpr", None), v.to_ref());
+            }
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("TargetEntry", "expr", None), v.to_ref());
+            }
+        }
+        NodeRef::RangeTblRef(_) => {}
+        NodeRef::JoinExpr(n) => {
+            if let Some(v) = n.larg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JoinExpr", "larg", None), v.to_ref());
+            }
+            if let Some(v) = n.rarg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JoinExpr", "rarg", None), v.to_ref());
+            }
+            for (i, v) in n.using_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JoinExpr", "using_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.join_using_alias {
+                f(step("JoinExpr", "join_using_alias", None), NodeRef::Alias(v));
+            }
+            if let Some(v) = n.quals.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JoinExpr", "quals", None), v.to_ref());
+            }
+            if let Some(v) = &n.alias {
+                f(step("JoinExpr", "alias", None), NodeRef::Alias(v));
+            }
+        }
+        NodeRef::FromExpr(n) => {
+            for (i, v) in n.fromlist.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("FromExpr", "fromlist", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.quals.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("FromExpr", "quals", None), v.to_ref());
+            }
+        }
+        NodeRef::OnConflictExpr(n) => {
+            for (i, v) in n.arbiter_elems.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("OnConflictExpr", "arbiter_elems", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.arbiter_where.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("OnConflictExpr", "arbiter_where", None), v.to_ref());
+            }
+            for (i, v) in n.on_conflict_set.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("OnConflictExpr", "on_conflict_set", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.on_conflict_where.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("OnConflictExpr", "on_conflict_where", None), v.to_ref());
+            }
+            for (i, v) in n.excl_rel_tlist.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("OnConflictExpr", "excl_rel_tlist", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::Query(n) => {
+            if let Some(v) = n.utility_stmt.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Query", "utility_stmt", None), v.to_ref());
+            }
+            for (i, v) in n.cte_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "cte_list", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.rtable.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "rtable", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.rteperminfos.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "rteperminfos", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.jointree {
+                f(step("Query", "jointree", None), NodeRef::FromExpr(v));
+            }
+            for (i, v) in n.merge_action_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "merge_action_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.merge_join_condition.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Query", "merge_join_condition", None), v.to_ref());
+            }
+            for (i, v) in n.target_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "target_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.on_conflict {
+                f(step("Query", "on_conflict", None), NodeRef::OnConflictExpr(v));
+            }
+            for (i, v) in n.returning_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "returning_list", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.group_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "group_clause", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.grouping_sets.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "grouping_sets", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.having_qual.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Query", "having_qual", None), v.to_ref());
+            }
+            for (i, v) in n.window_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "window_clause", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.distinct_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "distinct_clause", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.sort_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "sort_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.limit_offset.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Query", "limit_offset", None), v.to_ref());
+            }
+            if let Some(v) = n.limit_count.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Query", "limit_count", None), v.to_ref());
+            }
+            for (i, v) in n.row_marks.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "row_marks", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.set_operations.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Query", "set_operations", None), v.to_ref());
+            }
+            for (i, v) in n.constraint_deps.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "constraint_deps", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.with_check_options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Query", "with_check_options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::TypeName(n) => {
+            for (i, v) in n.names.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TypeName", "names", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.typmods.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TypeName", "typmods", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.array_bounds.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TypeName", "array_bounds", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ColumnRef(n) => {
+            for (i, v) in n.fields.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ColumnRef", "fields", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ParamRef(_) => {}
+        NodeRef::AExpr(n) => {
+            for (i, v) in n.name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AExpr", "name", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.lexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AExpr", "lexpr", None), v.to_ref());
+            }
+            if let Some(v) = n.rexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AExpr", "rexpr", None), v.to_ref());
+            }
+        }
+        NodeRef::TypeCast(n) => {
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("TypeCast", "arg", None), v.to_ref());
+            }
+            if let Some(v) = &n.type_name {
+                f(step("TypeCast", "type_name", None), NodeRef::TypeName(v));
+            }
+        }
+        NodeRef::CollateClause(n) => {
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CollateClause", "arg", None), v.to_ref());
+            }
+            for (i, v) in n.collname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CollateClause", "collname", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::RoleSpec(_) => {}
+        NodeRef::FuncCall(n) => {
+            for (i, v) in n.funcname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("FuncCall", "funcname", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("FuncCall", "args", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.agg_order.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("FuncCall", "agg_order", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.agg_filter.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("FuncCall", "agg_filter", None), v.to_ref());
+            }
+            if let Some(v) = &n.over {
+                f(step("FuncCall", "over", None), NodeRef::WindowDef(v));
+            }
+        }
+        NodeRef::AStar(_) => {}
+        NodeRef::AIndices(n) => {
+            if let Some(v) = n.lidx.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AIndices", "lidx", None), v.to_ref());
+            }
+            if let Some(v) = n.uidx.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AIndices", "uidx", None), v.to_ref());
+            }
+        }
+        NodeRef::AIndirection(n) => {
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AIndirection", "arg", None), v.to_ref());
+            }
+            for (i, v) in n.indirection.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AIndirection", "indirection", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AArrayExpr(n) => {
+            for (i, v) in n.elements.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AArrayExpr", "elements", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ResTarget(n) => {
+            for (i, v) in n.indirection.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ResTarget", "indirection", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.val.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ResTarget", "val", None), v.to_ref());
+            }
+        }
+        NodeRef::MultiAssignRef(n) => {
+            if let Some(v) = n.source.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("MultiAssignRef", "source", None), v.to_ref());
+            }
+        }
+        NodeRef::SortBy(n) => {
+            if let Some(v) = n.node.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SortBy", "node", None), v.to_ref());
+            }
+            for (i, v) in n.use_op.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SortBy", "use_op", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::WindowDef(n) => {
+            for (i, v) in n.partition_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("WindowDef", "partition_clause", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.order_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("WindowDef", "order_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.start_offset.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WindowDef", "start_offset", None), v.to_ref());
+            }
+            if let Some(v) = n.end_offset.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WindowDef", "end_offset", None), v.to_ref());
+            }
+        }
+        NodeRef::RangeSubselect(n) => {
+            if let Some(v) = n.subquery.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RangeSubselect", "subquery", None), v.to_ref());
+            }
+            if let Some(v) = &n.alias {
+                f(step("RangeSubselect", "alias", None), NodeRef::Alias(v));
+            }
+        }
+        NodeRef::RangeFunction(n) => {
+            for (i, v) in n.functions.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeFunction", "functions", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.alias {
+                f(step("RangeFunction", "alias", None), NodeRef::Alias(v));
+            }
+            for (i, v) in n.coldeflist.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeFunction", "coldeflist", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::RangeTableFunc(n) => {
+            if let Some(v) = n.docexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RangeTableFunc", "docexpr", None), v.to_ref());
+            }
+            if let Some(v) = n.rowexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RangeTableFunc", "rowexpr", None), v.to_ref());
+            }
+            for (i, v) in n.namespaces.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTableFunc", "namespaces", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.columns.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTableFunc", "columns", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.alias {
+                f(step("RangeTableFunc", "alias", None), NodeRef::Alias(v));
+            }
+        }
+        NodeRef::RangeTableFuncCol(n) => {
+            if let Some(v) = &n.type_name {
+                f(step("RangeTableFuncCol", "type_name", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = n.colexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RangeTableFuncCol", "colexpr", None), v.to_ref());
+            }
+            if let Some(v) = n.coldefexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RangeTableFuncCol", "coldefexpr", None), v.to_ref());
+            }
+        }
+        NodeRef::RangeTableSample(n) => {
+            if let Some(v) = n.relation.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RangeTableSample", "relation", None), v.to_ref());
+            }
+            for (i, v) in n.method.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTableSample", "method", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTableSample", "args", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.repeatable.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RangeTableSample", "repeatable", None), v.to_ref());
+            }
+        }
+        NodeRef::ColumnDef(n) => {
+            if let Some(v) = &n.type_name {
+                f(step("ColumnDef", "type_name", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = n.raw_default.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ColumnDef", "raw_default", None), v.to_ref());
+            }
+            if let Some(v) = n.cooked_default.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ColumnDef", "cooked_default", None), v.to_ref());
+            }
+            if let Some(v) = &n.identity_sequence {
+                f(step("ColumnDef", "identity_sequence", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = &n.coll_clause {
+                f(step("ColumnDef", "coll_clause", None), NodeRef::CollateClause(v));
+            }
+            for (i, v) in n.constraints.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ColumnDef", "constraints", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.fdwoptions.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ColumnDef", "fdwoptions", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::TableLikeClause(n) => {
+            if let Some(v) = &n.relation {
+                f(step("TableLikeClause", "relation", None), NodeRef::RangeVar(v));
+            }
+        }
+        NodeRef::IndexElem(n) => {
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("IndexElem", "expr", None), v.to_ref());
+            }
+            for (i, v) in n.collation.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IndexElem", "collation", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.opclass.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IndexElem", "opclass", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.opclassopts.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IndexElem", "opclassopts", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DefElem(n) => {
+            if let Some(v) = n.arg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("DefElem", "arg", None), v.to_ref());
+            }
+        }
+        NodeRef::LockingClause(n) => {
+            for (i, v) in n.locked_rels.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("LockingClause", "locked_rels", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::XmlSerialize(n) => {
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("XmlSerialize", "expr", None), v.to_ref());
+            }
+            if let Some(v) = &n.type_name {
+                f(step("XmlSerialize", "type_name", None), NodeRef::TypeName(v));
+            }
+        }
+        NodeRef::PartitionElem(n) => {
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("PartitionElem", "expr", None), v.to_ref());
+            }
+            for (i, v) in n.collation.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PartitionElem", "collation", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.opclass.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PartitionElem", "opclass", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::PartitionSpec(n) => {
+            for (i, v) in n.part_params.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PartitionSpec", "part_params", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::PartitionBoundSpec(n) => {
+            for (i, v) in n.listdatums.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PartitionBoundSpec", "listdatums", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.lowerdatums.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PartitionBoundSpec", "lowerdatums", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.upperdatums.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PartitionBoundSpec", "upperdatums", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::PartitionRangeDatum(n) => {
+            if let Some(v) = n.value.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("PartitionRangeDatum", "value", None), v.to_ref());
+            }
+        }
+        NodeRef::SinglePartitionSpec(_) => {}
+        NodeRef::PartitionCmd(n) => {
+            if let Some(v) = &n.name {
+                f(step("PartitionCmd", "name", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = &n.bound {
+                f(step("PartitionCmd", "bound", None), NodeRef::PartitionBoundSpec(v));
+            }
+        }
+        NodeRef::RangeTblEntry(n) => {
+            if let Some(v) = &n.alias {
+                f(step("RangeTblEntry", "alias", None), NodeRef::Alias(v));
+            }
+            if let Some(v) = &n.eref {
+                f(step("RangeTblEntry", "eref", None), NodeRef::Alias(v));
+            }
+            if let Some(v) = &n.tablesample {
+                f(step("RangeTblEntry", "tablesample", None), NodeRef::TableSampleClause(v));
+            }
+            if let Some(v) = &n.subquery {
+                f(step("RangeTblEntry", "subquery", None), NodeRef::Query(v));
+            }
+            for (i, v) in n.joinaliasvars.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "joinaliasvars", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.joinleftcols.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "joinleftcols", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.joinrightcols.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "joinrightcols", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.join_using_alias {
+                f(step("RangeTblEntry", "join_using_alias", None), NodeRef::Alias(v));
+            }
+            for (i, v) in n.functions.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "functions", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.tablefunc {
+                f(step("RangeTblEntry", "tablefunc", None), NodeRef::TableFunc(v));
+            }
+            for (i, v) in n.values_lists.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "values_lists", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.coltypes.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "coltypes", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.coltypmods.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "coltypmods", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.colcollations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "colcollations", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.security_quals.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblEntry", "security_quals", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::RtepermissionInfo(_) => {}
+        NodeRef::RangeTblFunction(n) => {
+            if let Some(v) = n.funcexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RangeTblFunction", "funcexpr", None), v.to_ref());
+            }
+            for (i, v) in n.funccolnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblFunction", "funccolnames", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.funccoltypes.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblFunction", "funccoltypes", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.funccoltypmods.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblFunction", "funccoltypmods", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.funccolcollations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RangeTblFunction", "funccolcollations", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::TableSampleClause(n) => {
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TableSampleClause", "args", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.repeatable.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("TableSampleClause", "repeatable", None), v.to_ref());
+            }
+        }
+        NodeRef::WithCheckOption(n) => {
+            if let Some(v) = n.qual.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WithCheckOption", "qual", None), v.to_ref());
+            }
+        }
+        NodeRef::SortGroupClause(_) => {}
+        NodeRef::GroupingSet(n) => {
+            for (i, v) in n.content.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GroupingSet", "content", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::WindowClause(n) => {
+            for (i, v) in n.partition_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("WindowClause", "partition_clause", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.order_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("WindowClause", "order_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.start_offset.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WindowClause", "start_offset", None), v.to_ref());
+            }
+            if let Some(v) = n.end_offset.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("WindowClause", "end_offset", None), v.to_ref());
+            }
+        }
+        NodeRef::RowMarkClause(_) => {}
+        NodeRef::WithClause(n) => {
+            for (i, v) in n.ctes.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("WithClause", "ctes", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::InferClause(n) => {
+            for (i, v) in n.index_elems.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("InferClause", "index_elems", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("InferClause", "where_clause", None), v.to_ref());
+            }
+        }
+        NodeRef::OnConflictClause(n) => {
+            if let Some(v) = &n.infer {
+                f(step("OnConflictClause", "infer", None), NodeRef::InferClause(v));
+            }
+            for (i, v) in n.target_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("OnConflictClause", "target_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("OnConflictClause", "where_clause", None), v.to_ref());
+            }
+        }
+        NodeRef::CtesearchClause(n) => {
+            for (i, v) in n.search_col_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CtesearchClause", "search_col_list", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CtecycleClause(n) => {
+            for (i, v) in n.cycle_col_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CtecycleClause", "cycle_col_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.cycle_mark_value.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CtecycleClause", "cycle_mark_value", None), v.to_ref());
+            }
+            if let Some(v) = n.cycle_mark_default.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CtecycleClause", "cycle_mark_default", None), v.to_ref());
+            }
+        }
+        NodeRef::CommonTableExpr(n) => {
+            for (i, v) in n.aliascolnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CommonTableExpr", "aliascolnames", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.ctequery.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CommonTableExpr", "ctequery", None), v.to_ref());
+            }
+            if let Some(v) = &n.search_clause {
+                f(step("CommonTableExpr", "search_clause", None), NodeRef::CtesearchClause(v));
+            }
+            if let Some(v) = &n.cycle_clause {
+                f(step("CommonTableExpr", "cycle_clause", None), NodeRef::CtecycleClause(v));
+            }
+            for (i, v) in n.ctecolnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CommonTableExpr", "ctecolnames", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.ctecoltypes.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CommonTableExpr", "ctecoltypes", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.ctecoltypmods.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CommonTableExpr", "ctecoltypmods", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.ctecolcollations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CommonTableExpr", "ctecolcollations", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::MergeWhenClause(n) => {
+            if let Some(v) = n.condition.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("MergeWhenClause", "condition", None), v.to_ref());
+            }
+            for (i, v) in n.target_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("MergeWhenClause", "target_list", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.values.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("MergeWhenClause", "values", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::TriggerTransition(_) => {}
+        NodeRef::JsonOutput(n) => {
+            if let Some(v) = &n.type_name {
+                f(step("JsonOutput", "type_name", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = &n.returning {
+                f(step("JsonOutput", "returning", None), NodeRef::JsonReturning(v));
+            }
+        }
+        NodeRef::JsonArgument(n) => {
+            if let Some(v) = &n.val {
+                f(step("JsonArgument", "val", None), NodeRef::JsonValueExpr(v));
+            }
+        }
+        NodeRef::JsonFuncExpr(n) => {
+            if let Some(v) = &n.context_item {
+                f(step("JsonFuncExpr", "context_item", None), NodeRef::JsonValueExpr(v));
+            }
+            if let Some(v) = n.pathspec.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonFuncExpr", "pathspec", None), v.to_ref());
+            }
+            for (i, v) in n.passing.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonFuncExpr", "passing", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.output {
+                f(step("JsonFuncExpr", "output", None), NodeRef::JsonOutput(v));
+            }
+            if let Some(v) = &n.on_empty {
+                f(step("JsonFuncExpr", "on_empty", None), NodeRef::JsonBehavior(v));
+            }
+            if let Some(v) = &n.on_error {
+                f(step("JsonFuncExpr", "on_error", None), NodeRef::JsonBehavior(v));
+            }
+        }
+        NodeRef::JsonTablePathSpec(n) => {
+            if let Some(v) = n.string.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonTablePathSpec", "string", None), v.to_ref());
+            }
+        }
+        NodeRef::JsonTable(n) => {
+            if let Some(v) = &n.context_item {
+                f(step("JsonTable", "context_item", None), NodeRef::JsonValueExpr(v));
+            }
+            if let Some(v) = &n.pathspec {
+                f(step("JsonTable", "pathspec", None), NodeRef::JsonTablePathSpec(v));
+            }
+            for (i, v) in n.passing.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonTable", "passing", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.columns.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonTable", "columns", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.on_error {
+                f(step("JsonTable", "on_error", None), NodeRef::JsonBehavior(v));
+            }
+            if let Some(v) = &n.alias {
+                f(step("JsonTable", "alias", None), NodeRef::Alias(v));
+            }
+        }
+        NodeRef::JsonTableColumn(n) => {
+            if let Some(v) = &n.type_name {
+                f(step("JsonTableColumn", "type_name", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = &n.pathspec {
+                f(step("JsonTableColumn", "pathspec", None), NodeRef::JsonTablePathSpec(v));
+            }
+            if let Some(v) = &n.format {
+                f(step("JsonTableColumn", "format", None), NodeRef::JsonFormat(v));
+            }
+            for (i, v) in n.columns.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonTableColumn", "columns", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.on_empty {
+                f(step("JsonTableColumn", "on_empty", None), NodeRef::JsonBehavior(v));
+            }
+            if let Some(v) = &n.on_error {
+                f(step("JsonTableColumn", "on_error", None), NodeRef::JsonBehavior(v));
+            }
+        }
+        NodeRef::JsonKeyValue(n) => {
+            if let Some(v) = n.key.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonKeyValue", "key", None), v.to_ref());
+            }
+            if let Some(v) = &n.value {
+                f(step("JsonKeyValue", "value", None), NodeRef::JsonValueExpr(v));
+            }
+        }
+        NodeRef::JsonParseExpr(n) => {
+            if let Some(v) = &n.expr {
+                f(step("JsonParseExpr", "expr", None), NodeRef::JsonValueExpr(v));
+            }
+            if let Some(v) = &n.output {
+                f(step("JsonParseExpr", "output", None), NodeRef::JsonOutput(v));
+            }
+        }
+        NodeRef::JsonScalarExpr(n) => {
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonScalarExpr", "expr", None), v.to_ref());
+            }
+            if let Some(v) = &n.output {
+                f(step("JsonScalarExpr", "output", None), NodeRef::JsonOutput(v));
+            }
+        }
+        NodeRef::JsonSerializeExpr(n) => {
+            if let Some(v) = &n.expr {
+                f(step("JsonSerializeExpr", "expr", None), NodeRef::JsonValueExpr(v));
+            }
+            if let Some(v) = &n.output {
+                f(step("JsonSerializeExpr", "output", None), NodeRef::JsonOutput(v));
+            }
+        }
+        NodeRef::JsonObjectConstructor(n) => {
+            for (i, v) in n.exprs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonObjectConstructor", "exprs", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.output {
+                f(step("JsonObjectConstructor", "output", None), NodeRef::JsonOutput(v));
+            }
+        }
+        NodeRef::JsonArrayConstructor(n) => {
+            for (i, v) in n.exprs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonArrayConstructor", "exprs", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.output {
+                f(step("JsonArrayConstructor", "output", None), NodeRef::JsonOutput(v));
+            }
+        }
+        NodeRef::JsonArrayQueryConstructor(n) => {
+            if let Some(v) = n.query.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonArrayQueryConstructor", "query", None), v.to_ref());
+            }
+            if let Some(v) = &n.output {
+                f(step("JsonArrayQueryConstructor", "output", None), NodeRef::JsonOutput(v));
+            }
+            if let Some(v) = &n.format {
+                f(step("JsonArrayQueryConstructor", "format", None), NodeRef::JsonFormat(v));
+            }
+        }
+        NodeRef::JsonAggConstructor(n) => {
+            if let Some(v) = &n.output {
+                f(step("JsonAggConstructor", "output", None), NodeRef::JsonOutput(v));
+            }
+            if let Some(v) = n.agg_filter.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("JsonAggConstructor", "agg_filter", None), v.to_ref());
+            }
+            for (i, v) in n.agg_order.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("JsonAggConstructor", "agg_order", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.over {
+                f(step("JsonAggConstructor", "over", None), NodeRef::WindowDef(v));
+            }
+        }
+        NodeRef::JsonObjectAgg(n) => {
+            if let Some(v) = &n.constructor {
+                f(step("JsonObjectAgg", "constructor", None), NodeRef::JsonAggConstructor(v));
+            }
+            if let Some(v) = &n.arg {
+                f(step("JsonObjectAgg", "arg", None), NodeRef::JsonKeyValue(v));
+            }
+        }
+        NodeRef::JsonArrayAgg(n) => {
+            if let Some(v) = &n.constructor {
+                f(step("JsonArrayAgg", "constructor", None), NodeRef::JsonAggConstructor(v));
+            }
+            if let Some(v) = &n.arg {
+                f(step("JsonArrayAgg", "arg", None), NodeRef::JsonValueExpr(v));
+            }
+        }
+        NodeRef::RawStmt(n) => {
+            if let Some(v) = n.stmt.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RawStmt", "stmt", None), v.to_ref());
+            }
+        }
+        NodeRef::InsertStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("InsertStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.cols.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("InsertStmt", "cols", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.select_stmt.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("InsertStmt", "select_stmt", None), v.to_ref());
+            }
+            if let Some(v) = &n.on_conflict_clause {
+                f(step("InsertStmt", "on_conflict_clause", None), NodeRef::OnConflictClause(v));
+            }
+            for (i, v) in n.returning_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("InsertStmt", "returning_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.with_clause {
+                f(step("InsertStmt", "with_clause", None), NodeRef::WithClause(v));
+            }
+        }
+        NodeRef::DeleteStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("DeleteStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.using_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DeleteStmt", "using_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("DeleteStmt", "where_clause", None), v.to_ref());
+            }
+            for (i, v) in n.returning_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DeleteStmt", "returning_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.with_clause {
+                f(step("DeleteStmt", "with_clause", None), NodeRef::WithClause(v));
+            }
+        }
+        NodeRef::UpdateStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("UpdateStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.target_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("UpdateStmt", "target_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("UpdateStmt", "where_clause", None), v.to_ref());
+            }
+            for (i, v) in n.from_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("UpdateStmt", "from_clause", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.returning_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("UpdateStmt", "returning_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.with_clause {
+                f(step("UpdateStmt", "with_clause", None), NodeRef::WithClause(v));
+            }
+        }
+        NodeRef::MergeStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("MergeStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = n.source_relation.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("MergeStmt", "source_relation", None), v.to_ref());
+            }
+            if let Some(v) = n.join_condition.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("MergeStmt", "join_condition", None), v.to_ref());
+            }
+            for (i, v) in n.merge_when_clauses.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("MergeStmt", "merge_when_clauses", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.returning_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("MergeStmt", "returning_list", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.with_clause {
+                f(step("MergeStmt", "with_clause", None), NodeRef::WithClause(v));
+            }
+        }
+        NodeRef::SelectStmt(n) => {
+            for (i, v) in n.distinct_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SelectStmt", "distinct_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.into_clause {
+                f(step("SelectStmt", "into_clause", None), NodeRef::IntoClause(v));
+            }
+            for (i, v) in n.target_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SelectStmt", "target_list", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.from_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SelectStmt", "from_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SelectStmt", "where_clause", None), v.to_ref());
+            }
+            for (i, v) in n.group_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SelectStmt", "group_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.having_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SelectStmt", "having_clause", None), v.to_ref());
+            }
+            for (i, v) in n.window_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SelectStmt", "window_clause", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.values_lists.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SelectStmt", "values_lists", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.sort_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SelectStmt", "sort_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.limit_offset.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SelectStmt", "limit_offset", None), v.to_ref());
+            }
+            if let Some(v) = n.limit_count.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SelectStmt", "limit_count", None), v.to_ref());
+            }
+            for (i, v) in n.locking_clause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SelectStmt", "locking_clause", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.with_clause {
+                f(step("SelectStmt", "with_clause", None), NodeRef::WithClause(v));
+            }
+            if let Some(v) = &n.larg {
+                f(step("SelectStmt", "larg", None), NodeRef::SelectStmt(v));
+            }
+            if let Some(v) = &n.rarg {
+                f(step("SelectStmt", "rarg", None), NodeRef::SelectStmt(v));
+            }
+        }
+        NodeRef::SetOperationStmt(n) => {
+            if let Some(v) = n.larg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SetOperationStmt", "larg", None), v.to_ref());
+            }
+            if let Some(v) = n.rarg.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SetOperationStmt", "rarg", None), v.to_ref());
+            }
+            for (i, v) in n.col_types.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SetOperationStmt", "col_types", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.col_typmods.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SetOperationStmt", "col_typmods", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.col_collations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SetOperationStmt", "col_collations", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.group_clauses.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("SetOperationStmt", "group_clauses", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ReturnStmt(n) => {
+            if let Some(v) = n.returnval.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ReturnStmt", "returnval", None), v.to_ref());
+            }
+        }
+        NodeRef::PlassignStmt(n) => {
+            for (i, v) in n.indirection.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PlassignStmt", "indirection", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.val {
+                f(step("PlassignStmt", "val", None), NodeRef::SelectStmt(v));
+            }
+        }
+        NodeRef::CreateSchemaStmt(n) => {
+            if let Some(v) = &n.authrole {
+                f(step("CreateSchemaStmt", "authrole", None), NodeRef::RoleSpec(v));
+            }
+            for (i, v) in n.schema_elts.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateSchemaStmt", "schema_elts", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterTableStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("AlterTableStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.cmds.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTableStmt", "cmds", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ReplicaIdentityStmt(_) => {}
+        NodeRef::AlterTableCmd(n) => {
+            if let Some(v) = &n.newowner {
+                f(step("AlterTableCmd", "newowner", None), NodeRef::RoleSpec(v));
+            }
+            if let Some(v) = n.def.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterTableCmd", "def", None), v.to_ref());
+            }
+        }
+        NodeRef::AlterCollationStmt(n) => {
+            for (i, v) in n.collname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterCollationStmt", "collname", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterDomainStmt(n) => {
+            for (i, v) in n.type_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterDomainStmt", "type_name", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.def.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterDomainStmt", "def", None), v.to_ref());
+            }
+        }
+        NodeRef::GrantStmt(n) => {
+            for (i, v) in n.objects.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GrantStmt", "objects", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.privileges.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GrantStmt", "privileges", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.grantees.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GrantStmt", "grantees", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.grantor {
+                f(step("GrantStmt", "grantor", None), NodeRef::RoleSpec(v));
+            }
+        }
+        NodeRef::ObjectWithArgs(n) => {
+            for (i, v) in n.objname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ObjectWithArgs", "objname", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.objargs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ObjectWithArgs", "objargs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.objfuncargs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ObjectWithArgs", "objfuncargs", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AccessPriv(n) => {
+            for (i, v) in n.cols.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AccessPriv", "cols", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::GrantRoleStmt(n) => {
+            for (i, v) in n.granted_roles.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GrantRoleStmt", "granted_roles", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.grantee_roles.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GrantRoleStmt", "grantee_roles", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.opt.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("GrantRoleStmt", "opt", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.grantor {
+                f(step("GrantRoleStmt", "grantor", None), NodeRef::RoleSpec(v));
+            }
+        }
+        NodeRef::AlterDefaultPrivilegesStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterDefaultPrivilegesStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.action {
+                f(step("AlterDefaultPrivilegesStmt", "action", None), NodeRef::GrantStmt(v));
+            }
+        }
+        NodeRef::CopyStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("CopyStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = n.query.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CopyStmt", "query", None), v.to_ref());
+            }
+            for (i, v) in n.attlist.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CopyStmt", "attlist", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CopyStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CopyStmt", "where_clause", None), v.to_ref());
+            }
+        }
+        NodeRef::VariableSetStmt(n) => {
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("VariableSetStmt", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::VariableShowStmt(_) => {}
+        NodeRef::CreateStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("CreateStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.table_elts.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateStmt", "table_elts", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.inh_relations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateStmt", "inh_relations", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.partbound {
+                f(step("CreateStmt", "partbound", None), NodeRef::PartitionBoundSpec(v));
+            }
+            if let Some(v) = &n.partspec {
+                f(step("CreateStmt", "partspec", None), NodeRef::PartitionSpec(v));
+            }
+            if let Some(v) = &n.of_typename {
+                f(step("CreateStmt", "of_typename", None), NodeRef::TypeName(v));
+            }
+            for (i, v) in n.constraints.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateStmt", "constraints", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::Constraint(n) => {
+            if let Some(v) = n.raw_expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Constraint", "raw_expr", None), v.to_ref());
+            }
+            for (i, v) in n.keys.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Constraint", "keys", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.including.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Constraint", "including", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.exclusions.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Constraint", "exclusions", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Constraint", "options", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("Constraint", "where_clause", None), v.to_ref());
+            }
+            if let Some(v) = &n.pktable {
+                f(step("Constraint", "pktable", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.fk_attrs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Constraint", "fk_attrs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.pk_attrs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Constraint", "pk_attrs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.fk_del_set_cols.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Constraint", "fk_del_set_cols", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.old_conpfeqop.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("Constraint", "old_conpfeqop", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateTableSpaceStmt(n) => {
+            if let Some(v) = &n.owner {
+                f(step("CreateTableSpaceStmt", "owner", None), NodeRef::RoleSpec(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateTableSpaceStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DropTableSpaceStmt(_) => {}
+        NodeRef::AlterTableSpaceOptionsStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTableSpaceOptionsStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterTableMoveAllStmt(n) => {
+            for (i, v) in n.roles.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTableMoveAllStmt", "roles", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateExtensionStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateExtensionStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterExtensionStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterExtensionStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterExtensionContentsStmt(n) => {
+            if let Some(v) = n.object.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterExtensionContentsStmt", "object", None), v.to_ref());
+            }
+        }
+        NodeRef::CreateFdwStmt(n) => {
+            for (i, v) in n.func_options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateFdwStmt", "func_options", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateFdwStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterFdwStmt(n) => {
+            for (i, v) in n.func_options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterFdwStmt", "func_options", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterFdwStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateForeignServerStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateForeignServerStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterForeignServerStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterForeignServerStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateForeignTableStmt(n) => {
+            if let Some(v) = &n.base_stmt {
+                f(step("CreateForeignTableStmt", "base_stmt", None), NodeRef::CreateStmt(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateForeignTableStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateUserMappingStmt(n) => {
+            if let Some(v) = &n.user {
+                f(step("CreateUserMappingStmt", "user", None), NodeRef::RoleSpec(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateUserMappingStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterUserMappingStmt(n) => {
+            if let Some(v) = &n.user {
+                f(step("AlterUserMappingStmt", "user", None), NodeRef::RoleSpec(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterUserMappingStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DropUserMappingStmt(n) => {
+            if let Some(v) = &n.user {
+                f(step("DropUserMappingStmt", "user", None), NodeRef::RoleSpec(v));
+            }
+        }
+        NodeRef::ImportForeignSchemaStmt(n) => {
+            for (i, v) in n.table_list.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ImportForeignSchemaStmt", "table_list", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ImportForeignSchemaStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreatePolicyStmt(n) => {
+            if let Some(v) = &n.table {
+                f(step("CreatePolicyStmt", "table", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.roles.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreatePolicyStmt", "roles", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.qual.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CreatePolicyStmt", "qual", None), v.to_ref());
+            }
+            if let Some(v) = n.with_check.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CreatePolicyStmt", "with_check", None), v.to_ref());
+            }
+        }
+        NodeRef::AlterPolicyStmt(n) => {
+            if let Some(v) = &n.table {
+                f(step("AlterPolicyStmt", "table", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.roles.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterPolicyStmt", "roles", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.qual.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterPolicyStmt", "qual", None), v.to_ref());
+            }
+            if let Some(v) = n.with_check.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterPolicyStmt", "with_check", None), v.to_ref());
+            }
+        }
+        NodeRef::CreateAmStmt(n) => {
+            for (i, v) in n.handler_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateAmStmt", "handler_name", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateTrigStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("CreateTrigStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.funcname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateTrigStmt", "funcname", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateTrigStmt", "args", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.columns.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateTrigStmt", "columns", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.when_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CreateTrigStmt", "when_clause", None), v.to_ref());
+            }
+            for (i, v) in n.transition_rels.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateTrigStmt", "transition_rels", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.constrrel {
+                f(step("CreateTrigStmt", "constrrel", None), NodeRef::RangeVar(v));
+            }
+        }
+        NodeRef::CreateEventTrigStmt(n) => {
+            for (i, v) in n.whenclause.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateEventTrigStmt", "whenclause", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.funcname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateEventTrigStmt", "funcname", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterEventTrigStmt(_) => {}
+        NodeRef::CreatePlangStmt(n) => {
+            for (i, v) in n.plhandler.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreatePlangStmt", "plhandler", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.plinline.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreatePlangStmt", "plinline", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.plvalidator.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreatePlangStmt", "plvalidator", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateRoleStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateRoleStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterRoleStmt(n) => {
+            if let Some(v) = &n.role {
+                f(step("AlterRoleStmt", "role", None), NodeRef::RoleSpec(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterRoleStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterRoleSetStmt(n) => {
+            if let Some(v) = &n.role {
+                f(step("AlterRoleSetStmt", "role", None), NodeRef::RoleSpec(v));
+            }
+            if let Some(v) = &n.setstmt {
+                f(step("AlterRoleSetStmt", "setstmt", None), NodeRef::VariableSetStmt(v));
+            }
+        }
+        NodeRef::DropRoleStmt(n) => {
+            for (i, v) in n.roles.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DropRoleStmt", "roles", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateSeqStmt(n) => {
+            if let Some(v) = &n.sequence {
+                f(step("CreateSeqStmt", "sequence", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateSeqStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterSeqStmt(n) => {
+            if let Some(v) = &n.sequence {
+                f(step("AlterSeqStmt", "sequence", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterSeqStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DefineStmt(n) => {
+            for (i, v) in n.defnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DefineStmt", "defnames", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DefineStmt", "args", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.definition.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DefineStmt", "definition", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateDomainStmt(n) => {
+            for (i, v) in n.domainname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateDomainStmt", "domainname", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.type_name {
+                f(step("CreateDomainStmt", "type_name", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = &n.coll_clause {
+                f(step("CreateDomainStmt", "coll_clause", None), NodeRef::CollateClause(v));
+            }
+            for (i, v) in n.constraints.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateDomainStmt", "constraints", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateOpClassStmt(n) => {
+            for (i, v) in n.opclassname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateOpClassStmt", "opclassname", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.opfamilyname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateOpClassStmt", "opfamilyname", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.datatype {
+                f(step("CreateOpClassStmt", "datatype", None), NodeRef::TypeName(v));
+            }
+            for (i, v) in n.items.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateOpClassStmt", "items", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateOpClassItem(n) => {
+            if let Some(v) = &n.name {
+                f(step("CreateOpClassItem", "name", None), NodeRef::ObjectWithArgs(v));
+            }
+            for (i, v) in n.order_family.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateOpClassItem", "order_family", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.class_args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateOpClassItem", "class_args", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.storedtype {
+                f(step("CreateOpClassItem", "storedtype", None), NodeRef::TypeName(v));
+            }
+        }
+        NodeRef::CreateOpFamilyStmt(n) => {
+            for (i, v) in n.opfamilyname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateOpFamilyStmt", "opfamilyname", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterOpFamilyStmt(n) => {
+            for (i, v) in n.opfamilyname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterOpFamilyStmt", "opfamilyname", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.items.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterOpFamilyStmt", "items", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DropStmt(n) => {
+            for (i, v) in n.objects.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DropStmt", "objects", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::TruncateStmt(n) => {
+            for (i, v) in n.relations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TruncateStmt", "relations", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CommentStmt(n) => {
+            if let Some(v) = n.object.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CommentStmt", "object", None), v.to_ref());
+            }
+        }
+        NodeRef::SecLabelStmt(n) => {
+            if let Some(v) = n.object.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("SecLabelStmt", "object", None), v.to_ref());
+            }
+        }
+        NodeRef::DeclareCursorStmt(n) => {
+            if let Some(v) = n.query.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("DeclareCursorStmt", "query", None), v.to_ref());
+            }
+        }
+        NodeRef::ClosePortalStmt(_) => {}
+        NodeRef::FetchStmt(_) => {}
+        NodeRef::IndexStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("IndexStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.index_params.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IndexStmt", "index_params", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.index_including_params.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IndexStmt", "index_including_params", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IndexStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("IndexStmt", "where_clause", None), v.to_ref());
+            }
+            for (i, v) in n.exclude_op_names.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IndexStmt", "exclude_op_names", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateStatsStmt(n) => {
+            for (i, v) in n.defnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateStatsStmt", "defnames", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.stat_types.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateStatsStmt", "stat_types", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.exprs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateStatsStmt", "exprs", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.relations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateStatsStmt", "relations", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::StatsElem(n) => {
+            if let Some(v) = n.expr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("StatsElem", "expr", None), v.to_ref());
+            }
+        }
+        NodeRef::AlterStatsStmt(n) => {
+            for (i, v) in n.defnames.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterStatsStmt", "defnames", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.stxstattarget.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterStatsStmt", "stxstattarget", None), v.to_ref());
+            }
+        }
+        NodeRef::CreateFunctionStmt(n) => {
+            for (i, v) in n.funcname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateFunctionStmt", "funcname", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.parameters.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateFunctionStmt", "parameters", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.return_type {
+                f(step("CreateFunctionStmt", "return_type", None), NodeRef::TypeName(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateFunctionStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.sql_body.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CreateFunctionStmt", "sql_body", None), v.to_ref());
+            }
+        }
+        NodeRef::FunctionParameter(n) => {
+            if let Some(v) = &n.arg_type {
+                f(step("FunctionParameter", "arg_type", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = n.defexpr.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("FunctionParameter", "defexpr", None), v.to_ref());
+            }
+        }
+        NodeRef::AlterFunctionStmt(n) => {
+            if let Some(v) = &n.func {
+                f(step("AlterFunctionStmt", "func", None), NodeRef::ObjectWithArgs(v));
+            }
+            for (i, v) in n.actions.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterFunctionStmt", "actions", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DoStmt(n) => {
+            for (i, v) in n.args.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DoStmt", "args", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::InlineCodeBlock(_) => {}
+        NodeRef::CallStmt(n) => {
+            if let Some(v) = &n.funccall {
+                f(step("CallStmt", "funccall", None), NodeRef::FuncCall(v));
+            }
+            if let Some(v) = &n.funcexpr {
+                f(step("CallStmt", "funcexpr", None), NodeRef::FuncExpr(v));
+            }
+            for (i, v) in n.outargs.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CallStmt", "outargs", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CallContext(_) => {}
+        NodeRef::RenameStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("RenameStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = n.object.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RenameStmt", "object", None), v.to_ref());
+            }
+        }
+        NodeRef::AlterObjectDependsStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("AlterObjectDependsStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = n.object.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterObjectDependsStmt", "object", None), v.to_ref());
+            }
+            if let Some(v) = &n.extname {
+                f(step("AlterObjectDependsStmt", "extname", None), NodeRef::String(v));
+            }
+        }
+        NodeRef::AlterObjectSchemaStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("AlterObjectSchemaStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = n.object.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterObjectSchemaStmt", "object", None), v.to_ref());
+            }
+        }
+        NodeRef::AlterOwnerStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("AlterOwnerStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = n.object.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("AlterOwnerStmt", "object", None), v.to_ref());
+            }
+            if let Some(v) = &n.newowner {
+                f(step("AlterOwnerStmt", "newowner", None), NodeRef::RoleSpec(v));
+            }
+        }
+        NodeRef::AlterOperatorStmt(n) => {
+            if let Some(v) = &n.opername {
+                f(step("AlterOperatorStmt", "opername", None), NodeRef::ObjectWithArgs(v));
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterOperatorStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterTypeStmt(n) => {
+            for (i, v) in n.type_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTypeStmt", "type_name", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTypeStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::RuleStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("RuleStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("RuleStmt", "where_clause", None), v.to_ref());
+            }
+            for (i, v) in n.actions.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("RuleStmt", "actions", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::NotifyStmt(_) => {}
+        NodeRef::ListenStmt(_) => {}
+        NodeRef::UnlistenStmt(_) => {}
+        NodeRef::TransactionStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("TransactionStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CompositeTypeStmt(n) => {
+            if let Some(v) = &n.typevar {
+                f(step("CompositeTypeStmt", "typevar", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.coldeflist.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CompositeTypeStmt", "coldeflist", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateEnumStmt(n) => {
+            for (i, v) in n.type_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateEnumStmt", "type_name", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.vals.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateEnumStmt", "vals", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateRangeStmt(n) => {
+            for (i, v) in n.type_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateRangeStmt", "type_name", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.params.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateRangeStmt", "params", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterEnumStmt(n) => {
+            for (i, v) in n.type_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterEnumStmt", "type_name", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ViewStmt(n) => {
+            if let Some(v) = &n.view {
+                f(step("ViewStmt", "view", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.aliases.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ViewStmt", "aliases", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.query.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ViewStmt", "query", None), v.to_ref());
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ViewStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::LoadStmt(_) => {}
+        NodeRef::CreatedbStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreatedbStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterDatabaseStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterDatabaseStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterDatabaseRefreshCollStmt(_) => {}
+        NodeRef::AlterDatabaseSetStmt(n) => {
+            if let Some(v) = &n.setstmt {
+                f(step("AlterDatabaseSetStmt", "setstmt", None), NodeRef::VariableSetStmt(v));
+            }
+        }
+        NodeRef::DropdbStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DropdbStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterSystemStmt(n) => {
+            if let Some(v) = &n.setstmt {
+                f(step("AlterSystemStmt", "setstmt", None), NodeRef::VariableSetStmt(v));
+            }
+        }
+        NodeRef::ClusterStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("ClusterStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.params.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ClusterStmt", "params", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::VacuumStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("VacuumStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.rels.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("VacuumStmt", "rels", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::VacuumRelation(n) => {
+            if let Some(v) = &n.relation {
+                f(step("VacuumRelation", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.va_cols.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("VacuumRelation", "va_cols", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ExplainStmt(n) => {
+            if let Some(v) = n.query.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("ExplainStmt", "query", None), v.to_ref());
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ExplainStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateTableAsStmt(n) => {
+            if let Some(v) = n.query.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("CreateTableAsStmt", "query", None), v.to_ref());
+            }
+            if let Some(v) = &n.into {
+                f(step("CreateTableAsStmt", "into", None), NodeRef::IntoClause(v));
+            }
+        }
+        NodeRef::RefreshMatViewStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("RefreshMatViewStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+        }
+        NodeRef::CheckPointStmt(_) => {}
+        NodeRef::DiscardStmt(_) => {}
+        NodeRef::LockStmt(n) => {
+            for (i, v) in n.relations.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("LockStmt", "relations", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ConstraintsSetStmt(n) => {
+            for (i, v) in n.constraints.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ConstraintsSetStmt", "constraints", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ReindexStmt(n) => {
+            if let Some(v) = &n.relation {
+                f(step("ReindexStmt", "relation", None), NodeRef::RangeVar(v));
+            }
+            for (i, v) in n.params.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ReindexStmt", "params", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateConversionStmt(n) => {
+            for (i, v) in n.conversion_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateConversionStmt", "conversion_name", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.func_name.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateConversionStmt", "func_name", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateCastStmt(n) => {
+            if let Some(v) = &n.sourcetype {
+                f(step("CreateCastStmt", "sourcetype", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = &n.targettype {
+                f(step("CreateCastStmt", "targettype", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = &n.func {
+                f(step("CreateCastStmt", "func", None), NodeRef::ObjectWithArgs(v));
+            }
+        }
+        NodeRef::CreateTransformStmt(n) => {
+            if let Some(v) = &n.type_name {
+                f(step("CreateTransformStmt", "type_name", None), NodeRef::TypeName(v));
+            }
+            if let Some(v) = &n.fromsql {
+                f(step("CreateTransformStmt", "fromsql", None), NodeRef::ObjectWithArgs(v));
+            }
+            if let Some(v) = &n.tosql {
+                f(step("CreateTransformStmt", "tosql", None), NodeRef::ObjectWithArgs(v));
+            }
+        }
+        NodeRef::PrepareStmt(n) => {
+            for (i, v) in n.argtypes.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PrepareStmt", "argtypes", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = n.query.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("PrepareStmt", "query", None), v.to_ref());
+            }
+        }
+        NodeRef::ExecuteStmt(n) => {
+            for (i, v) in n.params.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ExecuteStmt", "params", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DeallocateStmt(_) => {}
+        NodeRef::DropOwnedStmt(n) => {
+            for (i, v) in n.roles.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("DropOwnedStmt", "roles", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::ReassignOwnedStmt(n) => {
+            for (i, v) in n.roles.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("ReassignOwnedStmt", "roles", Some(i)), v.to_ref());
+                }
+            }
+            if let Some(v) = &n.newrole {
+                f(step("ReassignOwnedStmt", "newrole", None), NodeRef::RoleSpec(v));
+            }
+        }
+        NodeRef::AlterTsdictionaryStmt(n) => {
+            for (i, v) in n.dictname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTsdictionaryStmt", "dictname", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTsdictionaryStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterTsconfigurationStmt(n) => {
+            for (i, v) in n.cfgname.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTsconfigurationStmt", "cfgname", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.tokentype.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTsconfigurationStmt", "tokentype", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.dicts.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterTsconfigurationStmt", "dicts", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::PublicationTable(n) => {
+            if let Some(v) = &n.relation {
+                f(step("PublicationTable", "relation", None), NodeRef::RangeVar(v));
+            }
+            if let Some(v) = n.where_clause.as_ref().and_then(|v| v.node.as_ref()) {
+                f(step("PublicationTable", "where_clause", None), v.to_ref());
+            }
+            for (i, v) in n.columns.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("PublicationTable", "columns", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::PublicationObjSpec(n) => {
+            if let Some(v) = &n.pubtable {
+                f(step("PublicationObjSpec", "pubtable", None), NodeRef::PublicationTable(v));
+            }
+        }
+        NodeRef::CreatePublicationStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreatePublicationStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.pubobjects.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreatePublicationStmt", "pubobjects", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterPublicationStmt(n) => {
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterPublicationStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.pubobjects.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterPublicationStmt", "pubobjects", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::CreateSubscriptionStmt(n) => {
+            for (i, v) in n.publication.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateSubscriptionStmt", "publication", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("CreateSubscriptionStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AlterSubscriptionStmt(n) => {
+            for (i, v) in n.publication.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterSubscriptionStmt", "publication", Some(i)), v.to_ref());
+                }
+            }
+            for (i, v) in n.options.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("AlterSubscriptionStmt", "options", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::DropSubscriptionStmt(_) => {}
+        NodeRef::Integer(_) => {}
+        NodeRef::Float(_) => {}
+        NodeRef::Boolean(_) => {}
+        NodeRef::String(_) => {}
+        NodeRef::BitString(_) => {}
+        NodeRef::List(n) => {
+            for (i, v) in n.items.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("List", "items", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::IntList(n) => {
+            for (i, v) in n.items.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("IntList", "items", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::OidList(n) => {
+            for (i, v) in n.items.iter().enumerate() {
+                if let Some(v) = &v.node {
+                    f(step("OidList", "items", Some(i)), v.to_ref());
+                }
+            }
+        }
+        NodeRef::AConst(n) => match &n.val {
+            Some(a_const::Val::Ival(v)) => f(step("AConst", "val", None), NodeRef::Integer(v)),
+            Some(a_const::Val::Fval(v)) => f(step("AConst", "val", None), NodeRef::Float(v)),
+            Some(a_const::Val::Boolval(v)) => f(step("AConst", "val", None), NodeRef::Boolean(v)),
+            Some(a_const::Val::Sval(v)) => f(step("AConst", "val", None), NodeRef::String(v)),
+            Some(a_const::Val::Bsval(v)) => f(step("AConst", "val", None), NodeRef::BitString(v)),
+            None => {}
+        },
+    }
+}
+
+// Follows `steps` from `node` to a descendant that is stored as a `Node`.
+fn descendant_mut<'a>(node: &'a mut NodeEnum, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    if steps.is_empty() {
+        return Some(node);
+    }
+    match node {
+        NodeEnum::Alias(n) => resolve_alias(n, steps),
+        NodeEnum::RangeVar(n) => resolve_range_var(n, steps),
+        NodeEnum::TableFunc(n) => resolve_table_func(n, steps),
+        NodeEnum::IntoClause(n) => resolve_into_clause(n, steps),
+        NodeEnum::Var(n) => resolve_var(n, steps),
+        NodeEnum::Param(n) => resolve_param(n, steps),
+        NodeEnum::Aggref(n) => resolve_aggref(n, steps),
+        NodeEnum::GroupingFunc(n) => resolve_grouping_func(n, steps),
+        NodeEnum::WindowFunc(n) => resolve_window_func(n, steps),
+        NodeEnum::WindowFuncRunCondition(n) => resolve_window_func_run_condition(n, steps),
+        NodeEnum::MergeSupportFunc(n) => resolve_merge_support_func(n, steps),
+        NodeEnum::SubscriptingRef(n) => resolve_subscripting_ref(n, steps),
+        NodeEnum::FuncExpr(n) => resolve_func_expr(n, steps),
+        NodeEnum::NamedArgExpr(n) => resolve_named_arg_expr(n, steps),
+        NodeEnum::OpExpr(n) => resolve_op_expr(n, steps),
+        NodeEnum::DistinctExpr(n) => resolve_distinct_expr(n, steps),
+        NodeEnum::NullIfExpr(n) => resolve_null_if_expr(n, steps),
+        NodeEnum::ScalarArrayOpExpr(n) => resolve_scalar_array_op_expr(n, steps),
+        NodeEnum::BoolExpr(n) => resolve_bool_expr(n, steps),
+        NodeEnum::SubLink(n) => resolve_sub_link(n, steps),
+        NodeEnum::SubPlan(n) => resolve_sub_plan(n, steps),
+        NodeEnum::AlternativeSubPlan(n) => resolve_alternative_sub_plan(n, steps),
+        NodeEnum::FieldSelect(n) => resolve_field_select(n, steps),
+        NodeEnum::FieldStore(n) => resolve_field_store(n, steps),
+        NodeEnum::RelabelType(n) => resolve_relabel_type(n, steps),
+        NodeEnum::CoerceViaIo(n) => resolve_coerce_via_io(n, steps),
+        NodeEnum::ArrayCoerceExpr(n) => resolve_array_coerce_expr(n, steps),
+        NodeEnum::ConvertRowtypeExpr(n) => resolve_convert_rowtype_expr(n, steps),
+        NodeEnum::CollateExpr(n) => resolve_collate_expr(n, steps),
+        NodeEnum::CaseExpr(n) => resolve_case_expr(n, steps),
+        NodeEnum::CaseWhen(n) => resolve_case_when(n, steps),
+        NodeEnum::CaseTestExpr(n) => resolve_case_test_expr(n, steps),
+        NodeEnum::ArrayExpr(n) => resolve_array_expr(n, steps),
+        NodeEnum::RowExpr(n) => resolve_row_expr(n, steps),
+        NodeEnum::RowCompareExpr(n) => resolve_row_compare_expr(n, steps),
+        NodeEnum::CoalesceExpr(n) => resolve_coalesce_expr(n, steps),
+        NodeEnum::MinMaxExpr(n) => resolve_min_max_expr(n, steps),
+        NodeEnum::SqlvalueFunction(n) => resolve_sql_value_function(n, steps),
+        NodeEnum::XmlExpr(n) => resolve_xml_expr(n, steps),
+        NodeEnum::JsonValueExpr(n) => resolve_json_value_expr(n, steps),
+        NodeEnum::JsonConstructorExpr(n) => resolve_json_constructor_expr(n, steps),
+        NodeEnum::JsonIsPredicate(n) => resolve_json_is_predicate(n, steps),
+        NodeEnum::JsonBehavior(n) => resolve_json_behavior(n, steps),
+        NodeEnum::JsonExpr(n) => resolve_json_expr(n, steps),
+        NodeEnum::JsonTablePathScan(n) => resolve_json_table_path_scan(n, steps),
+        NodeEnum::JsonTableSiblingJoin(n) => resolve_json_table_sibling_join(n, steps),
+        NodeEnum::NullTest(n) => resolve_null_test(n, steps),
+        NodeEnum::BooleanTest(n) => resolve_boolean_test(n, steps),
+        NodeEnum::MergeAction(n) => resolve_merge_action(n, steps),
+        NodeEnum::CoerceToDomain(n) => resolve_coerce_to_domain(n, steps),
+        NodeEnum::CoerceToDomainValue(n) => resolve_coerce_to_domain_value(n, steps),
+        NodeEnum::SetToDefault(n) => resolve_set_to_default(n, steps),
+        NodeEnum::CurrentOfExpr(n) => resolve_current_of_expr(n, steps),
+        NodeEnum::NextValueExpr(n) => resolve_next_value_expr(n, steps),
+        NodeEnum::InferenceElem(n) => resolve_inference_elem(n, steps),
+        NodeEnum::TargetEntry(n) => resolve_target_entry(n, steps),
+        NodeEnum::JoinExpr(n) => resolve_join_expr(n, steps),
+        NodeEnum::FromExpr(n) => resolve_from_expr(n, steps),
+        NodeEnum::OnConflictExpr(n) => resolve_on_conflict_expr(n, steps),
+        NodeEnum::Query(n) => resolve_query(n, steps),
+        NodeEnum::TypeName(n) => resolve_type_name(n, steps),
+        NodeEnum::ColumnRef(n) => resolve_column_ref(n, steps),
+        NodeEnum::AExpr(n) => resolve_a_expr(n, steps),
+        NodeEnum::TypeCast(n) => resolve_type_cast(n, steps),
+        NodeEnum::CollateClause(n) => resolve_collate_clause(n, steps),
+        NodeEnum::FuncCall(n) => resolve_func_call(n, steps),
+        NodeEnum::AIndices(n) => resolve_a_indices(n, steps),
+        NodeEnum::AIndirection(n) => resolve_a_indirection(n, steps),
+        NodeEnum::AArrayExpr(n) => resolve_a_array_expr(n, steps),
+        NodeEnum::ResTarget(n) => resolve_res_target(n, steps),
+        NodeEnum::MultiAssignRef(n) => resolve_multi_assign_ref(n, steps),
+        NodeEnum::SortBy(n) => resolve_sort_by(n, steps),
+        NodeEnum::WindowDef(n) => resolve_window_def(n, steps),
+        NodeEnum::RangeSubselect(n) => resolve_range_subselect(n, steps),
+        NodeEnum::RangeFunction(n) => resolve_range_function(n, steps),
+        NodeEnum::RangeTableFunc(n) => resolve_range_table_func(n, steps),
+        NodeEnum::RangeTableFuncCol(n) => resolve_range_table_func_col(n, steps),
+        NodeEnum::RangeTableSample(n) => resolve_range_table_sample(n, steps),
+        NodeEnum::ColumnDef(n) => resolve_column_def(n, steps),
+        NodeEnum::TableLikeClause(n) => resolve_table_like_clause(n, steps),
+        NodeEnum::IndexElem(n) => resolve_index_elem(n, steps),
+        NodeEnum::DefElem(n) => resolve_def_elem(n, steps),
+        NodeEnum::LockingClause(n) => resolve_locking_clause(n, steps),
+        NodeEnum::XmlSerialize(n) => resolve_xml_serialize(n, steps),
+        NodeEnum::PartitionElem(n) => resolve_partition_elem(n, steps),
+        NodeEnum::PartitionSpec(n) => resolve_partition_spec(n, steps),
+        NodeEnum::PartitionBoundSpec(n) => resolve_partition_bound_spec(n, steps),
+        NodeEnum::PartitionRangeDatum(n) => resolve_partition_range_datum(n, steps),
+        NodeEnum::PartitionCmd(n) => resolve_partition_cmd(n, steps),
+        NodeEnum::RangeTblEntry(n) => resolve_range_tbl_entry(n, steps),
+        NodeEnum::RangeTblFunction(n) => resolve_range_tbl_function(n, steps),
+        NodeEnum::TableSampleClause(n) => resolve_table_sample_clause(n, steps),
+        NodeEnum::WithCheckOption(n) => resolve_with_check_option(n, steps),
+        NodeEnum::GroupingSet(n) => resolve_grouping_set(n, steps),
+        NodeEnum::WindowClause(n) => resolve_window_clause(n, steps),
+        NodeEnum::WithClause(n) => resolve_with_clause(n, steps),
+        NodeEnum::InferClause(n) => resolve_infer_clause(n, steps),
+        NodeEnum::OnConflictClause(n) => resolve_on_conflict_clause(n, steps),
+        NodeEnum::CtesearchClause(n) => resolve_cte_search_clause(n, steps),
+        NodeEnum::CtecycleClause(n) => resolve_cte_cycle_clause(n, steps),
+        NodeEnum::CommonTableExpr(n) => resolve_common_table_expr(n, steps),
+        NodeEnum::MergeWhenClause(n) => resolve_merge_when_clause(n, steps),
+        NodeEnum::JsonOutput(n) => resolve_json_output(n, steps),
+        NodeEnum::JsonArgument(n) => resolve_json_argument(n, steps),
+        NodeEnum::JsonFuncExpr(n) => resolve_json_func_expr(n, steps),
+        NodeEnum::JsonTablePathSpec(n) => resolve_json_table_path_spec(n, steps),
+        NodeEnum::JsonTable(n) => resolve_json_table(n, steps),
+        NodeEnum::JsonTableColumn(n) => resolve_json_table_column(n, steps),
+        NodeEnum::JsonKeyValue(n) => resolve_json_key_value(n, steps),
+        NodeEnum::JsonParseExpr(n) => resolve_json_parse_expr(n, steps),
+        NodeEnum::JsonScalarExpr(n) => resolve_json_scalar_expr(n, steps),
+        NodeEnum::JsonSerializeExpr(n) => resolve_json_serialize_expr(n, steps),
+        NodeEnum::JsonObjectConstructor(n) => resolve_json_object_constructor(n, steps),
+        NodeEnum::JsonArrayConstructor(n) => resolve_json_array_constructor(n, steps),
+        NodeEnum::JsonArrayQueryConstructor(n) => resolve_json_array_query_constructor(n, steps),
+        NodeEnum::JsonAggConstructor(n) => resolve_json_agg_constructor(n, steps),
+        NodeEnum::JsonObjectAgg(n) => resolve_json_object_agg(n, steps),
+        NodeEnum::JsonArrayAgg(n) => resolve_json_array_agg(n, steps),
+        NodeEnum::RawStmt(n) => resolve_raw_stmt(n, steps),
+        NodeEnum::InsertStmt(n) => resolve_insert_stmt(n, steps),
+        NodeEnum::DeleteStmt(n) => resolve_delete_stmt(n, steps),
+        NodeEnum::UpdateStmt(n) => resolve_update_stmt(n, steps),
+        NodeEnum::MergeStmt(n) => resolve_merge_stmt(n, steps),
+        NodeEnum::SelectStmt(n) => resolve_select_stmt(n, steps),
+        NodeEnum::SetOperationStmt(n) => resolve_set_operation_stmt(n, steps),
+        NodeEnum::ReturnStmt(n) => resolve_return_stmt(n, steps),
+        NodeEnum::PlassignStmt(n) => resolve_pl_assign_stmt(n, steps),
+        NodeEnum::CreateSchemaStmt(n) => resolve_create_schema_stmt(n, steps),
+        NodeEnum::AlterTableStmt(n) => resolve_alter_table_stmt(n, steps),
+        NodeEnum::AlterTableCmd(n) => resolve_alter_table_cmd(n, steps),
+        NodeEnum::AlterCollationStmt(n) => resolve_alter_collation_stmt(n, steps),
+        NodeEnum::AlterDomainStmt(n) => resolve_alter_domain_stmt(n, steps),
+        NodeEnum::GrantStmt(n) => resolve_grant_stmt(n, steps),
+        NodeEnum::ObjectWithArgs(n) => resolve_object_with_args(n, steps),
+        NodeEnum::AccessPriv(n) => resolve_access_priv(n, steps),
+        NodeEnum::GrantRoleStmt(n) => resolve_grant_role_stmt(n, steps),
+        NodeEnum::AlterDefaultPrivilegesStmt(n) => resolve_alter_default_privileges_stmt(n, steps),
+        NodeEnum::CopyStmt(n) => resolve_copy_stmt(n, steps),
+        NodeEnum::VariableSetStmt(n) => resolve_variable_set_stmt(n, steps),
+        NodeEnum::CreateStmt(n) => resolve_create_stmt(n, steps),
+        NodeEnum::Constraint(n) => resolve_constraint(n, steps),
+        NodeEnum::CreateTableSpaceStmt(n) => resolve_create_table_space_stmt(n, steps),
+        NodeEnum::AlterTableSpaceOptionsStmt(n) => resolve_alter_table_space_options_stmt(n, steps),
+        NodeEnum::AlterTableMoveAllStmt(n) => resolve_alter_table_move_all_stmt(n, steps),
+        NodeEnum::CreateExtensionStmt(n) => resolve_create_extension_stmt(n, steps),
+        NodeEnum::AlterExtensionStmt(n) => resolve_alter_extension_stmt(n, steps),
+        NodeEnum::AlterExtensionContentsStmt(n) => resolve_alter_extension_contents_stmt(n, steps),
+        NodeEnum::CreateFdwStmt(n) => resolve_create_fdw_stmt(n, steps),
+        NodeEnum::AlterFdwStmt(n) => resolve_alter_fdw_stmt(n, steps),
+        NodeEnum::CreateForeignServerStmt(n) => resolve_create_foreign_server_stmt(n, steps),
+        NodeEnum::AlterForeignServerStmt(n) => resolve_alter_foreign_server_stmt(n, steps),
+        NodeEnum::CreateForeignTableStmt(n) => resolve_create_foreign_table_stmt(n, steps),
+        NodeEnum::CreateUserMappingStmt(n) => resolve_create_user_mapping_stmt(n, steps),
+        NodeEnum::AlterUserMappingStmt(n) => resolve_alter_user_mapping_stmt(n, steps),
+        NodeEnum::ImportForeignSchemaStmt(n) => resolve_import_foreign_schema_stmt(n, steps),
+        NodeEnum::CreatePolicyStmt(n) => resolve_create_policy_stmt(n, steps),
+        NodeEnum::AlterPolicyStmt(n) => resolve_alter_policy_stmt(n, steps),
+        NodeEnum::CreateAmStmt(n) => resolve_create_am_stmt(n, steps),
+        NodeEnum::CreateTrigStmt(n) => resolve_create_trig_stmt(n, steps),
+        NodeEnum::CreateEventTrigStmt(n) => resolve_create_event_trig_stmt(n, steps),
+        NodeEnum::CreatePlangStmt(n) => resolve_create_p_lang_stmt(n, steps),
+        NodeEnum::CreateRoleStmt(n) => resolve_create_role_stmt(n, steps),
+        NodeEnum::AlterRoleStmt(n) => resolve_alter_role_stmt(n, steps),
+        NodeEnum::AlterRoleSetStmt(n) => resolve_alter_role_set_stmt(n, steps),
+        NodeEnum::DropRoleStmt(n) => resolve_drop_role_stmt(n, steps),
+        NodeEnum::CreateSeqStmt(n) => resolve_create_seq_stmt(n, steps),
+        NodeEnum::AlterSeqStmt(n) => resolve_alter_seq_stmt(n, steps),
+        NodeEnum::DefineStmt(n) => resolve_define_stmt(n, steps),
+        NodeEnum::CreateDomainStmt(n) => resolve_create_domain_stmt(n, steps),
+        NodeEnum::CreateOpClassStmt(n) => resolve_create_op_class_stmt(n, steps),
+        NodeEnum::CreateOpClassItem(n) => resolve_create_op_class_item(n, steps),
+        NodeEnum::CreateOpFamilyStmt(n) => resolve_create_op_family_stmt(n, steps),
+        NodeEnum::AlterOpFamilyStmt(n) => resolve_alter_op_family_stmt(n, steps),
+        NodeEnum::DropStmt(n) => resolve_drop_stmt(n, steps),
+        NodeEnum::TruncateStmt(n) => resolve_truncate_stmt(n, steps),
+        NodeEnum::CommentStmt(n) => resolve_comment_stmt(n, steps),
+        NodeEnum::SecLabelStmt(n) => resolve_sec_label_stmt(n, steps),
+        NodeEnum::DeclareCursorStmt(n) => resolve_declare_cursor_stmt(n, steps),
+        NodeEnum::IndexStmt(n) => resolve_index_stmt(n, steps),
+        NodeEnum::CreateStatsStmt(n) => resolve_create_stats_stmt(n, steps),
+        NodeEnum::StatsElem(n) => resolve_stats_elem(n, steps),
+        NodeEnum::AlterStatsStmt(n) => resolve_alter_stats_stmt(n, steps),
+        NodeEnum::CreateFunctionStmt(n) => resolve_create_function_stmt(n, steps),
+        NodeEnum::FunctionParameter(n) => resolve_function_parameter(n, steps),
+        NodeEnum::AlterFunctionStmt(n) => resolve_alter_function_stmt(n, steps),
+        NodeEnum::DoStmt(n) => resolve_do_stmt(n, steps),
+        NodeEnum::CallStmt(n) => resolve_call_stmt(n, steps),
+        NodeEnum::RenameStmt(n) => resolve_rename_stmt(n, steps),
+        NodeEnum::AlterObjectDependsStmt(n) => resolve_alter_object_depends_stmt(n, steps),
+        NodeEnum::AlterObjectSchemaStmt(n) => resolve_alter_object_schema_stmt(n, steps),
+        NodeEnum::AlterOwnerStmt(n) => resolve_alter_owner_stmt(n, steps),
+        NodeEnum::AlterOperatorStmt(n) => resolve_alter_operator_stmt(n, steps),
+        NodeEnum::AlterTypeStmt(n) => resolve_alter_type_stmt(n, steps),
+        NodeEnum::RuleStmt(n) => resolve_rule_stmt(n, steps),
+        NodeEnum::TransactionStmt(n) => resolve_transaction_stmt(n, steps),
+        NodeEnum::CompositeTypeStmt(n) => resolve_composite_type_stmt(n, steps),
+        NodeEnum::CreateEnumStmt(n) => resolve_create_enum_stmt(n, steps),
+        NodeEnum::CreateRangeStmt(n) => resolve_create_range_stmt(n, steps),
+        NodeEnum::AlterEnumStmt(n) => resolve_alter_enum_stmt(n, steps),
+        NodeEnum::ViewStmt(n) => resolve_view_stmt(n, steps),
+        NodeEnum::CreatedbStmt(n) => resolve_createdb_stmt(n, steps),
+        NodeEnum::AlterDatabaseStmt(n) => resolve_alter_database_stmt(n, steps),
+        NodeEnum::AlterDatabaseSetStmt(n) => resolve_alter_database_set_stmt(n, steps),
+        NodeEnum::DropdbStmt(n) => resolve_dropdb_stmt(n, steps),
+        NodeEnum::AlterSystemStmt(n) => resolve_alter_system_stmt(n, steps),
+        NodeEnum::ClusterStmt(n) => resolve_cluster_stmt(n, steps),
+        NodeEnum::VacuumStmt(n) => resolve_vacuum_stmt(n, steps),
+        NodeEnum::VacuumRelation(n) => resolve_vacuum_relation(n, steps),
+        NodeEnum::ExplainStmt(n) => resolve_explain_stmt(n, steps),
+        NodeEnum::CreateTableAsStmt(n) => resolve_create_table_as_stmt(n, steps),
+        NodeEnum::RefreshMatViewStmt(n) => resolve_refresh_mat_view_stmt(n, steps),
+        NodeEnum::LockStmt(n) => resolve_lock_stmt(n, steps),
+        NodeEnum::ConstraintsSetStmt(n) => resolve_constraints_set_stmt(n, steps),
+        NodeEnum::ReindexStmt(n) => resolve_reindex_stmt(n, steps),
+        NodeEnum::CreateConversionStmt(n) => resolve_create_conversion_stmt(n, steps),
+        NodeEnum::CreateCastStmt(n) => resolve_create_cast_stmt(n, steps),
+        NodeEnum::CreateTransformStmt(n) => resolve_create_transform_stmt(n, steps),
+        NodeEnum::PrepareStmt(n) => resolve_prepare_stmt(n, steps),
+        NodeEnum::ExecuteStmt(n) => resolve_execute_stmt(n, steps),
+        NodeEnum::DropOwnedStmt(n) => resolve_drop_owned_stmt(n, steps),
+        NodeEnum::ReassignOwnedStmt(n) => resolve_reassign_owned_stmt(n, steps),
+        NodeEnum::AlterTsdictionaryStmt(n) => resolve_alter_ts_dictionary_stmt(n, steps),
+        NodeEnum::AlterTsconfigurationStmt(n) => resolve_alter_ts_configuration_stmt(n, steps),
+        NodeEnum::PublicationTable(n) => resolve_publication_table(n, steps),
+        NodeEnum::PublicationObjSpec(n) => resolve_publication_obj_spec(n, steps),
+        NodeEnum::CreatePublicationStmt(n) => resolve_create_publication_stmt(n, steps),
+        NodeEnum::AlterPublicationStmt(n) => resolve_alter_publication_stmt(n, steps),
+        NodeEnum::CreateSubscriptionStmt(n) => resolve_create_subscription_stmt(n, steps),
+        NodeEnum::AlterSubscriptionStmt(n) => resolve_alter_subscription_stmt(n, steps),
+        NodeEnum::List(n) => resolve_list(n, steps),
+        NodeEnum::IntList(n) => resolve_int_list(n, steps),
+        NodeEnum::OidList(n) => resolve_oid_list(n, steps),
+        _ => None,
+    }
+}
+
+fn resolve_alias<'a>(n: &'a mut protobuf::Alias, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "Alias" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("colnames", Some(i)) => descendant_mut(n.colnames.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_range_var<'a>(n: &'a mut protobuf::RangeVar, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RangeVar" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("alias", None) => resolve_alias(n.alias.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_table_func<'a>(n: &'a mut protobuf::TableFunc, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "TableFunc" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("ns_uris", Some(i)) => descendant_mut(n.ns_uris.get_mut(i)?.node.as_mut()?, rest),
+        ("ns_names", Some(i)) => descendant_mut(n.ns_names.get_mut(i)?.node.as_mut()?, rest),
+        ("docexpr", None) => descendant_mut(n.docexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("rowexpr", None) => descendant_mut(n.rowexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("colnames", Some(i)) => descendant_mut(n.colnames.get_mut(i)?.node.as_mut()?, rest),
+        ("coltypes", Some(i)) => descendant_mut(n.coltypes.get_mut(i)?.node.as_mut()?, rest),
+        ("coltypmods", Some(i)) => descendant_mut(n.coltypmods.get_mut(i)?.node.as_mut()?, rest),
+        ("colcollations", Some(i)) => descendant_mut(n.colcollations.get_mut(i)?.node.as_mut()?, rest),
+        ("colexprs", Some(i)) => descendant_mut(n.colexprs.get_mut(i)?.node.as_mut()?, rest),
+        ("coldefexprs", Some(i)) => descendant_mut(n.coldefexprs.get_mut(i)?.node.as_mut()?, rest),
+        ("colvalexprs", Some(i)) => descendant_mut(n.colvalexprs.get_mut(i)?.node.as_mut()?, rest),
+        ("passingvalexprs", Some(i)) => descendant_mut(n.passingvalexprs.get_mut(i)?.node.as_mut()?, rest),
+        ("plan", None) => descendant_mut(n.plan.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_into_clause<'a>(n: &'a mut protobuf::IntoClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "IntoClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("rel", None) => resolve_range_var(n.rel.as_mut()?, rest),
+        ("col_names", Some(i)) => descendant_mut(n.col_names.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("view_query", None) => descendant_mut(n.view_query.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_var<'a>(n: &'a mut protobuf::Var, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "Var" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_param<'a>(n: &'a mut protobuf::Param, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "Param" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_aggref<'a>(n: &'a mut protobuf::Aggref, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "Aggref" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("aggargtypes", Some(i)) => descendant_mut(n.aggargtypes.get_mut(i)?.node.as_mut()?, rest),
+        ("aggdirectargs", Some(i)) => descendant_mut(n.aggdirectargs.get_mut(i)?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("aggorder", Some(i)) => descendant_mut(n.aggorder.get_mut(i)?.node.as_mut()?, rest),
+        ("aggdistinct", Some(i)) => descendant_mut(n.aggdistinct.get_mut(i)?.node.as_mut()?, rest),
+        ("aggfilter", None) => descendant_mut(n.aggfilter.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_grouping_func<'a>(n: &'a mut protobuf::GroupingFunc, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "GroupingFunc" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("refs", Some(i)) => descendant_mut(n.refs.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_window_func<'a>(n: &'a mut protobuf::WindowFunc, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "WindowFunc" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("aggfilter", None) => descendant_mut(n.aggfilter.as_deref_mut()?.node.as_mut()?, rest),
+        ("run_condition", Some(i)) => descendant_mut(n.run_condition.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_window_func_run_condition<'a>(n: &'a mut protobuf::WindowFuncRunCondition, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "WindowFuncRunCondition" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_merge_support_func<'a>(n: &'a mut protobuf::MergeSupportFunc, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "MergeSupportFunc" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_subscripting_ref<'a>(n: &'a mut protobuf::SubscriptingRef, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SubscriptingRef" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("refupperindexpr", Some(i)) => descendant_mut(n.refupperindexpr.get_mut(i)?.node.as_mut()?, rest),
+        ("reflowerindexpr", Some(i)) => descendant_mut(n.reflowerindexpr.get_mut(i)?.node.as_mut()?, rest),
+        ("refexpr", None) => descendant_mut(n.refexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("refassgnexpr", None) => descendant_mut(n.refassgnexpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_func_expr<'a>(n: &'a mut protobuf::FuncExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "FuncExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_named_arg_expr<'a>(n: &'a mut protobuf::NamedArgExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "NamedArgExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_op_expr<'a>(n: &'a mut protobuf::OpExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "OpExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_distinct_expr<'a>(n: &'a mut protobuf::DistinctExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DistinctExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_null_if_expr<'a>(n: &'a mut protobuf::NullIfExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "NullIfExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_scalar_array_op_expr<'a>(n: &'a mut protobuf::ScalarArrayOpExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ScalarArrayOpExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_bool_expr<'a>(n: &'a mut protobuf::BoolExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "BoolExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_sub_link<'a>(n: &'a mut protobuf::SubLink, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SubLink" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("testexpr", None) => descendant_mut(n.testexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("oper_name", Some(i)) => descendant_mut(n.oper_name.get_mut(i)?.node.as_mut()?, rest),
+        ("subselect", None) => descendant_mut(n.subselect.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_sub_plan<'a>(n: &'a mut protobuf::SubPlan, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SubPlan" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("testexpr", None) => descendant_mut(n.testexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("param_ids", Some(i)) => descendant_mut(n.param_ids.get_mut(i)?.node.as_mut()?, rest),
+        ("set_param", Some(i)) => descendant_mut(n.set_param.get_mut(i)?.node.as_mut()?, rest),
+        ("par_param", Some(i)) => descendant_mut(n.par_param.get_mut(i)?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alternative_sub_plan<'a>(n: &'a mut protobuf::AlternativeSubPlan, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlternativeSubPlan" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("subplans", Some(i)) => descendant_mut(n.subplans.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_field_select<'a>(n: &'a mut protobuf::FieldSelect, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "FieldSelect" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_field_store<'a>(n: &'a mut protobuf::FieldStore, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "FieldStore" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        ("newvals", Some(i)) => descendant_mut(n.newvals.get_mut(i)?.node.as_mut()?, rest),
+        ("fieldnums", Some(i)) => descendant_mut(n.fieldnums.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_relabel_type<'a>(n: &'a mut protobuf::RelabelType, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RelabelType" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_coerce_via_io<'a>(n: &'a mut protobuf::CoerceViaIo, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CoerceViaIo" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_array_coerce_expr<'a>(n: &'a mut protobuf::ArrayCoerceExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ArrayCoerceExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        ("elemexpr", None) => descendant_mut(n.elemexpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_convert_rowtype_expr<'a>(n: &'a mut protobuf::ConvertRowtypeExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ConvertRowtypeExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_collate_expr<'a>(n: &'a mut protobuf::CollateExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CollateExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_case_expr<'a>(n: &'a mut protobuf::CaseExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CaseExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("defresult", None) => descendant_mut(n.defresult.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_case_when<'a>(n: &'a mut protobuf::CaseWhen, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CaseWhen" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        ("result", None) => descendant_mut(n.result.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_case_test_expr<'a>(n: &'a mut protobuf::CaseTestExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CaseTestExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_array_expr<'a>(n: &'a mut protobuf::ArrayExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ArrayExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("elements", Some(i)) => descendant_mut(n.elements.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_row_expr<'a>(n: &'a mut protobuf::RowExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RowExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("colnames", Some(i)) => descendant_mut(n.colnames.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_row_compare_expr<'a>(n: &'a mut protobuf::RowCompareExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RowCompareExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("opnos", Some(i)) => descendant_mut(n.opnos.get_mut(i)?.node.as_mut()?, rest),
+        ("opfamilies", Some(i)) => descendant_mut(n.opfamilies.get_mut(i)?.node.as_mut()?, rest),
+        ("inputcollids", Some(i)) => descendant_mut(n.inputcollids.get_mut(i)?.node.as_mut()?, rest),
+        ("largs", Some(i)) => descendant_mut(n.largs.get_mut(i)?.node.as_mut()?, rest),
+        ("rargs", Some(i)) => descendant_mut(n.rargs.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_coalesce_expr<'a>(n: &'a mut protobuf::CoalesceExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CoalesceExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_min_max_expr<'a>(n: &'a mut protobuf::MinMaxExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "MinMaxExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_sql_value_function<'a>(n: &'a mut protobuf::SqlValueFunction, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SqlvalueFunction" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_xml_expr<'a>(n: &'a mut protobuf::XmlExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "XmlExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("named_args", Some(i)) => descendant_mut(n.named_args.get_mut(i)?.node.as_mut()?, rest),
+        ("arg_names", Some(i)) => descendant_mut(n.arg_names.get_mut(i)?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_value_expr<'a>(n: &'a mut protobuf::JsonValueExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonValueExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("raw_expr", None) => descendant_mut(n.raw_expr.as_deref_mut()?.node.as_mut()?, rest),
+        ("formatted_expr", None) => descendant_mut(n.formatted_expr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_constructor_expr<'a>(n: &'a mut protobuf::JsonConstructorExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonConstructorExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("func", None) => descendant_mut(n.func.as_deref_mut()?.node.as_mut()?, rest),
+        ("coercion", None) => descendant_mut(n.coercion.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_is_predicate<'a>(n: &'a mut protobuf::JsonIsPredicate, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonIsPredicate" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_behavior<'a>(n: &'a mut protobuf::JsonBehavior, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonBehavior" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_expr<'a>(n: &'a mut protobuf::JsonExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("formatted_expr", None) => descendant_mut(n.formatted_expr.as_deref_mut()?.node.as_mut()?, rest),
+        ("path_spec", None) => descendant_mut(n.path_spec.as_deref_mut()?.node.as_mut()?, rest),
+        ("passing_names", Some(i)) => descendant_mut(n.passing_names.get_mut(i)?.node.as_mut()?, rest),
+        ("passing_values", Some(i)) => descendant_mut(n.passing_values.get_mut(i)?.node.as_mut()?, rest),
+        ("on_empty", None) => resolve_json_behavior(n.on_empty.as_deref_mut()?, rest),
+        ("on_error", None) => resolve_json_behavior(n.on_error.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_table_path_scan<'a>(n: &'a mut protobuf::JsonTablePathScan, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonTablePathScan" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("plan", None) => descendant_mut(n.plan.as_deref_mut()?.node.as_mut()?, rest),
+        ("child", None) => descendant_mut(n.child.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_table_sibling_join<'a>(n: &'a mut protobuf::JsonTableSiblingJoin, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonTableSiblingJoin" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("plan", None) => descendant_mut(n.plan.as_deref_mut()?.node.as_mut()?, rest),
+        ("lplan", None) => descendant_mut(n.lplan.as_deref_mut()?.node.as_mut()?, rest),
+        ("rplan", None) => descendant_mut(n.rplan.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_null_test<'a>(n: &'a mut protobuf::NullTest, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "NullTest" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_boolean_test<'a>(n: &'a mut protobuf::BooleanTest, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "BooleanTest" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_merge_action<'a>(n: &'a mut protobuf::MergeAction, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "MergeAction" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("qual", None) => descendant_mut(n.qual.as_deref_mut()?.node.as_mut()?, rest),
+        ("target_list", Some(i)) => descendant_mut(n.target_list.get_mut(i)?.node.as_mut()?, rest),
+        ("update_colnos", Some(i)) => descendant_mut(n.update_colnos.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_coerce_to_domain<'a>(n: &'a mut protobuf::CoerceToDomain, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CoerceToDomain" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_coerce_to_domain_value<'a>(n: &'a mut protobuf::CoerceToDomainValue, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CoerceToDomainValue" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_set_to_default<'a>(n: &'a mut protobuf::SetToDefault, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SetToDefault" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_current_of_expr<'a>(n: &'a mut protobuf::CurrentOfExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CurrentOfExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_next_value_expr<'a>(n: &'a mut protobuf::NextValueExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "NextValueExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_inference_elem<'a>(n: &'a mut protobuf::InferenceElem, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "InferenceElem" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_target_entry<'a>(n: &'a mut protobuf::TargetEntry, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "TargetEntry" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("xpr", None) => descendant_mut(n.xpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_join_expr<'a>(n: &'a mut protobuf::JoinExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JoinExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("larg", None) => descendant_mut(n.larg.as_deref_mut()?.node.as_mut()?, rest),
+        ("rarg", None) => descendant_mut(n.rarg.as_deref_mut()?.node.as_mut()?, rest),
+        ("using_clause", Some(i)) => descendant_mut(n.using_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("join_using_alias", None) => resolve_alias(n.join_using_alias.as_mut()?, rest),
+        ("quals", None) => descendant_mut(n.quals.as_deref_mut()?.node.as_mut()?, rest),
+        ("alias", None) => resolve_alias(n.alias.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_from_expr<'a>(n: &'a mut protobuf::FromExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "FromExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("fromlist", Some(i)) => descendant_mut(n.fromlist.get_mut(i)?.node.as_mut()?, rest),
+        ("quals", None) => descendant_mut(n.quals.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_on_conflict_expr<'a>(n: &'a mut protobuf::OnConflictExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "OnConflictExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("arbiter_elems", Some(i)) => descendant_mut(n.arbiter_elems.get_mut(i)?.node.as_mut()?, rest),
+        ("arbiter_where", None) => descendant_mut(n.arbiter_where.as_deref_mut()?.node.as_mut()?, rest),
+        ("on_conflict_set", Some(i)) => descendant_mut(n.on_conflict_set.get_mut(i)?.node.as_mut()?, rest),
+        ("on_conflict_where", None) => descendant_mut(n.on_conflict_where.as_deref_mut()?.node.as_mut()?, rest),
+        ("excl_rel_tlist", Some(i)) => descendant_mut(n.excl_rel_tlist.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_query<'a>(n: &'a mut protobuf::Query, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "Query" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("utility_stmt", None) => descendant_mut(n.utility_stmt.as_deref_mut()?.node.as_mut()?, rest),
+        ("cte_list", Some(i)) => descendant_mut(n.cte_list.get_mut(i)?.node.as_mut()?, rest),
+        ("rtable", Some(i)) => descendant_mut(n.rtable.get_mut(i)?.node.as_mut()?, rest),
+        ("rteperminfos", Some(i)) => descendant_mut(n.rteperminfos.get_mut(i)?.node.as_mut()?, rest),
+        ("jointree", None) => resolve_from_expr(n.jointree.as_deref_mut()?, rest),
+        ("merge_action_list", Some(i)) => descendant_mut(n.merge_action_list.get_mut(i)?.node.as_mut()?, rest),
+        ("merge_join_condition", None) => descendant_mut(n.merge_join_condition.as_deref_mut()?.node.as_mut()?, rest),
+        ("target_list", Some(i)) => descendant_mut(n.target_list.get_mut(i)?.node.as_mut()?, rest),
+        ("on_conflict", None) => resolve_on_conflict_expr(n.on_conflict.as_deref_mut()?, rest),
+        ("returning_list", Some(i)) => descendant_mut(n.returning_list.get_mut(i)?.node.as_mut()?, rest),
+        ("group_clause", Some(i)) => descendant_mut(n.group_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("grouping_sets", Some(i)) => descendant_mut(n.grouping_sets.get_mut(i)?.node.as_mut()?, rest),
+        ("having_qual", None) => descendant_mut(n.having_qual.as_deref_mut()?.node.as_mut()?, rest),
+        ("window_clause", Some(i)) => descendant_mut(n.window_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("distinct_clause", Some(i)) => descendant_mut(n.distinct_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("sort_clause", Some(i)) => descendant_mut(n.sort_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("limit_offset", None) => descendant_mut(n.limit_offset.as_deref_mut()?.node.as_mut()?, rest),
+        ("limit_count", None) => descendant_mut(n.limit_count.as_deref_mut()?.node.as_mut()?, rest),
+        ("row_marks", Some(i)) => descendant_mut(n.row_marks.get_mut(i)?.node.as_mut()?, rest),
+        ("set_operations", None) => descendant_mut(n.set_operations.as_deref_mut()?.node.as_mut()?, rest),
+        ("constraint_deps", Some(i)) => descendant_mut(n.constraint_deps.get_mut(i)?.node.as_mut()?, rest),
+        ("with_check_options", Some(i)) => descendant_mut(n.with_check_options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_type_name<'a>(n: &'a mut protobuf::TypeName, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "TypeName" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("names", Some(i)) => descendant_mut(n.names.get_mut(i)?.node.as_mut()?, rest),
+        ("typmods", Some(i)) => descendant_mut(n.typmods.get_mut(i)?.node.as_mut()?, rest),
+        ("array_bounds", Some(i)) => descendant_mut(n.array_bounds.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_column_ref<'a>(n: &'a mut protobuf::ColumnRef, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ColumnRef" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("fields", Some(i)) => descendant_mut(n.fields.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_a_expr<'a>(n: &'a mut protobuf::AExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("name", Some(i)) => descendant_mut(n.name.get_mut(i)?.node.as_mut()?, rest),
+        ("lexpr", None) => descendant_mut(n.lexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("rexpr", None) => descendant_mut(n.rexpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_type_cast<'a>(n: &'a mut protobuf::TypeCast, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "TypeCast" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        ("type_name", None) => resolve_type_name(n.type_name.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_collate_clause<'a>(n: &'a mut protobuf::CollateClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CollateClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        ("collname", Some(i)) => descendant_mut(n.collname.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_func_call<'a>(n: &'a mut protobuf::FuncCall, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "FuncCall" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("funcname", Some(i)) => descendant_mut(n.funcname.get_mut(i)?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("agg_order", Some(i)) => descendant_mut(n.agg_order.get_mut(i)?.node.as_mut()?, rest),
+        ("agg_filter", None) => descendant_mut(n.agg_filter.as_deref_mut()?.node.as_mut()?, rest),
+        ("over", None) => resolve_window_def(n.over.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_a_indices<'a>(n: &'a mut protobuf::AIndices, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AIndices" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("lidx", None) => descendant_mut(n.lidx.as_deref_mut()?.node.as_mut()?, rest),
+        ("uidx", None) => descendant_mut(n.uidx.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_a_indirection<'a>(n: &'a mut protobuf::AIndirection, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AIndirection" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        ("indirection", Some(i)) => descendant_mut(n.indirection.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_a_array_expr<'a>(n: &'a mut protobuf::AArrayExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AArrayExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("elements", Some(i)) => descendant_mut(n.elements.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_res_target<'a>(n: &'a mut protobuf::ResTarget, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ResTarget" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("indirection", Some(i)) => descendant_mut(n.indirection.get_mut(i)?.node.as_mut()?, rest),
+        ("val", None) => descendant_mut(n.val.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_multi_assign_ref<'a>(n: &'a mut protobuf::MultiAssignRef, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "MultiAssignRef" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("source", None) => descendant_mut(n.source.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_sort_by<'a>(n: &'a mut protobuf::SortBy, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SortBy" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("node", None) => descendant_mut(n.node.as_deref_mut()?.node.as_mut()?, rest),
+        ("use_op", Some(i)) => descendant_mut(n.use_op.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_window_def<'a>(n: &'a mut protobuf::WindowDef, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "WindowDef" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("partition_clause", Some(i)) => descendant_mut(n.partition_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("order_clause", Some(i)) => descendant_mut(n.order_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("start_offset", None) => descendant_mut(n.start_offset.as_deref_mut()?.node.as_mut()?, rest),
+        ("end_offset", None) => descendant_mut(n.end_offset.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_range_subselect<'a>(n: &'a mut protobuf::RangeSubselect, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RangeSubselect" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("subquery", None) => descendant_mut(n.subquery.as_deref_mut()?.node.as_mut()?, rest),
+        ("alias", None) => resolve_alias(n.alias.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_range_function<'a>(n: &'a mut protobuf::RangeFunction, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RangeFunction" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("functions", Some(i)) => descendant_mut(n.functions.get_mut(i)?.node.as_mut()?, rest),
+        ("alias", None) => resolve_alias(n.alias.as_mut()?, rest),
+        ("coldeflist", Some(i)) => descendant_mut(n.coldeflist.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_range_table_func<'a>(n: &'a mut protobuf::RangeTableFunc, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RangeTableFunc" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("docexpr", None) => descendant_mut(n.docexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("rowexpr", None) => descendant_mut(n.rowexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("namespaces", Some(i)) => descendant_mut(n.namespaces.get_mut(i)?.node.as_mut()?, rest),
+        ("columns", Some(i)) => descendant_mut(n.columns.get_mut(i)?.node.as_mut()?, rest),
+        ("alias", None) => resolve_alias(n.alias.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_range_table_func_col<'a>(n: &'a mut protobuf::RangeTableFuncCol, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RangeTableFuncCol" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", None) => resolve_type_name(n.type_name.as_mut()?, rest),
+        ("colexpr", None) => descendant_mut(n.colexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("coldefexpr", None) => descendant_mut(n.coldefexpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_range_table_sample<'a>(n: &'a mut protobuf::RangeTableSample, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RangeTableSample" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => descendant_mut(n.relation.as_deref_mut()?.node.as_mut()?, rest),
+        ("method", Some(i)) => descendant_mut(n.method.get_mut(i)?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("repeatable", None) => descendant_mut(n.repeatable.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_column_def<'a>(n: &'a mut protobuf::ColumnDef, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ColumnDef" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", None) => resolve_type_name(n.type_name.as_mut()?, rest),
+        ("raw_default", None) => descendant_mut(n.raw_default.as_deref_mut()?.node.as_mut()?, rest),
+        ("cooked_default", None) => descendant_mut(n.cooked_default.as_deref_mut()?.node.as_mut()?, rest),
+        ("identity_sequence", None) => resolve_range_var(n.identity_sequence.as_mut()?, rest),
+        ("coll_clause", None) => resolve_collate_clause(n.coll_clause.as_deref_mut()?, rest),
+        ("constraints", Some(i)) => descendant_mut(n.constraints.get_mut(i)?.node.as_mut()?, rest),
+        ("fdwoptions", Some(i)) => descendant_mut(n.fdwoptions.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_table_like_clause<'a>(n: &'a mut protobuf::TableLikeClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "TableLikeClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_index_elem<'a>(n: &'a mut protobuf::IndexElem, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "IndexElem" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        ("collation", Some(i)) => descendant_mut(n.collation.get_mut(i)?.node.as_mut()?, rest),
+        ("opclass", Some(i)) => descendant_mut(n.opclass.get_mut(i)?.node.as_mut()?, rest),
+        ("opclassopts", Some(i)) => descendant_mut(n.opclassopts.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_def_elem<'a>(n: &'a mut protobuf::DefElem, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DefElem" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("arg", None) => descendant_mut(n.arg.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_locking_clause<'a>(n: &'a mut protobuf::LockingClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "LockingClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("locked_rels", Some(i)) => descendant_mut(n.locked_rels.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_xml_serialize<'a>(n: &'a mut protobuf::XmlSerialize, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "XmlSerialize" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        ("type_name", None) => resolve_type_name(n.type_name.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_partition_elem<'a>(n: &'a mut protobuf::PartitionElem, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PartitionElem" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        ("collation", Some(i)) => descendant_mut(n.collation.get_mut(i)?.node.as_mut()?, rest),
+        ("opclass", Some(i)) => descendant_mut(n.opclass.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_partition_spec<'a>(n: &'a mut protobuf::PartitionSpec, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PartitionSpec" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("part_params", Some(i)) => descendant_mut(n.part_params.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_partition_bound_spec<'a>(n: &'a mut protobuf::PartitionBoundSpec, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PartitionBoundSpec" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("listdatums", Some(i)) => descendant_mut(n.listdatums.get_mut(i)?.node.as_mut()?, rest),
+        ("lowerdatums", Some(i)) => descendant_mut(n.lowerdatums.get_mut(i)?.node.as_mut()?, rest),
+        ("upperdatums", Some(i)) => descendant_mut(n.upperdatums.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_partition_range_datum<'a>(n: &'a mut protobuf::PartitionRangeDatum, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PartitionRangeDatum" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("value", None) => descendant_mut(n.value.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_partition_cmd<'a>(n: &'a mut protobuf::PartitionCmd, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PartitionCmd" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("name", None) => resolve_range_var(n.name.as_mut()?, rest),
+        ("bound", None) => resolve_partition_bound_spec(n.bound.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_range_tbl_entry<'a>(n: &'a mut protobuf::RangeTblEntry, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RangeTblEntry" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("alias", None) => resolve_alias(n.alias.as_mut()?, rest),
+        ("eref", None) => resolve_alias(n.eref.as_mut()?, rest),
+        ("tablesample", None) => resolve_table_sample_clause(n.tablesample.as_deref_mut()?, rest),
+        ("subquery", None) => resolve_query(n.subquery.as_deref_mut()?, rest),
+        ("joinaliasvars", Some(i)) => descendant_mut(n.joinaliasvars.get_mut(i)?.node.as_mut()?, rest),
+        ("joinleftcols", Some(i)) => descendant_mut(n.joinleftcols.get_mut(i)?.node.as_mut()?, rest),
+        ("joinrightcols", Some(i)) => descendant_mut(n.joinrightcols.get_mut(i)?.node.as_mut()?, rest),
+        ("join_using_alias", None) => resolve_alias(n.join_using_alias.as_mut()?, rest),
+        ("functions", Some(i)) => descendant_mut(n.functions.get_mut(i)?.node.as_mut()?, rest),
+        ("tablefunc", None) => resolve_table_func(n.tablefunc.as_deref_mut()?, rest),
+        ("values_lists", Some(i)) => descendant_mut(n.values_lists.get_mut(i)?.node.as_mut()?, rest),
+        ("coltypes", Some(i)) => descendant_mut(n.coltypes.get_mut(i)?.node.as_mut()?, rest),
+        ("coltypmods", Some(i)) => descendant_mut(n.coltypmods.get_mut(i)?.node.as_mut()?, rest),
+        ("colcollations", Some(i)) => descendant_mut(n.colcollations.get_mut(i)?.node.as_mut()?, rest),
+        ("security_quals", Some(i)) => descendant_mut(n.security_quals.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_range_tbl_function<'a>(n: &'a mut protobuf::RangeTblFunction, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RangeTblFunction" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("funcexpr", None) => descendant_mut(n.funcexpr.as_deref_mut()?.node.as_mut()?, rest),
+        ("funccolnames", Some(i)) => descendant_mut(n.funccolnames.get_mut(i)?.node.as_mut()?, rest),
+        ("funccoltypes", Some(i)) => descendant_mut(n.funccoltypes.get_mut(i)?.node.as_mut()?, rest),
+        ("funccoltypmods", Some(i)) => descendant_mut(n.funccoltypmods.get_mut(i)?.node.as_mut()?, rest),
+        ("funccolcollations", Some(i)) => descendant_mut(n.funccolcollations.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_table_sample_clause<'a>(n: &'a mut protobuf::TableSampleClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "TableSampleClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("repeatable", None) => descendant_mut(n.repeatable.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_with_check_option<'a>(n: &'a mut protobuf::WithCheckOption, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "WithCheckOption" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("qual", None) => descendant_mut(n.qual.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_grouping_set<'a>(n: &'a mut protobuf::GroupingSet, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "GroupingSet" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("content", Some(i)) => descendant_mut(n.content.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_window_clause<'a>(n: &'a mut protobuf::WindowClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "WindowClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("partition_clause", Some(i)) => descendant_mut(n.partition_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("order_clause", Some(i)) => descendant_mut(n.order_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("start_offset", None) => descendant_mut(n.start_offset.as_deref_mut()?.node.as_mut()?, rest),
+        ("end_offset", None) => descendant_mut(n.end_offset.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_with_clause<'a>(n: &'a mut protobuf::WithClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "WithClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("ctes", Some(i)) => descendant_mut(n.ctes.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_infer_clause<'a>(n: &'a mut protobuf::InferClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "InferClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("index_elems", Some(i)) => descendant_mut(n.index_elems.get_mut(i)?.node.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_on_conflict_clause<'a>(n: &'a mut protobuf::OnConflictClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "OnConflictClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("infer", None) => resolve_infer_clause(n.infer.as_deref_mut()?, rest),
+        ("target_list", Some(i)) => descendant_mut(n.target_list.get_mut(i)?.node.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_cte_search_clause<'a>(n: &'a mut protobuf::CteSearchClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CtesearchClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("search_col_list", Some(i)) => descendant_mut(n.search_col_list.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_cte_cycle_clause<'a>(n: &'a mut protobuf::CteCycleClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CtecycleClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("cycle_col_list", Some(i)) => descendant_mut(n.cycle_col_list.get_mut(i)?.node.as_mut()?, rest),
+        ("cycle_mark_value", None) => descendant_mut(n.cycle_mark_value.as_deref_mut()?.node.as_mut()?, rest),
+        ("cycle_mark_default", None) => descendant_mut(n.cycle_mark_default.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_common_table_expr<'a>(n: &'a mut protobuf::CommonTableExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CommonTableExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("aliascolnames", Some(i)) => descendant_mut(n.aliascolnames.get_mut(i)?.node.as_mut()?, rest),
+        ("ctequery", None) => descendant_mut(n.ctequery.as_deref_mut()?.node.as_mut()?, rest),
+        ("search_clause", None) => resolve_cte_search_clause(n.search_clause.as_mut()?, rest),
+        ("cycle_clause", None) => resolve_cte_cycle_clause(n.cycle_clause.as_deref_mut()?, rest),
+        ("ctecolnames", Some(i)) => descendant_mut(n.ctecolnames.get_mut(i)?.node.as_mut()?, rest),
+        ("ctecoltypes", Some(i)) => descendant_mut(n.ctecoltypes.get_mut(i)?.node.as_mut()?, rest),
+        ("ctecoltypmods", Some(i)) => descendant_mut(n.ctecoltypmods.get_mut(i)?.node.as_mut()?, rest),
+        ("ctecolcollations", Some(i)) => descendant_mut(n.ctecolcollations.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_merge_when_clause<'a>(n: &'a mut protobuf::MergeWhenClause, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "MergeWhenClause" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("condition", None) => descendant_mut(n.condition.as_deref_mut()?.node.as_mut()?, rest),
+        ("target_list", Some(i)) => descendant_mut(n.target_list.get_mut(i)?.node.as_mut()?, rest),
+        ("values", Some(i)) => descendant_mut(n.values.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_output<'a>(n: &'a mut protobuf::JsonOutput, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonOutput" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", None) => resolve_type_name(n.type_name.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_argument<'a>(n: &'a mut protobuf::JsonArgument, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonArgument" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("val", None) => resolve_json_value_expr(n.val.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_func_expr<'a>(n: &'a mut protobuf::JsonFuncExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonFuncExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("context_item", None) => resolve_json_value_expr(n.context_item.as_deref_mut()?, rest),
+        ("pathspec", None) => descendant_mut(n.pathspec.as_deref_mut()?.node.as_mut()?, rest),
+        ("passing", Some(i)) => descendant_mut(n.passing.get_mut(i)?.node.as_mut()?, rest),
+        ("output", None) => resolve_json_output(n.output.as_mut()?, rest),
+        ("on_empty", None) => resolve_json_behavior(n.on_empty.as_deref_mut()?, rest),
+        ("on_error", None) => resolve_json_behavior(n.on_error.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_table_path_spec<'a>(n: &'a mut protobuf::JsonTablePathSpec, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonTablePathSpec" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("string", None) => descendant_mut(n.string.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_table<'a>(n: &'a mut protobuf::JsonTable, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonTable" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("context_item", None) => resolve_json_value_expr(n.context_item.as_deref_mut()?, rest),
+        ("pathspec", None) => resolve_json_table_path_spec(n.pathspec.as_deref_mut()?, rest),
+        ("passing", Some(i)) => descendant_mut(n.passing.get_mut(i)?.node.as_mut()?, rest),
+        ("columns", Some(i)) => descendant_mut(n.columns.get_mut(i)?.node.as_mut()?, rest),
+        ("on_error", None) => resolve_json_behavior(n.on_error.as_deref_mut()?, rest),
+        ("alias", None) => resolve_alias(n.alias.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_table_column<'a>(n: &'a mut protobuf::JsonTableColumn, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonTableColumn" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", None) => resolve_type_name(n.type_name.as_mut()?, rest),
+        ("pathspec", None) => resolve_json_table_path_spec(n.pathspec.as_deref_mut()?, rest),
+        ("columns", Some(i)) => descendant_mut(n.columns.get_mut(i)?.node.as_mut()?, rest),
+        ("on_empty", None) => resolve_json_behavior(n.on_empty.as_deref_mut()?, rest),
+        ("on_error", None) => resolve_json_behavior(n.on_error.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_key_value<'a>(n: &'a mut protobuf::JsonKeyValue, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonKeyValue" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("key", None) => descendant_mut(n.key.as_deref_mut()?.node.as_mut()?, rest),
+        ("value", None) => resolve_json_value_expr(n.value.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_parse_expr<'a>(n: &'a mut protobuf::JsonParseExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonParseExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => resolve_json_value_expr(n.expr.as_deref_mut()?, rest),
+        ("output", None) => resolve_json_output(n.output.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_scalar_expr<'a>(n: &'a mut protobuf::JsonScalarExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonScalarExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        ("output", None) => resolve_json_output(n.output.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_serialize_expr<'a>(n: &'a mut protobuf::JsonSerializeExpr, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonSerializeExpr" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => resolve_json_value_expr(n.expr.as_deref_mut()?, rest),
+        ("output", None) => resolve_json_output(n.output.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_object_constructor<'a>(n: &'a mut protobuf::JsonObjectConstructor, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonObjectConstructor" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("exprs", Some(i)) => descendant_mut(n.exprs.get_mut(i)?.node.as_mut()?, rest),
+        ("output", None) => resolve_json_output(n.output.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_array_constructor<'a>(n: &'a mut protobuf::JsonArrayConstructor, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonArrayConstructor" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("exprs", Some(i)) => descendant_mut(n.exprs.get_mut(i)?.node.as_mut()?, rest),
+        ("output", None) => resolve_json_output(n.output.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_array_query_constructor<'a>(n: &'a mut protobuf::JsonArrayQueryConstructor, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonArrayQueryConstructor" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("query", None) => descendant_mut(n.query.as_deref_mut()?.node.as_mut()?, rest),
+        ("output", None) => resolve_json_output(n.output.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_agg_constructor<'a>(n: &'a mut protobuf::JsonAggConstructor, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonAggConstructor" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("output", None) => resolve_json_output(n.output.as_mut()?, rest),
+        ("agg_filter", None) => descendant_mut(n.agg_filter.as_deref_mut()?.node.as_mut()?, rest),
+        ("agg_order", Some(i)) => descendant_mut(n.agg_order.get_mut(i)?.node.as_mut()?, rest),
+        ("over", None) => resolve_window_def(n.over.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_object_agg<'a>(n: &'a mut protobuf::JsonObjectAgg, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonObjectAgg" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("constructor", None) => resolve_json_agg_constructor(n.constructor.as_deref_mut()?, rest),
+        ("arg", None) => resolve_json_key_value(n.arg.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_json_array_agg<'a>(n: &'a mut protobuf::JsonArrayAgg, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "JsonArrayAgg" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("constructor", None) => resolve_json_agg_constructor(n.constructor.as_deref_mut()?, rest),
+        ("arg", None) => resolve_json_value_expr(n.arg.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_raw_stmt<'a>(n: &'a mut protobuf::RawStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RawStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("stmt", None) => descendant_mut(n.stmt.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_insert_stmt<'a>(n: &'a mut protobuf::InsertStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "InsertStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("cols", Some(i)) => descendant_mut(n.cols.get_mut(i)?.node.as_mut()?, rest),
+        ("select_stmt", None) => descendant_mut(n.select_stmt.as_deref_mut()?.node.as_mut()?, rest),
+        ("on_conflict_clause", None) => resolve_on_conflict_clause(n.on_conflict_clause.as_deref_mut()?, rest),
+        ("returning_list", Some(i)) => descendant_mut(n.returning_list.get_mut(i)?.node.as_mut()?, rest),
+        ("with_clause", None) => resolve_with_clause(n.with_clause.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_delete_stmt<'a>(n: &'a mut protobuf::DeleteStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DeleteStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("using_clause", Some(i)) => descendant_mut(n.using_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("returning_list", Some(i)) => descendant_mut(n.returning_list.get_mut(i)?.node.as_mut()?, rest),
+        ("with_clause", None) => resolve_with_clause(n.with_clause.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_update_stmt<'a>(n: &'a mut protobuf::UpdateStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "UpdateStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("target_list", Some(i)) => descendant_mut(n.target_list.get_mut(i)?.node.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("from_clause", Some(i)) => descendant_mut(n.from_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("returning_list", Some(i)) => descendant_mut(n.returning_list.get_mut(i)?.node.as_mut()?, rest),
+        ("with_clause", None) => resolve_with_clause(n.with_clause.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_merge_stmt<'a>(n: &'a mut protobuf::MergeStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "MergeStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("source_relation", None) => descendant_mut(n.source_relation.as_deref_mut()?.node.as_mut()?, rest),
+        ("join_condition", None) => descendant_mut(n.join_condition.as_deref_mut()?.node.as_mut()?, rest),
+        ("merge_when_clauses", Some(i)) => descendant_mut(n.merge_when_clauses.get_mut(i)?.node.as_mut()?, rest),
+        ("returning_list", Some(i)) => descendant_mut(n.returning_list.get_mut(i)?.node.as_mut()?, rest),
+        ("with_clause", None) => resolve_with_clause(n.with_clause.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_select_stmt<'a>(n: &'a mut protobuf::SelectStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SelectStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("distinct_clause", Some(i)) => descendant_mut(n.distinct_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("into_clause", None) => resolve_into_clause(n.into_clause.as_deref_mut()?, rest),
+        ("target_list", Some(i)) => descendant_mut(n.target_list.get_mut(i)?.node.as_mut()?, rest),
+        ("from_clause", Some(i)) => descendant_mut(n.from_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("group_clause", Some(i)) => descendant_mut(n.group_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("having_clause", None) => descendant_mut(n.having_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("window_clause", Some(i)) => descendant_mut(n.window_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("values_lists", Some(i)) => descendant_mut(n.values_lists.get_mut(i)?.node.as_mut()?, rest),
+        ("sort_clause", Some(i)) => descendant_mut(n.sort_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("limit_offset", None) => descendant_mut(n.limit_offset.as_deref_mut()?.node.as_mut()?, rest),
+        ("limit_count", None) => descendant_mut(n.limit_count.as_deref_mut()?.node.as_mut()?, rest),
+        ("locking_clause", Some(i)) => descendant_mut(n.locking_clause.get_mut(i)?.node.as_mut()?, rest),
+        ("with_clause", None) => resolve_with_clause(n.with_clause.as_mut()?, rest),
+        ("larg", None) => resolve_select_stmt(n.larg.as_deref_mut()?, rest),
+        ("rarg", None) => resolve_select_stmt(n.rarg.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_set_operation_stmt<'a>(n: &'a mut protobuf::SetOperationStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SetOperationStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("larg", None) => descendant_mut(n.larg.as_deref_mut()?.node.as_mut()?, rest),
+        ("rarg", None) => descendant_mut(n.rarg.as_deref_mut()?.node.as_mut()?, rest),
+        ("col_types", Some(i)) => descendant_mut(n.col_types.get_mut(i)?.node.as_mut()?, rest),
+        ("col_typmods", Some(i)) => descendant_mut(n.col_typmods.get_mut(i)?.node.as_mut()?, rest),
+        ("col_collations", Some(i)) => descendant_mut(n.col_collations.get_mut(i)?.node.as_mut()?, rest),
+        ("group_clauses", Some(i)) => descendant_mut(n.group_clauses.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_return_stmt<'a>(n: &'a mut protobuf::ReturnStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ReturnStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("returnval", None) => descendant_mut(n.returnval.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_pl_assign_stmt<'a>(n: &'a mut protobuf::PlAssignStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PlassignStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("indirection", Some(i)) => descendant_mut(n.indirection.get_mut(i)?.node.as_mut()?, rest),
+        ("val", None) => resolve_select_stmt(n.val.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_schema_stmt<'a>(n: &'a mut protobuf::CreateSchemaStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateSchemaStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("schema_elts", Some(i)) => descendant_mut(n.schema_elts.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_table_stmt<'a>(n: &'a mut protobuf::AlterTableStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterTableStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("cmds", Some(i)) => descendant_mut(n.cmds.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_table_cmd<'a>(n: &'a mut protobuf::AlterTableCmd, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterTableCmd" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("def", None) => descendant_mut(n.def.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_collation_stmt<'a>(n: &'a mut protobuf::AlterCollationStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterCollationStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("collname", Some(i)) => descendant_mut(n.collname.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_domain_stmt<'a>(n: &'a mut protobuf::AlterDomainStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterDomainStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", Some(i)) => descendant_mut(n.type_name.get_mut(i)?.node.as_mut()?, rest),
+        ("def", None) => descendant_mut(n.def.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_grant_stmt<'a>(n: &'a mut protobuf::GrantStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "GrantStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("objects", Some(i)) => descendant_mut(n.objects.get_mut(i)?.node.as_mut()?, rest),
+        ("privileges", Some(i)) => descendant_mut(n.privileges.get_mut(i)?.node.as_mut()?, rest),
+        ("grantees", Some(i)) => descendant_mut(n.grantees.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_object_with_args<'a>(n: &'a mut protobuf::ObjectWithArgs, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ObjectWithArgs" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("objname", Some(i)) => descendant_mut(n.objname.get_mut(i)?.node.as_mut()?, rest),
+        ("objargs", Some(i)) => descendant_mut(n.objargs.get_mut(i)?.node.as_mut()?, rest),
+        ("objfuncargs", Some(i)) => descendant_mut(n.objfuncargs.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_access_priv<'a>(n: &'a mut protobuf::AccessPriv, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AccessPriv" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("cols", Some(i)) => descendant_mut(n.cols.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_grant_role_stmt<'a>(n: &'a mut protobuf::GrantRoleStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "GrantRoleStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("granted_roles", Some(i)) => descendant_mut(n.granted_roles.get_mut(i)?.node.as_mut()?, rest),
+        ("grantee_roles", Some(i)) => descendant_mut(n.grantee_roles.get_mut(i)?.node.as_mut()?, rest),
+        ("opt", Some(i)) => descendant_mut(n.opt.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_default_privileges_stmt<'a>(n: &'a mut protobuf::AlterDefaultPrivilegesStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterDefaultPrivilegesStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("action", None) => resolve_grant_stmt(n.action.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_copy_stmt<'a>(n: &'a mut protobuf::CopyStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CopyStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("query", None) => descendant_mut(n.query.as_deref_mut()?.node.as_mut()?, rest),
+        ("attlist", Some(i)) => descendant_mut(n.attlist.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_variable_set_stmt<'a>(n: &'a mut protobuf::VariableSetStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "VariableSetStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_stmt<'a>(n: &'a mut protobuf::CreateStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("table_elts", Some(i)) => descendant_mut(n.table_elts.get_mut(i)?.node.as_mut()?, rest),
+        ("inh_relations", Some(i)) => descendant_mut(n.inh_relations.get_mut(i)?.node.as_mut()?, rest),
+        ("partbound", None) => resolve_partition_bound_spec(n.partbound.as_mut()?, rest),
+        ("partspec", None) => resolve_partition_spec(n.partspec.as_mut()?, rest),
+        ("of_typename", None) => resolve_type_name(n.of_typename.as_mut()?, rest),
+        ("constraints", Some(i)) => descendant_mut(n.constraints.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_constraint<'a>(n: &'a mut protobuf::Constraint, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "Constraint" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("raw_expr", None) => descendant_mut(n.raw_expr.as_deref_mut()?.node.as_mut()?, rest),
+        ("keys", Some(i)) => descendant_mut(n.keys.get_mut(i)?.node.as_mut()?, rest),
+        ("including", Some(i)) => descendant_mut(n.including.get_mut(i)?.node.as_mut()?, rest),
+        ("exclusions", Some(i)) => descendant_mut(n.exclusions.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("pktable", None) => resolve_range_var(n.pktable.as_mut()?, rest),
+        ("fk_attrs", Some(i)) => descendant_mut(n.fk_attrs.get_mut(i)?.node.as_mut()?, rest),
+        ("pk_attrs", Some(i)) => descendant_mut(n.pk_attrs.get_mut(i)?.node.as_mut()?, rest),
+        ("fk_del_set_cols", Some(i)) => descendant_mut(n.fk_del_set_cols.get_mut(i)?.node.as_mut()?, rest),
+        ("old_conpfeqop", Some(i)) => descendant_mut(n.old_conpfeqop.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_table_space_stmt<'a>(n: &'a mut protobuf::CreateTableSpaceStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateTableSpaceStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_table_space_options_stmt<'a>(n: &'a mut protobuf::AlterTableSpaceOptionsStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterTableSpaceOptionsStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_table_move_all_stmt<'a>(n: &'a mut protobuf::AlterTableMoveAllStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterTableMoveAllStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("roles", Some(i)) => descendant_mut(n.roles.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_extension_stmt<'a>(n: &'a mut protobuf::CreateExtensionStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateExtensionStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_extension_stmt<'a>(n: &'a mut protobuf::AlterExtensionStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterExtensionStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_extension_contents_stmt<'a>(n: &'a mut protobuf::AlterExtensionContentsStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterExtensionContentsStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("object", None) => descendant_mut(n.object.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_fdw_stmt<'a>(n: &'a mut protobuf::CreateFdwStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateFdwStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("func_options", Some(i)) => descendant_mut(n.func_options.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_fdw_stmt<'a>(n: &'a mut protobuf::AlterFdwStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterFdwStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("func_options", Some(i)) => descendant_mut(n.func_options.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_foreign_server_stmt<'a>(n: &'a mut protobuf::CreateForeignServerStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateForeignServerStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_foreign_server_stmt<'a>(n: &'a mut protobuf::AlterForeignServerStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterForeignServerStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_foreign_table_stmt<'a>(n: &'a mut protobuf::CreateForeignTableStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateForeignTableStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("base_stmt", None) => resolve_create_stmt(n.base_stmt.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_user_mapping_stmt<'a>(n: &'a mut protobuf::CreateUserMappingStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateUserMappingStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_user_mapping_stmt<'a>(n: &'a mut protobuf::AlterUserMappingStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterUserMappingStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_import_foreign_schema_stmt<'a>(n: &'a mut protobuf::ImportForeignSchemaStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ImportForeignSchemaStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("table_list", Some(i)) => descendant_mut(n.table_list.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_policy_stmt<'a>(n: &'a mut protobuf::CreatePolicyStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreatePolicyStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("table", None) => resolve_range_var(n.table.as_mut()?, rest),
+        ("roles", Some(i)) => descendant_mut(n.roles.get_mut(i)?.node.as_mut()?, rest),
+        ("qual", None) => descendant_mut(n.qual.as_deref_mut()?.node.as_mut()?, rest),
+        ("with_check", None) => descendant_mut(n.with_check.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_policy_stmt<'a>(n: &'a mut protobuf::AlterPolicyStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterPolicyStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("table", None) => resolve_range_var(n.table.as_mut()?, rest),
+        ("roles", Some(i)) => descendant_mut(n.roles.get_mut(i)?.node.as_mut()?, rest),
+        ("qual", None) => descendant_mut(n.qual.as_deref_mut()?.node.as_mut()?, rest),
+        ("with_check", None) => descendant_mut(n.with_check.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_am_stmt<'a>(n: &'a mut protobuf::CreateAmStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateAmStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("handler_name", Some(i)) => descendant_mut(n.handler_name.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_trig_stmt<'a>(n: &'a mut protobuf::CreateTrigStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateTrigStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("funcname", Some(i)) => descendant_mut(n.funcname.get_mut(i)?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("columns", Some(i)) => descendant_mut(n.columns.get_mut(i)?.node.as_mut()?, rest),
+        ("when_clause", None) => descendant_mut(n.when_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("transition_rels", Some(i)) => descendant_mut(n.transition_rels.get_mut(i)?.node.as_mut()?, rest),
+        ("constrrel", None) => resolve_range_var(n.constrrel.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_event_trig_stmt<'a>(n: &'a mut protobuf::CreateEventTrigStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateEventTrigStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("whenclause", Some(i)) => descendant_mut(n.whenclause.get_mut(i)?.node.as_mut()?, rest),
+        ("funcname", Some(i)) => descendant_mut(n.funcname.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_p_lang_stmt<'a>(n: &'a mut protobuf::CreatePLangStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreatePlangStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("plhandler", Some(i)) => descendant_mut(n.plhandler.get_mut(i)?.node.as_mut()?, rest),
+        ("plinline", Some(i)) => descendant_mut(n.plinline.get_mut(i)?.node.as_mut()?, rest),
+        ("plvalidator", Some(i)) => descendant_mut(n.plvalidator.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_role_stmt<'a>(n: &'a mut protobuf::CreateRoleStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateRoleStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_role_stmt<'a>(n: &'a mut protobuf::AlterRoleStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterRoleStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_role_set_stmt<'a>(n: &'a mut protobuf::AlterRoleSetStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterRoleSetStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("setstmt", None) => resolve_variable_set_stmt(n.setstmt.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_drop_role_stmt<'a>(n: &'a mut protobuf::DropRoleStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DropRoleStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("roles", Some(i)) => descendant_mut(n.roles.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_seq_stmt<'a>(n: &'a mut protobuf::CreateSeqStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateSeqStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("sequence", None) => resolve_range_var(n.sequence.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_seq_stmt<'a>(n: &'a mut protobuf::AlterSeqStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterSeqStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("sequence", None) => resolve_range_var(n.sequence.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_define_stmt<'a>(n: &'a mut protobuf::DefineStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DefineStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("defnames", Some(i)) => descendant_mut(n.defnames.get_mut(i)?.node.as_mut()?, rest),
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        ("definition", Some(i)) => descendant_mut(n.definition.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_domain_stmt<'a>(n: &'a mut protobuf::CreateDomainStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateDomainStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("domainname", Some(i)) => descendant_mut(n.domainname.get_mut(i)?.node.as_mut()?, rest),
+        ("type_name", None) => resolve_type_name(n.type_name.as_mut()?, rest),
+        ("coll_clause", None) => resolve_collate_clause(n.coll_clause.as_deref_mut()?, rest),
+        ("constraints", Some(i)) => descendant_mut(n.constraints.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_op_class_stmt<'a>(n: &'a mut protobuf::CreateOpClassStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateOpClassStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("opclassname", Some(i)) => descendant_mut(n.opclassname.get_mut(i)?.node.as_mut()?, rest),
+        ("opfamilyname", Some(i)) => descendant_mut(n.opfamilyname.get_mut(i)?.node.as_mut()?, rest),
+        ("datatype", None) => resolve_type_name(n.datatype.as_mut()?, rest),
+        ("items", Some(i)) => descendant_mut(n.items.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_op_class_item<'a>(n: &'a mut protobuf::CreateOpClassItem, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateOpClassItem" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("name", None) => resolve_object_with_args(n.name.as_mut()?, rest),
+        ("order_family", Some(i)) => descendant_mut(n.order_family.get_mut(i)?.node.as_mut()?, rest),
+        ("class_args", Some(i)) => descendant_mut(n.class_args.get_mut(i)?.node.as_mut()?, rest),
+        ("storedtype", None) => resolve_type_name(n.storedtype.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_op_family_stmt<'a>(n: &'a mut protobuf::CreateOpFamilyStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateOpFamilyStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("opfamilyname", Some(i)) => descendant_mut(n.opfamilyname.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_op_family_stmt<'a>(n: &'a mut protobuf::AlterOpFamilyStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterOpFamilyStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("opfamilyname", Some(i)) => descendant_mut(n.opfamilyname.get_mut(i)?.node.as_mut()?, rest),
+        ("items", Some(i)) => descendant_mut(n.items.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_drop_stmt<'a>(n: &'a mut protobuf::DropStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DropStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("objects", Some(i)) => descendant_mut(n.objects.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_truncate_stmt<'a>(n: &'a mut protobuf::TruncateStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "TruncateStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relations", Some(i)) => descendant_mut(n.relations.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_comment_stmt<'a>(n: &'a mut protobuf::CommentStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CommentStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("object", None) => descendant_mut(n.object.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_sec_label_stmt<'a>(n: &'a mut protobuf::SecLabelStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "SecLabelStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("object", None) => descendant_mut(n.object.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_declare_cursor_stmt<'a>(n: &'a mut protobuf::DeclareCursorStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DeclareCursorStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("query", None) => descendant_mut(n.query.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_index_stmt<'a>(n: &'a mut protobuf::IndexStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "IndexStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("index_params", Some(i)) => descendant_mut(n.index_params.get_mut(i)?.node.as_mut()?, rest),
+        ("index_including_params", Some(i)) => descendant_mut(n.index_including_params.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("exclude_op_names", Some(i)) => descendant_mut(n.exclude_op_names.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_stats_stmt<'a>(n: &'a mut protobuf::CreateStatsStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateStatsStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("defnames", Some(i)) => descendant_mut(n.defnames.get_mut(i)?.node.as_mut()?, rest),
+        ("stat_types", Some(i)) => descendant_mut(n.stat_types.get_mut(i)?.node.as_mut()?, rest),
+        ("exprs", Some(i)) => descendant_mut(n.exprs.get_mut(i)?.node.as_mut()?, rest),
+        ("relations", Some(i)) => descendant_mut(n.relations.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_stats_elem<'a>(n: &'a mut protobuf::StatsElem, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "StatsElem" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("expr", None) => descendant_mut(n.expr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_stats_stmt<'a>(n: &'a mut protobuf::AlterStatsStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterStatsStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("defnames", Some(i)) => descendant_mut(n.defnames.get_mut(i)?.node.as_mut()?, rest),
+        ("stxstattarget", None) => descendant_mut(n.stxstattarget.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_function_stmt<'a>(n: &'a mut protobuf::CreateFunctionStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateFunctionStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("funcname", Some(i)) => descendant_mut(n.funcname.get_mut(i)?.node.as_mut()?, rest),
+        ("parameters", Some(i)) => descendant_mut(n.parameters.get_mut(i)?.node.as_mut()?, rest),
+        ("return_type", None) => resolve_type_name(n.return_type.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("sql_body", None) => descendant_mut(n.sql_body.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_function_parameter<'a>(n: &'a mut protobuf::FunctionParameter, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "FunctionParameter" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("arg_type", None) => resolve_type_name(n.arg_type.as_mut()?, rest),
+        ("defexpr", None) => descendant_mut(n.defexpr.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_function_stmt<'a>(n: &'a mut protobuf::AlterFunctionStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterFunctionStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("func", None) => resolve_object_with_args(n.func.as_mut()?, rest),
+        ("actions", Some(i)) => descendant_mut(n.actions.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_do_stmt<'a>(n: &'a mut protobuf::DoStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DoStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("args", Some(i)) => descendant_mut(n.args.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_call_stmt<'a>(n: &'a mut protobuf::CallStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CallStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("funccall", None) => resolve_func_call(n.funccall.as_deref_mut()?, rest),
+        ("funcexpr", None) => resolve_func_expr(n.funcexpr.as_deref_mut()?, rest),
+        ("outargs", Some(i)) => descendant_mut(n.outargs.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_rename_stmt<'a>(n: &'a mut protobuf::RenameStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RenameStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("object", None) => descendant_mut(n.object.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_object_depends_stmt<'a>(n: &'a mut protobuf::AlterObjectDependsStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterObjectDependsStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("object", None) => descendant_mut(n.object.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_object_schema_stmt<'a>(n: &'a mut protobuf::AlterObjectSchemaStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterObjectSchemaStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("object", None) => descendant_mut(n.object.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_owner_stmt<'a>(n: &'a mut protobuf::AlterOwnerStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterOwnerStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("object", None) => descendant_mut(n.object.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_operator_stmt<'a>(n: &'a mut protobuf::AlterOperatorStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterOperatorStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("opername", None) => resolve_object_with_args(n.opername.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_type_stmt<'a>(n: &'a mut protobuf::AlterTypeStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterTypeStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", Some(i)) => descendant_mut(n.type_name.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_rule_stmt<'a>(n: &'a mut protobuf::RuleStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RuleStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("actions", Some(i)) => descendant_mut(n.actions.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_transaction_stmt<'a>(n: &'a mut protobuf::TransactionStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "TransactionStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_composite_type_stmt<'a>(n: &'a mut protobuf::CompositeTypeStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CompositeTypeStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("typevar", None) => resolve_range_var(n.typevar.as_mut()?, rest),
+        ("coldeflist", Some(i)) => descendant_mut(n.coldeflist.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_enum_stmt<'a>(n: &'a mut protobuf::CreateEnumStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateEnumStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", Some(i)) => descendant_mut(n.type_name.get_mut(i)?.node.as_mut()?, rest),
+        ("vals", Some(i)) => descendant_mut(n.vals.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_range_stmt<'a>(n: &'a mut protobuf::CreateRangeStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateRangeStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", Some(i)) => descendant_mut(n.type_name.get_mut(i)?.node.as_mut()?, rest),
+        ("params", Some(i)) => descendant_mut(n.params.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_enum_stmt<'a>(n: &'a mut protobuf::AlterEnumStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterEnumStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", Some(i)) => descendant_mut(n.type_name.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_view_stmt<'a>(n: &'a mut protobuf::ViewStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ViewStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("view", None) => resolve_range_var(n.view.as_mut()?, rest),
+        ("aliases", Some(i)) => descendant_mut(n.aliases.get_mut(i)?.node.as_mut()?, rest),
+        ("query", None) => descendant_mut(n.query.as_deref_mut()?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_createdb_stmt<'a>(n: &'a mut protobuf::CreatedbStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreatedbStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_database_stmt<'a>(n: &'a mut protobuf::AlterDatabaseStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterDatabaseStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_database_set_stmt<'a>(n: &'a mut protobuf::AlterDatabaseSetStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterDatabaseSetStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("setstmt", None) => resolve_variable_set_stmt(n.setstmt.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_dropdb_stmt<'a>(n: &'a mut protobuf::DropdbStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DropdbStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_system_stmt<'a>(n: &'a mut protobuf::AlterSystemStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterSystemStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("setstmt", None) => resolve_variable_set_stmt(n.setstmt.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_cluster_stmt<'a>(n: &'a mut protobuf::ClusterStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ClusterStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("params", Some(i)) => descendant_mut(n.params.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_vacuum_stmt<'a>(n: &'a mut protobuf::VacuumStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "VacuumStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("rels", Some(i)) => descendant_mut(n.rels.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_vacuum_relation<'a>(n: &'a mut protobuf::VacuumRelation, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "VacuumRelation" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("va_cols", Some(i)) => descendant_mut(n.va_cols.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_explain_stmt<'a>(n: &'a mut protobuf::ExplainStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ExplainStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("query", None) => descendant_mut(n.query.as_deref_mut()?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_table_as_stmt<'a>(n: &'a mut protobuf::CreateTableAsStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateTableAsStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("query", None) => descendant_mut(n.query.as_deref_mut()?.node.as_mut()?, rest),
+        ("into", None) => resolve_into_clause(n.into.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_refresh_mat_view_stmt<'a>(n: &'a mut protobuf::RefreshMatViewStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "RefreshMatViewStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_lock_stmt<'a>(n: &'a mut protobuf::LockStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "LockStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relations", Some(i)) => descendant_mut(n.relations.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_constraints_set_stmt<'a>(n: &'a mut protobuf::ConstraintsSetStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ConstraintsSetStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("constraints", Some(i)) => descendant_mut(n.constraints.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_reindex_stmt<'a>(n: &'a mut protobuf::ReindexStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ReindexStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("params", Some(i)) => descendant_mut(n.params.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_conversion_stmt<'a>(n: &'a mut protobuf::CreateConversionStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateConversionStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("conversion_name", Some(i)) => descendant_mut(n.conversion_name.get_mut(i)?.node.as_mut()?, rest),
+        ("func_name", Some(i)) => descendant_mut(n.func_name.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_cast_stmt<'a>(n: &'a mut protobuf::CreateCastStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateCastStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("sourcetype", None) => resolve_type_name(n.sourcetype.as_mut()?, rest),
+        ("targettype", None) => resolve_type_name(n.targettype.as_mut()?, rest),
+        ("func", None) => resolve_object_with_args(n.func.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_transform_stmt<'a>(n: &'a mut protobuf::CreateTransformStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateTransformStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("type_name", None) => resolve_type_name(n.type_name.as_mut()?, rest),
+        ("fromsql", None) => resolve_object_with_args(n.fromsql.as_mut()?, rest),
+        ("tosql", None) => resolve_object_with_args(n.tosql.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_prepare_stmt<'a>(n: &'a mut protobuf::PrepareStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PrepareStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("argtypes", Some(i)) => descendant_mut(n.argtypes.get_mut(i)?.node.as_mut()?, rest),
+        ("query", None) => descendant_mut(n.query.as_deref_mut()?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_execute_stmt<'a>(n: &'a mut protobuf::ExecuteStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ExecuteStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("params", Some(i)) => descendant_mut(n.params.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_drop_owned_stmt<'a>(n: &'a mut protobuf::DropOwnedStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "DropOwnedStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("roles", Some(i)) => descendant_mut(n.roles.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_reassign_owned_stmt<'a>(n: &'a mut protobuf::ReassignOwnedStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "ReassignOwnedStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("roles", Some(i)) => descendant_mut(n.roles.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_ts_dictionary_stmt<'a>(n: &'a mut protobuf::AlterTsDictionaryStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterTsdictionaryStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("dictname", Some(i)) => descendant_mut(n.dictname.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_ts_configuration_stmt<'a>(n: &'a mut protobuf::AlterTsConfigurationStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterTsconfigurationStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("cfgname", Some(i)) => descendant_mut(n.cfgname.get_mut(i)?.node.as_mut()?, rest),
+        ("tokentype", Some(i)) => descendant_mut(n.tokentype.get_mut(i)?.node.as_mut()?, rest),
+        ("dicts", Some(i)) => descendant_mut(n.dicts.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_publication_table<'a>(n: &'a mut protobuf::PublicationTable, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PublicationTable" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("relation", None) => resolve_range_var(n.relation.as_mut()?, rest),
+        ("where_clause", None) => descendant_mut(n.where_clause.as_deref_mut()?.node.as_mut()?, rest),
+        ("columns", Some(i)) => descendant_mut(n.columns.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_publication_obj_spec<'a>(n: &'a mut protobuf::PublicationObjSpec, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "PublicationObjSpec" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("pubtable", None) => resolve_publication_table(n.pubtable.as_deref_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_publication_stmt<'a>(n: &'a mut protobuf::CreatePublicationStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreatePublicationStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("pubobjects", Some(i)) => descendant_mut(n.pubobjects.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_publication_stmt<'a>(n: &'a mut protobuf::AlterPublicationStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterPublicationStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        ("pubobjects", Some(i)) => descendant_mut(n.pubobjects.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_create_subscription_stmt<'a>(n: &'a mut protobuf::CreateSubscriptionStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "CreateSubscriptionStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("publication", Some(i)) => descendant_mut(n.publication.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_alter_subscription_stmt<'a>(n: &'a mut protobuf::AlterSubscriptionStmt, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "AlterSubscriptionStmt" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("publication", Some(i)) => descendant_mut(n.publication.get_mut(i)?.node.as_mut()?, rest),
+        ("options", Some(i)) => descendant_mut(n.options.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_list<'a>(n: &'a mut protobuf::List, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "List" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("items", Some(i)) => descendant_mut(n.items.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_int_list<'a>(n: &'a mut protobuf::IntList, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "IntList" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("items", Some(i)) => descendant_mut(n.items.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+
+fn resolve_oid_list<'a>(n: &'a mut protobuf::OidList, steps: &[PathStep]) -> Option<&'a mut NodeEnum> {
+    let (step, rest) = steps.split_first()?;
+    if step.node_type != "OidList" {
+        return None;
+    }
+    match (step.field, step.index) {
+        ("items", Some(i)) => descendant_mut(n.items.get_mut(i)?.node.as_mut()?, rest),
+        _ => None,
+    }
+}
+// END GENERATED
@@ -273,14 +273,560 @@ pub enum NodeRef<'a> {
 }
 
 impl<'a> NodeRef<'a> {
-    // TODO: `deparseStmt` in pg_query_deparse.c panics on unexpected nodes. instead, return a Rust error
     pub fn deparse(&self) -> Result<String> {
-        crate::deparse(&protobuf::ParseResult {
-            version: crate::bindings::PG_VERSION_NUM as i32,
-            stmts: vec![protobuf::RawStmt { stmt: Some(Box::new(Node { node: Some(self.to_enum()) })), stmt_location: 0, stmt_len: 0 }],
-        })
+        self.to_enum().deparse()
     }
 
+    // BEGIN GENERATED by scripts/generate.py from src/protobuf.rs, do not edit by hand
+    /// The name of the node type, e.g. `SelectStmt`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            NodeRef::Alias(_) => "Alias",
+            NodeRef::RangeVar(_) => "RangeVar",
+            NodeRef::TableFunc(_) => "TableFunc",
+            NodeRef::IntoClause(_) => "IntoClause",
+            NodeRef::Var(_) => "Var",
+            NodeRef::Param(_) => "Param",
+            NodeRef::Aggref(_) => "Aggref",
+            NodeRef::GroupingFunc(_) => "GroupingFunc",
+            NodeRef::WindowFunc(_) => "WindowFunc",
+            NodeRef::WindowFuncRunCondition(_) => "WindowFuncRunCondition",
+            NodeRef::MergeSupportFunc(_) => "MergeSupportFunc",
+            NodeRef::SubscriptingRef(_) => "SubscriptingRef",
+            NodeRef::FuncExpr(_) => "FuncExpr",
+            NodeRef::NamedArgExpr(_) => "NamedArgExpr",
+            NodeRef::OpExpr(_) => "OpExpr",
+            NodeRef::DistinctExpr(_) => "DistinctExpr",
+            NodeRef::NullIfExpr(_) => "NullIfExpr",
+            NodeRef::ScalarArrayOpExpr(_) => "ScalarArrayOpExpr",
+            NodeRef::BoolExpr(_) => "BoolExpr",
+            NodeRef::SubLink(_) => "SubLink",
+            NodeRef::SubPlan(_) => "SubPlan",
+            NodeRef::AlternativeSubPlan(_) => "AlternativeSubPlan",
+            NodeRef::FieldSelect(_) => "FieldSelect",
+            NodeRef::FieldStore(_) => "FieldStore",
+            NodeRef::RelabelType(_) => "RelabelType",
+            NodeRef::CoerceViaIo(_) => "CoerceViaIo",
+            NodeRef::ArrayCoerceExpr(_) => "ArrayCoerceExpr",
+            NodeRef::ConvertRowtypeExpr(_) => "ConvertRowtypeExpr",
+            NodeRef::CollateExpr(_) => "CollateExpr",
+            NodeRef::CaseExpr(_) => "CaseExpr",
+            NodeRef::CaseWhen(_) => "CaseWhen",
+            NodeRef::CaseTestExpr(_) => "CaseTestExpr",
+            NodeRef::ArrayExpr(_) => "ArrayExpr",
+            NodeRef::RowExpr(_) => "RowExpr",
+            NodeRef::RowCompareExpr(_) => "RowCompareExpr",
+            NodeRef::CoalesceExpr(_) => "CoalesceExpr",
+            NodeRef::MinMaxExpr(_) => "MinMaxExpr",
+            NodeRef::SqlvalueFunction(_) => "SqlvalueFunction",
+            NodeRef::XmlExpr(_) => "XmlExpr",
+            NodeRef::JsonFormat(_) => "JsonFormat",
+            NodeRef::JsonReturning(_) => "JsonReturning",
+            NodeRef::JsonValueExpr(_) => "JsonValueExpr",
+            NodeRef::JsonConstructorExpr(_) => "JsonConstructorExpr",
+            NodeRef::JsonIsPredicate(_) => "JsonIsPredicate",
+            NodeRef::JsonBehavior(_) => "JsonBehavior",
+            NodeRef::JsonExpr(_) => "JsonExpr",
+            NodeRef::JsonTablePath(_) => "JsonTablePath",
+            NodeRef::JsonTablePathScan(_) => "JsonTablePathScan",
+            NodeRef::JsonTableSiblingJoin(_) => "JsonTableSiblingJoin",
+            NodeRef::NullTest(_) => "NullTest",
+            NodeRef::BooleanTest(_) => "BooleanTest",
+            NodeRef::MergeAction(_) => "MergeAction",
+            NodeRef::CoerceToDomain(_) => "CoerceToDomain",
+            NodeRef::CoerceToDomainValue(_) => "CoerceToDomainValue",
+            NodeRef::SetToDefault(_) => "SetToDefault",
+            NodeRef::CurrentOfExpr(_) => "CurrentOfExpr",
+            NodeRef::NextValueExpr(_) => "NextValueExpr",
+            NodeRef::InferenceElem(_) => "InferenceElem",
+            NodeRef::TargetEntry(_) => "TargetEntry",
+            NodeRef::RangeTblRef(_) => "RangeTblRef",
+            NodeRef::JoinExpr(_) => "JoinExpr",
+            NodeRef::FromExpr(_) => "FromExpr",
+            NodeRef::OnConflictExpr(_) => "OnConflictExpr",
+            NodeRef::Query(_) => "Query",
+            NodeRef::TypeName(_) => "TypeName",
+            NodeRef::ColumnRef(_) => "ColumnRef",
+            NodeRef::ParamRef(_) => "ParamRef",
+            NodeRef::AExpr(_) => "AExpr",
+            NodeRef::TypeCast(_) => "TypeCast",
+            NodeRef::CollateClause(_) => "CollateClause",
+            NodeRef::RoleSpec(_) => "RoleSpec",
+            NodeRef::FuncCall(_) => "FuncCall",
+            NodeRef::AStar(_) => "AStar",
+            NodeRef::AIndices(_) => "AIndices",
+            NodeRef::AIndirection(_) => "AIndirection",
+            NodeRef::AArrayExpr(_) => "AArrayExpr",
+            NodeRef::ResTarget(_) => "ResTarget",
+            NodeRef::MultiAssignRef(_) => "MultiAssignRef",
+            NodeRef::SortBy(_) => "SortBy",
+            NodeRef::WindowDef(_) => "WindowDef",
+            NodeRef::RangeSubselect(_) => "RangeSubselect",
+            NodeRef::RangeFunction(_) => "RangeFunction",
+            NodeRef::RangeTableFunc(_) => "RangeTableFunc",
+            NodeRef::RangeTableFuncCol(_) => "RangeTableFuncCol",
+            NodeRef::RangeTableSample(_) => "RangeTableSample",
+            NodeRef::ColumnDef(_) => "ColumnDef",
+            NodeRef::TableLikeClause(_) => "TableLikeClause",
+            NodeRef::IndexElem(_) => "IndexElem",
+            NodeRef::DefElem(_) => "DefElem",
+            NodeRef::LockingClause(_) => "LockingClause",
+            NodeRef::XmlSerialize(_) => "XmlSerialize",
+            NodeRef::PartitionElem(_) => "PartitionElem",
+            NodeRef::PartitionSpec(_) => "PartitionSpec",
+            NodeRef::PartitionBoundSpec(_) => "PartitionBoundSpec",
+            NodeRef::PartitionRangeDatum(_) => "PartitionRangeDatum",
+            NodeRef::SinglePartitionSpec(_) => "SinglePartitionSpec",
+            NodeRef::PartitionCmd(_) => "PartitionCmd",
+            NodeRef::RangeTblEntry(_) => "RangeTblEntry",
+            NodeRef::RtepermissionInfo(_) => "RtepermissionInfo",
+            NodeRef::RangeTblFunction(_) => "RangeTblFunction",
+            NodeRef::TableSampleClause(_) => "TableSampleClause",
+            NodeRef::WithCheckOption(_) => "WithCheckOption",
+            NodeRef::SortGroupClause(_) => "SortGroupClause",
+            NodeRef::GroupingSet(_) => "GroupingSet",
+            NodeRef::WindowClause(_) => "WindowClause",
+            NodeRef::RowMarkClause(_) => "RowMarkClause",
+            NodeRef::WithClause(_) => "WithClause",
+            NodeRef::InferClause(_) => "InferClause",
+            NodeRef::OnConflictClause(_) => "OnConflictClause",
+            NodeRef::CtesearchClause(_) => "CtesearchClause",
+            NodeRef::CtecycleClause(_) => "CtecycleClause",
+            NodeRef::CommonTableExpr(_) => "CommonTableExpr",
+            NodeRef::MergeWhenClause(_) => "MergeWhenClause",
+            NodeRef::TriggerTransition(_) => "TriggerTransition",
+            NodeRef::JsonOutput(_) => "JsonOutput",
+            NodeRef::JsonArgument(_) => "JsonArgument",
+            NodeRef::JsonFuncExpr(_) => "JsonFuncExpr",
+            NodeRef::JsonTablePathSpec(_) => "JsonTablePathSpec",
+            NodeRef::JsonTable(_) => "JsonTable",
+            NodeRef::JsonTableColumn(_) => "JsonTableColumn",
+            NodeRef::JsonKeyValue(_) => "JsonKeyValue",
+            NodeRef::JsonParseExpr(_) => "JsonParseExpr",
+            NodeRef::JsonScalarExpr(_) => "JsonScalarExpr",
+            NodeRef::JsonSerializeExpr(_) => "JsonSerializeExpr",
+            NodeRef::JsonObjectConstructor(_) => "JsonObjectConstructor",
+            NodeRef::JsonArrayConstructor(_) => "JsonArrayConstructor",
+            NodeRef::JsonArrayQueryConstructor(_) => "JsonArrayQueryConstructor",
+            NodeRef::JsonAggConstructor(_) => "JsonAggConstructor",
+            NodeRef::JsonObjectAgg(_) => "JsonObjectAgg",
+            NodeRef::JsonArrayAgg(_) => "JsonArrayAgg",
+            NodeRef::RawStmt(_) => "RawStmt",
+            NodeRef::InsertStmt(_) => "InsertStmt",
+            NodeRef::DeleteStmt(_) => "DeleteStmt",
+            NodeRef::UpdateStmt(_) => "UpdateStmt",
+            NodeRef::MergeStmt(_) => "MergeStmt",
+            NodeRef::SelectStmt(_) => "SelectStmt",
+            NodeRef::SetOperationStmt(_) => "SetOperationStmt",
+            NodeRef::ReturnStmt(_) => "ReturnStmt",
+            NodeRef::PlassignStmt(_) => "PlassignStmt",
+            NodeRef::CreateSchemaStmt(_) => "CreateSchemaStmt",
+            NodeRef::AlterTableStmt(_) => "AlterTableStmt",
+            NodeRef::ReplicaIdentityStmt(_) => "ReplicaIdentityStmt",
+            NodeRef::AlterTableCmd(_) => "AlterTableCmd",
+            NodeRef::AlterCollationStmt(_) => "AlterCollationStmt",
+            NodeRef::AlterDomainStmt(_) => "AlterDomainStmt",
+            NodeRef::GrantStmt(_) => "GrantStmt",
+            NodeRef::ObjectWithArgs(_) => "ObjectWithArgs",
+            NodeRef::AccessPriv(_) => "AccessPriv",
+            NodeRef::GrantRoleStmt(_) => "GrantRoleStmt",
+            NodeRef::AlterDefaultPrivilegesStmt(_) => "AlterDefaultPrivilegesStmt",
+            NodeRef::CopyStmt(_) => "CopyStmt",
+            NodeRef::VariableSetStmt(_) => "VariableSetStmt",
+            NodeRef::VariableShowStmt(_) => "VariableShowStmt",
+            NodeRef::CreateStmt(_) => "CreateStmt",
+            NodeRef::Constraint(_) => "Constraint",
+            NodeRef::CreateTableSpaceStmt(_) => "CreateTableSpaceStmt",
+            NodeRef::DropTableSpaceStmt(_) => "DropTableSpaceStmt",
+            NodeRef::AlterTableSpaceOptionsStmt(_) => "AlterTableSpaceOptionsStmt",
+            NodeRef::AlterTableMoveAllStmt(_) => "AlterTableMoveAllStmt",
+            NodeRef::CreateExtensionStmt(_) => "CreateExtensionStmt",
+            NodeRef::AlterExtensionStmt(_) => "AlterExtensionStmt",
+            NodeRef::AlterExtensionContentsStmt(_) => "AlterExtensionContentsStmt",
+            NodeRef::CreateFdwStmt(_) => "CreateFdwStmt",
+            NodeRef::AlterFdwStmt(_) => "AlterFdwStmt",
+            NodeRef::CreateForeignServerStmt(_) => "CreateForeignServerStmt",
+            NodeRef::AlterForeignServerStmt(_) => "AlterForeignServerStmt",
+            NodeRef::CreateForeignTableStmt(_) => "CreateForeignTableStmt",
+            NodeRef::CreateUserMappingStmt(_) => "CreateUserMappingStmt",
+            NodeRef::AlterUserMappingStmt(_) => "AlterUserMappingStmt",
+            NodeRef::DropUserMappingStmt(_) => "DropUserMappingStmt",
+            NodeRef::ImportForeignSchemaStmt(_) => "ImportForeignSchemaStmt",
+            NodeRef::CreatePolicyStmt(_) => "CreatePolicyStmt",
+            NodeRef::AlterPolicyStmt(_) => "AlterPolicyStmt",
+            NodeRef::CreateAmStmt(_) => "CreateAmStmt",
+            NodeRef::CreateTrigStmt(_) => "CreateTrigStmt",
+            NodeRef::CreateEventTrigStmt(_) => "CreateEventTrigStmt",
+            NodeRef::AlterEventTrigStmt(_) => "AlterEventTrigStmt",
+            NodeRef::CreatePlangStmt(_) => "CreatePlangStmt",
+            NodeRef::CreateRoleStmt(_) => "CreateRoleStmt",
+            NodeRef::AlterRoleStmt(_) => "AlterRoleStmt",
+            NodeRef::AlterRoleSetStmt(_) => "AlterRoleSetStmt",
+            NodeRef::DropRoleStmt(_) => "DropRoleStmt",
+            NodeRef::CreateSeqStmt(_) => "CreateSeqStmt",
+            NodeRef::AlterSeqStmt(_) => "AlterSeqStmt",
+            NodeRef::DefineStmt(_) => "DefineStmt",
+            NodeRef::CreateDomainStmt(_) => "CreateDomainStmt",
+            NodeRef::CreateOpClassStmt(_) => "CreateOpClassStmt",
+            NodeRef::CreateOpClassItem(_) => "CreateOpClassItem",
+            NodeRef::CreateOpFamilyStmt(_) => "CreateOpFamilyStmt",
+            NodeRef::AlterOpFamilyStmt(_) => "AlterOpFamilyStmt",
+            NodeRef::DropStmt(_) => "DropStmt",
+            NodeRef::TruncateStmt(_) => "TruncateStmt",
+            NodeRef::CommentStmt(_) => "CommentStmt",
+            NodeRef::SecLabelStmt(_) => "SecLabelStmt",
+            NodeRef::DeclareCursorStmt(_) => "DeclareCursorStmt",
+            NodeRef::ClosePortalStmt(_) => "ClosePortalStmt",
+            NodeRef::FetchStmt(_) => "FetchStmt",
+            NodeRef::IndexStmt(_) => "IndexStmt",
+            NodeRef::CreateStatsStmt(_) => "CreateStatsStmt",
+            NodeRef::StatsElem(_) => "StatsElem",
+            NodeRef::AlterStatsStmt(_) => "AlterStatsStmt",
+            NodeRef::CreateFunctionStmt(_) => "CreateFunctionStmt",
+            NodeRef::FunctionParameter(_) => "FunctionParameter",
+            NodeRef::AlterFunctionStmt(_) => "AlterFunctionStmt",
+            NodeRef::DoStmt(_) => "DoStmt",
+            NodeRef::InlineCodeBlock(_) => "InlineCodeBlock",
+            NodeRef::CallStmt(_) => "CallStmt",
+            NodeRef::CallContext(_) => "CallContext",
+            NodeRef::RenameStmt(_) => "RenameStmt",
+            NodeRef::AlterObjectDependsStmt(_) => "AlterObjectDependsStmt",
+            NodeRef::AlterObjectSchemaStmt(_) => "AlterObjectSchemaStmt",
+            NodeRef::AlterOwnerStmt(_) => "AlterOwnerStmt",
+            NodeRef::AlterOperatorStmt(_) => "AlterOperatorStmt",
+            NodeRef::AlterTypeStmt(_) => "AlterTypeStmt",
+            NodeRef::RuleStmt(_) => "RuleStmt",
+            NodeRef::NotifyStmt(_) => "NotifyStmt",
+            NodeRef::ListenStmt(_) => "ListenStmt",
+            NodeRef::UnlistenStmt(_) => "UnlistenStmt",
+            NodeRef::TransactionStmt(_) => "TransactionStmt",
+            NodeRef::CompositeTypeStmt(_) => "CompositeTypeStmt",
+            NodeRef::CreateEnumStmt(_) => "CreateEnumStmt",
+            NodeRef::CreateRangeStmt(_) => "CreateRangeStmt",
+            NodeRef::AlterEnumStmt(_) => "AlterEnumStmt",
+            NodeRef::ViewStmt(_) => "ViewStmt",
+            NodeRef::LoadStmt(_) => "LoadStmt",
+            NodeRef::CreatedbStmt(_) => "CreatedbStmt",
+            NodeRef::AlterDatabaseStmt(_) => "AlterDatabaseStmt",
+            NodeRef::AlterDatabaseRefreshCollStmt(_) => "AlterDatabaseRefreshCollStmt",
+            NodeRef::AlterDatabaseSetStmt(_) => "AlterDatabaseSetStmt",
+            NodeRef::DropdbStmt(_) => "DropdbStmt",
+            NodeRef::AlterSystemStmt(_) => "AlterSystemStmt",
+            NodeRef::ClusterStmt(_) => "ClusterStmt",
+            NodeRef::VacuumStmt(_) => "VacuumStmt",
+            NodeRef::VacuumRelation(_) => "VacuumRelation",
+            NodeRef::ExplainStmt(_) => "ExplainStmt",
+            NodeRef::CreateTableAsStmt(_) => "CreateTableAsStmt",
+            NodeRef::RefreshMatViewStmt(_) => "RefreshMatViewStmt",
+            NodeRef::CheckPointStmt(_) => "CheckPointStmt",
+            NodeRef::DiscardStmt(_) => "DiscardStmt",
+            NodeRef::LockStmt(_) => "LockStmt",
+            NodeRef::ConstraintsSetStmt(_) => "ConstraintsSetStmt",
+            NodeRef::ReindexStmt(_) => "ReindexStmt",
+            NodeRef::CreateConversionStmt(_) => "CreateConversionStmt",
+            NodeRef::CreateCastStmt(_) => "CreateCastStmt",
+            NodeRef::CreateTransformStmt(_) => "CreateTransformStmt",
+            NodeRef::PrepareStmt(_) => "PrepareStmt",
+            NodeRef::ExecuteStmt(_) => "ExecuteStmt",
+            NodeRef::DeallocateStmt(_) => "DeallocateStmt",
+            NodeRef::DropOwnedStmt(_) => "DropOwnedStmt",
+            NodeRef::ReassignOwnedStmt(_) => "ReassignOwnedStmt",
+            NodeRef::AlterTsdictionaryStmt(_) => "AlterTsdictionaryStmt",
+            NodeRef::AlterTsconfigurationStmt(_) => "AlterTsconfigurationStmt",
+            NodeRef::PublicationTable(_) => "PublicationTable",
+            NodeRef::PublicationObjSpec(_) => "PublicationObjSpec",
+            NodeRef::CreatePublicationStmt(_) => "CreatePublicationStmt",
+            NodeRef::AlterPublicationStmt(_) => "AlterPublicationStmt",
+            NodeRef::CreateSubscriptionStmt(_) => "CreateSubscriptionStmt",
+            NodeRef::AlterSubscriptionStmt(_) => "AlterSubscriptionStmt",
+            NodeRef::DropSubscriptionStmt(_) => "DropSubscriptionStmt",
+            NodeRef::Integer(_) => "Integer",
+            NodeRef::Float(_) => "Float",
+            NodeRef::Boolean(_) => "Boolean",
+            NodeRef::String(_) => "String",
+            NodeRef::BitString(_) => "BitString",
+            NodeRef::List(_) => "List",
+            NodeRef::IntList(_) => "IntList",
+            NodeRef::OidList(_) => "OidList",
+            NodeRef::AConst(_) => "AConst",
+        }
+    }
+
+    // The address of the node, which identifies it within a tree together with its type
+    pub(crate) fn as_ptr(&self) -> *const () {
+        match self {
+            NodeRef::Alias(n) => (*n as *const protobuf::Alias).cast(),
+            NodeRef::RangeVar(n) => (*n as *const protobuf::RangeVar).cast(),
+            NodeRef::TableFunc(n) => (*n as *const protobuf::TableFunc).cast(),
+            NodeRef::IntoClause(n) => (*n as *const protobuf::IntoClause).cast(),
+            NodeRef::Var(n) => (*n as *const protobuf::Var).cast(),
+            NodeRef::Param(n) => (*n as *const protobuf::Param).cast(),
+            NodeRef::Aggref(n) => (*n as *const protobuf::Aggref).cast(),
+            NodeRef::GroupingFunc(n) => (*n as *const protobuf::GroupingFunc).cast(),
+            NodeRef::WindowFunc(n) => (*n as *const protobuf::WindowFunc).cast(),
+            NodeRef::WindowFuncRunCondition(n) => (*n as *const protobuf::WindowFuncRunCondition).cast(),
+            NodeRef::MergeSupportFunc(n) => (*n as *const protobuf::MergeSupportFunc).cast(),
+            NodeRef::SubscriptingRef(n) => (*n as *const protobuf::SubscriptingRef).cast(),
+            NodeRef::FuncExpr(n) => (*n as *const protobuf::FuncExpr).cast(),
+            NodeRef::NamedArgExpr(n) => (*n as *const protobuf::NamedArgExpr).cast(),
+            NodeRef::OpExpr(n) => (*n as *const protobuf::OpExpr).cast(),
+            NodeRef::DistinctExpr(n) => (*n as *const protobuf::DistinctExpr).cast(),
+            NodeRef::NullIfExpr(n) => (*n as *const protobuf::NullIfExpr).cast(),
+            NodeRef::ScalarArrayOpExpr(n) => (*n as *const protobuf::ScalarArrayOpExpr).cast(),
+            NodeRef::BoolExpr(n) => (*n as *const protobuf::BoolExpr).cast(),
+            NodeRef::SubLink(n) => (*n as *const protobuf::SubLink).cast(),
+            NodeRef::SubPlan(n) => (*n as *const protobuf::SubPlan).cast(),
+            NodeRef::AlternativeSubPlan(n) => (*n as *const protobuf::AlternativeSubPlan).cast(),
+            NodeRef::FieldSelect(n) => (*n as *const protobuf::FieldSelect).cast(),
+            NodeRef::FieldStore(n) => (*n as *const protobuf::FieldStore).cast(),
+            NodeRef::RelabelType(n) => (*n as *const protobuf::RelabelType).cast(),
+            NodeRef::CoerceViaIo(n) => (*n as *const protobuf::CoerceViaIo).cast(),
+            NodeRef::ArrayCoerceExpr(n) => (*n as *const protobuf::ArrayCoerceExpr).cast(),
+            NodeRef::ConvertRowtypeExpr(n) => (*n as *const protobuf::ConvertRowtypeExpr).cast(),
+            NodeRef::CollateExpr(n) => (*n as *const protobuf::CollateExpr).cast(),
+            NodeRef::CaseExpr(n) => (*n as *const protobuf::CaseExpr).cast(),
+            NodeRef::CaseWhen(n) => (*n as *const protobuf::CaseWhen).cast(),
+            NodeRef::CaseTestExpr(n) => (*n as *const protobuf::CaseTestExpr).cast(),
+            NodeRef::ArrayExpr(n) => (*n as *const protobuf::ArrayExpr).cast(),
+            NodeRef::RowExpr(n) => (*n as *const protobuf::RowExpr).cast(),
+            NodeRef::RowCompareExpr(n) => (*n as *const protobuf::RowCompareExpr).cast(),
+            NodeRef::CoalesceExpr(n) => (*n as *const protobuf::CoalesceExpr).cast(),
+            NodeRef::MinMaxExpr(n) => (*n as *const protobuf::MinMaxExpr).cast(),
+            NodeRef::SqlvalueFunction(n) => (*n as *const protobuf::SqlValueFunction).cast(),
+            NodeRef::XmlExpr(n) => (*n as *const protobuf::XmlExpr).cast(),
+            NodeRef::JsonFormat(n) => (*n as *const protobuf::JsonFormat).cast(),
+            NodeRef::JsonReturning(n) => (*n as *const protobuf::JsonReturning).cast(),
+            NodeRef::JsonValueExpr(n) => (*n as *const protobuf::JsonValueExpr).cast(),
+            NodeRef::JsonConstructorExpr(n) => (*n as *const protobuf::JsonConstructorExpr).cast(),
+            NodeRef::JsonIsPredicate(n) => (*n as *const protobuf::JsonIsPredicate).cast(),
+            NodeRef::JsonBehavior(n) => (*n as *const protobuf::JsonBehavior).cast(),
+            NodeRef::JsonExpr(n) => (*n as *const protobuf::JsonExpr).cast(),
+            NodeRef::JsonTablePath(n) => (*n as *const protobuf::JsonTablePath).cast(),
+            NodeRef::JsonTablePathScan(n) => (*n as *const protobuf::JsonTablePathScan).cast(),
+            NodeRef::JsonTableSiblingJoin(n) => (*n as *const protobuf::JsonTableSiblingJoin).cast(),
+            NodeRef::NullTest(n) => (*n as *const protobuf::NullTest).cast(),
+            NodeRef::BooleanTest(n) => (*n as *const protobuf::BooleanTest).cast(),
+            NodeRef::MergeAction(n) => (*n as *const protobuf::MergeAction).cast(),
+            NodeRef::CoerceToDomain(n) => (*n as *const protobuf::CoerceToDomain).cast(),
+            NodeRef::CoerceToDomainValue(n) => (*n as *const protobuf::CoerceToDomainValue).cast(),
+            NodeRef::SetToDefault(n) => (*n as *const protobuf::SetToDefault).cast(),
+            NodeRef::CurrentOfExpr(n) => (*n as *const protobuf::CurrentOfExpr).cast(),
+            NodeRef::NextValueExpr(n) => (*n as *const protobuf::NextValueExpr).cast(),
+            NodeRef::InferenceElem(n) => (*n as *const protobuf::InferenceElem).cast(),
+            NodeRef::TargetEntry(n) => (*n as *const protobuf::TargetEntry).cast(),
+            NodeRef::RangeTblRef(n) => (*n as *const protobuf::RangeTblRef).cast(),
+            NodeRef::JoinExpr(n) => (*n as *const protobuf::JoinExpr).cast(),
+            NodeRef::FromExpr(n) => (*n as *const protobuf::FromExpr).cast(),
+            NodeRef::OnConflictExpr(n) => (*n as *const protobuf::OnConflictExpr).cast(),
+            NodeRef::Query(n) => (*n as *const protobuf::Query).cast(),
+            NodeRef::TypeName(n) => (*n as *const protobuf::TypeName).cast(),
+            NodeRef::ColumnRef(n) => (*n as *const protobuf::ColumnRef).cast(),
+            NodeRef::ParamRef(n) => (*n as *const protobuf::ParamRef).cast(),
+            NodeRef::AExpr(n) => (*n as *const protobuf::AExpr).cast(),
+            NodeRef::TypeCast(n) => (*n as *const protobuf::TypeCast).cast(),
+            NodeRef::CollateClause(n) => (*n as *const protobuf::CollateClause).cast(),
+            NodeRef::RoleSpec(n) => (*n as *const protobuf::RoleSpec).cast(),
+            NodeRef::FuncCall(n) => (*n as *const protobuf::FuncCall).cast(),
+            NodeRef::AStar(n) => (*n as *const protobuf::AStar).cast(),
+            NodeRef::AIndices(n) => (*n as *const protobuf::AIndices).cast(),
+            NodeRef::AIndirection(n) => (*n as *const protobuf::AIndirection).cast(),
+            NodeRef::AArrayExpr(n) => (*n as *const protobuf::AArrayExpr).cast(),
+            NodeRef::ResTarget(n) => (*n as *const protobuf::ResTarget).cast(),
+            NodeRef::MultiAssignRef(n) => (*n as *const protobuf::MultiAssignRef).cast(),
+            NodeRef::SortBy(n) => (*n as *const protobuf::SortBy).cast(),
+            NodeRef::WindowDef(n) => (*n as *const protobuf::WindowDef).cast(),
+            NodeRef::RangeSubselect(n) => (*n as *const protobuf::RangeSubselect).cast(),
+            NodeRef::RangeFunction(n) => (*n as *const protobuf::RangeFunction).cast(),
+            NodeRef::RangeTableFunc(n) => (*n as *const protobuf::RangeTableFunc).cast(),
+            NodeRef::RangeTableFuncCol(n) => (*n as *const protobuf::RangeTableFuncCol).cast(),
+            NodeRef::RangeTableSample(n) => (*n as *const protobuf::RangeTableSample).cast(),
+            NodeRef::ColumnDef(n) => (*n as *const protobuf::ColumnDef).cast(),
+            NodeRef::TableLikeClause(n) => (*n as *const protobuf::TableLikeClause).cast(),
+            NodeRef::IndexElem(n) => (*n as *const protobuf::IndexElem).cast(),
+            NodeRef::DefElem(n) => (*n as *const protobuf::DefElem).cast(),
+            NodeRef::LockingClause(n) => (*n as *const protobuf::LockingClause).cast(),
+            NodeRef::XmlSerialize(n) => (*n as *const protobuf::XmlSerialize).cast(),
+            NodeRef::PartitionElem(n) => (*n as *const protobuf::PartitionElem).cast(),
+            NodeRef::PartitionSpec(n) => (*n as *const protobuf::PartitionSpec).cast(),
+            NodeRef::PartitionBoundSpec(n) => (*n as *const protobuf::PartitionBoundSpec).cast(),
+            NodeRef::PartitionRangeDatum(n) => (*n as *const protobuf::PartitionRangeDatum).cast(),
+            NodeRef::SinglePartitionSpec(n) => (*n as *const protobuf::SinglePartitionSpec).cast(),
+            NodeRef::PartitionCmd(n) => (*n as *const protobuf::PartitionCmd).cast(),
+            NodeRef::RangeTblEntry(n) => (*n as *const protobuf::RangeTblEntry).cast(),
+            NodeRef::RtepermissionInfo(n) => (*n as *const protobuf::RtePermissionInfo).cast(),
+            NodeRef::RangeTblFunction(n) => (*n as *const protobuf::RangeTblFunction).cast(),
+            NodeRef::TableSampleClause(n) => (*n as *const protobuf::TableSampleClause).cast(),
+            NodeRef::WithCheckOption(n) => (*n as *const protobuf::WithCheckOption).cast(),
+            NodeRef::SortGroupClause(n) => (*n as *const protobuf::SortGroupClause).cast(),
+            NodeRef::GroupingSet(n) => (*n as *const protobuf::GroupingSet).cast(),
+            NodeRef::WindowClause(n) => (*n as *const protobuf::WindowClause).cast(),
+            NodeRef::RowMarkClause(n) => (*n as *const protobuf::RowMarkClause).cast(),
+            NodeRef::WithClause(n) => (*n as *const protobuf::WithClause).cast(),
+            NodeRef::InferClause(n) => (*n as *const protobuf::InferClause).cast(),
+            NodeRef::OnConflictClause(n) => (*n as *const protobuf::OnConflictClause).cast(),
+            NodeRef::CtesearchClause(n) => (*n as *const protobuf::CteSearchClause).cast(),
+            NodeRef::CtecycleClause(n) => (*n as *const protobuf::CteCycleClause).cast(),
+            NodeRef::CommonTableExpr(n) => (*n as *const protobuf::CommonTableExpr).cast(),
+            NodeRef::MergeWhenClause(n) => (*n as *const protobuf::MergeWhenClause).cast(),
+            NodeRef::TriggerTransition(n) => (*n as *const protobuf::TriggerTransition).cast(),
+            NodeRef::JsonOutput(n) => (*n as *const protobuf::JsonOutput).cast(),
+            NodeRef::JsonArgument(n) => (*n as *const protobuf::JsonArgument).cast(),
+            NodeRef::JsonFuncExpr(n) => (*n as *const protobuf::JsonFuncExpr).cast(),
+            NodeRef::JsonTablePathSpec(n) => (*n as *const protobuf::JsonTablePathSpec).cast(),
+            NodeRef::JsonTable(n) => (*n as *const protobuf::JsonTable).cast(),
+            NodeRef::JsonTableColumn(n) => (*n as *const protobuf::JsonTableColumn).cast(),
+            NodeRef::JsonKeyValue(n) => (*n as *const protobuf::JsonKeyValue).cast(),
+            NodeRef::JsonParseExpr(n) => (*n as *const protobuf::JsonParseExpr).cast(),
+            NodeRef::JsonScalarExpr(n) => (*n as *const protobuf::JsonScalarExpr).cast(),
+            NodeRef::JsonSerializeExpr(n) => (*n as *const protobuf::JsonSerializeExpr).cast(),
+            NodeRef::JsonObjectConstructor(n) => (*n as *const protobuf::JsonObjectConstructor).cast(),
+            NodeRef::JsonArrayConstructor(n) => (*n as *const protobuf::JsonArrayConstructor).cast(),
+            NodeRef::JsonArrayQueryConstructor(n) => (*n as *const protobuf::JsonArrayQueryConstructor).cast(),
+            NodeRef::JsonAggConstructor(n) => (*n as *const protobuf::JsonAggConstructor).cast(),
+            NodeRef::JsonObjectAgg(n) => (*n as *const protobuf::JsonObjectAgg).cast(),
+            NodeRef::JsonArrayAgg(n) => (*n as *const protobuf::JsonArrayAgg).cast(),
+            NodeRef::RawStmt(n) => (*n as *const protobuf::RawStmt).cast(),
+            NodeRef::InsertStmt(n) => (*n as *const protobuf::InsertStmt).cast(),
+            NodeRef::DeleteStmt(n) => (*n as *const protobuf::DeleteStmt).cast(),
+            NodeRef::UpdateStmt(n) => (*n as *const protobuf::UpdateStmt).cast(),
+            NodeRef::MergeStmt(n) => (*n as *const protobuf::MergeStmt).cast(),
+            NodeRef::SelectStmt(n) => (*n as *const protobuf::SelectStmt).cast(),
+            NodeRef::SetOperationStmt(n) => (*n as *const protobuf::SetOperationStmt).cast(),
+            NodeRef::ReturnStmt(n) => (*n as *const protobuf::ReturnStmt).cast(),
+            NodeRef::PlassignStmt(n) => (*n as *const protobuf::PlAssignStmt).cast(),
+            NodeRef::CreateSchemaStmt(n) => (*n as *const protobuf::CreateSchemaStmt).cast(),
+            NodeRef::AlterTableStmt(n) => (*n as *const protobuf::AlterTableStmt).cast(),
+            NodeRef::ReplicaIdentityStmt(n) => (*n as *const protobuf::ReplicaIdentityStmt).cast(),
+            NodeRef::AlterTableCmd(n) => (*n as *const protobuf::AlterTableCmd).cast(),
+            NodeRef::AlterCollationStmt(n) => (*n as *const protobuf::AlterCollationStmt).cast(),
+            NodeRef::AlterDomainStmt(n) => (*n as *const protobuf::AlterDomainStmt).cast(),
+            NodeRef::GrantStmt(n) => (*n as *const protobuf::GrantStmt).cast(),
+            NodeRef::ObjectWithArgs(n) => (*n as *const protobuf::ObjectWithArgs).cast(),
+            NodeRef::AccessPriv(n) => (*n as *const protobuf::AccessPriv).cast(),
+            NodeRef::GrantRoleStmt(n) => (*n as *const protobuf::GrantRoleStmt).cast(),
+            NodeRef::AlterDefaultPrivilegesStmt(n) => (*n as *const protobuf::AlterDefaultPrivilegesStmt).cast(),
+            NodeRef::CopyStmt(n) => (*n as *const protobuf::CopyStmt).cast(),
+            NodeRef::VariableSetStmt(n) => (*n as *const protobuf::VariableSetStmt).cast(),
+            NodeRef::VariableShowStmt(n) => (*n as *const protobuf::VariableShowStmt).cast(),
+            NodeRef::CreateStmt(n) => (*n as *const protobuf::CreateStmt).cast(),
+            NodeRef::Constraint(n) => (*n as *const protobuf::Constraint).cast(),
+            NodeRef::CreateTableSpaceStmt(n) => (*n as *const protobuf::CreateTableSpaceStmt).cast(),
+            NodeRef::DropTableSpaceStmt(n) => (*n as *const protobuf::DropTableSpaceStmt).cast(),
+            NodeRef::AlterTableSpaceOptionsStmt(n) => (*n as *const protobuf::AlterTableSpaceOptionsStmt).cast(),
+            NodeRef::AlterTableMoveAllStmt(n) => (*n as *const protobuf::AlterTableMoveAllStmt).cast(),
+            NodeRef::CreateExtensionStmt(n) => (*n as *const protobuf::CreateExtensionStmt).cast(),
+            NodeRef::AlterExtensionStmt(n) => (*n as *const protobuf::AlterExtensionStmt).cast(),
+            NodeRef::AlterExtensionContentsStmt(n) => (*n as *const protobuf::AlterExtensionContentsStmt).cast(),
+            NodeRef::CreateFdwStmt(n) => (*n as *const protobuf::CreateFdwStmt).cast(),
+            NodeRef::AlterFdwStmt(n) => (*n as *const protobuf::AlterFdwStmt).cast(),
+            NodeRef::CreateForeignServerStmt(n) => (*n as *const protobuf::CreateForeignServerStmt).cast(),
+            NodeRef::AlterForeignServerStmt(n) => (*n as *const protobuf::AlterForeignServerStmt).cast(),
+            NodeRef::CreateForeignTableStmt(n) => (*n as *const protobuf::CreateForeignTableStmt).cast(),
+            NodeRef::CreateUserMappingStmt(n) => (*n as *const protobuf::CreateUserMappingStmt).cast(),
+            NodeRef::AlterUserMappingStmt(n) => (*n as *const protobuf::AlterUserMappingStmt).cast(),
+            NodeRef::DropUserMappingStmt(n) => (*n as *const protobuf::DropUserMappingStmt).cast(),
+            NodeRef::ImportForeignSchemaStmt(n) => (*n as *const protobuf::ImportForeignSchemaStmt).cast(),
+            NodeRef::CreatePolicyStmt(n) => (*n as *const protobuf::CreatePolicyStmt).cast(),
+            NodeRef::AlterPolicyStmt(n) => (*n as *const protobuf::AlterPolicyStmt).cast(),
+            NodeRef::CreateAmStmt(n) => (*n as *const protobuf::CreateAmStmt).cast(),
+            NodeRef::CreateTrigStmt(n) => (*n as *const protobuf::CreateTrigStmt).cast(),
+            NodeRef::CreateEventTrigStmt(n) => (*n as *const protobuf::CreateEventTrigStmt).cast(),
+            NodeRef::AlterEventTrigStmt(n) => (*n as *const protobuf::AlterEventTrigStmt).cast(),
+            NodeRef::CreatePlangStmt(n) => (*n as *const protobuf::CreatePLangStmt).cast(),
+            NodeRef::CreateRoleStmt(n) => (*n as *const protobuf::CreateRoleStmt).cast(),
+            NodeRef::AlterRoleStmt(n) => (*n as *const protobuf::AlterRoleStmt).cast(),
+            NodeRef::AlterRoleSetStmt(n) => (*n as *const protobuf::AlterRoleSetStmt).cast(),
+            NodeRef::DropRoleStmt(n) => (*n as *const protobuf::DropRoleStmt).cast(),
+            NodeRef::CreateSeqStmt(n) => (*n as *const protobuf::CreateSeqStmt).cast(),
+            NodeRef::AlterSeqStmt(n) => (*n as *const protobuf::AlterSeqStmt).cast(),
+            NodeRef::DefineStmt(n) => (*n as *const protobuf::DefineStmt).cast(),
+            NodeRef::CreateDomainStmt(n) => (*n as *const protobuf::CreateDomainStmt).cast(),
+            NodeRef::CreateOpClassStmt(n) => (*n as *const protobuf::CreateOpClassStmt).cast(),
+            NodeRef::CreateOpClassItem(n) => (*n as *const protobuf::CreateOpClassItem).cast(),
+            NodeRef::CreateOpFamilyStmt(n) => (*n as *const protobuf::CreateOpFamilyStmt).cast(),
+            NodeRef::AlterOpFamilyStmt(n) => (*n as *const protobuf::AlterOpFamilyStmt).cast(),
+            NodeRef::DropStmt(n) => (*n as *const protobuf::DropStmt).cast(),
+            NodeRef::TruncateStmt(n) => (*n as *const protobuf::TruncateStmt).cast(),
+            NodeRef::CommentStmt(n) => (*n as *const protobuf::CommentStmt).cast(),
+            NodeRef::SecLabelStmt(n) => (*n as *const protobuf::SecLabelStmt).cast(),
+            NodeRef::DeclareCursorStmt(n) => (*n as *const protobuf::DeclareCursorStmt).cast(),
+            NodeRef::ClosePortalStmt(n) => (*n as *const protobuf::ClosePortalStmt).cast(),
+            NodeRef::FetchStmt(n) => (*n as *const protobuf::FetchStmt).cast(),
+            NodeRef::IndexStmt(n) => (*n as *const protobuf::IndexStmt).cast(),
+            NodeRef::CreateStatsStmt(n) => (*n as *const protobuf::CreateStatsStmt).cast(),
+            NodeRef::StatsElem(n) => (*n as *const protobuf::StatsElem).cast(),
+            NodeRef::AlterStatsStmt(n) => (*n as *const protobuf::AlterStatsStmt).cast(),
+            NodeRef::CreateFunctionStmt(n) => (*n as *const protobuf::CreateFunctionStmt).cast(),
+            NodeRef::FunctionParameter(n) => (*n as *const protobuf::FunctionParameter).cast(),
+            NodeRef::AlterFunctionStmt(n) => (*n as *const protobuf::AlterFunctionStmt).cast(),
+            NodeRef::DoStmt(n) => (*n as *const protobuf::DoStmt).cast(),
+            NodeRef::InlineCodeBlock(n) => (*n as *const protobuf::InlineCodeBlock).cast(),
+            NodeRef::CallStmt(n) => (*n as *const protobuf::CallStmt).cast(),
+            NodeRef::CallContext(n) => (*n as *const protobuf::CallContext).cast(),
+            NodeRef::RenameStmt(n) => (*n as *const protobuf::RenameStmt).cast(),
+            NodeRef::AlterObjectDependsStmt(n) => (*n as *const protobuf::AlterObjectDependsStmt).cast(),
+            NodeRef::AlterObjectSchemaStmt(n) => (*n as *const protobuf::AlterObjectSchemaStmt).cast(),
+            NodeRef::AlterOwnerStmt(n) => (*n as *const protobuf::AlterOwnerStmt).cast(),
+            NodeRef::AlterOperatorStmt(n) => (*n as *const protobuf::AlterOperatorStmt).cast(),
+            NodeRef::AlterTypeStmt(n) => (*n as *const protobuf::AlterTypeStmt).cast(),
+            NodeRef::RuleStmt(n) => (*n as *const protobuf::RuleStmt).cast(),
+            NodeRef::NotifyStmt(n) => (*n as *const protobuf::NotifyStmt).cast(),
+            NodeRef::ListenStmt(n) => (*n as *const protobuf::ListenStmt).cast(),
+            NodeRef::UnlistenStmt(n) => (*n as *const protobuf::UnlistenStmt).cast(),
+            NodeRef::TransactionStmt(n) => (*n as *const protobuf::TransactionStmt).cast(),
+            NodeRef::CompositeTypeStmt(n) => (*n as *const protobuf::CompositeTypeStmt).cast(),
+            NodeRef::CreateEnumStmt(n) => (*n as *const protobuf::CreateEnumStmt).cast(),
+            NodeRef::CreateRangeStmt(n) => (*n as *const protobuf::CreateRangeStmt).cast(),
+            NodeRef::AlterEnumStmt(n) => (*n as *const protobuf::AlterEnumStmt).cast(),
+            NodeRef::ViewStmt(n) => (*n as *const protobuf::ViewStmt).cast(),
+            NodeRef::LoadStmt(n) => (*n as *const protobuf::LoadStmt).cast(),
+            NodeRef::CreatedbStmt(n) => (*n as *const protobuf::CreatedbStmt).cast(),
+            NodeRef::AlterDatabaseStmt(n) => (*n as *const protobuf::AlterDatabaseStmt).cast(),
+            NodeRef::AlterDatabaseRefreshCollStmt(n) => (*n as *const protobuf::AlterDatabaseRefreshCollStmt).cast(),
+            NodeRef::AlterDatabaseSetStmt(n) => (*n as *const protobuf::AlterDatabaseSetStmt).cast(),
+            NodeRef::DropdbStmt(n) => (*n as *const protobuf::DropdbStmt).cast(),
+            NodeRef::AlterSystemStmt(n) => (*n as *const protobuf::AlterSystemStmt).cast(),
+            NodeRef::ClusterStmt(n) => (*n as *const protobuf::ClusterStmt).cast(),
+            NodeRef::VacuumStmt(n) => (*n as *const protobuf::VacuumStmt).cast(),
+            NodeRef::VacuumRelation(n) => (*n as *const protobuf::VacuumRelation).cast(),
+            NodeRef::ExplainStmt(n) => (*n as *const protobuf::ExplainStmt).cast(),
+            NodeRef::CreateTableAsStmt(n) => (*n as *const protobuf::CreateTableAsStmt).cast(),
+            NodeRef::RefreshMatViewStmt(n) => (*n as *const protobuf::RefreshMatViewStmt).cast(),
+            NodeRef::CheckPointStmt(n) => (*n as *const protobuf::CheckPointStmt).cast(),
+            NodeRef::DiscardStmt(n) => (*n as *const protobuf::DiscardStmt).cast(),
+            NodeRef::LockStmt(n) => (*n as *const protobuf::LockStmt).cast(),
+            NodeRef::ConstraintsSetStmt(n) => (*n as *const protobuf::ConstraintsSetStmt).cast(),
+            NodeRef::ReindexStmt(n) => (*n as *const protobuf::ReindexStmt).cast(),
+            NodeRef::CreateConversionStmt(n) => (*n as *const protobuf::CreateConversionStmt).cast(),
+            NodeRef::CreateCastStmt(n) => (*n as *const protobuf::CreateCastStmt).cast(),
+            NodeRef::CreateTransformStmt(n) => (*n as *const protobuf::CreateTransformStmt).cast(),
+            NodeRef::PrepareStmt(n) => (*n as *const protobuf::PrepareStmt).cast(),
+            NodeRef::ExecuteStmt(n) => (*n as *const protobuf::ExecuteStmt).cast(),
+            NodeRef::DeallocateStmt(n) => (*n as *const protobuf::DeallocateStmt).cast(),
+            NodeRef::DropOwnedStmt(n) => (*n as *const protobuf::DropOwnedStmt).cast(),
+            NodeRef::ReassignOwnedStmt(n) => (*n as *const protobuf::ReassignOwnedStmt).cast(),
+            NodeRef::AlterTsdictionaryStmt(n) => (*n as *const protobuf::AlterTsDictionaryStmt).cast(),
+            NodeRef::AlterTsconfigurationStmt(n) => (*n as *const protobuf::AlterTsConfigurationStmt).cast(),
+            NodeRef::PublicationTable(n) => (*n as *const protobuf::PublicationTable).cast(),
+            NodeRef::PublicationObjSpec(n) => (*n as *const protobuf::PublicationObjSpec).cast(),
+            NodeRef::CreatePublicationStmt(n) => (*n as *const protobuf::CreatePublicationStmt).cast(),
+            NodeRef::AlterPublicationStmt(n) => (*n as *const protobuf::AlterPublicationStmt).cast(),
+            NodeRef::CreateSubscriptionStmt(n) => (*n as *const protobuf::CreateSubscriptionStmt).cast(),
+            NodeRef::AlterSubscriptionStmt(n) => (*n as *const protobuf::AlterSubscriptionStmt).cast(),
+            NodeRef::DropSubscriptionStmt(n) => (*n as *const protobuf::DropSubscriptionStmt).cast(),
+            NodeRef::Integer(n) => (*n as *const protobuf::Integer).cast(),
+            NodeRef::Float(n) => (*n as *const protobuf::Float).cast(),
+            NodeRef::Boolean(n) => (*n as *const protobuf::Boolean).cast(),
+            NodeRef::String(n) => (*n as *const protobuf::String).cast(),
+            NodeRef::BitString(n) => (*n as *const protobuf::BitString).cast(),
+            NodeRef::List(n) => (*n as *const protobuf::List).cast(),
+            NodeRef::IntList(n) => (*n as *const protobuf::IntList).cast(),
+            NodeRef::OidList(n) => (*n as *const protobuf::OidList).cast(),
+            NodeRef::AConst(n) => (*n as *const protobuf::AConst).cast(),
+        }
+    }
+    // END GENERATED
+
     pub fn to_enum(&self) -> NodeEnum {
         match self {
             NodeRef::Alias(n) => NodeEnum::Alias((*n).clone()),
@@ -2,10 +2,7 @@ use crate::*;
 
 impl Node {
     pub fn deparse(&self) -> Result<String> {
-        crate::deparse(&protobuf::ParseResult {
-            version: crate::bindings::PG_VERSION_NUM as i32,
-            stmts: vec![protobuf::RawStmt { stmt: Some(Box::new(self.clone())), stmt_location: 0, stmt_len: 0 }],
-        })
+        self.node.as_ref().ok_or(Error::InvalidPointer)?.deparse()
     }
 }
 
@@ -23,6 +23,10 @@ impl protobuf::ParseResult {
         crate::deparse(self)
     }
 
+    pub fn deparse_with(&self, options: &DeparseOptions) -> Result<String> {
+        crate::deparse_with(self, options)
+    }
+
     // Note: this doesn't iterate over every possible node type, since we only care about a subset of nodes.
     pub fn nodes(&self) -> Vec<(NodeRef<'_>, i32, Context, bool)> {
         self.stmts
@@ -60,6 +64,8 @@ pub struct ParseResult {
     pub aliases: HashMap<String, String>,
     pub cte_names: Vec<String>,
     pub functions: Vec<(String, Context)>,
+    /// Columns referenced by `WHERE` conditions, as `(table, column)`. Aliases, subqueries and CTEs are
+    /// resolved to the underlying `schema.table`, and the table is `None` if it could be any of several.
     pub filter_columns: Vec<(Option<String>, String)>,
 }
 
@@ -70,19 +76,19 @@ impl ParseResult {
         let mut aliases: HashMap<String, String> = HashMap::new();
         let mut cte_names: HashSet<String> = HashSet::new();
         let mut functions: HashSet<(String, Context)> = HashSet::new();
-        let mut filter_columns: HashSet<(Option<String>, String)> = HashSet::new();
+        // Whether a name refers to a CTE or a table depends on where it's used, following SQL scoping rules
+        let names = crate::lineage::resolve_names(&protobuf);
 
-        for (node, _depth, context, has_filter_columns) in protobuf.nodes().into_iter() {
+        for (node, _depth, context, _has_filter_columns) in protobuf.nodes().into_iter() {
             match node {
                 NodeRef::CommonTableExpr(s) => {
                     cte_names.insert(s.ctename.to_owned());
                 }
                 NodeRef::RangeVar(v) => {
-                    // TODO: this incorrectly returns no tables: parse('with f as (select * from f limit 1) select * from f')
-                    let table = if !v.schemaname.is_empty() { format!("{}.{}", v.schemaname, v.relname) } else { v.relname.to_owned() };
-                    if cte_names.contains(&table) {
+                    if names.cte_references.contains(&(v as *const protobuf::RangeVar)) {
                         continue;
                     }
+                    let table = relation_name(v);
                     tables.insert((table.to_owned(), context));
                     v.alias.as_ref().and_then(|alias| aliases.insert(alias.aliasname.to_owned(), table));
                 }
@@ -143,27 +149,23 @@ impl ParseResult {
                         }
                     }
                 }
-                NodeRef::ColumnRef(c) => {
-                    if !has_filter_columns {
-                        continue;
-                    }
-                    let f: Vec<String> = c
-                        .fields
-                        .iter()
-                        .filter_map(|n| match n.node.as_ref() {
-                            Some(NodeEnum::String(s)) => Some(s.sval.to_string()),
-                            _ => None,
-                        })
-                        .rev()
-                        .collect();
-                    if f.len() > 0 {
-                        filter_columns.insert((f.get(1).cloned(), f[0].to_string()));
-                    }
-                }
                 _ => (),
             }
         }
 
+        // Filter columns are resolved through aliases, subqueries and CTEs to the table they belong to
+        let filter_columns = names
+            .filter_columns
+            .into_iter()
+            .map(|c| {
+                let table = match (c.schema, c.table) {
+                    (Some(schema), Some(table)) => Some(format!("{schema}.{table}")),
+                    (_, table) => table,
+                };
+                (table, c.column)
+            })
+            .collect();
+
         Self {
             protobuf,
             warnings,
@@ -171,7 +173,7 @@ impl ParseResult {
             aliases,
             cte_names: Vec::from_iter(cte_names),
             functions: Vec::from_iter(functions),
-            filter_columns: Vec::from_iter(filter_columns),
+            filter_columns,
         }
     }
 
@@ -253,6 +255,11 @@ impl ParseResult {
         crate::deparse(&self.protobuf)
     }
 
+    /// Converts the parsed query back into a SQL string, formatted according to `options`
+    pub fn deparse_with(&self, options: &DeparseOptions) -> Result<String> {
+        crate::deparse_with(&self.protobuf, options)
+    }
+
     /// Intelligently truncates queries to a max length.
     ///
     /// # Example
@@ -394,3 +401,12 @@ impl ParseResult {
             .collect()
     }
 }
+
+// The name of the relation, as `schema.table` if qualified.
+pub(crate) fn relation_name(relation: &protobuf::RangeVar) -> String {
+    if relation.schemaname.is_empty() {
+        relation.relname.to_owned()
+    } else {
+        format!("{}.{}", relation.schemaname, relation.relname)
+    }
+}
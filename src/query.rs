@@ -1,20 +1,81 @@
+use std::collections::HashSet;
 use std::ffi::{CStr, CString};
+use std::ops::Range;
 use std::os::raw::c_char;
 
 use prost::Message;
 
 use crate::bindings::*;
 use crate::error::*;
+use crate::node_enum::NodeEnum;
+use crate::node_ref::NodeRef;
 use crate::parse_result::ParseResult;
 use crate::protobuf;
+use crate::span::{SourceSpans, Span};
 
 /// Represents the resulting fingerprint containing both the raw integer form as well as the
 /// corresponding 16 character hex value.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Fingerprint {
     pub value: u64,
     pub hex: String,
 }
 
+/// Grammar entry point used by [parse_with_options].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ParseMode {
+    /// A list of SQL statements
+    #[default]
+    Default,
+    /// A single type name, e.g. `varchar(20)[]`. The result contains a single `TypeName` node.
+    TypeName,
+    /// A PL/pgSQL expression, e.g. `a + 1 > $1`. The result contains a `SelectStmt` with the expression in its target list.
+    PlpgsqlExpr,
+    /// A PL/pgSQL assignment to a simple variable, e.g. `a := 1`
+    PlpgsqlAssign1,
+    /// A PL/pgSQL assignment to a qualified variable, e.g. `a.b := 1`
+    PlpgsqlAssign2,
+    /// A PL/pgSQL assignment to a doubly qualified variable, e.g. `a.b.c := 1`
+    PlpgsqlAssign3,
+}
+
+/// Options that control how [parse_with_options] interprets its input.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ParseOptions {
+    /// The grammar entry point, i.e. what kind of input to expect
+    pub mode: ParseMode,
+    /// Reject `\'` as an escape for a single quote, like `backslash_quote = off`
+    pub disable_backslash_quote: bool,
+    /// Treat backslashes in ordinary string literals as escapes, like `standard_conforming_strings = off`
+    pub disable_standard_conforming_strings: bool,
+    /// Don't warn about backslashes in ordinary string literals, like `escape_string_warning = off`
+    pub disable_escape_string_warning: bool,
+}
+
+impl ParseOptions {
+    fn to_bits(self) -> i32 {
+        let mode = match self.mode {
+            ParseMode::Default => PgQueryParseMode_PG_QUERY_PARSE_DEFAULT,
+            ParseMode::TypeName => PgQueryParseMode_PG_QUERY_PARSE_TYPE_NAME,
+            ParseMode::PlpgsqlExpr => PgQueryParseMode_PG_QUERY_PARSE_PLPGSQL_EXPR,
+            ParseMode::PlpgsqlAssign1 => PgQueryParseMode_PG_QUERY_PARSE_PLPGSQL_ASSIGN1,
+            ParseMode::PlpgsqlAssign2 => PgQueryParseMode_PG_QUERY_PARSE_PLPGSQL_ASSIGN2,
+            ParseMode::PlpgsqlAssign3 => PgQueryParseMode_PG_QUERY_PARSE_PLPGSQL_ASSIGN3,
+        };
+        let mut bits = mode as i32;
+        if self.disable_backslash_quote {
+            bits |= PG_QUERY_DISABLE_BACKSLASH_QUOTE as i32;
+        }
+        if self.disable_standard_conforming_strings {
+            bits |= PG_QUERY_DISABLE_STANDARD_CONFORMING_STRINGS as i32;
+        }
+        if self.disable_escape_string_warning {
+            bits |= PG_QUERY_DISABLE_ESCAPE_STRING_WARNING as i32;
+        }
+        bits
+    }
+}
+
 /// Parses the given SQL statement into the given abstract syntax tree.
 ///
 /// # Example
@@ -29,11 +90,26 @@ pub struct Fingerprint {
 /// assert!(matches!(result.protobuf.nodes()[0].0, NodeRef::SelectStmt(_)));
 /// ```
 pub fn parse(statement: &str) -> Result<ParseResult> {
+    parse_with_options(statement, ParseOptions::default())
+}
+
+/// Parses the given input into an abstract syntax tree, starting from the grammar entry point selected by `options.mode`.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeEnum, ParseMode, ParseOptions};
+///
+/// let options = ParseOptions { mode: ParseMode::PlpgsqlAssign1, ..ParseOptions::default() };
+/// let result = pg_query::parse_with_options("total := total + 1", options).unwrap();
+/// let stmt = result.protobuf.stmts[0].stmt.as_ref().and_then(|n| n.node.as_ref());
+/// assert!(matches!(stmt, Some(NodeEnum::PlassignStmt(s)) if s.name == "total"));
+/// ```
+pub fn parse_with_options(statement: &str, options: ParseOptions) -> Result<ParseResult> {
     let input = CString::new(statement)?;
-    let result = unsafe { pg_query_parse_protobuf(input.as_ptr()) };
+    let result = unsafe { pg_query_parse_protobuf_opts(input.as_ptr(), options.to_bits()) };
     let parse_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Parse(Box::new(unsafe { ParseError::from_raw(&*result.error, statement) })))
     } else {
         let data = unsafe { std::slice::from_raw_parts(result.parse_tree.data as *const u8, result.parse_tree.len as usize) };
         let stderr = unsafe { CStr::from_ptr(result.stderr_buffer) }.to_string_lossy().to_string();
@@ -43,6 +119,70 @@ pub fn parse(statement: &str) -> Result<ParseResult> {
     parse_result
 }
 
+/// Parses a type name, such as a user-supplied column type.
+///
+/// # Example
+///
+/// ```rust
+/// let type_name = pg_query::parse_type_name("varchar(20)[]").unwrap();
+/// let names: Vec<_> = type_name.names.iter().filter_map(|n| match &n.node {
+///     Some(pg_query::NodeEnum::String(s)) => Some(s.sval.as_str()),
+///     _ => None,
+/// }).collect();
+/// assert_eq!(names, ["pg_catalog", "varchar"]);
+/// assert_eq!(type_name.typmods.len(), 1);
+/// assert_eq!(type_name.array_bounds.len(), 1);
+/// ```
+pub fn parse_type_name(type_name: &str) -> Result<protobuf::TypeName> {
+    let result = parse_with_options(type_name, ParseOptions { mode: ParseMode::TypeName, ..ParseOptions::default() })?;
+    match first_stmt(result.protobuf) {
+        Some(NodeEnum::TypeName(t)) => Ok(t),
+        _ => Err(Error::Parse(Box::new("expected a type name".into()))),
+    }
+}
+
+/// Parses a single scalar expression, such as a user-supplied filter.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::NodeEnum;
+///
+/// let expr = pg_query::parse_expr("a + 1 > $1").unwrap();
+/// let NodeEnum::AExpr(expr) = expr else { panic!("expected an expression") };
+/// assert!(matches!(expr.lexpr.and_then(|n| n.node), Some(NodeEnum::AExpr(_))));
+/// assert!(matches!(expr.rexpr.and_then(|n| n.node), Some(NodeEnum::ParamRef(_))));
+/// ```
+pub fn parse_expr(expr: &str) -> Result<NodeEnum> {
+    let result = parse_with_options(expr, ParseOptions { mode: ParseMode::PlpgsqlExpr, ..ParseOptions::default() })?;
+    let mut select = match first_stmt(result.protobuf) {
+        Some(NodeEnum::SelectStmt(s)) => s,
+        _ => return Err(Error::Parse(Box::new("expected an expression".into()))),
+    };
+    // PL/pgSQL expressions may technically be a full query with FROM, LIMIT, INTO and so on, so only accept
+    // a lone expression, i.e. a SelectStmt that has nothing but its target list set
+    let target_list = std::mem::take(&mut select.target_list);
+    let plain = protobuf::SelectStmt {
+        limit_option: protobuf::LimitOption::Default as i32,
+        op: protobuf::SetOperation::SetopNone as i32,
+        ..Default::default()
+    };
+    match target_list.as_slice() {
+        [target] if *select == plain => match &target.node {
+            Some(NodeEnum::ResTarget(t)) if t.name.is_empty() => t.val.as_ref().and_then(|v| v.node.clone()).ok_or(Error::InvalidPointer),
+            _ => Err(Error::Parse(Box::new("expected a single expression".into()))),
+        },
+        _ => Err(Error::Parse(Box::new("expected a single expression".into()))),
+    }
+}
+
+fn first_stmt(mut protobuf: protobuf::ParseResult) -> Option<NodeEnum> {
+    if protobuf.stmts.len() != 1 {
+        return None;
+    }
+    protobuf.stmts.remove(0).stmt.and_then(|n| n.node)
+}
+
 /// Converts a parsed tree back into a string.
 ///
 /// # Example
@@ -73,8 +213,7 @@ pub fn deparse(protobuf: &protobuf::ParseResult) -> Result<String> {
     let result = unsafe { pg_query_deparse_protobuf(protobuf) };
 
     let deparse_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Parse(Box::new(unsafe { ParseError::from_raw(&*result.error, "") })))
     } else {
         let query = unsafe { CStr::from_ptr(result.query) }.to_string_lossy().to_string();
         Ok(query)
@@ -84,6 +223,193 @@ pub fn deparse(protobuf: &protobuf::ParseResult) -> Result<String> {
     deparse_result
 }
 
+/// Letter case used for SQL keywords by [deparse_with].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum KeywordCase {
+    /// `SELECT a FROM t`
+    #[default]
+    Upper,
+    /// `select a from t`
+    Lower,
+}
+
+/// Options that control the output of [deparse_with].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeparseOptions {
+    /// Break clauses, joins, CTEs and long lists across multiple lines
+    pub pretty: bool,
+    /// Number of spaces per indentation level when `pretty` is set
+    pub indent: usize,
+    /// Line length after which lists are broken up into one item per line when `pretty` is set
+    pub max_line_length: usize,
+    /// Case of keywords in the output; identifiers and literals are never changed
+    pub keyword_case: KeywordCase,
+    /// Place separating commas at the end of a line (`a,\n b`) instead of the start of the next one (`a\n, b`)
+    pub trailing_commas: bool,
+    /// End the output with a newline
+    pub trailing_newline: bool,
+    /// Comments to re-insert into the output, see [deparse_comments_for_query]
+    pub comments: Vec<DeparseComment>,
+}
+
+/// A comment from the original query text, attached to the first node that starts at or after `match_location`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DeparseComment {
+    /// Byte offset in the original query; the comment is emitted before the first node located at or after it
+    pub match_location: usize,
+    /// Newlines to emit before the comment, 1 if the comment was on its own line
+    pub newlines_before_comment: usize,
+    /// Newlines to emit after the comment, 1 if the comment was followed by a line break
+    pub newlines_after_comment: usize,
+    /// The comment itself, including its `--` or `/* */` delimiters
+    pub text: String,
+}
+
+impl Default for DeparseOptions {
+    fn default() -> Self {
+        Self {
+            pretty: false,
+            indent: 4,
+            max_line_length: 80,
+            keyword_case: KeywordCase::Upper,
+            trailing_commas: true,
+            trailing_newline: false,
+            comments: Vec::new(),
+        }
+    }
+}
+
+/// Converts a parsed tree back into a string, formatted according to `options`.
+///
+/// Formatting only changes whitespace and keyword case, so parsing the output yields the same tree apart from locations.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{DeparseOptions, KeywordCase};
+///
+/// let result = pg_query::parse("SELECT a, b FROM x JOIN y ON x.id = y.x_id WHERE a = 1").unwrap();
+/// let options = DeparseOptions { pretty: true, keyword_case: KeywordCase::Lower, ..DeparseOptions::default() };
+/// let formatted = pg_query::deparse_with(&result.protobuf, &options).unwrap();
+/// assert!(formatted.starts_with("select"));
+/// assert!(formatted.lines().count() > 1);
+///
+/// // The formatted query parses back into the same tree
+/// assert_eq!(pg_query::parse(&formatted).unwrap().deparse().unwrap(), result.deparse().unwrap());
+/// ```
+pub fn deparse_with(protobuf: &protobuf::ParseResult, options: &DeparseOptions) -> Result<String> {
+    let buffer = protobuf.encode_to_vec();
+    let len = buffer.len();
+    let data = buffer.as_ptr() as *const c_char as *mut c_char;
+    let protobuf = PgQueryProtobuf { data, len };
+    // The C structs only borrow the comment strings, so these need to outlive the call
+    let texts = options.comments.iter().map(|c| CString::new(c.text.as_str())).collect::<core::result::Result<Vec<_>, _>>()?;
+    let mut comments: Vec<PostgresDeparseComment> = options
+        .comments
+        .iter()
+        .zip(&texts)
+        .map(|(c, text)| PostgresDeparseComment {
+            match_location: c.match_location as i32,
+            newlines_before_comment: c.newlines_before_comment as i32,
+            newlines_after_comment: c.newlines_after_comment as i32,
+            str: text.as_ptr() as *mut c_char,
+        })
+        .collect();
+    let mut comment_ptrs: Vec<*mut PostgresDeparseComment> = comments.iter_mut().map(|c| c as *mut _).collect();
+    let opts = PostgresDeparseOpts {
+        comments: if comment_ptrs.is_empty() { std::ptr::null_mut() } else { comment_ptrs.as_mut_ptr() },
+        comment_count: comment_ptrs.len(),
+        pretty_print: options.pretty,
+        indent_size: options.indent as i32,
+        max_line_length: options.max_line_length as i32,
+        trailing_newline: options.trailing_newline,
+        commas_start_of_line: !options.trailing_commas,
+    };
+    let result = unsafe { pg_query_deparse_protobuf_opts(protobuf, opts) };
+
+    let deparse_result = if !result.error.is_null() {
+        Err(Error::Parse(Box::new(unsafe { ParseError::from_raw(&*result.error, "") })))
+    } else {
+        let query = unsafe { CStr::from_ptr(result.query) }.to_string_lossy().to_string();
+        Ok(query)
+    };
+
+    unsafe { pg_query_free_deparse_result(result) };
+    apply_keyword_case(deparse_result?, options.keyword_case)
+}
+
+/// Extracts the `--` and `/* */` comments of a query, so [deparse_with] can emit them again.
+///
+/// Each comment is attached to the token that follows it, so the resulting locations are only meaningful for the
+/// parse tree of this exact query text.
+///
+/// # Example
+///
+/// ```rust
+/// let comments = pg_query::deparse_comments_for_query("SELECT a -- the id\nFROM t /* done */").unwrap();
+/// assert_eq!(comments[0].text, "-- the id");
+/// assert_eq!(comments[0].match_location, 19);
+/// assert_eq!(comments[1].text, "/* done */");
+/// ```
+pub fn deparse_comments_for_query(query: &str) -> Result<Vec<DeparseComment>> {
+    let input = CString::new(query)?;
+    let result = unsafe { pg_query_deparse_comments_for_query(input.as_ptr()) };
+    let comments = if !result.error.is_null() {
+        Err(Error::Scan(Box::new(unsafe { ParseError::from_raw(&*result.error, query) })))
+    } else if result.comments.is_null() {
+        Ok(Vec::new())
+    } else {
+        let comments = unsafe { std::slice::from_raw_parts(result.comments, result.comment_count) };
+        Ok(comments
+            .iter()
+            .map(|&comment| {
+                let comment = unsafe { &*comment };
+                DeparseComment {
+                    match_location: comment.match_location as usize,
+                    newlines_before_comment: comment.newlines_before_comment as usize,
+                    newlines_after_comment: comment.newlines_after_comment as usize,
+                    text: unsafe { CStr::from_ptr(comment.str) }.to_string_lossy().to_string(),
+                }
+            })
+            .collect())
+    };
+    unsafe { pg_query_free_deparse_comments_result(result) };
+    comments
+}
+
+/// Parses and re-formats the given SQL according to `options`, keeping its comments.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::DeparseOptions;
+///
+/// let sql = "-- Add the users table\ncreate table users (id bigint primary key /* surrogate */, email text)";
+/// let formatted = pg_query::format(sql, &DeparseOptions::default()).unwrap();
+/// assert!(formatted.contains("-- Add the users table\n"));
+/// assert!(formatted.contains("/* surrogate */"));
+/// ```
+pub fn format(statement: &str, options: &DeparseOptions) -> Result<String> {
+    let result = parse(statement)?;
+    let comments = deparse_comments_for_query(statement)?;
+    deparse_with(&result.protobuf, &DeparseOptions { comments, ..options.clone() })
+}
+
+// The deparser always emits uppercase keywords and lowercase (or quoted) identifiers, so only keyword tokens need changing
+fn apply_keyword_case(query: String, keyword_case: KeywordCase) -> Result<String> {
+    if keyword_case == KeywordCase::Upper {
+        return Ok(query);
+    }
+    let mut output = query.clone();
+    for token in scan(&query)?.tokens {
+        if token.keyword_kind != protobuf::KeywordKind::NoKeyword as i32 {
+            let range = token.start as usize..token.end as usize;
+            output.replace_range(range.clone(), &query[range].to_lowercase());
+        }
+    }
+    Ok(output)
+}
+
 /// Normalizes the given SQL statement, returning a parametized version.
 ///
 /// # Example
@@ -98,8 +424,7 @@ pub fn normalize(statement: &str) -> Result<String> {
     let input = CString::new(statement)?;
     let result = unsafe { pg_query_normalize(input.as_ptr()) };
     let normalized_query = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Parse(Box::new(unsafe { ParseError::from_raw(&*result.error, statement) })))
     } else {
         let n = unsafe { CStr::from_ptr(result.normalized_query) };
         Ok(n.to_string_lossy().to_string())
@@ -108,6 +433,235 @@ pub fn normalize(statement: &str) -> Result<String> {
     normalized_query
 }
 
+/// The kind of literal that a [NormalizedParam] replaced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LiteralKind {
+    /// `'text'`, `E'text'`, `$$text$$` or `U&'text'`
+    String,
+    Integer,
+    /// A number with a decimal point or exponent, or an integer too large for `int4`
+    Float,
+    /// `B'1010'` or `X'1F'`
+    BitString,
+    Boolean,
+    Null,
+}
+
+/// A constant that normalization replaced by a parameter placeholder.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NormalizedParam {
+    /// The placeholder number, i.e. `1` for `$1`
+    pub number: usize,
+    /// The constant as written in the original query, including quotes and a leading minus sign
+    pub text: String,
+    /// Byte offset of the constant in the original query
+    pub location: usize,
+    pub kind: LiteralKind,
+}
+
+/// The result of [normalize_with_params].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NormalizedQuery {
+    pub normalized: String,
+    /// The replaced constants, in the order they appear in the query
+    pub params: Vec<NormalizedParam>,
+}
+
+/// Normalizes the given SQL statement like [normalize], also returning the constants that were replaced.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::LiteralKind;
+///
+/// let result = pg_query::normalize_with_params("SELECT * FROM t WHERE a = 'x' AND b > -1.5 AND c = $1").unwrap();
+/// assert_eq!(result.normalized, "SELECT * FROM t WHERE a = $2 AND b > $3 AND c = $1");
+/// assert_eq!(result.params[0].text, "'x'");
+/// assert_eq!(result.params[0].number, 2);
+/// assert_eq!(result.params[0].location, 26);
+/// assert_eq!(result.params[1].text, "-1.5");
+/// assert_eq!(result.params[1].kind, LiteralKind::Float);
+/// ```
+pub fn normalize_with_params(statement: &str) -> Result<NormalizedQuery> {
+    let normalized = normalize(statement)?;
+    let tokens = scan(statement)?.tokens;
+    let mut params = Vec::new();
+    // The normalized query is the original with some constants replaced by `$n`, so the two are walked in step, with
+    // a constant wherever a token of the original doesn't appear in the normalized query
+    let (mut original_pos, mut normalized_pos) = (0, 0);
+    let mut tokens = tokens.iter().peekable();
+    while let Some(token) = tokens.next() {
+        let (start, end) = (token.start as usize, token.end as usize);
+        normalized_pos += start - original_pos;
+        let rest = normalized.get(normalized_pos..).unwrap_or_default();
+        if rest.starts_with(&statement[start..end]) {
+            normalized_pos += end - start;
+            original_pos = end;
+            continue;
+        }
+        let digits = rest.get(1..).unwrap_or_default().bytes().take_while(u8::is_ascii_digit).count();
+        let number =
+            rest.get(1..1 + digits).and_then(|n| n.parse().ok()).ok_or_else(|| Error::Parse(Box::new("unexpected normalized query".into())))?;
+        let mut kind = literal_kind(token.token);
+        original_pos = end;
+        // Negative numbers are replaced together with their minus sign
+        if token.token == protobuf::Token::Ascii45 as i32 {
+            if let Some(number) = tokens.next_if(|t| t.token == protobuf::Token::Iconst as i32 || t.token == protobuf::Token::Fconst as i32) {
+                kind = literal_kind(number.token);
+                original_pos = number.end as usize;
+            }
+        }
+        params.push(NormalizedParam { number, text: statement[start..original_pos].to_string(), location: start, kind });
+        normalized_pos += 1 + digits;
+    }
+    Ok(NormalizedQuery { normalized, params })
+}
+
+fn literal_kind(token: i32) -> LiteralKind {
+    use protobuf::Token;
+    match Token::try_from(token) {
+        Ok(Token::Iconst) => LiteralKind::Integer,
+        Ok(Token::Fconst) => LiteralKind::Float,
+        Ok(Token::Bconst | Token::Xconst) => LiteralKind::BitString,
+        Ok(Token::TrueP | Token::FalseP) => LiteralKind::Boolean,
+        Ok(Token::NullP) => LiteralKind::Null,
+        _ => LiteralKind::String,
+    }
+}
+
+/// Options that control the output of [normalize_with_options].
+///
+/// Collapsing only merges parts of a query that [fingerprint] already treats as the same, so the normalized query
+/// keeps the fingerprint of the original.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct NormalizeOptions {
+    /// Replace `IN` lists and `ARRAY[...]` constructors of constants with a single placeholder, e.g. `IN ($1 /*, ... */)`,
+    /// so that queries only differing in the number of values are normalized the same
+    pub collapse_lists: bool,
+    /// Replace multi-row `VALUES` lists whose rows only differ in their constants with the first row, e.g.
+    /// `VALUES ($1, $2) /*, ... */`
+    pub collapse_values: bool,
+    /// Keep constant `LIMIT` and `OFFSET` values as written
+    pub keep_limit: bool,
+    /// Keep `true` and `false` as written
+    pub keep_booleans: bool,
+}
+
+/// Normalizes the given SQL statement like [normalize], with additional options for collapsing lists and keeping
+/// some constants.
+///
+/// Placeholders are numbered consecutively, following any parameters the query already has.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::NormalizeOptions;
+///
+/// let options = NormalizeOptions { collapse_lists: true, keep_limit: true, ..NormalizeOptions::default() };
+/// let a = pg_query::normalize_with_options("SELECT * FROM t WHERE id IN (1, 2, 3) AND x = 'a' LIMIT 10", &options).unwrap();
+/// let b = pg_query::normalize_with_options("SELECT * FROM t WHERE id IN (4, 5) AND x = 'b' LIMIT 10", &options).unwrap();
+/// assert_eq!(a, "SELECT * FROM t WHERE id IN ($1 /*, ... */) AND x = $2 LIMIT 10");
+/// assert_eq!(a, b);
+/// ```
+pub fn normalize_with_options(statement: &str, options: &NormalizeOptions) -> Result<String> {
+    let NormalizedQuery { params, .. } = normalize_with_params(statement)?;
+    let result = parse(statement)?;
+    let spans = SourceSpans::new(statement)?;
+    let tokens = scan(statement)?.tokens;
+
+    // Edits of the original query, as the replaced span and whether it's a constant or a collapsed list
+    let mut kept = HashSet::new();
+    let mut edits: Vec<(Span, bool)> = Vec::new();
+    for node in result.protobuf.iter() {
+        match node {
+            NodeRef::AExpr(expr) if options.collapse_lists && expr.kind == protobuf::AExprKind::AexprIn as i32 => {
+                if let Some(NodeEnum::List(list)) = expr.rexpr.as_ref().and_then(|n| n.node.as_ref()) {
+                    edits.extend(collapsed_list(&spans, &list.items).map(|span| (span, false)));
+                }
+            }
+            NodeRef::AArrayExpr(array) if options.collapse_lists => {
+                edits.extend(collapsed_list(&spans, &array.elements).map(|span| (span, false)));
+            }
+            NodeRef::SelectStmt(select) => {
+                if options.collapse_values {
+                    edits.extend(collapsed_rows(&spans, &tokens, &select.values_lists).map(|span| (span, false)));
+                }
+                if options.keep_limit {
+                    for limit in [&select.limit_count, &select.limit_offset] {
+                        if let Some(NodeEnum::AConst(c)) = limit.as_ref().and_then(|n| n.node.as_ref()) {
+                            kept.insert(c.location as usize);
+                        }
+                    }
+                }
+            }
+            NodeRef::AConst(c) if options.keep_booleans && matches!(c.val, Some(protobuf::a_const::Val::Boolval(_))) => {
+                kept.insert(c.location as usize);
+            }
+            _ => {}
+        }
+    }
+    let constants = params.iter().filter(|p| !kept.contains(&p.location));
+    edits.extend(constants.map(|p| (Span::new(p.location, p.location + p.text.len()), true)));
+    edits.sort_by_key(|(span, _)| span.start);
+
+    let mut output = String::with_capacity(statement.len());
+    let mut number = params.first().map_or(0, |p| p.number - 1);
+    let mut last_end = 0;
+    for (span, is_constant) in edits {
+        // Constants and lists within a collapsed list are dropped along with it
+        if span.start < last_end {
+            continue;
+        }
+        output.push_str(&statement[last_end..span.start]);
+        if is_constant {
+            number += 1;
+            output.push_str(&format!("${number}"));
+        } else {
+            output.push_str(" /*, ... */");
+        }
+        last_end = span.end;
+    }
+    output.push_str(&statement[last_end..]);
+    Ok(output)
+}
+
+// The span following the first item of a list of constants, which collapsing removes. It's empty for a single item,
+// which still gets the marker so that the query is normalized the same as with more items.
+fn collapsed_list<'a>(spans: &SourceSpans<'a>, items: &'a [protobuf::Node]) -> Option<Span> {
+    // Mirrors the nodes that fingerprinting leaves out, so that collapsing doesn't change the fingerprint
+    let is_constant = |node: &protobuf::Node| match node.node.as_ref() {
+        Some(NodeEnum::AConst(_) | NodeEnum::ParamRef(_)) => true,
+        Some(NodeEnum::TypeCast(cast)) => {
+            matches!(cast.arg.as_ref().and_then(|n| n.node.as_ref()), Some(NodeEnum::AConst(_) | NodeEnum::ParamRef(_)))
+        }
+        _ => false,
+    };
+    let (Some(first), Some(last)) = (items.first(), items.last()) else { return None };
+    if !items.iter().all(is_constant) {
+        return None;
+    }
+    Some(Span::new(spans.span(first.node.as_ref()?.to_ref())?.end, spans.span(last.node.as_ref()?.to_ref())?.end))
+}
+
+// The span following the first row of a `VALUES` list whose rows all have the same fingerprint, which collapsing
+// removes
+fn collapsed_rows<'a>(spans: &SourceSpans<'a>, tokens: &[protobuf::ScanToken], rows: &'a [protobuf::Node]) -> Option<Span> {
+    let (Some(first), Some(last)) = (rows.first(), rows.last()) else { return None };
+    let fingerprint = first.fingerprint();
+    if !rows.iter().all(|row| row.fingerprint() == fingerprint) {
+        return None;
+    }
+    // Row spans only cover the values, so extend them over the closing parenthesis
+    let row_end = |row: &'a protobuf::Node| {
+        let end = spans.span(row.node.as_ref()?.to_ref())?.end;
+        let close = tokens
+            .iter()
+            .find(|t| t.start as usize >= end && t.token != protobuf::Token::SqlComment as i32 && t.token != protobuf::Token::CComment as i32)?;
+        (close.token == protobuf::Token::Ascii41 as i32).then_some(close.end as usize)
+    };
+    Some(Span::new(row_end(first)?, row_end(last)?))
+}
+
 /// Fingerprints the given SQL statement. Useful for comparing parse trees across different implementations
 /// of `libpg_query`.
 ///
@@ -123,8 +677,7 @@ pub fn fingerprint(statement: &str) -> Result<Fingerprint> {
     let input = CString::new(statement)?;
     let result = unsafe { pg_query_fingerprint(input.as_ptr()) };
     let fingerprint = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Parse(Box::new(unsafe { ParseError::from_raw(&*result.error, statement) })))
     } else {
         let hex = unsafe { CStr::from_ptr(result.fingerprint_str) };
         Ok(Fingerprint { value: result.fingerprint, hex: hex.to_string_lossy().to_string() })
@@ -133,6 +686,84 @@ pub fn fingerprint(statement: &str) -> Result<Fingerprint> {
     fingerprint
 }
 
+/// A single statement of a multi-statement query, as returned by [fingerprint_statements].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StatementFingerprint {
+    /// Byte range of the statement in the query, excluding surrounding whitespace and the terminating semicolon
+    pub range: Range<usize>,
+    pub fingerprint: Fingerprint,
+    /// The node type of the statement, e.g. `SelectStmt`
+    pub statement_type: &'static str,
+}
+
+/// A single statement of a multi-statement query, as returned by [normalize_statements].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NormalizedStatement {
+    /// Byte range of the statement in the query, excluding surrounding whitespace and the terminating semicolon
+    pub range: Range<usize>,
+    /// The statement with its constants replaced by `$1`, `$2`, etc., numbered from `$1` for each statement
+    pub normalized: String,
+    pub fingerprint: Fingerprint,
+    /// The node type of the statement, e.g. `SelectStmt`
+    pub statement_type: &'static str,
+}
+
+/// Fingerprints each statement of the given SQL separately, unlike [fingerprint] which fingerprints all of them
+/// together.
+///
+/// # Example
+///
+/// ```rust
+/// let query = "SELECT * FROM users WHERE id = 1; UPDATE users SET name = 'x' WHERE id = 2";
+/// let statements = pg_query::fingerprint_statements(query).unwrap();
+/// assert_eq!(statements.len(), 2);
+/// assert_eq!(&query[statements[1].range.clone()], "UPDATE users SET name = 'x' WHERE id = 2");
+/// assert_eq!(statements[1].statement_type, "UpdateStmt");
+/// assert_eq!(statements[0].fingerprint, pg_query::fingerprint("SELECT * FROM users WHERE id = 1").unwrap());
+/// ```
+pub fn fingerprint_statements(query: &str) -> Result<Vec<StatementFingerprint>> {
+    let result = parse(query)?;
+    Ok(statements(query, &result)
+        .map(|(range, stmt, statement_type)| StatementFingerprint { range, fingerprint: stmt.fingerprint(), statement_type })
+        .collect())
+}
+
+/// Normalizes each statement of the given SQL separately, numbering the parameters of each statement from `$1`.
+///
+/// # Example
+///
+/// ```rust
+/// let query = "SELECT * FROM users WHERE id = 1;\nDELETE FROM sessions WHERE user_id = 1;";
+/// let statements = pg_query::normalize_statements(query).unwrap();
+/// assert_eq!(statements[0].normalized, "SELECT * FROM users WHERE id = $1");
+/// assert_eq!(statements[1].normalized, "DELETE FROM sessions WHERE user_id = $1");
+/// assert_eq!(statements[1].range, 34..72);
+/// assert_eq!(statements[1].statement_type, "DeleteStmt");
+/// ```
+pub fn normalize_statements(query: &str) -> Result<Vec<NormalizedStatement>> {
+    let result = parse(query)?;
+    statements(query, &result)
+        .map(|(range, stmt, statement_type)| {
+            Ok(NormalizedStatement { normalized: normalize(&query[range.clone()])?, range, fingerprint: stmt.fingerprint(), statement_type })
+        })
+        .collect()
+}
+
+// The statements of a parsed query with their trimmed byte range and node type
+fn statements<'a>(query: &'a str, result: &'a ParseResult) -> impl Iterator<Item = (Range<usize>, &'a protobuf::RawStmt, &'static str)> + 'a {
+    result.protobuf.stmts.iter().filter_map(move |stmt| {
+        let node = stmt.stmt.as_ref()?.node.as_ref()?;
+        let start = stmt.stmt_location as usize;
+        // A length of 0 means the statement extends to the end of the query
+        let end = if stmt.stmt_len == 0 { query.len() } else { start + stmt.stmt_len as usize };
+        let text = &query[start..end];
+        let trimmed = text.trim_start();
+        let start = start + text.len() - trimmed.len();
+        let end = start + trimmed.trim_end().trim_end_matches(';').trim_end().len();
+        Some((start..end, stmt, node.to_ref().type_name()))
+    })
+}
+
 /// An experimental API which parses a PLPGSQL function. This currently returns the raw JSON structure.
 ///
 /// # Example
@@ -155,8 +786,7 @@ pub fn parse_plpgsql(stmt: &str) -> Result<serde_json::Value> {
     let input = CString::new(stmt)?;
     let result = unsafe { pg_query_parse_plpgsql(input.as_ptr()) };
     let structure = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Parse(Box::new(unsafe { ParseError::from_raw(&*result.error, stmt) })))
     } else {
         let raw = unsafe { CStr::from_ptr(result.plpgsql_funcs) };
         serde_json::from_str(&raw.to_string_lossy()).map_err(|e| Error::InvalidJson(e.to_string()))
@@ -180,15 +810,15 @@ pub fn parse_plpgsql(stmt: &str) -> Result<serde_json::Value> {
 /// ```rust
 /// let query = "select 1; this statement is not sql; select 2;";
 /// let result = pg_query::split_with_parser(query);
-/// let err = r#"syntax error at or near "this""#;
-/// assert_eq!(result, Err(pg_query::Error::Split(err.to_string())));
+/// let Err(pg_query::Error::Split(err)) = result else { panic!("expected a split error") };
+/// assert_eq!(err.message, r#"syntax error at or near "this""#);
+/// assert_eq!(err.position.unwrap().byte_offset, 10);
 /// ```
 pub fn split_with_parser(query: &str) -> Result<Vec<&str>> {
     let input = CString::new(query)?;
     let result = unsafe { pg_query_split_with_parser(input.as_ptr()) };
     let split_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Split(message))
+        Err(Error::Split(Box::new(unsafe { ParseError::from_raw(&*result.error, query) })))
     } else {
         let n_stmts = result.n_stmts as usize;
         let mut statements = Vec::with_capacity(n_stmts);
@@ -232,8 +862,7 @@ pub fn scan(sql: &str) -> Result<protobuf::ScanResult> {
     let input = CString::new(sql)?;
     let result = unsafe { pg_query_scan(input.as_ptr()) };
     let scan_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Scan(message))
+        Err(Error::Scan(Box::new(unsafe { ParseError::from_raw(&*result.error, sql) })))
     } else {
         let data = unsafe { std::slice::from_raw_parts(result.pbuf.data as *const u8, result.pbuf.len as usize) };
         protobuf::ScanResult::decode(data).map_err(Error::Decode)
@@ -257,8 +886,7 @@ pub fn split_with_scanner(query: &str) -> Result<Vec<&str>> {
     let input = CString::new(query)?;
     let result = unsafe { pg_query_split_with_scanner(input.as_ptr()) };
     let split_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Split(message))
+        Err(Error::Split(Box::new(unsafe { ParseError::from_raw(&*result.error, query) })))
     } else {
         // don't use result.stderr_buffer since it appears unused unless
         // libpg_query is compiled with DEBUG defined.
@@ -0,0 +1,187 @@
+use crate::*;
+
+/// Applies edits to the original query text, leaving everything outside of the edited regions,
+/// such as formatting, comments and casing, untouched.
+///
+/// Edits are keyed by node, using [SourceSpans] to find where the node is in the text, or by
+/// [Span]. Edits that overlap each other are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeRef, Rewriter};
+///
+/// let query = "SELECT u.id -- the user\nFROM ONLY users u\nWHERE u.email = $1";
+/// let result = pg_query::parse(query).unwrap();
+/// let mut rewriter = Rewriter::new(query).unwrap();
+/// for node in result.protobuf.iter() {
+///     if let NodeRef::RangeVar(r) = node {
+///         if r.relname == "users" && r.schemaname.is_empty() {
+///             rewriter.insert_before_name(node, "app.").unwrap();
+///         }
+///     }
+/// }
+/// assert_eq!(rewriter.finish(), "SELECT u.id -- the user\nFROM ONLY app.users u\nWHERE u.email = $1");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rewriter<'a> {
+    sql: &'a str,
+    spans: SourceSpans<'a>,
+    edits: Vec<(Span, String)>,
+    // The spans of the operands of each `AND` and `OR` in the query, found when first deleting a node
+    bool_operands: Option<Vec<Vec<Span>>>,
+}
+
+impl<'a> Rewriter<'a> {
+    /// Starts rewriting `sql`, which must be the text the nodes passed to the edit methods were parsed from.
+    pub fn new(sql: &'a str) -> Result<Self> {
+        Ok(Self { sql, spans: SourceSpans::new(sql)?, edits: Vec::new(), bool_operands: None })
+    }
+
+    /// The spans used to locate nodes, e.g. to build edits for [Rewriter::replace_span].
+    pub fn spans(&self) -> &SourceSpans<'a> {
+        &self.spans
+    }
+
+    /// Replaces the text of `node`, see [SourceSpans::span].
+    pub fn replace(&mut self, node: NodeRef<'a>, text: impl Into<String>) -> Result<&mut Self> {
+        let span = self.span(node)?;
+        self.replace_span(span, text)
+    }
+
+    /// Replaces just the name of a `RangeVar`, `ColumnRef` or `FuncCall`, keeping e.g. its alias or
+    /// arguments, see [SourceSpans::name_span].
+    pub fn replace_name(&mut self, node: NodeRef, text: impl Into<String>) -> Result<&mut Self> {
+        let span = self.name_span(node)?;
+        self.replace_span(span, text)
+    }
+
+    /// Replaces the text within `span`.
+    pub fn replace_span(&mut self, span: Span, text: impl Into<String>) -> Result<&mut Self> {
+        self.add(span, text.into())
+    }
+
+    /// Inserts `text` directly before `node`.
+    pub fn insert_before(&mut self, node: NodeRef<'a>, text: impl Into<String>) -> Result<&mut Self> {
+        let span = self.span(node)?;
+        self.insert_at(span.start, text)
+    }
+
+    /// Inserts `text` directly before the name of a `RangeVar`, `ColumnRef` or `FuncCall`, e.g. to
+    /// add a schema to `ONLY users`.
+    pub fn insert_before_name(&mut self, node: NodeRef, text: impl Into<String>) -> Result<&mut Self> {
+        let span = self.name_span(node)?;
+        self.insert_at(span.start, text)
+    }
+
+    /// Inserts `text` at byte offset `offset`. Multiple insertions at the same offset are applied in
+    /// the order they were added, and before a replacement starting at that offset.
+    pub fn insert_at(&mut self, offset: usize, text: impl Into<String>) -> Result<&mut Self> {
+        self.add(Span::new(offset, offset), text.into())
+    }
+
+    /// Deletes `node`. When the node is an element of a comma-separated list, such as a target list
+    /// entry, a function argument or a `FROM` item, the adjacent comma is deleted as well. Likewise,
+    /// deleting an operand of `AND` or `OR` deletes the adjacent `AND` or `OR`.
+    pub fn delete(&mut self, node: NodeRef<'a>) -> Result<&mut Self> {
+        let span = self.span(node)?;
+        // Prefer the preceding separator, falling back to the following one when a neighbouring element
+        // has already been deleted together with that separator
+        let (before, after) = match self.bool_operand_neighbours(span)? {
+            Some((before, after)) => {
+                let span = self.parenthesized(span);
+                (before.map(|before| Span::new(before.end, span.end)), after.map(|after| Span::new(span.start, after.start)))
+            }
+            None => (
+                self.sql[..span.start].trim_end().strip_suffix(',').map(|rest| Span::new(rest.len(), span.end)),
+                self.sql[span.end..].trim_start().strip_prefix(',').map(|rest| Span::new(span.start, self.sql.len() - rest.trim_start().len())),
+            ),
+        };
+        let span = [before, after].into_iter().flatten().find(|candidate| self.conflict(candidate).is_none()).unwrap_or(span);
+        self.add(span, String::new())
+    }
+
+    /// Deletes the text within `span`.
+    pub fn delete_span(&mut self, span: Span) -> Result<&mut Self> {
+        self.add(span, String::new())
+    }
+
+    /// Returns the rewritten query text.
+    pub fn finish(&self) -> String {
+        let mut edits: Vec<&(Span, String)> = self.edits.iter().collect();
+        // Stable, so insertions at the same offset keep their order and come before a replacement
+        edits.sort_by_key(|(span, _)| (span.start, span.end));
+        let mut output = String::with_capacity(self.sql.len());
+        let mut offset = 0;
+        for (span, text) in edits {
+            output.push_str(&self.sql[offset..span.start]);
+            output.push_str(text);
+            offset = span.end;
+        }
+        output.push_str(&self.sql[offset..]);
+        output
+    }
+
+    fn span(&self, node: NodeRef<'a>) -> Result<Span> {
+        self.spans.span(node).ok_or_else(|| Error::Rewrite(format!("{} has no location in the query", node.type_name())))
+    }
+
+    fn name_span(&self, node: NodeRef) -> Result<Span> {
+        self.spans.name_span(node).ok_or_else(|| Error::Rewrite(format!("{} has no name in the query", node.type_name())))
+    }
+
+    // The spans of the operands before and after `span`, if it's an operand of `AND` or `OR`. All spans include
+    // parentheses around the operand, so that they are deleted along with it.
+    fn bool_operand_neighbours(&mut self, span: Span) -> Result<Option<(Option<Span>, Option<Span>)>> {
+        if self.bool_operands.is_none() {
+            // The spans of a separate parse can't be cached along with those of the caller's nodes
+            let result = parse(self.sql)?;
+            let spans = SourceSpans::new(self.sql)?;
+            let operands = result
+                .protobuf
+                .iter()
+                .filter_map(|node| match node {
+                    NodeRef::BoolExpr(expr) if expr.boolop != protobuf::BoolExprType::NotExpr as i32 => Some(
+                        expr.args.iter().filter_map(|arg| spans.span(arg.node.as_ref()?.to_ref())).map(|span| self.parenthesized(span)).collect(),
+                    ),
+                    _ => None,
+                })
+                .collect();
+            self.bool_operands = Some(operands);
+        }
+        let span = self.parenthesized(span);
+        let mut operands = self.bool_operands.iter().flatten();
+        let neighbours = operands.find_map(|operands| {
+            let i = operands.iter().position(|operand| *operand == span)?;
+            Some((i.checked_sub(1).map(|i| operands[i]), operands.get(i + 1).copied()))
+        });
+        Ok(neighbours)
+    }
+
+    // Extends `span` over the parentheses enclosing it, e.g. to `(a OR b)` for `a OR b`
+    fn parenthesized(&self, mut span: Span) -> Span {
+        loop {
+            let before = self.sql[..span.start].trim_end().strip_suffix('(');
+            let after = self.sql[span.end..].trim_start().strip_prefix(')');
+            match (before, after) {
+                (Some(before), Some(after)) => span = Span::new(before.len(), self.sql.len() - after.len()),
+                _ => return span,
+            }
+        }
+    }
+
+    fn add(&mut self, span: Span, text: String) -> Result<&mut Self> {
+        if span.start > span.end || span.end > self.sql.len() || !self.sql.is_char_boundary(span.start) || !self.sql.is_char_boundary(span.end) {
+            return Err(Error::Rewrite(format!("{}..{} is not a valid range in the query", span.start, span.end)));
+        }
+        if let Some(other) = self.conflict(&span) {
+            return Err(Error::Rewrite(format!("edit at {}..{} overlaps edit at {}..{}", span.start, span.end, other.start, other.end)));
+        }
+        self.edits.push((span, text));
+        Ok(self)
+    }
+
+    fn conflict(&self, span: &Span) -> Option<Span> {
+        self.edits.iter().map(|(other, _)| *other).find(|other| span.overlaps(other))
+    }
+}
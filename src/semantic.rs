@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::node_fields::{for_each_scalar, Scalar};
+use crate::node_path::for_each_child;
+use crate::protobuf::BoolExprType;
+use crate::*;
+
+/// Which differences [SemanticEq] and [SemanticHash] ignore, in addition to locations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SemanticOptions {
+    /// Ignore the names given by `AS` to tables, subqueries and output columns.
+    ///
+    /// Qualified column references are compared by which alias they refer to, so
+    /// `SELECT u.id FROM users u` equals `SELECT x.id FROM users x`.
+    pub ignore_aliases: bool,
+    /// Treat `AND` and `OR` as commutative and associative, so `a AND (b AND c)` equals `c AND b AND a`.
+    pub commutative_bool_ops: bool,
+}
+
+/// Equality of parse trees that ignores locations, so queries differing only in
+/// whitespace, comments or keyword case are equal.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{SemanticEq, SemanticOptions};
+///
+/// let a = pg_query::parse("SELECT * FROM users u WHERE u.id = $1 AND u.active").unwrap();
+/// let b = pg_query::parse("select *\n  from users u\n where u.id = $1 and u.active").unwrap();
+/// assert_ne!(a.protobuf, b.protobuf);
+/// assert!(a.semantic_eq(&b));
+///
+/// let c = pg_query::parse("SELECT * FROM users v WHERE v.active AND v.id = $1").unwrap();
+/// assert!(!a.semantic_eq(&c));
+/// let options = SemanticOptions { ignore_aliases: true, commutative_bool_ops: true };
+/// assert!(a.semantic_eq_with(&c, &options));
+/// ```
+pub trait SemanticEq {
+    /// Whether the trees are equal, ignoring locations.
+    fn semantic_eq(&self, other: &Self) -> bool {
+        self.semantic_eq_with(other, &SemanticOptions::default())
+    }
+
+    fn semantic_eq_with(&self, other: &Self, options: &SemanticOptions) -> bool;
+}
+
+/// Hashing of parse trees that is consistent with [SemanticEq]: trees that are
+/// semantically equal under the same options have the same hash.
+///
+/// Use [SemanticKey] to store trees in a `HashSet` or `HashMap`.
+pub trait SemanticHash {
+    fn semantic_hash<H: Hasher>(&self, state: &mut H) {
+        self.semantic_hash_with(&SemanticOptions::default(), state)
+    }
+
+    fn semantic_hash_with<H: Hasher>(&self, options: &SemanticOptions, state: &mut H);
+}
+
+/// Wraps a tree so that `Eq` and `Hash` use [SemanticEq] and [SemanticHash].
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use pg_query::SemanticKey;
+///
+/// let queries = ["SELECT a FROM t WHERE b = $1", "select a from t where b = $1", "SELECT a FROM t"];
+/// let unique: HashSet<_> = queries.iter().map(|q| SemanticKey::new(pg_query::parse(q).unwrap())).collect();
+/// assert_eq!(unique.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SemanticKey<T> {
+    pub value: T,
+    pub options: SemanticOptions,
+}
+
+impl<T> SemanticKey<T> {
+    pub fn new(value: T) -> Self {
+        Self { value, options: SemanticOptions::default() }
+    }
+
+    pub fn with_options(value: T, options: SemanticOptions) -> Self {
+        Self { value, options }
+    }
+}
+
+impl<T: SemanticEq> PartialEq for SemanticKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.options == other.options && self.value.semantic_eq_with(&other.value, &self.options)
+    }
+}
+
+impl<T: SemanticEq> Eq for SemanticKey<T> {}
+
+impl<T: SemanticHash> Hash for SemanticKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.semantic_hash_with(&self.options, state)
+    }
+}
+
+impl SemanticEq for NodeRef<'_> {
+    fn semantic_eq_with(&self, other: &Self, options: &SemanticOptions) -> bool {
+        canonical(*self, options) == canonical(*other, options)
+    }
+}
+
+impl SemanticHash for NodeRef<'_> {
+    fn semantic_hash_with<H: Hasher>(&self, options: &SemanticOptions, state: &mut H) {
+        canonical(*self, options).hash(state)
+    }
+}
+
+impl SemanticEq for NodeEnum {
+    fn semantic_eq_with(&self, other: &Self, options: &SemanticOptions) -> bool {
+        self.to_ref().semantic_eq_with(&other.to_ref(), options)
+    }
+}
+
+impl SemanticHash for NodeEnum {
+    fn semantic_hash_with<H: Hasher>(&self, options: &SemanticOptions, state: &mut H) {
+        self.to_ref().semantic_hash_with(options, state)
+    }
+}
+
+impl SemanticEq for protobuf::Node {
+    fn semantic_eq_with(&self, other: &Self, options: &SemanticOptions) -> bool {
+        match (&self.node, &other.node) {
+            (Some(a), Some(b)) => a.semantic_eq_with(b, options),
+            (a, b) => a.is_none() && b.is_none(),
+        }
+    }
+}
+
+impl SemanticHash for protobuf::Node {
+    fn semantic_hash_with<H: Hasher>(&self, options: &SemanticOptions, state: &mut H) {
+        if let Some(node) = &self.node {
+            node.semantic_hash_with(options, state)
+        }
+    }
+}
+
+impl SemanticEq for protobuf::ParseResult {
+    /// Compares the statements, ignoring the Postgres version and statement locations.
+    fn semantic_eq_with(&self, other: &Self, options: &SemanticOptions) -> bool {
+        statements(self, options) == statements(other, options)
+    }
+}
+
+impl SemanticHash for protobuf::ParseResult {
+    fn semantic_hash_with<H: Hasher>(&self, options: &SemanticOptions, state: &mut H) {
+        statements(self, options).hash(state)
+    }
+}
+
+impl SemanticEq for ParseResult {
+    fn semantic_eq_with(&self, other: &Self, options: &SemanticOptions) -> bool {
+        self.protobuf.semantic_eq_with(&other.protobuf, options)
+    }
+}
+
+impl SemanticHash for ParseResult {
+    fn semantic_hash_with<H: Hasher>(&self, options: &SemanticOptions, state: &mut H) {
+        self.protobuf.semantic_hash_with(options, state)
+    }
+}
+
+// A canonical form of a tree, which is equal for semantically equal trees
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Token<'a> {
+    Node(&'static str),
+    Field(&'static str),
+    End,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(u64),
+    Str(&'a str),
+    // The position of an alias among all aliases of the statement
+    Alias(usize),
+}
+
+fn statements<'a>(result: &'a protobuf::ParseResult, options: &SemanticOptions) -> Vec<Vec<Token<'a>>> {
+    result.stmts.iter().map(|s| s.stmt.as_ref().and_then(|s| s.node.as_ref()).map_or_else(Vec::new, |n| canonical(n.to_ref(), options))).collect()
+}
+
+fn canonical<'a>(root: NodeRef<'a>, options: &SemanticOptions) -> Vec<Token<'a>> {
+    let mut aliases = HashMap::new();
+    if options.ignore_aliases {
+        for node in root.iter() {
+            if let NodeRef::Alias(alias) = node {
+                let next = aliases.len();
+                aliases.entry(alias.aliasname.as_str()).or_insert(next);
+            }
+        }
+    }
+    let canonicalizer = Canonicalizer { options, aliases };
+    let mut tokens = Vec::new();
+    canonicalizer.node(root, false, &mut tokens);
+    tokens
+}
+
+struct Canonicalizer<'a, 'o> {
+    options: &'o SemanticOptions,
+    aliases: HashMap<&'a str, usize>,
+}
+
+impl<'a> Canonicalizer<'a, '_> {
+    // `output_column` is set for target list entries whose name is an alias
+    fn node(&self, node: NodeRef<'a>, output_column: bool, tokens: &mut Vec<Token<'a>>) {
+        tokens.push(Token::Node(node.type_name()));
+        for_each_scalar(node, &mut |name, value| {
+            match (name, value) {
+                ("location" | "name_location" | "stmt_location" | "stmt_len", _) => return,
+                ("aliasname", Scalar::Str(alias)) if self.options.ignore_aliases => {
+                    tokens.push(Token::Field(name));
+                    tokens.push(Token::Alias(self.aliases[alias]));
+                    return;
+                }
+                ("name", _) if output_column && self.options.ignore_aliases => return,
+                _ => {}
+            }
+            tokens.push(Token::Field(name));
+            match value {
+                Scalar::Bool(v) => tokens.push(Token::Bool(v)),
+                Scalar::Int(v) => tokens.push(Token::Int(v)),
+                Scalar::Enum(v, _) => tokens.push(Token::Int(v.into())),
+                Scalar::UInt(v) => tokens.push(Token::UInt(v)),
+                Scalar::Float(v) => tokens.push(Token::Float(v.to_bits())),
+                Scalar::Str(v) => tokens.push(Token::Str(v)),
+                Scalar::UInts(v) => {
+                    tokens.push(Token::UInt(v.len() as u64));
+                    tokens.extend(v.iter().map(|v| Token::UInt(*v)));
+                }
+            }
+        });
+
+        if let Some(args) = self.commutative_args(node) {
+            tokens.push(Token::Field("args"));
+            let mut args: Vec<_> = args
+                .into_iter()
+                .map(|arg| {
+                    let mut tokens = Vec::new();
+                    self.node(arg, false, &mut tokens);
+                    tokens
+                })
+                .collect();
+            args.sort();
+            tokens.extend(args.into_iter().flatten());
+        } else {
+            let qualifier = self.alias_qualifier(node);
+            for_each_child(node, &mut |step, child| {
+                tokens.push(Token::Field(step.field));
+                match qualifier {
+                    Some(alias) if step.index == Some(0) => tokens.push(Token::Alias(alias)),
+                    _ => {
+                        let output_column = matches!((step.node_type, step.field), ("SelectStmt", "target_list") | (_, "returning_list"));
+                        self.node(child, output_column, tokens)
+                    }
+                }
+            });
+        }
+        tokens.push(Token::End);
+    }
+
+    // The operands of an AND or OR, including those of nested ANDs or ORs of the same kind
+    fn commutative_args(&self, node: NodeRef<'a>) -> Option<Vec<NodeRef<'a>>> {
+        let NodeRef::BoolExpr(expr) = node else { return None };
+        if !self.options.commutative_bool_ops || expr.boolop == BoolExprType::NotExpr as i32 {
+            return None;
+        }
+        let mut args = Vec::new();
+        let mut pending: Vec<_> = expr.args.iter().rev().filter_map(|arg| arg.node.as_ref()).collect();
+        while let Some(arg) = pending.pop() {
+            match arg {
+                NodeEnum::BoolExpr(nested) if nested.boolop == expr.boolop => {
+                    pending.extend(nested.args.iter().rev().filter_map(|arg| arg.node.as_ref()))
+                }
+                arg => args.push(arg.to_ref()),
+            }
+        }
+        Some(args)
+    }
+
+    // The alias that a qualified column reference such as `u.id` starts with
+    fn alias_qualifier(&self, node: NodeRef<'a>) -> Option<usize> {
+        let NodeRef::ColumnRef(column) = node else { return None };
+        if column.fields.len() < 2 {
+            return None;
+        }
+        match column.fields[0].node.as_ref() {
+            Some(NodeEnum::String(s)) => self.aliases.get(s.sval.as_str()).copied(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,586 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use crate::node_path::for_each_child;
+use crate::protobuf::{ConstrType, KeywordKind, ScanToken, SetOperation, Token};
+use crate::*;
+
+/// A byte range within the original query text.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Span {
+    /// Byte offset of the first character.
+    pub start: usize,
+    /// Byte offset just past the last character.
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Whether the two spans share at least one byte.
+    pub fn overlaps(&self, other: &Span) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Whether `other` lies entirely within this span.
+    pub fn contains(&self, other: &Span) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// The text covered by this span.
+    pub fn text<'a>(&self, sql: &'a str) -> &'a str {
+        &sql[self.start..self.end]
+    }
+}
+
+/// Computes the span of any node within the query text it was parsed from.
+///
+/// Postgres only records where some nodes start, so the span of a node is derived from the locations
+/// of the node and its descendants, extended over the tokens that belong to it, such as closing
+/// parentheses, `IS NULL`, `END` or an alias. Leading and trailing comments are not included.
+///
+/// Spans are cached per node, so the nodes passed in stay borrowed for as long as the `SourceSpans` is used.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeRef, SourceSpans};
+///
+/// let query = "SELECT coalesce(a, 0) AS total FROM public.t t1 WHERE b IS NOT NULL";
+/// let result = pg_query::parse(query).unwrap();
+/// let spans = SourceSpans::new(query).unwrap();
+///
+/// let texts: Vec<&str> = result
+///     .protobuf
+///     .iter()
+///     .filter(|node| matches!(node, NodeRef::ResTarget(_) | NodeRef::RangeVar(_) | NodeRef::NullTest(_)))
+///     .filter_map(|node| spans.text(node))
+///     .collect();
+/// assert_eq!(texts, ["coalesce(a, 0) AS total", "public.t t1", "b IS NOT NULL"]);
+/// ```
+#[derive(Debug)]
+pub struct SourceSpans<'a> {
+    sql: &'a str,
+    tokens: Vec<ScanToken>,
+    // The token range of every node seen so far by address and type, since the range of a node is computed from those
+    // of all its descendants
+    ranges: Mutex<HashMap<NodeKey, Option<(usize, usize)>>>,
+    // Nodes are borrowed for `'a`, so they can't be freed or modified while their ranges are cached
+    nodes: PhantomData<fn(NodeRef<'a>) -> NodeRef<'a>>,
+}
+
+// The address and type of a node
+type NodeKey = (usize, &'static str);
+
+impl Clone for SourceSpans<'_> {
+    fn clone(&self) -> Self {
+        let ranges = Mutex::new(self.ranges.lock().unwrap().clone());
+        Self { sql: self.sql, tokens: self.tokens.clone(), ranges, nodes: PhantomData }
+    }
+}
+
+impl<'a> SourceSpans<'a> {
+    /// Scans `sql`, which must be the text the nodes were parsed from.
+    pub fn new(sql: &'a str) -> Result<Self> {
+        let tokens = scan(sql)?.tokens.into_iter().filter(|t| !matches!(Token::try_from(t.token), Ok(Token::SqlComment | Token::CComment))).collect();
+        Ok(Self { sql, tokens, ranges: Mutex::default(), nodes: PhantomData })
+    }
+
+    /// The span of `node`, or `None` if neither the node nor any of its descendants has a location,
+    /// such as for the `String` nodes of a qualified name.
+    pub fn span(&self, node: NodeRef<'a>) -> Option<Span> {
+        let (first, last) = self.token_range(node)?;
+        Some(Span::new(self.tokens[first].start as usize, self.tokens[last].end as usize))
+    }
+
+    /// The text of `node`, see [SourceSpans::span].
+    pub fn text(&self, node: NodeRef<'a>) -> Option<&'a str> {
+        self.span(node).map(|span| span.text(self.sql))
+    }
+
+    /// The span of just the name of a `RangeVar`, `ColumnRef` or `FuncCall`, including its schema or
+    /// table qualifier, e.g. `public.users` in `ONLY public.users AS u`.
+    pub fn name_span(&self, node: NodeRef) -> Option<Span> {
+        if !matches!(node, NodeRef::RangeVar(_) | NodeRef::ColumnRef(_) | NodeRef::FuncCall(_)) {
+            return None;
+        }
+        let first = location(node).and_then(|location| self.token_at(location))?;
+        let last = self.qualified_name(first);
+        Some(Span::new(self.tokens[first].start as usize, self.tokens[last].end as usize))
+    }
+
+    /// The span of a top-level statement, without surrounding whitespace, comments and the semicolon.
+    ///
+    /// Unlike `stmt_len`, this also works for the last statement, whose length Postgres reports as 0.
+    pub fn statement_span(&self, stmt: &protobuf::RawStmt) -> Option<Span> {
+        let start = stmt.stmt_location as usize;
+        let end = if stmt.stmt_len > 0 { start + stmt.stmt_len as usize } else { self.sql.len() };
+        let mut tokens = self.tokens.iter().filter(|t| t.start as usize >= start && t.end as usize <= end && !self.is_token(t, ";"));
+        let first = tokens.next()?;
+        let last = tokens.next_back().unwrap_or(first);
+        Some(Span::new(first.start as usize, last.end as usize))
+    }
+
+    // Returns the indices of the first and last token of `node`
+    fn token_range(&self, node: NodeRef<'a>) -> Option<(usize, usize)> {
+        let key = (node.as_ptr() as usize, node.type_name());
+        if let Some(range) = self.ranges.lock().unwrap().get(&key) {
+            return *range;
+        }
+        let range = self.compute_token_range(node);
+        self.ranges.lock().unwrap().insert(key, range);
+        range
+    }
+
+    fn compute_token_range(&self, node: NodeRef<'a>) -> Option<(usize, usize)> {
+        let own = location(node).and_then(|location| self.token_at(location));
+        let mut range = own.map(|i| (i, i));
+        for_each_child(node, &mut |_, child| {
+            if let Some((first, last)) = self.token_range(child) {
+                range = Some(range.map_or((first, last), |(f, l)| (f.min(first), l.max(last))));
+            }
+        });
+        let (mut first, mut last) = range?;
+        (first, last) = self.balance(first, last);
+
+        match node {
+            NodeRef::ColumnRef(_) | NodeRef::FuncCall(_) | NodeRef::TypeName(_) => {
+                if let Some(own) = own {
+                    last = last.max(self.qualified_name(own));
+                }
+                if let (NodeRef::FuncCall(_), Some(own)) = (node, own) {
+                    let name_end = self.qualified_name(own);
+                    if self.is(name_end + 1, "(") {
+                        last = last.max(self.matching(name_end + 1).unwrap_or(last));
+                    }
+                }
+                if let NodeRef::TypeName(_) = node {
+                    last = self.type_name_end(last);
+                }
+            }
+            NodeRef::RangeVar(r) => {
+                if let Some(own) = own {
+                    last = last.max(self.qualified_name(own));
+                }
+                if !r.inh && self.is_word(first.wrapping_sub(1), "only") {
+                    first -= 1;
+                }
+                last = self.alias_end(last, r.alias.as_ref());
+            }
+            NodeRef::RangeSubselect(r) => {
+                if self.is(first.wrapping_sub(1), "(") && self.matching(first - 1) == Some(last + 1) {
+                    first -= 1;
+                    last += 1;
+                }
+                if r.lateral && self.is_word(first.wrapping_sub(1), "lateral") {
+                    first -= 1;
+                }
+                last = self.alias_end(last, r.alias.as_ref());
+            }
+            NodeRef::RangeFunction(r) => {
+                if r.lateral && self.is_word(first.wrapping_sub(1), "lateral") {
+                    first -= 1;
+                }
+                last = self.alias_end(last, r.alias.as_ref());
+            }
+            NodeRef::RangeTableFunc(r) => last = self.alias_end(last, r.alias.as_ref()),
+            NodeRef::JoinExpr(j) => {
+                if j.alias.is_some() && self.is(first.wrapping_sub(1), "(") && self.matching(first - 1) == Some(last + 1) {
+                    first -= 1;
+                    last += 1;
+                }
+                if !j.using_clause.is_empty() && self.is_word(last + 1, "using") && self.is(last + 2, "(") {
+                    last = self.matching(last + 2).unwrap_or(last);
+                }
+                last = self.alias_end(last, j.alias.as_ref());
+            }
+            NodeRef::ResTarget(r) if r.val.is_some() && !r.name.is_empty() => {
+                if self.is_word(last + 1, "as") && self.is_name(last + 2, &r.name) {
+                    last += 2;
+                } else if self.is_name(last + 1, &r.name) {
+                    last += 1;
+                }
+            }
+            NodeRef::AIndirection(_) => {
+                // The argument of `(row).field` is parenthesized
+                if self.is(first.wrapping_sub(1), "(") && self.matching(first - 1) == Some(last + 1) {
+                    first -= 1;
+                    last += 1;
+                }
+                loop {
+                    if self.is(last + 1, ".") && (self.is_identifier(last + 2) || self.is(last + 2, "*")) {
+                        last += 2;
+                    } else if self.is(last + 1, "[") {
+                        last = self.matching(last + 1).unwrap_or(last + 1);
+                    } else {
+                        break;
+                    }
+                }
+            }
+            // Precision, e.g. `CURRENT_TIMESTAMP(3)`
+            NodeRef::SqlvalueFunction(_) if self.is(last + 1, "(") => last = self.matching(last + 1).unwrap_or(last),
+            NodeRef::OnConflictClause(_) => last = self.words_end(last, &["do", "nothing"]),
+            // Negative numbers are located at the sign
+            NodeRef::AConst(_) if own.is_some_and(|own| self.is(own, "-") || self.is(own, "+")) => last = last.max(first + 1),
+            NodeRef::NullTest(_) => last = self.words_end(last, &["not", "null"]),
+            NodeRef::BooleanTest(_) => last = self.words_end(last, &["not", "true", "false", "unknown"]),
+            NodeRef::SortBy(_) => {
+                last = self.words_end(last, &["asc", "desc", "nulls", "first", "last"]);
+                if self.is_word(last + 1, "using") {
+                    last = self.words_end(last + 2, &["asc", "desc", "nulls", "first", "last"]);
+                }
+            }
+            NodeRef::CaseExpr(_) => {
+                if let Some(end) = own.and_then(|own| self.case_end(own)) {
+                    last = last.max(end);
+                }
+            }
+            NodeRef::CollateClause(_) => {
+                if let Some(own) = own.filter(|own| self.is_word(*own, "collate")) {
+                    last = last.max(self.qualified_name(own + 1));
+                }
+            }
+            NodeRef::WindowDef(_) => {
+                if let Some(own) = own.filter(|own| self.is(*own, "(")) {
+                    last = last.max(self.matching(own).unwrap_or(last));
+                }
+            }
+            NodeRef::Constraint(c) => last = self.constraint_end(c, own.unwrap_or(first), last),
+            NodeRef::SelectStmt(s) => {
+                if s.op == SetOperation::SetopNone as i32 {
+                    first = self.keyword_start(first, &["select", "values", "table"]);
+                }
+                if !s.locking_clause.is_empty() || s.limit_count.is_some() || s.limit_offset.is_some() {
+                    last = self.words_end(last, &["for", "update", "share", "no", "key", "nowait", "skip", "locked", "rows", "row", "only"]);
+                }
+            }
+            NodeRef::InsertStmt(_) => first = self.keyword_start(first, &["insert"]),
+            NodeRef::UpdateStmt(_) => first = self.keyword_start(first, &["update"]),
+            NodeRef::DeleteStmt(_) => first = self.keyword_start(first, &["delete"]),
+            NodeRef::MergeStmt(_) => first = self.keyword_start(first, &["merge"]),
+            // Other statements span everything up to the surrounding semicolons, unless they are nested in
+            // `CREATE SCHEMA` or `EXPLAIN`
+            _ if node.type_name().ends_with("Stmt") => {
+                let (mut start, mut end) = (first, last);
+                while start > 0 && !self.is(start - 1, ";") && !self.is(start - 1, "(") {
+                    start = self.matching(start - 1).filter(|_| self.is(start - 1, ")")).unwrap_or(start - 1);
+                }
+                while end + 1 < self.tokens.len() && !self.is(end + 1, ";") && !self.is(end + 1, ")") {
+                    end = self.matching(end + 1).filter(|_| self.is(end + 1, "(")).unwrap_or(end + 1);
+                }
+                let schema = self.is_word(start, "create") && self.is_word(start + 1, "schema");
+                let nested = !matches!(node, NodeRef::CreateSchemaStmt(_) | NodeRef::ExplainStmt(_)) && (schema || self.is_word(start, "explain"));
+                if nested {
+                    // Starts at its own keyword, and a schema element ends where the next one starts
+                    let starts = ["create", "grant", "execute"];
+                    first = (start + 1..=first).rev().find(|&i| starts.iter().any(|word| self.is_word(i, word))).unwrap_or(first);
+                    last = (last + 1..=end).find(|&i| schema && (self.is_word(i, "create") || self.is_word(i, "grant"))).map_or(end, |i| i - 1);
+                } else {
+                    (first, last) = (start, end);
+                }
+            }
+            _ => {}
+        }
+        Some(self.balance(first, last))
+    }
+
+    // Returns the index of the token at byte offset `location`
+    fn token_at(&self, location: usize) -> Option<usize> {
+        let i = self.tokens.partition_point(|t| t.start as usize <= location);
+        match i.checked_sub(1) {
+            Some(i) if location < self.tokens[i].end as usize => Some(i),
+            _ => (i < self.tokens.len()).then_some(i),
+        }
+    }
+
+    fn is_token(&self, token: &ScanToken, text: &str) -> bool {
+        self.sql[token.start as usize..token.end as usize].eq_ignore_ascii_case(text)
+    }
+
+    fn is(&self, i: usize, text: &str) -> bool {
+        self.tokens.get(i).is_some_and(|t| self.is_token(t, text))
+    }
+
+    fn is_word(&self, i: usize, word: &str) -> bool {
+        self.tokens.get(i).is_some_and(|t| t.keyword_kind != KeywordKind::NoKeyword as i32 && self.is_token(t, word))
+    }
+
+    fn is_identifier(&self, i: usize) -> bool {
+        self.tokens.get(i).is_some_and(|t| t.token == Token::Ident as i32 || t.keyword_kind != KeywordKind::NoKeyword as i32)
+    }
+
+    // Whether the token at `i` is an identifier matching `name`, e.g. `"Total"` for `Total` or `total` for `total`
+    fn is_name(&self, i: usize, name: &str) -> bool {
+        if !self.is_identifier(i) {
+            return false;
+        }
+        let text = &self.sql[self.tokens[i].start as usize..self.tokens[i].end as usize];
+        match text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+            Some(quoted) => quoted.replace("\"\"", "\"") == name,
+            None => text.to_lowercase() == name,
+        }
+    }
+
+    // Returns the index of the bracket matching the one at `i`
+    fn matching(&self, i: usize) -> Option<usize> {
+        let forward = self.is(i, "(") || self.is(i, "[");
+        if !forward && !self.is(i, ")") && !self.is(i, "]") {
+            return None;
+        }
+        let mut depth = 0;
+        let mut j = i;
+        loop {
+            if self.is(j, "(") || self.is(j, "[") {
+                depth += if forward { 1 } else { -1 };
+            } else if self.is(j, ")") || self.is(j, "]") {
+                depth += if forward { -1 } else { 1 };
+            }
+            if depth == 0 {
+                return Some(j);
+            }
+            if depth < 0 {
+                return None;
+            }
+            j = if forward { j + 1 } else { j.checked_sub(1)? };
+            if j >= self.tokens.len() {
+                return None;
+            }
+        }
+    }
+
+    // Extends the range until all brackets within it are matched
+    fn balance(&self, mut first: usize, mut last: usize) -> (usize, usize) {
+        let mut i = first;
+        while i <= last {
+            if self.is(i, "(") || self.is(i, "[") {
+                match self.matching(i) {
+                    Some(end) => {
+                        last = last.max(end);
+                        i = end;
+                    }
+                    None => return (first, last),
+                }
+            } else if self.is(i, ")") || self.is(i, "]") {
+                match self.matching(i) {
+                    Some(start) if start < first => first = start,
+                    Some(_) => {}
+                    None => return (first, last),
+                }
+            }
+            i += 1;
+        }
+        (first, last)
+    }
+
+    // Returns the last token of a dotted name starting at `i`, e.g. `public.t` or `t.*`
+    fn qualified_name(&self, mut i: usize) -> usize {
+        while self.is(i + 1, ".") && (self.is_identifier(i + 2) || self.is(i + 2, "*")) {
+            i += 2;
+        }
+        i
+    }
+
+    fn words_end(&self, mut last: usize, words: &[&str]) -> usize {
+        while words.iter().any(|word| self.is_word(last + 1, word)) {
+            last += 1;
+        }
+        last
+    }
+
+    // Includes multi-word type names, e.g. `double precision` or `timestamp with time zone`, and array bounds
+    fn type_name_end(&self, mut last: usize) -> usize {
+        loop {
+            if self.is(last + 1, "[") {
+                match self.matching(last + 1) {
+                    Some(end) => last = end,
+                    None => return last,
+                }
+            } else if ["precision", "varying", "with", "without", "time", "zone", "array"].iter().any(|word| self.is_word(last + 1, word)) {
+                last += 1;
+            } else {
+                return last;
+            }
+        }
+    }
+
+    fn alias_end(&self, last: usize, alias: Option<&protobuf::Alias>) -> usize {
+        let Some(alias) = alias else { return last };
+        let name = if self.is_word(last + 1, "as") { last + 2 } else { last + 1 };
+        if !self.is_name(name, &alias.aliasname) {
+            return last;
+        }
+        if !alias.colnames.is_empty() && self.is(name + 1, "(") {
+            return self.matching(name + 1).unwrap_or(name);
+        }
+        name
+    }
+
+    // Returns the END matching the CASE at `i`
+    fn case_end(&self, i: usize) -> Option<usize> {
+        let mut depth = 0;
+        for j in i..self.tokens.len() {
+            if self.is_word(j, "case") {
+                depth += 1;
+            } else if self.is_word(j, "end") {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(j);
+                }
+            }
+        }
+        None
+    }
+
+    // Moves `first` back to the keyword starting a statement, e.g. from `t` to `DELETE` in `DELETE FROM t`
+    fn keyword_start(&self, first: usize, keywords: &[&str]) -> usize {
+        if keywords.iter().chain(&["with"]).any(|keyword| self.is_word(first, keyword)) {
+            return first;
+        }
+        let mut i = first;
+        while i > 0 {
+            i -= 1;
+            if keywords.iter().any(|keyword| self.is_word(i, keyword)) {
+                return i;
+            }
+            if !(self.is(i, "(") || ["distinct", "on", "all", "into", "from", "only"].iter().any(|word| self.is_word(i, word))) {
+                break;
+            }
+        }
+        first
+    }
+
+    // Constraints end where the next constraint or column definition starts
+    fn constraint_end(&self, constraint: &protobuf::Constraint, own: usize, mut last: usize) -> usize {
+        let starts = ["constraint", "primary", "unique", "check", "references", "collate", "generated", "default", "null"];
+        if self.is_word(own, "constraint") {
+            // Skip the constraint name and the keyword that follows it
+            last = last.max(own + 2);
+        } else if constraint.contype == ConstrType::ConstrNotnull as i32 && self.is_word(own, "not") {
+            last = last.max(own + 1);
+        }
+        // A table-level `FOREIGN KEY (a) REFERENCES t` contains the keyword of a column-level foreign key
+        let mut references = !(self.is_word(own, "foreign") || self.is_word(own + 2, "foreign"));
+        while let Some(token) = self.tokens.get(last + 1) {
+            let next = last + 1;
+            if self.is_token(token, ",") || self.is_token(token, ")") || self.is_token(token, ";") {
+                break;
+            }
+            // `NOT NULL`, but not `NOT DEFERRABLE` or `NULLS NOT DISTINCT`, and neither `SET NULL` nor `SET DEFAULT`
+            let starts_constraint = starts.iter().any(|word| self.is_word(next, word)) || self.is_word(next, "not") && self.is_word(next + 1, "null");
+            let after_set = self.is_word(last, "set") || self.is_word(last, "not");
+            if self.is_word(next, "references") && !references {
+                references = true;
+            } else if starts_constraint && !after_set {
+                break;
+            }
+            last = if self.is_token(token, "(") { self.matching(next).unwrap_or(next) } else { next };
+        }
+        last
+    }
+}
+
+// BEGIN GENERATED by scripts/generate.py from src/protobuf.rs, do not edit by hand
+// The `location` field of the node, if it has one and it is set.
+fn location(node: NodeRef) -> Option<usize> {
+    let location = match node {
+        NodeRef::RangeVar(n) => n.location,
+        NodeRef::TableFunc(n) => n.location,
+        NodeRef::Var(n) => n.location,
+        NodeRef::Param(n) => n.location,
+        NodeRef::Aggref(n) => n.location,
+        NodeRef::GroupingFunc(n) => n.location,
+        NodeRef::WindowFunc(n) => n.location,
+        NodeRef::MergeSupportFunc(n) => n.location,
+        NodeRef::FuncExpr(n) => n.location,
+        NodeRef::NamedArgExpr(n) => n.location,
+        NodeRef::OpExpr(n) => n.location,
+        NodeRef::DistinctExpr(n) => n.location,
+        NodeRef::NullIfExpr(n) => n.location,
+        NodeRef::ScalarArrayOpExpr(n) => n.location,
+        NodeRef::BoolExpr(n) => n.location,
+        NodeRef::SubLink(n) => n.location,
+        NodeRef::RelabelType(n) => n.location,
+        NodeRef::CoerceViaIo(n) => n.location,
+        NodeRef::ArrayCoerceExpr(n) => n.location,
+        NodeRef::ConvertRowtypeExpr(n) => n.location,
+        NodeRef::CollateExpr(n) => n.location,
+        NodeRef::CaseExpr(n) => n.location,
+        NodeRef::CaseWhen(n) => n.location,
+        NodeRef::ArrayExpr(n) => n.location,
+        NodeRef::RowExpr(n) => n.location,
+        NodeRef::CoalesceExpr(n) => n.location,
+        NodeRef::MinMaxExpr(n) => n.location,
+        NodeRef::SqlvalueFunction(n) => n.location,
+        NodeRef::XmlExpr(n) => n.location,
+        NodeRef::JsonFormat(n) => n.location,
+        NodeRef::JsonConstructorExpr(n) => n.location,
+        NodeRef::JsonIsPredicate(n) => n.location,
+        NodeRef::JsonBehavior(n) => n.location,
+        NodeRef::JsonExpr(n) => n.location,
+        NodeRef::NullTest(n) => n.location,
+        NodeRef::BooleanTest(n) => n.location,
+        NodeRef::CoerceToDomain(n) => n.location,
+        NodeRef::CoerceToDomainValue(n) => n.location,
+        NodeRef::SetToDefault(n) => n.location,
+        NodeRef::TypeName(n) => n.location,
+        NodeRef::ColumnRef(n) => n.location,
+        NodeRef::ParamRef(n) => n.location,
+        NodeRef::AExpr(n) => n.location,
+        NodeRef::TypeCast(n) => n.location,
+        NodeRef::CollateClause(n) => n.location,
+        NodeRef::RoleSpec(n) => n.location,
+        NodeRef::FuncCall(n) => n.location,
+        NodeRef::AArrayExpr(n) => n.location,
+        NodeRef::ResTarget(n) => n.location,
+        NodeRef::SortBy(n) => n.location,
+        NodeRef::WindowDef(n) => n.location,
+        NodeRef::RangeTableFunc(n) => n.location,
+        NodeRef::RangeTableFuncCol(n) => n.location,
+        NodeRef::RangeTableSample(n) => n.location,
+        NodeRef::ColumnDef(n) => n.location,
+        NodeRef::DefElem(n) => n.location,
+        NodeRef::XmlSerialize(n) => n.location,
+        NodeRef::PartitionElem(n) => n.location,
+        NodeRef::PartitionSpec(n) => n.location,
+        NodeRef::PartitionBoundSpec(n) => n.location,
+        NodeRef::PartitionRangeDatum(n) => n.location,
+        NodeRef::GroupingSet(n) => n.location,
+        NodeRef::WithClause(n) => n.location,
+        NodeRef::InferClause(n) => n.location,
+        NodeRef::OnConflictClause(n) => n.location,
+        NodeRef::CtesearchClause(n) => n.location,
+        NodeRef::CtecycleClause(n) => n.location,
+        NodeRef::CommonTableExpr(n) => n.location,
+        NodeRef::JsonFuncExpr(n) => n.location,
+        NodeRef::JsonTablePathSpec(n) => n.location,
+        NodeRef::JsonTable(n) => n.location,
+        NodeRef::JsonTableColumn(n) => n.location,
+        NodeRef::JsonParseExpr(n) => n.location,
+        NodeRef::JsonScalarExpr(n) => n.location,
+        NodeRef::JsonSerializeExpr(n) => n.location,
+        NodeRef::JsonObjectConstructor(n) => n.location,
+        NodeRef::JsonArrayConstructor(n) => n.location,
+        NodeRef::JsonArrayQueryConstructor(n) => n.location,
+        NodeRef::JsonAggConstructor(n) => n.location,
+        NodeRef::PlassignStmt(n) => n.location,
+        NodeRef::Constraint(n) => n.location,
+        NodeRef::TransactionStmt(n) => n.location,
+        NodeRef::DeallocateStmt(n) => n.location,
+        NodeRef::PublicationObjSpec(n) => n.location,
+        NodeRef::AConst(n) => n.location,
+        _ => return None,
+    };
+    usize::try_from(location).ok()
+}
+// END GENERATED
@@ -5,7 +5,7 @@ use prost::Message;
 use crate::bindings::*;
 use crate::error::*;
 use crate::protobuf;
-use crate::summary_result::SummaryResult;
+use crate::summary_result::{FilterColumn, SummaryResult};
 
 /// Parses the given SQL statement and provides a summary of it.
 ///
@@ -20,6 +20,11 @@ use crate::summary_result::SummaryResult;
 /// You can run `cargo bench parse_vs_summary` to run the benchmarks that
 /// comparse the two options.
 ///
+/// Resolving `filter_columns` through aliases, subqueries and CTEs needs the
+/// parse tree, so queries with filter columns are parsed as well. This gives
+/// up most of the speed of `summary` for them: compare the `summary` and
+/// `summary without filter columns` benchmarks.
+///
 /// # Example
 ///
 /// ```rust
@@ -34,13 +39,21 @@ pub fn summary(statement: &str, truncate_limit: i32) -> Result<SummaryResult> {
     let input = CString::new(statement)?;
     let result = unsafe { pg_query_summary(input.as_ptr(), 0, truncate_limit) };
     let parse_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Parse(Box::new(unsafe { ParseError::from_raw(&*result.error, statement) })))
     } else {
         let data = unsafe { std::slice::from_raw_parts(result.summary.data as *const u8, result.summary.len as usize) };
         let stderr = unsafe { CStr::from_ptr(result.stderr_buffer) }.to_string_lossy().to_string();
         protobuf::SummaryResult::decode(data).map_err(Error::Decode).map(|result| SummaryResult::new(result, stderr))
     };
     unsafe { pg_query_free_summary_parse_result(result) };
-    parse_result
+    let mut summary_result = parse_result?;
+    // The summary has no parse tree to resolve aliases, subqueries and CTEs with, so filter columns need a full parse
+    if !summary_result.filter_columns.is_empty() {
+        summary_result.filter_columns = crate::lineage::resolve_names(&crate::parse(statement)?.protobuf)
+            .filter_columns
+            .into_iter()
+            .map(|c| FilterColumn { schema_name: c.schema, table_name: c.table, column: c.column })
+            .collect();
+    }
+    Ok(summary_result)
 }
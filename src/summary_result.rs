@@ -27,6 +27,9 @@ pub struct SummaryResult {
     pub aliases: HashMap<String, String>,
     pub cte_names: Vec<String>,
     pub functions: Vec<Function>,
+    /// Columns used in filters, resolved to the underlying tables like
+    /// [`ParseResult::filter_columns`]. Resolving them needs a full parse of
+    /// the query, which [summary] does whenever there are any.
     pub filter_columns: Vec<FilterColumn>,
     pub truncated_query: String,
     pub statement_types: Vec<String>,
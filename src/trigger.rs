@@ -0,0 +1,177 @@
+use crate::parse_result::relation_name;
+use crate::*;
+
+/// When a trigger fires relative to the event, see [TriggerDefinition].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TriggerTiming {
+    Before,
+    After,
+    InsteadOf,
+}
+
+/// An event that fires a trigger, see [TriggerDefinition].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TriggerEvent {
+    Insert,
+    /// `UPDATE`, or `UPDATE OF` the given columns
+    Update {
+        columns: Vec<String>,
+    },
+    Delete,
+    Truncate,
+}
+
+/// A transition table of a trigger's `REFERENCING` clause, e.g. `NEW TABLE AS inserted`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TransitionTable {
+    pub name: String,
+    /// Whether this holds the new rows rather than the old ones
+    pub is_new: bool,
+}
+
+/// A trigger as defined by `CREATE TRIGGER`, see [ParseResult::triggers].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TriggerDefinition {
+    pub name: String,
+    /// `CREATE OR REPLACE TRIGGER`
+    pub replace: bool,
+    /// The table, view or foreign table the trigger is defined on, as `schema.table` if qualified
+    pub relation: String,
+    pub timing: TriggerTiming,
+    pub events: Vec<TriggerEvent>,
+    /// `FOR EACH ROW`, rather than `FOR EACH STATEMENT`
+    pub for_each_row: bool,
+    /// The `WHEN` condition, which can be turned back into SQL with [NodeEnum::deparse]
+    pub when: Option<NodeEnum>,
+    pub transition_tables: Vec<TransitionTable>,
+    /// `CREATE CONSTRAINT TRIGGER`
+    pub is_constraint: bool,
+    pub deferrable: bool,
+    pub initially_deferred: bool,
+    /// The table referenced by `FROM` of a constraint trigger
+    pub referenced_relation: Option<String>,
+    /// The function to execute, as `schema.function` if qualified
+    pub function: String,
+    /// The arguments passed to the function, which are always strings
+    pub arguments: Vec<String>,
+}
+
+impl TriggerDefinition {
+    /// Returns the trigger type as a bitmask of [TriggerType], like `tgtype` of the `pg_trigger` catalog.
+    pub fn trigger_type(&self) -> i32 {
+        let mut bits = 0;
+        if self.for_each_row {
+            bits |= TriggerType::Row as i32;
+        }
+        match self.timing {
+            TriggerTiming::Before => bits |= TriggerType::Before as i32,
+            TriggerTiming::InsteadOf => bits |= TriggerType::Instead as i32,
+            TriggerTiming::After => {}
+        }
+        for event in &self.events {
+            bits |= match event {
+                TriggerEvent::Insert => TriggerType::Insert,
+                TriggerEvent::Update { .. } => TriggerType::Update,
+                TriggerEvent::Delete => TriggerType::Delete,
+                TriggerEvent::Truncate => TriggerType::Truncate,
+            } as i32;
+        }
+        bits
+    }
+}
+
+impl From<&protobuf::CreateTrigStmt> for TriggerDefinition {
+    fn from(v: &protobuf::CreateTrigStmt) -> Self {
+        let has = |bits: i32, flag: TriggerType| bits & flag as i32 != 0;
+        let timing = if has(v.timing, TriggerType::Instead) {
+            TriggerTiming::InsteadOf
+        } else if has(v.timing, TriggerType::Before) {
+            TriggerTiming::Before
+        } else {
+            TriggerTiming::After
+        };
+        // Events are listed in the order Postgres shows them, e.g. in `pg_get_triggerdef`
+        let mut events = Vec::new();
+        if has(v.events, TriggerType::Insert) {
+            events.push(TriggerEvent::Insert);
+        }
+        if has(v.events, TriggerType::Delete) {
+            events.push(TriggerEvent::Delete);
+        }
+        if has(v.events, TriggerType::Update) {
+            events.push(TriggerEvent::Update { columns: strings(&v.columns) });
+        }
+        if has(v.events, TriggerType::Truncate) {
+            events.push(TriggerEvent::Truncate);
+        }
+        let transition_tables = v
+            .transition_rels
+            .iter()
+            .filter_map(|n| match &n.node {
+                Some(NodeEnum::TriggerTransition(t)) => Some(TransitionTable { name: t.name.to_owned(), is_new: t.is_new }),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            name: v.trigname.to_owned(),
+            replace: v.replace,
+            relation: v.relation.as_ref().map(relation_name).unwrap_or_default(),
+            timing,
+            events,
+            for_each_row: v.row,
+            when: v.when_clause.as_ref().and_then(|n| n.node.clone()),
+            transition_tables,
+            is_constraint: v.isconstraint,
+            deferrable: v.deferrable,
+            initially_deferred: v.initdeferred,
+            referenced_relation: v.constrrel.as_ref().map(relation_name),
+            function: strings(&v.funcname).join("."),
+            arguments: strings(&v.args),
+        }
+    }
+}
+
+impl ParseResult {
+    /// Returns the triggers defined by `CREATE TRIGGER` statements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::{TriggerEvent, TriggerTiming, TriggerType};
+    ///
+    /// let result = pg_query::parse(
+    ///     "CREATE TRIGGER audit_email AFTER INSERT OR UPDATE OF email ON app.users
+    ///      FOR EACH ROW WHEN (NEW.email IS NOT NULL) EXECUTE FUNCTION audit.log('users', 1)",
+    /// )
+    /// .unwrap();
+    /// let trigger = &result.triggers()[0];
+    /// assert_eq!(trigger.relation, "app.users");
+    /// assert_eq!(trigger.timing, TriggerTiming::After);
+    /// assert_eq!(trigger.events, [TriggerEvent::Insert, TriggerEvent::Update { columns: vec!["email".into()] }]);
+    /// assert!(trigger.for_each_row);
+    /// assert_eq!(trigger.when.as_ref().unwrap().deparse().unwrap(), "new.email IS NOT NULL");
+    /// assert_eq!(trigger.function, "audit.log");
+    /// assert_eq!(trigger.arguments, ["users", "1"]);
+    /// assert_eq!(trigger.trigger_type(), TriggerType::Row as i32 | TriggerType::Insert as i32 | TriggerType::Update as i32);
+    /// ```
+    pub fn triggers(&self) -> Vec<TriggerDefinition> {
+        self.protobuf
+            .iter()
+            .filter_map(|node| match node {
+                NodeRef::CreateTrigStmt(s) => Some(TriggerDefinition::from(s)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn strings(nodes: &[protobuf::Node]) -> Vec<String> {
+    nodes
+        .iter()
+        .filter_map(|n| match &n.node {
+            Some(NodeEnum::String(s)) => Some(s.sval.to_owned()),
+            _ => None,
+        })
+        .collect()
+}
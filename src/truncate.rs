@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 
+use crate::fragment::{deparse_clause, ListClause};
 use crate::*;
 
 #[derive(Debug)]
@@ -272,28 +273,23 @@ fn truncate_str(string: &str, max_chars: usize) -> &str {
 }
 
 fn select_target_list_len(nodes: Vec<Node>) -> Result<i32> {
-    let fragment = dummy_select(nodes, None, vec![]).deparse()?;
-    Ok(fragment.len() as i32 - 7) // "SELECT "
+    Ok(deparse_clause(ListClause::TargetList, nodes)?.len() as i32)
 }
 
 fn select_values_lists_len(nodes: Vec<Node>) -> Result<i32> {
-    let fragment = dummy_select(vec![], None, nodes).deparse()?;
-    Ok(fragment.len() as i32 - 7) // "SELECT "
+    Ok(deparse_clause(ListClause::ValuesLists, nodes)?.len() as i32)
 }
 
 fn update_target_list_len(nodes: Vec<Node>) -> Result<i32> {
-    let fragment = dummy_update(nodes).deparse()?;
-    Ok(fragment.len() as i32 - 13) // "UPDATE x SET "
+    Ok(deparse_clause(ListClause::SetClause, nodes)?.len() as i32)
 }
 
 fn where_clause_len(node: Box<Node>) -> Result<i32> {
-    let fragment = dummy_select(vec![], Some(node), vec![]).deparse()?;
-    Ok(fragment.len() as i32 - 13) // "SELECT WHERE "
+    Ok(node.deparse()?.len() as i32)
 }
 
 fn cols_len(nodes: Vec<Node>) -> Result<i32> {
-    let fragment = dummy_insert(nodes).deparse()?;
-    Ok(fragment.len() as i32 - 31) // "INSERT INTO x () DEFAULT VALUES"
+    Ok(deparse_clause(ListClause::InsertColumns, nodes)?.len() as i32)
 }
 
 fn dummy_column() -> Box<Node> {
@@ -342,46 +338,3 @@ fn dummy_select(target_list: Vec<Node>, where_clause: Option<Box<Node>>, values_
         }))),
     })
 }
-
-fn dummy_insert(cols: Vec<Node>) -> Box<Node> {
-    Box::new(Node {
-        node: Some(NodeEnum::InsertStmt(Box::new(protobuf::InsertStmt {
-            relation: Some(protobuf::RangeVar {
-                catalogname: "".to_string(),
-                schemaname: "".to_string(),
-                relname: "x".to_string(),
-                inh: true,
-                relpersistence: "p".to_string(),
-                alias: None,
-                location: 0,
-            }),
-            cols,
-            select_stmt: None,
-            on_conflict_clause: None,
-            returning_list: vec![],
-            with_clause: None,
-            r#override: 1,
-        }))),
-    })
-}
-
-fn dummy_update(target_list: Vec<Node>) -> Box<Node> {
-    Box::new(Node {
-        node: Some(NodeEnum::UpdateStmt(Box::new(protobuf::UpdateStmt {
-            relation: Some(protobuf::RangeVar {
-                catalogname: "".to_string(),
-                schemaname: "".to_string(),
-                relname: "x".to_string(),
-                inh: true,
-                relpersistence: "p".to_string(),
-                alias: None,
-                location: 0,
-            }),
-            from_clause: vec![],
-            target_list,
-            where_clause: None,
-            returning_list: vec![],
-            with_clause: None,
-        }))),
-    })
-}
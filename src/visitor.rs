@@ -0,0 +1,7654 @@
+//! Traits for walking a parse tree, generated by scripts/generate.py from the protobuf definitions.
+
+use crate::protobuf::{self, a_const, node};
+
+/// Walks a parse tree by shared reference, with one method per node type.
+///
+/// Every method defaults to calling the matching `walk_*` function in this module, which visits all
+/// child nodes in field order. Override a method to inspect that node type, and call the `walk_*`
+/// function from it to keep descending; returning without calling it skips the subtree.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::protobuf::{ColumnRef, SubLink};
+/// use pg_query::visitor::{walk_column_ref, Visitor};
+///
+/// #[derive(Default)]
+/// struct Columns(usize);
+///
+/// impl<'ast> Visitor<'ast> for Columns {
+///     fn visit_column_ref(&mut self, node: &'ast ColumnRef) {
+///         self.0 += 1;
+///         walk_column_ref(self, node);
+///     }
+///
+///     // Skip subqueries
+///     fn visit_sub_link(&mut self, _node: &'ast SubLink) {}
+/// }
+///
+/// let result = pg_query::parse("SELECT a, b FROM t WHERE c IN (SELECT d FROM u)").unwrap();
+/// let mut columns = Columns::default();
+/// columns.visit_parse_result(&result.protobuf);
+/// assert_eq!(columns.0, 2);
+/// ```
+pub trait Visitor<'ast> {
+    fn visit_node(&mut self, node: &'ast protobuf::Node) {
+        walk_node(self, node);
+    }
+    fn visit_parse_result(&mut self, node: &'ast protobuf::ParseResult) {
+        walk_parse_result(self, node);
+    }
+    fn visit_integer(&mut self, node: &'ast protobuf::Integer) {
+        walk_integer(self, node);
+    }
+    fn visit_float(&mut self, node: &'ast protobuf::Float) {
+        walk_float(self, node);
+    }
+    fn visit_boolean(&mut self, node: &'ast protobuf::Boolean) {
+        walk_boolean(self, node);
+    }
+    fn visit_string(&mut self, node: &'ast protobuf::String) {
+        walk_string(self, node);
+    }
+    fn visit_bit_string(&mut self, node: &'ast protobuf::BitString) {
+        walk_bit_string(self, node);
+    }
+    fn visit_list(&mut self, node: &'ast protobuf::List) {
+        walk_list(self, node);
+    }
+    fn visit_oid_list(&mut self, node: &'ast protobuf::OidList) {
+        walk_oid_list(self, node);
+    }
+    fn visit_int_list(&mut self, node: &'ast protobuf::IntList) {
+        walk_int_list(self, node);
+    }
+    fn visit_a_const(&mut self, node: &'ast protobuf::AConst) {
+        walk_a_const(self, node);
+    }
+    fn visit_alias(&mut self, node: &'ast protobuf::Alias) {
+        walk_alias(self, node);
+    }
+    fn visit_range_var(&mut self, node: &'ast protobuf::RangeVar) {
+        walk_range_var(self, node);
+    }
+    fn visit_table_func(&mut self, node: &'ast protobuf::TableFunc) {
+        walk_table_func(self, node);
+    }
+    fn visit_into_clause(&mut self, node: &'ast protobuf::IntoClause) {
+        walk_into_clause(self, node);
+    }
+    fn visit_var(&mut self, node: &'ast protobuf::Var) {
+        walk_var(self, node);
+    }
+    fn visit_param(&mut self, node: &'ast protobuf::Param) {
+        walk_param(self, node);
+    }
+    fn visit_aggref(&mut self, node: &'ast protobuf::Aggref) {
+        walk_aggref(self, node);
+    }
+    fn visit_grouping_func(&mut self, node: &'ast protobuf::GroupingFunc) {
+        walk_grouping_func(self, node);
+    }
+    fn visit_window_func(&mut self, node: &'ast protobuf::WindowFunc) {
+        walk_window_func(self, node);
+    }
+    fn visit_window_func_run_condition(&mut self, node: &'ast protobuf::WindowFuncRunCondition) {
+        walk_window_func_run_condition(self, node);
+    }
+    fn visit_merge_support_func(&mut self, node: &'ast protobuf::MergeSupportFunc) {
+        walk_merge_support_func(self, node);
+    }
+    fn visit_subscripting_ref(&mut self, node: &'ast protobuf::SubscriptingRef) {
+        walk_subscripting_ref(self, node);
+    }
+    fn visit_func_expr(&mut self, node: &'ast protobuf::FuncExpr) {
+        walk_func_expr(self, node);
+    }
+    fn visit_named_arg_expr(&mut self, node: &'ast protobuf::NamedArgExpr) {
+        walk_named_arg_expr(self, node);
+    }
+    fn visit_op_expr(&mut self, node: &'ast protobuf::OpExpr) {
+        walk_op_expr(self, node);
+    }
+    fn visit_distinct_expr(&mut self, node: &'ast protobuf::DistinctExpr) {
+        walk_distinct_expr(self, node);
+    }
+    fn visit_null_if_expr(&mut self, node: &'ast protobuf::NullIfExpr) {
+        walk_null_if_expr(self, node);
+    }
+    fn visit_scalar_array_op_expr(&mut self, node: &'ast protobuf::ScalarArrayOpExpr) {
+        walk_scalar_array_op_expr(self, node);
+    }
+    fn visit_bool_expr(&mut self, node: &'ast protobuf::BoolExpr) {
+        walk_bool_expr(self, node);
+    }
+    fn visit_sub_link(&mut self, node: &'ast protobuf::SubLink) {
+        walk_sub_link(self, node);
+    }
+    fn visit_sub_plan(&mut self, node: &'ast protobuf::SubPlan) {
+        walk_sub_plan(self, node);
+    }
+    fn visit_alternative_sub_plan(&mut self, node: &'ast protobuf::AlternativeSubPlan) {
+        walk_alternative_sub_plan(self, node);
+    }
+    fn visit_field_select(&mut self, node: &'ast protobuf::FieldSelect) {
+        walk_field_select(self, node);
+    }
+    fn visit_field_store(&mut self, node: &'ast protobuf::FieldStore) {
+        walk_field_store(self, node);
+    }
+    fn visit_relabel_type(&mut self, node: &'ast protobuf::RelabelType) {
+        walk_relabel_type(self, node);
+    }
+    fn visit_coerce_via_io(&mut self, node: &'ast protobuf::CoerceViaIo) {
+        walk_coerce_via_io(self, node);
+    }
+    fn visit_array_coerce_expr(&mut self, node: &'ast protobuf::ArrayCoerceExpr) {
+        walk_array_coerce_expr(self, node);
+    }
+    fn visit_convert_rowtype_expr(&mut self, node: &'ast protobuf::ConvertRowtypeExpr) {
+        walk_convert_rowtype_expr(self, node);
+    }
+    fn visit_collate_expr(&mut self, node: &'ast protobuf::CollateExpr) {
+        walk_collate_expr(self, node);
+    }
+    fn visit_case_expr(&mut self, node: &'ast protobuf::CaseExpr) {
+        walk_case_expr(self, node);
+    }
+    fn visit_case_when(&mut self, node: &'ast protobuf::CaseWhen) {
+        walk_case_when(self, node);
+    }
+    fn visit_case_test_expr(&mut self, node: &'ast protobuf::CaseTestExpr) {
+        walk_case_test_expr(self, node);
+    }
+    fn visit_array_expr(&mut self, node: &'ast protobuf::ArrayExpr) {
+        walk_array_expr(self, node);
+    }
+    fn visit_row_expr(&mut self, node: &'ast protobuf::RowExpr) {
+        walk_row_expr(self, node);
+    }
+    fn visit_row_compare_expr(&mut self, node: &'ast protobuf::RowCompareExpr) {
+        walk_row_compare_expr(self, node);
+    }
+    fn visit_coalesce_expr(&mut self, node: &'ast protobuf::CoalesceExpr) {
+        walk_coalesce_expr(self, node);
+    }
+    fn visit_min_max_expr(&mut self, node: &'ast protobuf::MinMaxExpr) {
+        walk_min_max_expr(self, node);
+    }
+    fn visit_sql_value_function(&mut self, node: &'ast protobuf::SqlValueFunction) {
+        walk_sql_value_function(self, node);
+    }
+    fn visit_xml_expr(&mut self, node: &'ast protobuf::XmlExpr) {
+        walk_xml_expr(self, node);
+    }
+    fn visit_json_format(&mut self, node: &'ast protobuf::JsonFormat) {
+        walk_json_format(self, node);
+    }
+    fn visit_json_returning(&mut self, node: &'ast protobuf::JsonReturning) {
+        walk_json_returning(self, node);
+    }
+    fn visit_json_value_expr(&mut self, node: &'ast protobuf::JsonValueExpr) {
+        walk_json_value_expr(self, node);
+    }
+    fn visit_json_constructor_expr(&mut self, node: &'ast protobuf::JsonConstructorExpr) {
+        walk_json_constructor_expr(self, node);
+    }
+    fn visit_json_is_predicate(&mut self, node: &'ast protobuf::JsonIsPredicate) {
+        walk_json_is_predicate(self, node);
+    }
+    fn visit_json_behavior(&mut self, node: &'ast protobuf::JsonBehavior) {
+        walk_json_behavior(self, node);
+    }
+    fn visit_json_expr(&mut self, node: &'ast protobuf::JsonExpr) {
+        walk_json_expr(self, node);
+    }
+    fn visit_json_table_path(&mut self, node: &'ast protobuf::JsonTablePath) {
+        walk_json_table_path(self, node);
+    }
+    fn visit_json_table_path_scan(&mut self, node: &'ast protobuf::JsonTablePathScan) {
+        walk_json_table_path_scan(self, node);
+    }
+    fn visit_json_table_sibling_join(&mut self, node: &'ast protobuf::JsonTableSiblingJoin) {
+        walk_json_table_sibling_join(self, node);
+    }
+    fn visit_null_test(&mut self, node: &'ast protobuf::NullTest) {
+        walk_null_test(self, node);
+    }
+    fn visit_boolean_test(&mut self, node: &'ast protobuf::BooleanTest) {
+        walk_boolean_test(self, node);
+    }
+    fn visit_merge_action(&mut self, node: &'ast protobuf::MergeAction) {
+        walk_merge_action(self, node);
+    }
+    fn visit_coerce_to_domain(&mut self, node: &'ast protobuf::CoerceToDomain) {
+        walk_coerce_to_domain(self, node);
+    }
+    fn visit_coerce_to_domain_value(&mut self, node: &'ast protobuf::CoerceToDomainValue) {
+        walk_coerce_to_domain_value(self, node);
+    }
+    fn visit_set_to_default(&mut self, node: &'ast protobuf::SetToDefault) {
+        walk_set_to_default(self, node);
+    }
+    fn visit_current_of_expr(&mut self, node: &'ast protobuf::CurrentOfExpr) {
+        walk_current_of_expr(self, node);
+    }
+    fn visit_next_value_expr(&mut self, node: &'ast protobuf::NextValueExpr) {
+        walk_next_value_expr(self, node);
+    }
+    fn visit_inference_elem(&mut self, node: &'ast protobuf::InferenceElem) {
+        walk_inference_elem(self, node);
+    }
+    fn visit_target_entry(&mut self, node: &'ast protobuf::TargetEntry) {
+        walk_target_entry(self, node);
+    }
+    fn visit_range_tbl_ref(&mut self, node: &'ast protobuf::RangeTblRef) {
+        walk_range_tbl_ref(self, node);
+    }
+    fn visit_join_expr(&mut self, node: &'ast protobuf::JoinExpr) {
+        walk_join_expr(self, node);
+    }
+    fn visit_from_expr(&mut self, node: &'ast protobuf::FromExpr) {
+        walk_from_expr(self, node);
+    }
+    fn visit_on_conflict_expr(&mut self, node: &'ast protobuf::OnConflictExpr) {
+        walk_on_conflict_expr(self, node);
+    }
+    fn visit_query(&mut self, node: &'ast protobuf::Query) {
+        walk_query(self, node);
+    }
+    fn visit_type_name(&mut self, node: &'ast protobuf::TypeName) {
+        walk_type_name(self, node);
+    }
+    fn visit_column_ref(&mut self, node: &'ast protobuf::ColumnRef) {
+        walk_column_ref(self, node);
+    }
+    fn visit_param_ref(&mut self, node: &'ast protobuf::ParamRef) {
+        walk_param_ref(self, node);
+    }
+    fn visit_a_expr(&mut self, node: &'ast protobuf::AExpr) {
+        walk_a_expr(self, node);
+    }
+    fn visit_type_cast(&mut self, node: &'ast protobuf::TypeCast) {
+        walk_type_cast(self, node);
+    }
+    fn visit_collate_clause(&mut self, node: &'ast protobuf::CollateClause) {
+        walk_collate_clause(self, node);
+    }
+    fn visit_role_spec(&mut self, node: &'ast protobuf::RoleSpec) {
+        walk_role_spec(self, node);
+    }
+    fn visit_func_call(&mut self, node: &'ast protobuf::FuncCall) {
+        walk_func_call(self, node);
+    }
+    fn visit_a_star(&mut self, node: &'ast protobuf::AStar) {
+        walk_a_star(self, node);
+    }
+    fn visit_a_indices(&mut self, node: &'ast protobuf::AIndices) {
+        walk_a_indices(self, node);
+    }
+    fn visit_a_indirection(&mut self, node: &'ast protobuf::AIndirection) {
+        walk_a_indirection(self, node);
+    }
+    fn visit_a_array_expr(&mut self, node: &'ast protobuf::AArrayExpr) {
+        walk_a_array_expr(self, node);
+    }
+    fn visit_res_target(&mut self, node: &'ast protobuf::ResTarget) {
+        walk_res_target(self, node);
+    }
+    fn visit_multi_assign_ref(&mut self, node: &'ast protobuf::MultiAssignRef) {
+        walk_multi_assign_ref(self, node);
+    }
+    fn visit_sort_by(&mut self, node: &'ast protobuf::SortBy) {
+        walk_sort_by(self, node);
+    }
+    fn visit_window_def(&mut self, node: &'ast protobuf::WindowDef) {
+        walk_window_def(self, node);
+    }
+    fn visit_range_subselect(&mut self, node: &'ast protobuf::RangeSubselect) {
+        walk_range_subselect(self, node);
+    }
+    fn visit_range_function(&mut self, node: &'ast protobuf::RangeFunction) {
+        walk_range_function(self, node);
+    }
+    fn visit_range_table_func(&mut self, node: &'ast protobuf::RangeTableFunc) {
+        walk_range_table_func(self, node);
+    }
+    fn visit_range_table_func_col(&mut self, node: &'ast protobuf::RangeTableFuncCol) {
+        walk_range_table_func_col(self, node);
+    }
+    fn visit_range_table_sample(&mut self, node: &'ast protobuf::RangeTableSample) {
+        walk_range_table_sample(self, node);
+    }
+    fn visit_column_def(&mut self, node: &'ast protobuf::ColumnDef) {
+        walk_column_def(self, node);
+    }
+    fn visit_table_like_clause(&mut self, node: &'ast protobuf::TableLikeClause) {
+        walk_table_like_clause(self, node);
+    }
+    fn visit_index_elem(&mut self, node: &'ast protobuf::IndexElem) {
+        walk_index_elem(self, node);
+    }
+    fn visit_def_elem(&mut self, node: &'ast protobuf::DefElem) {
+        walk_def_elem(self, node);
+    }
+    fn visit_locking_clause(&mut self, node: &'ast protobuf::LockingClause) {
+        walk_locking_clause(self, node);
+    }
+    fn visit_xml_serialize(&mut self, node: &'ast protobuf::XmlSerialize) {
+        walk_xml_serialize(self, node);
+    }
+    fn visit_partition_elem(&mut self, node: &'ast protobuf::PartitionElem) {
+        walk_partition_elem(self, node);
+    }
+    fn visit_partition_spec(&mut self, node: &'ast protobuf::PartitionSpec) {
+        walk_partition_spec(self, node);
+    }
+    fn visit_partition_bound_spec(&mut self, node: &'ast protobuf::PartitionBoundSpec) {
+        walk_partition_bound_spec(self, node);
+    }
+    fn visit_partition_range_datum(&mut self, node: &'ast protobuf::PartitionRangeDatum) {
+        walk_partition_range_datum(self, node);
+    }
+    fn visit_single_partition_spec(&mut self, node: &'ast protobuf::SinglePartitionSpec) {
+        walk_single_partition_spec(self, node);
+    }
+    fn visit_partition_cmd(&mut self, node: &'ast protobuf::PartitionCmd) {
+        walk_partition_cmd(self, node);
+    }
+    fn visit_range_tbl_entry(&mut self, node: &'ast protobuf::RangeTblEntry) {
+        walk_range_tbl_entry(self, node);
+    }
+    fn visit_rte_permission_info(&mut self, node: &'ast protobuf::RtePermissionInfo) {
+        walk_rte_permission_info(self, node);
+    }
+    fn visit_range_tbl_function(&mut self, node: &'ast protobuf::RangeTblFunction) {
+        walk_range_tbl_function(self, node);
+    }
+    fn visit_table_sample_clause(&mut self, node: &'ast protobuf::TableSampleClause) {
+        walk_table_sample_clause(self, node);
+    }
+    fn visit_with_check_option(&mut self, node: &'ast protobuf::WithCheckOption) {
+        walk_with_check_option(self, node);
+    }
+    fn visit_sort_group_clause(&mut self, node: &'ast protobuf::SortGroupClause) {
+        walk_sort_group_clause(self, node);
+    }
+    fn visit_grouping_set(&mut self, node: &'ast protobuf::GroupingSet) {
+        walk_grouping_set(self, node);
+    }
+    fn visit_window_clause(&mut self, node: &'ast protobuf::WindowClause) {
+        walk_window_clause(self, node);
+    }
+    fn visit_row_mark_clause(&mut self, node: &'ast protobuf::RowMarkClause) {
+        walk_row_mark_clause(self, node);
+    }
+    fn visit_with_clause(&mut self, node: &'ast protobuf::WithClause) {
+        walk_with_clause(self, node);
+    }
+    fn visit_infer_clause(&mut self, node: &'ast protobuf::InferClause) {
+        walk_infer_clause(self, node);
+    }
+    fn visit_on_conflict_clause(&mut self, node: &'ast protobuf::OnConflictClause) {
+        walk_on_conflict_clause(self, node);
+    }
+    fn visit_cte_search_clause(&mut self, node: &'ast protobuf::CteSearchClause) {
+        walk_cte_search_clause(self, node);
+    }
+    fn visit_cte_cycle_clause(&mut self, node: &'ast protobuf::CteCycleClause) {
+        walk_cte_cycle_clause(self, node);
+    }
+    fn visit_common_table_expr(&mut self, node: &'ast protobuf::CommonTableExpr) {
+        walk_common_table_expr(self, node);
+    }
+    fn visit_merge_when_clause(&mut self, node: &'ast protobuf::MergeWhenClause) {
+        walk_merge_when_clause(self, node);
+    }
+    fn visit_trigger_transition(&mut self, node: &'ast protobuf::TriggerTransition) {
+        walk_trigger_transition(self, node);
+    }
+    fn visit_json_output(&mut self, node: &'ast protobuf::JsonOutput) {
+        walk_json_output(self, node);
+    }
+    fn visit_json_argument(&mut self, node: &'ast protobuf::JsonArgument) {
+        walk_json_argument(self, node);
+    }
+    fn visit_json_func_expr(&mut self, node: &'ast protobuf::JsonFuncExpr) {
+        walk_json_func_expr(self, node);
+    }
+    fn visit_json_table_path_spec(&mut self, node: &'ast protobuf::JsonTablePathSpec) {
+        walk_json_table_path_spec(self, node);
+    }
+    fn visit_json_table(&mut self, node: &'ast protobuf::JsonTable) {
+        walk_json_table(self, node);
+    }
+    fn visit_json_table_column(&mut self, node: &'ast protobuf::JsonTableColumn) {
+        walk_json_table_column(self, node);
+    }
+    fn visit_json_key_value(&mut self, node: &'ast protobuf::JsonKeyValue) {
+        walk_json_key_value(self, node);
+    }
+    fn visit_json_parse_expr(&mut self, node: &'ast protobuf::JsonParseExpr) {
+        walk_json_parse_expr(self, node);
+    }
+    fn visit_json_scalar_expr(&mut self, node: &'ast protobuf::JsonScalarExpr) {
+        walk_json_scalar_expr(self, node);
+    }
+    fn visit_json_serialize_expr(&mut self, node: &'ast protobuf::JsonSerializeExpr) {
+        walk_json_serialize_expr(self, node);
+    }
+    fn visit_json_object_constructor(&mut self, node: &'ast protobuf::JsonObjectConstructor) {
+        walk_json_object_constructor(self, node);
+    }
+    fn visit_json_array_constructor(&mut self, node: &'ast protobuf::JsonArrayConstructor) {
+        walk_json_array_constructor(self, node);
+    }
+    fn visit_json_array_query_constructor(&mut self, node: &'ast protobuf::JsonArrayQueryConstructor) {
+        walk_json_array_query_constructor(self, node);
+    }
+    fn visit_json_agg_constructor(&mut self, node: &'ast protobuf::JsonAggConstructor) {
+        walk_json_agg_constructor(self, node);
+    }
+    fn visit_json_object_agg(&mut self, node: &'ast protobuf::JsonObjectAgg) {
+        walk_json_object_agg(self, node);
+    }
+    fn visit_json_array_agg(&mut self, node: &'ast protobuf::JsonArrayAgg) {
+        walk_json_array_agg(self, node);
+    }
+    fn visit_raw_stmt(&mut self, node: &'ast protobuf::RawStmt) {
+        walk_raw_stmt(self, node);
+    }
+    fn visit_insert_stmt(&mut self, node: &'ast protobuf::InsertStmt) {
+        walk_insert_stmt(self, node);
+    }
+    fn visit_delete_stmt(&mut self, node: &'ast protobuf::DeleteStmt) {
+        walk_delete_stmt(self, node);
+    }
+    fn visit_update_stmt(&mut self, node: &'ast protobuf::UpdateStmt) {
+        walk_update_stmt(self, node);
+    }
+    fn visit_merge_stmt(&mut self, node: &'ast protobuf::MergeStmt) {
+        walk_merge_stmt(self, node);
+    }
+    fn visit_select_stmt(&mut self, node: &'ast protobuf::SelectStmt) {
+        walk_select_stmt(self, node);
+    }
+    fn visit_set_operation_stmt(&mut self, node: &'ast protobuf::SetOperationStmt) {
+        walk_set_operation_stmt(self, node);
+    }
+    fn visit_return_stmt(&mut self, node: &'ast protobuf::ReturnStmt) {
+        walk_return_stmt(self, node);
+    }
+    fn visit_pl_assign_stmt(&mut self, node: &'ast protobuf::PlAssignStmt) {
+        walk_pl_assign_stmt(self, node);
+    }
+    fn visit_create_schema_stmt(&mut self, node: &'ast protobuf::CreateSchemaStmt) {
+        walk_create_schema_stmt(self, node);
+    }
+    fn visit_alter_table_stmt(&mut self, node: &'ast protobuf::AlterTableStmt) {
+        walk_alter_table_stmt(self, node);
+    }
+    fn visit_replica_identity_stmt(&mut self, node: &'ast protobuf::ReplicaIdentityStmt) {
+        walk_replica_identity_stmt(self, node);
+    }
+    fn visit_alter_table_cmd(&mut self, node: &'ast protobuf::AlterTableCmd) {
+        walk_alter_table_cmd(self, node);
+    }
+    fn visit_alter_collation_stmt(&mut self, node: &'ast protobuf::AlterCollationStmt) {
+        walk_alter_collation_stmt(self, node);
+    }
+    fn visit_alter_domain_stmt(&mut self, node: &'ast protobuf::AlterDomainStmt) {
+        walk_alter_domain_stmt(self, node);
+    }
+    fn visit_grant_stmt(&mut self, node: &'ast protobuf::GrantStmt) {
+        walk_grant_stmt(self, node);
+    }
+    fn visit_object_with_args(&mut self, node: &'ast protobuf::ObjectWithArgs) {
+        walk_object_with_args(self, node);
+    }
+    fn visit_access_priv(&mut self, node: &'ast protobuf::AccessPriv) {
+        walk_access_priv(self, node);
+    }
+    fn visit_grant_role_stmt(&mut self, node: &'ast protobuf::GrantRoleStmt) {
+        walk_grant_role_stmt(self, node);
+    }
+    fn visit_alter_default_privileges_stmt(&mut self, node: &'ast protobuf::AlterDefaultPrivilegesStmt) {
+        walk_alter_default_privileges_stmt(self, node);
+    }
+    fn visit_copy_stmt(&mut self, node: &'ast protobuf::CopyStmt) {
+        walk_copy_stmt(self, node);
+    }
+    fn visit_variable_set_stmt(&mut self, node: &'ast protobuf::VariableSetStmt) {
+        walk_variable_set_stmt(self, node);
+    }
+    fn visit_variable_show_stmt(&mut self, node: &'ast protobuf::VariableShowStmt) {
+        walk_variable_show_stmt(self, node);
+    }
+    fn visit_create_stmt(&mut self, node: &'ast protobuf::CreateStmt) {
+        walk_create_stmt(self, node);
+    }
+    fn visit_constraint(&mut self, node: &'ast protobuf::Constraint) {
+        walk_constraint(self, node);
+    }
+    fn visit_create_table_space_stmt(&mut self, node: &'ast protobuf::CreateTableSpaceStmt) {
+        walk_create_table_space_stmt(self, node);
+    }
+    fn visit_drop_table_space_stmt(&mut self, node: &'ast protobuf::DropTableSpaceStmt) {
+        walk_drop_table_space_stmt(self, node);
+    }
+    fn visit_alter_table_space_options_stmt(&mut self, node: &'ast protobuf::AlterTableSpaceOptionsStmt) {
+        walk_alter_table_space_options_stmt(self, node);
+    }
+    fn visit_alter_table_move_all_stmt(&mut self, node: &'ast protobuf::AlterTableMoveAllStmt) {
+        walk_alter_table_move_all_stmt(self, node);
+    }
+    fn visit_create_extension_stmt(&mut self, node: &'ast protobuf::CreateExtensionStmt) {
+        walk_create_extension_stmt(self, node);
+    }
+    fn visit_alter_extension_stmt(&mut self, node: &'ast protobuf::AlterExtensionStmt) {
+        walk_alter_extension_stmt(self, node);
+    }
+    fn visit_alter_extension_contents_stmt(&mut self, node: &'ast protobuf::AlterExtensionContentsStmt) {
+        walk_alter_extension_contents_stmt(self, node);
+    }
+    fn visit_create_fdw_stmt(&mut self, node: &'ast protobuf::CreateFdwStmt) {
+        walk_create_fdw_stmt(self, node);
+    }
+    fn visit_alter_fdw_stmt(&mut self, node: &'ast protobuf::AlterFdwStmt) {
+        walk_alter_fdw_stmt(self, node);
+    }
+    fn visit_create_foreign_server_stmt(&mut self, node: &'ast protobuf::CreateForeignServerStmt) {
+        walk_create_foreign_server_stmt(self, node);
+    }
+    fn visit_alter_foreign_server_stmt(&mut self, node: &'ast protobuf::AlterForeignServerStmt) {
+        walk_alter_foreign_server_stmt(self, node);
+    }
+    fn visit_create_foreign_table_stmt(&mut self, node: &'ast protobuf::CreateForeignTableStmt) {
+        walk_create_foreign_table_stmt(self, node);
+    }
+    fn visit_create_user_mapping_stmt(&mut self, node: &'ast protobuf::CreateUserMappingStmt) {
+        walk_create_user_mapping_stmt(self, node);
+    }
+    fn visit_alter_user_mapping_stmt(&mut self, node: &'ast protobuf::AlterUserMappingStmt) {
+        walk_alter_user_mapping_stmt(self, node);
+    }
+    fn visit_drop_user_mapping_stmt(&mut self, node: &'ast protobuf::DropUserMappingStmt) {
+        walk_drop_user_mapping_stmt(self, node);
+    }
+    fn visit_import_foreign_schema_stmt(&mut self, node: &'ast protobuf::ImportForeignSchemaStmt) {
+        walk_import_foreign_schema_stmt(self, node);
+    }
+    fn visit_create_policy_stmt(&mut self, node: &'ast protobuf::CreatePolicyStmt) {
+        walk_create_policy_stmt(self, node);
+    }
+    fn visit_alter_policy_stmt(&mut self, node: &'ast protobuf::AlterPolicyStmt) {
+        walk_alter_policy_stmt(self, node);
+    }
+    fn visit_create_am_stmt(&mut self, node: &'ast protobuf::CreateAmStmt) {
+        walk_create_am_stmt(self, node);
+    }
+    fn visit_create_trig_stmt(&mut self, node: &'ast protobuf::CreateTrigStmt) {
+        walk_create_trig_stmt(self, node);
+    }
+    fn visit_create_event_trig_stmt(&mut self, node: &'ast protobuf::CreateEventTrigStmt) {
+        walk_create_event_trig_stmt(self, node);
+    }
+    fn visit_alter_event_trig_stmt(&mut self, node: &'ast protobuf::AlterEventTrigStmt) {
+        walk_alter_event_trig_stmt(self, node);
+    }
+    fn visit_create_p_lang_stmt(&mut self, node: &'ast protobuf::CreatePLangStmt) {
+        walk_create_p_lang_stmt(self, node);
+    }
+    fn visit_create_role_stmt(&mut self, node: &'ast protobuf::CreateRoleStmt) {
+        walk_create_role_stmt(self, node);
+    }
+    fn visit_alter_role_stmt(&mut self, node: &'ast protobuf::AlterRoleStmt) {
+        walk_alter_role_stmt(self, node);
+    }
+    fn visit_alter_role_set_stmt(&mut self, node: &'ast protobuf::AlterRoleSetStmt) {
+        walk_alter_role_set_stmt(self, node);
+    }
+    fn visit_drop_role_stmt(&mut self, node: &'ast protobuf::DropRoleStmt) {
+        walk_drop_role_stmt(self, node);
+    }
+    fn visit_create_seq_stmt(&mut self, node: &'ast protobuf::CreateSeqStmt) {
+        walk_create_seq_stmt(self, node);
+    }
+    fn visit_alter_seq_stmt(&mut self, node: &'ast protobuf::AlterSeqStmt) {
+        walk_alter_seq_stmt(self, node);
+    }
+    fn visit_define_stmt(&mut self, node: &'ast protobuf::DefineStmt) {
+        walk_define_stmt(self, node);
+    }
+    fn visit_create_domain_stmt(&mut self, node: &'ast protobuf::CreateDomainStmt) {
+        walk_create_domain_stmt(self, node);
+    }
+    fn visit_create_op_class_stmt(&mut self, node: &'ast protobuf::CreateOpClassStmt) {
+        walk_create_op_class_stmt(self, node);
+    }
+    fn visit_create_op_class_item(&mut self, node: &'ast protobuf::CreateOpClassItem) {
+        walk_create_op_class_item(self, node);
+    }
+    fn visit_create_op_family_stmt(&mut self, node: &'ast protobuf::CreateOpFamilyStmt) {
+        walk_create_op_family_stmt(self, node);
+    }
+    fn visit_alter_op_family_stmt(&mut self, node: &'ast protobuf::AlterOpFamilyStmt) {
+        walk_alter_op_family_stmt(self, node);
+    }
+    fn visit_drop_stmt(&mut self, node: &'ast protobuf::DropStmt) {
+        walk_drop_stmt(self, node);
+    }
+    fn visit_truncate_stmt(&mut self, node: &'ast protobuf::TruncateStmt) {
+        walk_truncate_stmt(self, node);
+    }
+    fn visit_comment_stmt(&mut self, node: &'ast protobuf::CommentStmt) {
+        walk_comment_stmt(self, node);
+    }
+    fn visit_sec_label_stmt(&mut self, node: &'ast protobuf::SecLabelStmt) {
+        walk_sec_label_stmt(self, node);
+    }
+    fn visit_declare_cursor_stmt(&mut self, node: &'ast protobuf::DeclareCursorStmt) {
+        walk_declare_cursor_stmt(self, node);
+    }
+    fn visit_close_portal_stmt(&mut self, node: &'ast protobuf::ClosePortalStmt) {
+        walk_close_portal_stmt(self, node);
+    }
+    fn visit_fetch_stmt(&mut self, node: &'ast protobuf::FetchStmt) {
+        walk_fetch_stmt(self, node);
+    }
+    fn visit_index_stmt(&mut self, node: &'ast protobuf::IndexStmt) {
+        walk_index_stmt(self, node);
+    }
+    fn visit_create_stats_stmt(&mut self, node: &'ast protobuf::CreateStatsStmt) {
+        walk_create_stats_stmt(self, node);
+    }
+    fn visit_stats_elem(&mut self, node: &'ast protobuf::StatsElem) {
+        walk_stats_elem(self, node);
+    }
+    fn visit_alter_stats_stmt(&mut self, node: &'ast protobuf::AlterStatsStmt) {
+        walk_alter_stats_stmt(self, node);
+    }
+    fn visit_create_function_stmt(&mut self, node: &'ast protobuf::CreateFunctionStmt) {
+        walk_create_function_stmt(self, node);
+    }
+    fn visit_function_parameter(&mut self, node: &'ast protobuf::FunctionParameter) {
+        walk_function_parameter(self, node);
+    }
+    fn visit_alter_function_stmt(&mut self, node: &'ast protobuf::AlterFunctionStmt) {
+        walk_alter_function_stmt(self, node);
+    }
+    fn visit_do_stmt(&mut self, node: &'ast protobuf::DoStmt) {
+        walk_do_stmt(self, node);
+    }
+    fn visit_inline_code_block(&mut self, node: &'ast protobuf::InlineCodeBlock) {
+        walk_inline_code_block(self, node);
+    }
+    fn visit_call_stmt(&mut self, node: &'ast protobuf::CallStmt) {
+        walk_call_stmt(self, node);
+    }
+    fn visit_call_context(&mut self, node: &'ast protobuf::CallContext) {
+        walk_call_context(self, node);
+    }
+    fn visit_rename_stmt(&mut self, node: &'ast protobuf::RenameStmt) {
+        walk_rename_stmt(self, node);
+    }
+    fn visit_alter_object_depends_stmt(&mut self, node: &'ast protobuf::AlterObjectDependsStmt) {
+        walk_alter_object_depends_stmt(self, node);
+    }
+    fn visit_alter_object_schema_stmt(&mut self, node: &'ast protobuf::AlterObjectSchemaStmt) {
+        walk_alter_object_schema_stmt(self, node);
+    }
+    fn visit_alter_owner_stmt(&mut self, node: &'ast protobuf::AlterOwnerStmt) {
+        walk_alter_owner_stmt(self, node);
+    }
+    fn visit_alter_operator_stmt(&mut self, node: &'ast protobuf::AlterOperatorStmt) {
+        walk_alter_operator_stmt(self, node);
+    }
+    fn visit_alter_type_stmt(&mut self, node: &'ast protobuf::AlterTypeStmt) {
+        walk_alter_type_stmt(self, node);
+    }
+    fn visit_rule_stmt(&mut self, node: &'ast protobuf::RuleStmt) {
+        walk_rule_stmt(self, node);
+    }
+    fn visit_notify_stmt(&mut self, node: &'ast protobuf::NotifyStmt) {
+        walk_notify_stmt(self, node);
+    }
+    fn visit_listen_stmt(&mut self, node: &'ast protobuf::ListenStmt) {
+        walk_listen_stmt(self, node);
+    }
+    fn visit_unlisten_stmt(&mut self, node: &'ast protobuf::UnlistenStmt) {
+        walk_unlisten_stmt(self, node);
+    }
+    fn visit_transaction_stmt(&mut self, node: &'ast protobuf::TransactionStmt) {
+        walk_transaction_stmt(self, node);
+    }
+    fn visit_composite_type_stmt(&mut self, node: &'ast protobuf::CompositeTypeStmt) {
+        walk_composite_type_stmt(self, node);
+    }
+    fn visit_create_enum_stmt(&mut self, node: &'ast protobuf::CreateEnumStmt) {
+        walk_create_enum_stmt(self, node);
+    }
+    fn visit_create_range_stmt(&mut self, node: &'ast protobuf::CreateRangeStmt) {
+        walk_create_range_stmt(self, node);
+    }
+    fn visit_alter_enum_stmt(&mut self, node: &'ast protobuf::AlterEnumStmt) {
+        walk_alter_enum_stmt(self, node);
+    }
+    fn visit_view_stmt(&mut self, node: &'ast protobuf::ViewStmt) {
+        walk_view_stmt(self, node);
+    }
+    fn visit_load_stmt(&mut self, node: &'ast protobuf::LoadStmt) {
+        walk_load_stmt(self, node);
+    }
+    fn visit_createdb_stmt(&mut self, node: &'ast protobuf::CreatedbStmt) {
+        walk_createdb_stmt(self, node);
+    }
+    fn visit_alter_database_stmt(&mut self, node: &'ast protobuf::AlterDatabaseStmt) {
+        walk_alter_database_stmt(self, node);
+    }
+    fn visit_alter_database_refresh_coll_stmt(&mut self, node: &'ast protobuf::AlterDatabaseRefreshCollStmt) {
+        walk_alter_database_refresh_coll_stmt(self, node);
+    }
+    fn visit_alter_database_set_stmt(&mut self, node: &'ast protobuf::AlterDatabaseSetStmt) {
+        walk_alter_database_set_stmt(self, node);
+    }
+    fn visit_dropdb_stmt(&mut self, node: &'ast protobuf::DropdbStmt) {
+        walk_dropdb_stmt(self, node);
+    }
+    fn visit_alter_system_stmt(&mut self, node: &'ast protobuf::AlterSystemStmt) {
+        walk_alter_system_stmt(self, node);
+    }
+    fn visit_cluster_stmt(&mut self, node: &'ast protobuf::ClusterStmt) {
+        walk_cluster_stmt(self, node);
+    }
+    fn visit_vacuum_stmt(&mut self, node: &'ast protobuf::VacuumStmt) {
+        walk_vacuum_stmt(self, node);
+    }
+    fn visit_vacuum_relation(&mut self, node: &'ast protobuf::VacuumRelation) {
+        walk_vacuum_relation(self, node);
+    }
+    fn visit_explain_stmt(&mut self, node: &'ast protobuf::ExplainStmt) {
+        walk_explain_stmt(self, node);
+    }
+    fn visit_create_table_as_stmt(&mut self, node: &'ast protobuf::CreateTableAsStmt) {
+        walk_create_table_as_stmt(self, node);
+    }
+    fn visit_refresh_mat_view_stmt(&mut self, node: &'ast protobuf::RefreshMatViewStmt) {
+        walk_refresh_mat_view_stmt(self, node);
+    }
+    fn visit_check_point_stmt(&mut self, node: &'ast protobuf::CheckPointStmt) {
+        walk_check_point_stmt(self, node);
+    }
+    fn visit_discard_stmt(&mut self, node: &'ast protobuf::DiscardStmt) {
+        walk_discard_stmt(self, node);
+    }
+    fn visit_lock_stmt(&mut self, node: &'ast protobuf::LockStmt) {
+        walk_lock_stmt(self, node);
+    }
+    fn visit_constraints_set_stmt(&mut self, node: &'ast protobuf::ConstraintsSetStmt) {
+        walk_constraints_set_stmt(self, node);
+    }
+    fn visit_reindex_stmt(&mut self, node: &'ast protobuf::ReindexStmt) {
+        walk_reindex_stmt(self, node);
+    }
+    fn visit_create_conversion_stmt(&mut self, node: &'ast protobuf::CreateConversionStmt) {
+        walk_create_conversion_stmt(self, node);
+    }
+    fn visit_create_cast_stmt(&mut self, node: &'ast protobuf::CreateCastStmt) {
+        walk_create_cast_stmt(self, node);
+    }
+    fn visit_create_transform_stmt(&mut self, node: &'ast protobuf::CreateTransformStmt) {
+        walk_create_transform_stmt(self, node);
+    }
+    fn visit_prepare_stmt(&mut self, node: &'ast protobuf::PrepareStmt) {
+        walk_prepare_stmt(self, node);
+    }
+    fn visit_execute_stmt(&mut self, node: &'ast protobuf::ExecuteStmt) {
+        walk_execute_stmt(self, node);
+    }
+    fn visit_deallocate_stmt(&mut self, node: &'ast protobuf::DeallocateStmt) {
+        walk_deallocate_stmt(self, node);
+    }
+    fn visit_drop_owned_stmt(&mut self, node: &'ast protobuf::DropOwnedStmt) {
+        walk_drop_owned_stmt(self, node);
+    }
+    fn visit_reassign_owned_stmt(&mut self, node: &'ast protobuf::ReassignOwnedStmt) {
+        walk_reassign_owned_stmt(self, node);
+    }
+    fn visit_alter_ts_dictionary_stmt(&mut self, node: &'ast protobuf::AlterTsDictionaryStmt) {
+        walk_alter_ts_dictionary_stmt(self, node);
+    }
+    fn visit_alter_ts_configuration_stmt(&mut self, node: &'ast protobuf::AlterTsConfigurationStmt) {
+        walk_alter_ts_configuration_stmt(self, node);
+    }
+    fn visit_publication_table(&mut self, node: &'ast protobuf::PublicationTable) {
+        walk_publication_table(self, node);
+    }
+    fn visit_publication_obj_spec(&mut self, node: &'ast protobuf::PublicationObjSpec) {
+        walk_publication_obj_spec(self, node);
+    }
+    fn visit_create_publication_stmt(&mut self, node: &'ast protobuf::CreatePublicationStmt) {
+        walk_create_publication_stmt(self, node);
+    }
+    fn visit_alter_publication_stmt(&mut self, node: &'ast protobuf::AlterPublicationStmt) {
+        walk_alter_publication_stmt(self, node);
+    }
+    fn visit_create_subscription_stmt(&mut self, node: &'ast protobuf::CreateSubscriptionStmt) {
+        walk_create_subscription_stmt(self, node);
+    }
+    fn visit_alter_subscription_stmt(&mut self, node: &'ast protobuf::AlterSubscriptionStmt) {
+        walk_alter_subscription_stmt(self, node);
+    }
+    fn visit_drop_subscription_stmt(&mut self, node: &'ast protobuf::DropSubscriptionStmt) {
+        walk_drop_subscription_stmt(self, node);
+    }
+}
+
+pub fn walk_parse_result<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ParseResult) {
+    for n in &node.stmts {
+        v.visit_raw_stmt(n);
+    }
+}
+
+pub fn walk_node<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::Node) {
+    match &node.node {
+        Some(node::Node::Alias(n)) => v.visit_alias(n),
+        Some(node::Node::RangeVar(n)) => v.visit_range_var(n),
+        Some(node::Node::TableFunc(n)) => v.visit_table_func(n),
+        Some(node::Node::IntoClause(n)) => v.visit_into_clause(n),
+        Some(node::Node::Var(n)) => v.visit_var(n),
+        Some(node::Node::Param(n)) => v.visit_param(n),
+        Some(node::Node::Aggref(n)) => v.visit_aggref(n),
+        Some(node::Node::GroupingFunc(n)) => v.visit_grouping_func(n),
+        Some(node::Node::WindowFunc(n)) => v.visit_window_func(n),
+        Some(node::Node::WindowFuncRunCondition(n)) => v.visit_window_func_run_condition(n),
+        Some(node::Node::MergeSupportFunc(n)) => v.visit_merge_support_func(n),
+        Some(node::Node::SubscriptingRef(n)) => v.visit_subscripting_ref(n),
+        Some(node::Node::FuncExpr(n)) => v.visit_func_expr(n),
+        Some(node::Node::NamedArgExpr(n)) => v.visit_named_arg_expr(n),
+        Some(node::Node::OpExpr(n)) => v.visit_op_expr(n),
+        Some(node::Node::DistinctExpr(n)) => v.visit_distinct_expr(n),
+        Some(node::Node::NullIfExpr(n)) => v.visit_null_if_expr(n),
+        Some(node::Node::ScalarArrayOpExpr(n)) => v.visit_scalar_array_op_expr(n),
+        Some(node::Node::BoolExpr(n)) => v.visit_bool_expr(n),
+        Some(node::Node::SubLink(n)) => v.visit_sub_link(n),
+        Some(node::Node::SubPlan(n)) => v.visit_sub_plan(n),
+        Some(node::Node::AlternativeSubPlan(n)) => v.visit_alternative_sub_plan(n),
+        Some(node::Node::FieldSelect(n)) => v.visit_field_select(n),
+        Some(node::Node::FieldStore(n)) => v.visit_field_store(n),
+        Some(node::Node::RelabelType(n)) => v.visit_relabel_type(n),
+        Some(node::Node::CoerceViaIo(n)) => v.visit_coerce_via_io(n),
+        Some(node::Node::ArrayCoerceExpr(n)) => v.visit_array_coerce_expr(n),
+        Some(node::Node::ConvertRowtypeExpr(n)) => v.visit_convert_rowtype_expr(n),
+        Some(node::Node::CollateExpr(n)) => v.visit_collate_expr(n),
+        Some(node::Node::CaseExpr(n)) => v.visit_case_expr(n),
+        Some(node::Node::CaseWhen(n)) => v.visit_case_when(n),
+        Some(node::Node::CaseTestExpr(n)) => v.visit_case_test_expr(n),
+        Some(node::Node::ArrayExpr(n)) => v.visit_array_expr(n),
+        Some(node::Node::RowExpr(n)) => v.visit_row_expr(n),
+        Some(node::Node::RowCompareExpr(n)) => v.visit_row_compare_expr(n),
+        Some(node::Node::CoalesceExpr(n)) => v.visit_coalesce_expr(n),
+        Some(node::Node::MinMaxExpr(n)) => v.visit_min_max_expr(n),
+        Some(node::Node::SqlvalueFunction(n)) => v.visit_sql_value_function(n),
+        Some(node::Node::XmlExpr(n)) => v.visit_xml_expr(n),
+        Some(node::Node::JsonFormat(n)) => v.visit_json_format(n),
+        Some(node::Node::JsonReturning(n)) => v.visit_json_returning(n),
+        Some(node::Node::JsonValueExpr(n)) => v.visit_json_value_expr(n),
+        Some(node::Node::JsonConstructorExpr(n)) => v.visit_json_constructor_expr(n),
+        Some(node::Node::JsonIsPredicate(n)) => v.visit_json_is_predicate(n),
+        Some(node::Node::JsonBehavior(n)) => v.visit_json_behavior(n),
+        Some(node::Node::JsonExpr(n)) => v.visit_json_expr(n),
+        Some(node::Node::JsonTablePath(n)) => v.visit_json_table_path(n),
+        Some(node::Node::JsonTablePathScan(n)) => v.visit_json_table_path_scan(n),
+        Some(node::Node::JsonTableSiblingJoin(n)) => v.visit_json_table_sibling_join(n),
+        Some(node::Node::NullTest(n)) => v.visit_null_test(n),
+        Some(node::Node::BooleanTest(n)) => v.visit_boolean_test(n),
+        Some(node::Node::MergeAction(n)) => v.visit_merge_action(n),
+        Some(node::Node::CoerceToDomain(n)) => v.visit_coerce_to_domain(n),
+        Some(node::Node::CoerceToDomainValue(n)) => v.visit_coerce_to_domain_value(n),
+        Some(node::Node::SetToDefault(n)) => v.visit_set_to_default(n),
+        Some(node::Node::CurrentOfExpr(n)) => v.visit_current_of_expr(n),
+        Some(node::Node::NextValueExpr(n)) => v.visit_next_value_expr(n),
+        Some(node::Node::InferenceElem(n)) => v.visit_inference_elem(n),
+        Some(node::Node::TargetEntry(n)) => v.visit_target_entry(n),
+        Some(node::Node::RangeTblRef(n)) => v.visit_range_tbl_ref(n),
+        Some(node::Node::JoinExpr(n)) => v.visit_join_expr(n),
+        Some(node::Node::FromExpr(n)) => v.visit_from_expr(n),
+        Some(node::Node::OnConflictExpr(n)) => v.visit_on_conflict_expr(n),
+        Some(node::Node::Query(n)) => v.visit_query(n),
+        Some(node::Node::TypeName(n)) => v.visit_type_name(n),
+        Some(node::Node::ColumnRef(n)) => v.visit_column_ref(n),
+        Some(node::Node::ParamRef(n)) => v.visit_param_ref(n),
+        Some(node::Node::AExpr(n)) => v.visit_a_expr(n),
+        Some(node::Node::TypeCast(n)) => v.visit_type_cast(n),
+        Some(node::Node::CollateClause(n)) => v.visit_collate_clause(n),
+        Some(node::Node::RoleSpec(n)) => v.visit_role_spec(n),
+        Some(node::Node::FuncCall(n)) => v.visit_func_call(n),
+        Some(node::Node::AStar(n)) => v.visit_a_star(n),
+        Some(node::Node::AIndices(n)) => v.visit_a_indices(n),
+        Some(node::Node::AIndirection(n)) => v.visit_a_indirection(n),
+        Some(node::Node::AArrayExpr(n)) => v.visit_a_array_expr(n),
+        Some(node::Node::ResTarget(n)) => v.visit_res_target(n),
+        Some(node::Node::MultiAssignRef(n)) => v.visit_multi_assign_ref(n),
+        Some(node::Node::SortBy(n)) => v.visit_sort_by(n),
+        Some(node::Node::WindowDef(n)) => v.visit_window_def(n),
+        Some(node::Node::RangeSubselect(n)) => v.visit_range_subselect(n),
+        Some(node::Node::RangeFunction(n)) => v.visit_range_function(n),
+        Some(node::Node::RangeTableFunc(n)) => v.visit_range_table_func(n),
+        Some(node::Node::RangeTableFuncCol(n)) => v.visit_range_table_func_col(n),
+        Some(node::Node::RangeTableSample(n)) => v.visit_range_table_sample(n),
+        Some(node::Node::ColumnDef(n)) => v.visit_column_def(n),
+        Some(node::Node::TableLikeClause(n)) => v.visit_table_like_clause(n),
+        Some(node::Node::IndexElem(n)) => v.visit_index_elem(n),
+        Some(node::Node::DefElem(n)) => v.visit_def_elem(n),
+        Some(node::Node::LockingClause(n)) => v.visit_locking_clause(n),
+        Some(node::Node::XmlSerialize(n)) => v.visit_xml_serialize(n),
+        Some(node::Node::PartitionElem(n)) => v.visit_partition_elem(n),
+        Some(node::Node::PartitionSpec(n)) => v.visit_partition_spec(n),
+        Some(node::Node::PartitionBoundSpec(n)) => v.visit_partition_bound_spec(n),
+        Some(node::Node::PartitionRangeDatum(n)) => v.visit_partition_range_datum(n),
+        Some(node::Node::SinglePartitionSpec(n)) => v.visit_single_partition_spec(n),
+        Some(node::Node::PartitionCmd(n)) => v.visit_partition_cmd(n),
+        Some(node::Node::RangeTblEntry(n)) => v.visit_range_tbl_entry(n),
+        Some(node::Node::RtepermissionInfo(n)) => v.visit_rte_permission_info(n),
+        Some(node::Node::RangeTblFunction(n)) => v.visit_range_tbl_function(n),
+        Some(node::Node::TableSampleClause(n)) => v.visit_table_sample_clause(n),
+        Some(node::Node::WithCheckOption(n)) => v.visit_with_check_option(n),
+        Some(node::Node::SortGroupClause(n)) => v.visit_sort_group_clause(n),
+        Some(node::Node::GroupingSet(n)) => v.visit_grouping_set(n),
+        Some(node::Node::WindowClause(n)) => v.visit_window_clause(n),
+        Some(node::Node::RowMarkClause(n)) => v.visit_row_mark_clause(n),
+        Some(node::Node::WithClause(n)) => v.visit_with_clause(n),
+        Some(node::Node::InferClause(n)) => v.visit_infer_clause(n),
+        Some(node::Node::OnConflictClause(n)) => v.visit_on_conflict_clause(n),
+        Some(node::Node::CtesearchClause(n)) => v.visit_cte_search_clause(n),
+        Some(node::Node::CtecycleClause(n)) => v.visit_cte_cycle_clause(n),
+        Some(node::Node::CommonTableExpr(n)) => v.visit_common_table_expr(n),
+        Some(node::Node::MergeWhenClause(n)) => v.visit_merge_when_clause(n),
+        Some(node::Node::TriggerTransition(n)) => v.visit_trigger_transition(n),
+        Some(node::Node::JsonOutput(n)) => v.visit_json_output(n),
+        Some(node::Node::JsonArgument(n)) => v.visit_json_argument(n),
+        Some(node::Node::JsonFuncExpr(n)) => v.visit_json_func_expr(n),
+        Some(node::Node::JsonTablePathSpec(n)) => v.visit_json_table_path_spec(n),
+        Some(node::Node::JsonTable(n)) => v.visit_json_table(n),
+        Some(node::Node::JsonTableColumn(n)) => v.visit_json_table_column(n),
+        Some(node::Node::JsonKeyValue(n)) => v.visit_json_key_value(n),
+        Some(node::Node::JsonParseExpr(n)) => v.visit_json_parse_expr(n),
+        Some(node::Node::JsonScalarExpr(n)) => v.visit_json_scalar_expr(n),
+        Some(node::Node::JsonSerializeExpr(n)) => v.visit_json_serialize_expr(n),
+        Some(node::Node::JsonObjectConstructor(n)) => v.visit_json_object_constructor(n),
+        Some(node::Node::JsonArrayConstructor(n)) => v.visit_json_array_constructor(n),
+        Some(node::Node::JsonArrayQueryConstructor(n)) => v.visit_json_array_query_constructor(n),
+        Some(node::Node::JsonAggConstructor(n)) => v.visit_json_agg_constructor(n),
+        Some(node::Node::JsonObjectAgg(n)) => v.visit_json_object_agg(n),
+        Some(node::Node::JsonArrayAgg(n)) => v.visit_json_array_agg(n),
+        Some(node::Node::RawStmt(n)) => v.visit_raw_stmt(n),
+        Some(node::Node::InsertStmt(n)) => v.visit_insert_stmt(n),
+        Some(node::Node::DeleteStmt(n)) => v.visit_delete_stmt(n),
+        Some(node::Node::UpdateStmt(n)) => v.visit_update_stmt(n),
+        Some(node::Node::MergeStmt(n)) => v.visit_merge_stmt(n),
+        Some(node::Node::SelectStmt(n)) => v.visit_select_stmt(n),
+        Some(node::Node::SetOperationStmt(n)) => v.visit_set_operation_stmt(n),
+        Some(node::Node::ReturnStmt(n)) => v.visit_return_stmt(n),
+        Some(node::Node::PlassignStmt(n)) => v.visit_pl_assign_stmt(n),
+        Some(node::Node::CreateSchemaStmt(n)) => v.visit_create_schema_stmt(n),
+        Some(node::Node::AlterTableStmt(n)) => v.visit_alter_table_stmt(n),
+        Some(node::Node::ReplicaIdentityStmt(n)) => v.visit_replica_identity_stmt(n),
+        Some(node::Node::AlterTableCmd(n)) => v.visit_alter_table_cmd(n),
+        Some(node::Node::AlterCollationStmt(n)) => v.visit_alter_collation_stmt(n),
+        Some(node::Node::AlterDomainStmt(n)) => v.visit_alter_domain_stmt(n),
+        Some(node::Node::GrantStmt(n)) => v.visit_grant_stmt(n),
+        Some(node::Node::ObjectWithArgs(n)) => v.visit_object_with_args(n),
+        Some(node::Node::AccessPriv(n)) => v.visit_access_priv(n),
+        Some(node::Node::GrantRoleStmt(n)) => v.visit_grant_role_stmt(n),
+        Some(node::Node::AlterDefaultPrivilegesStmt(n)) => v.visit_alter_default_privileges_stmt(n),
+        Some(node::Node::CopyStmt(n)) => v.visit_copy_stmt(n),
+        Some(node::Node::VariableSetStmt(n)) => v.visit_variable_set_stmt(n),
+        Some(node::Node::VariableShowStmt(n)) => v.visit_variable_show_stmt(n),
+        Some(node::Node::CreateStmt(n)) => v.visit_create_stmt(n),
+        Some(node::Node::Constraint(n)) => v.visit_constraint(n),
+        Some(node::Node::CreateTableSpaceStmt(n)) => v.visit_create_table_space_stmt(n),
+        Some(node::Node::DropTableSpaceStmt(n)) => v.visit_drop_table_space_stmt(n),
+        Some(node::Node::AlterTableSpaceOptionsStmt(n)) => v.visit_alter_table_space_options_stmt(n),
+        Some(node::Node::AlterTableMoveAllStmt(n)) => v.visit_alter_table_move_all_stmt(n),
+        Some(node::Node::CreateExtensionStmt(n)) => v.visit_create_extension_stmt(n),
+        Some(node::Node::AlterExtensionStmt(n)) => v.visit_alter_extension_stmt(n),
+        Some(node::Node::AlterExtensionContentsStmt(n)) => v.visit_alter_extension_contents_stmt(n),
+        Some(node::Node::CreateFdwStmt(n)) => v.visit_create_fdw_stmt(n),
+        Some(node::Node::AlterFdwStmt(n)) => v.visit_alter_fdw_stmt(n),
+        Some(node::Node::CreateForeignServerStmt(n)) => v.visit_create_foreign_server_stmt(n),
+        Some(node::Node::AlterForeignServerStmt(n)) => v.visit_alter_foreign_server_stmt(n),
+        Some(node::Node::CreateForeignTableStmt(n)) => v.visit_create_foreign_table_stmt(n),
+        Some(node::Node::CreateUserMappingStmt(n)) => v.visit_create_user_mapping_stmt(n),
+        Some(node::Node::AlterUserMappingStmt(n)) => v.visit_alter_user_mapping_stmt(n),
+        Some(node::Node::DropUserMappingStmt(n)) => v.visit_drop_user_mapping_stmt(n),
+        Some(node::Node::ImportForeignSchemaStmt(n)) => v.visit_import_foreign_schema_stmt(n),
+        Some(node::Node::CreatePolicyStmt(n)) => v.visit_create_policy_stmt(n),
+        Some(node::Node::AlterPolicyStmt(n)) => v.visit_alter_policy_stmt(n),
+        Some(node::Node::CreateAmStmt(n)) => v.visit_create_am_stmt(n),
+        Some(node::Node::CreateTrigStmt(n)) => v.visit_create_trig_stmt(n),
+        Some(node::Node::CreateEventTrigStmt(n)) => v.visit_create_event_trig_stmt(n),
+        Some(node::Node::AlterEventTrigStmt(n)) => v.visit_alter_event_trig_stmt(n),
+        Some(node::Node::CreatePlangStmt(n)) => v.visit_create_p_lang_stmt(n),
+        Some(node::Node::CreateRoleStmt(n)) => v.visit_create_role_stmt(n),
+        Some(node::Node::AlterRoleStmt(n)) => v.visit_alter_role_stmt(n),
+        Some(node::Node::AlterRoleSetStmt(n)) => v.visit_alter_role_set_stmt(n),
+        Some(node::Node::DropRoleStmt(n)) => v.visit_drop_role_stmt(n),
+        Some(node::Node::CreateSeqStmt(n)) => v.visit_create_seq_stmt(n),
+        Some(node::Node::AlterSeqStmt(n)) => v.visit_alter_seq_stmt(n),
+        Some(node::Node::DefineStmt(n)) => v.visit_define_stmt(n),
+        Some(node::Node::CreateDomainStmt(n)) => v.visit_create_domain_stmt(n),
+        Some(node::Node::CreateOpClassStmt(n)) => v.visit_create_op_class_stmt(n),
+        Some(node::Node::CreateOpClassItem(n)) => v.visit_create_op_class_item(n),
+        Some(node::Node::CreateOpFamilyStmt(n)) => v.visit_create_op_family_stmt(n),
+        Some(node::Node::AlterOpFamilyStmt(n)) => v.visit_alter_op_family_stmt(n),
+        Some(node::Node::DropStmt(n)) => v.visit_drop_stmt(n),
+        Some(node::Node::TruncateStmt(n)) => v.visit_truncate_stmt(n),
+        Some(node::Node::CommentStmt(n)) => v.visit_comment_stmt(n),
+        Some(node::Node::SecLabelStmt(n)) => v.visit_sec_label_stmt(n),
+        Some(node::Node::DeclareCursorStmt(n)) => v.visit_declare_cursor_stmt(n),
+        Some(node::Node::ClosePortalStmt(n)) => v.visit_close_portal_stmt(n),
+        Some(node::Node::FetchStmt(n)) => v.visit_fetch_stmt(n),
+        Some(node::Node::IndexStmt(n)) => v.visit_index_stmt(n),
+        Some(node::Node::CreateStatsStmt(n)) => v.visit_create_stats_stmt(n),
+        Some(node::Node::StatsElem(n)) => v.visit_stats_elem(n),
+        Some(node::Node::AlterStatsStmt(n)) => v.visit_alter_stats_stmt(n),
+        Some(node::Node::CreateFunctionStmt(n)) => v.visit_create_function_stmt(n),
+        Some(node::Node::FunctionParameter(n)) => v.visit_function_parameter(n),
+        Some(node::Node::AlterFunctionStmt(n)) => v.visit_alter_function_stmt(n),
+        Some(node::Node::DoStmt(n)) => v.visit_do_stmt(n),
+        Some(node::Node::InlineCodeBlock(n)) => v.visit_inline_code_block(n),
+        Some(node::Node::CallStmt(n)) => v.visit_call_stmt(n),
+        Some(node::Node::CallContext(n)) => v.visit_call_context(n),
+        Some(node::Node::RenameStmt(n)) => v.visit_rename_stmt(n),
+        Some(node::Node::AlterObjectDependsStmt(n)) => v.visit_alter_object_depends_stmt(n),
+        Some(node::Node::AlterObjectSchemaStmt(n)) => v.visit_alter_object_schema_stmt(n),
+        Some(node::Node::AlterOwnerStmt(n)) => v.visit_alter_owner_stmt(n),
+        Some(node::Node::AlterOperatorStmt(n)) => v.visit_alter_operator_stmt(n),
+        Some(node::Node::AlterTypeStmt(n)) => v.visit_alter_type_stmt(n),
+        Some(node::Node::RuleStmt(n)) => v.visit_rule_stmt(n),
+        Some(node::Node::NotifyStmt(n)) => v.visit_notify_stmt(n),
+        Some(node::Node::ListenStmt(n)) => v.visit_listen_stmt(n),
+        Some(node::Node::UnlistenStmt(n)) => v.visit_unlisten_stmt(n),
+        Some(node::Node::TransactionStmt(n)) => v.visit_transaction_stmt(n),
+        Some(node::Node::CompositeTypeStmt(n)) => v.visit_composite_type_stmt(n),
+        Some(node::Node::CreateEnumStmt(n)) => v.visit_create_enum_stmt(n),
+        Some(node::Node::CreateRangeStmt(n)) => v.visit_create_range_stmt(n),
+        Some(node::Node::AlterEnumStmt(n)) => v.visit_alter_enum_stmt(n),
+        Some(node::Node::ViewStmt(n)) => v.visit_view_stmt(n),
+        Some(node::Node::LoadStmt(n)) => v.visit_load_stmt(n),
+        Some(node::Node::CreatedbStmt(n)) => v.visit_createdb_stmt(n),
+        Some(node::Node::AlterDatabaseStmt(n)) => v.visit_alter_database_stmt(n),
+        Some(node::Node::AlterDatabaseRefreshCollStmt(n)) => v.visit_alter_database_refresh_coll_stmt(n),
+        Some(node::Node::AlterDatabaseSetStmt(n)) => v.visit_alter_database_set_stmt(n),
+        Some(node::Node::DropdbStmt(n)) => v.visit_dropdb_stmt(n),
+        Some(node::Node::AlterSystemStmt(n)) => v.visit_alter_system_stmt(n),
+        Some(node::Node::ClusterStmt(n)) => v.visit_cluster_stmt(n),
+        Some(node::Node::VacuumStmt(n)) => v.visit_vacuum_stmt(n),
+        Some(node::Node::VacuumRelation(n)) => v.visit_vacuum_relation(n),
+        Some(node::Node::ExplainStmt(n)) => v.visit_explain_stmt(n),
+        Some(node::Node::CreateTableAsStmt(n)) => v.visit_create_table_as_stmt(n),
+        Some(node::Node::RefreshMatViewStmt(n)) => v.visit_refresh_mat_view_stmt(n),
+        Some(node::Node::CheckPointStmt(n)) => v.visit_check_point_stmt(n),
+        Some(node::Node::DiscardStmt(n)) => v.visit_discard_stmt(n),
+        Some(node::Node::LockStmt(n)) => v.visit_lock_stmt(n),
+        Some(node::Node::ConstraintsSetStmt(n)) => v.visit_constraints_set_stmt(n),
+        Some(node::Node::ReindexStmt(n)) => v.visit_reindex_stmt(n),
+        Some(node::Node::CreateConversionStmt(n)) => v.visit_create_conversion_stmt(n),
+        Some(node::Node::CreateCastStmt(n)) => v.visit_create_cast_stmt(n),
+        Some(node::Node::CreateTransformStmt(n)) => v.visit_create_transform_stmt(n),
+        Some(node::Node::PrepareStmt(n)) => v.visit_prepare_stmt(n),
+        Some(node::Node::ExecuteStmt(n)) => v.visit_execute_stmt(n),
+        Some(node::Node::DeallocateStmt(n)) => v.visit_deallocate_stmt(n),
+        Some(node::Node::DropOwnedStmt(n)) => v.visit_drop_owned_stmt(n),
+        Some(node::Node::ReassignOwnedStmt(n)) => v.visit_reassign_owned_stmt(n),
+        Some(node::Node::AlterTsdictionaryStmt(n)) => v.visit_alter_ts_dictionary_stmt(n),
+        Some(node::Node::AlterTsconfigurationStmt(n)) => v.visit_alter_ts_configuration_stmt(n),
+        Some(node::Node::PublicationTable(n)) => v.visit_publication_table(n),
+        Some(node::Node::PublicationObjSpec(n)) => v.visit_publication_obj_spec(n),
+        Some(node::Node::CreatePublicationStmt(n)) => v.visit_create_publication_stmt(n),
+        Some(node::Node::AlterPublicationStmt(n)) => v.visit_alter_publication_stmt(n),
+        Some(node::Node::CreateSubscriptionStmt(n)) => v.visit_create_subscription_stmt(n),
+        Some(node::Node::AlterSubscriptionStmt(n)) => v.visit_alter_subscription_stmt(n),
+        Some(node::Node::DropSubscriptionStmt(n)) => v.visit_drop_subscription_stmt(n),
+        Some(node::Node::Integer(n)) => v.visit_integer(n),
+        Some(node::Node::Float(n)) => v.visit_float(n),
+        Some(node::Node::Boolean(n)) => v.visit_boolean(n),
+        Some(node::Node::String(n)) => v.visit_string(n),
+        Some(node::Node::BitString(n)) => v.visit_bit_string(n),
+        Some(node::Node::List(n)) => v.visit_list(n),
+        Some(node::Node::IntList(n)) => v.visit_int_list(n),
+        Some(node::Node::OidList(n)) => v.visit_oid_list(n),
+        Some(node::Node::AConst(n)) => v.visit_a_const(n),
+        None => {}
+    }
+}
+
+pub fn walk_integer<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::Integer) {}
+
+pub fn walk_float<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::Float) {}
+
+pub fn walk_boolean<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::Boolean) {}
+
+pub fn walk_string<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::String) {}
+
+pub fn walk_bit_string<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::BitString) {}
+
+pub fn walk_list<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::List) {
+    for n in &node.items {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_oid_list<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::OidList) {
+    for n in &node.items {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_int_list<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::IntList) {
+    for n in &node.items {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_a_const<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AConst) {
+    match &node.val {
+        Some(a_const::Val::Ival(n)) => v.visit_integer(n),
+        Some(a_const::Val::Fval(n)) => v.visit_float(n),
+        Some(a_const::Val::Boolval(n)) => v.visit_boolean(n),
+        Some(a_const::Val::Sval(n)) => v.visit_string(n),
+        Some(a_const::Val::Bsval(n)) => v.visit_bit_string(n),
+        None => {}
+    }
+}
+
+pub fn walk_alias<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::Alias) {
+    for n in &node.colnames {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_range_var<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RangeVar) {
+    if let Some(n) = &node.alias {
+        v.visit_alias(n);
+    }
+}
+
+pub fn walk_table_func<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::TableFunc) {
+    for n in &node.ns_uris {
+        v.visit_node(n);
+    }
+    for n in &node.ns_names {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.docexpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.rowexpr {
+        v.visit_node(n);
+    }
+    for n in &node.colnames {
+        v.visit_node(n);
+    }
+    for n in &node.coltypes {
+        v.visit_node(n);
+    }
+    for n in &node.coltypmods {
+        v.visit_node(n);
+    }
+    for n in &node.colcollations {
+        v.visit_node(n);
+    }
+    for n in &node.colexprs {
+        v.visit_node(n);
+    }
+    for n in &node.coldefexprs {
+        v.visit_node(n);
+    }
+    for n in &node.colvalexprs {
+        v.visit_node(n);
+    }
+    for n in &node.passingvalexprs {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.plan {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_into_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::IntoClause) {
+    if let Some(n) = &node.rel {
+        v.visit_range_var(n);
+    }
+    for n in &node.col_names {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.view_query {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_var<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::Var) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_param<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::Param) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_aggref<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::Aggref) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.aggargtypes {
+        v.visit_node(n);
+    }
+    for n in &node.aggdirectargs {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    for n in &node.aggorder {
+        v.visit_node(n);
+    }
+    for n in &node.aggdistinct {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.aggfilter {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_grouping_func<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::GroupingFunc) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    for n in &node.refs {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_window_func<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::WindowFunc) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.aggfilter {
+        v.visit_node(n);
+    }
+    for n in &node.run_condition {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_window_func_run_condition<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::WindowFuncRunCondition) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_merge_support_func<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::MergeSupportFunc) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_subscripting_ref<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SubscriptingRef) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.refupperindexpr {
+        v.visit_node(n);
+    }
+    for n in &node.reflowerindexpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.refexpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.refassgnexpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_func_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::FuncExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_named_arg_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::NamedArgExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_op_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::OpExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_distinct_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DistinctExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_null_if_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::NullIfExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_scalar_array_op_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ScalarArrayOpExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_bool_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::BoolExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_sub_link<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SubLink) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.testexpr {
+        v.visit_node(n);
+    }
+    for n in &node.oper_name {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.subselect {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_sub_plan<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SubPlan) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.testexpr {
+        v.visit_node(n);
+    }
+    for n in &node.param_ids {
+        v.visit_node(n);
+    }
+    for n in &node.set_param {
+        v.visit_node(n);
+    }
+    for n in &node.par_param {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alternative_sub_plan<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlternativeSubPlan) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.subplans {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_field_select<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::FieldSelect) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_field_store<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::FieldStore) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+    for n in &node.newvals {
+        v.visit_node(n);
+    }
+    for n in &node.fieldnums {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_relabel_type<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RelabelType) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_coerce_via_io<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CoerceViaIo) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_array_coerce_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ArrayCoerceExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.elemexpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_convert_rowtype_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ConvertRowtypeExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_collate_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CollateExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_case_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CaseExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.defresult {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_case_when<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CaseWhen) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.result {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_case_test_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CaseTestExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_array_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ArrayExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.elements {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_row_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RowExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    for n in &node.colnames {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_row_compare_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RowCompareExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.opnos {
+        v.visit_node(n);
+    }
+    for n in &node.opfamilies {
+        v.visit_node(n);
+    }
+    for n in &node.inputcollids {
+        v.visit_node(n);
+    }
+    for n in &node.largs {
+        v.visit_node(n);
+    }
+    for n in &node.rargs {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_coalesce_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CoalesceExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_min_max_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::MinMaxExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_sql_value_function<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SqlValueFunction) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_xml_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::XmlExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.named_args {
+        v.visit_node(n);
+    }
+    for n in &node.arg_names {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_json_format<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::JsonFormat) {}
+
+pub fn walk_json_returning<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonReturning) {
+    if let Some(n) = &node.format {
+        v.visit_json_format(n);
+    }
+}
+
+pub fn walk_json_value_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonValueExpr) {
+    if let Some(n) = &node.raw_expr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.formatted_expr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.format {
+        v.visit_json_format(n);
+    }
+}
+
+pub fn walk_json_constructor_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonConstructorExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.func {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.coercion {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.returning {
+        v.visit_json_returning(n);
+    }
+}
+
+pub fn walk_json_is_predicate<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonIsPredicate) {
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.format {
+        v.visit_json_format(n);
+    }
+}
+
+pub fn walk_json_behavior<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonBehavior) {
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_json_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.formatted_expr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.format {
+        v.visit_json_format(n);
+    }
+    if let Some(n) = &node.path_spec {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.returning {
+        v.visit_json_returning(n);
+    }
+    for n in &node.passing_names {
+        v.visit_node(n);
+    }
+    for n in &node.passing_values {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.on_empty {
+        v.visit_json_behavior(n);
+    }
+    if let Some(n) = &node.on_error {
+        v.visit_json_behavior(n);
+    }
+}
+
+pub fn walk_json_table_path<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::JsonTablePath) {}
+
+pub fn walk_json_table_path_scan<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonTablePathScan) {
+    if let Some(n) = &node.plan {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.path {
+        v.visit_json_table_path(n);
+    }
+    if let Some(n) = &node.child {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_json_table_sibling_join<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonTableSiblingJoin) {
+    if let Some(n) = &node.plan {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.lplan {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.rplan {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_null_test<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::NullTest) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_boolean_test<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::BooleanTest) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_merge_action<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::MergeAction) {
+    if let Some(n) = &node.qual {
+        v.visit_node(n);
+    }
+    for n in &node.target_list {
+        v.visit_node(n);
+    }
+    for n in &node.update_colnos {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_coerce_to_domain<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CoerceToDomain) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_coerce_to_domain_value<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CoerceToDomainValue) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_set_to_default<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SetToDefault) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_current_of_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CurrentOfExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_next_value_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::NextValueExpr) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_inference_elem<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::InferenceElem) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_target_entry<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::TargetEntry) {
+    if let Some(n) = &node.xpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_range_tbl_ref<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::RangeTblRef) {}
+
+pub fn walk_join_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JoinExpr) {
+    if let Some(n) = &node.larg {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.rarg {
+        v.visit_node(n);
+    }
+    for n in &node.using_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.join_using_alias {
+        v.visit_alias(n);
+    }
+    if let Some(n) = &node.quals {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.alias {
+        v.visit_alias(n);
+    }
+}
+
+pub fn walk_from_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::FromExpr) {
+    for n in &node.fromlist {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.quals {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_on_conflict_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::OnConflictExpr) {
+    for n in &node.arbiter_elems {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.arbiter_where {
+        v.visit_node(n);
+    }
+    for n in &node.on_conflict_set {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.on_conflict_where {
+        v.visit_node(n);
+    }
+    for n in &node.excl_rel_tlist {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_query<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::Query) {
+    if let Some(n) = &node.utility_stmt {
+        v.visit_node(n);
+    }
+    for n in &node.cte_list {
+        v.visit_node(n);
+    }
+    for n in &node.rtable {
+        v.visit_node(n);
+    }
+    for n in &node.rteperminfos {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.jointree {
+        v.visit_from_expr(n);
+    }
+    for n in &node.merge_action_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.merge_join_condition {
+        v.visit_node(n);
+    }
+    for n in &node.target_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.on_conflict {
+        v.visit_on_conflict_expr(n);
+    }
+    for n in &node.returning_list {
+        v.visit_node(n);
+    }
+    for n in &node.group_clause {
+        v.visit_node(n);
+    }
+    for n in &node.grouping_sets {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.having_qual {
+        v.visit_node(n);
+    }
+    for n in &node.window_clause {
+        v.visit_node(n);
+    }
+    for n in &node.distinct_clause {
+        v.visit_node(n);
+    }
+    for n in &node.sort_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.limit_offset {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.limit_count {
+        v.visit_node(n);
+    }
+    for n in &node.row_marks {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.set_operations {
+        v.visit_node(n);
+    }
+    for n in &node.constraint_deps {
+        v.visit_node(n);
+    }
+    for n in &node.with_check_options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_type_name<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::TypeName) {
+    for n in &node.names {
+        v.visit_node(n);
+    }
+    for n in &node.typmods {
+        v.visit_node(n);
+    }
+    for n in &node.array_bounds {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_column_ref<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ColumnRef) {
+    for n in &node.fields {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_param_ref<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::ParamRef) {}
+
+pub fn walk_a_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AExpr) {
+    for n in &node.name {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.lexpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.rexpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_type_cast<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::TypeCast) {
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.type_name {
+        v.visit_type_name(n);
+    }
+}
+
+pub fn walk_collate_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CollateClause) {
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+    for n in &node.collname {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_role_spec<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::RoleSpec) {}
+
+pub fn walk_func_call<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::FuncCall) {
+    for n in &node.funcname {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    for n in &node.agg_order {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.agg_filter {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.over {
+        v.visit_window_def(n);
+    }
+}
+
+pub fn walk_a_star<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::AStar) {}
+
+pub fn walk_a_indices<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AIndices) {
+    if let Some(n) = &node.lidx {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.uidx {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_a_indirection<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AIndirection) {
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+    for n in &node.indirection {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_a_array_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AArrayExpr) {
+    for n in &node.elements {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_res_target<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ResTarget) {
+    for n in &node.indirection {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.val {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_multi_assign_ref<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::MultiAssignRef) {
+    if let Some(n) = &node.source {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_sort_by<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SortBy) {
+    if let Some(n) = &node.node {
+        v.visit_node(n);
+    }
+    for n in &node.use_op {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_window_def<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::WindowDef) {
+    for n in &node.partition_clause {
+        v.visit_node(n);
+    }
+    for n in &node.order_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.start_offset {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.end_offset {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_range_subselect<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RangeSubselect) {
+    if let Some(n) = &node.subquery {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.alias {
+        v.visit_alias(n);
+    }
+}
+
+pub fn walk_range_function<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RangeFunction) {
+    for n in &node.functions {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.alias {
+        v.visit_alias(n);
+    }
+    for n in &node.coldeflist {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_range_table_func<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RangeTableFunc) {
+    if let Some(n) = &node.docexpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.rowexpr {
+        v.visit_node(n);
+    }
+    for n in &node.namespaces {
+        v.visit_node(n);
+    }
+    for n in &node.columns {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.alias {
+        v.visit_alias(n);
+    }
+}
+
+pub fn walk_range_table_func_col<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RangeTableFuncCol) {
+    if let Some(n) = &node.type_name {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.colexpr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.coldefexpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_range_table_sample<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RangeTableSample) {
+    if let Some(n) = &node.relation {
+        v.visit_node(n);
+    }
+    for n in &node.method {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.repeatable {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_column_def<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ColumnDef) {
+    if let Some(n) = &node.type_name {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.raw_default {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.cooked_default {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.identity_sequence {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.coll_clause {
+        v.visit_collate_clause(n);
+    }
+    for n in &node.constraints {
+        v.visit_node(n);
+    }
+    for n in &node.fdwoptions {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_table_like_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::TableLikeClause) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+}
+
+pub fn walk_index_elem<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::IndexElem) {
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+    for n in &node.collation {
+        v.visit_node(n);
+    }
+    for n in &node.opclass {
+        v.visit_node(n);
+    }
+    for n in &node.opclassopts {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_def_elem<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DefElem) {
+    if let Some(n) = &node.arg {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_locking_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::LockingClause) {
+    for n in &node.locked_rels {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_xml_serialize<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::XmlSerialize) {
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.type_name {
+        v.visit_type_name(n);
+    }
+}
+
+pub fn walk_partition_elem<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PartitionElem) {
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+    for n in &node.collation {
+        v.visit_node(n);
+    }
+    for n in &node.opclass {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_partition_spec<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PartitionSpec) {
+    for n in &node.part_params {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_partition_bound_spec<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PartitionBoundSpec) {
+    for n in &node.listdatums {
+        v.visit_node(n);
+    }
+    for n in &node.lowerdatums {
+        v.visit_node(n);
+    }
+    for n in &node.upperdatums {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_partition_range_datum<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PartitionRangeDatum) {
+    if let Some(n) = &node.value {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_single_partition_spec<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::SinglePartitionSpec) {}
+
+pub fn walk_partition_cmd<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PartitionCmd) {
+    if let Some(n) = &node.name {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.bound {
+        v.visit_partition_bound_spec(n);
+    }
+}
+
+pub fn walk_range_tbl_entry<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RangeTblEntry) {
+    if let Some(n) = &node.alias {
+        v.visit_alias(n);
+    }
+    if let Some(n) = &node.eref {
+        v.visit_alias(n);
+    }
+    if let Some(n) = &node.tablesample {
+        v.visit_table_sample_clause(n);
+    }
+    if let Some(n) = &node.subquery {
+        v.visit_query(n);
+    }
+    for n in &node.joinaliasvars {
+        v.visit_node(n);
+    }
+    for n in &node.joinleftcols {
+        v.visit_node(n);
+    }
+    for n in &node.joinrightcols {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.join_using_alias {
+        v.visit_alias(n);
+    }
+    for n in &node.functions {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.tablefunc {
+        v.visit_table_func(n);
+    }
+    for n in &node.values_lists {
+        v.visit_node(n);
+    }
+    for n in &node.coltypes {
+        v.visit_node(n);
+    }
+    for n in &node.coltypmods {
+        v.visit_node(n);
+    }
+    for n in &node.colcollations {
+        v.visit_node(n);
+    }
+    for n in &node.security_quals {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_rte_permission_info<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::RtePermissionInfo) {}
+
+pub fn walk_range_tbl_function<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RangeTblFunction) {
+    if let Some(n) = &node.funcexpr {
+        v.visit_node(n);
+    }
+    for n in &node.funccolnames {
+        v.visit_node(n);
+    }
+    for n in &node.funccoltypes {
+        v.visit_node(n);
+    }
+    for n in &node.funccoltypmods {
+        v.visit_node(n);
+    }
+    for n in &node.funccolcollations {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_table_sample_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::TableSampleClause) {
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.repeatable {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_with_check_option<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::WithCheckOption) {
+    if let Some(n) = &node.qual {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_sort_group_clause<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::SortGroupClause) {}
+
+pub fn walk_grouping_set<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::GroupingSet) {
+    for n in &node.content {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_window_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::WindowClause) {
+    for n in &node.partition_clause {
+        v.visit_node(n);
+    }
+    for n in &node.order_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.start_offset {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.end_offset {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_row_mark_clause<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::RowMarkClause) {}
+
+pub fn walk_with_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::WithClause) {
+    for n in &node.ctes {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_infer_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::InferClause) {
+    for n in &node.index_elems {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_on_conflict_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::OnConflictClause) {
+    if let Some(n) = &node.infer {
+        v.visit_infer_clause(n);
+    }
+    for n in &node.target_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_cte_search_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CteSearchClause) {
+    for n in &node.search_col_list {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_cte_cycle_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CteCycleClause) {
+    for n in &node.cycle_col_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.cycle_mark_value {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.cycle_mark_default {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_common_table_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CommonTableExpr) {
+    for n in &node.aliascolnames {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.ctequery {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.search_clause {
+        v.visit_cte_search_clause(n);
+    }
+    if let Some(n) = &node.cycle_clause {
+        v.visit_cte_cycle_clause(n);
+    }
+    for n in &node.ctecolnames {
+        v.visit_node(n);
+    }
+    for n in &node.ctecoltypes {
+        v.visit_node(n);
+    }
+    for n in &node.ctecoltypmods {
+        v.visit_node(n);
+    }
+    for n in &node.ctecolcollations {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_merge_when_clause<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::MergeWhenClause) {
+    if let Some(n) = &node.condition {
+        v.visit_node(n);
+    }
+    for n in &node.target_list {
+        v.visit_node(n);
+    }
+    for n in &node.values {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_trigger_transition<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::TriggerTransition) {}
+
+pub fn walk_json_output<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonOutput) {
+    if let Some(n) = &node.type_name {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.returning {
+        v.visit_json_returning(n);
+    }
+}
+
+pub fn walk_json_argument<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonArgument) {
+    if let Some(n) = &node.val {
+        v.visit_json_value_expr(n);
+    }
+}
+
+pub fn walk_json_func_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonFuncExpr) {
+    if let Some(n) = &node.context_item {
+        v.visit_json_value_expr(n);
+    }
+    if let Some(n) = &node.pathspec {
+        v.visit_node(n);
+    }
+    for n in &node.passing {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.output {
+        v.visit_json_output(n);
+    }
+    if let Some(n) = &node.on_empty {
+        v.visit_json_behavior(n);
+    }
+    if let Some(n) = &node.on_error {
+        v.visit_json_behavior(n);
+    }
+}
+
+pub fn walk_json_table_path_spec<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonTablePathSpec) {
+    if let Some(n) = &node.string {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_json_table<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonTable) {
+    if let Some(n) = &node.context_item {
+        v.visit_json_value_expr(n);
+    }
+    if let Some(n) = &node.pathspec {
+        v.visit_json_table_path_spec(n);
+    }
+    for n in &node.passing {
+        v.visit_node(n);
+    }
+    for n in &node.columns {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.on_error {
+        v.visit_json_behavior(n);
+    }
+    if let Some(n) = &node.alias {
+        v.visit_alias(n);
+    }
+}
+
+pub fn walk_json_table_column<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonTableColumn) {
+    if let Some(n) = &node.type_name {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.pathspec {
+        v.visit_json_table_path_spec(n);
+    }
+    if let Some(n) = &node.format {
+        v.visit_json_format(n);
+    }
+    for n in &node.columns {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.on_empty {
+        v.visit_json_behavior(n);
+    }
+    if let Some(n) = &node.on_error {
+        v.visit_json_behavior(n);
+    }
+}
+
+pub fn walk_json_key_value<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonKeyValue) {
+    if let Some(n) = &node.key {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.value {
+        v.visit_json_value_expr(n);
+    }
+}
+
+pub fn walk_json_parse_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonParseExpr) {
+    if let Some(n) = &node.expr {
+        v.visit_json_value_expr(n);
+    }
+    if let Some(n) = &node.output {
+        v.visit_json_output(n);
+    }
+}
+
+pub fn walk_json_scalar_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonScalarExpr) {
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.output {
+        v.visit_json_output(n);
+    }
+}
+
+pub fn walk_json_serialize_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonSerializeExpr) {
+    if let Some(n) = &node.expr {
+        v.visit_json_value_expr(n);
+    }
+    if let Some(n) = &node.output {
+        v.visit_json_output(n);
+    }
+}
+
+pub fn walk_json_object_constructor<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonObjectConstructor) {
+    for n in &node.exprs {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.output {
+        v.visit_json_output(n);
+    }
+}
+
+pub fn walk_json_array_constructor<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonArrayConstructor) {
+    for n in &node.exprs {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.output {
+        v.visit_json_output(n);
+    }
+}
+
+pub fn walk_json_array_query_constructor<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonArrayQueryConstructor) {
+    if let Some(n) = &node.query {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.output {
+        v.visit_json_output(n);
+    }
+    if let Some(n) = &node.format {
+        v.visit_json_format(n);
+    }
+}
+
+pub fn walk_json_agg_constructor<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonAggConstructor) {
+    if let Some(n) = &node.output {
+        v.visit_json_output(n);
+    }
+    if let Some(n) = &node.agg_filter {
+        v.visit_node(n);
+    }
+    for n in &node.agg_order {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.over {
+        v.visit_window_def(n);
+    }
+}
+
+pub fn walk_json_object_agg<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonObjectAgg) {
+    if let Some(n) = &node.constructor {
+        v.visit_json_agg_constructor(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_json_key_value(n);
+    }
+}
+
+pub fn walk_json_array_agg<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::JsonArrayAgg) {
+    if let Some(n) = &node.constructor {
+        v.visit_json_agg_constructor(n);
+    }
+    if let Some(n) = &node.arg {
+        v.visit_json_value_expr(n);
+    }
+}
+
+pub fn walk_raw_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RawStmt) {
+    if let Some(n) = &node.stmt {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_insert_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::InsertStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.cols {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.select_stmt {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.on_conflict_clause {
+        v.visit_on_conflict_clause(n);
+    }
+    for n in &node.returning_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.with_clause {
+        v.visit_with_clause(n);
+    }
+}
+
+pub fn walk_delete_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DeleteStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.using_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+    for n in &node.returning_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.with_clause {
+        v.visit_with_clause(n);
+    }
+}
+
+pub fn walk_update_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::UpdateStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.target_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+    for n in &node.from_clause {
+        v.visit_node(n);
+    }
+    for n in &node.returning_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.with_clause {
+        v.visit_with_clause(n);
+    }
+}
+
+pub fn walk_merge_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::MergeStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.source_relation {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.join_condition {
+        v.visit_node(n);
+    }
+    for n in &node.merge_when_clauses {
+        v.visit_node(n);
+    }
+    for n in &node.returning_list {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.with_clause {
+        v.visit_with_clause(n);
+    }
+}
+
+pub fn walk_select_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SelectStmt) {
+    for n in &node.distinct_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.into_clause {
+        v.visit_into_clause(n);
+    }
+    for n in &node.target_list {
+        v.visit_node(n);
+    }
+    for n in &node.from_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+    for n in &node.group_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.having_clause {
+        v.visit_node(n);
+    }
+    for n in &node.window_clause {
+        v.visit_node(n);
+    }
+    for n in &node.values_lists {
+        v.visit_node(n);
+    }
+    for n in &node.sort_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.limit_offset {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.limit_count {
+        v.visit_node(n);
+    }
+    for n in &node.locking_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.with_clause {
+        v.visit_with_clause(n);
+    }
+    if let Some(n) = &node.larg {
+        v.visit_select_stmt(n);
+    }
+    if let Some(n) = &node.rarg {
+        v.visit_select_stmt(n);
+    }
+}
+
+pub fn walk_set_operation_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SetOperationStmt) {
+    if let Some(n) = &node.larg {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.rarg {
+        v.visit_node(n);
+    }
+    for n in &node.col_types {
+        v.visit_node(n);
+    }
+    for n in &node.col_typmods {
+        v.visit_node(n);
+    }
+    for n in &node.col_collations {
+        v.visit_node(n);
+    }
+    for n in &node.group_clauses {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_return_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ReturnStmt) {
+    if let Some(n) = &node.returnval {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_pl_assign_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PlAssignStmt) {
+    for n in &node.indirection {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.val {
+        v.visit_select_stmt(n);
+    }
+}
+
+pub fn walk_create_schema_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateSchemaStmt) {
+    if let Some(n) = &node.authrole {
+        v.visit_role_spec(n);
+    }
+    for n in &node.schema_elts {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_table_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterTableStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.cmds {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_replica_identity_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::ReplicaIdentityStmt) {}
+
+pub fn walk_alter_table_cmd<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterTableCmd) {
+    if let Some(n) = &node.newowner {
+        v.visit_role_spec(n);
+    }
+    if let Some(n) = &node.def {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_collation_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterCollationStmt) {
+    for n in &node.collname {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_domain_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterDomainStmt) {
+    for n in &node.type_name {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.def {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_grant_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::GrantStmt) {
+    for n in &node.objects {
+        v.visit_node(n);
+    }
+    for n in &node.privileges {
+        v.visit_node(n);
+    }
+    for n in &node.grantees {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.grantor {
+        v.visit_role_spec(n);
+    }
+}
+
+pub fn walk_object_with_args<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ObjectWithArgs) {
+    for n in &node.objname {
+        v.visit_node(n);
+    }
+    for n in &node.objargs {
+        v.visit_node(n);
+    }
+    for n in &node.objfuncargs {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_access_priv<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AccessPriv) {
+    for n in &node.cols {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_grant_role_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::GrantRoleStmt) {
+    for n in &node.granted_roles {
+        v.visit_node(n);
+    }
+    for n in &node.grantee_roles {
+        v.visit_node(n);
+    }
+    for n in &node.opt {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.grantor {
+        v.visit_role_spec(n);
+    }
+}
+
+pub fn walk_alter_default_privileges_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterDefaultPrivilegesStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.action {
+        v.visit_grant_stmt(n);
+    }
+}
+
+pub fn walk_copy_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CopyStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.query {
+        v.visit_node(n);
+    }
+    for n in &node.attlist {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_variable_set_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::VariableSetStmt) {
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_variable_show_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::VariableShowStmt) {}
+
+pub fn walk_create_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.table_elts {
+        v.visit_node(n);
+    }
+    for n in &node.inh_relations {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.partbound {
+        v.visit_partition_bound_spec(n);
+    }
+    if let Some(n) = &node.partspec {
+        v.visit_partition_spec(n);
+    }
+    if let Some(n) = &node.of_typename {
+        v.visit_type_name(n);
+    }
+    for n in &node.constraints {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_constraint<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::Constraint) {
+    if let Some(n) = &node.raw_expr {
+        v.visit_node(n);
+    }
+    for n in &node.keys {
+        v.visit_node(n);
+    }
+    for n in &node.including {
+        v.visit_node(n);
+    }
+    for n in &node.exclusions {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.pktable {
+        v.visit_range_var(n);
+    }
+    for n in &node.fk_attrs {
+        v.visit_node(n);
+    }
+    for n in &node.pk_attrs {
+        v.visit_node(n);
+    }
+    for n in &node.fk_del_set_cols {
+        v.visit_node(n);
+    }
+    for n in &node.old_conpfeqop {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_table_space_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateTableSpaceStmt) {
+    if let Some(n) = &node.owner {
+        v.visit_role_spec(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_drop_table_space_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::DropTableSpaceStmt) {}
+
+pub fn walk_alter_table_space_options_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterTableSpaceOptionsStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_table_move_all_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterTableMoveAllStmt) {
+    for n in &node.roles {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_extension_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateExtensionStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_extension_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterExtensionStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_extension_contents_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterExtensionContentsStmt) {
+    if let Some(n) = &node.object {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_fdw_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateFdwStmt) {
+    for n in &node.func_options {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_fdw_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterFdwStmt) {
+    for n in &node.func_options {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_foreign_server_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateForeignServerStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_foreign_server_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterForeignServerStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_foreign_table_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateForeignTableStmt) {
+    if let Some(n) = &node.base_stmt {
+        v.visit_create_stmt(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_user_mapping_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateUserMappingStmt) {
+    if let Some(n) = &node.user {
+        v.visit_role_spec(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_user_mapping_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterUserMappingStmt) {
+    if let Some(n) = &node.user {
+        v.visit_role_spec(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_drop_user_mapping_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DropUserMappingStmt) {
+    if let Some(n) = &node.user {
+        v.visit_role_spec(n);
+    }
+}
+
+pub fn walk_import_foreign_schema_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ImportForeignSchemaStmt) {
+    for n in &node.table_list {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_policy_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreatePolicyStmt) {
+    if let Some(n) = &node.table {
+        v.visit_range_var(n);
+    }
+    for n in &node.roles {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.qual {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.with_check {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_policy_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterPolicyStmt) {
+    if let Some(n) = &node.table {
+        v.visit_range_var(n);
+    }
+    for n in &node.roles {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.qual {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.with_check {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_am_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateAmStmt) {
+    for n in &node.handler_name {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_trig_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateTrigStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.funcname {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    for n in &node.columns {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.when_clause {
+        v.visit_node(n);
+    }
+    for n in &node.transition_rels {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.constrrel {
+        v.visit_range_var(n);
+    }
+}
+
+pub fn walk_create_event_trig_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateEventTrigStmt) {
+    for n in &node.whenclause {
+        v.visit_node(n);
+    }
+    for n in &node.funcname {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_event_trig_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::AlterEventTrigStmt) {}
+
+pub fn walk_create_p_lang_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreatePLangStmt) {
+    for n in &node.plhandler {
+        v.visit_node(n);
+    }
+    for n in &node.plinline {
+        v.visit_node(n);
+    }
+    for n in &node.plvalidator {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_role_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateRoleStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_role_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterRoleStmt) {
+    if let Some(n) = &node.role {
+        v.visit_role_spec(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_role_set_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterRoleSetStmt) {
+    if let Some(n) = &node.role {
+        v.visit_role_spec(n);
+    }
+    if let Some(n) = &node.setstmt {
+        v.visit_variable_set_stmt(n);
+    }
+}
+
+pub fn walk_drop_role_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DropRoleStmt) {
+    for n in &node.roles {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_seq_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateSeqStmt) {
+    if let Some(n) = &node.sequence {
+        v.visit_range_var(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_seq_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterSeqStmt) {
+    if let Some(n) = &node.sequence {
+        v.visit_range_var(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_define_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DefineStmt) {
+    for n in &node.defnames {
+        v.visit_node(n);
+    }
+    for n in &node.args {
+        v.visit_node(n);
+    }
+    for n in &node.definition {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_domain_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateDomainStmt) {
+    for n in &node.domainname {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.type_name {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.coll_clause {
+        v.visit_collate_clause(n);
+    }
+    for n in &node.constraints {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_op_class_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateOpClassStmt) {
+    for n in &node.opclassname {
+        v.visit_node(n);
+    }
+    for n in &node.opfamilyname {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.datatype {
+        v.visit_type_name(n);
+    }
+    for n in &node.items {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_op_class_item<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateOpClassItem) {
+    if let Some(n) = &node.name {
+        v.visit_object_with_args(n);
+    }
+    for n in &node.order_family {
+        v.visit_node(n);
+    }
+    for n in &node.class_args {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.storedtype {
+        v.visit_type_name(n);
+    }
+}
+
+pub fn walk_create_op_family_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateOpFamilyStmt) {
+    for n in &node.opfamilyname {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_op_family_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterOpFamilyStmt) {
+    for n in &node.opfamilyname {
+        v.visit_node(n);
+    }
+    for n in &node.items {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_drop_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DropStmt) {
+    for n in &node.objects {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_truncate_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::TruncateStmt) {
+    for n in &node.relations {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_comment_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CommentStmt) {
+    if let Some(n) = &node.object {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_sec_label_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::SecLabelStmt) {
+    if let Some(n) = &node.object {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_declare_cursor_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DeclareCursorStmt) {
+    if let Some(n) = &node.query {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_close_portal_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::ClosePortalStmt) {}
+
+pub fn walk_fetch_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::FetchStmt) {}
+
+pub fn walk_index_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::IndexStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.index_params {
+        v.visit_node(n);
+    }
+    for n in &node.index_including_params {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+    for n in &node.exclude_op_names {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_stats_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateStatsStmt) {
+    for n in &node.defnames {
+        v.visit_node(n);
+    }
+    for n in &node.stat_types {
+        v.visit_node(n);
+    }
+    for n in &node.exprs {
+        v.visit_node(n);
+    }
+    for n in &node.relations {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_stats_elem<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::StatsElem) {
+    if let Some(n) = &node.expr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_stats_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterStatsStmt) {
+    for n in &node.defnames {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.stxstattarget {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_function_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateFunctionStmt) {
+    for n in &node.funcname {
+        v.visit_node(n);
+    }
+    for n in &node.parameters {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.return_type {
+        v.visit_type_name(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.sql_body {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_function_parameter<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::FunctionParameter) {
+    if let Some(n) = &node.arg_type {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.defexpr {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_function_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterFunctionStmt) {
+    if let Some(n) = &node.func {
+        v.visit_object_with_args(n);
+    }
+    for n in &node.actions {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_do_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DoStmt) {
+    for n in &node.args {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_inline_code_block<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::InlineCodeBlock) {}
+
+pub fn walk_call_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CallStmt) {
+    if let Some(n) = &node.funccall {
+        v.visit_func_call(n);
+    }
+    if let Some(n) = &node.funcexpr {
+        v.visit_func_expr(n);
+    }
+    for n in &node.outargs {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_call_context<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::CallContext) {}
+
+pub fn walk_rename_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RenameStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.object {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_object_depends_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterObjectDependsStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.object {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.extname {
+        v.visit_string(n);
+    }
+}
+
+pub fn walk_alter_object_schema_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterObjectSchemaStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.object {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_owner_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterOwnerStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.object {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.newowner {
+        v.visit_role_spec(n);
+    }
+}
+
+pub fn walk_alter_operator_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterOperatorStmt) {
+    if let Some(n) = &node.opername {
+        v.visit_object_with_args(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_type_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterTypeStmt) {
+    for n in &node.type_name {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_rule_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RuleStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+    for n in &node.actions {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_notify_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::NotifyStmt) {}
+
+pub fn walk_listen_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::ListenStmt) {}
+
+pub fn walk_unlisten_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::UnlistenStmt) {}
+
+pub fn walk_transaction_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::TransactionStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_composite_type_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CompositeTypeStmt) {
+    if let Some(n) = &node.typevar {
+        v.visit_range_var(n);
+    }
+    for n in &node.coldeflist {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_enum_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateEnumStmt) {
+    for n in &node.type_name {
+        v.visit_node(n);
+    }
+    for n in &node.vals {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_range_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateRangeStmt) {
+    for n in &node.type_name {
+        v.visit_node(n);
+    }
+    for n in &node.params {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_enum_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterEnumStmt) {
+    for n in &node.type_name {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_view_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ViewStmt) {
+    if let Some(n) = &node.view {
+        v.visit_range_var(n);
+    }
+    for n in &node.aliases {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.query {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_load_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::LoadStmt) {}
+
+pub fn walk_createdb_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreatedbStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_database_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterDatabaseStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_database_refresh_coll_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::AlterDatabaseRefreshCollStmt) {}
+
+pub fn walk_alter_database_set_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterDatabaseSetStmt) {
+    if let Some(n) = &node.setstmt {
+        v.visit_variable_set_stmt(n);
+    }
+}
+
+pub fn walk_dropdb_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DropdbStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_system_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterSystemStmt) {
+    if let Some(n) = &node.setstmt {
+        v.visit_variable_set_stmt(n);
+    }
+}
+
+pub fn walk_cluster_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ClusterStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.params {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_vacuum_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::VacuumStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    for n in &node.rels {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_vacuum_relation<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::VacuumRelation) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.va_cols {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_explain_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ExplainStmt) {
+    if let Some(n) = &node.query {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_table_as_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateTableAsStmt) {
+    if let Some(n) = &node.query {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.into {
+        v.visit_into_clause(n);
+    }
+}
+
+pub fn walk_refresh_mat_view_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::RefreshMatViewStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+}
+
+pub fn walk_check_point_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::CheckPointStmt) {}
+
+pub fn walk_discard_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::DiscardStmt) {}
+
+pub fn walk_lock_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::LockStmt) {
+    for n in &node.relations {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_constraints_set_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ConstraintsSetStmt) {
+    for n in &node.constraints {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_reindex_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ReindexStmt) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    for n in &node.params {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_conversion_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateConversionStmt) {
+    for n in &node.conversion_name {
+        v.visit_node(n);
+    }
+    for n in &node.func_name {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_cast_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateCastStmt) {
+    if let Some(n) = &node.sourcetype {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.targettype {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.func {
+        v.visit_object_with_args(n);
+    }
+}
+
+pub fn walk_create_transform_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateTransformStmt) {
+    if let Some(n) = &node.type_name {
+        v.visit_type_name(n);
+    }
+    if let Some(n) = &node.fromsql {
+        v.visit_object_with_args(n);
+    }
+    if let Some(n) = &node.tosql {
+        v.visit_object_with_args(n);
+    }
+}
+
+pub fn walk_prepare_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PrepareStmt) {
+    for n in &node.argtypes {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.query {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_execute_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ExecuteStmt) {
+    for n in &node.params {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_deallocate_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::DeallocateStmt) {}
+
+pub fn walk_drop_owned_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::DropOwnedStmt) {
+    for n in &node.roles {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_reassign_owned_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::ReassignOwnedStmt) {
+    for n in &node.roles {
+        v.visit_node(n);
+    }
+    if let Some(n) = &node.newrole {
+        v.visit_role_spec(n);
+    }
+}
+
+pub fn walk_alter_ts_dictionary_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterTsDictionaryStmt) {
+    for n in &node.dictname {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_ts_configuration_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterTsConfigurationStmt) {
+    for n in &node.cfgname {
+        v.visit_node(n);
+    }
+    for n in &node.tokentype {
+        v.visit_node(n);
+    }
+    for n in &node.dicts {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_publication_table<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PublicationTable) {
+    if let Some(n) = &node.relation {
+        v.visit_range_var(n);
+    }
+    if let Some(n) = &node.where_clause {
+        v.visit_node(n);
+    }
+    for n in &node.columns {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_publication_obj_spec<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::PublicationObjSpec) {
+    if let Some(n) = &node.pubtable {
+        v.visit_publication_table(n);
+    }
+}
+
+pub fn walk_create_publication_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreatePublicationStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    for n in &node.pubobjects {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_publication_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterPublicationStmt) {
+    for n in &node.options {
+        v.visit_node(n);
+    }
+    for n in &node.pubobjects {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_create_subscription_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::CreateSubscriptionStmt) {
+    for n in &node.publication {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_alter_subscription_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, node: &'ast protobuf::AlterSubscriptionStmt) {
+    for n in &node.publication {
+        v.visit_node(n);
+    }
+    for n in &node.options {
+        v.visit_node(n);
+    }
+}
+
+pub fn walk_drop_subscription_stmt<'ast, V: Visitor<'ast> + ?Sized>(_v: &mut V, _node: &'ast protobuf::DropSubscriptionStmt) {}
+
+/// Walks a parse tree by mutable reference, with one method per node type.
+///
+/// Works like [Visitor], but every node can be modified or replaced in place. Methods default to
+/// calling the matching `walk_*_mut` function in this module.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::protobuf::RangeVar;
+/// use pg_query::visitor::VisitorMut;
+///
+/// struct Rename;
+///
+/// impl VisitorMut for Rename {
+///     fn visit_range_var_mut(&mut self, node: &mut RangeVar) {
+///         if node.relname == "users" {
+///             node.relname = "accounts".to_string();
+///         }
+///     }
+/// }
+///
+/// let mut result = pg_query::parse("SELECT * FROM users JOIN posts ON true").unwrap();
+/// Rename.visit_parse_result_mut(&mut result.protobuf);
+/// assert_eq!(result.deparse().unwrap(), "SELECT * FROM accounts JOIN posts ON true");
+/// ```
+pub trait VisitorMut {
+    fn visit_node_mut(&mut self, node: &mut protobuf::Node) {
+        walk_node_mut(self, node);
+    }
+    fn visit_parse_result_mut(&mut self, node: &mut protobuf::ParseResult) {
+        walk_parse_result_mut(self, node);
+    }
+    fn visit_integer_mut(&mut self, node: &mut protobuf::Integer) {
+        walk_integer_mut(self, node);
+    }
+    fn visit_float_mut(&mut self, node: &mut protobuf::Float) {
+        walk_float_mut(self, node);
+    }
+    fn visit_boolean_mut(&mut self, node: &mut protobuf::Boolean) {
+        walk_boolean_mut(self, node);
+    }
+    fn visit_string_mut(&mut self, node: &mut protobuf::String) {
+        walk_string_mut(self, node);
+    }
+    fn visit_bit_string_mut(&mut self, node: &mut protobuf::BitString) {
+        walk_bit_string_mut(self, node);
+    }
+    fn visit_list_mut(&mut self, node: &mut protobuf::List) {
+        walk_list_mut(self, node);
+    }
+    fn visit_oid_list_mut(&mut self, node: &mut protobuf::OidList) {
+        walk_oid_list_mut(self, node);
+    }
+    fn visit_int_list_mut(&mut self, node: &mut protobuf::IntList) {
+        walk_int_list_mut(self, node);
+    }
+    fn visit_a_const_mut(&mut self, node: &mut protobuf::AConst) {
+        walk_a_const_mut(self, node);
+    }
+    fn visit_alias_mut(&mut self, node: &mut protobuf::Alias) {
+        walk_alias_mut(self, node);
+    }
+    fn visit_range_var_mut(&mut self, node: &mut protobuf::RangeVar) {
+        walk_range_var_mut(self, node);
+    }
+    fn visit_table_func_mut(&mut self, node: &mut protobuf::TableFunc) {
+        walk_table_func_mut(self, node);
+    }
+    fn visit_into_clause_mut(&mut self, node: &mut protobuf::IntoClause) {
+        walk_into_clause_mut(self, node);
+    }
+    fn visit_var_mut(&mut self, node: &mut protobuf::Var) {
+        walk_var_mut(self, node);
+    }
+    fn visit_param_mut(&mut self, node: &mut protobuf::Param) {
+        walk_param_mut(self, node);
+    }
+    fn visit_aggref_mut(&mut self, node: &mut protobuf::Aggref) {
+        walk_aggref_mut(self, node);
+    }
+    fn visit_grouping_func_mut(&mut self, node: &mut protobuf::GroupingFunc) {
+        walk_grouping_func_mut(self, node);
+    }
+    fn visit_window_func_mut(&mut self, node: &mut protobuf::WindowFunc) {
+        walk_window_func_mut(self, node);
+    }
+    fn visit_window_func_run_condition_mut(&mut self, node: &mut protobuf::WindowFuncRunCondition) {
+        walk_window_func_run_condition_mut(self, node);
+    }
+    fn visit_merge_support_func_mut(&mut self, node: &mut protobuf::MergeSupportFunc) {
+        walk_merge_support_func_mut(self, node);
+    }
+    fn visit_subscripting_ref_mut(&mut self, node: &mut protobuf::SubscriptingRef) {
+        walk_subscripting_ref_mut(self, node);
+    }
+    fn visit_func_expr_mut(&mut self, node: &mut protobuf::FuncExpr) {
+        walk_func_expr_mut(self, node);
+    }
+    fn visit_named_arg_expr_mut(&mut self, node: &mut protobuf::NamedArgExpr) {
+        walk_named_arg_expr_mut(self, node);
+    }
+    fn visit_op_expr_mut(&mut self, node: &mut protobuf::OpExpr) {
+        walk_op_expr_mut(self, node);
+    }
+    fn visit_distinct_expr_mut(&mut self, node: &mut protobuf::DistinctExpr) {
+        walk_distinct_expr_mut(self, node);
+    }
+    fn visit_null_if_expr_mut(&mut self, node: &mut protobuf::NullIfExpr) {
+        walk_null_if_expr_mut(self, node);
+    }
+    fn visit_scalar_array_op_expr_mut(&mut self, node: &mut protobuf::ScalarArrayOpExpr) {
+        walk_scalar_array_op_expr_mut(self, node);
+    }
+    fn visit_bool_expr_mut(&mut self, node: &mut protobuf::BoolExpr) {
+        walk_bool_expr_mut(self, node);
+    }
+    fn visit_sub_link_mut(&mut self, node: &mut protobuf::SubLink) {
+        walk_sub_link_mut(self, node);
+    }
+    fn visit_sub_plan_mut(&mut self, node: &mut protobuf::SubPlan) {
+        walk_sub_plan_mut(self, node);
+    }
+    fn visit_alternative_sub_plan_mut(&mut self, node: &mut protobuf::AlternativeSubPlan) {
+        walk_alternative_sub_plan_mut(self, node);
+    }
+    fn visit_field_select_mut(&mut self, node: &mut protobuf::FieldSelect) {
+        walk_field_select_mut(self, node);
+    }
+    fn visit_field_store_mut(&mut self, node: &mut protobuf::FieldStore) {
+        walk_field_store_mut(self, node);
+    }
+    fn visit_relabel_type_mut(&mut self, node: &mut protobuf::RelabelType) {
+        walk_relabel_type_mut(self, node);
+    }
+    fn visit_coerce_via_io_mut(&mut self, node: &mut protobuf::CoerceViaIo) {
+        walk_coerce_via_io_mut(self, node);
+    }
+    fn visit_array_coerce_expr_mut(&mut self, node: &mut protobuf::ArrayCoerceExpr) {
+        walk_array_coerce_expr_mut(self, node);
+    }
+    fn visit_convert_rowtype_expr_mut(&mut self, node: &mut protobuf::ConvertRowtypeExpr) {
+        walk_convert_rowtype_expr_mut(self, node);
+    }
+    fn visit_collate_expr_mut(&mut self, node: &mut protobuf::CollateExpr) {
+        walk_collate_expr_mut(self, node);
+    }
+    fn visit_case_expr_mut(&mut self, node: &mut protobuf::CaseExpr) {
+        walk_case_expr_mut(self, node);
+    }
+    fn visit_case_when_mut(&mut self, node: &mut protobuf::CaseWhen) {
+        walk_case_when_mut(self, node);
+    }
+    fn visit_case_test_expr_mut(&mut self, node: &mut protobuf::CaseTestExpr) {
+        walk_case_test_expr_mut(self, node);
+    }
+    fn visit_array_expr_mut(&mut self, node: &mut protobuf::ArrayExpr) {
+        walk_array_expr_mut(self, node);
+    }
+    fn visit_row_expr_mut(&mut self, node: &mut protobuf::RowExpr) {
+        walk_row_expr_mut(self, node);
+    }
+    fn visit_row_compare_expr_mut(&mut self, node: &mut protobuf::RowCompareExpr) {
+        walk_row_compare_expr_mut(self, node);
+    }
+    fn visit_coalesce_expr_mut(&mut self, node: &mut protobuf::CoalesceExpr) {
+        walk_coalesce_expr_mut(self, node);
+    }
+    fn visit_min_max_expr_mut(&mut self, node: &mut protobuf::MinMaxExpr) {
+        walk_min_max_expr_mut(self, node);
+    }
+    fn visit_sql_value_function_mut(&mut self, node: &mut protobuf::SqlValueFunction) {
+        walk_sql_value_function_mut(self, node);
+    }
+    fn visit_xml_expr_mut(&mut self, node: &mut protobuf::XmlExpr) {
+        walk_xml_expr_mut(self, node);
+    }
+    fn visit_json_format_mut(&mut self, node: &mut protobuf::JsonFormat) {
+        walk_json_format_mut(self, node);
+    }
+    fn visit_json_returning_mut(&mut self, node: &mut protobuf::JsonReturning) {
+        walk_json_returning_mut(self, node);
+    }
+    fn visit_json_value_expr_mut(&mut self, node: &mut protobuf::JsonValueExpr) {
+        walk_json_value_expr_mut(self, node);
+    }
+    fn visit_json_constructor_expr_mut(&mut self, node: &mut protobuf::JsonConstructorExpr) {
+        walk_json_constructor_expr_mut(self, node);
+    }
+    fn visit_json_is_predicate_mut(&mut self, node: &mut protobuf::JsonIsPredicate) {
+        walk_json_is_predicate_mut(self, node);
+    }
+    fn visit_json_behavior_mut(&mut self, node: &mut protobuf::JsonBehavior) {
+        walk_json_behavior_mut(self, node);
+    }
+    fn visit_json_expr_mut(&mut self, node: &mut protobuf::JsonExpr) {
+        walk_json_expr_mut(self, node);
+    }
+    fn visit_json_table_path_mut(&mut self, node: &mut protobuf::JsonTablePath) {
+        walk_json_table_path_mut(self, node);
+    }
+    fn visit_json_table_path_scan_mut(&mut self, node: &mut protobuf::JsonTablePathScan) {
+        walk_json_table_path_scan_mut(self, node);
+    }
+    fn visit_json_table_sibling_join_mut(&mut self, node: &mut protobuf::JsonTableSiblingJoin) {
+        walk_json_table_sibling_join_mut(self, node);
+    }
+    fn visit_null_test_mut(&mut self, node: &mut protobuf::NullTest) {
+        walk_null_test_mut(self, node);
+    }
+    fn visit_boolean_test_mut(&mut self, node: &mut protobuf::BooleanTest) {
+        walk_boolean_test_mut(self, node);
+    }
+    fn visit_merge_action_mut(&mut self, node: &mut protobuf::MergeAction) {
+        walk_merge_action_mut(self, node);
+    }
+    fn visit_coerce_to_domain_mut(&mut self, node: &mut protobuf::CoerceToDomain) {
+        walk_coerce_to_domain_mut(self, node);
+    }
+    fn visit_coerce_to_domain_value_mut(&mut self, node: &mut protobuf::CoerceToDomainValue) {
+        walk_coerce_to_domain_value_mut(self, node);
+    }
+    fn visit_set_to_default_mut(&mut self, node: &mut protobuf::SetToDefault) {
+        walk_set_to_default_mut(self, node);
+    }
+    fn visit_current_of_expr_mut(&mut self, node: &mut protobuf::CurrentOfExpr) {
+        walk_current_of_expr_mut(self, node);
+    }
+    fn visit_next_value_expr_mut(&mut self, node: &mut protobuf::NextValueExpr) {
+        walk_next_value_expr_mut(self, node);
+    }
+    fn visit_inference_elem_mut(&mut self, node: &mut protobuf::InferenceElem) {
+        walk_inference_elem_mut(self, node);
+    }
+    fn visit_target_entry_mut(&mut self, node: &mut protobuf::TargetEntry) {
+        walk_target_entry_mut(self, node);
+    }
+    fn visit_range_tbl_ref_mut(&mut self, node: &mut protobuf::RangeTblRef) {
+        walk_range_tbl_ref_mut(self, node);
+    }
+    fn visit_join_expr_mut(&mut self, node: &mut protobuf::JoinExpr) {
+        walk_join_expr_mut(self, node);
+    }
+    fn visit_from_expr_mut(&mut self, node: &mut protobuf::FromExpr) {
+        walk_from_expr_mut(self, node);
+    }
+    fn visit_on_conflict_expr_mut(&mut self, node: &mut protobuf::OnConflictExpr) {
+        walk_on_conflict_expr_mut(self, node);
+    }
+    fn visit_query_mut(&mut self, node: &mut protobuf::Query) {
+        walk_query_mut(self, node);
+    }
+    fn visit_type_name_mut(&mut self, node: &mut protobuf::TypeName) {
+        walk_type_name_mut(self, node);
+    }
+    fn visit_column_ref_mut(&mut self, node: &mut protobuf::ColumnRef) {
+        walk_column_ref_mut(self, node);
+    }
+    fn visit_param_ref_mut(&mut self, node: &mut protobuf::ParamRef) {
+        walk_param_ref_mut(self, node);
+    }
+    fn visit_a_expr_mut(&mut self, node: &mut protobuf::AExpr) {
+        walk_a_expr_mut(self, node);
+    }
+    fn visit_type_cast_mut(&mut self, node: &mut protobuf::TypeCast) {
+        walk_type_cast_mut(self, node);
+    }
+    fn visit_collate_clause_mut(&mut self, node: &mut protobuf::CollateClause) {
+        walk_collate_clause_mut(self, node);
+    }
+    fn visit_role_spec_mut(&mut self, node: &mut protobuf::RoleSpec) {
+        walk_role_spec_mut(self, node);
+    }
+    fn visit_func_call_mut(&mut self, node: &mut protobuf::FuncCall) {
+        walk_func_call_mut(self, node);
+    }
+    fn visit_a_star_mut(&mut self, node: &mut protobuf::AStar) {
+        walk_a_star_mut(self, node);
+    }
+    fn visit_a_indices_mut(&mut self, node: &mut protobuf::AIndices) {
+        walk_a_indices_mut(self, node);
+    }
+    fn visit_a_indirection_mut(&mut self, node: &mut protobuf::AIndirection) {
+        walk_a_indirection_mut(self, node);
+    }
+    fn visit_a_array_expr_mut(&mut self, node: &mut protobuf::AArrayExpr) {
+        walk_a_array_expr_mut(self, node);
+    }
+    fn visit_res_target_mut(&mut self, node: &mut protobuf::ResTarget) {
+        walk_res_target_mut(self, node);
+    }
+    fn visit_multi_assign_ref_mut(&mut self, node: &mut protobuf::MultiAssignRef) {
+        walk_multi_assign_ref_mut(self, node);
+    }
+    fn visit_sort_by_mut(&mut self, node: &mut protobuf::SortBy) {
+        walk_sort_by_mut(self, node);
+    }
+    fn visit_window_def_mut(&mut self, node: &mut protobuf::WindowDef) {
+        walk_window_def_mut(self, node);
+    }
+    fn visit_range_subselect_mut(&mut self, node: &mut protobuf::RangeSubselect) {
+        walk_range_subselect_mut(self, node);
+    }
+    fn visit_range_function_mut(&mut self, node: &mut protobuf::RangeFunction) {
+        walk_range_function_mut(self, node);
+    }
+    fn visit_range_table_func_mut(&mut self, node: &mut protobuf::RangeTableFunc) {
+        walk_range_table_func_mut(self, node);
+    }
+    fn visit_range_table_func_col_mut(&mut self, node: &mut protobuf::RangeTableFuncCol) {
+        walk_range_table_func_col_mut(self, node);
+    }
+    fn visit_range_table_sample_mut(&mut self, node: &mut protobuf::RangeTableSample) {
+        walk_range_table_sample_mut(self, node);
+    }
+    fn visit_column_def_mut(&mut self, node: &mut protobuf::ColumnDef) {
+        walk_column_def_mut(self, node);
+    }
+    fn visit_table_like_clause_mut(&mut self, node: &mut protobuf::TableLikeClause) {
+        walk_table_like_clause_mut(self, node);
+    }
+    fn visit_index_elem_mut(&mut self, node: &mut protobuf::IndexElem) {
+        walk_index_elem_mut(self, node);
+    }
+    fn visit_def_elem_mut(&mut self, node: &mut protobuf::DefElem) {
+        walk_def_elem_mut(self, node);
+    }
+    fn visit_locking_clause_mut(&mut self, node: &mut protobuf::LockingClause) {
+        walk_locking_clause_mut(self, node);
+    }
+    fn visit_xml_serialize_mut(&mut self, node: &mut protobuf::XmlSerialize) {
+        walk_xml_serialize_mut(self, node);
+    }
+    fn visit_partition_elem_mut(&mut self, node: &mut protobuf::PartitionElem) {
+        walk_partition_elem_mut(self, node);
+    }
+    fn visit_partition_spec_mut(&mut self, node: &mut protobuf::PartitionSpec) {
+        walk_partition_spec_mut(self, node);
+    }
+    fn visit_partition_bound_spec_mut(&mut self, node: &mut protobuf::PartitionBoundSpec) {
+        walk_partition_bound_spec_mut(self, node);
+    }
+    fn visit_partition_range_datum_mut(&mut self, node: &mut protobuf::PartitionRangeDatum) {
+        walk_partition_range_datum_mut(self, node);
+    }
+    fn visit_single_partition_spec_mut(&mut self, node: &mut protobuf::SinglePartitionSpec) {
+        walk_single_partition_spec_mut(self, node);
+    }
+    fn visit_partition_cmd_mut(&mut self, node: &mut protobuf::PartitionCmd) {
+        walk_partition_cmd_mut(self, node);
+    }
+    fn visit_range_tbl_entry_mut(&mut self, node: &mut protobuf::RangeTblEntry) {
+        walk_range_tbl_entry_mut(self, node);
+    }
+    fn visit_rte_permission_info_mut(&mut self, node: &mut protobuf::RtePermissionInfo) {
+        walk_rte_permission_info_mut(self, node);
+    }
+    fn visit_range_tbl_function_mut(&mut self, node: &mut protobuf::RangeTblFunction) {
+        walk_range_tbl_function_mut(self, node);
+    }
+    fn visit_table_sample_clause_mut(&mut self, node: &mut protobuf::TableSampleClause) {
+        walk_table_sample_clause_mut(self, node);
+    }
+    fn visit_with_check_option_mut(&mut self, node: &mut protobuf::WithCheckOption) {
+        walk_with_check_option_mut(self, node);
+    }
+    fn visit_sort_group_clause_mut(&mut self, node: &mut protobuf::SortGroupClause) {
+        walk_sort_group_clause_mut(self, node);
+    }
+    fn visit_grouping_set_mut(&mut self, node: &mut protobuf::GroupingSet) {
+        walk_grouping_set_mut(self, node);
+    }
+    fn visit_window_clause_mut(&mut self, node: &mut protobuf::WindowClause) {
+        walk_window_clause_mut(self, node);
+    }
+    fn visit_row_mark_clause_mut(&mut self, node: &mut protobuf::RowMarkClause) {
+        walk_row_mark_clause_mut(self, node);
+    }
+    fn visit_with_clause_mut(&mut self, node: &mut protobuf::WithClause) {
+        walk_with_clause_mut(self, node);
+    }
+    fn visit_infer_clause_mut(&mut self, node: &mut protobuf::InferClause) {
+        walk_infer_clause_mut(self, node);
+    }
+    fn visit_on_conflict_clause_mut(&mut self, node: &mut protobuf::OnConflictClause) {
+        walk_on_conflict_clause_mut(self, node);
+    }
+    fn visit_cte_search_clause_mut(&mut self, node: &mut protobuf::CteSearchClause) {
+        walk_cte_search_clause_mut(self, node);
+    }
+    fn visit_cte_cycle_clause_mut(&mut self, node: &mut protobuf::CteCycleClause) {
+        walk_cte_cycle_clause_mut(self, node);
+    }
+    fn visit_common_table_expr_mut(&mut self, node: &mut protobuf::CommonTableExpr) {
+        walk_common_table_expr_mut(self, node);
+    }
+    fn visit_merge_when_clause_mut(&mut self, node: &mut protobuf::MergeWhenClause) {
+        walk_merge_when_clause_mut(self, node);
+    }
+    fn visit_trigger_transition_mut(&mut self, node: &mut protobuf::TriggerTransition) {
+        walk_trigger_transition_mut(self, node);
+    }
+    fn visit_json_output_mut(&mut self, node: &mut protobuf::JsonOutput) {
+        walk_json_output_mut(self, node);
+    }
+    fn visit_json_argument_mut(&mut self, node: &mut protobuf::JsonArgument) {
+        walk_json_argument_mut(self, node);
+    }
+    fn visit_json_func_expr_mut(&mut self, node: &mut protobuf::JsonFuncExpr) {
+        walk_json_func_expr_mut(self, node);
+    }
+    fn visit_json_table_path_spec_mut(&mut self, node: &mut protobuf::JsonTablePathSpec) {
+        walk_json_table_path_spec_mut(self, node);
+    }
+    fn visit_json_table_mut(&mut self, node: &mut protobuf::JsonTable) {
+        walk_json_table_mut(self, node);
+    }
+    fn visit_json_table_column_mut(&mut self, node: &mut protobuf::JsonTableColumn) {
+        walk_json_table_column_mut(self, node);
+    }
+    fn visit_json_key_value_mut(&mut self, node: &mut protobuf::JsonKeyValue) {
+        walk_json_key_value_mut(self, node);
+    }
+    fn visit_json_parse_expr_mut(&mut self, node: &mut protobuf::JsonParseExpr) {
+        walk_json_parse_expr_mut(self, node);
+    }
+    fn visit_json_scalar_expr_mut(&mut self, node: &mut protobuf::JsonScalarExpr) {
+        walk_json_scalar_expr_mut(self, node);
+    }
+    fn visit_json_serialize_expr_mut(&mut self, node: &mut protobuf::JsonSerializeExpr) {
+        walk_json_serialize_expr_mut(self, node);
+    }
+    fn visit_json_object_constructor_mut(&mut self, node: &mut protobuf::JsonObjectConstructor) {
+        walk_json_object_constructor_mut(self, node);
+    }
+    fn visit_json_array_constructor_mut(&mut self, node: &mut protobuf::JsonArrayConstructor) {
+        walk_json_array_constructor_mut(self, node);
+    }
+    fn visit_json_array_query_constructor_mut(&mut self, node: &mut protobuf::JsonArrayQueryConstructor) {
+        walk_json_array_query_constructor_mut(self, node);
+    }
+    fn visit_json_agg_constructor_mut(&mut self, node: &mut protobuf::JsonAggConstructor) {
+        walk_json_agg_constructor_mut(self, node);
+    }
+    fn visit_json_object_agg_mut(&mut self, node: &mut protobuf::JsonObjectAgg) {
+        walk_json_object_agg_mut(self, node);
+    }
+    fn visit_json_array_agg_mut(&mut self, node: &mut protobuf::JsonArrayAgg) {
+        walk_json_array_agg_mut(self, node);
+    }
+    fn visit_raw_stmt_mut(&mut self, node: &mut protobuf::RawStmt) {
+        walk_raw_stmt_mut(self, node);
+    }
+    fn visit_insert_stmt_mut(&mut self, node: &mut protobuf::InsertStmt) {
+        walk_insert_stmt_mut(self, node);
+    }
+    fn visit_delete_stmt_mut(&mut self, node: &mut protobuf::DeleteStmt) {
+        walk_delete_stmt_mut(self, node);
+    }
+    fn visit_update_stmt_mut(&mut self, node: &mut protobuf::UpdateStmt) {
+        walk_update_stmt_mut(self, node);
+    }
+    fn visit_merge_stmt_mut(&mut self, node: &mut protobuf::MergeStmt) {
+        walk_merge_stmt_mut(self, node);
+    }
+    fn visit_select_stmt_mut(&mut self, node: &mut protobuf::SelectStmt) {
+        walk_select_stmt_mut(self, node);
+    }
+    fn visit_set_operation_stmt_mut(&mut self, node: &mut protobuf::SetOperationStmt) {
+        walk_set_operation_stmt_mut(self, node);
+    }
+    fn visit_return_stmt_mut(&mut self, node: &mut protobuf::ReturnStmt) {
+        walk_return_stmt_mut(self, node);
+    }
+    fn visit_pl_assign_stmt_mut(&mut self, node: &mut protobuf::PlAssignStmt) {
+        walk_pl_assign_stmt_mut(self, node);
+    }
+    fn visit_create_schema_stmt_mut(&mut self, node: &mut protobuf::CreateSchemaStmt) {
+        walk_create_schema_stmt_mut(self, node);
+    }
+    fn visit_alter_table_stmt_mut(&mut self, node: &mut protobuf::AlterTableStmt) {
+        walk_alter_table_stmt_mut(self, node);
+    }
+    fn visit_replica_identity_stmt_mut(&mut self, node: &mut protobuf::ReplicaIdentityStmt) {
+        walk_replica_identity_stmt_mut(self, node);
+    }
+    fn visit_alter_table_cmd_mut(&mut self, node: &mut protobuf::AlterTableCmd) {
+        walk_alter_table_cmd_mut(self, node);
+    }
+    fn visit_alter_collation_stmt_mut(&mut self, node: &mut protobuf::AlterCollationStmt) {
+        walk_alter_collation_stmt_mut(self, node);
+    }
+    fn visit_alter_domain_stmt_mut(&mut self, node: &mut protobuf::AlterDomainStmt) {
+        walk_alter_domain_stmt_mut(self, node);
+    }
+    fn visit_grant_stmt_mut(&mut self, node: &mut protobuf::GrantStmt) {
+        walk_grant_stmt_mut(self, node);
+    }
+    fn visit_object_with_args_mut(&mut self, node: &mut protobuf::ObjectWithArgs) {
+        walk_object_with_args_mut(self, node);
+    }
+    fn visit_access_priv_mut(&mut self, node: &mut protobuf::AccessPriv) {
+        walk_access_priv_mut(self, node);
+    }
+    fn visit_grant_role_stmt_mut(&mut self, node: &mut protobuf::GrantRoleStmt) {
+        walk_grant_role_stmt_mut(self, node);
+    }
+    fn visit_alter_default_privileges_stmt_mut(&mut self, node: &mut protobuf::AlterDefaultPrivilegesStmt) {
+        walk_alter_default_privileges_stmt_mut(self, node);
+    }
+    fn visit_copy_stmt_mut(&mut self, node: &mut protobuf::CopyStmt) {
+        walk_copy_stmt_mut(self, node);
+    }
+    fn visit_variable_set_stmt_mut(&mut self, node: &mut protobuf::VariableSetStmt) {
+        walk_variable_set_stmt_mut(self, node);
+    }
+    fn visit_variable_show_stmt_mut(&mut self, node: &mut protobuf::VariableShowStmt) {
+        walk_variable_show_stmt_mut(self, node);
+    }
+    fn visit_create_stmt_mut(&mut self, node: &mut protobuf::CreateStmt) {
+        walk_create_stmt_mut(self, node);
+    }
+    fn visit_constraint_mut(&mut self, node: &mut protobuf::Constraint) {
+        walk_constraint_mut(self, node);
+    }
+    fn visit_create_table_space_stmt_mut(&mut self, node: &mut protobuf::CreateTableSpaceStmt) {
+        walk_create_table_space_stmt_mut(self, node);
+    }
+    fn visit_drop_table_space_stmt_mut(&mut self, node: &mut protobuf::DropTableSpaceStmt) {
+        walk_drop_table_space_stmt_mut(self, node);
+    }
+    fn visit_alter_table_space_options_stmt_mut(&mut self, node: &mut protobuf::AlterTableSpaceOptionsStmt) {
+        walk_alter_table_space_options_stmt_mut(self, node);
+    }
+    fn visit_alter_table_move_all_stmt_mut(&mut self, node: &mut protobuf::AlterTableMoveAllStmt) {
+        walk_alter_table_move_all_stmt_mut(self, node);
+    }
+    fn visit_create_extension_stmt_mut(&mut self, node: &mut protobuf::CreateExtensionStmt) {
+        walk_create_extension_stmt_mut(self, node);
+    }
+    fn visit_alter_extension_stmt_mut(&mut self, node: &mut protobuf::AlterExtensionStmt) {
+        walk_alter_extension_stmt_mut(self, node);
+    }
+    fn visit_alter_extension_contents_stmt_mut(&mut self, node: &mut protobuf::AlterExtensionContentsStmt) {
+        walk_alter_extension_contents_stmt_mut(self, node);
+    }
+    fn visit_create_fdw_stmt_mut(&mut self, node: &mut protobuf::CreateFdwStmt) {
+        walk_create_fdw_stmt_mut(self, node);
+    }
+    fn visit_alter_fdw_stmt_mut(&mut self, node: &mut protobuf::AlterFdwStmt) {
+        walk_alter_fdw_stmt_mut(self, node);
+    }
+    fn visit_create_foreign_server_stmt_mut(&mut self, node: &mut protobuf::CreateForeignServerStmt) {
+        walk_create_foreign_server_stmt_mut(self, node);
+    }
+    fn visit_alter_foreign_server_stmt_mut(&mut self, node: &mut protobuf::AlterForeignServerStmt) {
+        walk_alter_foreign_server_stmt_mut(self, node);
+    }
+    fn visit_create_foreign_table_stmt_mut(&mut self, node: &mut protobuf::CreateForeignTableStmt) {
+        walk_create_foreign_table_stmt_mut(self, node);
+    }
+    fn visit_create_user_mapping_stmt_mut(&mut self, node: &mut protobuf::CreateUserMappingStmt) {
+        walk_create_user_mapping_stmt_mut(self, node);
+    }
+    fn visit_alter_user_mapping_stmt_mut(&mut self, node: &mut protobuf::AlterUserMappingStmt) {
+        walk_alter_user_mapping_stmt_mut(self, node);
+    }
+    fn visit_drop_user_mapping_stmt_mut(&mut self, node: &mut protobuf::DropUserMappingStmt) {
+        walk_drop_user_mapping_stmt_mut(self, node);
+    }
+    fn visit_import_foreign_schema_stmt_mut(&mut self, node: &mut protobuf::ImportForeignSchemaStmt) {
+        walk_import_foreign_schema_stmt_mut(self, node);
+    }
+    fn visit_create_policy_stmt_mut(&mut self, node: &mut protobuf::CreatePolicyStmt) {
+        walk_create_policy_stmt_mut(self, node);
+    }
+    fn visit_alter_policy_stmt_mut(&mut self, node: &mut protobuf::AlterPolicyStmt) {
+        walk_alter_policy_stmt_mut(self, node);
+    }
+    fn visit_create_am_stmt_mut(&mut self, node: &mut protobuf::CreateAmStmt) {
+        walk_create_am_stmt_mut(self, node);
+    }
+    fn visit_create_trig_stmt_mut(&mut self, node: &mut protobuf::CreateTrigStmt) {
+        walk_create_trig_stmt_mut(self, node);
+    }
+    fn visit_create_event_trig_stmt_mut(&mut self, node: &mut protobuf::CreateEventTrigStmt) {
+        walk_create_event_trig_stmt_mut(self, node);
+    }
+    fn visit_alter_event_trig_stmt_mut(&mut self, node: &mut protobuf::AlterEventTrigStmt) {
+        walk_alter_event_trig_stmt_mut(self, node);
+    }
+    fn visit_create_p_lang_stmt_mut(&mut self, node: &mut protobuf::CreatePLangStmt) {
+        walk_create_p_lang_stmt_mut(self, node);
+    }
+    fn visit_create_role_stmt_mut(&mut self, node: &mut protobuf::CreateRoleStmt) {
+        walk_create_role_stmt_mut(self, node);
+    }
+    fn visit_alter_role_stmt_mut(&mut self, node: &mut protobuf::AlterRoleStmt) {
+        walk_alter_role_stmt_mut(self, node);
+    }
+    fn visit_alter_role_set_stmt_mut(&mut self, node: &mut protobuf::AlterRoleSetStmt) {
+        walk_alter_role_set_stmt_mut(self, node);
+    }
+    fn visit_drop_role_stmt_mut(&mut self, node: &mut protobuf::DropRoleStmt) {
+        walk_drop_role_stmt_mut(self, node);
+    }
+    fn visit_create_seq_stmt_mut(&mut self, node: &mut protobuf::CreateSeqStmt) {
+        walk_create_seq_stmt_mut(self, node);
+    }
+    fn visit_alter_seq_stmt_mut(&mut self, node: &mut protobuf::AlterSeqStmt) {
+        walk_alter_seq_stmt_mut(self, node);
+    }
+    fn visit_define_stmt_mut(&mut self, node: &mut protobuf::DefineStmt) {
+        walk_define_stmt_mut(self, node);
+    }
+    fn visit_create_domain_stmt_mut(&mut self, node: &mut protobuf::CreateDomainStmt) {
+        walk_create_domain_stmt_mut(self, node);
+    }
+    fn visit_create_op_class_stmt_mut(&mut self, node: &mut protobuf::CreateOpClassStmt) {
+        walk_create_op_class_stmt_mut(self, node);
+    }
+    fn visit_create_op_class_item_mut(&mut self, node: &mut protobuf::CreateOpClassItem) {
+        walk_create_op_class_item_mut(self, node);
+    }
+    fn visit_create_op_family_stmt_mut(&mut self, node: &mut protobuf::CreateOpFamilyStmt) {
+        walk_create_op_family_stmt_mut(self, node);
+    }
+    fn visit_alter_op_family_stmt_mut(&mut self, node: &mut protobuf::AlterOpFamilyStmt) {
+        walk_alter_op_family_stmt_mut(self, node);
+    }
+    fn visit_drop_stmt_mut(&mut self, node: &mut protobuf::DropStmt) {
+        walk_drop_stmt_mut(self, node);
+    }
+    fn visit_truncate_stmt_mut(&mut self, node: &mut protobuf::TruncateStmt) {
+        walk_truncate_stmt_mut(self, node);
+    }
+    fn visit_comment_stmt_mut(&mut self, node: &mut protobuf::CommentStmt) {
+        walk_comment_stmt_mut(self, node);
+    }
+    fn visit_sec_label_stmt_mut(&mut self, node: &mut protobuf::SecLabelStmt) {
+        walk_sec_label_stmt_mut(self, node);
+    }
+    fn visit_declare_cursor_stmt_mut(&mut self, node: &mut protobuf::DeclareCursorStmt) {
+        walk_declare_cursor_stmt_mut(self, node);
+    }
+    fn visit_close_portal_stmt_mut(&mut self, node: &mut protobuf::ClosePortalStmt) {
+        walk_close_portal_stmt_mut(self, node);
+    }
+    fn visit_fetch_stmt_mut(&mut self, node: &mut protobuf::FetchStmt) {
+        walk_fetch_stmt_mut(self, node);
+    }
+    fn visit_index_stmt_mut(&mut self, node: &mut protobuf::IndexStmt) {
+        walk_index_stmt_mut(self, node);
+    }
+    fn visit_create_stats_stmt_mut(&mut self, node: &mut protobuf::CreateStatsStmt) {
+        walk_create_stats_stmt_mut(self, node);
+    }
+    fn visit_stats_elem_mut(&mut self, node: &mut protobuf::StatsElem) {
+        walk_stats_elem_mut(self, node);
+    }
+    fn visit_alter_stats_stmt_mut(&mut self, node: &mut protobuf::AlterStatsStmt) {
+        walk_alter_stats_stmt_mut(self, node);
+    }
+    fn visit_create_function_stmt_mut(&mut self, node: &mut protobuf::CreateFunctionStmt) {
+        walk_create_function_stmt_mut(self, node);
+    }
+    fn visit_function_parameter_mut(&mut self, node: &mut protobuf::FunctionParameter) {
+        walk_function_parameter_mut(self, node);
+    }
+    fn visit_alter_function_stmt_mut(&mut self, node: &mut protobuf::AlterFunctionStmt) {
+        walk_alter_function_stmt_mut(self, node);
+    }
+    fn visit_do_stmt_mut(&mut self, node: &mut protobuf::DoStmt) {
+        walk_do_stmt_mut(self, node);
+    }
+    fn visit_inline_code_block_mut(&mut self, node: &mut protobuf::InlineCodeBlock) {
+        walk_inline_code_block_mut(self, node);
+    }
+    fn visit_call_stmt_mut(&mut self, node: &mut protobuf::CallStmt) {
+        walk_call_stmt_mut(self, node);
+    }
+    fn visit_call_context_mut(&mut self, node: &mut protobuf::CallContext) {
+        walk_call_context_mut(self, node);
+    }
+    fn visit_rename_stmt_mut(&mut self, node: &mut protobuf::RenameStmt) {
+        walk_rename_stmt_mut(self, node);
+    }
+    fn visit_alter_object_depends_stmt_mut(&mut self, node: &mut protobuf::AlterObjectDependsStmt) {
+        walk_alter_object_depends_stmt_mut(self, node);
+    }
+    fn visit_alter_object_schema_stmt_mut(&mut self, node: &mut protobuf::AlterObjectSchemaStmt) {
+        walk_alter_object_schema_stmt_mut(self, node);
+    }
+    fn visit_alter_owner_stmt_mut(&mut self, node: &mut protobuf::AlterOwnerStmt) {
+        walk_alter_owner_stmt_mut(self, node);
+    }
+    fn visit_alter_operator_stmt_mut(&mut self, node: &mut protobuf::AlterOperatorStmt) {
+        walk_alter_operator_stmt_mut(self, node);
+    }
+    fn visit_alter_type_stmt_mut(&mut self, node: &mut protobuf::AlterTypeStmt) {
+        walk_alter_type_stmt_mut(self, node);
+    }
+    fn visit_rule_stmt_mut(&mut self, node: &mut protobuf::RuleStmt) {
+        walk_rule_stmt_mut(self, node);
+    }
+    fn visit_notify_stmt_mut(&mut self, node: &mut protobuf::NotifyStmt) {
+        walk_notify_stmt_mut(self, node);
+    }
+    fn visit_listen_stmt_mut(&mut self, node: &mut protobuf::ListenStmt) {
+        walk_listen_stmt_mut(self, node);
+    }
+    fn visit_unlisten_stmt_mut(&mut self, node: &mut protobuf::UnlistenStmt) {
+        walk_unlisten_stmt_mut(self, node);
+    }
+    fn visit_transaction_stmt_mut(&mut self, node: &mut protobuf::TransactionStmt) {
+        walk_transaction_stmt_mut(self, node);
+    }
+    fn visit_composite_type_stmt_mut(&mut self, node: &mut protobuf::CompositeTypeStmt) {
+        walk_composite_type_stmt_mut(self, node);
+    }
+    fn visit_create_enum_stmt_mut(&mut self, node: &mut protobuf::CreateEnumStmt) {
+        walk_create_enum_stmt_mut(self, node);
+    }
+    fn visit_create_range_stmt_mut(&mut self, node: &mut protobuf::CreateRangeStmt) {
+        walk_create_range_stmt_mut(self, node);
+    }
+    fn visit_alter_enum_stmt_mut(&mut self, node: &mut protobuf::AlterEnumStmt) {
+        walk_alter_enum_stmt_mut(self, node);
+    }
+    fn visit_view_stmt_mut(&mut self, node: &mut protobuf::ViewStmt) {
+        walk_view_stmt_mut(self, node);
+    }
+    fn visit_load_stmt_mut(&mut self, node: &mut protobuf::LoadStmt) {
+        walk_load_stmt_mut(self, node);
+    }
+    fn visit_createdb_stmt_mut(&mut self, node: &mut protobuf::CreatedbStmt) {
+        walk_createdb_stmt_mut(self, node);
+    }
+    fn visit_alter_database_stmt_mut(&mut self, node: &mut protobuf::AlterDatabaseStmt) {
+        walk_alter_database_stmt_mut(self, node);
+    }
+    fn visit_alter_database_refresh_coll_stmt_mut(&mut self, node: &mut protobuf::AlterDatabaseRefreshCollStmt) {
+        walk_alter_database_refresh_coll_stmt_mut(self, node);
+    }
+    fn visit_alter_database_set_stmt_mut(&mut self, node: &mut protobuf::AlterDatabaseSetStmt) {
+        walk_alter_database_set_stmt_mut(self, node);
+    }
+    fn visit_dropdb_stmt_mut(&mut self, node: &mut protobuf::DropdbStmt) {
+        walk_dropdb_stmt_mut(self, node);
+    }
+    fn visit_alter_system_stmt_mut(&mut self, node: &mut protobuf::AlterSystemStmt) {
+        walk_alter_system_stmt_mut(self, node);
+    }
+    fn visit_cluster_stmt_mut(&mut self, node: &mut protobuf::ClusterStmt) {
+        walk_cluster_stmt_mut(self, node);
+    }
+    fn visit_vacuum_stmt_mut(&mut self, node: &mut protobuf::VacuumStmt) {
+        walk_vacuum_stmt_mut(self, node);
+    }
+    fn visit_vacuum_relation_mut(&mut self, node: &mut protobuf::VacuumRelation) {
+        walk_vacuum_relation_mut(self, node);
+    }
+    fn visit_explain_stmt_mut(&mut self, node: &mut protobuf::ExplainStmt) {
+        walk_explain_stmt_mut(self, node);
+    }
+    fn visit_create_table_as_stmt_mut(&mut self, node: &mut protobuf::CreateTableAsStmt) {
+        walk_create_table_as_stmt_mut(self, node);
+    }
+    fn visit_refresh_mat_view_stmt_mut(&mut self, node: &mut protobuf::RefreshMatViewStmt) {
+        walk_refresh_mat_view_stmt_mut(self, node);
+    }
+    fn visit_check_point_stmt_mut(&mut self, node: &mut protobuf::CheckPointStmt) {
+        walk_check_point_stmt_mut(self, node);
+    }
+    fn visit_discard_stmt_mut(&mut self, node: &mut protobuf::DiscardStmt) {
+        walk_discard_stmt_mut(self, node);
+    }
+    fn visit_lock_stmt_mut(&mut self, node: &mut protobuf::LockStmt) {
+        walk_lock_stmt_mut(self, node);
+    }
+    fn visit_constraints_set_stmt_mut(&mut self, node: &mut protobuf::ConstraintsSetStmt) {
+        walk_constraints_set_stmt_mut(self, node);
+    }
+    fn visit_reindex_stmt_mut(&mut self, node: &mut protobuf::ReindexStmt) {
+        walk_reindex_stmt_mut(self, node);
+    }
+    fn visit_create_conversion_stmt_mut(&mut self, node: &mut protobuf::CreateConversionStmt) {
+        walk_create_conversion_stmt_mut(self, node);
+    }
+    fn visit_create_cast_stmt_mut(&mut self, node: &mut protobuf::CreateCastStmt) {
+        walk_create_cast_stmt_mut(self, node);
+    }
+    fn visit_create_transform_stmt_mut(&mut self, node: &mut protobuf::CreateTransformStmt) {
+        walk_create_transform_stmt_mut(self, node);
+    }
+    fn visit_prepare_stmt_mut(&mut self, node: &mut protobuf::PrepareStmt) {
+        walk_prepare_stmt_mut(self, node);
+    }
+    fn visit_execute_stmt_mut(&mut self, node: &mut protobuf::ExecuteStmt) {
+        walk_execute_stmt_mut(self, node);
+    }
+    fn visit_deallocate_stmt_mut(&mut self, node: &mut protobuf::DeallocateStmt) {
+        walk_deallocate_stmt_mut(self, node);
+    }
+    fn visit_drop_owned_stmt_mut(&mut self, node: &mut protobuf::DropOwnedStmt) {
+        walk_drop_owned_stmt_mut(self, node);
+    }
+    fn visit_reassign_owned_stmt_mut(&mut self, node: &mut protobuf::ReassignOwnedStmt) {
+        walk_reassign_owned_stmt_mut(self, node);
+    }
+    fn visit_alter_ts_dictionary_stmt_mut(&mut self, node: &mut protobuf::AlterTsDictionaryStmt) {
+        walk_alter_ts_dictionary_stmt_mut(self, node);
+    }
+    fn visit_alter_ts_configuration_stmt_mut(&mut self, node: &mut protobuf::AlterTsConfigurationStmt) {
+        walk_alter_ts_configuration_stmt_mut(self, node);
+    }
+    fn visit_publication_table_mut(&mut self, node: &mut protobuf::PublicationTable) {
+        walk_publication_table_mut(self, node);
+    }
+    fn visit_publication_obj_spec_mut(&mut self, node: &mut protobuf::PublicationObjSpec) {
+        walk_publication_obj_spec_mut(self, node);
+    }
+    fn visit_create_publication_stmt_mut(&mut self, node: &mut protobuf::CreatePublicationStmt) {
+        walk_create_publication_stmt_mut(self, node);
+    }
+    fn visit_alter_publication_stmt_mut(&mut self, node: &mut protobuf::AlterPublicationStmt) {
+        walk_alter_publication_stmt_mut(self, node);
+    }
+    fn visit_create_subscription_stmt_mut(&mut self, node: &mut protobuf::CreateSubscriptionStmt) {
+        walk_create_subscription_stmt_mut(self, node);
+    }
+    fn visit_alter_subscription_stmt_mut(&mut self, node: &mut protobuf::AlterSubscriptionStmt) {
+        walk_alter_subscription_stmt_mut(self, node);
+    }
+    fn visit_drop_subscription_stmt_mut(&mut self, node: &mut protobuf::DropSubscriptionStmt) {
+        walk_drop_subscription_stmt_mut(self, node);
+    }
+}
+
+pub fn walk_parse_result_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ParseResult) {
+    for n in &mut node.stmts {
+        v.visit_raw_stmt_mut(n);
+    }
+}
+
+pub fn walk_node_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::Node) {
+    match &mut node.node {
+        Some(node::Node::Alias(n)) => v.visit_alias_mut(n),
+        Some(node::Node::RangeVar(n)) => v.visit_range_var_mut(n),
+        Some(node::Node::TableFunc(n)) => v.visit_table_func_mut(n),
+        Some(node::Node::IntoClause(n)) => v.visit_into_clause_mut(n),
+        Some(node::Node::Var(n)) => v.visit_var_mut(n),
+        Some(node::Node::Param(n)) => v.visit_param_mut(n),
+        Some(node::Node::Aggref(n)) => v.visit_aggref_mut(n),
+        Some(node::Node::GroupingFunc(n)) => v.visit_grouping_func_mut(n),
+        Some(node::Node::WindowFunc(n)) => v.visit_window_func_mut(n),
+        Some(node::Node::WindowFuncRunCondition(n)) => v.visit_window_func_run_condition_mut(n),
+        Some(node::Node::MergeSupportFunc(n)) => v.visit_merge_support_func_mut(n),
+        Some(node::Node::SubscriptingRef(n)) => v.visit_subscripting_ref_mut(n),
+        Some(node::Node::FuncExpr(n)) => v.visit_func_expr_mut(n),
+        Some(node::Node::NamedArgExpr(n)) => v.visit_named_arg_expr_mut(n),
+        Some(node::Node::OpExpr(n)) => v.visit_op_expr_mut(n),
+        Some(node::Node::DistinctExpr(n)) => v.visit_distinct_expr_mut(n),
+        Some(node::Node::NullIfExpr(n)) => v.visit_null_if_expr_mut(n),
+        Some(node::Node::ScalarArrayOpExpr(n)) => v.visit_scalar_array_op_expr_mut(n),
+        Some(node::Node::BoolExpr(n)) => v.visit_bool_expr_mut(n),
+        Some(node::Node::SubLink(n)) => v.visit_sub_link_mut(n),
+        Some(node::Node::SubPlan(n)) => v.visit_sub_plan_mut(n),
+        Some(node::Node::AlternativeSubPlan(n)) => v.visit_alternative_sub_plan_mut(n),
+        Some(node::Node::FieldSelect(n)) => v.visit_field_select_mut(n),
+        Some(node::Node::FieldStore(n)) => v.visit_field_store_mut(n),
+        Some(node::Node::RelabelType(n)) => v.visit_relabel_type_mut(n),
+        Some(node::Node::CoerceViaIo(n)) => v.visit_coerce_via_io_mut(n),
+        Some(node::Node::ArrayCoerceExpr(n)) => v.visit_array_coerce_expr_mut(n),
+        Some(node::Node::ConvertRowtypeExpr(n)) => v.visit_convert_rowtype_expr_mut(n),
+        Some(node::Node::CollateExpr(n)) => v.visit_collate_expr_mut(n),
+        Some(node::Node::CaseExpr(n)) => v.visit_case_expr_mut(n),
+        Some(node::Node::CaseWhen(n)) => v.visit_case_when_mut(n),
+        Some(node::Node::CaseTestExpr(n)) => v.visit_case_test_expr_mut(n),
+        Some(node::Node::ArrayExpr(n)) => v.visit_array_expr_mut(n),
+        Some(node::Node::RowExpr(n)) => v.visit_row_expr_mut(n),
+        Some(node::Node::RowCompareExpr(n)) => v.visit_row_compare_expr_mut(n),
+        Some(node::Node::CoalesceExpr(n)) => v.visit_coalesce_expr_mut(n),
+        Some(node::Node::MinMaxExpr(n)) => v.visit_min_max_expr_mut(n),
+        Some(node::Node::SqlvalueFunction(n)) => v.visit_sql_value_function_mut(n),
+        Some(node::Node::XmlExpr(n)) => v.visit_xml_expr_mut(n),
+        Some(node::Node::JsonFormat(n)) => v.visit_json_format_mut(n),
+        Some(node::Node::JsonReturning(n)) => v.visit_json_returning_mut(n),
+        Some(node::Node::JsonValueExpr(n)) => v.visit_json_value_expr_mut(n),
+        Some(node::Node::JsonConstructorExpr(n)) => v.visit_json_constructor_expr_mut(n),
+        Some(node::Node::JsonIsPredicate(n)) => v.visit_json_is_predicate_mut(n),
+        Some(node::Node::JsonBehavior(n)) => v.visit_json_behavior_mut(n),
+        Some(node::Node::JsonExpr(n)) => v.visit_json_expr_mut(n),
+        Some(node::Node::JsonTablePath(n)) => v.visit_json_table_path_mut(n),
+        Some(node::Node::JsonTablePathScan(n)) => v.visit_json_table_path_scan_mut(n),
+        Some(node::Node::JsonTableSiblingJoin(n)) => v.visit_json_table_sibling_join_mut(n),
+        Some(node::Node::NullTest(n)) => v.visit_null_test_mut(n),
+        Some(node::Node::BooleanTest(n)) => v.visit_boolean_test_mut(n),
+        Some(node::Node::MergeAction(n)) => v.visit_merge_action_mut(n),
+        Some(node::Node::CoerceToDomain(n)) => v.visit_coerce_to_domain_mut(n),
+        Some(node::Node::CoerceToDomainValue(n)) => v.visit_coerce_to_domain_value_mut(n),
+        Some(node::Node::SetToDefault(n)) => v.visit_set_to_default_mut(n),
+        Some(node::Node::CurrentOfExpr(n)) => v.visit_current_of_expr_mut(n),
+        Some(node::Node::NextValueExpr(n)) => v.visit_next_value_expr_mut(n),
+        Some(node::Node::InferenceElem(n)) => v.visit_inference_elem_mut(n),
+        Some(node::Node::TargetEntry(n)) => v.visit_target_entry_mut(n),
+        Some(node::Node::RangeTblRef(n)) => v.visit_range_tbl_ref_mut(n),
+        Some(node::Node::JoinExpr(n)) => v.visit_join_expr_mut(n),
+        Some(node::Node::FromExpr(n)) => v.visit_from_expr_mut(n),
+        Some(node::Node::OnConflictExpr(n)) => v.visit_on_conflict_expr_mut(n),
+        Some(node::Node::Query(n)) => v.visit_query_mut(n),
+        Some(node::Node::TypeName(n)) => v.visit_type_name_mut(n),
+        Some(node::Node::ColumnRef(n)) => v.visit_column_ref_mut(n),
+        Some(node::Node::ParamRef(n)) => v.visit_param_ref_mut(n),
+        Some(node::Node::AExpr(n)) => v.visit_a_expr_mut(n),
+        Some(node::Node::TypeCast(n)) => v.visit_type_cast_mut(n),
+        Some(node::Node::CollateClause(n)) => v.visit_collate_clause_mut(n),
+        Some(node::Node::RoleSpec(n)) => v.visit_role_spec_mut(n),
+        Some(node::Node::FuncCall(n)) => v.visit_func_call_mut(n),
+        Some(node::Node::AStar(n)) => v.visit_a_star_mut(n),
+        Some(node::Node::AIndices(n)) => v.visit_a_indices_mut(n),
+        Some(node::Node::AIndirection(n)) => v.visit_a_indirection_mut(n),
+        Some(node::Node::AArrayExpr(n)) => v.visit_a_array_expr_mut(n),
+        Some(node::Node::ResTarget(n)) => v.visit_res_target_mut(n),
+        Some(node::Node::MultiAssignRef(n)) => v.visit_multi_assign_ref_mut(n),
+        Some(node::Node::SortBy(n)) => v.visit_sort_by_mut(n),
+        Some(node::Node::WindowDef(n)) => v.visit_window_def_mut(n),
+        Some(node::Node::RangeSubselect(n)) => v.visit_range_subselect_mut(n),
+        Some(node::Node::RangeFunction(n)) => v.visit_range_function_mut(n),
+        Some(node::Node::RangeTableFunc(n)) => v.visit_range_table_func_mut(n),
+        Some(node::Node::RangeTableFuncCol(n)) => v.visit_range_table_func_col_mut(n),
+        Some(node::Node::RangeTableSample(n)) => v.visit_range_table_sample_mut(n),
+        Some(node::Node::ColumnDef(n)) => v.visit_column_def_mut(n),
+        Some(node::Node::TableLikeClause(n)) => v.visit_table_like_clause_mut(n),
+        Some(node::Node::IndexElem(n)) => v.visit_index_elem_mut(n),
+        Some(node::Node::DefElem(n)) => v.visit_def_elem_mut(n),
+        Some(node::Node::LockingClause(n)) => v.visit_locking_clause_mut(n),
+        Some(node::Node::XmlSerialize(n)) => v.visit_xml_serialize_mut(n),
+        Some(node::Node::PartitionElem(n)) => v.visit_partition_elem_mut(n),
+        Some(node::Node::PartitionSpec(n)) => v.visit_partition_spec_mut(n),
+        Some(node::Node::PartitionBoundSpec(n)) => v.visit_partition_bound_spec_mut(n),
+        Some(node::Node::PartitionRangeDatum(n)) => v.visit_partition_range_datum_mut(n),
+        Some(node::Node::SinglePartitionSpec(n)) => v.visit_single_partition_spec_mut(n),
+        Some(node::Node::PartitionCmd(n)) => v.visit_partition_cmd_mut(n),
+        Some(node::Node::RangeTblEntry(n)) => v.visit_range_tbl_entry_mut(n),
+        Some(node::Node::RtepermissionInfo(n)) => v.visit_rte_permission_info_mut(n),
+        Some(node::Node::RangeTblFunction(n)) => v.visit_range_tbl_function_mut(n),
+        Some(node::Node::TableSampleClause(n)) => v.visit_table_sample_clause_mut(n),
+        Some(node::Node::WithCheckOption(n)) => v.visit_with_check_option_mut(n),
+        Some(node::Node::SortGroupClause(n)) => v.visit_sort_group_clause_mut(n),
+        Some(node::Node::GroupingSet(n)) => v.visit_grouping_set_mut(n),
+        Some(node::Node::WindowClause(n)) => v.visit_window_clause_mut(n),
+        Some(node::Node::RowMarkClause(n)) => v.visit_row_mark_clause_mut(n),
+        Some(node::Node::WithClause(n)) => v.visit_with_clause_mut(n),
+        Some(node::Node::InferClause(n)) => v.visit_infer_clause_mut(n),
+        Some(node::Node::OnConflictClause(n)) => v.visit_on_conflict_clause_mut(n),
+        Some(node::Node::CtesearchClause(n)) => v.visit_cte_search_clause_mut(n),
+        Some(node::Node::CtecycleClause(n)) => v.visit_cte_cycle_clause_mut(n),
+        Some(node::Node::CommonTableExpr(n)) => v.visit_common_table_expr_mut(n),
+        Some(node::Node::MergeWhenClause(n)) => v.visit_merge_when_clause_mut(n),
+        Some(node::Node::TriggerTransition(n)) => v.visit_trigger_transition_mut(n),
+        Some(node::Node::JsonOutput(n)) => v.visit_json_output_mut(n),
+        Some(node::Node::JsonArgument(n)) => v.visit_json_argument_mut(n),
+        Some(node::Node::JsonFuncExpr(n)) => v.visit_json_func_expr_mut(n),
+        Some(node::Node::JsonTablePathSpec(n)) => v.visit_json_table_path_spec_mut(n),
+        Some(node::Node::JsonTable(n)) => v.visit_json_table_mut(n),
+        Some(node::Node::JsonTableColumn(n)) => v.visit_json_table_column_mut(n),
+        Some(node::Node::JsonKeyValue(n)) => v.visit_json_key_value_mut(n),
+        Some(node::Node::JsonParseExpr(n)) => v.visit_json_parse_expr_mut(n),
+        Some(node::Node::JsonScalarExpr(n)) => v.visit_json_scalar_expr_mut(n),
+        Some(node::Node::JsonSerializeExpr(n)) => v.visit_json_serialize_expr_mut(n),
+        Some(node::Node::JsonObjectConstructor(n)) => v.visit_json_object_constructor_mut(n),
+        Some(node::Node::JsonArrayConstructor(n)) => v.visit_json_array_constructor_mut(n),
+        Some(node::Node::JsonArrayQueryConstructor(n)) => v.visit_json_array_query_constructor_mut(n),
+        Some(node::Node::JsonAggConstructor(n)) => v.visit_json_agg_constructor_mut(n),
+        Some(node::Node::JsonObjectAgg(n)) => v.visit_json_object_agg_mut(n),
+        Some(node::Node::JsonArrayAgg(n)) => v.visit_json_array_agg_mut(n),
+        Some(node::Node::RawStmt(n)) => v.visit_raw_stmt_mut(n),
+        Some(node::Node::InsertStmt(n)) => v.visit_insert_stmt_mut(n),
+        Some(node::Node::DeleteStmt(n)) => v.visit_delete_stmt_mut(n),
+        Some(node::Node::UpdateStmt(n)) => v.visit_update_stmt_mut(n),
+        Some(node::Node::MergeStmt(n)) => v.visit_merge_stmt_mut(n),
+        Some(node::Node::SelectStmt(n)) => v.visit_select_stmt_mut(n),
+        Some(node::Node::SetOperationStmt(n)) => v.visit_set_operation_stmt_mut(n),
+        Some(node::Node::ReturnStmt(n)) => v.visit_return_stmt_mut(n),
+        Some(node::Node::PlassignStmt(n)) => v.visit_pl_assign_stmt_mut(n),
+        Some(node::Node::CreateSchemaStmt(n)) => v.visit_create_schema_stmt_mut(n),
+        Some(node::Node::AlterTableStmt(n)) => v.visit_alter_table_stmt_mut(n),
+        Some(node::Node::ReplicaIdentityStmt(n)) => v.visit_replica_identity_stmt_mut(n),
+        Some(node::Node::AlterTableCmd(n)) => v.visit_alter_table_cmd_mut(n),
+        Some(node::Node::AlterCollationStmt(n)) => v.visit_alter_collation_stmt_mut(n),
+        Some(node::Node::AlterDomainStmt(n)) => v.visit_alter_domain_stmt_mut(n),
+        Some(node::Node::GrantStmt(n)) => v.visit_grant_stmt_mut(n),
+        Some(node::Node::ObjectWithArgs(n)) => v.visit_object_with_args_mut(n),
+        Some(node::Node::AccessPriv(n)) => v.visit_access_priv_mut(n),
+        Some(node::Node::GrantRoleStmt(n)) => v.visit_grant_role_stmt_mut(n),
+        Some(node::Node::AlterDefaultPrivilegesStmt(n)) => v.visit_alter_default_privileges_stmt_mut(n),
+        Some(node::Node::CopyStmt(n)) => v.visit_copy_stmt_mut(n),
+        Some(node::Node::VariableSetStmt(n)) => v.visit_variable_set_stmt_mut(n),
+        Some(node::Node::VariableShowStmt(n)) => v.visit_variable_show_stmt_mut(n),
+        Some(node::Node::CreateStmt(n)) => v.visit_create_stmt_mut(n),
+        Some(node::Node::Constraint(n)) => v.visit_constraint_mut(n),
+        Some(node::Node::CreateTableSpaceStmt(n)) => v.visit_create_table_space_stmt_mut(n),
+        Some(node::Node::DropTableSpaceStmt(n)) => v.visit_drop_table_space_stmt_mut(n),
+        Some(node::Node::AlterTableSpaceOptionsStmt(n)) => v.visit_alter_table_space_options_stmt_mut(n),
+        Some(node::Node::AlterTableMoveAllStmt(n)) => v.visit_alter_table_move_all_stmt_mut(n),
+        Some(node::Node::CreateExtensionStmt(n)) => v.visit_create_extension_stmt_mut(n),
+        Some(node::Node::AlterExtensionStmt(n)) => v.visit_alter_extension_stmt_mut(n),
+        Some(node::Node::AlterExtensionContentsStmt(n)) => v.visit_alter_extension_contents_stmt_mut(n),
+        Some(node::Node::CreateFdwStmt(n)) => v.visit_create_fdw_stmt_mut(n),
+        Some(node::Node::AlterFdwStmt(n)) => v.visit_alter_fdw_stmt_mut(n),
+        Some(node::Node::CreateForeignServerStmt(n)) => v.visit_create_foreign_server_stmt_mut(n),
+        Some(node::Node::AlterForeignServerStmt(n)) => v.visit_alter_foreign_server_stmt_mut(n),
+        Some(node::Node::CreateForeignTableStmt(n)) => v.visit_create_foreign_table_stmt_mut(n),
+        Some(node::Node::CreateUserMappingStmt(n)) => v.visit_create_user_mapping_stmt_mut(n),
+        Some(node::Node::AlterUserMappingStmt(n)) => v.visit_alter_user_mapping_stmt_mut(n),
+        Some(node::Node::DropUserMappingStmt(n)) => v.visit_drop_user_mapping_stmt_mut(n),
+        Some(node::Node::ImportForeignSchemaStmt(n)) => v.visit_import_foreign_schema_stmt_mut(n),
+        Some(node::Node::CreatePolicyStmt(n)) => v.visit_create_policy_stmt_mut(n),
+        Some(node::Node::AlterPolicyStmt(n)) => v.visit_alter_policy_stmt_mut(n),
+        Some(node::Node::CreateAmStmt(n)) => v.visit_create_am_stmt_mut(n),
+        Some(node::Node::CreateTrigStmt(n)) => v.visit_create_trig_stmt_mut(n),
+        Some(node::Node::CreateEventTrigStmt(n)) => v.visit_create_event_trig_stmt_mut(n),
+        Some(node::Node::AlterEventTrigStmt(n)) => v.visit_alter_event_trig_stmt_mut(n),
+        Some(node::Node::CreatePlangStmt(n)) => v.visit_create_p_lang_stmt_mut(n),
+        Some(node::Node::CreateRoleStmt(n)) => v.visit_create_role_stmt_mut(n),
+        Some(node::Node::AlterRoleStmt(n)) => v.visit_alter_role_stmt_mut(n),
+        Some(node::Node::AlterRoleSetStmt(n)) => v.visit_alter_role_set_stmt_mut(n),
+        Some(node::Node::DropRoleStmt(n)) => v.visit_drop_role_stmt_mut(n),
+        Some(node::Node::CreateSeqStmt(n)) => v.visit_create_seq_stmt_mut(n),
+        Some(node::Node::AlterSeqStmt(n)) => v.visit_alter_seq_stmt_mut(n),
+        Some(node::Node::DefineStmt(n)) => v.visit_define_stmt_mut(n),
+        Some(node::Node::CreateDomainStmt(n)) => v.visit_create_domain_stmt_mut(n),
+        Some(node::Node::CreateOpClassStmt(n)) => v.visit_create_op_class_stmt_mut(n),
+        Some(node::Node::CreateOpClassItem(n)) => v.visit_create_op_class_item_mut(n),
+        Some(node::Node::CreateOpFamilyStmt(n)) => v.visit_create_op_family_stmt_mut(n),
+        Some(node::Node::AlterOpFamilyStmt(n)) => v.visit_alter_op_family_stmt_mut(n),
+        Some(node::Node::DropStmt(n)) => v.visit_drop_stmt_mut(n),
+        Some(node::Node::TruncateStmt(n)) => v.visit_truncate_stmt_mut(n),
+        Some(node::Node::CommentStmt(n)) => v.visit_comment_stmt_mut(n),
+        Some(node::Node::SecLabelStmt(n)) => v.visit_sec_label_stmt_mut(n),
+        Some(node::Node::DeclareCursorStmt(n)) => v.visit_declare_cursor_stmt_mut(n),
+        Some(node::Node::ClosePortalStmt(n)) => v.visit_close_portal_stmt_mut(n),
+        Some(node::Node::FetchStmt(n)) => v.visit_fetch_stmt_mut(n),
+        Some(node::Node::IndexStmt(n)) => v.visit_index_stmt_mut(n),
+        Some(node::Node::CreateStatsStmt(n)) => v.visit_create_stats_stmt_mut(n),
+        Some(node::Node::StatsElem(n)) => v.visit_stats_elem_mut(n),
+        Some(node::Node::AlterStatsStmt(n)) => v.visit_alter_stats_stmt_mut(n),
+        Some(node::Node::CreateFunctionStmt(n)) => v.visit_create_function_stmt_mut(n),
+        Some(node::Node::FunctionParameter(n)) => v.visit_function_parameter_mut(n),
+        Some(node::Node::AlterFunctionStmt(n)) => v.visit_alter_function_stmt_mut(n),
+        Some(node::Node::DoStmt(n)) => v.visit_do_stmt_mut(n),
+        Some(node::Node::InlineCodeBlock(n)) => v.visit_inline_code_block_mut(n),
+        Some(node::Node::CallStmt(n)) => v.visit_call_stmt_mut(n),
+        Some(node::Node::CallContext(n)) => v.visit_call_context_mut(n),
+        Some(node::Node::RenameStmt(n)) => v.visit_rename_stmt_mut(n),
+        Some(node::Node::AlterObjectDependsStmt(n)) => v.visit_alter_object_depends_stmt_mut(n),
+        Some(node::Node::AlterObjectSchemaStmt(n)) => v.visit_alter_object_schema_stmt_mut(n),
+        Some(node::Node::AlterOwnerStmt(n)) => v.visit_alter_owner_stmt_mut(n),
+        Some(node::Node::AlterOperatorStmt(n)) => v.visit_alter_operator_stmt_mut(n),
+        Some(node::Node::AlterTypeStmt(n)) => v.visit_alter_type_stmt_mut(n),
+        Some(node::Node::RuleStmt(n)) => v.visit_rule_stmt_mut(n),
+        Some(node::Node::NotifyStmt(n)) => v.visit_notify_stmt_mut(n),
+        Some(node::Node::ListenStmt(n)) => v.visit_listen_stmt_mut(n),
+        Some(node::Node::UnlistenStmt(n)) => v.visit_unlisten_stmt_mut(n),
+        Some(node::Node::TransactionStmt(n)) => v.visit_transaction_stmt_mut(n),
+        Some(node::Node::CompositeTypeStmt(n)) => v.visit_composite_type_stmt_mut(n),
+        Some(node::Node::CreateEnumStmt(n)) => v.visit_create_enum_stmt_mut(n),
+        Some(node::Node::CreateRangeStmt(n)) => v.visit_create_range_stmt_mut(n),
+        Some(node::Node::AlterEnumStmt(n)) => v.visit_alter_enum_stmt_mut(n),
+        Some(node::Node::ViewStmt(n)) => v.visit_view_stmt_mut(n),
+        Some(node::Node::LoadStmt(n)) => v.visit_load_stmt_mut(n),
+        Some(node::Node::CreatedbStmt(n)) => v.visit_createdb_stmt_mut(n),
+        Some(node::Node::AlterDatabaseStmt(n)) => v.visit_alter_database_stmt_mut(n),
+        Some(node::Node::AlterDatabaseRefreshCollStmt(n)) => v.visit_alter_database_refresh_coll_stmt_mut(n),
+        Some(node::Node::AlterDatabaseSetStmt(n)) => v.visit_alter_database_set_stmt_mut(n),
+        Some(node::Node::DropdbStmt(n)) => v.visit_dropdb_stmt_mut(n),
+        Some(node::Node::AlterSystemStmt(n)) => v.visit_alter_system_stmt_mut(n),
+        Some(node::Node::ClusterStmt(n)) => v.visit_cluster_stmt_mut(n),
+        Some(node::Node::VacuumStmt(n)) => v.visit_vacuum_stmt_mut(n),
+        Some(node::Node::VacuumRelation(n)) => v.visit_vacuum_relation_mut(n),
+        Some(node::Node::ExplainStmt(n)) => v.visit_explain_stmt_mut(n),
+        Some(node::Node::CreateTableAsStmt(n)) => v.visit_create_table_as_stmt_mut(n),
+        Some(node::Node::RefreshMatViewStmt(n)) => v.visit_refresh_mat_view_stmt_mut(n),
+        Some(node::Node::CheckPointStmt(n)) => v.visit_check_point_stmt_mut(n),
+        Some(node::Node::DiscardStmt(n)) => v.visit_discard_stmt_mut(n),
+        Some(node::Node::LockStmt(n)) => v.visit_lock_stmt_mut(n),
+        Some(node::Node::ConstraintsSetStmt(n)) => v.visit_constraints_set_stmt_mut(n),
+        Some(node::Node::ReindexStmt(n)) => v.visit_reindex_stmt_mut(n),
+        Some(node::Node::CreateConversionStmt(n)) => v.visit_create_conversion_stmt_mut(n),
+        Some(node::Node::CreateCastStmt(n)) => v.visit_create_cast_stmt_mut(n),
+        Some(node::Node::CreateTransformStmt(n)) => v.visit_create_transform_stmt_mut(n),
+        Some(node::Node::PrepareStmt(n)) => v.visit_prepare_stmt_mut(n),
+        Some(node::Node::ExecuteStmt(n)) => v.visit_execute_stmt_mut(n),
+        Some(node::Node::DeallocateStmt(n)) => v.visit_deallocate_stmt_mut(n),
+        Some(node::Node::DropOwnedStmt(n)) => v.visit_drop_owned_stmt_mut(n),
+        Some(node::Node::ReassignOwnedStmt(n)) => v.visit_reassign_owned_stmt_mut(n),
+        Some(node::Node::AlterTsdictionaryStmt(n)) => v.visit_alter_ts_dictionary_stmt_mut(n),
+        Some(node::Node::AlterTsconfigurationStmt(n)) => v.visit_alter_ts_configuration_stmt_mut(n),
+        Some(node::Node::PublicationTable(n)) => v.visit_publication_table_mut(n),
+        Some(node::Node::PublicationObjSpec(n)) => v.visit_publication_obj_spec_mut(n),
+        Some(node::Node::CreatePublicationStmt(n)) => v.visit_create_publication_stmt_mut(n),
+        Some(node::Node::AlterPublicationStmt(n)) => v.visit_alter_publication_stmt_mut(n),
+        Some(node::Node::CreateSubscriptionStmt(n)) => v.visit_create_subscription_stmt_mut(n),
+        Some(node::Node::AlterSubscriptionStmt(n)) => v.visit_alter_subscription_stmt_mut(n),
+        Some(node::Node::DropSubscriptionStmt(n)) => v.visit_drop_subscription_stmt_mut(n),
+        Some(node::Node::Integer(n)) => v.visit_integer_mut(n),
+        Some(node::Node::Float(n)) => v.visit_float_mut(n),
+        Some(node::Node::Boolean(n)) => v.visit_boolean_mut(n),
+        Some(node::Node::String(n)) => v.visit_string_mut(n),
+        Some(node::Node::BitString(n)) => v.visit_bit_string_mut(n),
+        Some(node::Node::List(n)) => v.visit_list_mut(n),
+        Some(node::Node::IntList(n)) => v.visit_int_list_mut(n),
+        Some(node::Node::OidList(n)) => v.visit_oid_list_mut(n),
+        Some(node::Node::AConst(n)) => v.visit_a_const_mut(n),
+        None => {}
+    }
+}
+
+pub fn walk_integer_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::Integer) {}
+
+pub fn walk_float_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::Float) {}
+
+pub fn walk_boolean_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::Boolean) {}
+
+pub fn walk_string_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::String) {}
+
+pub fn walk_bit_string_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::BitString) {}
+
+pub fn walk_list_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::List) {
+    for n in &mut node.items {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_oid_list_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::OidList) {
+    for n in &mut node.items {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_int_list_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::IntList) {
+    for n in &mut node.items {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_a_const_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AConst) {
+    match &mut node.val {
+        Some(a_const::Val::Ival(n)) => v.visit_integer_mut(n),
+        Some(a_const::Val::Fval(n)) => v.visit_float_mut(n),
+        Some(a_const::Val::Boolval(n)) => v.visit_boolean_mut(n),
+        Some(a_const::Val::Sval(n)) => v.visit_string_mut(n),
+        Some(a_const::Val::Bsval(n)) => v.visit_bit_string_mut(n),
+        None => {}
+    }
+}
+
+pub fn walk_alias_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::Alias) {
+    for n in &mut node.colnames {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_range_var_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RangeVar) {
+    if let Some(n) = &mut node.alias {
+        v.visit_alias_mut(n);
+    }
+}
+
+pub fn walk_table_func_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::TableFunc) {
+    for n in &mut node.ns_uris {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.ns_names {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.docexpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.rowexpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.colnames {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.coltypes {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.coltypmods {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.colcollations {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.colexprs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.coldefexprs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.colvalexprs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.passingvalexprs {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.plan {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_into_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::IntoClause) {
+    if let Some(n) = &mut node.rel {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.col_names {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.view_query {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_var_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::Var) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_param_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::Param) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_aggref_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::Aggref) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.aggargtypes {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.aggdirectargs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.aggorder {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.aggdistinct {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.aggfilter {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_grouping_func_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::GroupingFunc) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.refs {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_window_func_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::WindowFunc) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.aggfilter {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.run_condition {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_window_func_run_condition_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::WindowFuncRunCondition) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_merge_support_func_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::MergeSupportFunc) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_subscripting_ref_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SubscriptingRef) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.refupperindexpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.reflowerindexpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.refexpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.refassgnexpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_func_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::FuncExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_named_arg_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::NamedArgExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_op_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::OpExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_distinct_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DistinctExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_null_if_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::NullIfExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_scalar_array_op_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ScalarArrayOpExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_bool_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::BoolExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_sub_link_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SubLink) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.testexpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.oper_name {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.subselect {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_sub_plan_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SubPlan) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.testexpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.param_ids {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.set_param {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.par_param {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alternative_sub_plan_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlternativeSubPlan) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.subplans {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_field_select_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::FieldSelect) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_field_store_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::FieldStore) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.newvals {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.fieldnums {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_relabel_type_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RelabelType) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_coerce_via_io_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CoerceViaIo) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_array_coerce_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ArrayCoerceExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.elemexpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_convert_rowtype_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ConvertRowtypeExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_collate_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CollateExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_case_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CaseExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.defresult {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_case_when_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CaseWhen) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.result {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_case_test_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CaseTestExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_array_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ArrayExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.elements {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_row_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RowExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.colnames {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_row_compare_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RowCompareExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.opnos {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.opfamilies {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.inputcollids {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.largs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.rargs {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_coalesce_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CoalesceExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_min_max_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::MinMaxExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_sql_value_function_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SqlValueFunction) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_xml_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::XmlExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.named_args {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.arg_names {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_json_format_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::JsonFormat) {}
+
+pub fn walk_json_returning_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonReturning) {
+    if let Some(n) = &mut node.format {
+        v.visit_json_format_mut(n);
+    }
+}
+
+pub fn walk_json_value_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonValueExpr) {
+    if let Some(n) = &mut node.raw_expr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.formatted_expr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.format {
+        v.visit_json_format_mut(n);
+    }
+}
+
+pub fn walk_json_constructor_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonConstructorExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.func {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.coercion {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.returning {
+        v.visit_json_returning_mut(n);
+    }
+}
+
+pub fn walk_json_is_predicate_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonIsPredicate) {
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.format {
+        v.visit_json_format_mut(n);
+    }
+}
+
+pub fn walk_json_behavior_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonBehavior) {
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_json_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.formatted_expr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.format {
+        v.visit_json_format_mut(n);
+    }
+    if let Some(n) = &mut node.path_spec {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.returning {
+        v.visit_json_returning_mut(n);
+    }
+    for n in &mut node.passing_names {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.passing_values {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.on_empty {
+        v.visit_json_behavior_mut(n);
+    }
+    if let Some(n) = &mut node.on_error {
+        v.visit_json_behavior_mut(n);
+    }
+}
+
+pub fn walk_json_table_path_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::JsonTablePath) {}
+
+pub fn walk_json_table_path_scan_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonTablePathScan) {
+    if let Some(n) = &mut node.plan {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.path {
+        v.visit_json_table_path_mut(n);
+    }
+    if let Some(n) = &mut node.child {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_json_table_sibling_join_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonTableSiblingJoin) {
+    if let Some(n) = &mut node.plan {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.lplan {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.rplan {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_null_test_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::NullTest) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_boolean_test_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::BooleanTest) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_merge_action_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::MergeAction) {
+    if let Some(n) = &mut node.qual {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.target_list {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.update_colnos {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_coerce_to_domain_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CoerceToDomain) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_coerce_to_domain_value_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CoerceToDomainValue) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_set_to_default_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SetToDefault) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_current_of_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CurrentOfExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_next_value_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::NextValueExpr) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_inference_elem_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::InferenceElem) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_target_entry_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::TargetEntry) {
+    if let Some(n) = &mut node.xpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_range_tbl_ref_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::RangeTblRef) {}
+
+pub fn walk_join_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JoinExpr) {
+    if let Some(n) = &mut node.larg {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.rarg {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.using_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.join_using_alias {
+        v.visit_alias_mut(n);
+    }
+    if let Some(n) = &mut node.quals {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.alias {
+        v.visit_alias_mut(n);
+    }
+}
+
+pub fn walk_from_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::FromExpr) {
+    for n in &mut node.fromlist {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.quals {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_on_conflict_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::OnConflictExpr) {
+    for n in &mut node.arbiter_elems {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.arbiter_where {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.on_conflict_set {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.on_conflict_where {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.excl_rel_tlist {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_query_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::Query) {
+    if let Some(n) = &mut node.utility_stmt {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.cte_list {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.rtable {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.rteperminfos {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.jointree {
+        v.visit_from_expr_mut(n);
+    }
+    for n in &mut node.merge_action_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.merge_join_condition {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.target_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.on_conflict {
+        v.visit_on_conflict_expr_mut(n);
+    }
+    for n in &mut node.returning_list {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.group_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.grouping_sets {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.having_qual {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.window_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.distinct_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.sort_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.limit_offset {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.limit_count {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.row_marks {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.set_operations {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.constraint_deps {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.with_check_options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_type_name_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::TypeName) {
+    for n in &mut node.names {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.typmods {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.array_bounds {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_column_ref_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ColumnRef) {
+    for n in &mut node.fields {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_param_ref_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::ParamRef) {}
+
+pub fn walk_a_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AExpr) {
+    for n in &mut node.name {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.lexpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.rexpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_type_cast_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::TypeCast) {
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.type_name {
+        v.visit_type_name_mut(n);
+    }
+}
+
+pub fn walk_collate_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CollateClause) {
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.collname {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_role_spec_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::RoleSpec) {}
+
+pub fn walk_func_call_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::FuncCall) {
+    for n in &mut node.funcname {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.agg_order {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.agg_filter {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.over {
+        v.visit_window_def_mut(n);
+    }
+}
+
+pub fn walk_a_star_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::AStar) {}
+
+pub fn walk_a_indices_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AIndices) {
+    if let Some(n) = &mut node.lidx {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.uidx {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_a_indirection_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AIndirection) {
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.indirection {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_a_array_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AArrayExpr) {
+    for n in &mut node.elements {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_res_target_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ResTarget) {
+    for n in &mut node.indirection {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.val {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_multi_assign_ref_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::MultiAssignRef) {
+    if let Some(n) = &mut node.source {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_sort_by_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SortBy) {
+    if let Some(n) = &mut node.node {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.use_op {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_window_def_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::WindowDef) {
+    for n in &mut node.partition_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.order_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.start_offset {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.end_offset {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_range_subselect_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RangeSubselect) {
+    if let Some(n) = &mut node.subquery {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.alias {
+        v.visit_alias_mut(n);
+    }
+}
+
+pub fn walk_range_function_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RangeFunction) {
+    for n in &mut node.functions {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.alias {
+        v.visit_alias_mut(n);
+    }
+    for n in &mut node.coldeflist {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_range_table_func_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RangeTableFunc) {
+    if let Some(n) = &mut node.docexpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.rowexpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.namespaces {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.columns {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.alias {
+        v.visit_alias_mut(n);
+    }
+}
+
+pub fn walk_range_table_func_col_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RangeTableFuncCol) {
+    if let Some(n) = &mut node.type_name {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.colexpr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.coldefexpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_range_table_sample_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RangeTableSample) {
+    if let Some(n) = &mut node.relation {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.method {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.repeatable {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_column_def_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ColumnDef) {
+    if let Some(n) = &mut node.type_name {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.raw_default {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.cooked_default {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.identity_sequence {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.coll_clause {
+        v.visit_collate_clause_mut(n);
+    }
+    for n in &mut node.constraints {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.fdwoptions {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_table_like_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::TableLikeClause) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+}
+
+pub fn walk_index_elem_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::IndexElem) {
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.collation {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.opclass {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.opclassopts {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_def_elem_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DefElem) {
+    if let Some(n) = &mut node.arg {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_locking_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::LockingClause) {
+    for n in &mut node.locked_rels {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_xml_serialize_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::XmlSerialize) {
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.type_name {
+        v.visit_type_name_mut(n);
+    }
+}
+
+pub fn walk_partition_elem_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PartitionElem) {
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.collation {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.opclass {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_partition_spec_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PartitionSpec) {
+    for n in &mut node.part_params {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_partition_bound_spec_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PartitionBoundSpec) {
+    for n in &mut node.listdatums {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.lowerdatums {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.upperdatums {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_partition_range_datum_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PartitionRangeDatum) {
+    if let Some(n) = &mut node.value {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_single_partition_spec_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::SinglePartitionSpec) {}
+
+pub fn walk_partition_cmd_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PartitionCmd) {
+    if let Some(n) = &mut node.name {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.bound {
+        v.visit_partition_bound_spec_mut(n);
+    }
+}
+
+pub fn walk_range_tbl_entry_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RangeTblEntry) {
+    if let Some(n) = &mut node.alias {
+        v.visit_alias_mut(n);
+    }
+    if let Some(n) = &mut node.eref {
+        v.visit_alias_mut(n);
+    }
+    if let Some(n) = &mut node.tablesample {
+        v.visit_table_sample_clause_mut(n);
+    }
+    if let Some(n) = &mut node.subquery {
+        v.visit_query_mut(n);
+    }
+    for n in &mut node.joinaliasvars {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.joinleftcols {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.joinrightcols {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.join_using_alias {
+        v.visit_alias_mut(n);
+    }
+    for n in &mut node.functions {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.tablefunc {
+        v.visit_table_func_mut(n);
+    }
+    for n in &mut node.values_lists {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.coltypes {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.coltypmods {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.colcollations {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.security_quals {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_rte_permission_info_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::RtePermissionInfo) {}
+
+pub fn walk_range_tbl_function_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RangeTblFunction) {
+    if let Some(n) = &mut node.funcexpr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.funccolnames {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.funccoltypes {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.funccoltypmods {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.funccolcollations {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_table_sample_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::TableSampleClause) {
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.repeatable {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_with_check_option_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::WithCheckOption) {
+    if let Some(n) = &mut node.qual {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_sort_group_clause_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::SortGroupClause) {}
+
+pub fn walk_grouping_set_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::GroupingSet) {
+    for n in &mut node.content {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_window_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::WindowClause) {
+    for n in &mut node.partition_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.order_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.start_offset {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.end_offset {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_row_mark_clause_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::RowMarkClause) {}
+
+pub fn walk_with_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::WithClause) {
+    for n in &mut node.ctes {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_infer_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::InferClause) {
+    for n in &mut node.index_elems {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_on_conflict_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::OnConflictClause) {
+    if let Some(n) = &mut node.infer {
+        v.visit_infer_clause_mut(n);
+    }
+    for n in &mut node.target_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_cte_search_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CteSearchClause) {
+    for n in &mut node.search_col_list {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_cte_cycle_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CteCycleClause) {
+    for n in &mut node.cycle_col_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.cycle_mark_value {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.cycle_mark_default {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_common_table_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CommonTableExpr) {
+    for n in &mut node.aliascolnames {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.ctequery {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.search_clause {
+        v.visit_cte_search_clause_mut(n);
+    }
+    if let Some(n) = &mut node.cycle_clause {
+        v.visit_cte_cycle_clause_mut(n);
+    }
+    for n in &mut node.ctecolnames {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.ctecoltypes {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.ctecoltypmods {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.ctecolcollations {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_merge_when_clause_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::MergeWhenClause) {
+    if let Some(n) = &mut node.condition {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.target_list {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.values {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_trigger_transition_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::TriggerTransition) {}
+
+pub fn walk_json_output_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonOutput) {
+    if let Some(n) = &mut node.type_name {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.returning {
+        v.visit_json_returning_mut(n);
+    }
+}
+
+pub fn walk_json_argument_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonArgument) {
+    if let Some(n) = &mut node.val {
+        v.visit_json_value_expr_mut(n);
+    }
+}
+
+pub fn walk_json_func_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonFuncExpr) {
+    if let Some(n) = &mut node.context_item {
+        v.visit_json_value_expr_mut(n);
+    }
+    if let Some(n) = &mut node.pathspec {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.passing {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.output {
+        v.visit_json_output_mut(n);
+    }
+    if let Some(n) = &mut node.on_empty {
+        v.visit_json_behavior_mut(n);
+    }
+    if let Some(n) = &mut node.on_error {
+        v.visit_json_behavior_mut(n);
+    }
+}
+
+pub fn walk_json_table_path_spec_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonTablePathSpec) {
+    if let Some(n) = &mut node.string {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_json_table_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonTable) {
+    if let Some(n) = &mut node.context_item {
+        v.visit_json_value_expr_mut(n);
+    }
+    if let Some(n) = &mut node.pathspec {
+        v.visit_json_table_path_spec_mut(n);
+    }
+    for n in &mut node.passing {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.columns {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.on_error {
+        v.visit_json_behavior_mut(n);
+    }
+    if let Some(n) = &mut node.alias {
+        v.visit_alias_mut(n);
+    }
+}
+
+pub fn walk_json_table_column_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonTableColumn) {
+    if let Some(n) = &mut node.type_name {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.pathspec {
+        v.visit_json_table_path_spec_mut(n);
+    }
+    if let Some(n) = &mut node.format {
+        v.visit_json_format_mut(n);
+    }
+    for n in &mut node.columns {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.on_empty {
+        v.visit_json_behavior_mut(n);
+    }
+    if let Some(n) = &mut node.on_error {
+        v.visit_json_behavior_mut(n);
+    }
+}
+
+pub fn walk_json_key_value_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonKeyValue) {
+    if let Some(n) = &mut node.key {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.value {
+        v.visit_json_value_expr_mut(n);
+    }
+}
+
+pub fn walk_json_parse_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonParseExpr) {
+    if let Some(n) = &mut node.expr {
+        v.visit_json_value_expr_mut(n);
+    }
+    if let Some(n) = &mut node.output {
+        v.visit_json_output_mut(n);
+    }
+}
+
+pub fn walk_json_scalar_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonScalarExpr) {
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.output {
+        v.visit_json_output_mut(n);
+    }
+}
+
+pub fn walk_json_serialize_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonSerializeExpr) {
+    if let Some(n) = &mut node.expr {
+        v.visit_json_value_expr_mut(n);
+    }
+    if let Some(n) = &mut node.output {
+        v.visit_json_output_mut(n);
+    }
+}
+
+pub fn walk_json_object_constructor_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonObjectConstructor) {
+    for n in &mut node.exprs {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.output {
+        v.visit_json_output_mut(n);
+    }
+}
+
+pub fn walk_json_array_constructor_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonArrayConstructor) {
+    for n in &mut node.exprs {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.output {
+        v.visit_json_output_mut(n);
+    }
+}
+
+pub fn walk_json_array_query_constructor_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonArrayQueryConstructor) {
+    if let Some(n) = &mut node.query {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.output {
+        v.visit_json_output_mut(n);
+    }
+    if let Some(n) = &mut node.format {
+        v.visit_json_format_mut(n);
+    }
+}
+
+pub fn walk_json_agg_constructor_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonAggConstructor) {
+    if let Some(n) = &mut node.output {
+        v.visit_json_output_mut(n);
+    }
+    if let Some(n) = &mut node.agg_filter {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.agg_order {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.over {
+        v.visit_window_def_mut(n);
+    }
+}
+
+pub fn walk_json_object_agg_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonObjectAgg) {
+    if let Some(n) = &mut node.constructor {
+        v.visit_json_agg_constructor_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_json_key_value_mut(n);
+    }
+}
+
+pub fn walk_json_array_agg_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::JsonArrayAgg) {
+    if let Some(n) = &mut node.constructor {
+        v.visit_json_agg_constructor_mut(n);
+    }
+    if let Some(n) = &mut node.arg {
+        v.visit_json_value_expr_mut(n);
+    }
+}
+
+pub fn walk_raw_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RawStmt) {
+    if let Some(n) = &mut node.stmt {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_insert_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::InsertStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.cols {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.select_stmt {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.on_conflict_clause {
+        v.visit_on_conflict_clause_mut(n);
+    }
+    for n in &mut node.returning_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.with_clause {
+        v.visit_with_clause_mut(n);
+    }
+}
+
+pub fn walk_delete_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DeleteStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.using_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.returning_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.with_clause {
+        v.visit_with_clause_mut(n);
+    }
+}
+
+pub fn walk_update_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::UpdateStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.target_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.from_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.returning_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.with_clause {
+        v.visit_with_clause_mut(n);
+    }
+}
+
+pub fn walk_merge_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::MergeStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.source_relation {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.join_condition {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.merge_when_clauses {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.returning_list {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.with_clause {
+        v.visit_with_clause_mut(n);
+    }
+}
+
+pub fn walk_select_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SelectStmt) {
+    for n in &mut node.distinct_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.into_clause {
+        v.visit_into_clause_mut(n);
+    }
+    for n in &mut node.target_list {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.from_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.group_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.having_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.window_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.values_lists {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.sort_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.limit_offset {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.limit_count {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.locking_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.with_clause {
+        v.visit_with_clause_mut(n);
+    }
+    if let Some(n) = &mut node.larg {
+        v.visit_select_stmt_mut(n);
+    }
+    if let Some(n) = &mut node.rarg {
+        v.visit_select_stmt_mut(n);
+    }
+}
+
+pub fn walk_set_operation_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SetOperationStmt) {
+    if let Some(n) = &mut node.larg {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.rarg {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.col_types {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.col_typmods {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.col_collations {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.group_clauses {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_return_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ReturnStmt) {
+    if let Some(n) = &mut node.returnval {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_pl_assign_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PlAssignStmt) {
+    for n in &mut node.indirection {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.val {
+        v.visit_select_stmt_mut(n);
+    }
+}
+
+pub fn walk_create_schema_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateSchemaStmt) {
+    if let Some(n) = &mut node.authrole {
+        v.visit_role_spec_mut(n);
+    }
+    for n in &mut node.schema_elts {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_table_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterTableStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.cmds {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_replica_identity_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::ReplicaIdentityStmt) {}
+
+pub fn walk_alter_table_cmd_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterTableCmd) {
+    if let Some(n) = &mut node.newowner {
+        v.visit_role_spec_mut(n);
+    }
+    if let Some(n) = &mut node.def {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_collation_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterCollationStmt) {
+    for n in &mut node.collname {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_domain_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterDomainStmt) {
+    for n in &mut node.type_name {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.def {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_grant_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::GrantStmt) {
+    for n in &mut node.objects {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.privileges {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.grantees {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.grantor {
+        v.visit_role_spec_mut(n);
+    }
+}
+
+pub fn walk_object_with_args_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ObjectWithArgs) {
+    for n in &mut node.objname {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.objargs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.objfuncargs {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_access_priv_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AccessPriv) {
+    for n in &mut node.cols {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_grant_role_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::GrantRoleStmt) {
+    for n in &mut node.granted_roles {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.grantee_roles {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.opt {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.grantor {
+        v.visit_role_spec_mut(n);
+    }
+}
+
+pub fn walk_alter_default_privileges_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterDefaultPrivilegesStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.action {
+        v.visit_grant_stmt_mut(n);
+    }
+}
+
+pub fn walk_copy_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CopyStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.query {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.attlist {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_variable_set_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::VariableSetStmt) {
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_variable_show_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::VariableShowStmt) {}
+
+pub fn walk_create_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.table_elts {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.inh_relations {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.partbound {
+        v.visit_partition_bound_spec_mut(n);
+    }
+    if let Some(n) = &mut node.partspec {
+        v.visit_partition_spec_mut(n);
+    }
+    if let Some(n) = &mut node.of_typename {
+        v.visit_type_name_mut(n);
+    }
+    for n in &mut node.constraints {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_constraint_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::Constraint) {
+    if let Some(n) = &mut node.raw_expr {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.keys {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.including {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.exclusions {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.pktable {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.fk_attrs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.pk_attrs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.fk_del_set_cols {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.old_conpfeqop {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_table_space_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateTableSpaceStmt) {
+    if let Some(n) = &mut node.owner {
+        v.visit_role_spec_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_drop_table_space_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::DropTableSpaceStmt) {}
+
+pub fn walk_alter_table_space_options_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterTableSpaceOptionsStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_table_move_all_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterTableMoveAllStmt) {
+    for n in &mut node.roles {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_extension_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateExtensionStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_extension_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterExtensionStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_extension_contents_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterExtensionContentsStmt) {
+    if let Some(n) = &mut node.object {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_fdw_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateFdwStmt) {
+    for n in &mut node.func_options {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_fdw_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterFdwStmt) {
+    for n in &mut node.func_options {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_foreign_server_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateForeignServerStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_foreign_server_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterForeignServerStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_foreign_table_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateForeignTableStmt) {
+    if let Some(n) = &mut node.base_stmt {
+        v.visit_create_stmt_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_user_mapping_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateUserMappingStmt) {
+    if let Some(n) = &mut node.user {
+        v.visit_role_spec_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_user_mapping_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterUserMappingStmt) {
+    if let Some(n) = &mut node.user {
+        v.visit_role_spec_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_drop_user_mapping_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DropUserMappingStmt) {
+    if let Some(n) = &mut node.user {
+        v.visit_role_spec_mut(n);
+    }
+}
+
+pub fn walk_import_foreign_schema_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ImportForeignSchemaStmt) {
+    for n in &mut node.table_list {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_policy_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreatePolicyStmt) {
+    if let Some(n) = &mut node.table {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.roles {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.qual {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.with_check {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_policy_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterPolicyStmt) {
+    if let Some(n) = &mut node.table {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.roles {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.qual {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.with_check {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_am_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateAmStmt) {
+    for n in &mut node.handler_name {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_trig_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateTrigStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.funcname {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.columns {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.when_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.transition_rels {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.constrrel {
+        v.visit_range_var_mut(n);
+    }
+}
+
+pub fn walk_create_event_trig_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateEventTrigStmt) {
+    for n in &mut node.whenclause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.funcname {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_event_trig_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::AlterEventTrigStmt) {}
+
+pub fn walk_create_p_lang_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreatePLangStmt) {
+    for n in &mut node.plhandler {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.plinline {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.plvalidator {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_role_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateRoleStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_role_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterRoleStmt) {
+    if let Some(n) = &mut node.role {
+        v.visit_role_spec_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_role_set_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterRoleSetStmt) {
+    if let Some(n) = &mut node.role {
+        v.visit_role_spec_mut(n);
+    }
+    if let Some(n) = &mut node.setstmt {
+        v.visit_variable_set_stmt_mut(n);
+    }
+}
+
+pub fn walk_drop_role_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DropRoleStmt) {
+    for n in &mut node.roles {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_seq_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateSeqStmt) {
+    if let Some(n) = &mut node.sequence {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_seq_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterSeqStmt) {
+    if let Some(n) = &mut node.sequence {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_define_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DefineStmt) {
+    for n in &mut node.defnames {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.definition {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_domain_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateDomainStmt) {
+    for n in &mut node.domainname {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.type_name {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.coll_clause {
+        v.visit_collate_clause_mut(n);
+    }
+    for n in &mut node.constraints {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_op_class_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateOpClassStmt) {
+    for n in &mut node.opclassname {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.opfamilyname {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.datatype {
+        v.visit_type_name_mut(n);
+    }
+    for n in &mut node.items {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_op_class_item_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateOpClassItem) {
+    if let Some(n) = &mut node.name {
+        v.visit_object_with_args_mut(n);
+    }
+    for n in &mut node.order_family {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.class_args {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.storedtype {
+        v.visit_type_name_mut(n);
+    }
+}
+
+pub fn walk_create_op_family_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateOpFamilyStmt) {
+    for n in &mut node.opfamilyname {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_op_family_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterOpFamilyStmt) {
+    for n in &mut node.opfamilyname {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.items {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_drop_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DropStmt) {
+    for n in &mut node.objects {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_truncate_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::TruncateStmt) {
+    for n in &mut node.relations {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_comment_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CommentStmt) {
+    if let Some(n) = &mut node.object {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_sec_label_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::SecLabelStmt) {
+    if let Some(n) = &mut node.object {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_declare_cursor_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DeclareCursorStmt) {
+    if let Some(n) = &mut node.query {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_close_portal_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::ClosePortalStmt) {}
+
+pub fn walk_fetch_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::FetchStmt) {}
+
+pub fn walk_index_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::IndexStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.index_params {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.index_including_params {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.exclude_op_names {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_stats_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateStatsStmt) {
+    for n in &mut node.defnames {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.stat_types {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.exprs {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.relations {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_stats_elem_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::StatsElem) {
+    if let Some(n) = &mut node.expr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_stats_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterStatsStmt) {
+    for n in &mut node.defnames {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.stxstattarget {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_function_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateFunctionStmt) {
+    for n in &mut node.funcname {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.parameters {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.return_type {
+        v.visit_type_name_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.sql_body {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_function_parameter_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::FunctionParameter) {
+    if let Some(n) = &mut node.arg_type {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.defexpr {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_function_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterFunctionStmt) {
+    if let Some(n) = &mut node.func {
+        v.visit_object_with_args_mut(n);
+    }
+    for n in &mut node.actions {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_do_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DoStmt) {
+    for n in &mut node.args {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_inline_code_block_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::InlineCodeBlock) {}
+
+pub fn walk_call_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CallStmt) {
+    if let Some(n) = &mut node.funccall {
+        v.visit_func_call_mut(n);
+    }
+    if let Some(n) = &mut node.funcexpr {
+        v.visit_func_expr_mut(n);
+    }
+    for n in &mut node.outargs {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_call_context_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::CallContext) {}
+
+pub fn walk_rename_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RenameStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.object {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_object_depends_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterObjectDependsStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.object {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.extname {
+        v.visit_string_mut(n);
+    }
+}
+
+pub fn walk_alter_object_schema_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterObjectSchemaStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.object {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_owner_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterOwnerStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.object {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.newowner {
+        v.visit_role_spec_mut(n);
+    }
+}
+
+pub fn walk_alter_operator_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterOperatorStmt) {
+    if let Some(n) = &mut node.opername {
+        v.visit_object_with_args_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_type_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterTypeStmt) {
+    for n in &mut node.type_name {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_rule_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RuleStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.actions {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_notify_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::NotifyStmt) {}
+
+pub fn walk_listen_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::ListenStmt) {}
+
+pub fn walk_unlisten_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::UnlistenStmt) {}
+
+pub fn walk_transaction_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::TransactionStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_composite_type_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CompositeTypeStmt) {
+    if let Some(n) = &mut node.typevar {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.coldeflist {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_enum_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateEnumStmt) {
+    for n in &mut node.type_name {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.vals {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_range_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateRangeStmt) {
+    for n in &mut node.type_name {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.params {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_enum_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterEnumStmt) {
+    for n in &mut node.type_name {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_view_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ViewStmt) {
+    if let Some(n) = &mut node.view {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.aliases {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.query {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_load_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::LoadStmt) {}
+
+pub fn walk_createdb_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreatedbStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_database_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterDatabaseStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_database_refresh_coll_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::AlterDatabaseRefreshCollStmt) {}
+
+pub fn walk_alter_database_set_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterDatabaseSetStmt) {
+    if let Some(n) = &mut node.setstmt {
+        v.visit_variable_set_stmt_mut(n);
+    }
+}
+
+pub fn walk_dropdb_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DropdbStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_system_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterSystemStmt) {
+    if let Some(n) = &mut node.setstmt {
+        v.visit_variable_set_stmt_mut(n);
+    }
+}
+
+pub fn walk_cluster_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ClusterStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.params {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_vacuum_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::VacuumStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.rels {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_vacuum_relation_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::VacuumRelation) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.va_cols {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_explain_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ExplainStmt) {
+    if let Some(n) = &mut node.query {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_table_as_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateTableAsStmt) {
+    if let Some(n) = &mut node.query {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.into {
+        v.visit_into_clause_mut(n);
+    }
+}
+
+pub fn walk_refresh_mat_view_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::RefreshMatViewStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+}
+
+pub fn walk_check_point_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::CheckPointStmt) {}
+
+pub fn walk_discard_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::DiscardStmt) {}
+
+pub fn walk_lock_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::LockStmt) {
+    for n in &mut node.relations {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_constraints_set_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ConstraintsSetStmt) {
+    for n in &mut node.constraints {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_reindex_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ReindexStmt) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    for n in &mut node.params {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_conversion_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateConversionStmt) {
+    for n in &mut node.conversion_name {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.func_name {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_cast_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateCastStmt) {
+    if let Some(n) = &mut node.sourcetype {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.targettype {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.func {
+        v.visit_object_with_args_mut(n);
+    }
+}
+
+pub fn walk_create_transform_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateTransformStmt) {
+    if let Some(n) = &mut node.type_name {
+        v.visit_type_name_mut(n);
+    }
+    if let Some(n) = &mut node.fromsql {
+        v.visit_object_with_args_mut(n);
+    }
+    if let Some(n) = &mut node.tosql {
+        v.visit_object_with_args_mut(n);
+    }
+}
+
+pub fn walk_prepare_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PrepareStmt) {
+    for n in &mut node.argtypes {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.query {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_execute_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ExecuteStmt) {
+    for n in &mut node.params {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_deallocate_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::DeallocateStmt) {}
+
+pub fn walk_drop_owned_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::DropOwnedStmt) {
+    for n in &mut node.roles {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_reassign_owned_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::ReassignOwnedStmt) {
+    for n in &mut node.roles {
+        v.visit_node_mut(n);
+    }
+    if let Some(n) = &mut node.newrole {
+        v.visit_role_spec_mut(n);
+    }
+}
+
+pub fn walk_alter_ts_dictionary_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterTsDictionaryStmt) {
+    for n in &mut node.dictname {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_ts_configuration_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterTsConfigurationStmt) {
+    for n in &mut node.cfgname {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.tokentype {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.dicts {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_publication_table_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PublicationTable) {
+    if let Some(n) = &mut node.relation {
+        v.visit_range_var_mut(n);
+    }
+    if let Some(n) = &mut node.where_clause {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.columns {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_publication_obj_spec_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::PublicationObjSpec) {
+    if let Some(n) = &mut node.pubtable {
+        v.visit_publication_table_mut(n);
+    }
+}
+
+pub fn walk_create_publication_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreatePublicationStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.pubobjects {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_publication_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterPublicationStmt) {
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.pubobjects {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_create_subscription_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::CreateSubscriptionStmt) {
+    for n in &mut node.publication {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_alter_subscription_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, node: &mut protobuf::AlterSubscriptionStmt) {
+    for n in &mut node.publication {
+        v.visit_node_mut(n);
+    }
+    for n in &mut node.options {
+        v.visit_node_mut(n);
+    }
+}
+
+pub fn walk_drop_subscription_stmt_mut<V: VisitorMut + ?Sized>(_v: &mut V, _node: &mut protobuf::DropSubscriptionStmt) {}
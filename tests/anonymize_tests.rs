@@ -0,0 +1,151 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{anonymize, fingerprint, Anonymizer, Error};
+
+#[macro_use]
+mod support;
+
+#[test]
+fn it_anonymizes_names() {
+    let query = "SELECT u.email AS address, count(*) FROM app.users u JOIN orders USING (user_id) WHERE u.active GROUP BY u.email";
+    assert_eq!(anonymize(query).unwrap(), "SELECT t1.c1 AS c2, count(*) FROM s1.t2 t1 JOIN t3 USING (c3) WHERE t1.c4 GROUP BY t1.c1");
+}
+
+#[test]
+fn it_keeps_the_structure() {
+    let queries = [
+        "WITH recent(id) AS (SELECT id FROM events WHERE created_at > now() - interval '1 day') SELECT * FROM recent r",
+        "INSERT INTO accounts (id, name) VALUES ($1, $2) ON CONFLICT (id) DO UPDATE SET name = excluded.name",
+        "SELECT coalesce(a.total, 0), lower(a.name) FROM (SELECT total, name FROM billing.accounts) a",
+        "CREATE TABLE items (id bigint PRIMARY KEY, owner_id bigint REFERENCES owners (id))",
+    ];
+    for query in queries {
+        let anonymized = anonymize(query).unwrap();
+        assert_eq!(fingerprint(&anonymize(&anonymized).unwrap()).unwrap(), fingerprint(&anonymized).unwrap());
+        for name in ["events", "created_at", "recent", "accounts", "billing", "total", "items", "owner_id", "owners"] {
+            assert!(!anonymized.contains(name));
+        }
+    }
+}
+
+#[test]
+fn it_keeps_builtins_and_system_catalogs() {
+    let query =
+        "SELECT c.relname, pg_catalog.format_type(a.atttypid, a.atttypmod), greatest(x, 1), my_func(x) FROM pg_class c, pg_catalog.pg_attribute a, t";
+    assert_eq!(
+        anonymize(query).unwrap(),
+        "SELECT c.relname, pg_catalog.format_type(a.atttypid, a.atttypmod), greatest(c1, 1), f1(c1) FROM pg_class c, pg_catalog.pg_attribute a, t1"
+    );
+    assert_eq!(
+        anonymize("SELECT relname FROM pg_class JOIN pg_namespace n ON n.oid = relnamespace WHERE relkind = 'r'").unwrap(),
+        "SELECT relname FROM pg_class JOIN pg_namespace n ON n.oid = relnamespace WHERE relkind = 'r'"
+    );
+    assert_eq!(
+        anonymize("SELECT name FROM users WHERE id IN (SELECT relowner FROM pg_class)").unwrap(),
+        "SELECT c1 FROM t1 WHERE c2 IN (SELECT relowner FROM pg_class)"
+    );
+}
+
+#[test]
+fn it_anonymizes_functions_named_like_unreserved_keywords() {
+    assert_eq!(
+        anonymize("SELECT search(title), validate(body), label(kind), coalesce(a, b) FROM posts").unwrap(),
+        "SELECT f1(c1), f2(c2), f3(c3), coalesce(c4, c5) FROM t1"
+    );
+}
+
+#[test]
+fn it_anonymizes_DDL() {
+    assert_eq!(anonymize("DROP TABLE app.users, orders").unwrap(), "DROP TABLE s1.t1, t2");
+    assert_eq!(anonymize("CREATE SCHEMA app").unwrap(), "CREATE SCHEMA s1");
+    assert_eq!(anonymize("ALTER TABLE users RENAME COLUMN email TO address").unwrap(), "ALTER TABLE t1 RENAME COLUMN c1 TO c2");
+    assert_eq!(anonymize("SELECT total::app.money_amount, id::int FROM orders").unwrap(), "SELECT c1::s1.y1, c2::int FROM t1");
+
+    let queries = [
+        "CREATE SCHEMA billing CREATE TABLE invoices (id int) CREATE VIEW open_invoices AS SELECT id FROM invoices",
+        "ALTER TABLE billing.invoices ADD CONSTRAINT invoices_total_check CHECK (total > 0), ALTER COLUMN total TYPE billing.amount, DROP CONSTRAINT invoices_old_key",
+        "ALTER TABLE invoices RENAME CONSTRAINT invoices_total_check TO invoices_positive",
+        "ALTER TABLE invoices OWNER TO accountant",
+        "ALTER SCHEMA billing RENAME TO accounting",
+        "DROP TRIGGER audit_invoices ON billing.invoices",
+        "DROP FUNCTION billing.audit(int), billing.recalculate",
+        "DROP TYPE billing.amount",
+        "CREATE TRIGGER audit_invoices AFTER UPDATE OF total ON invoices FOR EACH ROW EXECUTE FUNCTION billing.audit()",
+        "CREATE INDEX invoices_customer_idx ON invoices (customer) INCLUDE (total)",
+        "CREATE TABLE ledger (id int, total billing.amount, booked date) PARTITION BY RANGE (booked)",
+        "CREATE VIEW open_invoices (invoice) AS SELECT id FROM invoices",
+        "INSERT INTO invoices (id) VALUES (1) ON CONFLICT ON CONSTRAINT invoices_pkey DO NOTHING",
+        "SELECT (line).sku, lag(total) OVER recent, billing.convert(total, currency => 'EUR') FROM invoices WINDOW recent AS (ORDER BY issued)",
+        "CREATE TABLE archive (id int PRIMARY KEY USING INDEX TABLESPACE cold_storage, memo text COLLATE german) TABLESPACE cold_storage",
+        "CREATE INDEX ON invoices (customer billing.customer_ops, memo COLLATE german text_pattern_ops) TABLESPACE cold_storage",
+        "CREATE TABLE invoices_copy TABLESPACE cold_storage AS SELECT memo COLLATE german FROM invoices",
+        "PREPARE open_invoices_by_customer (int) AS SELECT id FROM invoices WHERE customer = $1",
+        "EXECUTE open_invoices_by_customer (1); DEALLOCATE open_invoices_by_customer",
+    ];
+    for query in queries {
+        let anonymized = anonymize(query).unwrap();
+        // Names are numbered in order of appearance, so anonymizing again doesn't change anything
+        assert_eq!(anonymize(&anonymized).unwrap(), anonymized);
+        for name in [
+            "billing",
+            "invoices",
+            "ledger",
+            "total",
+            "amount",
+            "positive",
+            "accountant",
+            "accounting",
+            "audit",
+            "recalculate",
+            "customer",
+            "booked",
+            "line",
+            "sku",
+            "recent",
+            "currency",
+            "issued",
+            "archive",
+            "memo",
+            "cold_storage",
+            "german",
+            "customer_ops",
+            "open_invoices_by_customer",
+        ] {
+            assert!(!anonymized.contains(name));
+        }
+    }
+}
+
+#[test]
+fn it_keeps_builtin_collations_and_operator_classes() {
+    assert_eq!(
+        anonymize("CREATE INDEX ON users (email text_pattern_ops, name COLLATE \"C\", tags array_ops) TABLESPACE pg_default").unwrap(),
+        "CREATE INDEX ON t1 (c1 text_pattern_ops, c2 COLLATE \"C\", c3 array_ops) TABLESPACE pg_default"
+    );
+}
+
+#[test]
+fn it_rejects_statements_it_cannot_anonymize() {
+    assert_eq!(
+        anonymize("CREATE FUNCTION f() RETURNS int AS 'SELECT a FROM t' LANGUAGE sql"),
+        Err(Error::Anonymize("CreateFunctionStmt".to_string()))
+    );
+    assert_eq!(anonymize("SELECT 1; GRANT SELECT ON t TO r"), Err(Error::Anonymize("GrantStmt".to_string())));
+    assert_eq!(anonymize("DROP POLICY p ON t"), Err(Error::Anonymize("DropStmt of OBJECT_POLICY".to_string())));
+    assert_eq!(anonymize("ALTER TABLE t SET TABLESPACE fast"), Err(Error::Anonymize("AlterTableCmd AT_SetTableSpace".to_string())));
+}
+
+#[test]
+fn it_is_consistent_across_queries() {
+    let mut anonymizer = Anonymizer::new();
+    assert_eq!(anonymizer.anonymize("SELECT name FROM users").unwrap(), "SELECT c1 FROM t1");
+    assert_eq!(anonymizer.anonymize("SELECT id, name FROM users; SELECT id FROM orders").unwrap(), "SELECT c2, c1 FROM t1; SELECT c2 FROM t2");
+
+    let a = Anonymizer::with_key(7).anonymize("SELECT name FROM users").unwrap();
+    let b = Anonymizer::with_key(7).anonymize("SELECT x, name FROM users").unwrap();
+    let c = Anonymizer::with_key(8).anonymize("SELECT name FROM users").unwrap();
+    assert!(!a.contains("name") && !a.contains("users"));
+    assert!(b.ends_with(a.trim_start_matches("SELECT ")));
+    assert_ne!(a, c);
+}
@@ -0,0 +1,167 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::builder::*;
+use pg_query::{deparse, parse, NodeEnum};
+
+#[macro_use]
+mod support;
+
+// The built statement deparses to `expected`, which in turn parses back to the same SQL
+fn assert_sql(actual: pg_query::Result<String>, expected: &str) {
+    let actual = actual.unwrap();
+    assert_eq!(actual, expected);
+    assert_eq!(parse(&actual).unwrap().deparse().unwrap(), expected);
+}
+
+#[test]
+fn it_builds_select() {
+    let query = select().columns(["a", "b"]).from(range_var("public", "t")).where_(col("x").eq(param(1)));
+    assert_sql(query.deparse(), "SELECT a, b FROM public.t WHERE x = $1");
+
+    let query = select().column(star()).from("t");
+    assert_sql(query.deparse(), "SELECT * FROM t");
+}
+
+#[test]
+fn it_builds_select_clauses() {
+    let query = select()
+        .distinct()
+        .columns(["u.country"])
+        .column_as(count_star(), "users")
+        .from(table("users").alias("u").join(table("orders").alias("o"), col("o.user_id").eq(col("u.id"))))
+        .where_(col("u.active"))
+        .where_(col("o.total").gt(100))
+        .group_by(col("u.country"))
+        .having(count_star().ge(10))
+        .order_by_desc(col("users"))
+        .order_by(col("u.country"))
+        .limit(20)
+        .offset(param(1));
+    assert_sql(
+        query.deparse(),
+        "SELECT DISTINCT u.country, count(*) AS users FROM users u JOIN orders o ON o.user_id = u.id \
+         WHERE u.active AND o.total > 100 GROUP BY u.country HAVING count(*) >= 10 \
+         ORDER BY users DESC, u.country LIMIT 20 OFFSET $1",
+    );
+}
+
+#[test]
+fn it_builds_expressions() {
+    let cases = [
+        (col("a").ne(1).or(col("b").lt(2)).and(col("c").le(3)), "(a <> 1 OR b < 2) AND c <= 3"),
+        (!col("a").or(col("b")), "NOT (a OR b)"),
+        (col("a").is_null().and(col("b").is_not_null()), "a IS NULL AND b IS NOT NULL"),
+        (col("a").in_list([1, 2, 3]), "a IN (1, 2, 3)"),
+        (col("name").like(string("it's%")), "name LIKE 'it''s%'"),
+        (col("tags").op("@>", func("array_agg", [col("t.*")])), "tags @> array_agg(t.*)"),
+        (func("pg_catalog.lower", [col("email")]).eq(string("a@b.c")), "pg_catalog.lower(email) = 'a@b.c'"),
+        (col("a").cast("integer"), "a::int"),
+        (col("b").cast("text[]"), "b::text[]"),
+        (col("c").cast("double precision"), "c::double precision"),
+        (col("d").eq(null()).or(boolean(false)).or(float(1.5)), "d = NULL OR false OR 1.5"),
+        (integer(5_000_000_000).gt(integer(-1)), "5000000000 > -1"),
+    ];
+    for (expr, expected) in cases {
+        assert_eq!(expr.deparse().unwrap(), expected);
+    }
+}
+
+#[test]
+fn it_builds_type_modifiers_like_the_parser() {
+    for type_name in ["char", "character", "bit", "varchar(10)", "numeric(10, 2)"] {
+        let NodeEnum::TypeCast(built) = col("a").cast(type_name).into_node() else { panic!("expected a TypeCast") };
+        let parsed = parse(&format!("SELECT a::{type_name}")).unwrap();
+        let NodeEnum::SelectStmt(stmt) = parsed.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap() else {
+            panic!("expected a SelectStmt")
+        };
+        let NodeEnum::ResTarget(target) = stmt.target_list[0].node.as_ref().unwrap() else { panic!("expected a ResTarget") };
+        let NodeEnum::TypeCast(parsed) = target.val.as_ref().unwrap().node.as_ref().unwrap() else { panic!("expected a TypeCast") };
+        let (built, parsed) = (built.type_name.unwrap(), parsed.type_name.as_ref().unwrap());
+        assert_eq!(built.names, parsed.names);
+        assert_eq!(built.typmods.len(), parsed.typmods.len());
+        for (built, parsed) in built.typmods.iter().zip(&parsed.typmods) {
+            let (NodeEnum::AConst(built), NodeEnum::AConst(parsed)) = (built.node.as_ref().unwrap(), parsed.node.as_ref().unwrap()) else {
+                panic!("expected constants")
+            };
+            assert_eq!(built.val, parsed.val);
+        }
+    }
+}
+
+#[test]
+fn it_builds_non_finite_floats() {
+    assert_eq!(float(f64::NAN).deparse().unwrap(), "'NaN'::float8");
+    assert_eq!(float(f64::INFINITY).deparse().unwrap(), "'Infinity'::float8");
+    assert_eq!(float(f64::NEG_INFINITY).deparse().unwrap(), "'-Infinity'::float8");
+}
+
+#[test]
+fn it_leaves_locations_unset() {
+    let NodeEnum::SelectStmt(stmt) = select().column(col("a")).from("t").where_(col("b").is_null()).into() else { panic!("expected a SelectStmt") };
+    let NodeEnum::ResTarget(target) = stmt.target_list[0].node.as_ref().unwrap() else { panic!("expected a ResTarget") };
+    assert_eq!(target.location, -1);
+    let NodeEnum::RangeVar(table) = stmt.from_clause[0].node.as_ref().unwrap() else { panic!("expected a RangeVar") };
+    assert_eq!(table.location, -1);
+    let NodeEnum::NullTest(test) = stmt.where_clause.as_ref().unwrap().node.as_ref().unwrap() else { panic!("expected a NullTest") };
+    assert_eq!(test.location, -1);
+}
+
+#[test]
+fn it_builds_subqueries() {
+    let admins = select().columns(["user_id"]).from("admins");
+    let query = select()
+        .column(star())
+        .column_as(subquery(select().column(func("max", [col("total")])).from("orders")), "max_total")
+        .from(from_subquery(select().columns(["id"]).from("users"), "u"))
+        .where_(col("id").in_subquery(admins))
+        .where_(!exists(select().column(1).from("bans").where_(col("bans.user_id").eq(col("u.id")))));
+    assert_sql(
+        query.deparse(),
+        "SELECT *, (SELECT max(total) FROM orders) AS max_total FROM (SELECT id FROM users) u \
+         WHERE id IN (SELECT user_id FROM admins) AND NOT EXISTS (SELECT 1 FROM bans WHERE bans.user_id = u.id)",
+    );
+
+    let query = select().columns(["a"]).from("t").union(select().columns(["a"]).from("u"), true);
+    assert_sql(query.deparse(), "SELECT a FROM t UNION ALL SELECT a FROM u");
+}
+
+#[test]
+fn it_builds_dml() {
+    let insert = insert_into(range_var("app", "users"))
+        .columns(["name", "email"])
+        .values([string("Alice"), string("alice@example.com")])
+        .values([param(1), null()])
+        .returning(col("id"));
+    assert_sql(insert.deparse(), "INSERT INTO app.users (name, email) VALUES ('Alice', 'alice@example.com'), ($1, NULL) RETURNING id");
+
+    let insert = insert_into("archive").select(select().column(star()).from(table("users").only()));
+    assert_sql(insert.deparse(), "INSERT INTO archive SELECT * FROM ONLY users");
+
+    let update =
+        update("users").set("visits", col("visits").op("+", 1)).set("seen_at", func("now", Vec::<Expr>::new())).where_(col("id").eq(param(1)));
+    assert_sql(update.deparse(), "UPDATE users SET visits = visits + 1, seen_at = now() WHERE id = $1");
+
+    let delete = delete_from(table("sessions").alias("s")).using("users").where_(col("s.user_id").eq(col("users.id"))).returning(col("s.id"));
+    assert_sql(delete.deparse(), "DELETE FROM sessions s USING users WHERE s.user_id = users.id RETURNING s.id");
+}
+
+#[test]
+fn it_builds_parse_results() {
+    let result = parse_result([NodeEnum::from(select().column(1)), NodeEnum::from(delete_from("t"))]);
+    assert_eq!(deparse(&result).unwrap(), "SELECT 1; DELETE FROM t");
+    assert_eq!(result.stmts.len(), 2);
+}
+
+#[test]
+fn it_builds_the_same_tree_as_the_parser() {
+    let built = select().columns(["a"]).from("t").where_(col("b").eq(1)).limit(5).build();
+    let parsed = parse(&select().columns(["a"]).from("t").where_(col("b").eq(1)).limit(5).deparse().unwrap()).unwrap();
+    let NodeEnum::SelectStmt(parsed) = parsed.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap() else {
+        panic!("expected a SelectStmt")
+    };
+    // Enum fields have the values the parser assigns
+    assert_eq!(built.op, parsed.op);
+    assert_eq!(built.limit_option, parsed.limit_option);
+    assert_eq!(built.from_clause[0].deparse().unwrap(), parsed.from_clause[0].deparse().unwrap());
+}
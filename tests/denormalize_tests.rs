@@ -0,0 +1,60 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{denormalize, normalize, normalize_with_params, quote_literal, Error, ParamValue};
+
+#[macro_use]
+mod support;
+
+#[test]
+fn it_quotes_literals() {
+    assert_eq!(quote_literal("abc"), "'abc'");
+    assert_eq!(quote_literal(""), "''");
+    assert_eq!(quote_literal("it's"), "'it''s'");
+    assert_eq!(quote_literal(r"a\b'c"), r"E'a\\b''c'");
+}
+
+#[test]
+fn it_writes_typed_values() {
+    assert_eq!(ParamValue::Null.to_sql(), "NULL");
+    assert_eq!(ParamValue::from(None::<i64>).to_sql(), "NULL");
+    assert_eq!(ParamValue::from(true).to_sql(), "true");
+    assert_eq!(ParamValue::from(-42).to_sql(), "-42");
+    assert_eq!(ParamValue::from(3.0).to_sql(), "3.0");
+    assert_eq!(ParamValue::from(f64::NAN).to_sql(), "'NaN'::float8");
+    assert_eq!(ParamValue::from(f64::NEG_INFINITY).to_sql(), "'-Infinity'::float8");
+    assert_eq!(ParamValue::Bytea(vec![0xde, 0xad, 0x01]).to_sql(), r"'\xdead01'::bytea");
+    assert_eq!(ParamValue::Array(vec![]).to_sql(), "'{}'");
+    assert_eq!(
+        ParamValue::Array(vec![ParamValue::Array(vec!["a".into(), "b'".into()]), ParamValue::Array(vec![ParamValue::Null, "c".into()])]).to_sql(),
+        "ARRAY[ARRAY['a', 'b'''], ARRAY[NULL, 'c']]"
+    );
+    assert_eq!(ParamValue::Literal("DATE '2024-01-01'".into()).to_sql(), "DATE '2024-01-01'");
+}
+
+#[test]
+fn it_substitutes_placeholders() {
+    let query = "SELECT $1, $2::bytea FROM t WHERE a = $1 AND b IS $3 AND c-$4 > 0 -- $2\nAND d = '$2' AND e = $$ $2 $$";
+    let params = [ParamValue::from("x"), ParamValue::Bytea(vec![1]), ParamValue::Null, ParamValue::from(-1)];
+    assert_eq!(
+        denormalize(query, &params).unwrap(),
+        "SELECT 'x', '\\x01'::bytea::bytea FROM t WHERE a = 'x' AND b IS NULL AND c- -1 > 0 -- $2\nAND d = '$2' AND e = $$ $2 $$"
+    );
+}
+
+#[test]
+fn it_reverses_normalize() {
+    let query = "SELECT * FROM t WHERE a = 'it''s' AND b IN (1, -2.5) AND c = true";
+    let result = normalize_with_params(query).unwrap();
+    let params: Vec<ParamValue> = result.params.iter().map(ParamValue::from).collect();
+    let denormalized = denormalize(&result.normalized, &params).unwrap();
+    assert_eq!(denormalized, query);
+    assert_eq!(normalize(&denormalized).unwrap(), result.normalized);
+}
+
+#[test]
+fn it_errors_on_missing_params() {
+    let error = denormalize("SELECT $1, $2", &[1.into()]).err().unwrap();
+    assert!(matches!(error, Error::MissingParam(ref p) if p == "$2"));
+    assert_eq!(error.to_string(), "No value for parameter $2");
+}
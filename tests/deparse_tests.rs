@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 #![cfg(test)]
 
-use pg_query::parse;
+use pg_query::{parse, parse_expr, parse_type_name, protobuf, NodeEnum};
 
 #[cfg(test)]
 use regex::Regex;
@@ -260,6 +260,78 @@ fn it_deparses_SELECT_JOIN_with_USING() {
     assert_deparse(query, query);
 }
 
+fn select_stmt(query: &str) -> Box<protobuf::SelectStmt> {
+    let result = parse(query).unwrap();
+    match result.protobuf.stmts[0].stmt.as_ref().and_then(|n| n.node.clone()) {
+        Some(NodeEnum::SelectStmt(s)) => s,
+        _ => panic!("expected a SELECT statement"),
+    }
+}
+
+#[test]
+fn it_deparses_expressions() {
+    for expr in [
+        "a + 1 > $1",
+        "status = 'active' AND deleted_at IS NULL",
+        "NOT (a OR b)",
+        "lower(email)",
+        "id IN (SELECT user_id FROM orders)",
+        "EXISTS (SELECT 1 FROM t WHERE t.x = y)",
+        "CASE WHEN a > 0 THEN 'pos' ELSE 'neg' END",
+        "x::text",
+        "coalesce(a, b, 0)",
+    ] {
+        assert_eq!(parse_expr(expr).unwrap().deparse().unwrap(), expr);
+    }
+}
+
+#[test]
+fn it_deparses_type_names() {
+    for type_name in ["varchar(20)[]", "numeric(10, 2)", "public.my_enum", "timestamptz"] {
+        let node = NodeEnum::TypeName(parse_type_name(type_name).unwrap());
+        assert_eq!(node.deparse().unwrap(), type_name);
+    }
+}
+
+#[test]
+fn it_deparses_clause_fragments() {
+    let select = select_stmt(
+        "WITH recent AS (SELECT * FROM orders WHERE created_at > now())
+        SELECT u.id, count(*) OVER (PARTITION BY u.id) AS total, rank() OVER w
+        FROM users u JOIN recent r ON r.user_id = u.id
+        WHERE u.active
+        WINDOW w AS (PARTITION BY u.team ORDER BY u.id)
+        ORDER BY u.created_at DESC NULLS LAST",
+    );
+    let targets: Vec<String> = select.target_list.iter().map(|n| n.deparse().unwrap()).collect();
+    assert_eq!(targets, ["u.id", "count(*) OVER (PARTITION BY u.id) AS total", "rank() OVER w"]);
+    assert_eq!(select.from_clause[0].deparse().unwrap(), "users u JOIN recent r ON r.user_id = u.id");
+    assert_eq!(select.where_clause.unwrap().deparse().unwrap(), "u.active");
+    assert_eq!(select.window_clause[0].deparse().unwrap(), "w AS (PARTITION BY u.team ORDER BY u.id)");
+    assert_eq!(select.sort_clause[0].deparse().unwrap(), "u.created_at DESC NULLS LAST");
+    assert_eq!(select.with_clause.unwrap().ctes[0].deparse().unwrap(), "recent AS (SELECT * FROM orders WHERE created_at > now())");
+
+    let Some(NodeEnum::ResTarget(target)) = &select.target_list[1].node else { panic!("expected a ResTarget") };
+    let Some(NodeEnum::FuncCall(call)) = target.val.as_ref().and_then(|n| n.node.as_ref()) else { panic!("expected a FuncCall") };
+    let over = NodeEnum::WindowDef(call.over.clone().unwrap());
+    assert_eq!(over.deparse().unwrap(), "(PARTITION BY u.id)");
+}
+
+#[test]
+fn it_deparses_FROM_items() {
+    let select = select_stmt("SELECT * FROM public.a AS x, LATERAL (SELECT 1) s, generate_series(1, 3) g(n)");
+    let items: Vec<String> = select.from_clause.iter().map(|n| n.deparse().unwrap()).collect();
+    assert_eq!(items, ["public.a x", "LATERAL (SELECT 1) s", "generate_series(1, 3) g(n)"]);
+}
+
+#[test]
+fn it_rejects_fragments_it_cannot_deparse() {
+    let alias = NodeEnum::Alias(protobuf::Alias { aliasname: "a".to_string(), colnames: vec![] });
+    assert_eq!(alias.deparse().unwrap_err().to_string(), "Invalid statement: Alias can't be deparsed on its own");
+    let string = NodeEnum::String(protobuf::String { sval: "a".to_string() });
+    assert!(string.deparse().is_err());
+}
+
 // There are many more Ruby tests but we probably don't need to implement
 // them here since we're just passing the protobuf back to C
 // https://github.com/pganalyze/pg_query/tree/main/spec/lib/pg_query/deparse_spec.rb
@@ -0,0 +1,108 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{diff, parse, Change, ChangeKind};
+
+#[macro_use]
+mod support;
+
+fn changes(a: &str, b: &str) -> Vec<Change> {
+    diff(&parse(a).unwrap().protobuf, &parse(b).unwrap().protobuf)
+}
+
+fn descriptions(a: &str, b: &str) -> Vec<String> {
+    changes(a, b).iter().map(|c| c.to_string()).collect()
+}
+
+#[test]
+fn it_ignores_formatting() {
+    assert!(changes("select a, b from t where c = 1", "SELECT a,\n       b\n  FROM t -- the table\n WHERE c = 1").is_empty());
+    assert!(changes("SELECT 1; SELECT 2", "SELECT 1;\n\nSELECT 2;").is_empty());
+}
+
+#[test]
+fn it_reports_target_list_changes() {
+    assert_eq!(descriptions("SELECT id, email FROM users", "SELECT id FROM users"), ["target list: removed column `email`"]);
+    assert_eq!(
+        descriptions("SELECT id FROM users", "SELECT id, lower(email) AS email FROM users"),
+        ["target list: added column `lower(email) AS email`"]
+    );
+    assert_eq!(descriptions("SELECT id, a AS x FROM users", "SELECT id, a AS y FROM users"), ["target list: changed column `a AS x` to `a AS y`"]);
+}
+
+#[test]
+fn it_reports_predicate_changes() {
+    assert_eq!(
+        descriptions("SELECT * FROM t WHERE a = 1", "SELECT * FROM t WHERE a = 1 AND status = $1"),
+        ["WHERE clause: added predicate `status = $1`"]
+    );
+    assert_eq!(
+        descriptions("SELECT * FROM t WHERE a = 1 AND b IS NULL AND c", "SELECT * FROM t WHERE a = 1 AND c"),
+        ["WHERE clause: removed predicate `b IS NULL`"]
+    );
+    assert_eq!(descriptions("SELECT * FROM t WHERE a = 1", "SELECT * FROM t WHERE a = 2"), ["WHERE clause: changed expression `1` to `2`"]);
+    assert_eq!(
+        descriptions("SELECT * FROM t WHERE a = 1 OR b = 2", "SELECT * FROM t WHERE a = 1 AND b = 2"),
+        ["WHERE clause: changed predicate `a = 1 OR b = 2` to `a = 1 AND b = 2`"]
+    );
+    assert_eq!(descriptions("SELECT * FROM t", "SELECT * FROM t WHERE a"), ["WHERE clause: added predicate `a`"]);
+}
+
+#[test]
+fn it_reports_clause_changes() {
+    assert_eq!(descriptions("SELECT * FROM users u", "SELECT * FROM accounts u"), ["FROM clause: changed table `users u` to `accounts u`"]);
+    assert_eq!(descriptions("SELECT a FROM t ORDER BY a", "SELECT a FROM t ORDER BY a DESC"), ["ORDER BY clause: changed sort key `a` to `a DESC`"]);
+    assert_eq!(descriptions("SELECT a FROM t", "SELECT a FROM t LIMIT 10"), ["LIMIT clause: added expression `10`"]);
+    assert_eq!(
+        descriptions("SELECT a FROM t JOIN u ON t.id = u.t_id", "SELECT a FROM t JOIN u ON t.id = u.t_id AND u.active"),
+        ["FROM clause → JOIN condition: added predicate `u.active`"]
+    );
+    assert_eq!(descriptions("UPDATE t SET a = 1", "UPDATE t SET a = 1, b = 2"), ["SET clause: added column `b = 2`"]);
+}
+
+#[test]
+fn it_matches_long_lists() {
+    let columns: Vec<String> = (0..500).map(|i| format!("lower(c{i})")).collect();
+    let before = format!("SELECT {} FROM t", columns.join(", "));
+    let after = format!("SELECT {} FROM t", columns.iter().filter(|c| *c != "lower(c250)").cloned().collect::<Vec<_>>().join(", "));
+    assert_eq!(descriptions(&before, &after), ["target list: removed column `lower(c250)`"]);
+}
+
+#[test]
+fn it_reports_nested_changes() {
+    let found = changes("SELECT * FROM (SELECT a, b FROM t) s", "SELECT * FROM (SELECT a FROM t) s");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].to_string(), "FROM clause → target list: removed column `b`");
+    assert_eq!(found[0].path.to_string(), "SelectStmt.from_clause[0] → RangeSubselect.subquery → SelectStmt.target_list[1]");
+}
+
+#[test]
+fn it_reports_statement_changes() {
+    let found = changes("SELECT 1; DELETE FROM t", "SELECT 1; UPDATE t SET a = 1; DELETE FROM t");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].kind, ChangeKind::Added);
+    assert_eq!(found[0].path.statement, 1);
+    assert!(found[0].path.steps.is_empty());
+    assert_eq!(found[0].to_string(), "statement: added statement `UPDATE t SET a = 1`");
+
+    assert_eq!(descriptions("SELECT a FROM t UNION SELECT a FROM u", "SELECT a FROM t UNION ALL SELECT a FROM u").len(), 1);
+}
+
+#[test]
+fn it_exposes_change_details() {
+    let found = changes("SELECT id, email FROM users", "SELECT id, name FROM users");
+    assert_eq!(found.len(), 1);
+    let change = &found[0];
+    assert_eq!(change.kind, ChangeKind::Modified);
+    assert_eq!(change.node_type, "ColumnRef");
+    assert_eq!(change.before.as_deref(), Some("email"));
+    assert_eq!(change.after.as_deref(), Some("name"));
+    assert_eq!(change.path.to_string(), "SelectStmt.target_list[1] → ResTarget.val");
+    assert_eq!(change.clause(), "target list");
+    assert_eq!(change.noun(), "column");
+    assert_eq!(change.to_string(), "target list: changed column `email` to `name`");
+
+    // The path points into the second tree
+    let b = parse("SELECT id, name FROM users").unwrap();
+    assert_eq!(b.protobuf.resolve(&change.path).unwrap().deparse().unwrap(), "name");
+}
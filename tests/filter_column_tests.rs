@@ -10,7 +10,7 @@ use pg_query::parse;
 fn it_finds_unqualified_names() {
     let result = parse("SELECT * FROM x WHERE y = $1 AND z = 1").unwrap();
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
-    assert_eq!(filter_columns, [(None, "y".into()), (None, "z".into())]);
+    assert_eq!(filter_columns, [(Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
 
 #[test]
@@ -24,7 +24,7 @@ fn it_finds_qualified_names() {
 fn it_traverses_into_ctes() {
     let result = parse("WITH a AS (SELECT * FROM x WHERE x.y = $1 AND x.z = 1) SELECT * FROM a WHERE b = 5").unwrap();
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
-    assert_eq!(filter_columns, [(None, "b".into()), (Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
+    assert_eq!(filter_columns, [(Some("x".into()), "b".into()), (Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
 
 #[test]
@@ -52,42 +52,42 @@ fn it_finds_coalesce_argument_names() {
 fn it_finds_unqualified_names_in_union_query() {
     let result = parse("SELECT * FROM x where y = $1 UNION SELECT * FROM x where z = $2").unwrap();
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
-    assert_eq!(filter_columns, [(None, "y".into()), (None, "z".into())]);
+    assert_eq!(filter_columns, [(Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
 
 #[test]
 fn it_finds_unqualified_names_in_union_all_query() {
     let result = parse("SELECT * FROM x where y = $1 UNION ALL SELECT * FROM x where z = $2").unwrap();
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
-    assert_eq!(filter_columns, [(None, "y".into()), (None, "z".into())]);
+    assert_eq!(filter_columns, [(Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
 
 #[test]
 fn it_finds_unqualified_names_in_except_query() {
     let result = parse("SELECT * FROM x where y = $1 EXCEPT SELECT * FROM x where z = $2").unwrap();
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
-    assert_eq!(filter_columns, [(None, "y".into()), (None, "z".into())]);
+    assert_eq!(filter_columns, [(Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
 
 #[test]
 fn it_finds_unqualified_names_in_except_all_query() {
     let result = parse("SELECT * FROM x where y = $1 EXCEPT ALL SELECT * FROM x where z = $2").unwrap();
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
-    assert_eq!(filter_columns, [(None, "y".into()), (None, "z".into())]);
+    assert_eq!(filter_columns, [(Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
 
 #[test]
 fn it_finds_unqualified_names_in_intersect_query() {
     let result = parse("SELECT * FROM x where y = $1 INTERSECT SELECT * FROM x where z = $2").unwrap();
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
-    assert_eq!(filter_columns, [(None, "y".into()), (None, "z".into())]);
+    assert_eq!(filter_columns, [(Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
 
 #[test]
 fn it_finds_unqualified_names_in_intersect_all_query() {
     let result = parse("SELECT * FROM x where y = $1 INTERSECT ALL SELECT * FROM x where z = $2").unwrap();
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
-    assert_eq!(filter_columns, [(None, "y".into()), (None, "z".into())]);
+    assert_eq!(filter_columns, [(Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
 
 #[test]
@@ -103,3 +103,38 @@ fn it_ignores_order_by_columns() {
     let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
     assert_eq!(filter_columns, [(Some("x".into()), "y".into()), (Some("x".into()), "z".into())]);
 }
+
+#[test]
+fn it_resolves_table_aliases() {
+    let result = parse("SELECT * FROM users u JOIN orders o ON o.user_id = u.id WHERE u.email = $1 AND o.state = 'open'").unwrap();
+    let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
+    assert_eq!(filter_columns, [(Some("orders".into()), "state".into()), (Some("users".into()), "email".into())]);
+}
+
+#[test]
+fn it_resolves_schema_qualified_tables() {
+    let result = parse("SELECT * FROM app.users u WHERE u.email = $1 AND deleted_at IS NULL").unwrap();
+    let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
+    assert_eq!(filter_columns, [(Some("app.users".into()), "deleted_at".into()), (Some("app.users".into()), "email".into())]);
+}
+
+#[test]
+fn it_resolves_subquery_aliases() {
+    let result = parse("SELECT * FROM (SELECT id, created_at AS created FROM events) e WHERE e.created > $1 AND id = $2").unwrap();
+    let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
+    assert_eq!(filter_columns, [(Some("events".into()), "created_at".into()), (Some("events".into()), "id".into())]);
+}
+
+#[test]
+fn it_resolves_cte_names() {
+    let result = parse("WITH recent AS (SELECT * FROM app.events) SELECT * FROM recent r WHERE r.kind = $1").unwrap();
+    let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
+    assert_eq!(filter_columns, [(Some("app.events".into()), "kind".into())]);
+}
+
+#[test]
+fn it_keeps_ambiguous_unqualified_names_unresolved() {
+    let result = parse("SELECT * FROM users u JOIN orders o ON o.user_id = u.id WHERE state = $1").unwrap();
+    let filter_columns: Vec<(Option<String>, String)> = sorted(result.filter_columns).collect();
+    assert_eq!(filter_columns, [(None, "state".into())]);
+}
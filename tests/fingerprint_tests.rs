@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 #![cfg(test)]
 
-use pg_query::{fingerprint, Error};
+use pg_query::{fingerprint, fingerprint_statements, parse};
 
 #[test]
 fn it_can_fingerprint_a_simple_statement() {
@@ -12,7 +12,7 @@ fn it_can_fingerprint_a_simple_statement() {
 #[test]
 fn it_will_error_on_invalid_input() {
     let error = fingerprint("CREATE RANDOM ix_test ON contacts.person;").err().unwrap();
-    assert_eq!(error, Error::Parse("syntax error at or near \"RANDOM\"".into()));
+    assert_eq!(error.to_string(), "Invalid statement: syntax error at or near \"RANDOM\"");
 }
 
 #[test]
@@ -309,3 +309,162 @@ fn it_works() {
     let result = fingerprint("SELECT * FROM t_2").unwrap();
     assert_eq!(result.hex, "3f1444da570c1a66");
 }
+
+#[test]
+fn it_fingerprints_parse_trees_like_libpg_query() {
+    let queries = [
+        "SELECT * FROM contacts.person WHERE id IN (1, 2, 3, 4);",
+        "SELECT 1",
+        "SELECT 2",
+        "SELECT $1",
+        "SELECT 1; SELECT a FROM b",
+        "SELECT COUNT(DISTINCT id), * FROM targets WHERE something IS NOT NULL AND elsewhere::interval < now()",
+        "INSERT INTO test (a, b) VALUES ($1, $2)",
+        "INSERT INTO test (b, a) VALUES ($1, $2)",
+        "SELECT b AS x, a AS y FROM z",
+        "SELECT * FROM x WHERE y = $1",
+        "SELECT * FROM x WHERE y = ANY ($1)",
+        "SELECT * FROM x WHERE y IN ($1)",
+        "SELECT * FROM x WHERE y IN ($1, $2, $3)",
+        "SELECT * FROM x WHERE y IN ( $1::uuid )",
+        "SELECT * FROM x WHERE y IN ( $1::uuid, $2::uuid, $3::uuid )",
+        "PREPARE a123 AS SELECT a",
+        "EXECUTE a123",
+        "DEALLOCATE a123",
+        "DEALLOCATE ALL",
+        "EXPLAIN ANALYZE SELECT a",
+        "WITH a AS (SELECT * FROM x WHERE x.y = $1 AND x.z = 1) SELECT * FROM a",
+        "CREATE TABLE types (a float(2), b float(49), c NUMERIC(2, 3), d character(4), e char(5), f varchar(6), g character varying(7))",
+        "CREATE VIEW view_a (a, b) AS WITH RECURSIVE view_a (a, b) AS (SELECT * FROM a(1)) SELECT \"a\", \"b\" FROM \"view_a\"",
+        "VACUUM FULL my_table",
+        "SELECT * FROM x AS a, y AS b",
+        "SELECT * FROM y AS a, x AS b",
+        "SELECT x AS a, y AS b FROM x",
+        "SELECT y AS a, x AS b FROM x",
+        "SELECT x, y FROM z",
+        "SELECT y, x FROM z",
+        "INSERT INTO films (code, title, did) VALUES ('UA502', 'Bananas', 105), ('T_601', 'Yojimbo', DEFAULT)",
+        "INSERT INTO films (code, title, did) VALUES ($1, $2, $3)",
+        "SELECT * FROM a",
+        "SELECT * FROM a AS b",
+        "UPDATE users SET one_thing = $1, second_thing = $2 WHERE users.id = $1",
+        "UPDATE users SET something_else = $1 WHERE users.id = $1",
+        "UPDATE users SET something_else = (SELECT a FROM x WHERE uid = users.id LIMIT 1) WHERE users.id = $1",
+        "SAVEPOINT some_id",
+        "RELEASE some_id",
+        "PREPARE TRANSACTION 'some_id'",
+        "START TRANSACTION READ WRITE",
+        "DECLARE cursor_123 CURSOR FOR SELECT * FROM test WHERE id = 123",
+        "FETCH 1000 FROM cursor_123",
+        "CLOSE cursor_123",
+        "-- nothing",
+        "CREATE FOREIGN TABLE ft1 () SERVER no_server",
+        "UPDATE x SET a = 1, b = 2, c = 3",
+        "UPDATE x SET z = now()",
+        "CREATE TEMPORARY TABLE my_temp_table (test_id integer NOT NULL) ON COMMIT DROP",
+        "CREATE TEMPORARY TABLE my_temp_table AS SELECT 1",
+        "SELECT INTERVAL (0) $2",
+        "SELECT INTERVAL (2) $2",
+        "SELECT * FROM t WHERE t.a IN (1, 2) AND t.b = 3",
+        "SELECT * FROM t WHERE t.b = 3 AND t.a IN (1, 2)",
+        "SELECT * FROM t WHERE a && '[1,2]'",
+        "SELECT * FROM t WHERE a && '[1,2]'::int4range",
+        "SELECT * FROM t_20210301_x",
+        "SELECT * FROM t_20210302_x",
+        "SELECT * FROM t_20210302_y",
+        "SELECT * FROM t_1",
+        "SELECT * FROM t_2",
+    ];
+    for query in queries {
+        let result = parse(query).unwrap();
+        let expected = fingerprint(query).unwrap();
+        assert_eq!(result.fingerprint().hex, expected.hex);
+        assert_eq!(result.fingerprint().value, expected.value);
+        if let [stmt] = result.protobuf.stmts.as_slice() {
+            assert_eq!(stmt.fingerprint().hex, expected.hex);
+        }
+    }
+}
+
+#[test]
+fn it_fingerprints_libpg_query_fixtures() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/libpg_query/test/fingerprint_tests.c");
+    let source = std::fs::read_to_string(path).unwrap();
+    // The `tests` array alternates between queries and their expected fingerprint
+    let start = source.find("tests[] = {").unwrap();
+    let fixtures = c_strings(&source[start..start + source[start..].find("};").unwrap()]);
+    let pairs = fixtures.chunks_exact(2);
+    assert!(pairs.len() > 0 && pairs.remainder().is_empty());
+    let mut failures = Vec::new();
+    for fixture in pairs {
+        let (query, expected) = (&fixture[0], &fixture[1]);
+        match parse(query) {
+            Ok(result) if result.fingerprint().hex == *expected => {}
+            Ok(result) => failures.push(format!("{query}: expected {expected}, got {}", result.fingerprint().hex)),
+            Err(error) => failures.push(format!("{query}: {error}")),
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+// The string literals of a C source file, with adjacent literals joined as in C
+fn c_strings(source: &str) -> Vec<String> {
+    let mut strings: Vec<String> = Vec::new();
+    let mut chars = source.chars().peekable();
+    let mut adjacent = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let mut string = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => match chars.next() {
+                            Some('n') => string.push('\n'),
+                            Some('t') => string.push('\t'),
+                            Some('r') => string.push('\r'),
+                            Some(c) => string.push(c),
+                            None => {}
+                        },
+                        c => string.push(c),
+                    }
+                }
+                match strings.last_mut() {
+                    Some(last) if adjacent => last.push_str(&string),
+                    _ => strings.push(string),
+                }
+                adjacent = true;
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                chars.by_ref().take_while(|c| *c != '\n').for_each(drop);
+            }
+            c if c.is_whitespace() => {}
+            _ => adjacent = false,
+        }
+    }
+    strings
+}
+
+#[test]
+fn it_fingerprints_expressions() {
+    let a = pg_query::parse_expr("lower(email) = 'a@example.com'").unwrap();
+    let b = pg_query::parse_expr("LOWER(email) = $1").unwrap();
+    let c = pg_query::parse_expr("upper(email) = $1").unwrap();
+    assert_eq!(a.to_ref().fingerprint().hex, b.to_ref().fingerprint().hex);
+    assert_ne!(a.to_ref().fingerprint().hex, c.to_ref().fingerprint().hex);
+}
+
+#[test]
+fn it_fingerprints_statements_separately() {
+    let query = "SELECT * FROM t WHERE a = 1;\n  SET x = 1 ;\nselect * from t where a = 2";
+    let statements = fingerprint_statements(query).unwrap();
+    let texts: Vec<_> = statements.iter().map(|s| &query[s.range.clone()]).collect();
+    assert_eq!(texts, ["SELECT * FROM t WHERE a = 1", "SET x = 1", "select * from t where a = 2"]);
+    let types: Vec<_> = statements.iter().map(|s| s.statement_type).collect();
+    assert_eq!(types, ["SelectStmt", "VariableSetStmt", "SelectStmt"]);
+    for (statement, text) in statements.iter().zip(texts) {
+        assert_eq!(statement.fingerprint, fingerprint(text).unwrap());
+    }
+    assert_eq!(statements[0].fingerprint, statements[2].fingerprint);
+    assert_ne!(statements[0].fingerprint, fingerprint(query).unwrap());
+}
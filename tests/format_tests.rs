@@ -0,0 +1,129 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{deparse_comments_for_query, format, parse, protobuf::KeywordKind, scan, DeparseComment, DeparseOptions, KeywordCase};
+
+#[macro_use]
+mod support;
+
+const QUERIES: [&str; 6] = [
+    "SELECT a, b, c FROM x WHERE y = $1 AND z IN (1, 2, 3) ORDER BY a DESC LIMIT 10",
+    "WITH recent AS (SELECT * FROM orders WHERE created_at > now()), totals AS (SELECT user_id, sum(amount) AS total FROM recent GROUP BY user_id) SELECT u.name, t.total FROM users u JOIN totals t ON t.user_id = u.id LEFT JOIN teams tm ON tm.id = u.team_id WHERE t.total > 100",
+    "SELECT very_long_column_name_one, very_long_column_name_two, very_long_column_name_three, very_long_column_name_four FROM some_table",
+    "INSERT INTO contacts (id, name, email) VALUES (1, 'Paul', 'paul@example.com') ON CONFLICT (id) DO UPDATE SET name = excluded.name RETURNING id",
+    "UPDATE accounts SET balance = balance - 10 FROM transfers WHERE transfers.account_id = accounts.id AND transfers.pending",
+    "CREATE TABLE items (id bigint PRIMARY KEY, name text NOT NULL, price numeric(10, 2) DEFAULT 0); CREATE INDEX ON items (name)",
+];
+
+fn pretty() -> DeparseOptions {
+    DeparseOptions { pretty: true, ..DeparseOptions::default() }
+}
+
+#[test]
+fn it_matches_deparse_without_pretty_printing() {
+    for query in QUERIES {
+        let result = parse(query).unwrap();
+        assert_eq!(result.deparse_with(&DeparseOptions::default()).unwrap(), result.deparse().unwrap());
+    }
+}
+
+#[test]
+fn it_round_trips_pretty_printed_queries() {
+    for query in QUERIES {
+        let result = parse(query).unwrap();
+        let formatted = result.deparse_with(&pretty()).unwrap();
+        assert_eq!(parse(&formatted).unwrap().deparse().unwrap(), result.deparse().unwrap());
+    }
+}
+
+#[test]
+fn it_breaks_clauses_across_lines() {
+    let result = parse(QUERIES[1]).unwrap();
+    let formatted = result.deparse_with(&pretty()).unwrap();
+    assert!(formatted.lines().count() > 5, "{formatted}");
+    assert!(formatted.lines().any(|l| l.starts_with("FROM")), "{formatted}");
+    assert!(formatted.lines().any(|l| l.starts_with("WHERE")), "{formatted}");
+    assert!(formatted.lines().any(|l| l.starts_with("    ")), "{formatted}");
+}
+
+#[test]
+fn it_uses_the_configured_indent() {
+    let result = parse(QUERIES[1]).unwrap();
+    let formatted = result.deparse_with(&DeparseOptions { indent: 2, ..pretty() }).unwrap();
+    assert!(formatted.lines().any(|l| l.starts_with("  ") && !l.starts_with("   ")), "{formatted}");
+}
+
+#[test]
+fn it_lowercases_keywords() {
+    for query in QUERIES {
+        let result = parse(query).unwrap();
+        let formatted = result.deparse_with(&DeparseOptions { keyword_case: KeywordCase::Lower, ..pretty() }).unwrap();
+        for token in scan(&formatted).unwrap().tokens {
+            if token.keyword_kind != KeywordKind::NoKeyword as i32 {
+                let text = &formatted[token.start as usize..token.end as usize];
+                assert_eq!(text, text.to_lowercase());
+            }
+        }
+        assert_eq!(parse(&formatted).unwrap().deparse().unwrap(), result.deparse().unwrap());
+    }
+
+    let result = parse(r#"SELECT "SELECT", 'FROM' FROM "Users""#).unwrap();
+    let formatted = result.deparse_with(&DeparseOptions { keyword_case: KeywordCase::Lower, ..DeparseOptions::default() }).unwrap();
+    assert_eq!(formatted, r#"select "SELECT", 'FROM' from "Users""#);
+}
+
+#[test]
+fn it_places_commas_at_the_start_of_lines() {
+    let result = parse(QUERIES[2]).unwrap();
+    let formatted = result.deparse_with(&DeparseOptions { trailing_commas: false, ..pretty() }).unwrap();
+    assert!(formatted.lines().any(|l| l.trim_start().starts_with(',')), "{formatted}");
+    assert!(!formatted.lines().any(|l| l.trim_end().ends_with(',')), "{formatted}");
+}
+
+#[test]
+fn it_adds_a_trailing_newline() {
+    let result = parse(QUERIES[0]).unwrap();
+    assert!(result.deparse_with(&DeparseOptions { trailing_newline: true, ..pretty() }).unwrap().ends_with('\n'));
+    assert!(!result.deparse_with(&pretty()).unwrap().ends_with('\n'));
+}
+
+#[test]
+fn it_extracts_comments() {
+    let sql = "-- header\nSELECT a, /* inline */ b\nFROM t; -- trailing";
+    let comments = deparse_comments_for_query(sql).unwrap();
+    assert_eq!(
+        comments,
+        vec![
+            DeparseComment { match_location: 10, newlines_before_comment: 0, newlines_after_comment: 1, text: "-- header".into() },
+            DeparseComment { match_location: 33, newlines_before_comment: 0, newlines_after_comment: 0, text: "/* inline */".into() },
+            DeparseComment { match_location: 54, newlines_before_comment: 0, newlines_after_comment: 0, text: "-- trailing".into() },
+        ]
+    );
+
+    assert!(deparse_comments_for_query("SELECT 1").unwrap().is_empty());
+}
+
+#[test]
+fn it_preserves_comments_when_formatting() {
+    let sql = "-- Create the users table
+CREATE TABLE users (
+    id bigint PRIMARY KEY, -- surrogate key
+    email text NOT NULL /* must be unique */
+);
+
+/* Backfill */
+INSERT INTO users (id, email) SELECT id, email FROM legacy_users WHERE active;";
+    for options in [DeparseOptions::default(), pretty(), DeparseOptions { keyword_case: KeywordCase::Lower, ..pretty() }] {
+        let formatted = format(sql, &options).unwrap();
+        for comment in ["-- Create the users table", "-- surrogate key", "/* must be unique */", "/* Backfill */"] {
+            assert!(formatted.contains(comment), "missing {comment} in {formatted}");
+        }
+        assert_eq!(parse(&formatted).unwrap().deparse().unwrap(), parse(sql).unwrap().deparse().unwrap());
+    }
+}
+
+#[test]
+fn it_drops_comments_without_format() {
+    let sql = "SELECT 1 -- one";
+    assert!(!parse(sql).unwrap().deparse_with(&pretty()).unwrap().contains("-- one"));
+}
@@ -0,0 +1,175 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, ColumnLineage, TableColumn};
+
+#[macro_use]
+mod support;
+
+fn lineage(query: &str) -> ColumnLineage {
+    let mut lineage = parse(query).unwrap().column_lineage();
+    assert_eq!(lineage.len(), 1);
+    lineage.remove(0)
+}
+
+fn names(columns: &[TableColumn]) -> Vec<String> {
+    columns
+        .iter()
+        .map(|c| {
+            let table = c.table.as_deref().unwrap_or("?");
+            match &c.schema {
+                Some(schema) => format!("{schema}.{table}.{}", c.column),
+                None => format!("{table}.{}", c.column),
+            }
+        })
+        .collect()
+}
+
+fn reads(query: &str) -> Vec<String> {
+    names(&lineage(query).reads)
+}
+
+fn writes(query: &str) -> Vec<String> {
+    names(&lineage(query).writes)
+}
+
+#[test]
+fn it_resolves_table_aliases() {
+    let query = "SELECT u.name, o.total FROM users u JOIN orders o ON o.user_id = u.id WHERE u.active ORDER BY o.created_at";
+    assert_eq!(reads(query), ["orders.created_at", "orders.total", "orders.user_id", "users.active", "users.id", "users.name"]);
+    assert!(writes(query).is_empty());
+}
+
+#[test]
+fn it_attributes_unqualified_columns() {
+    assert_eq!(reads("SELECT name FROM users WHERE id = $1 GROUP BY name HAVING count(*) > 1"), ["users.id", "users.name"]);
+    // Without a schema it's unknown which table has the column
+    assert_eq!(reads("SELECT name FROM users, orders WHERE orders.id = $1"), ["?.name", "orders.id"]);
+}
+
+#[test]
+fn it_resolves_schema_qualified_columns() {
+    assert_eq!(reads("SELECT app.users.name FROM app.users WHERE users.id = 1"), ["app.users.id", "app.users.name"]);
+}
+
+#[test]
+fn it_resolves_ctes_and_subqueries() {
+    let query = "WITH active AS (SELECT id, email AS mail FROM users WHERE active)
+                 SELECT a.mail, s.n FROM active a JOIN (SELECT user_id, count(*) AS n FROM logins GROUP BY user_id) s ON s.user_id = a.id";
+    assert_eq!(reads(query), ["logins.user_id", "users.active", "users.email", "users.id"]);
+}
+
+#[test]
+fn it_resolves_column_aliases_of_ctes() {
+    let query = "WITH t (a, b) AS (SELECT x, y + z FROM source) SELECT b FROM t WHERE a > 0";
+    assert_eq!(reads(query), ["source.x", "source.y", "source.z"]);
+}
+
+#[test]
+fn it_resolves_columns_through_stars() {
+    let query = "SELECT s.name FROM (SELECT * FROM app.users) s WHERE s.id = $1";
+    assert_eq!(reads(query), ["app.users.*", "app.users.id", "app.users.name"]);
+    assert_eq!(reads("SELECT * FROM users JOIN orders USING (id)"), ["orders.*", "orders.id", "users.*", "users.id"]);
+}
+
+#[test]
+fn it_resolves_output_names_in_order_by() {
+    let query = "SELECT customer_id, sum(amount) AS total FROM payments GROUP BY customer_id ORDER BY total DESC";
+    assert_eq!(reads(query), ["payments.amount", "payments.customer_id"]);
+}
+
+#[test]
+fn it_resolves_correlated_subqueries() {
+    let query = "SELECT name FROM users u WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = u.id)";
+    assert_eq!(reads(query), ["orders.user_id", "users.id", "users.name"]);
+}
+
+#[test]
+fn it_resolves_columns_of_lateral_subqueries() {
+    let query = "SELECT u.name, r.total FROM users u, LATERAL (SELECT sum(amount) AS total FROM orders o WHERE o.user_id = u.id) r";
+    assert_eq!(reads(query), ["orders.amount", "orders.user_id", "users.id", "users.name"]);
+}
+
+#[test]
+fn it_resolves_columns_of_recursive_ctes() {
+    let query = "WITH RECURSIVE tree (node, parent) AS (
+                     SELECT id, parent_id FROM nodes WHERE parent_id IS NULL
+                     UNION ALL
+                     SELECT n.id, n.parent_id FROM nodes n JOIN tree t ON n.parent_id = t.node
+                 )
+                 SELECT node FROM tree";
+    assert_eq!(reads(query), ["nodes.id", "nodes.parent_id"]);
+}
+
+#[test]
+fn it_resolves_ctes_shadowing_tables() {
+    let query = "WITH users AS (SELECT id, email AS name FROM accounts) SELECT name FROM users WHERE id = $1";
+    assert_eq!(reads(query), ["accounts.email", "accounts.id"]);
+    // The CTE isn't visible in its own query
+    let query = "WITH users AS (SELECT id, name FROM users WHERE active) SELECT name FROM users";
+    assert_eq!(reads(query), ["users.active", "users.id", "users.name"]);
+}
+
+#[test]
+fn it_finds_insert_columns() {
+    let query = "INSERT INTO archive (id, body) SELECT id, body FROM posts WHERE created_at < $1 RETURNING id";
+    assert_eq!(reads(query), ["archive.id", "posts.body", "posts.created_at", "posts.id"]);
+    assert_eq!(writes(query), ["archive.body", "archive.id"]);
+    assert_eq!(writes("INSERT INTO log VALUES (1, 'started')"), ["log.*"]);
+}
+
+#[test]
+fn it_finds_upsert_columns() {
+    let query = "INSERT INTO counters (key, n) VALUES ($1, 1) ON CONFLICT (key) DO UPDATE SET n = counters.n + excluded.n";
+    assert_eq!(reads(query), ["counters.key", "counters.n"]);
+    assert_eq!(writes(query), ["counters.key", "counters.n"]);
+}
+
+#[test]
+fn it_finds_update_columns() {
+    let query = "UPDATE accounts a SET balance = a.balance - t.amount, updated_at = now()
+                 FROM transfers t WHERE t.account_id = a.id RETURNING a.balance";
+    assert_eq!(reads(query), ["accounts.balance", "accounts.id", "transfers.account_id", "transfers.amount"]);
+    assert_eq!(writes(query), ["accounts.balance", "accounts.updated_at"]);
+}
+
+#[test]
+fn it_finds_delete_columns() {
+    let query = "DELETE FROM sessions s USING users u WHERE s.user_id = u.id AND u.banned";
+    assert_eq!(reads(query), ["sessions.user_id", "users.banned", "users.id"]);
+    assert!(writes(query).is_empty());
+}
+
+#[test]
+fn it_finds_merge_columns() {
+    let query = "MERGE INTO stock s USING deliveries d ON s.item = d.item
+                 WHEN MATCHED THEN UPDATE SET qty = s.qty + d.qty
+                 WHEN NOT MATCHED THEN INSERT (item, qty) VALUES (d.item, d.qty)";
+    assert_eq!(reads(query), ["deliveries.item", "deliveries.qty", "stock.item", "stock.qty"]);
+    assert_eq!(writes(query), ["stock.item", "stock.qty"]);
+}
+
+#[test]
+fn it_finds_columns_of_data_modifying_ctes() {
+    let query = "WITH moved AS (DELETE FROM inbox WHERE read RETURNING id, body) INSERT INTO archive (id, body) SELECT id, body FROM moved";
+    let lineage = lineage(query);
+    assert_eq!(lineage.statement_type, "InsertStmt");
+    assert_eq!(names(&lineage.reads), ["inbox.body", "inbox.id", "inbox.read"]);
+    assert_eq!(names(&lineage.writes), ["archive.body", "archive.id"]);
+}
+
+#[test]
+fn it_finds_columns_of_queries_in_utility_statements() {
+    assert_eq!(reads("CREATE VIEW active_users AS SELECT id, name FROM users WHERE active"), ["users.active", "users.id", "users.name"]);
+    assert_eq!(reads("EXPLAIN SELECT id FROM users"), ["users.id"]);
+}
+
+#[test]
+fn it_returns_lineage_per_statement() {
+    let lineage = parse("SELECT a FROM x; UPDATE y SET b = 1; CREATE TABLE z (c int)").unwrap().column_lineage();
+    let statement_types: Vec<&str> = lineage.iter().map(|l| l.statement_type).collect();
+    assert_eq!(statement_types, ["SelectStmt", "UpdateStmt", "CreateStmt"]);
+    assert_eq!(names(&lineage[0].reads), ["x.a"]);
+    assert_eq!(names(&lineage[1].writes), ["y.b"]);
+    assert!(lineage[2].reads.is_empty() && lineage[2].writes.is_empty());
+}
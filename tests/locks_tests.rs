@@ -0,0 +1,166 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, LockMode, LockMode::*};
+
+#[macro_use]
+mod support;
+
+fn locks(query: &str) -> Vec<(String, LockMode)> {
+    parse(query).unwrap().locks()
+}
+
+fn lock(relation: &str, mode: LockMode) -> (String, LockMode) {
+    (relation.to_string(), mode)
+}
+
+#[test]
+fn it_finds_locks_of_queries() {
+    assert_eq!(
+        locks("SELECT * FROM users u JOIN app.orders o ON o.user_id = u.id WHERE u.id IN (SELECT user_id FROM bans)"),
+        [lock("users", AccessShareLock), lock("app.orders", AccessShareLock), lock("bans", AccessShareLock)]
+    );
+    // CTE references aren't relations
+    assert_eq!(locks("WITH recent AS (SELECT * FROM orders) SELECT * FROM recent"), [lock("orders", AccessShareLock)]);
+    // The table created by SELECT INTO doesn't exist yet
+    assert_eq!(locks("SELECT * INTO archive FROM orders"), [lock("orders", AccessShareLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_locking_clauses() {
+    assert_eq!(locks("SELECT * FROM jobs WHERE state = 'new' FOR UPDATE SKIP LOCKED"), [lock("jobs", RowShareLock)]);
+    assert_eq!(
+        locks("SELECT * FROM jobs j JOIN workers w ON w.id = j.worker_id FOR SHARE OF j"),
+        [lock("jobs", RowShareLock), lock("workers", AccessShareLock)]
+    );
+    assert_eq!(locks("SELECT * FROM (SELECT * FROM jobs) s FOR NO KEY UPDATE OF s"), [lock("jobs", RowShareLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_dml() {
+    assert_eq!(
+        locks("UPDATE accounts SET balance = balance - t.amount FROM transfers t WHERE t.account_id = accounts.id"),
+        [lock("accounts", RowExclusiveLock), lock("transfers", AccessShareLock)]
+    );
+    assert_eq!(locks("INSERT INTO archive SELECT * FROM posts"), [lock("archive", RowExclusiveLock), lock("posts", AccessShareLock)]);
+    assert_eq!(
+        locks("WITH moved AS (DELETE FROM inbox RETURNING *) INSERT INTO archive SELECT * FROM moved"),
+        [lock("archive", RowExclusiveLock), lock("inbox", RowExclusiveLock)]
+    );
+    assert_eq!(
+        locks("MERGE INTO stock s USING deliveries d ON s.item = d.item WHEN MATCHED THEN DELETE"),
+        [lock("stock", RowExclusiveLock), lock("deliveries", AccessShareLock)]
+    );
+    assert_eq!(locks("COPY events FROM STDIN"), [lock("events", RowExclusiveLock)]);
+    assert_eq!(locks("COPY events TO STDOUT"), [lock("events", AccessShareLock)]);
+    assert_eq!(locks("EXPLAIN DELETE FROM events"), [lock("events", RowExclusiveLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_alter_table() {
+    assert_eq!(locks("ALTER TABLE users ADD COLUMN nickname text"), [lock("users", AccessExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE users ALTER COLUMN nickname SET STATISTICS 500"), [lock("users", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE users VALIDATE CONSTRAINT users_email_check"), [lock("users", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE users DISABLE TRIGGER audit"), [lock("users", ShareRowExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE users SET (fillfactor = 70, autovacuum_enabled = off)"), [lock("users", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("ALTER VIEW active_users SET (security_barrier = true)"), [lock("active_users", AccessExclusiveLock)]);
+    // The strongest level of all subcommands
+    assert_eq!(locks("ALTER TABLE users CLUSTER ON users_pkey, ALTER COLUMN email SET NOT NULL"), [lock("users", AccessExclusiveLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_constraints() {
+    assert_eq!(
+        locks("ALTER TABLE orders ADD FOREIGN KEY (user_id) REFERENCES users (id) NOT VALID"),
+        [lock("orders", ShareRowExclusiveLock), lock("users", ShareRowExclusiveLock)]
+    );
+    assert_eq!(
+        locks("ALTER TABLE orders ADD COLUMN coupon_id int REFERENCES coupons"),
+        [lock("orders", AccessExclusiveLock), lock("coupons", ShareRowExclusiveLock)]
+    );
+    assert_eq!(locks("ALTER TABLE orders ADD CONSTRAINT orders_total_check CHECK (total >= 0)"), [lock("orders", AccessExclusiveLock)]);
+    assert_eq!(
+        locks("CREATE TABLE orders (id int PRIMARY KEY, user_id int REFERENCES users, LIKE order_template)"),
+        [lock("users", ShareRowExclusiveLock), lock("order_template", AccessShareLock)]
+    );
+}
+
+#[test]
+fn it_finds_locks_of_partitions() {
+    assert_eq!(
+        locks("ALTER TABLE events ATTACH PARTITION events_2024 FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')"),
+        [lock("events", ShareUpdateExclusiveLock), lock("events_2024", AccessExclusiveLock)]
+    );
+    assert_eq!(
+        locks("ALTER TABLE events DETACH PARTITION events_2020"),
+        [lock("events", AccessExclusiveLock), lock("events_2020", AccessExclusiveLock)]
+    );
+    assert_eq!(
+        locks("ALTER TABLE events DETACH PARTITION events_2020 CONCURRENTLY"),
+        [lock("events", ShareUpdateExclusiveLock), lock("events_2020", ShareUpdateExclusiveLock)]
+    );
+    assert_eq!(
+        locks("CREATE TABLE events_2025 PARTITION OF events FOR VALUES FROM ('2025-01-01') TO ('2026-01-01')"),
+        [lock("events", AccessExclusiveLock)]
+    );
+    assert_eq!(locks("CREATE TABLE special_events () INHERITS (events)"), [lock("events", ShareUpdateExclusiveLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_indexes() {
+    assert_eq!(locks("CREATE INDEX ON users (email)"), [lock("users", ShareLock)]);
+    assert_eq!(locks("CREATE UNIQUE INDEX CONCURRENTLY users_email ON users (email)"), [lock("users", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("DROP INDEX users_email"), [lock("users_email", AccessExclusiveLock)]);
+    assert_eq!(locks("DROP INDEX CONCURRENTLY users_email"), [lock("users_email", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("ALTER INDEX users_email RENAME TO users_email_idx"), [lock("users_email", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("REINDEX TABLE users"), [lock("users", ShareLock)]);
+    assert_eq!(locks("REINDEX INDEX users_email"), [lock("users_email", AccessExclusiveLock)]);
+    assert_eq!(locks("REINDEX (VERBOSE) TABLE CONCURRENTLY users"), [lock("users", ShareUpdateExclusiveLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_maintenance() {
+    assert_eq!(locks("VACUUM users, orders"), [lock("users", ShareUpdateExclusiveLock), lock("orders", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("VACUUM FULL users"), [lock("users", AccessExclusiveLock)]);
+    assert_eq!(locks("VACUUM (FULL false, ANALYZE) users"), [lock("users", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("ANALYZE users"), [lock("users", ShareUpdateExclusiveLock)]);
+    assert!(locks("VACUUM").is_empty());
+    assert_eq!(locks("CLUSTER users USING users_pkey"), [lock("users", AccessExclusiveLock)]);
+    assert_eq!(locks("REFRESH MATERIALIZED VIEW daily_totals"), [lock("daily_totals", AccessExclusiveLock)]);
+    assert_eq!(locks("REFRESH MATERIALIZED VIEW CONCURRENTLY daily_totals"), [lock("daily_totals", ExclusiveLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_lock_table() {
+    assert_eq!(locks("LOCK TABLE users"), [lock("users", AccessExclusiveLock)]);
+    assert_eq!(
+        locks("LOCK TABLE users, orders IN SHARE ROW EXCLUSIVE MODE"),
+        [lock("users", ShareRowExclusiveLock), lock("orders", ShareRowExclusiveLock)]
+    );
+    assert_eq!(locks("LOCK app.users IN ROW SHARE MODE NOWAIT"), [lock("app.users", RowShareLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_other_ddl() {
+    assert_eq!(locks("DROP TABLE users, app.orders"), [lock("users", AccessExclusiveLock), lock("app.orders", AccessExclusiveLock)]);
+    assert_eq!(locks("DROP TRIGGER audit ON app.users"), [lock("app.users", AccessExclusiveLock)]);
+    assert_eq!(locks("TRUNCATE users"), [lock("users", AccessExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE users RENAME COLUMN name TO full_name"), [lock("users", AccessExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE users SET SCHEMA archive"), [lock("users", AccessExclusiveLock)]);
+    assert_eq!(locks("CREATE TRIGGER audit AFTER INSERT ON users FOR EACH ROW EXECUTE FUNCTION audit()"), [lock("users", ShareRowExclusiveLock)]);
+    assert_eq!(locks("CREATE POLICY own_rows ON users USING (id = current_user_id())"), [lock("users", AccessExclusiveLock)]);
+    assert_eq!(locks("ALTER SEQUENCE users_id_seq RESTART"), [lock("users_id_seq", ShareRowExclusiveLock)]);
+    assert_eq!(
+        locks("CREATE OR REPLACE VIEW active_users AS SELECT * FROM users"),
+        [lock("active_users", AccessExclusiveLock), lock("users", AccessShareLock)]
+    );
+    assert!(locks("CREATE TABLE users (id int)").is_empty());
+}
+
+#[test]
+fn it_lists_locks_once_per_mode() {
+    assert_eq!(
+        locks("LOCK users IN SHARE MODE; SELECT * FROM users; UPDATE users SET active = false; SELECT count(*) FROM users"),
+        [lock("users", ShareLock), lock("users", AccessShareLock), lock("users", RowExclusiveLock)]
+    );
+}
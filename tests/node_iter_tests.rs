@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, NodeEnum, NodeRef};
+
+#[macro_use]
+mod support;
+
+fn describe(node: NodeRef) -> String {
+    match node {
+        NodeRef::SelectStmt(_) => "SelectStmt".to_string(),
+        NodeRef::ResTarget(_) => "ResTarget".to_string(),
+        NodeRef::ColumnRef(_) => "ColumnRef".to_string(),
+        NodeRef::RangeVar(r) => format!("RangeVar({})", r.relname),
+        NodeRef::AExpr(_) => "AExpr".to_string(),
+        NodeRef::AConst(_) => "AConst".to_string(),
+        NodeRef::String(s) => format!("String({})", s.sval),
+        NodeRef::Integer(i) => format!("Integer({})", i.ival),
+        _ => "other".to_string(),
+    }
+}
+
+#[test]
+fn it_iterates_depth_first() {
+    let result = parse("SELECT a FROM t WHERE b = 1").unwrap();
+    let nodes: Vec<String> = result.protobuf.iter().map(describe).collect();
+    assert_eq!(
+        nodes,
+        ["SelectStmt", "ResTarget", "ColumnRef", "String(a)", "RangeVar(t)", "AExpr", "String(=)", "ColumnRef", "String(b)", "AConst", "Integer(1)"]
+    );
+}
+
+#[test]
+fn it_iterates_breadth_first() {
+    let result = parse("SELECT a FROM t WHERE b = 1").unwrap();
+    let nodes: Vec<String> = result.protobuf.iter_breadth_first().map(describe).collect();
+    assert_eq!(
+        nodes,
+        ["SelectStmt", "ResTarget", "RangeVar(t)", "AExpr", "ColumnRef", "String(=)", "ColumnRef", "AConst", "String(a)", "String(b)", "Integer(1)"]
+    );
+}
+
+#[test]
+fn it_iterates_over_multiple_statements() {
+    let result = parse("SELECT 1 FROM a; UPDATE b SET c = 2; DELETE FROM d").unwrap();
+    let tables: Vec<String> = result.protobuf.iter().filter(|n| matches!(n, NodeRef::RangeVar(_))).map(describe).collect();
+    assert_eq!(tables, ["RangeVar(a)", "RangeVar(b)", "RangeVar(d)"]);
+    let tables: Vec<String> = result.protobuf.iter_breadth_first().filter(|n| matches!(n, NodeRef::RangeVar(_))).map(describe).collect();
+    assert_eq!(tables, ["RangeVar(a)", "RangeVar(b)", "RangeVar(d)"]);
+}
+
+#[test]
+fn it_yields_every_node_type() {
+    // nodes() skips node types it doesn't need, such as the RETURNING list of a DELETE
+    let result = parse("DELETE FROM t RETURNING lower(email)").unwrap();
+    assert!(!result.protobuf.nodes().iter().any(|(n, ..)| matches!(n, NodeRef::FuncCall(_))));
+    let nodes: Vec<String> = result.protobuf.iter().map(describe).filter(|n| n != "other").collect();
+    assert_eq!(nodes, ["RangeVar(t)", "ResTarget", "String(lower)", "ColumnRef", "String(email)"]);
+    assert!(result.protobuf.iter().any(|n| matches!(n, NodeRef::FuncCall(_))));
+}
+
+#[test]
+fn it_iterates_from_any_node() {
+    let expr = pg_query::parse_expr("a + (b * 2)").unwrap();
+    assert!(matches!(expr, NodeEnum::AExpr(_)));
+    let nodes: Vec<String> = expr.iter().skip(1).filter(|n| matches!(n, NodeRef::ColumnRef(_) | NodeRef::Integer(_))).map(describe).collect();
+    assert_eq!(nodes, ["ColumnRef", "ColumnRef", "Integer(2)"]);
+    let count = expr.to_ref().iter_breadth_first().count();
+    assert_eq!(count, expr.iter().count());
+}
+
+#[test]
+fn it_stops_early() {
+    let mut query = "SELECT * FROM t".to_string();
+    for i in 0..500 {
+        query = format!("{query} JOIN t{i} ON t.id = t{i}.t_id");
+    }
+    let result = parse(&query).unwrap();
+    let mut iter = result.protobuf.iter_breadth_first();
+    let first = iter.find(|n| matches!(n, NodeRef::RangeVar(_)));
+    assert!(first.is_some());
+    // Only part of the tree has been visited, so plenty of nodes are left
+    assert!(iter.count() > 1000);
+}
@@ -0,0 +1,163 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, parse_expr, FieldPath, NodeEnum, NodePath, NodeRef, PathStep};
+
+#[macro_use]
+mod support;
+
+fn column_paths(query: &str) -> Vec<(String, String)> {
+    let result = parse(query).unwrap();
+    result
+        .protobuf
+        .iter_with_paths()
+        .filter(|(node, _)| matches!(node, NodeRef::ColumnRef(_)))
+        .map(|(node, path)| (node.deparse().unwrap(), path.to_string()))
+        .collect()
+}
+
+#[test]
+fn it_yields_paths_to_nodes() {
+    let paths = column_paths("SELECT * FROM t WHERE a = 1 AND b > c");
+    assert_eq!(
+        paths,
+        [
+            ("*".to_string(), "SelectStmt.target_list[0] → ResTarget.val".to_string()),
+            ("a".to_string(), "SelectStmt.where_clause → BoolExpr.args[0] → AExpr.lexpr".to_string()),
+            ("b".to_string(), "SelectStmt.where_clause → BoolExpr.args[1] → AExpr.lexpr".to_string()),
+            ("c".to_string(), "SelectStmt.where_clause → BoolExpr.args[1] → AExpr.rexpr".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn it_identifies_clauses() {
+    let paths = column_paths("SELECT t.a FROM t JOIN u ON t.id = u.t_id GROUP BY t.a");
+    assert_eq!(paths[0].1, "SelectStmt.target_list[0] → ResTarget.val");
+    assert_eq!(paths[1], ("t.id".to_string(), "SelectStmt.from_clause[0] → JoinExpr.quals → AExpr.lexpr".to_string()));
+    assert_eq!(paths[2], ("u.t_id".to_string(), "SelectStmt.from_clause[0] → JoinExpr.quals → AExpr.rexpr".to_string()));
+    assert_eq!(paths[3], ("t.a".to_string(), "SelectStmt.group_clause[0]".to_string()));
+
+    let paths = column_paths("UPDATE t SET a = 1 WHERE b = 2 RETURNING c");
+    assert_eq!(
+        paths,
+        [
+            ("b".to_string(), "UpdateStmt.where_clause → AExpr.lexpr".to_string()),
+            ("c".to_string(), "UpdateStmt.returning_list[0] → ResTarget.val".to_string())
+        ]
+    );
+}
+
+#[test]
+fn it_exposes_ancestors() {
+    let result = parse("SELECT a FROM t WHERE b IN (SELECT c FROM u WHERE d = 1)").unwrap();
+    let (_, path) =
+        result.protobuf.iter_with_paths().find(|(node, _)| matches!(node, NodeRef::ColumnRef(_)) && node.deparse().unwrap() == "d").unwrap();
+
+    // The closest SELECT is the subquery, not the outer statement
+    let owner = path.ancestors.iter().rev().find_map(|(node, _)| match node {
+        NodeRef::SelectStmt(s) => Some(s),
+        _ => None,
+    });
+    assert_eq!(owner.unwrap().from_clause[0].deparse().unwrap(), "u");
+    assert_eq!(path.parent().unwrap().type_name(), "AExpr");
+    assert_eq!(path.last_step(), Some(PathStep { node_type: "AExpr", field: "lexpr", index: None }));
+    assert_eq!(path.depth(), 4);
+    assert!(path.is_within("SubLink", "subselect"));
+    assert!(!path.is_within("SelectStmt", "target_list"));
+}
+
+#[test]
+fn it_tracks_statements() {
+    let result = parse("SELECT a FROM t; DELETE FROM u WHERE b = 1").unwrap();
+    let statements: Vec<(usize, &str)> = result
+        .protobuf
+        .iter_with_paths()
+        .filter_map(|(node, path)| match node {
+            NodeRef::RangeVar(r) => Some((path.statement, r.relname.as_str())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(statements, [(0, "t"), (1, "u")]);
+}
+
+#[test]
+fn it_resolves_paths() {
+    let query = "WITH x AS (SELECT a FROM t) SELECT coalesce(a, 1) FROM x WHERE b IN (SELECT c FROM u) ORDER BY 1";
+    let result = parse(query).unwrap();
+    for (node, path) in result.protobuf.iter_with_paths() {
+        let resolved = result.protobuf.resolve(&path.to_field_path()).unwrap();
+        assert_eq!(resolved.type_name(), node.type_name());
+        assert_eq!(format!("{resolved:?}"), format!("{node:?}"));
+    }
+
+    // Paths only hold field names, so they can be resolved in another copy of the tree
+    let paths: Vec<FieldPath> = result.protobuf.iter_with_paths().map(|(_, path)| path.to_field_path()).collect();
+    let reparsed = parse(query).unwrap();
+    assert!(paths.iter().all(|path| reparsed.protobuf.resolve(path).is_some()));
+}
+
+#[test]
+fn it_rejects_invalid_paths() {
+    let result = parse("SELECT a FROM t").unwrap();
+    let step = |node_type, field, index| PathStep { node_type, field, index };
+
+    let path = FieldPath { statement: 0, steps: vec![step("SelectStmt", "from_clause", Some(0))] };
+    assert!(matches!(result.protobuf.resolve(&path), Some(NodeRef::RangeVar(_))));
+
+    let path = FieldPath { statement: 0, steps: vec![step("SelectStmt", "from_clause", Some(1))] };
+    assert!(result.protobuf.resolve(&path).is_none());
+    let path = FieldPath { statement: 0, steps: vec![step("SelectStmt", "from_clause", None)] };
+    assert!(result.protobuf.resolve(&path).is_none());
+    let path = FieldPath { statement: 0, steps: vec![step("UpdateStmt", "from_clause", Some(0))] };
+    assert!(result.protobuf.resolve(&path).is_none());
+    let path = FieldPath { statement: 0, steps: vec![step("SelectStmt", "where_clause", None)] };
+    assert!(result.protobuf.resolve(&path).is_none());
+    let path = FieldPath { statement: 1, steps: vec![] };
+    assert!(result.protobuf.resolve(&path).is_none());
+}
+
+#[test]
+fn it_rewrites_resolved_nodes() {
+    let mut result = parse("SELECT a FROM t WHERE b IN (SELECT c FROM u WHERE d = 1)").unwrap();
+    let path = result
+        .protobuf
+        .iter_with_paths()
+        .find(|(node, path)| matches!(node, NodeRef::RangeVar(_)) && path.is_within("SubLink", "subselect"))
+        .map(|(_, path)| path.to_field_path())
+        .unwrap();
+    assert_eq!(path.to_string(), "SelectStmt.where_clause → SubLink.subselect → SelectStmt.from_clause[0]");
+
+    let Some(NodeEnum::RangeVar(range_var)) = result.protobuf.resolve_mut(&path) else { panic!("expected a RangeVar") };
+    range_var.relname = "v".to_string();
+    assert_eq!(result.protobuf.deparse().unwrap(), "SELECT a FROM t WHERE b IN (SELECT c FROM v WHERE d = 1)");
+}
+
+#[test]
+fn it_resolves_nodes_for_modification_through_typed_fields() {
+    let mut result = parse("WITH x AS (SELECT a FROM t) SELECT * FROM x").unwrap();
+    let paths: Vec<FieldPath> = result.protobuf.iter_with_paths().map(|(_, path)| path.to_field_path()).collect();
+    let path = paths.iter().find(|path| path.to_string().ends_with("CommonTableExpr.ctequery → SelectStmt.from_clause[0]")).unwrap();
+    assert_eq!(path.to_string(), "SelectStmt.with_clause → WithClause.ctes[0] → CommonTableExpr.ctequery → SelectStmt.from_clause[0]");
+
+    let Some(NodeEnum::RangeVar(range_var)) = result.protobuf.resolve_mut(path) else { panic!("expected a RangeVar") };
+    range_var.relname = "u".to_string();
+    assert_eq!(result.protobuf.deparse().unwrap(), "WITH x AS (SELECT a FROM u) SELECT * FROM x");
+
+    // The WITH clause itself is not stored as a node
+    let with_clause = FieldPath { steps: path.steps[..1].to_vec(), ..path.clone() };
+    assert!(result.protobuf.resolve(&with_clause).is_some());
+    assert!(result.protobuf.resolve_mut(&with_clause).is_none());
+}
+
+#[test]
+fn it_yields_paths_from_any_node() {
+    let expr = parse_expr("a + b * 2").unwrap();
+    let paths: Vec<(&str, NodePath)> = expr.iter_with_paths().map(|(node, path)| (node.type_name(), path)).collect();
+    assert_eq!(paths[0].0, "AExpr");
+    assert_eq!(paths[0].1.depth(), 0);
+    assert_eq!(paths[0].1.to_string(), "");
+    let (_, path) = paths.iter().find(|(node_type, _)| *node_type == "Integer").unwrap();
+    assert_eq!(path.to_string(), "AExpr.rexpr → AExpr.rexpr → AConst.val");
+    assert!(matches!(expr.resolve(&path.to_field_path()), Some(NodeRef::Integer(i)) if i.ival == 2));
+}
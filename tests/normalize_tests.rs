@@ -1,7 +1,9 @@
 #![allow(non_snake_case)]
 #![cfg(test)]
 
-use pg_query::{normalize, Error};
+use pg_query::{
+    fingerprint, normalize, normalize_statements, normalize_with_options, normalize_with_params, LiteralKind, NormalizeOptions, NormalizedParam,
+};
 
 #[test]
 fn it_normalizes_simple_query() {
@@ -18,7 +20,7 @@ fn it_normalizes_IN() {
 #[test]
 fn it_errors_on_invalid_input() {
     let error = normalize("CREATE RANDOM ix_test ON contacts.person;").err().unwrap();
-    assert_eq!(error, Error::Parse("syntax error at or near \"RANDOM\"".into()));
+    assert_eq!(error.to_string(), "Invalid statement: syntax error at or near \"RANDOM\"");
 }
 
 #[test]
@@ -83,3 +85,121 @@ fn it_normalizes_DECLARE_CURSON() {
     let result = normalize("DECLARE cursor_b CURSOR FOR SELECT * FROM databases WHERE id = 23").unwrap();
     assert_eq!(result, "DECLARE cursor_b CURSOR FOR SELECT * FROM databases WHERE id = $1");
 }
+
+#[test]
+fn it_normalizes_statements_separately() {
+    let query = "INSERT INTO t (a) VALUES (1); UPDATE t SET a = 2 WHERE b = 'x';";
+    let statements = normalize_statements(query).unwrap();
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[0].normalized, "INSERT INTO t (a) VALUES ($1)");
+    assert_eq!(statements[0].range, 0..28);
+    assert_eq!(statements[0].statement_type, "InsertStmt");
+    assert_eq!(statements[1].normalized, "UPDATE t SET a = $1 WHERE b = $2");
+    assert_eq!(&query[statements[1].range.clone()], "UPDATE t SET a = 2 WHERE b = 'x'");
+    assert_eq!(statements[1].statement_type, "UpdateStmt");
+    assert_eq!(statements[1].fingerprint, fingerprint("UPDATE t SET a = 2 WHERE b = 'x'").unwrap());
+}
+
+#[test]
+fn it_errors_on_invalid_statements() {
+    assert!(normalize_statements("SELECT 1; SELEC 2").is_err());
+}
+
+#[test]
+fn it_returns_replaced_constants() {
+    let query = "SELECT 'a''b', 42, -7, 1.5e3, B'101', X'1F', true, NULL FROM t WHERE x = $$y$$";
+    let result = normalize_with_params(query).unwrap();
+    assert_eq!(result.normalized, "SELECT $1, $2, $3, $4, $5, $6, $7, $8 FROM t WHERE x = $9");
+    let params: Vec<_> = result.params.iter().map(|p| (p.number, p.text.as_str(), p.kind)).collect();
+    assert_eq!(
+        params,
+        [
+            (1, "'a''b'", LiteralKind::String),
+            (2, "42", LiteralKind::Integer),
+            (3, "-7", LiteralKind::Integer),
+            (4, "1.5e3", LiteralKind::Float),
+            (5, "B'101'", LiteralKind::BitString),
+            (6, "X'1F'", LiteralKind::BitString),
+            (7, "true", LiteralKind::Boolean),
+            (8, "NULL", LiteralKind::Null),
+            (9, "$$y$$", LiteralKind::String),
+        ]
+    );
+    for param in &result.params {
+        assert_eq!(&query[param.location..param.location + param.text.len()], param.text);
+    }
+}
+
+#[test]
+fn it_numbers_constants_after_existing_params() {
+    let result = normalize_with_params("UPDATE t SET a = $2, b = 'x' WHERE c = $1 AND d = 3 - 1").unwrap();
+    assert_eq!(result.normalized, "UPDATE t SET a = $2, b = $3 WHERE c = $1 AND d = $4 - $5");
+    assert_eq!(
+        result.params,
+        [
+            NormalizedParam { number: 3, text: "'x'".into(), location: 25, kind: LiteralKind::String },
+            NormalizedParam { number: 4, text: "3".into(), location: 50, kind: LiteralKind::Integer },
+            NormalizedParam { number: 5, text: "1".into(), location: 54, kind: LiteralKind::Integer },
+        ]
+    );
+}
+
+#[test]
+fn it_returns_no_params_without_constants() {
+    let result = normalize_with_params("SELECT a FROM t -- 1\nWHERE b = $1").unwrap();
+    assert_eq!(result.normalized, "SELECT a FROM t -- 1\nWHERE b = $1");
+    assert!(result.params.is_empty());
+}
+
+#[test]
+fn it_collapses_lists() {
+    let options = NormalizeOptions { collapse_lists: true, ..NormalizeOptions::default() };
+    let a = "SELECT * FROM t WHERE a IN (1, 2, 3) AND b = ANY(ARRAY[-1, 2]) AND c NOT IN ('x', 'y'::text)";
+    let b = "SELECT * FROM t WHERE a IN (4, 5) AND b = ANY(ARRAY[6, 7, 8]) AND c NOT IN ('z', 'w')";
+    let expected = "SELECT * FROM t WHERE a IN ($1 /*, ... */) AND b = ANY(ARRAY[$2 /*, ... */]) AND c NOT IN ($3 /*, ... */)";
+    for query in [a, b] {
+        let normalized = normalize_with_options(query, &options).unwrap();
+        assert_eq!(normalized, expected);
+        assert_eq!(fingerprint(&normalized).unwrap(), fingerprint(query).unwrap());
+    }
+
+    // A single item is collapsed the same as several
+    let a = "SELECT * FROM t WHERE a IN (1) AND b = ANY(ARRAY['x'])";
+    let b = "SELECT * FROM t WHERE a IN (1, 2, 3) AND b = ANY(ARRAY['x', 'y'])";
+    let expected = "SELECT * FROM t WHERE a IN ($1 /*, ... */) AND b = ANY(ARRAY[$2 /*, ... */])";
+    assert_eq!(normalize_with_options(a, &options).unwrap(), expected);
+    assert_eq!(normalize_with_options(b, &options).unwrap(), expected);
+
+    // Placeholders follow the parameters the query already has
+    let query = "SELECT * FROM t WHERE a IN ($1, 2) AND b = 'x'";
+    assert_eq!(normalize_with_options(query, &options).unwrap(), "SELECT * FROM t WHERE a IN ($1 /*, ... */) AND b = $2");
+
+    // Lists of anything but constants are kept
+    let query = "SELECT * FROM t WHERE a IN (b, 2) AND c IN (SELECT 1)";
+    assert_eq!(normalize_with_options(query, &options).unwrap(), "SELECT * FROM t WHERE a IN (b, $1) AND c IN (SELECT $2)");
+}
+
+#[test]
+fn it_collapses_values() {
+    let options = NormalizeOptions { collapse_values: true, ..NormalizeOptions::default() };
+    let query = "INSERT INTO t (a, b) VALUES (1, 'x'), (2, 'y') , (3, 'z') RETURNING id";
+    let normalized = normalize_with_options(query, &options).unwrap();
+    assert_eq!(normalized, "INSERT INTO t (a, b) VALUES ($1, $2) /*, ... */ RETURNING id");
+    assert_eq!(fingerprint(&normalized).unwrap(), fingerprint(query).unwrap());
+    let query = "INSERT INTO t (a, b) VALUES (1, 'x') RETURNING id";
+    assert_eq!(normalize_with_options(query, &options).unwrap(), normalized);
+
+    // Rows that differ in more than their constants are kept
+    let query = "INSERT INTO t (a, b) VALUES (1, now()), (2, 'y')";
+    assert_eq!(normalize_with_options(query, &options).unwrap(), "INSERT INTO t (a, b) VALUES ($1, now()), ($2, $3)");
+}
+
+#[test]
+fn it_keeps_limits_and_booleans() {
+    let query = "SELECT * FROM t WHERE a = true AND b = 'x' LIMIT 10 OFFSET 20";
+    let options = NormalizeOptions { keep_limit: true, ..NormalizeOptions::default() };
+    assert_eq!(normalize_with_options(query, &options).unwrap(), "SELECT * FROM t WHERE a = $1 AND b = $2 LIMIT 10 OFFSET 20");
+    let options = NormalizeOptions { keep_booleans: true, ..NormalizeOptions::default() };
+    assert_eq!(normalize_with_options(query, &options).unwrap(), "SELECT * FROM t WHERE a = true AND b = $1 LIMIT $2 OFFSET $3");
+    assert_eq!(normalize_with_options(query, &NormalizeOptions::default()).unwrap(), normalize(query).unwrap());
+}
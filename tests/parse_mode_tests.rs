@@ -0,0 +1,95 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse_expr, parse_type_name, parse_with_options, protobuf, Error, NodeEnum, ParseMode, ParseOptions};
+
+#[macro_use]
+mod support;
+
+fn names(nodes: &[protobuf::Node]) -> Vec<&str> {
+    nodes
+        .iter()
+        .filter_map(|n| match &n.node {
+            Some(NodeEnum::String(s)) => Some(s.sval.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn it_parses_type_names() {
+    let type_name = parse_type_name("varchar(20)[]").unwrap();
+    assert_eq!(names(&type_name.names), ["pg_catalog", "varchar"]);
+    assert_eq!(type_name.typmods.len(), 1);
+    assert_eq!(type_name.array_bounds.len(), 1);
+
+    let type_name = parse_type_name("public.my_enum").unwrap();
+    assert_eq!(names(&type_name.names), ["public", "my_enum"]);
+    assert!(type_name.typmods.is_empty());
+
+    let type_name = parse_type_name("timestamp with time zone").unwrap();
+    assert_eq!(names(&type_name.names), ["pg_catalog", "timestamptz"]);
+}
+
+#[test]
+fn it_rejects_invalid_type_names() {
+    let error = parse_type_name("varchar(").err().unwrap();
+    assert_eq!(error.to_string(), "Invalid statement: syntax error at end of input");
+
+    let error = parse_type_name("SELECT 1").err().unwrap();
+    assert!(matches!(error, Error::Parse(_)));
+}
+
+#[test]
+fn it_parses_expressions() {
+    let expr = parse_expr("a + 1 > $1").unwrap();
+    let NodeEnum::AExpr(expr) = expr else { panic!("expected an AExpr") };
+    assert_eq!(names(&expr.name), [">"]);
+    let Some(NodeEnum::AExpr(lexpr)) = expr.lexpr.and_then(|n| n.node) else { panic!("expected an AExpr") };
+    assert_eq!(names(&lexpr.name), ["+"]);
+    assert!(matches!(expr.rexpr.and_then(|n| n.node), Some(NodeEnum::ParamRef(p)) if p.number == 1));
+
+    let expr = parse_expr("status = 'active' AND deleted_at IS NULL").unwrap();
+    assert!(matches!(expr, NodeEnum::BoolExpr(_)));
+
+    let expr = parse_expr("lower(email)").unwrap();
+    assert!(matches!(expr, NodeEnum::FuncCall(_)));
+}
+
+#[test]
+fn it_rejects_non_expressions() {
+    let error = parse_expr("a, b").err().unwrap();
+    assert_eq!(error.to_string(), "Invalid statement: expected a single expression");
+
+    let error = parse_expr("a FROM t").err().unwrap();
+    assert_eq!(error.to_string(), "Invalid statement: expected a single expression");
+
+    // Clauses that aren't part of the expression aren't silently dropped
+    for expr in ["1 LIMIT 5", "1 OFFSET 1", "a ORDER BY a", "1 FOR UPDATE", "DISTINCT a", "sum(a) OVER w WINDOW w AS ()"] {
+        let error = parse_expr(expr).err().unwrap();
+        assert_eq!(error.to_string(), "Invalid statement: expected a single expression");
+    }
+
+    let error = parse_expr("a +").err().unwrap();
+    assert_eq!(error.to_string(), "Invalid statement: syntax error at end of input");
+}
+
+#[test]
+fn it_parses_plpgsql_assignments() {
+    let options = ParseOptions { mode: ParseMode::PlpgsqlAssign2, ..ParseOptions::default() };
+    let result = parse_with_options("rec.total := rec.total + 1", options).unwrap();
+    let stmt = result.protobuf.stmts[0].stmt.as_ref().and_then(|n| n.node.as_ref());
+    let Some(NodeEnum::PlassignStmt(stmt)) = stmt else { panic!("expected a PLAssignStmt") };
+    assert_eq!(stmt.name, "rec");
+    assert_eq!(stmt.nnames, 2);
+}
+
+#[test]
+fn it_parses_with_string_options() {
+    let query = "SELECT 'a\\'b'";
+    let options = ParseOptions { disable_standard_conforming_strings: true, ..ParseOptions::default() };
+    let result = parse_with_options(query, options).unwrap();
+    assert_eq!(result.deparse().unwrap(), "SELECT 'a''b'");
+
+    assert!(parse_with_options(query, ParseOptions::default()).is_err());
+}
@@ -24,10 +24,33 @@ fn it_parses_simple_query() {
 #[test]
 fn it_handles_errors() {
     let error = parse("CREATE RANDOM ix_test ON contacts.person;").err().unwrap();
-    assert_eq!(error, Error::Parse("syntax error at or near \"RANDOM\"".into()));
+    assert_eq!(error.to_string(), "Invalid statement: syntax error at or near \"RANDOM\"");
 
     let error = parse("SELECT 'ERR").err().unwrap();
-    assert_eq!(error, Error::Parse("unterminated quoted string at or near \"'ERR\"".into()));
+    assert_eq!(error.to_string(), "Invalid statement: unterminated quoted string at or near \"'ERR\"");
+}
+
+#[test]
+fn it_reports_error_positions() {
+    let Error::Parse(error) = parse("SELECT 1;\nSELECT * FROM café WHERE x = 'ERR").err().unwrap() else { panic!("expected a parse error") };
+    assert_eq!(error.message, "unterminated quoted string at or near \"'ERR\"");
+    assert_eq!(error.sqlstate, Some("42601"));
+    assert_eq!(error.cursorpos, 40);
+    let position = error.position.unwrap();
+    assert_eq!(position.char_offset, 39);
+    assert_eq!(position.byte_offset, 40);
+    assert_eq!((position.line, position.column), (2, 30));
+    assert_eq!(error.snippet.unwrap(), "LINE 2: SELECT * FROM café WHERE x = 'ERR\n                                     ^");
+
+    // Other errors raised by the grammar have various codes, here 0A000, which libpg_query doesn't report
+    let Error::Parse(error) = parse("SELECT * FROM t LIMIT 10, 20").err().unwrap() else { panic!("expected a parse error") };
+    assert_eq!(error.message, "LIMIT #,# syntax is not supported");
+    assert_eq!(error.filename.as_deref(), Some("gram.y"));
+    assert_eq!(error.sqlstate, None);
+
+    let Error::Scan(error) = pg_query::scan("SELECT $$ERR").err().unwrap() else { panic!("expected a scan error") };
+    assert_eq!(error.message, "unterminated dollar-quoted string at or near \"$$ERR\"");
+    assert_eq!(error.position.map(|p| p.byte_offset), Some(7));
 }
 
 #[test]
@@ -454,7 +477,7 @@ fn it_parses_CREATE_TABLE_AS() {
 #[test]
 fn it_fails_to_parse_CREATE_TABLE_WITH_OIDS() {
     let error = parse("CREATE TABLE test (a int4) WITH OIDS").err().unwrap();
-    assert_eq!(error, Error::Parse("syntax error at or near \"OIDS\"".to_string()));
+    assert_eq!(error.to_string(), "Invalid statement: syntax error at or near \"OIDS\"");
 }
 
 #[test]
@@ -1618,6 +1641,66 @@ fn it_correctly_categorizes_CTEs_after_INTERSECT_SELECT() {
     assert_eq!(result.statement_types(), ["SelectStmt"]);
 }
 
+#[test]
+fn it_finds_tables_shadowed_by_a_CTE_of_the_same_name() {
+    // The CTE isn't visible in its own query, so the inner `f` is the table
+    let result = parse("with f as (select * from f limit 1) select * from f").unwrap();
+    assert_eq!(result.tables(), ["f"]);
+    assert_eq!(result.select_tables(), ["f"]);
+    assert_eq!(result.cte_names, ["f"]);
+
+    // Neither are later CTEs of the same WITH
+    let result = parse("WITH a AS (SELECT * FROM b), b AS (SELECT * FROM c) SELECT * FROM a, b").unwrap();
+    let tables: Vec<String> = sorted(result.tables()).collect();
+    assert_eq!(tables, ["b", "c"]);
+
+    // Schema-qualified names always refer to tables
+    let result = parse("WITH users AS (SELECT 1) SELECT * FROM public.users, users").unwrap();
+    assert_eq!(result.tables(), ["public.users"]);
+
+    // As do the targets of DML statements
+    let result = parse("WITH t AS (SELECT 1) DELETE FROM t").unwrap();
+    assert_eq!(result.dml_tables(), ["t"]);
+}
+
+#[test]
+fn it_finds_tables_in_recursive_CTEs() {
+    let sql = "
+        WITH RECURSIVE tree AS (
+            SELECT id, parent_id FROM nodes WHERE parent_id IS NULL
+            UNION ALL
+            SELECT n.id, n.parent_id FROM nodes n JOIN tree t ON n.parent_id = t.id
+        )
+        SELECT * FROM tree
+    ";
+    let result = parse(sql).unwrap();
+    assert_eq!(result.tables(), ["nodes"]);
+    assert_eq!(result.cte_names, ["tree"]);
+
+    // With RECURSIVE, all CTEs of the list are visible in each other
+    let result = parse("WITH RECURSIVE a AS (SELECT * FROM b), b AS (SELECT 1) SELECT * FROM a").unwrap();
+    assert!(result.tables().is_empty());
+}
+
+#[test]
+fn it_scopes_CTEs_to_their_query() {
+    // The CTE of the subquery isn't visible outside of it
+    let result = parse("SELECT * FROM (WITH x AS (SELECT * FROM t) SELECT * FROM x) s, x").unwrap();
+    let tables: Vec<String> = sorted(result.tables()).collect();
+    assert_eq!(tables, ["t", "x"]);
+
+    // A nested CTE shadows an outer one of the same name
+    let sql = "WITH x AS (SELECT * FROM a) SELECT * FROM (WITH x AS (SELECT * FROM b) SELECT * FROM x) s JOIN x ON true";
+    let result = parse(sql).unwrap();
+    let tables: Vec<String> = sorted(result.tables()).collect();
+    assert_eq!(tables, ["a", "b"]);
+
+    // CTEs are visible in subqueries, including those in expressions
+    let result = parse("WITH x AS (SELECT * FROM a) SELECT * FROM b WHERE id IN (SELECT id FROM x)").unwrap();
+    let tables: Vec<String> = sorted(result.tables()).collect();
+    assert_eq!(tables, ["a", "b"]);
+}
+
 #[test]
 fn it_finds_tables_inside_subselectes_in_MIN_MAX_COALESCE() {
     let sql = "
@@ -0,0 +1,74 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{redact_secrets, SecretKind, Span};
+
+#[macro_use]
+mod support;
+
+fn redact(query: &str) -> String {
+    redact_secrets(query).unwrap().query
+}
+
+#[test]
+fn it_redacts_role_passwords() {
+    let query = "ALTER USER app WITH ENCRYPTED PASSWORD 'md5abc'; CREATE ROLE b PASSWORD NULL";
+    let result = redact_secrets(query).unwrap();
+    assert_eq!(result.query, "ALTER USER app WITH ENCRYPTED PASSWORD '<redacted>'; CREATE ROLE b PASSWORD NULL");
+    assert_eq!(result.redactions.len(), 1);
+    assert_eq!(result.redactions[0].statement_type, "AlterRoleStmt");
+    assert_eq!(result.redactions[0].kind, SecretKind::Option("password".into()));
+    assert_eq!(result.redactions[0].span, Span::new(39, 47));
+}
+
+#[test]
+fn it_redacts_secret_options() {
+    assert_eq!(
+        redact("CREATE USER MAPPING FOR app SERVER remote OPTIONS (user 'app', password 'se''cret')"),
+        "CREATE USER MAPPING FOR app SERVER remote OPTIONS (user 'app', password '<redacted>')"
+    );
+    assert_eq!(
+        redact("ALTER USER MAPPING FOR app SERVER remote OPTIONS (SET password 'new', ADD sslkey '/keys/app.key')"),
+        "ALTER USER MAPPING FOR app SERVER remote OPTIONS (SET password '<redacted>', ADD sslkey '<redacted>')"
+    );
+    assert_eq!(
+        redact("CREATE SERVER s FOREIGN DATA WRAPPER http OPTIONS (base_url 'https://api', api_token 'abc')"),
+        "CREATE SERVER s FOREIGN DATA WRAPPER http OPTIONS (base_url 'https://api', api_token '<redacted>')"
+    );
+}
+
+#[test]
+fn it_redacts_connection_passwords() {
+    let query = "CREATE SUBSCRIPTION s CONNECTION 'postgresql://repl:pw@primary/app' PUBLICATION p WITH (password_required = false)";
+    let result = redact_secrets(query).unwrap();
+    assert_eq!(
+        result.query,
+        "CREATE SUBSCRIPTION s CONNECTION 'postgresql://repl:<redacted>@primary/app' PUBLICATION p WITH (password_required = false)"
+    );
+    assert_eq!(result.redactions.len(), 1);
+    assert_eq!(result.redactions[0].kind, SecretKind::ConnectionPassword);
+
+    assert_eq!(
+        redact("ALTER SUBSCRIPTION s CONNECTION 'host=db user=repl password=''x y'''"),
+        "ALTER SUBSCRIPTION s CONNECTION 'host=db user=repl password=<redacted>'"
+    );
+    assert_eq!(redact("ALTER SUBSCRIPTION s CONNECTION 'host=db user=repl'"), "ALTER SUBSCRIPTION s CONNECTION 'host=db user=repl'");
+}
+
+#[test]
+fn it_redacts_copy_programs() {
+    let query = "COPY t FROM PROGRAM 'curl -H \"Authorization: Bearer abc\" https://example.com' WITH (FORMAT csv)";
+    let result = redact_secrets(query).unwrap();
+    assert_eq!(result.query, "COPY t FROM PROGRAM '<redacted>' WITH (FORMAT csv)");
+    assert_eq!(result.redactions[0].statement_type, "CopyStmt");
+    assert_eq!(result.redactions[0].kind, SecretKind::Program);
+    assert_eq!(redact("COPY t TO '/tmp/t.csv'"), "COPY t TO '/tmp/t.csv'");
+}
+
+#[test]
+fn it_keeps_other_statements() {
+    let query = "SELECT 'password' AS key, password FROM users /* token */ WHERE secret = 'x'";
+    let result = redact_secrets(query).unwrap();
+    assert_eq!(result.query, query);
+    assert!(result.redactions.is_empty());
+}
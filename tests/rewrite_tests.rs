@@ -0,0 +1,143 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, Error, NodeEnum, NodeRef, Rewriter, Span};
+
+#[macro_use]
+mod support;
+
+fn find<'a>(result: &'a pg_query::ParseResult, predicate: impl Fn(&NodeRef) -> bool) -> Vec<NodeRef<'a>> {
+    result.protobuf.iter().filter(predicate).collect()
+}
+
+#[test]
+fn it_renames_tables_keeping_formatting() {
+    let query = "select u.name, count(*)\n  from Users u -- every user\n  join orders o on o.user_id = u.id\n group by 1";
+    let result = parse(query).unwrap();
+    let mut rewriter = Rewriter::new(query).unwrap();
+    for node in find(&result, |n| matches!(n, NodeRef::RangeVar(r) if r.relname == "users")) {
+        rewriter.replace_name(node, "accounts").unwrap();
+    }
+    assert_eq!(rewriter.finish(), "select u.name, count(*)\n  from accounts u -- every user\n  join orders o on o.user_id = u.id\n group by 1");
+}
+
+#[test]
+fn it_adds_schema_qualifiers() {
+    let query = "SELECT * FROM ONLY a JOIN public.b ON true, LATERAL (SELECT * FROM c) AS s";
+    let result = parse(query).unwrap();
+    let mut rewriter = Rewriter::new(query).unwrap();
+    for node in find(&result, |n| matches!(n, NodeRef::RangeVar(r) if r.schemaname.is_empty())) {
+        rewriter.insert_before_name(node, "app.").unwrap();
+    }
+    assert_eq!(rewriter.finish(), "SELECT * FROM ONLY app.a JOIN public.b ON true, LATERAL (SELECT * FROM app.c) AS s");
+}
+
+#[test]
+fn it_replaces_nodes() {
+    let query = "SELECT a FROM t WHERE  b = 1   AND c > now() - interval '1 day'";
+    let result = parse(query).unwrap();
+    let mut rewriter = Rewriter::new(query).unwrap();
+    let conditions = find(&result, |n| matches!(n, NodeRef::AExpr(_)));
+    rewriter.replace(conditions[0], "b = $1").unwrap().replace(conditions[1], "c > $2").unwrap();
+    assert_eq!(rewriter.finish(), "SELECT a FROM t WHERE  b = $1   AND c > $2");
+    // The original text is untouched
+    assert_eq!(Rewriter::new(query).unwrap().finish(), query);
+}
+
+#[test]
+fn it_inserts_before_nodes() {
+    let query = "SELECT a, b FROM t";
+    let result = parse(query).unwrap();
+    let mut rewriter = Rewriter::new(query).unwrap();
+    let targets = find(&result, |n| matches!(n, NodeRef::ResTarget(_)));
+    rewriter.insert_before(targets[1], "x, ").unwrap();
+    rewriter.insert_before(targets[1], "y, ").unwrap();
+    rewriter.insert_at(query.len(), " WHERE x").unwrap();
+    assert_eq!(rewriter.finish(), "SELECT a, x, y, b FROM t WHERE x");
+}
+
+#[test]
+fn it_deletes_list_elements() {
+    let query = "SELECT a, b, c FROM t, u";
+    let result = parse(query).unwrap();
+    let targets = find(&result, |n| matches!(n, NodeRef::ResTarget(_)));
+    let tables = find(&result, |n| matches!(n, NodeRef::RangeVar(_)));
+
+    let mut rewriter = Rewriter::new(query).unwrap();
+    rewriter.delete(targets[1]).unwrap().delete(tables[1]).unwrap();
+    assert_eq!(rewriter.finish(), "SELECT a, c FROM t");
+
+    let mut rewriter = Rewriter::new(query).unwrap();
+    rewriter.delete(targets[0]).unwrap().delete(tables[0]).unwrap();
+    assert_eq!(rewriter.finish(), "SELECT b, c FROM u");
+
+    let mut rewriter = Rewriter::new(query).unwrap();
+    rewriter.delete(targets[1]).unwrap().delete(targets[2]).unwrap();
+    assert_eq!(rewriter.finish(), "SELECT a FROM t, u");
+
+    let mut rewriter = Rewriter::new(query).unwrap();
+    rewriter.delete(targets[0]).unwrap().delete(targets[1]).unwrap();
+    assert_eq!(rewriter.finish(), "SELECT c FROM t, u");
+}
+
+#[test]
+fn it_deletes_boolean_operands() {
+    let query = "SELECT * FROM t WHERE a = 1 AND (b = 2 OR c = 3) AND d BETWEEN 4 AND 5";
+    let result = parse(query).unwrap();
+    let conditions = find(&result, |n| matches!(n, NodeRef::AExpr(_)));
+
+    let mut rewriter = Rewriter::new(query).unwrap();
+    rewriter.delete(conditions[0]).unwrap().delete(conditions[2]).unwrap();
+    assert_eq!(rewriter.finish(), "SELECT * FROM t WHERE (b = 2) AND d BETWEEN 4 AND 5");
+
+    let mut rewriter = Rewriter::new(query).unwrap();
+    rewriter.delete(conditions[3]).unwrap().delete(conditions[1]).unwrap();
+    assert_eq!(rewriter.finish(), "SELECT * FROM t WHERE a = 1 AND (c = 3)");
+
+    // Parentheses around an operand are deleted with it, and adjacent operands don't leave an operator behind
+    let or = find(&result, |n| matches!(n, NodeRef::BoolExpr(_)))[1];
+    let mut rewriter = Rewriter::new(query).unwrap();
+    rewriter.delete(conditions[0]).unwrap().delete(or).unwrap();
+    assert_eq!(rewriter.finish(), "SELECT * FROM t WHERE d BETWEEN 4 AND 5");
+}
+
+#[test]
+fn it_rejects_overlapping_edits() {
+    let query = "SELECT a FROM users u WHERE b = 1";
+    let result = parse(query).unwrap();
+    let table = find(&result, |n| matches!(n, NodeRef::RangeVar(_)))[0];
+    let condition = find(&result, |n| matches!(n, NodeRef::AExpr(_)))[0];
+
+    let mut rewriter = Rewriter::new(query).unwrap();
+    rewriter.replace(table, "accounts AS u").unwrap();
+    assert_eq!(rewriter.replace_name(table, "people").unwrap_err(), Error::Rewrite("edit at 14..19 overlaps edit at 14..21".to_string()));
+    assert_eq!(rewriter.insert_at(17, "x").unwrap_err(), Error::Rewrite("edit at 17..17 overlaps edit at 14..21".to_string()));
+    assert!(matches!(rewriter.replace_span(Span::new(9, 15), "x"), Err(Error::Rewrite(_))));
+
+    // Replacing a region with an insertion inside of it is rejected as well
+    let mut inserted = Rewriter::new(query).unwrap();
+    inserted.insert_at(17, "x").unwrap();
+    assert_eq!(inserted.replace(table, "accounts").unwrap_err(), Error::Rewrite("edit at 14..21 overlaps edit at 17..17".to_string()));
+
+    // Edits next to each other, and insertions where a replacement starts, don't overlap
+    rewriter.insert_before(table, "/* renamed */ ").unwrap();
+    rewriter.replace_span(Span::new(21, 27), " WHERE").unwrap();
+    rewriter.replace(condition, "c = 2").unwrap();
+    assert_eq!(rewriter.finish(), "SELECT a FROM /* renamed */ accounts AS u WHERE c = 2");
+}
+
+#[test]
+fn it_rejects_invalid_edits() {
+    let query = "DROP TABLE café";
+    let result = parse(query).unwrap();
+    let mut rewriter = Rewriter::new(query).unwrap();
+    let Some(NodeEnum::DropStmt(drop)) = &result.protobuf.stmts[0].stmt.as_ref().unwrap().node else { panic!("expected a DropStmt") };
+    assert_eq!(rewriter.replace(NodeRef::DropStmt(drop), "").unwrap_err(), Error::Rewrite("DropStmt has no location in the query".to_string()));
+    assert!(matches!(rewriter.delete_span(Span::new(11, 15)), Err(Error::Rewrite(_))));
+    assert!(matches!(rewriter.delete_span(Span::new(11, 100)), Err(Error::Rewrite(_))));
+
+    // Statements without a location can be edited through their statement span
+    let span = rewriter.spans().statement_span(&result.protobuf.stmts[0]).unwrap();
+    rewriter.replace_span(span, "TRUNCATE café").unwrap();
+    assert_eq!(rewriter.finish(), "TRUNCATE café");
+}
@@ -0,0 +1,112 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hasher;
+
+use pg_query::{parse, NodeEnum, SemanticEq, SemanticHash, SemanticKey, SemanticOptions};
+
+#[macro_use]
+mod support;
+
+const ALIASES: SemanticOptions = SemanticOptions { ignore_aliases: true, commutative_bool_ops: false };
+const COMMUTATIVE: SemanticOptions = SemanticOptions { ignore_aliases: false, commutative_bool_ops: true };
+
+fn hash(query: &str, options: &SemanticOptions) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    parse(query).unwrap().semantic_hash_with(options, &mut hasher);
+    hasher.finish()
+}
+
+// Whether the queries are equal under `options`, checking that equal queries hash the same
+fn equal(a: &str, b: &str, options: &SemanticOptions) -> bool {
+    let equal = parse(a).unwrap().semantic_eq_with(&parse(b).unwrap(), options);
+    if equal {
+        assert_eq!(hash(a, options), hash(b, options));
+    }
+    equal
+}
+
+#[test]
+fn it_ignores_locations() {
+    let options = SemanticOptions::default();
+    assert!(equal("SELECT a, b FROM t WHERE c = 1", "select a,\n       b\n  from t -- comment\n where c = 1", &options));
+    assert!(equal("SELECT 1; SELECT 2", "SELECT 1;\n\n/* two */ SELECT 2;", &options));
+    assert!(equal("CREATE TABLE t (id int PRIMARY KEY, name text)", "create table t(id int primary key,name text)", &options));
+
+    assert!(!equal("SELECT a FROM t", "SELECT b FROM t", &options));
+    assert!(!equal("SELECT 1; SELECT 2", "SELECT 1", &options));
+    assert!(!equal("SELECT a FROM t WHERE b = 1", "SELECT a FROM t WHERE b = 1.0", &options));
+    assert!(!equal("SELECT a FROM t u", "SELECT a FROM t v", &options));
+    assert!(!equal("SELECT a FROM t WHERE b AND c", "SELECT a FROM t WHERE c AND b", &options));
+}
+
+#[test]
+fn it_optionally_ignores_aliases() {
+    assert!(equal("SELECT u.id FROM users u", "SELECT x.id FROM users AS x", &ALIASES));
+    assert!(equal("SELECT a AS x FROM t", "SELECT a AS y FROM t", &ALIASES));
+    assert!(equal(
+        "SELECT s.n FROM (SELECT count(*) AS n FROM t) s JOIN u ON u.id = s.n",
+        "SELECT c.n FROM (SELECT count(*) AS total FROM t) c JOIN u ON u.id = c.n",
+        &ALIASES
+    ));
+    assert!(equal("DELETE FROM t RETURNING id AS a", "DELETE FROM t RETURNING id AS b", &ALIASES));
+
+    // Aliases must still refer to the same tables
+    assert!(!equal("SELECT a.id FROM users a, orders b", "SELECT b.id FROM users a, orders b", &ALIASES));
+    assert!(!equal("SELECT u.id FROM users u", "SELECT x.id FROM orders x", &ALIASES));
+    // Column names in UPDATE and INSERT are not aliases
+    assert!(!equal("UPDATE t SET a = 1", "UPDATE t SET b = 1", &ALIASES));
+    assert!(!equal("INSERT INTO t (a) VALUES (1)", "INSERT INTO t (b) VALUES (1)", &ALIASES));
+}
+
+#[test]
+fn it_optionally_ignores_the_order_of_and_and_or() {
+    assert!(equal("SELECT * FROM t WHERE a = 1 AND b = 2", "SELECT * FROM t WHERE b = 2 AND a = 1", &COMMUTATIVE));
+    assert!(equal("SELECT * FROM t WHERE a OR (b OR c)", "SELECT * FROM t WHERE c OR a OR b", &COMMUTATIVE));
+    assert!(equal("SELECT * FROM t WHERE (a OR b) AND c", "SELECT * FROM t WHERE c AND (b OR a)", &COMMUTATIVE));
+    assert!(equal("SELECT * FROM t JOIN u ON t.x = u.x AND t.y = u.y", "SELECT * FROM t JOIN u ON t.y = u.y AND t.x = u.x", &COMMUTATIVE));
+
+    assert!(!equal("SELECT * FROM t WHERE a AND b", "SELECT * FROM t WHERE a OR b", &COMMUTATIVE));
+    assert!(!equal("SELECT * FROM t WHERE a AND (b OR c)", "SELECT * FROM t WHERE (a AND b) OR c", &COMMUTATIVE));
+    assert!(!equal("SELECT * FROM t WHERE a AND a", "SELECT * FROM t WHERE a", &COMMUTATIVE));
+    // Other operators keep their order
+    assert!(!equal("SELECT * FROM t WHERE a = b", "SELECT * FROM t WHERE b = a", &COMMUTATIVE));
+}
+
+#[test]
+fn it_compares_nodes() {
+    let a = parse("SELECT * FROM t WHERE x = 1").unwrap();
+    let b = parse("SELECT y FROM u  WHERE  x = 1").unwrap();
+    let condition = |result: &pg_query::ParseResult| {
+        let NodeEnum::SelectStmt(select) = result.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap() else {
+            panic!("expected a SelectStmt")
+        };
+        select.where_clause.as_deref().unwrap().clone()
+    };
+    assert_ne!(condition(&a), condition(&b));
+    assert!(condition(&a).semantic_eq(&condition(&b)));
+    assert!(condition(&a).node.unwrap().semantic_eq(&condition(&b).node.unwrap()));
+    assert!(!a.protobuf.semantic_eq(&b.protobuf));
+}
+
+#[test]
+fn it_deduplicates_in_sets_and_maps() {
+    let queries = [
+        "SELECT * FROM users u WHERE u.id = $1 AND u.active",
+        "select * from users u where u.id = $1 and u.active",
+        "SELECT * FROM users x WHERE x.active AND x.id = $1",
+        "SELECT * FROM users u WHERE u.id = $2",
+    ];
+    let unique: HashSet<_> = queries.iter().map(|q| SemanticKey::new(parse(q).unwrap())).collect();
+    assert_eq!(unique.len(), 3);
+
+    let options = SemanticOptions { ignore_aliases: true, commutative_bool_ops: true };
+    let mut counts = HashMap::new();
+    for query in queries {
+        *counts.entry(SemanticKey::with_options(parse(query).unwrap(), options)).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&SemanticKey::with_options(parse(queries[0]).unwrap(), options)], 3);
+}
@@ -0,0 +1,205 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, protobuf, Node, NodeEnum, NodeRef, SourceSpans, Span};
+
+#[macro_use]
+mod support;
+
+fn select_stmt(result: &protobuf::ParseResult, index: usize) -> &protobuf::SelectStmt {
+    match result.stmts[index].stmt.as_ref().and_then(|n| n.node.as_ref()) {
+        Some(NodeEnum::SelectStmt(s)) => s,
+        _ => panic!("expected a SelectStmt"),
+    }
+}
+
+fn texts<'a>(spans: &SourceSpans<'a>, nodes: &'a [Node]) -> Vec<&'a str> {
+    nodes.iter().map(|n| spans.text(n.node.as_ref().unwrap().to_ref()).unwrap()).collect()
+}
+
+#[test]
+fn it_spans_expressions() {
+    let query =
+        "SELECT a + 1, -2, f(x, y), count(*), now(), x::int[], CAST(y AS varchar(10)), CASE WHEN a THEN 1 ELSE 2 END, t.*, (a).b, arr[1] FROM t";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let select = select_stmt(&result.protobuf, 0);
+    assert_eq!(
+        texts(&spans, &select.target_list),
+        [
+            "a + 1",
+            "-2",
+            "f(x, y)",
+            "count(*)",
+            "now()",
+            "x::int[]",
+            "CAST(y AS varchar(10))",
+            "CASE WHEN a THEN 1 ELSE 2 END",
+            "t.*",
+            "(a).b",
+            "arr[1]"
+        ]
+    );
+}
+
+#[test]
+fn it_spans_aliases() {
+    let query = "SELECT coalesce(a, 0) AS total, b \"Name\", c FROM t";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let select = select_stmt(&result.protobuf, 0);
+    assert_eq!(texts(&spans, &select.target_list), ["coalesce(a, 0) AS total", "b \"Name\"", "c"]);
+}
+
+#[test]
+fn it_spans_from_items() {
+    let query = "SELECT * FROM ONLY a, public.b AS bb (c1, c2), LATERAL (SELECT 1) AS s, generate_series(1, 2) g, c JOIN d USING (id) WHERE true";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let select = select_stmt(&result.protobuf, 0);
+    assert_eq!(
+        texts(&spans, &select.from_clause),
+        ["ONLY a", "public.b AS bb (c1, c2)", "LATERAL (SELECT 1) AS s", "generate_series(1, 2) g", "c JOIN d USING (id)"]
+    );
+}
+
+#[test]
+fn it_spans_conditions() {
+    let query = "SELECT 1 FROM t WHERE a IS NULL AND b IS NOT TRUE AND c IN (1, 2) AND d BETWEEN 1 AND 2 AND e = ANY(ARRAY[1]) AND EXISTS (SELECT 1) AND f LIKE 'x%'";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let select = select_stmt(&result.protobuf, 0);
+    let Some(NodeEnum::BoolExpr(and)) = select.where_clause.as_ref().and_then(|n| n.node.as_ref()) else { panic!("expected a BoolExpr") };
+    assert_eq!(
+        texts(&spans, &and.args),
+        ["a IS NULL", "b IS NOT TRUE", "c IN (1, 2)", "d BETWEEN 1 AND 2", "e = ANY(ARRAY[1])", "EXISTS (SELECT 1)", "f LIKE 'x%'"]
+    );
+    assert_eq!(spans.text(NodeRef::BoolExpr(and)), Some(&query[22..]));
+}
+
+#[test]
+fn it_spans_sort_clauses() {
+    let query = "SELECT a FROM t ORDER BY a DESC NULLS LAST, b USING < LIMIT 5";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let select = select_stmt(&result.protobuf, 0);
+    assert_eq!(texts(&spans, &select.sort_clause), ["a DESC NULLS LAST", "b USING <"]);
+    assert_eq!(spans.text(NodeRef::SelectStmt(select)), Some(query));
+}
+
+#[test]
+fn it_spans_statements() {
+    let query = "SELECT 1;\nCREATE TABLE t (a int NOT NULL DEFAULT 0, b text REFERENCES u (id) ON DELETE CASCADE, CONSTRAINT pk PRIMARY KEY (a));\n  DROP TABLE t CASCADE -- bye\n";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let statements: Vec<&str> = result.protobuf.stmts.iter().map(|s| spans.statement_span(s).unwrap().text(query)).collect();
+    assert_eq!(
+        statements,
+        [
+            "SELECT 1",
+            "CREATE TABLE t (a int NOT NULL DEFAULT 0, b text REFERENCES u (id) ON DELETE CASCADE, CONSTRAINT pk PRIMARY KEY (a))",
+            "DROP TABLE t CASCADE"
+        ]
+    );
+
+    let Some(NodeEnum::CreateStmt(create)) = result.protobuf.stmts[1].stmt.as_ref().and_then(|n| n.node.as_ref()) else {
+        panic!("expected a CreateStmt")
+    };
+    assert_eq!(spans.text(NodeRef::CreateStmt(create)), Some(statements[1]));
+    assert_eq!(
+        texts(&spans, &create.table_elts),
+        ["a int NOT NULL DEFAULT 0", "b text REFERENCES u (id) ON DELETE CASCADE", "CONSTRAINT pk PRIMARY KEY (a)"]
+    );
+    let constraints: Vec<&str> = create
+        .table_elts
+        .iter()
+        .filter_map(|n| match &n.node {
+            Some(NodeEnum::ColumnDef(c)) => Some(texts(&spans, &c.constraints)),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    assert_eq!(constraints, ["NOT NULL", "DEFAULT 0", "REFERENCES u (id) ON DELETE CASCADE"]);
+}
+
+#[test]
+fn it_spans_nested_statements() {
+    let query = "WITH x AS (INSERT INTO t (a) VALUES (1) RETURNING a) SELECT * FROM x UNION SELECT b FROM u";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let texts: Vec<&str> = result
+        .protobuf
+        .iter()
+        .filter(|n| matches!(n, NodeRef::SelectStmt(_) | NodeRef::InsertStmt(_) | NodeRef::CommonTableExpr(_)))
+        .map(|n| spans.text(n).unwrap())
+        .collect();
+    assert_eq!(
+        texts,
+        [
+            query,
+            "x AS (INSERT INTO t (a) VALUES (1) RETURNING a)",
+            "INSERT INTO t (a) VALUES (1) RETURNING a",
+            "VALUES (1)",
+            "SELECT * FROM x",
+            "SELECT b FROM u"
+        ]
+    );
+}
+
+#[test]
+fn it_spans_statements_within_other_statements() {
+    let query = "CREATE SCHEMA s CREATE TABLE a (x int) CREATE VIEW v AS SELECT 1 CREATE TABLE b (y int); EXPLAIN CREATE TABLE c AS SELECT 2";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let texts: Vec<&str> = result
+        .protobuf
+        .iter()
+        .filter(|n| n.type_name().ends_with("Stmt") && !matches!(n, NodeRef::SelectStmt(_)))
+        .map(|n| spans.text(n).unwrap())
+        .collect();
+    assert_eq!(
+        texts,
+        [
+            "CREATE SCHEMA s CREATE TABLE a (x int) CREATE VIEW v AS SELECT 1 CREATE TABLE b (y int)",
+            "CREATE TABLE a (x int)",
+            "CREATE VIEW v AS SELECT 1",
+            "CREATE TABLE b (y int)",
+            "EXPLAIN CREATE TABLE c AS SELECT 2",
+            "CREATE TABLE c AS SELECT 2"
+        ]
+    );
+}
+
+#[test]
+fn it_spans_multibyte_text_and_comments() {
+    let query = "SELECT /* first */ 'café'  AS  \"Näme\" -- trailing\n FROM t";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let select = select_stmt(&result.protobuf, 0);
+    let target = select.target_list[0].node.as_ref().unwrap().to_ref();
+    assert_eq!(spans.text(target), Some("'café'  AS  \"Näme\""));
+    assert_eq!(spans.span(target), Some(Span::new(19, 39)));
+}
+
+#[test]
+fn it_returns_none_without_locations() {
+    let query = "SELECT t.a FROM t";
+    let result = parse(query).unwrap();
+    let spans = SourceSpans::new(query).unwrap();
+    let names: Vec<Option<&str>> = result.protobuf.iter().filter(|n| matches!(n, NodeRef::String(_))).map(|n| spans.text(n)).collect();
+    assert_eq!(names, [None, None]);
+}
+
+#[test]
+fn it_compares_spans() {
+    let outer = Span::new(5, 20);
+    let inner = Span::new(8, 12);
+    assert!(outer.contains(&inner));
+    assert!(!inner.contains(&outer));
+    assert!(outer.overlaps(&inner));
+    assert!(!Span::new(0, 5).overlaps(&outer));
+    assert!(Span::new(0, 6).overlaps(&outer));
+    assert_eq!(inner.len(), 4);
+    assert!(Span::new(3, 3).is_empty());
+    assert_eq!(inner.text("0123456789abcdef"), "89ab");
+}
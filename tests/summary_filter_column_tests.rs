@@ -14,8 +14,8 @@ fn it_finds_unqualified_names() {
     assert_eq!(
         filter_columns,
         vec![
-            FilterColumn { schema_name: None, table_name: None, column: "y".to_string() },
-            FilterColumn { schema_name: None, table_name: None, column: "z".to_string() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "y".to_string() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "z".to_string() },
         ]
     );
 }
@@ -40,7 +40,7 @@ fn it_traverses_into_ctes() {
     assert_eq!(
         filter_columns,
         [
-            FilterColumn { schema_name: None, table_name: None, column: "b".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "b".into() },
             FilterColumn { schema_name: None, table_name: Some("x".into()), column: "y".into() },
             FilterColumn { schema_name: None, table_name: Some("x".into()), column: "z".into() },
         ]
@@ -94,8 +94,8 @@ fn it_finds_unqualified_names_in_union_query() {
     assert_eq!(
         filter_columns,
         [
-            FilterColumn { schema_name: None, table_name: None, column: "y".into() },
-            FilterColumn { schema_name: None, table_name: None, column: "z".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "y".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "z".into() },
         ]
     );
 }
@@ -107,8 +107,8 @@ fn it_finds_unqualified_names_in_union_all_query() {
     assert_eq!(
         filter_columns,
         [
-            FilterColumn { schema_name: None, table_name: None, column: "y".into() },
-            FilterColumn { schema_name: None, table_name: None, column: "z".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "y".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "z".into() },
         ]
     );
 }
@@ -120,8 +120,8 @@ fn it_finds_unqualified_names_in_except_query() {
     assert_eq!(
         filter_columns,
         [
-            FilterColumn { schema_name: None, table_name: None, column: "y".into() },
-            FilterColumn { schema_name: None, table_name: None, column: "z".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "y".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "z".into() },
         ]
     );
 }
@@ -133,8 +133,8 @@ fn it_finds_unqualified_names_in_except_all_query() {
     assert_eq!(
         filter_columns,
         [
-            FilterColumn { schema_name: None, table_name: None, column: "y".into() },
-            FilterColumn { schema_name: None, table_name: None, column: "z".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "y".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "z".into() },
         ]
     );
 }
@@ -146,8 +146,8 @@ fn it_finds_unqualified_names_in_intersect_query() {
     assert_eq!(
         filter_columns,
         [
-            FilterColumn { schema_name: None, table_name: None, column: "y".into() },
-            FilterColumn { schema_name: None, table_name: None, column: "z".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "y".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "z".into() },
         ]
     );
 }
@@ -159,8 +159,8 @@ fn it_finds_unqualified_names_in_intersect_all_query() {
     assert_eq!(
         filter_columns,
         [
-            FilterColumn { schema_name: None, table_name: None, column: "y".into() },
-            FilterColumn { schema_name: None, table_name: None, column: "z".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "y".into() },
+            FilterColumn { schema_name: None, table_name: Some("x".into()), column: "z".into() },
         ]
     );
 }
@@ -190,3 +190,17 @@ fn it_ignores_order_by_columns() {
         ]
     );
 }
+
+#[test]
+fn it_resolves_aliases_like_parse() {
+    let query = "WITH recent AS (SELECT * FROM app.events) SELECT * FROM recent r JOIN users u ON u.id = r.user_id WHERE r.kind = $1 AND u.active";
+    let result = summary(query, -1).unwrap();
+    let filter_columns: Vec<FilterColumn> = sorted(result.filter_columns).collect();
+    assert_eq!(
+        filter_columns,
+        [
+            FilterColumn { schema_name: None, table_name: Some("users".into()), column: "active".into() },
+            FilterColumn { schema_name: Some("app".into()), table_name: Some("events".into()), column: "kind".into() },
+        ]
+    );
+}
@@ -51,8 +51,8 @@ fn it_parses_query_with_nested_select_where() {
     assert_eq!(
         filter_columns,
         [
-            &FilterColumn { schema_name: None, table_name: None, column: "col1".to_string() },
-            &FilterColumn { schema_name: None, table_name: None, column: "col3".to_string() },
+            &FilterColumn { schema_name: None, table_name: Some("test".into()), column: "col1".to_string() },
+            &FilterColumn { schema_name: None, table_name: Some("test2".into()), column: "col3".to_string() },
         ]
     );
     assert_eq!(result.statement_types(), ["SelectStmt"]);
@@ -61,10 +61,14 @@ fn it_parses_query_with_nested_select_where() {
 #[test]
 fn it_handles_errors() {
     let error = summary("CREATE RANDOM ix_test ON contacts.person;", -1).err().unwrap();
-    assert_eq!(error, Error::Parse("syntax error at or near \"RANDOM\"".into()));
+    assert_eq!(error.to_string(), "Invalid statement: syntax error at or near \"RANDOM\"");
 
     let error = summary("SELECT 'ERR", -1).err().unwrap();
-    assert_eq!(error, Error::Parse("unterminated quoted string at or near \"'ERR\"".into()));
+    assert_eq!(error.to_string(), "Invalid statement: unterminated quoted string at or near \"'ERR\"");
+
+    let Error::Parse(error) = summary("SELECT * FROM t WHERE", -1).err().unwrap() else { panic!("expected a parse error") };
+    assert_eq!(error.message, "syntax error at end of input");
+    assert_eq!(error.position.map(|p| (p.line, p.column)), Some((1, 22)));
 }
 
 #[test]
@@ -306,7 +310,7 @@ fn it_parses_CREATE_TABLE_AS() {
 #[test]
 fn it_fails_to_parse_CREATE_TABLE_WITH_OIDS() {
     let error = summary("CREATE TABLE test (a int4) WITH OIDS", -1).err().unwrap();
-    assert_eq!(error, Error::Parse("syntax error at or near \"OIDS\"".to_string()));
+    assert_eq!(error.to_string(), "Invalid statement: syntax error at or near \"OIDS\"");
 }
 
 #[test]
@@ -0,0 +1,106 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, TransitionTable, TriggerDefinition, TriggerEvent, TriggerTiming, TriggerType};
+
+#[macro_use]
+mod support;
+
+fn trigger(query: &str) -> TriggerDefinition {
+    let mut triggers = parse(query).unwrap().triggers();
+    assert_eq!(triggers.len(), 1);
+    triggers.remove(0)
+}
+
+#[test]
+fn it_decodes_a_row_trigger() {
+    let trigger = trigger("CREATE TRIGGER set_updated_at BEFORE UPDATE ON users FOR EACH ROW EXECUTE FUNCTION set_updated_at()");
+    assert_eq!(trigger.name, "set_updated_at");
+    assert_eq!(trigger.relation, "users");
+    assert_eq!(trigger.timing, TriggerTiming::Before);
+    assert_eq!(trigger.events, [TriggerEvent::Update { columns: vec![] }]);
+    assert!(trigger.for_each_row);
+    assert_eq!(trigger.when, None);
+    assert_eq!(trigger.function, "set_updated_at");
+    assert!(trigger.arguments.is_empty());
+    assert!(!trigger.replace && !trigger.is_constraint);
+    assert_eq!(trigger.trigger_type(), TriggerType::Row as i32 | TriggerType::Before as i32 | TriggerType::Update as i32);
+}
+
+#[test]
+fn it_decodes_a_statement_trigger() {
+    let trigger = trigger("CREATE OR REPLACE TRIGGER forbid_truncate BEFORE TRUNCATE ON app.events EXECUTE PROCEDURE app.forbid()");
+    assert!(trigger.replace);
+    assert_eq!(trigger.relation, "app.events");
+    assert_eq!(trigger.events, [TriggerEvent::Truncate]);
+    assert!(!trigger.for_each_row);
+    assert_eq!(trigger.function, "app.forbid");
+    assert_eq!(trigger.trigger_type(), TriggerType::Before as i32 | TriggerType::Truncate as i32);
+}
+
+#[test]
+fn it_decodes_events() {
+    let trigger = trigger("CREATE TRIGGER audit AFTER UPDATE OF email, name OR DELETE OR INSERT ON users FOR EACH ROW EXECUTE FUNCTION audit()");
+    assert_eq!(trigger.timing, TriggerTiming::After);
+    assert_eq!(
+        trigger.events,
+        [TriggerEvent::Insert, TriggerEvent::Delete, TriggerEvent::Update { columns: vec!["email".to_string(), "name".to_string()] }]
+    );
+    assert_eq!(
+        trigger.trigger_type(),
+        TriggerType::Row as i32 | TriggerType::Insert as i32 | TriggerType::Delete as i32 | TriggerType::Update as i32
+    );
+}
+
+#[test]
+fn it_decodes_instead_of_triggers() {
+    let trigger = trigger("CREATE TRIGGER insert_active INSTEAD OF INSERT ON active_users FOR EACH ROW EXECUTE FUNCTION insert_user()");
+    assert_eq!(trigger.timing, TriggerTiming::InsteadOf);
+    assert_eq!(trigger.trigger_type(), TriggerType::Row as i32 | TriggerType::Instead as i32 | TriggerType::Insert as i32);
+}
+
+#[test]
+fn it_decodes_when_conditions_and_arguments() {
+    let trigger = trigger(
+        "CREATE TRIGGER notify AFTER UPDATE ON jobs FOR EACH ROW
+         WHEN (OLD.state IS DISTINCT FROM NEW.state) EXECUTE FUNCTION notify('jobs', 42, 1.5, state)",
+    );
+    assert_eq!(trigger.when.unwrap().deparse().unwrap(), "old.state IS DISTINCT FROM new.state");
+    assert_eq!(trigger.arguments, ["jobs", "42", "1.5", "state"]);
+}
+
+#[test]
+fn it_decodes_transition_tables() {
+    let trigger = trigger(
+        "CREATE TRIGGER summarize AFTER UPDATE ON orders REFERENCING OLD TABLE AS before NEW TABLE AS after
+         FOR EACH STATEMENT EXECUTE FUNCTION summarize()",
+    );
+    assert_eq!(
+        trigger.transition_tables,
+        [TransitionTable { name: "before".to_string(), is_new: false }, TransitionTable { name: "after".to_string(), is_new: true }]
+    );
+}
+
+#[test]
+fn it_decodes_constraint_triggers() {
+    let trigger = trigger(
+        "CREATE CONSTRAINT TRIGGER check_balance AFTER INSERT ON transfers FROM accounts
+         DEFERRABLE INITIALLY DEFERRED FOR EACH ROW EXECUTE FUNCTION check_balance()",
+    );
+    assert!(trigger.is_constraint);
+    assert!(trigger.deferrable);
+    assert!(trigger.initially_deferred);
+    assert_eq!(trigger.referenced_relation.as_deref(), Some("accounts"));
+}
+
+#[test]
+fn it_finds_triggers_of_all_statements() {
+    let result = parse(
+        "CREATE TABLE t (id int);
+         CREATE TRIGGER a AFTER INSERT ON t EXECUTE FUNCTION f();
+         CREATE SCHEMA s CREATE TRIGGER b BEFORE DELETE ON t FOR EACH ROW EXECUTE FUNCTION f()",
+    )
+    .unwrap();
+    let names: Vec<String> = result.triggers().into_iter().map(|t| t.name).collect();
+    assert_eq!(names, ["a", "b"]);
+}
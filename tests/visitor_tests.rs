@@ -0,0 +1,151 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{
+    parse,
+    protobuf::{self, a_const::Val},
+    visitor::{walk_node_mut, walk_range_var, walk_sub_link},
+    NodeEnum, Visitor, VisitorMut,
+};
+
+#[macro_use]
+mod support;
+
+#[derive(Default)]
+struct Collector {
+    relations: Vec<String>,
+    columns: Vec<String>,
+    integers: Vec<i32>,
+    skip_sublinks: bool,
+}
+
+impl<'ast> Visitor<'ast> for Collector {
+    fn visit_range_var(&mut self, node: &'ast protobuf::RangeVar) {
+        self.relations.push(node.relname.clone());
+        walk_range_var(self, node);
+    }
+
+    fn visit_column_ref(&mut self, node: &'ast protobuf::ColumnRef) {
+        let fields: Vec<&str> = node
+            .fields
+            .iter()
+            .filter_map(|n| match &n.node {
+                Some(NodeEnum::String(s)) => Some(s.sval.as_str()),
+                Some(NodeEnum::AStar(_)) => Some("*"),
+                _ => None,
+            })
+            .collect();
+        self.columns.push(fields.join("."));
+    }
+
+    fn visit_integer(&mut self, node: &'ast protobuf::Integer) {
+        self.integers.push(node.ival);
+    }
+
+    fn visit_sub_link(&mut self, node: &'ast protobuf::SubLink) {
+        if !self.skip_sublinks {
+            walk_sub_link(self, node);
+        }
+    }
+}
+
+fn collect(query: &str) -> Collector {
+    let result = parse(query).unwrap();
+    let mut collector = Collector::default();
+    collector.visit_parse_result(&result.protobuf);
+    collector
+}
+
+#[test]
+fn it_visits_nested_nodes() {
+    let collector = collect(
+        "WITH recent AS (SELECT id FROM orders WHERE created_at > now() - interval '1 day')
+        SELECT u.name, (SELECT count(*) FROM items i WHERE i.order_id = r.id)
+        FROM users u
+        JOIN recent r ON r.user_id = u.id
+        CROSS JOIN LATERAL (SELECT * FROM events e WHERE e.user_id = u.id LIMIT 3) ev
+        WHERE u.id IN (SELECT user_id FROM admins)",
+    );
+    assert_eq!(collector.relations, ["items", "users", "recent", "events", "admins", "orders"]);
+    assert_eq!(
+        collector.columns,
+        ["u.name", "i.order_id", "r.id", "r.user_id", "u.id", "*", "e.user_id", "u.id", "u.id", "user_id", "id", "created_at"]
+    );
+    assert_eq!(collector.integers, [3]);
+}
+
+#[test]
+fn it_visits_fields_outside_of_node_wrappers() {
+    // The INTO target and the ON CONFLICT clause are plain messages rather than `Node`s
+    let collector = collect("SELECT a INTO archive FROM t");
+    assert_eq!(collector.relations, ["archive", "t"]);
+
+    let collector = collect("INSERT INTO t (a) VALUES (1) ON CONFLICT (a) DO UPDATE SET b = excluded.b + 2 RETURNING c");
+    assert_eq!(collector.relations, ["t"]);
+    assert_eq!(collector.columns, ["excluded.b", "c"]);
+    assert_eq!(collector.integers, [1, 2]);
+}
+
+#[test]
+fn it_visits_ddl_statements() {
+    let collector = collect("CREATE TABLE t (a int DEFAULT 42 REFERENCES other (id), CHECK (a > 0))");
+    assert_eq!(collector.relations, ["t", "other"]);
+    assert_eq!(collector.columns, ["a"]);
+    assert_eq!(collector.integers, [42, 0]);
+}
+
+#[test]
+fn it_skips_subtrees() {
+    let query = "SELECT a FROM t WHERE b IN (SELECT c FROM u) AND EXISTS (SELECT 1 FROM v)";
+    let collector = collect(query);
+    assert_eq!(collector.relations, ["t", "u", "v"]);
+
+    let result = parse(query).unwrap();
+    let mut collector = Collector { skip_sublinks: true, ..Collector::default() };
+    collector.visit_parse_result(&result.protobuf);
+    assert_eq!(collector.relations, ["t"]);
+    assert_eq!(collector.columns, ["a"]);
+}
+
+struct Rename<'a>(&'a str, &'a str);
+
+impl VisitorMut for Rename<'_> {
+    fn visit_range_var_mut(&mut self, node: &mut protobuf::RangeVar) {
+        if node.relname == self.0 {
+            node.relname = self.1.to_string();
+        }
+    }
+}
+
+#[test]
+fn it_mutates_nodes() {
+    let mut result = parse("SELECT * FROM users WHERE id IN (SELECT user_id FROM users_admins UNION SELECT id FROM users)").unwrap();
+    Rename("users", "accounts").visit_parse_result_mut(&mut result.protobuf);
+    assert_eq!(result.deparse().unwrap(), "SELECT * FROM accounts WHERE id IN (SELECT user_id FROM users_admins UNION SELECT id FROM accounts)");
+}
+
+// Replaces every integer constant with a parameter reference
+#[derive(Default)]
+struct Parameterize(i32);
+
+impl VisitorMut for Parameterize {
+    fn visit_node_mut(&mut self, node: &mut protobuf::Node) {
+        if let Some(NodeEnum::AConst(aconst)) = &node.node {
+            if let Some(Val::Ival(_)) = aconst.val {
+                self.0 += 1;
+                node.node = Some(NodeEnum::ParamRef(protobuf::ParamRef { number: self.0, location: aconst.location }));
+                return;
+            }
+        }
+        walk_node_mut(self, node);
+    }
+}
+
+#[test]
+fn it_replaces_nodes() {
+    let mut result = parse("SELECT a + 1 FROM t WHERE b = 2 AND c = 'x' LIMIT 10").unwrap();
+    let mut parameterize = Parameterize::default();
+    parameterize.visit_parse_result_mut(&mut result.protobuf);
+    assert_eq!(parameterize.0, 3);
+    assert_eq!(result.deparse().unwrap(), "SELECT a + $1 FROM t WHERE b = $2 AND c = 'x' LIMIT $3");
+}